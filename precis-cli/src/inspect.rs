@@ -0,0 +1,139 @@
+use precis_core::context;
+use precis_core::{DerivedPropertyValue, FreeformClass, IdentifierClass};
+use precis_profiles::properties::{self, BidiClass};
+use serde_json::{json, Value};
+use std::fmt;
+
+/// Properties of a code point relevant to the PRECIS profiles
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inspection {
+    /// The code point
+    pub c: char,
+    /// Derived property value in the `IdentifierClass`
+    pub identifier_class: DerivedPropertyValue,
+    /// Derived property value in the `FreeformClass`
+    pub freeform_class: DerivedPropertyValue,
+    /// Name of the context rule that applies to the code point, if any
+    pub context_rule: Option<&'static str>,
+    /// Value of the `Bidi_Class` property
+    pub bidi_class: BidiClass,
+    /// Code point this one is mapped to by the width mapping rule, if any
+    pub width_mapping: Option<char>,
+}
+
+fn codepoint_str(c: char) -> String {
+    format!("U+{:04X}", c as u32)
+}
+
+impl Inspection {
+    /// Collects the properties of a code point
+    pub fn new(c: char) -> Self {
+        Self {
+            c,
+            identifier_class: IdentifierClass::default().get_value_from_char(c),
+            freeform_class: FreeformClass::default().get_value_from_char(c),
+            context_rule: context::get_context_rule_name(c as u32),
+            bidi_class: properties::bidi_class(c),
+            width_mapping: properties::width_mapping(c),
+        }
+    }
+
+    /// Returns the properties as a `JSON` object
+    pub fn to_json(&self) -> Value {
+        json!({
+            "codepoint": codepoint_str(self.c),
+            "character": self.c.to_string(),
+            "identifier_class": format!("{:?}", self.identifier_class),
+            "freeform_class": format!("{:?}", self.freeform_class),
+            "context_rule": self.context_rule,
+            "bidi_class": format!("{:?}", self.bidi_class),
+            "width_mapping": self.width_mapping.map(codepoint_str),
+        })
+    }
+}
+
+impl fmt::Display for Inspection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Control characters are not displayed
+        let glyph = if self.c.is_control() {
+            String::new()
+        } else {
+            format!(" '{}'", self.c)
+        };
+        writeln!(f, "{}{}", codepoint_str(self.c), glyph)?;
+        writeln!(f, "  IdentifierClass: {:?}", self.identifier_class)?;
+        writeln!(f, "  FreeformClass:   {:?}", self.freeform_class)?;
+        writeln!(
+            f,
+            "  Context rule:    {}",
+            self.context_rule.unwrap_or("none")
+        )?;
+        writeln!(f, "  Bidi class:      {:?}", self.bidi_class)?;
+        write!(
+            f,
+            "  Width mapping:   {}",
+            self.width_mapping
+                .map_or(String::from("none"), codepoint_str)
+        )
+    }
+}
+
+fn parse_hex(s: &str) -> Option<char> {
+    let hex = s
+        .strip_prefix("U+")
+        .or_else(|| s.strip_prefix("u+"))
+        .or_else(|| s.strip_prefix("0x"))
+        .or_else(|| s.strip_prefix("0X"))?;
+    u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+}
+
+/// Parses the code points to inspect. Code points can be written as
+/// `U+00B7` or `0xB7`, any other argument is taken as a string whose
+/// code points are all inspected.
+/// # Returns
+/// An error message if the argument looks like a code point, but it is
+/// not a valid Unicode scalar value.
+pub fn parse_codepoints(s: &str) -> Result<Vec<char>, String> {
+    let is_hex = ["U+", "u+", "0x", "0X"]
+        .iter()
+        .any(|prefix| s.len() > prefix.len() && s.starts_with(prefix));
+    if is_hex {
+        return parse_hex(s)
+            .map(|c| vec![c])
+            .ok_or_else(|| format!("invalid code point: {}", s));
+    }
+    Ok(s.chars().collect())
+}
+
+#[cfg(test)]
+mod test_inspect {
+    use crate::inspect::*;
+
+    #[test]
+    fn codepoints() {
+        assert_eq!(parse_codepoints("U+00B7"), Ok(vec!['\u{00b7}']));
+        assert_eq!(parse_codepoints("u+b7"), Ok(vec!['\u{00b7}']));
+        assert_eq!(parse_codepoints("0x1F469"), Ok(vec!['\u{1f469}']));
+        assert_eq!(parse_codepoints("ab"), Ok(vec!['a', 'b']));
+        assert_eq!(parse_codepoints("U+"), Ok(vec!['U', '+']));
+        assert_eq!(parse_codepoints(""), Ok(vec![]));
+        assert!(parse_codepoints("U+D800").is_err());
+        assert!(parse_codepoints("U+110000").is_err());
+        assert!(parse_codepoints("U+XYZ").is_err());
+    }
+
+    #[test]
+    fn inspection() {
+        let info = Inspection::new('\u{00b7}');
+        assert_eq!(info.identifier_class, DerivedPropertyValue::ContextO);
+        assert_eq!(info.context_rule, Some("MIDDLE DOT"));
+        assert_eq!(info.bidi_class, BidiClass::ON);
+        assert_eq!(info.width_mapping, None);
+
+        let json = Inspection::new('\u{ff21}').to_json();
+        assert_eq!(json["codepoint"], "U+FF21");
+        assert_eq!(json["identifier_class"], "SpecClassDis");
+        assert_eq!(json["width_mapping"], "U+0041");
+        assert_eq!(json["context_rule"], Value::Null);
+    }
+}
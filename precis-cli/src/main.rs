@@ -0,0 +1,375 @@
+//! Command line tool to prepare, enforce and compare strings with the
+//! PRECIS profiles, and to inspect how the PRECIS string classes deal
+//! with code points.
+//!
+//! ```text
+//! $ precis enforce --profile nickname "  Guybrush   Threepwood "
+//! Guybrush Threepwood
+//! $ precis compare --profile username-case-mapped Juliet juliet
+//! true
+//! $ printf 'Juliet\njuliet\n\n' | precis collisions -p username-case-mapped
+//! collision "juliet": "Juliet" (line 1), "juliet" (line 2)
+//! rejected "" (line 3): invalid label
+//! $ precis --json inspect U+00B7
+//! {"bidi_class":"ON","character":"·","codepoint":"U+00B7","context_rule":"MIDDLE DOT",...}
+//! ```
+//!
+//! Strings are read from the command line, or line by line from the
+//! standard input when none are provided.
+
+mod inspect;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use inspect::Inspection;
+use precis_core::profile::Profile;
+use precis_core::Error;
+use precis_profiles::collisions::{CollisionDetector, EquivalenceGroup, Reject};
+use precis_profiles::{Nickname, OpaqueString, UsernameCaseMapped, UsernameCasePreserved};
+use serde_json::json;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(name = "precis", version, about)]
+struct Cli {
+    /// Print results as JSON, one object per line
+    #[arg(long, global = true)]
+    json: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Prepares strings with a profile
+    Prepare {
+        #[arg(short, long, value_enum, default_value_t = ProfileName::Nickname)]
+        profile: ProfileName,
+        /// Strings to prepare, read from stdin if none is provided
+        strings: Vec<String>,
+    },
+    /// Enforces strings with a profile
+    Enforce {
+        #[arg(short, long, value_enum, default_value_t = ProfileName::Nickname)]
+        profile: ProfileName,
+        /// Strings to enforce, read from stdin if none is provided
+        strings: Vec<String>,
+    },
+    /// Compares two strings with a profile. Pairs of strings separated
+    /// by a tab are read from stdin if none is provided
+    Compare {
+        #[arg(short, long, value_enum, default_value_t = ProfileName::Nickname)]
+        profile: ProfileName,
+        #[arg(requires = "b")]
+        a: Option<String>,
+        b: Option<String>,
+    },
+    /// Shows the derived property values, context rule, bidi class and
+    /// width mapping of code points, written as `U+00B7`, `0xB7` or as
+    /// plain text. Read from stdin if none is provided
+    Inspect { codepoints: Vec<String> },
+    /// Finds the strings that become equal, or are rejected, when they are
+    /// enforced with a profile. Strings are read line by line from the
+    /// files, or from stdin if none is provided
+    Collisions {
+        #[arg(short, long, value_enum, default_value_t = ProfileName::Nickname)]
+        profile: ProfileName,
+        /// Memory used to keep the strings before they are written to disk, in bytes
+        #[arg(long)]
+        memory_limit: Option<usize>,
+        /// Directory where the temporary files are created
+        #[arg(long)]
+        spill_dir: Option<PathBuf>,
+        /// Number of strings shown for each collision
+        #[arg(long)]
+        max_members: Option<usize>,
+        files: Vec<PathBuf>,
+    },
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+enum ProfileName {
+    UsernameCaseMapped,
+    UsernameCasePreserved,
+    OpaqueString,
+    Nickname,
+}
+
+#[derive(Copy, Clone)]
+enum Operation {
+    Prepare,
+    Enforce,
+}
+
+fn apply<P: Profile>(profile: &P, op: Operation, s: &str) -> Result<String, Error> {
+    let res = match op {
+        Operation::Prepare => profile.prepare(s),
+        Operation::Enforce => profile.enforce(s),
+    };
+    res.map(|s| s.into_owned())
+}
+
+// Profiles can not be used as trait objects, so calls are dispatched
+// to each profile type
+macro_rules! with_profile {
+    ($name:expr, |$p:ident| $body:expr) => {
+        match $name {
+            ProfileName::UsernameCaseMapped => {
+                let $p = UsernameCaseMapped::new();
+                $body
+            }
+            ProfileName::UsernameCasePreserved => {
+                let $p = UsernameCasePreserved::new();
+                $body
+            }
+            ProfileName::OpaqueString => {
+                let $p = OpaqueString::new();
+                $body
+            }
+            ProfileName::Nickname => {
+                let $p = Nickname::new();
+                $body
+            }
+        }
+    };
+}
+
+// Returns the strings provided as arguments, or the lines read from stdin.
+// Lines are yielded as they are read, so each one can be processed before
+// the next one arrives.
+fn inputs(args: Vec<String>) -> Box<dyn Iterator<Item = io::Result<String>>> {
+    if !args.is_empty() {
+        return Box::new(args.into_iter().map(Ok));
+    }
+    Box::new(io::stdin().lines())
+}
+
+// Splits a line read from stdin into the pair of strings to compare
+fn pair(line: String) -> (String, String) {
+    match line.split_once('\t') {
+        Some((a, b)) => (String::from(a), String::from(b)),
+        None => (line, String::new()),
+    }
+}
+
+// Reads the lines of the files, or stdin if there are none. Reading stops
+// at the first error, which is stored in `error`.
+fn lines<'a>(
+    files: &[PathBuf],
+    error: &'a mut Option<io::Error>,
+) -> io::Result<impl Iterator<Item = String> + 'a> {
+    let mut readers: Vec<Box<dyn BufRead>> = Vec::new();
+    for path in files {
+        readers.push(Box::new(BufReader::new(File::open(path)?)));
+    }
+    if readers.is_empty() {
+        readers.push(Box::new(io::stdin().lock()));
+    }
+    Ok(readers
+        .into_iter()
+        .flat_map(|r| r.lines())
+        .map_while(move |line| line.map_err(|e| *error = Some(e)).ok()))
+}
+
+fn collisions<P: Profile>(
+    detector: CollisionDetector<P>,
+    files: &[PathBuf],
+    output: &mut Output,
+) -> io::Result<()> {
+    let mut error = None;
+    let report = detector.detect(lines(files, &mut error)?)?;
+    if let Some(e) = error {
+        return Err(e);
+    }
+    for group in report.groups()? {
+        output.collision(&group?)?;
+    }
+    for reject in report.rejects()? {
+        output.rejected(&reject?)?;
+    }
+    Ok(())
+}
+
+struct Output {
+    json: bool,
+    failed: bool,
+}
+
+impl Output {
+    fn transformed(&mut self, input: &str, res: Result<String, Error>) -> io::Result<()> {
+        let mut out = io::stdout().lock();
+        match (self.json, res) {
+            (true, Ok(s)) => writeln!(out, "{}", json!({"input": input, "output": s})),
+            (true, Err(e)) => {
+                self.failed = true;
+                writeln!(out, "{}", json!({"input": input, "error": e.to_string()}))
+            }
+            (false, Ok(s)) => writeln!(out, "{}", s),
+            (false, Err(e)) => {
+                self.failed = true;
+                writeln!(out, "error: {}", e)
+            }
+        }
+    }
+
+    fn compared(&mut self, a: &str, b: &str, res: Result<bool, Error>) -> io::Result<()> {
+        let mut out = io::stdout().lock();
+        match (self.json, res) {
+            (true, Ok(equal)) => writeln!(out, "{}", json!({"a": a, "b": b, "equal": equal})),
+            (true, Err(e)) => {
+                self.failed = true;
+                writeln!(out, "{}", json!({"a": a, "b": b, "error": e.to_string()}))
+            }
+            (false, Ok(equal)) => writeln!(out, "{}", equal),
+            (false, Err(e)) => {
+                self.failed = true;
+                writeln!(out, "error: {}", e)
+            }
+        }
+    }
+
+    // Line numbers start at one
+    fn collision(&mut self, group: &EquivalenceGroup) -> io::Result<()> {
+        self.failed = true;
+        let mut out = io::stdout().lock();
+        if self.json {
+            let members: Vec<_> = group
+                .members
+                .iter()
+                .map(|m| json!({"line": m.index + 1, "input": m.input}))
+                .collect();
+            return writeln!(
+                out,
+                "{}",
+                json!({"key": group.key, "members": members, "size": group.size})
+            );
+        }
+        let mut members: Vec<_> = group
+            .members
+            .iter()
+            .map(|m| format!("{:?} (line {})", m.input, m.index + 1))
+            .collect();
+        let more = group.size - group.members.len() as u64;
+        if more > 0 {
+            members.push(format!("{} more", more));
+        }
+        writeln!(out, "collision {:?}: {}", group.key, members.join(", "))
+    }
+
+    fn rejected(&mut self, reject: &Reject) -> io::Result<()> {
+        self.failed = true;
+        let mut out = io::stdout().lock();
+        let line = reject.index + 1;
+        match self.json {
+            true => writeln!(
+                out,
+                "{}",
+                json!({"line": line, "input": reject.input, "error": reject.error.to_string()})
+            ),
+            false => writeln!(
+                out,
+                "rejected {:?} (line {}): {}",
+                reject.input, line, reject.error
+            ),
+        }
+    }
+
+    fn inspected(&mut self, input: &str, res: Result<Vec<char>, String>) -> io::Result<()> {
+        let mut out = io::stdout().lock();
+        let cps = match res {
+            Ok(cps) => cps,
+            Err(e) => {
+                self.failed = true;
+                return match self.json {
+                    true => writeln!(out, "{}", json!({"input": input, "error": e})),
+                    false => writeln!(out, "error: {}", e),
+                };
+            }
+        };
+
+        for c in cps {
+            let info = Inspection::new(c);
+            match self.json {
+                true => writeln!(out, "{}", info.to_json())?,
+                false => writeln!(out, "{}", info)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn run(cli: Cli) -> io::Result<bool> {
+    let mut output = Output {
+        json: cli.json,
+        failed: false,
+    };
+
+    match cli.command {
+        Command::Prepare { profile, strings } => {
+            for s in inputs(strings) {
+                let s = s?;
+                let res = with_profile!(profile, |p| apply(&p, Operation::Prepare, &s));
+                output.transformed(&s, res)?;
+            }
+        }
+        Command::Enforce { profile, strings } => {
+            for s in inputs(strings) {
+                let s = s?;
+                let res = with_profile!(profile, |p| apply(&p, Operation::Enforce, &s));
+                output.transformed(&s, res)?;
+            }
+        }
+        Command::Compare { profile, a, b } => {
+            let pairs: Box<dyn Iterator<Item = io::Result<(String, String)>>> = match (a, b) {
+                (Some(a), Some(b)) => Box::new(std::iter::once(Ok((a, b)))),
+                _ => Box::new(inputs(Vec::new()).map(|line| line.map(pair))),
+            };
+            for p in pairs {
+                let (a, b) = p?;
+                let res = with_profile!(profile, |p| p.compare(&a, &b));
+                output.compared(&a, &b, res)?;
+            }
+        }
+        Command::Inspect { codepoints } => {
+            for s in inputs(codepoints) {
+                let s = s?;
+                output.inspected(&s, inspect::parse_codepoints(&s))?;
+            }
+        }
+        Command::Collisions {
+            profile,
+            memory_limit,
+            spill_dir,
+            max_members,
+            files,
+        } => with_profile!(profile, |p| {
+            let mut detector = CollisionDetector::new(p);
+            if let Some(bytes) = memory_limit {
+                detector = detector.with_memory_limit(bytes);
+            }
+            if let Some(dir) = spill_dir {
+                detector = detector.with_spill_dir(dir);
+            }
+            if let Some(members) = max_members {
+                detector = detector.with_max_members(members);
+            }
+            collisions(detector, &files, &mut output)?
+        }),
+    }
+
+    Ok(!output.failed)
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("precis: {}", e);
+            ExitCode::from(2)
+        }
+    }
+}
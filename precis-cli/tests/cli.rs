@@ -0,0 +1,203 @@
+#[cfg(test)]
+mod cli {
+    use std::io::{BufRead, BufReader, Write};
+    use std::process::{Command, Output, Stdio};
+
+    fn precis(args: &[&str], stdin: Option<&str>) -> Output {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_precis"))
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        {
+            let mut input = child.stdin.take().unwrap();
+            if let Some(s) = stdin {
+                input.write_all(s.as_bytes()).unwrap();
+            }
+        }
+        child.wait_with_output().unwrap()
+    }
+
+    fn stdout(output: &Output) -> String {
+        String::from_utf8(output.stdout.clone()).unwrap()
+    }
+
+    #[test]
+    fn enforce() {
+        let out = precis(
+            &["enforce", "-p", "nickname", "  Guybrush   Threepwood "],
+            None,
+        );
+        assert!(out.status.success());
+        assert_eq!(stdout(&out), "Guybrush Threepwood\n");
+
+        let out = precis(
+            &["enforce", "--profile", "username-case-mapped", "Juliet", ""],
+            None,
+        );
+        assert_eq!(out.status.code(), Some(1));
+        assert_eq!(stdout(&out), "juliet\nerror: invalid label\n");
+    }
+
+    #[test]
+    fn prepare() {
+        let out = precis(&["prepare", "-p", "opaque-string", "Pass Word"], None);
+        assert!(out.status.success());
+        assert_eq!(stdout(&out), "Pass Word\n");
+    }
+
+    #[test]
+    fn compare() {
+        let out = precis(
+            &["compare", "-p", "username-case-mapped", "Juliet", "juliet"],
+            None,
+        );
+        assert!(out.status.success());
+        assert_eq!(stdout(&out), "true\n");
+
+        let out = precis(
+            &[
+                "compare",
+                "-p",
+                "username-case-preserved",
+                "Juliet",
+                "juliet",
+            ],
+            None,
+        );
+        assert_eq!(stdout(&out), "false\n");
+
+        let out = precis(&["compare"], Some("Foo\tfoo\nFoo\tbar\n"));
+        assert_eq!(stdout(&out), "true\nfalse\n");
+
+        // A single string is not enough to compare
+        let out = precis(&["compare", "Foo"], None);
+        assert_eq!(out.status.code(), Some(2));
+    }
+
+    #[test]
+    fn stdin() {
+        let out = precis(&["enforce"], Some("  Foo \nBar\n"));
+        assert!(out.status.success());
+        assert_eq!(stdout(&out), "Foo\nBar\n");
+    }
+
+    #[test]
+    fn stdin_is_streamed() {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_precis"))
+            .args(["enforce", "-p", "username-case-mapped"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let mut input = child.stdin.take().unwrap();
+        let mut output = BufReader::new(child.stdout.take().unwrap());
+
+        // Each line is enforced as soon as it is read, before stdin is closed
+        let mut line = String::new();
+        for (s, expected) in [("Juliet\n", "juliet\n"), ("Romeo\n", "romeo\n")] {
+            input.write_all(s.as_bytes()).unwrap();
+            input.flush().unwrap();
+            line.clear();
+            output.read_line(&mut line).unwrap();
+            assert_eq!(line, expected);
+        }
+
+        drop(input);
+        assert!(child.wait().unwrap().success());
+    }
+
+    #[test]
+    fn json() {
+        let out = precis(
+            &["--json", "enforce", "-p", "username-case-mapped"],
+            Some("Juliet\n\n"),
+        );
+        assert_eq!(out.status.code(), Some(1));
+        let lines: Vec<serde_json::Value> = stdout(&out)
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["input"], "Juliet");
+        assert_eq!(lines[0]["output"], "juliet");
+        assert_eq!(lines[1]["error"], "invalid label");
+
+        let out = precis(&["compare", "--json", "a", "A"], None);
+        let res: serde_json::Value = serde_json::from_str(&stdout(&out)).unwrap();
+        assert_eq!(res["equal"], true);
+    }
+
+    #[test]
+    fn inspect() {
+        let out = precis(&["inspect", "U+00B7"], None);
+        assert!(out.status.success());
+        let text = stdout(&out);
+        assert!(text.starts_with("U+00B7 '\u{00b7}'\n"));
+        assert!(text.contains("IdentifierClass: ContextO"));
+        assert!(text.contains("Context rule:    MIDDLE DOT"));
+        assert!(text.contains("Bidi class:      ON"));
+
+        let out = precis(&["--json", "inspect", "\u{ff21}b"], None);
+        let lines: Vec<serde_json::Value> = stdout(&out)
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["codepoint"], "U+FF21");
+        assert_eq!(lines[0]["width_mapping"], "U+0041");
+        assert_eq!(lines[1]["identifier_class"], "PValid");
+
+        let out = precis(&["inspect", "U+D800"], None);
+        assert_eq!(out.status.code(), Some(1));
+        assert_eq!(stdout(&out), "error: invalid code point: U+D800\n");
+    }
+
+    #[test]
+    fn collisions() {
+        let input = "Juliet\nromeo\njuliet\n\n";
+        let out = precis(&["collisions", "-p", "username-case-mapped"], Some(input));
+        assert_eq!(out.status.code(), Some(1));
+        assert_eq!(
+            stdout(&out),
+            "collision \"juliet\": \"Juliet\" (line 1), \"juliet\" (line 3)\n\
+             rejected \"\" (line 4): invalid label\n"
+        );
+
+        let out = precis(
+            &["--json", "collisions", "--memory-limit", "0"],
+            Some("Guybrush  Threepwood\nGuybrush Threepwood\n"),
+        );
+        let res: serde_json::Value = serde_json::from_str(&stdout(&out)).unwrap();
+        assert_eq!(res["key"], "Guybrush Threepwood");
+        assert_eq!(res["members"][1]["line"], 2);
+        assert_eq!(res["size"], 2);
+
+        let out = precis(
+            &[
+                "collisions",
+                "-p",
+                "username-case-mapped",
+                "--max-members",
+                "2",
+            ],
+            Some(
+                "Romeo
+ROMEO
+romeo
+Romeo
+",
+            ),
+        );
+        assert_eq!(
+            stdout(&out),
+            "collision \"romeo\": \"Romeo\" (line 1), \"ROMEO\" (line 2), 2 more\n"
+        );
+
+        let out = precis(&["collisions"], Some("romeo\njuliet\n"));
+        assert!(out.status.success());
+        assert_eq!(stdout(&out), "");
+    }
+}
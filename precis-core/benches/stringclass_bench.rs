@@ -102,11 +102,39 @@ fn bench_allows_length(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_table_lookups(c: &mut Criterion) {
+    let id_class = IdentifierClass::default();
+
+    let blocks = vec![
+        (0x0000..=0x007F, "Basic Latin"),
+        (0x0370..=0x03FF, "Greek"),
+        (0x4E00..=0x4FFF, "CJK ideographs"),
+        (0xAC00..=0xADFF, "Hangul syllables"),
+        (0x1F300..=0x1F3FF, "Pictographs"),
+        (0xE0000..=0xE00FF, "Unassigned"),
+    ];
+
+    let mut group = c.benchmark_group("table_lookups");
+
+    for (block, name) in blocks {
+        group.bench_with_input(BenchmarkId::from_parameter(name), &block, |b, block| {
+            b.iter(|| {
+                for cp in block.clone() {
+                    black_box(id_class.get_value_from_codepoint(black_box(cp)));
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_get_value_from_char,
     bench_get_value_from_codepoint,
     bench_allows,
-    bench_allows_length
+    bench_allows_length,
+    bench_table_lookups
 );
 criterion_main!(benches);
@@ -1,194 +1,180 @@
-include!(concat!(env!("OUT_DIR"), "/precis_tables.rs"));
-include!(concat!(env!("OUT_DIR"), "/context_tables.rs"));
+include_table!("precis_tables.rs");
+include_table!("context_tables.rs");
+#[cfg(feature = "iana-tables")]
+include_table!("iana_tables.rs");
 
+use crate::CodepointSet;
 use crate::Codepoints;
 use crate::DerivedPropertyValue;
-use std::char;
-use unicode_normalization::UnicodeNormalization;
+use std::cmp::Ordering;
 
 /// Helper function to perform binary search on a table of (`Codepoints`, T) tuples
-/// and return the associated value if found.
+/// and return the associated value if found. It can be used in const context.
 #[inline]
-fn binary_search_codepoints_table<T>(table: &[(Codepoints, T)], cp: u32) -> Option<&T> {
-    table
-        .binary_search_by(|(cps, _)| {
-            // Use PartialOrd<u32> which correctly handles range containment
-            // This always returns Some(_) for valid codepoints
-            cps.partial_cmp(&cp)
-                .expect("Codepoints::partial_cmp should always return Some")
-        })
-        .ok()
-        .map(|idx| &table[idx].1)
+const fn binary_search_codepoints_table<T>(table: &[(Codepoints, T)], cp: u32) -> Option<&T> {
+    let mut low = 0;
+    let mut high = table.len();
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match table[mid].0.cmp_codepoint(cp) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Some(&table[mid].1),
+        }
+    }
+    None
 }
 
 #[inline]
-pub(crate) fn get_exception_val(cp: u32) -> Option<&'static DerivedPropertyValue> {
-    binary_search_codepoints_table(&EXCEPTIONS, cp)
+pub(crate) const fn get_exception_val(cp: u32) -> Option<&'static DerivedPropertyValue> {
+    binary_search_codepoints_table(EXCEPTIONS, cp)
 }
 
 #[inline]
-pub(crate) fn get_backward_compatible_val(cp: u32) -> Option<&'static DerivedPropertyValue> {
-    binary_search_codepoints_table(&BACKWARD_COMPATIBLE, cp)
+pub(crate) const fn get_backward_compatible_val(cp: u32) -> Option<&'static DerivedPropertyValue> {
+    binary_search_codepoints_table(BACKWARD_COMPATIBLE, cp)
 }
 
+/// Returns the `IdentifierClass` and `FreeformClass` values of the code
+/// point published in the `IANA` registry.
+#[cfg(feature = "iana-tables")]
 #[inline]
-fn is_in_table(cp: u32, table: &[Codepoints]) -> bool {
-    table
-        .binary_search_by(|cps| {
-            // Use PartialOrd<u32> which correctly handles range containment
-            // This always returns Some(_) for valid codepoints
-            cps.partial_cmp(&cp)
-                .expect("Codepoints::partial_cmp should always return Some")
-        })
-        .is_ok()
+pub(crate) const fn get_registry_vals(
+    cp: u32,
+) -> Option<&'static (DerivedPropertyValue, DerivedPropertyValue)> {
+    binary_search_codepoints_table(DERIVED_PROPERTIES, cp)
 }
 
 #[inline]
-pub(crate) fn is_letter_digit(cp: u32) -> bool {
-    is_in_table(cp, &LOWERCASE_LETTER)
-        || is_in_table(cp, &UPPERCASE_LETTER)
-        || is_in_table(cp, &OTHER_LETTER)
-        || is_in_table(cp, &DECIMAL_NUMBER)
-        || is_in_table(cp, &MODIFIER_LETTER)
-        || is_in_table(cp, &NONSPACING_MARK)
-        || is_in_table(cp, &SPACING_MARK)
+pub(crate) const fn is_letter_digit(cp: u32) -> bool {
+    LOWERCASE_LETTER.contains(cp)
+        || UPPERCASE_LETTER.contains(cp)
+        || OTHER_LETTER.contains(cp)
+        || DECIMAL_NUMBER.contains(cp)
+        || MODIFIER_LETTER.contains(cp)
+        || NONSPACING_MARK.contains(cp)
+        || SPACING_MARK.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_join_control(cp: u32) -> bool {
-    is_in_table(cp, &JOIN_CONTROL)
+pub(crate) const fn is_join_control(cp: u32) -> bool {
+    JOIN_CONTROL.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_old_hangul_jamo(cp: u32) -> bool {
-    is_in_table(cp, &LEADING_JAMO)
-        || is_in_table(cp, &VOWEL_JAMO)
-        || is_in_table(cp, &TRAILING_JAMO)
+pub(crate) const fn is_old_hangul_jamo(cp: u32) -> bool {
+    LEADING_JAMO.contains(cp) || VOWEL_JAMO.contains(cp) || TRAILING_JAMO.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_unassigned(cp: u32) -> bool {
-    !is_in_table(cp, &NONCHARACTER_CODE_POINT) && is_in_table(cp, &UNASSIGNED)
+pub(crate) const fn is_unassigned(cp: u32) -> bool {
+    !NONCHARACTER_CODE_POINT.contains(cp) && UNASSIGNED.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_ascii7(cp: u32) -> bool {
-    is_in_table(cp, &ASCII7)
+pub(crate) const fn is_ascii7(cp: u32) -> bool {
+    ASCII7.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_control(cp: u32) -> bool {
-    is_in_table(cp, &CONTROL)
+pub(crate) const fn is_control(cp: u32) -> bool {
+    CONTROL.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_precis_ignorable_property(cp: u32) -> bool {
-    is_in_table(cp, &DEFAULT_IGNORABLE_CODE_POINT) || is_in_table(cp, &NONCHARACTER_CODE_POINT)
+pub(crate) const fn is_precis_ignorable_property(cp: u32) -> bool {
+    DEFAULT_IGNORABLE_CODE_POINT.contains(cp) || NONCHARACTER_CODE_POINT.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_space(cp: u32) -> bool {
-    is_in_table(cp, &SPACE_SEPARATOR)
+pub(crate) const fn is_space(cp: u32) -> bool {
+    SPACE_SEPARATOR.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_symbol(cp: u32) -> bool {
-    is_in_table(cp, &MATH_SYMBOL)
-        || is_in_table(cp, &CURRENCY_SYMBOL)
-        || is_in_table(cp, &MODIFIER_SYMBOL)
-        || is_in_table(cp, &OTHER_SYMBOL)
+pub(crate) const fn is_symbol(cp: u32) -> bool {
+    MATH_SYMBOL.contains(cp)
+        || CURRENCY_SYMBOL.contains(cp)
+        || MODIFIER_SYMBOL.contains(cp)
+        || OTHER_SYMBOL.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_punctuation(cp: u32) -> bool {
-    is_in_table(cp, &CONNECTOR_PUNCTUATION)
-        || is_in_table(cp, &DASH_PUNCTUATION)
-        || is_in_table(cp, &OPEN_PUNCTUATION)
-        || is_in_table(cp, &CLOSE_PUNCTUATION)
-        || is_in_table(cp, &INITIAL_PUNCTUATION)
-        || is_in_table(cp, &FINAL_PUNCTUATION)
-        || is_in_table(cp, &OTHER_PUNCTUATION)
+pub(crate) const fn is_punctuation(cp: u32) -> bool {
+    CONNECTOR_PUNCTUATION.contains(cp)
+        || DASH_PUNCTUATION.contains(cp)
+        || OPEN_PUNCTUATION.contains(cp)
+        || CLOSE_PUNCTUATION.contains(cp)
+        || INITIAL_PUNCTUATION.contains(cp)
+        || FINAL_PUNCTUATION.contains(cp)
+        || OTHER_PUNCTUATION.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_other_letter_digit(cp: u32) -> bool {
-    is_in_table(cp, &TITLECASE_LETTER)
-        || is_in_table(cp, &LETTER_NUMBER)
-        || is_in_table(cp, &OTHER_NUMBER)
-        || is_in_table(cp, &ENCLOSING_MARK)
+pub(crate) const fn is_other_letter_digit(cp: u32) -> bool {
+    TITLECASE_LETTER.contains(cp)
+        || LETTER_NUMBER.contains(cp)
+        || OTHER_NUMBER.contains(cp)
+        || ENCLOSING_MARK.contains(cp)
 }
 
-pub(crate) fn has_compat(cp: u32) -> bool {
-    let c: char = match char::from_u32(cp) {
-        Some(c) => c,
-        None => return false,
-    };
-
-    // This PRECIS-specific category is used to group any code point that is
-    // decomposed and recomposed into something other than itself under
-    // Unicode Normalization Form KC.
-    // Typically, this category is true of code points that are
-    // "compatibility `decomposable` characters" as defined in the Unicode
-    // Standard.
-
-    // Optimize: Check if NFKC normalization changes the character
-    // without allocating strings. If the normalized form produces
-    // exactly one character equal to the original, it has no compat mapping.
-    // We check by consuming the first two elements of the iterator:
-    // - If first char equals original and second is None => no compat
-    // - Otherwise => has compat
-    let mut normalized = c.nfkc();
-    !matches!((normalized.next(), normalized.next()), (Some(nc), None) if nc == c)
+// This PRECIS-specific category is used to group any code point that is
+// decomposed and recomposed into something other than itself under
+// Unicode Normalization Form KC. The table is generated ahead of time so
+// that no normalization is needed to look it up.
+#[inline]
+pub(crate) const fn has_compat(cp: u32) -> bool {
+    HAS_COMPAT.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_virama(cp: u32) -> bool {
-    is_in_table(cp, &VIRAMA)
+pub(crate) const fn is_virama(cp: u32) -> bool {
+    VIRAMA.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_greek(cp: u32) -> bool {
-    is_in_table(cp, &GREEK)
+pub(crate) const fn is_greek(cp: u32) -> bool {
+    GREEK.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_hebrew(cp: u32) -> bool {
-    is_in_table(cp, &HEBREW)
+pub(crate) const fn is_hebrew(cp: u32) -> bool {
+    HEBREW.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_hiragana(cp: u32) -> bool {
-    is_in_table(cp, &HIRAGANA)
+pub(crate) const fn is_hiragana(cp: u32) -> bool {
+    HIRAGANA.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_katakana(cp: u32) -> bool {
-    is_in_table(cp, &KATAKANA)
+pub(crate) const fn is_katakana(cp: u32) -> bool {
+    KATAKANA.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_han(cp: u32) -> bool {
-    is_in_table(cp, &HAN)
+pub(crate) const fn is_han(cp: u32) -> bool {
+    HAN.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_dual_joining(cp: u32) -> bool {
-    is_in_table(cp, &DUAL_JOINING)
+pub(crate) const fn is_dual_joining(cp: u32) -> bool {
+    DUAL_JOINING.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_left_joining(cp: u32) -> bool {
-    is_in_table(cp, &LEFT_JOINING)
+pub(crate) const fn is_left_joining(cp: u32) -> bool {
+    LEFT_JOINING.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_right_joining(cp: u32) -> bool {
-    is_in_table(cp, &RIGHT_JOINING)
+pub(crate) const fn is_right_joining(cp: u32) -> bool {
+    RIGHT_JOINING.contains(cp)
 }
 
 #[inline]
-pub(crate) fn is_transparent(cp: u32) -> bool {
-    is_in_table(cp, &TRANSPARENT)
+pub(crate) const fn is_transparent(cp: u32) -> bool {
+    TRANSPARENT.contains(cp)
 }
 
 #[cfg(test)]
@@ -197,66 +183,90 @@ mod tests {
 
     #[test]
     fn find_codepoint() {
-        let empty_table: [Codepoints; 0] = [];
-        assert!(!is_in_table(34, &empty_table));
-
-        let test_table: [Codepoints; 1] = [Codepoints::Single(11)];
-        assert!(is_in_table(11, &test_table));
-        assert!(!is_in_table(12, &test_table));
-
-        let test_table: [Codepoints; 2] = [Codepoints::Single(1), Codepoints::Single(10)];
-        assert!(is_in_table(1, &test_table));
-        assert!(is_in_table(10, &test_table));
-        assert!(!is_in_table(5, &test_table));
-
-        let test_table: [Codepoints; 3] = [
-            Codepoints::Single(1),
-            Codepoints::Single(5),
-            Codepoints::Single(10),
+        let empty_sets = [
+            CodepointSet::Ranges(&[]),
+            CodepointSet::Bitset {
+                start: 0,
+                bits: &[],
+            },
+            CodepointSet::Trie {
+                index: &[],
+                leaves: &[0],
+            },
         ];
-        assert!(is_in_table(1, &test_table));
-        assert!(is_in_table(5, &test_table));
-        assert!(is_in_table(10, &test_table));
-        assert!(!is_in_table(7, &test_table));
-
-        let test_table: [Codepoints; 1] =
-            [Codepoints::Range(std::ops::RangeInclusive::new(10, 20))];
-        for i in 10..=20 {
-            assert!(is_in_table(i, &test_table));
+        for set in empty_sets.iter() {
+            assert!(!set.contains(34));
         }
-        assert!(!is_in_table(9, &test_table));
-        assert!(!is_in_table(21, &test_table));
-
-        let test_table: [Codepoints; 4] = [
-            Codepoints::Range(std::ops::RangeInclusive::new(2, 4)),
-            Codepoints::Single(7),
-            Codepoints::Range(std::ops::RangeInclusive::new(10, 20)),
-            Codepoints::Single(25),
+
+        let single_sets = [
+            CodepointSet::Ranges(&[(11, 11)]),
+            CodepointSet::Bitset {
+                start: 11,
+                bits: &[0x1],
+            },
+            CodepointSet::Trie {
+                index: &[1],
+                leaves: &[0, 0x800],
+            },
         ];
-        for i in 2..=4 {
-            assert!(is_in_table(i, &test_table));
-        }
-        for i in 10..=20 {
-            assert!(is_in_table(i, &test_table));
+        for set in single_sets.iter() {
+            assert!(set.contains(11));
+            assert!(!set.contains(10));
+            assert!(!set.contains(12));
+            assert!(!set.contains(0x10ffff));
         }
-        assert!(is_in_table(7, &test_table));
-        assert!(is_in_table(25, &test_table));
-        assert!(!is_in_table(1, &test_table));
-        assert!(!is_in_table(8, &test_table));
-        assert!(!is_in_table(26, &test_table));
-
-        let test_table: [Codepoints; 2] = [
-            Codepoints::Range(std::ops::RangeInclusive::new(2, 4)),
-            Codepoints::Range(std::ops::RangeInclusive::new(10, 20)),
+
+        // {2..=4, 7, 10..=20, 25}
+        let sets = [
+            CodepointSet::Ranges(&[(2, 4), (7, 7), (10, 20), (25, 25)]),
+            CodepointSet::Bitset {
+                start: 2,
+                bits: &[0x87ff27],
+            },
+            CodepointSet::Trie {
+                index: &[1],
+                leaves: &[0, 0x21ffc9c],
+            },
         ];
-        for i in 2..=4 {
-            assert!(is_in_table(i, &test_table));
+        for set in sets.iter() {
+            for i in 2..=4 {
+                assert!(set.contains(i));
+            }
+            for i in 10..=20 {
+                assert!(set.contains(i));
+            }
+            assert!(set.contains(7));
+            assert!(set.contains(25));
+            assert!(!set.contains(1));
+            assert!(!set.contains(8));
+            assert!(!set.contains(9));
+            assert!(!set.contains(21));
+            assert!(!set.contains(26));
+            assert!(!set.contains(0x10ffff));
         }
-        for i in 10..=20 {
-            assert!(is_in_table(i, &test_table));
+
+        // {0x41..=0x5a, 0x1100..=0x113f}, sharing the empty block
+        let sets = [
+            CodepointSet::Ranges(&[(0x41, 0x5a), (0x1100, 0x113f)]),
+            CodepointSet::Trie {
+                index: &[
+                    0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2,
+                ],
+                leaves: &[0, 0x7fffffe, u64::MAX],
+            },
+        ];
+        for set in sets.iter() {
+            assert!(set.contains(0x41));
+            assert!(set.contains(0x5a));
+            assert!(set.contains(0x1100));
+            assert!(set.contains(0x113f));
+            assert!(!set.contains(0x40));
+            assert!(!set.contains(0x5b));
+            assert!(!set.contains(0x10ff));
+            assert!(!set.contains(0x1140));
         }
-        assert!(!is_in_table(1, &test_table));
-        assert!(!is_in_table(21, &test_table));
     }
 
     #[test]
@@ -268,6 +278,21 @@ mod tests {
         assert!(!has_compat(cp));
     }
 
+    #[test]
+    fn compat_table_matches_nfkc() {
+        use unicode_normalization::UnicodeNormalization;
+
+        for c in (0..=0x10ffff).filter_map(char::from_u32) {
+            if is_unassigned(c as u32) {
+                continue;
+            }
+            let mut normalized = c.nfkc();
+            let compat =
+                !matches!((normalized.next(), normalized.next()), (Some(nc), None) if nc == c);
+            assert_eq!(has_compat(c as u32), compat, "{:#06x}", c as u32);
+        }
+    }
+
     // Character classification tests
     #[test]
     fn test_letter_digit_classification() {
@@ -306,6 +306,32 @@ pub(crate) fn get_context_rule(cp: u32) -> Option<ContextRule> {
     }
 }
 
+/// Gets the name of the context rule associated to an Unicode code point,
+/// as listed in [Appendix A](https://datatracker.ietf.org/doc/html/rfc5892#appendix-A)
+/// of `rfc5892`.
+/// # Example
+/// ```rust
+/// # use precis_core::context::get_context_rule_name;
+/// assert_eq!(get_context_rule_name(0x00b7), Some("MIDDLE DOT"));
+/// assert_eq!(get_context_rule_name(0x0061), None);
+/// ```
+pub fn get_context_rule_name(cp: u32) -> Option<&'static str> {
+    match cp {
+        MIDDLE_DOT => Some("MIDDLE DOT"),
+        ZERO_WIDTH_NON_JOINER => Some("ZERO WIDTH NON-JOINER"),
+        ZERO_WIDTH_JOINER => Some("ZERO WIDTH JOINER"),
+        GREEK_LOWER_NUMERAL_SIGN => Some("GREEK LOWER NUMERAL SIGN (KERAIA)"),
+        HEBREW_PUNCTUATION_GERESH => Some("HEBREW PUNCTUATION GERESH"),
+        HEBREW_PUNCTUATION_GERSHAYIM => Some("HEBREW PUNCTUATION GERSHAYIM"),
+        KATAKANA_MIDDLE_DOT => Some("KATAKANA MIDDLE DOT"),
+        ARABIC_INDIC_DIGIT_START..=ARABIC_INDIC_DIGIT_END => Some("ARABIC-INDIC DIGITS"),
+        EXTENDED_ARABIC_INDIC_DIGIT_START..=EXTENDED_ARABIC_INDIC_DIGIT_END => {
+            Some("EXTENDED ARABIC-INDIC DIGITS")
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::context::*;
@@ -877,4 +903,26 @@ mod tests {
         let val = get_context_rule(0x06fa);
         assert!(val.is_none());
     }
+
+    #[test]
+    fn context_rule_names() {
+        assert_eq!(
+            get_context_rule_name(ZERO_WIDTH_JOINER),
+            Some("ZERO WIDTH JOINER")
+        );
+        assert_eq!(
+            get_context_rule_name(HEBREW_PUNCTUATION_GERSHAYIM),
+            Some("HEBREW PUNCTUATION GERSHAYIM")
+        );
+        assert_eq!(get_context_rule_name(0x0665), Some("ARABIC-INDIC DIGITS"));
+        assert_eq!(get_context_rule_name(0x06ef), None);
+
+        // Every code point with a context rule has a name
+        for cp in 0..=0x10ffff {
+            assert_eq!(
+                get_context_rule(cp).is_some(),
+                get_context_rule_name(cp).is_some()
+            );
+        }
+    }
 }
@@ -4,13 +4,20 @@ use std::fmt;
 /// Represents any kind of error that may happen when
 /// preparing, enforcing or comparing internationalized
 /// strings
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Error {
     /// Invalid label
     Invalid,
     /// Detected a disallowed Unicode code pint in the label.
     /// [`CodepointInfo`] contains information about the code point.
     BadCodepoint(CodepointInfo),
+    /// The length of the label is out of the limits set by the application.
+    /// [`LengthInfo`] contains the measured length and the limit.
+    BadLength(LengthInfo),
+    /// The label mixes scripts beyond the restriction level set by the
+    /// application. [`CodepointInfo`] contains information about the first
+    /// code point that breaks the restriction level.
+    MixedScript(CodepointInfo),
     /// Error used to deal with any unexpected condition not directly
     /// covered by any other category.
     Unexpected(UnexpectedError),
@@ -21,6 +28,8 @@ impl fmt::Display for Error {
         match self {
             Error::Invalid => write!(f, "invalid label"),
             Error::BadCodepoint(info) => write!(f, "bad codepoint: {}", info),
+            Error::BadLength(info) => write!(f, "bad length: {}", info),
+            Error::MixedScript(info) => write!(f, "mixed script: {}", info),
             Error::Unexpected(unexpected) => write!(f, "unexpected: {}", unexpected),
         }
     }
@@ -29,7 +38,7 @@ impl fmt::Display for Error {
 impl std::error::Error for Error {}
 
 /// Error that contains information regarding the wrong Unicode code point
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CodepointInfo {
     /// Unicode code point
     pub cp: u32,
@@ -60,10 +69,78 @@ impl fmt::Display for CodepointInfo {
     }
 }
 
+/// Unit used to measure the length of a label
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LengthUnit {
+    /// Number of bytes of the `UTF-8` encoded label
+    Bytes,
+    /// Number of Unicode code points
+    Codepoints,
+    /// Number of extended grapheme clusters as defined in
+    /// [`UAX #29`](https://www.unicode.org/reports/tr29/)
+    Graphemes,
+}
+
+impl fmt::Display for LengthUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LengthUnit::Bytes => write!(f, "bytes"),
+            LengthUnit::Codepoints => write!(f, "code points"),
+            LengthUnit::Graphemes => write!(f, "grapheme clusters"),
+        }
+    }
+}
+
+/// Length limit that a label did not satisfy
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LengthLimit {
+    /// Minimum length allowed
+    Min(usize),
+    /// Maximum length allowed
+    Max(usize),
+}
+
+impl fmt::Display for LengthLimit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LengthLimit::Min(n) => write!(f, "min {}", n),
+            LengthLimit::Max(n) => write!(f, "max {}", n),
+        }
+    }
+}
+
+/// Error that contains information regarding the length of a label
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LengthInfo {
+    /// Unit in which the length is measured
+    pub unit: LengthUnit,
+    /// The measured length of the label
+    pub length: usize,
+    /// The limit the label did not satisfy
+    pub limit: LengthLimit,
+}
+
+impl LengthInfo {
+    /// Creates a new `LengthInfo` `struct`
+    pub fn new(unit: LengthUnit, length: usize, limit: LengthLimit) -> Self {
+        Self {
+            unit,
+            length,
+            limit,
+        }
+    }
+}
+
+impl fmt::Display for LengthInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}, limit: {}", self.length, self.unit, self.limit)
+    }
+}
+
 /// Internal errors that group unusual error conditions that mostly
 /// have to do with the processing of wrong labels, unexpected Unicode
 /// code points if tested against another version defined in PRECIS, etc.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum UnexpectedError {
     /// Error caused when trying to apply a context rule over
     /// an invalid code point.
@@ -110,6 +187,23 @@ mod tests {
             })
         );
         let _val = format!("{}", Error::Unexpected(UnexpectedError::Undefined));
+        let _val = format!(
+            "{}",
+            Error::BadLength(LengthInfo::new(LengthUnit::Bytes, 10, LengthLimit::Max(8)))
+        );
+        let _val = format!(
+            "{}",
+            Error::MixedScript(CodepointInfo::new(0x0430, 1, DerivedPropertyValue::PValid))
+        );
+    }
+
+    #[test]
+    fn fmt_length_info() {
+        let info = LengthInfo::new(LengthUnit::Codepoints, 2, LengthLimit::Min(3));
+        assert_eq!(format!("{}", info), "2 code points, limit: min 3");
+
+        let info = LengthInfo::new(LengthUnit::Graphemes, 5, LengthLimit::Max(4));
+        assert_eq!(format!("{}", info), "5 grapheme clusters, limit: max 4");
     }
 
     #[test]
@@ -0,0 +1,427 @@
+// File generated with precis-tools version 0.1.9
+
+// Encoded as ranges: ranges 264 bytes, bitset 8624 bytes, trie 2382 bytes
+const VIRAMA: CodepointSet = CodepointSet::Ranges(&[
+	(0x094d, 0x094d),
+	(0x09cd, 0x09cd),
+	(0x0a4d, 0x0a4d),
+	(0x0acd, 0x0acd),
+	(0x0b4d, 0x0b4d),
+	(0x0bcd, 0x0bcd),
+	(0x0c4d, 0x0c4d),
+	(0x0ccd, 0x0ccd),
+	(0x0d4d, 0x0d4d),
+	(0x0dca, 0x0dca),
+	(0x0e3a, 0x0e3a),
+	(0x0f84, 0x0f84),
+	(0x1039, 0x103a),
+	(0x1714, 0x1714),
+	(0x1734, 0x1734),
+	(0x17d2, 0x17d2),
+	(0x1a60, 0x1a60),
+	(0x1b44, 0x1b44),
+	(0x1baa, 0x1bab),
+	(0x1bf2, 0x1bf3),
+	(0x2d7f, 0x2d7f),
+	(0xa806, 0xa806),
+	(0xa8c4, 0xa8c4),
+	(0xa953, 0xa953),
+	(0xa9c0, 0xa9c0),
+	(0xaaf6, 0xaaf6),
+	(0xabed, 0xabed),
+	(0x10a3f, 0x10a3f),
+	(0x11046, 0x11046),
+	(0x110b9, 0x110b9),
+	(0x11133, 0x11134),
+	(0x111c0, 0x111c0),
+	(0x116b6, 0x116b6),
+]);
+
+// Encoded as ranges: ranges 264 bytes, bitset 14816 bytes, trie 3852 bytes
+const GREEK: CodepointSet = CodepointSet::Ranges(&[
+	(0x0370, 0x0373),
+	(0x0375, 0x0377),
+	(0x037a, 0x037d),
+	(0x0384, 0x0384),
+	(0x0386, 0x0386),
+	(0x0388, 0x038a),
+	(0x038c, 0x038c),
+	(0x038e, 0x03a1),
+	(0x03a3, 0x03e1),
+	(0x03f0, 0x03ff),
+	(0x1d26, 0x1d2a),
+	(0x1d5d, 0x1d61),
+	(0x1d66, 0x1d6a),
+	(0x1dbf, 0x1dbf),
+	(0x1f00, 0x1f15),
+	(0x1f18, 0x1f1d),
+	(0x1f20, 0x1f45),
+	(0x1f48, 0x1f4d),
+	(0x1f50, 0x1f57),
+	(0x1f59, 0x1f59),
+	(0x1f5b, 0x1f5b),
+	(0x1f5d, 0x1f5d),
+	(0x1f5f, 0x1f7d),
+	(0x1f80, 0x1fb4),
+	(0x1fb6, 0x1fc4),
+	(0x1fc6, 0x1fd3),
+	(0x1fd6, 0x1fdb),
+	(0x1fdd, 0x1fef),
+	(0x1ff2, 0x1ff4),
+	(0x1ff6, 0x1ffe),
+	(0x2126, 0x2126),
+	(0x10140, 0x1018a),
+	(0x1d200, 0x1d245),
+]);
+
+// Encoded as ranges: ranges 72 bytes, bitset 7864 bytes, trie 2052 bytes
+const HEBREW: CodepointSet = CodepointSet::Ranges(&[
+	(0x0591, 0x05c7),
+	(0x05d0, 0x05ea),
+	(0x05f0, 0x05f4),
+	(0xfb1d, 0xfb36),
+	(0xfb38, 0xfb3c),
+	(0xfb3e, 0xfb3e),
+	(0xfb40, 0xfb41),
+	(0xfb43, 0xfb44),
+	(0xfb46, 0xfb4f),
+]);
+
+// Encoded as ranges: ranges 32 bytes, bitset 14392 bytes, trie 4026 bytes
+const HIRAGANA: CodepointSet = CodepointSet::Ranges(&[
+	(0x3041, 0x3096),
+	(0x309d, 0x309f),
+	(0x1b001, 0x1b001),
+	(0x1f200, 0x1f200),
+]);
+
+// Encoded as ranges: ranges 64 bytes, bitset 12272 bytes, trie 3538 bytes
+const KATAKANA: CodepointSet = CodepointSet::Ranges(&[
+	(0x30a1, 0x30fa),
+	(0x30fd, 0x30ff),
+	(0x31f0, 0x31ff),
+	(0x32d0, 0x32fe),
+	(0x3300, 0x3357),
+	(0xff66, 0xff6f),
+	(0xff71, 0xff9d),
+	(0x1b000, 0x1b000),
+]);
+
+// Encoded as ranges: ranges 120 bytes, bitset 22904 bytes, trie 6202 bytes
+const HAN: CodepointSet = CodepointSet::Ranges(&[
+	(0x2e80, 0x2e99),
+	(0x2e9b, 0x2ef3),
+	(0x2f00, 0x2fd5),
+	(0x3005, 0x3005),
+	(0x3007, 0x3007),
+	(0x3021, 0x3029),
+	(0x3038, 0x303b),
+	(0x3400, 0x4db5),
+	(0x4e00, 0x9fcc),
+	(0xf900, 0xfa6d),
+	(0xfa70, 0xfad9),
+	(0x20000, 0x2a6d6),
+	(0x2a700, 0x2b734),
+	(0x2b740, 0x2b81d),
+	(0x2f800, 0x2fa1d),
+]);
+
+// Encoded as ranges: ranges 328 bytes, bitset 5200 bytes, trie 1460 bytes
+const DUAL_JOINING: CodepointSet = CodepointSet::Ranges(&[
+	(0x0620, 0x0620),
+	(0x0626, 0x0626),
+	(0x0628, 0x0628),
+	(0x062a, 0x062e),
+	(0x0633, 0x063f),
+	(0x0641, 0x0647),
+	(0x0649, 0x064a),
+	(0x066e, 0x066f),
+	(0x0678, 0x0687),
+	(0x069a, 0x06bf),
+	(0x06c1, 0x06c2),
+	(0x06cc, 0x06cc),
+	(0x06ce, 0x06ce),
+	(0x06d0, 0x06d1),
+	(0x06fa, 0x06fc),
+	(0x06ff, 0x06ff),
+	(0x0712, 0x0714),
+	(0x071a, 0x071d),
+	(0x071f, 0x0727),
+	(0x0729, 0x0729),
+	(0x072b, 0x072b),
+	(0x072d, 0x072e),
+	(0x074e, 0x0758),
+	(0x075c, 0x076a),
+	(0x076d, 0x0770),
+	(0x0772, 0x0772),
+	(0x0775, 0x0777),
+	(0x077a, 0x077f),
+	(0x07ca, 0x07ea),
+	(0x0841, 0x0845),
+	(0x0847, 0x0848),
+	(0x084a, 0x084e),
+	(0x0850, 0x0853),
+	(0x0855, 0x0855),
+	(0x08a0, 0x08a0),
+	(0x08a2, 0x08a9),
+	(0x1807, 0x1807),
+	(0x1820, 0x1877),
+	(0x1887, 0x18a8),
+	(0x18aa, 0x18aa),
+	(0xa840, 0xa871),
+]);
+
+// Encoded as bitset: ranges 8 bytes, bitset 8 bytes, trie 1364 bytes
+const LEFT_JOINING: CodepointSet = CodepointSet::Bitset {
+	start: 0xa872,
+	bits: &[
+		0x0000000000000001,
+	],
+};
+
+// Encoded as bitset: ranges 272 bytes, bitset 88 bytes, trie 142 bytes
+const RIGHT_JOINING: CodepointSet = CodepointSet::Bitset {
+	start: 0x0622,
+	bits: &[
+		0x000000400001e0af, 0x00ffffc0003b8000, 0x000b2bfe40000000, 0x10f8400000003000,
+		0x0380080000002540, 0x0000000000c68600, 0x0000000000000000, 0x0000000000000000,
+		0x0004209040000000, 0x0000000000000000, 0x0000000000000700,
+	],
+};
+
+// Encoded as ranges: ranges 1856 bytes, bitset 114736 bytes, trie 29472 bytes
+const TRANSPARENT: CodepointSet = CodepointSet::Ranges(&[
+	(0x00ad, 0x00ad),
+	(0x0300, 0x036f),
+	(0x0483, 0x0489),
+	(0x0591, 0x05bd),
+	(0x05bf, 0x05bf),
+	(0x05c1, 0x05c2),
+	(0x05c4, 0x05c5),
+	(0x05c7, 0x05c7),
+	(0x0610, 0x061a),
+	(0x061c, 0x061c),
+	(0x064b, 0x065f),
+	(0x0670, 0x0670),
+	(0x06d6, 0x06dc),
+	(0x06df, 0x06e4),
+	(0x06e7, 0x06e8),
+	(0x06ea, 0x06ed),
+	(0x070f, 0x070f),
+	(0x0711, 0x0711),
+	(0x0730, 0x074a),
+	(0x07a6, 0x07b0),
+	(0x07eb, 0x07f3),
+	(0x0816, 0x0819),
+	(0x081b, 0x0823),
+	(0x0825, 0x0827),
+	(0x0829, 0x082d),
+	(0x0859, 0x085b),
+	(0x08e4, 0x08fe),
+	(0x0900, 0x0902),
+	(0x093a, 0x093a),
+	(0x093c, 0x093c),
+	(0x0941, 0x0948),
+	(0x094d, 0x094d),
+	(0x0951, 0x0957),
+	(0x0962, 0x0963),
+	(0x0981, 0x0981),
+	(0x09bc, 0x09bc),
+	(0x09c1, 0x09c4),
+	(0x09cd, 0x09cd),
+	(0x09e2, 0x09e3),
+	(0x0a01, 0x0a02),
+	(0x0a3c, 0x0a3c),
+	(0x0a41, 0x0a42),
+	(0x0a47, 0x0a48),
+	(0x0a4b, 0x0a4d),
+	(0x0a51, 0x0a51),
+	(0x0a70, 0x0a71),
+	(0x0a75, 0x0a75),
+	(0x0a81, 0x0a82),
+	(0x0abc, 0x0abc),
+	(0x0ac1, 0x0ac5),
+	(0x0ac7, 0x0ac8),
+	(0x0acd, 0x0acd),
+	(0x0ae2, 0x0ae3),
+	(0x0b01, 0x0b01),
+	(0x0b3c, 0x0b3c),
+	(0x0b3f, 0x0b3f),
+	(0x0b41, 0x0b44),
+	(0x0b4d, 0x0b4d),
+	(0x0b56, 0x0b56),
+	(0x0b62, 0x0b63),
+	(0x0b82, 0x0b82),
+	(0x0bc0, 0x0bc0),
+	(0x0bcd, 0x0bcd),
+	(0x0c3e, 0x0c40),
+	(0x0c46, 0x0c48),
+	(0x0c4a, 0x0c4d),
+	(0x0c55, 0x0c56),
+	(0x0c62, 0x0c63),
+	(0x0cbc, 0x0cbc),
+	(0x0cbf, 0x0cbf),
+	(0x0cc6, 0x0cc6),
+	(0x0ccc, 0x0ccd),
+	(0x0ce2, 0x0ce3),
+	(0x0d41, 0x0d44),
+	(0x0d4d, 0x0d4d),
+	(0x0d62, 0x0d63),
+	(0x0dca, 0x0dca),
+	(0x0dd2, 0x0dd4),
+	(0x0dd6, 0x0dd6),
+	(0x0e31, 0x0e31),
+	(0x0e34, 0x0e3a),
+	(0x0e47, 0x0e4e),
+	(0x0eb1, 0x0eb1),
+	(0x0eb4, 0x0eb9),
+	(0x0ebb, 0x0ebc),
+	(0x0ec8, 0x0ecd),
+	(0x0f18, 0x0f19),
+	(0x0f35, 0x0f35),
+	(0x0f37, 0x0f37),
+	(0x0f39, 0x0f39),
+	(0x0f71, 0x0f7e),
+	(0x0f80, 0x0f84),
+	(0x0f86, 0x0f87),
+	(0x0f8d, 0x0f97),
+	(0x0f99, 0x0fbc),
+	(0x0fc6, 0x0fc6),
+	(0x102d, 0x1030),
+	(0x1032, 0x1037),
+	(0x1039, 0x103a),
+	(0x103d, 0x103e),
+	(0x1058, 0x1059),
+	(0x105e, 0x1060),
+	(0x1071, 0x1074),
+	(0x1082, 0x1082),
+	(0x1085, 0x1086),
+	(0x108d, 0x108d),
+	(0x109d, 0x109d),
+	(0x135d, 0x135f),
+	(0x1712, 0x1714),
+	(0x1732, 0x1734),
+	(0x1752, 0x1753),
+	(0x1772, 0x1773),
+	(0x17b4, 0x17b5),
+	(0x17b7, 0x17bd),
+	(0x17c6, 0x17c6),
+	(0x17c9, 0x17d3),
+	(0x17dd, 0x17dd),
+	(0x180b, 0x180d),
+	(0x18a9, 0x18a9),
+	(0x1920, 0x1922),
+	(0x1927, 0x1928),
+	(0x1932, 0x1932),
+	(0x1939, 0x193b),
+	(0x1a17, 0x1a18),
+	(0x1a1b, 0x1a1b),
+	(0x1a56, 0x1a56),
+	(0x1a58, 0x1a5e),
+	(0x1a60, 0x1a60),
+	(0x1a62, 0x1a62),
+	(0x1a65, 0x1a6c),
+	(0x1a73, 0x1a7c),
+	(0x1a7f, 0x1a7f),
+	(0x1b00, 0x1b03),
+	(0x1b34, 0x1b34),
+	(0x1b36, 0x1b3a),
+	(0x1b3c, 0x1b3c),
+	(0x1b42, 0x1b42),
+	(0x1b6b, 0x1b73),
+	(0x1b80, 0x1b81),
+	(0x1ba2, 0x1ba5),
+	(0x1ba8, 0x1ba9),
+	(0x1bab, 0x1bab),
+	(0x1be6, 0x1be6),
+	(0x1be8, 0x1be9),
+	(0x1bed, 0x1bed),
+	(0x1bef, 0x1bf1),
+	(0x1c2c, 0x1c33),
+	(0x1c36, 0x1c37),
+	(0x1cd0, 0x1cd2),
+	(0x1cd4, 0x1ce0),
+	(0x1ce2, 0x1ce8),
+	(0x1ced, 0x1ced),
+	(0x1cf4, 0x1cf4),
+	(0x1dc0, 0x1de6),
+	(0x1dfc, 0x1dff),
+	(0x200b, 0x200b),
+	(0x200e, 0x200f),
+	(0x202a, 0x202e),
+	(0x2060, 0x2064),
+	(0x206a, 0x206f),
+	(0x20d0, 0x20f0),
+	(0x2cef, 0x2cf1),
+	(0x2d7f, 0x2d7f),
+	(0x2de0, 0x2dff),
+	(0x302a, 0x302d),
+	(0x3099, 0x309a),
+	(0xa66f, 0xa672),
+	(0xa674, 0xa67d),
+	(0xa69f, 0xa69f),
+	(0xa6f0, 0xa6f1),
+	(0xa802, 0xa802),
+	(0xa806, 0xa806),
+	(0xa80b, 0xa80b),
+	(0xa825, 0xa826),
+	(0xa8c4, 0xa8c4),
+	(0xa8e0, 0xa8f1),
+	(0xa926, 0xa92d),
+	(0xa947, 0xa951),
+	(0xa980, 0xa982),
+	(0xa9b3, 0xa9b3),
+	(0xa9b6, 0xa9b9),
+	(0xa9bc, 0xa9bc),
+	(0xaa29, 0xaa2e),
+	(0xaa31, 0xaa32),
+	(0xaa35, 0xaa36),
+	(0xaa43, 0xaa43),
+	(0xaa4c, 0xaa4c),
+	(0xaab0, 0xaab0),
+	(0xaab2, 0xaab4),
+	(0xaab7, 0xaab8),
+	(0xaabe, 0xaabf),
+	(0xaac1, 0xaac1),
+	(0xaaec, 0xaaed),
+	(0xaaf6, 0xaaf6),
+	(0xabe5, 0xabe5),
+	(0xabe8, 0xabe8),
+	(0xabed, 0xabed),
+	(0xfb1e, 0xfb1e),
+	(0xfe00, 0xfe0f),
+	(0xfe20, 0xfe26),
+	(0xfeff, 0xfeff),
+	(0xfff9, 0xfffb),
+	(0x101fd, 0x101fd),
+	(0x10a01, 0x10a03),
+	(0x10a05, 0x10a06),
+	(0x10a0c, 0x10a0f),
+	(0x10a38, 0x10a3a),
+	(0x10a3f, 0x10a3f),
+	(0x11001, 0x11001),
+	(0x11038, 0x11046),
+	(0x11080, 0x11081),
+	(0x110b3, 0x110b6),
+	(0x110b9, 0x110ba),
+	(0x110bd, 0x110bd),
+	(0x11100, 0x11102),
+	(0x11127, 0x1112b),
+	(0x1112d, 0x11134),
+	(0x11180, 0x11181),
+	(0x111b6, 0x111be),
+	(0x116ab, 0x116ab),
+	(0x116ad, 0x116ad),
+	(0x116b0, 0x116b5),
+	(0x116b7, 0x116b7),
+	(0x16f8f, 0x16f92),
+	(0x1d167, 0x1d169),
+	(0x1d173, 0x1d182),
+	(0x1d185, 0x1d18b),
+	(0x1d1aa, 0x1d1ad),
+	(0x1d242, 0x1d244),
+	(0xe0001, 0xe0001),
+	(0xe0020, 0xe007f),
+	(0xe0100, 0xe01ef),
+]);
+
@@ -0,0 +1,1507 @@
+// File generated with precis-tools version 0.1.9
+
+const DERIVED_PROPERTIES: &[(Codepoints, (DerivedPropertyValue, DerivedPropertyValue))] = &[
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0000, 0x001f)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Single(0x0020), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0021, 0x007e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x007f, 0x009f)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x00a0, 0x00ac)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x00ad), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x00ae, 0x00b6)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x00b7), (DerivedPropertyValue::ContextO, DerivedPropertyValue::ContextO)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x00b8, 0x00bf)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x00c0, 0x00d6)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x00d7), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x00d8, 0x00f6)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x00f7), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x00f8, 0x0131)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0132, 0x0133)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0134, 0x013e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x013f, 0x0140)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0141, 0x0148)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0149), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x014a, 0x017e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x017f), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0180, 0x01c3)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x01c4, 0x01cc)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x01cd, 0x01f0)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x01f1, 0x01f3)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x01f4, 0x02af)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x02b0, 0x02b8)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x02b9, 0x02c1)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x02c2, 0x02c5)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x02c6, 0x02d1)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x02d2, 0x02eb)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x02ec), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x02ed), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x02ee), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x02ef, 0x02ff)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0300, 0x033f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0340, 0x0341)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0342), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0343, 0x0344)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0345, 0x034e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x034f), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0350, 0x0373)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0374), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0375), (DerivedPropertyValue::ContextO, DerivedPropertyValue::ContextO)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0376, 0x0377)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0378, 0x0379)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x037a), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x037b, 0x037d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x037e), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x037f, 0x0383)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0384, 0x0385)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0386), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0387), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0388, 0x038a)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x038b), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x038c), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x038d), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x038e, 0x03a1)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x03a2), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x03a3, 0x03cf)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x03d0, 0x03d6)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x03d7, 0x03ef)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x03f0, 0x03f2)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x03f3), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x03f4, 0x03f6)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x03f7, 0x03f8)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x03f9), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x03fa, 0x0481)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0482), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0483, 0x0487)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0488, 0x0489)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x048a, 0x0527)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0528, 0x0530)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0531, 0x0556)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0557, 0x0558)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0559), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x055a, 0x055f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0560), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0561, 0x0586)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0587), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0588), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0589, 0x058a)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x058b, 0x058e)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x058f), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0590), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0591, 0x05bd)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x05be), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x05bf), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x05c0), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x05c1, 0x05c2)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x05c3), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x05c4, 0x05c5)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x05c6), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x05c7), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x05c8, 0x05cf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x05d0, 0x05ea)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x05eb, 0x05ef)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x05f0, 0x05f2)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x05f3, 0x05f4)), (DerivedPropertyValue::ContextO, DerivedPropertyValue::ContextO)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x05f5, 0x05ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0600, 0x0604)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Single(0x0605), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0606, 0x060f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0610, 0x061a)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x061b), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x061c), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Single(0x061d), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x061e, 0x061f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0620, 0x063f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0640), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0641, 0x065f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0660, 0x0669)), (DerivedPropertyValue::ContextO, DerivedPropertyValue::ContextO)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x066a, 0x066d)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x066e, 0x0674)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0675, 0x0678)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0679, 0x06d3)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x06d4), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x06d5, 0x06dc)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x06dd), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Single(0x06de), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x06df, 0x06e8)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x06e9), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x06ea, 0x06ef)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x06f0, 0x06f9)), (DerivedPropertyValue::ContextO, DerivedPropertyValue::ContextO)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x06fa, 0x06ff)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0700, 0x070d)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x070e), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x070f), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0710, 0x074a)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x074b, 0x074c)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x074d, 0x07b1)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x07b2, 0x07bf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x07c0, 0x07f5)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x07f6, 0x07f9)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x07fa), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x07fb, 0x07ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0800, 0x082d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x082e, 0x082f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0830, 0x083e)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x083f), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0840, 0x085b)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x085c, 0x085d)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x085e), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x085f, 0x089f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x08a0), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x08a1), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x08a2, 0x08ac)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x08ad, 0x08e3)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x08e4, 0x08fe)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x08ff), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0900, 0x0957)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0958, 0x095f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0960, 0x0963)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0964, 0x0965)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0966, 0x096f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0970), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0971, 0x0977)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0978), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0979, 0x097f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0980), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0981, 0x0983)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0984), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0985, 0x098c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x098d, 0x098e)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x098f, 0x0990)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0991, 0x0992)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0993, 0x09a8)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x09a9), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09aa, 0x09b0)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x09b1), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x09b2), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09b3, 0x09b5)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09b6, 0x09b9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09ba, 0x09bb)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09bc, 0x09c4)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09c5, 0x09c6)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09c7, 0x09c8)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09c9, 0x09ca)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09cb, 0x09ce)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09cf, 0x09d6)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x09d7), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09d8, 0x09db)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09dc, 0x09dd)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x09de), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x09df), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09e0, 0x09e3)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09e4, 0x09e5)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09e6, 0x09f1)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09f2, 0x09fb)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09fc, 0x0a00)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a01, 0x0a03)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0a04), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a05, 0x0a0a)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a0b, 0x0a0e)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a0f, 0x0a10)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a11, 0x0a12)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a13, 0x0a28)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0a29), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a2a, 0x0a30)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0a31), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0a32), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0a33), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0a34), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0a35), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0a36), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0a37), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a38, 0x0a39)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a3a, 0x0a3b)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0a3c), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0a3d), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a3e, 0x0a42)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a43, 0x0a46)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a47, 0x0a48)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a49, 0x0a4a)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a4b, 0x0a4d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a4e, 0x0a50)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0a51), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a52, 0x0a58)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a59, 0x0a5b)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0a5c), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0a5d), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0a5e), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a5f, 0x0a65)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a66, 0x0a75)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a76, 0x0a80)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a81, 0x0a83)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0a84), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a85, 0x0a8d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0a8e), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a8f, 0x0a91)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0a92), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a93, 0x0aa8)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0aa9), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0aaa, 0x0ab0)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0ab1), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ab2, 0x0ab3)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0ab4), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ab5, 0x0ab9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0aba, 0x0abb)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0abc, 0x0ac5)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0ac6), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ac7, 0x0ac9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0aca), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0acb, 0x0acd)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ace, 0x0acf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0ad0), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ad1, 0x0adf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ae0, 0x0ae3)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ae4, 0x0ae5)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ae6, 0x0aef)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0af0, 0x0af1)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0af2, 0x0b00)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b01, 0x0b03)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0b04), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b05, 0x0b0c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b0d, 0x0b0e)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b0f, 0x0b10)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b11, 0x0b12)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b13, 0x0b28)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0b29), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b2a, 0x0b30)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0b31), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b32, 0x0b33)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0b34), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b35, 0x0b39)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b3a, 0x0b3b)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b3c, 0x0b44)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b45, 0x0b46)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b47, 0x0b48)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b49, 0x0b4a)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b4b, 0x0b4d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b4e, 0x0b55)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b56, 0x0b57)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b58, 0x0b5b)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b5c, 0x0b5d)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0b5e), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b5f, 0x0b63)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b64, 0x0b65)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b66, 0x0b6f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0b70), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0b71), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b72, 0x0b77)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b78, 0x0b81)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b82, 0x0b83)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0b84), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b85, 0x0b8a)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b8b, 0x0b8d)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b8e, 0x0b90)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0b91), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b92, 0x0b95)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b96, 0x0b98)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b99, 0x0b9a)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0b9b), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0b9c), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0b9d), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b9e, 0x0b9f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ba0, 0x0ba2)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ba3, 0x0ba4)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ba5, 0x0ba7)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ba8, 0x0baa)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0bab, 0x0bad)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0bae, 0x0bb9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0bba, 0x0bbd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0bbe, 0x0bc2)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0bc3, 0x0bc5)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0bc6, 0x0bc8)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0bc9), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0bca, 0x0bcd)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0bce, 0x0bcf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0bd0), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0bd1, 0x0bd6)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0bd7), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0bd8, 0x0be5)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0be6, 0x0bef)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0bf0, 0x0bfa)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0bfb, 0x0c00)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c01, 0x0c03)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0c04), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c05, 0x0c0c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0c0d), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c0e, 0x0c10)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0c11), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c12, 0x0c28)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0c29), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c2a, 0x0c33)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0c34), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c35, 0x0c39)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c3a, 0x0c3c)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c3d, 0x0c44)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0c45), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c46, 0x0c48)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0c49), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c4a, 0x0c4d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c4e, 0x0c54)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c55, 0x0c56)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0c57), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c58, 0x0c59)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c5a, 0x0c5f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c60, 0x0c63)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c64, 0x0c65)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c66, 0x0c6f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c70, 0x0c77)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c78, 0x0c7f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c80, 0x0c81)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c82, 0x0c83)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0c84), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c85, 0x0c8c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0c8d), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c8e, 0x0c90)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0c91), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c92, 0x0ca8)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0ca9), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0caa, 0x0cb3)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0cb4), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cb5, 0x0cb9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cba, 0x0cbb)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cbc, 0x0cc4)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0cc5), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cc6, 0x0cc8)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0cc9), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cca, 0x0ccd)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cce, 0x0cd4)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cd5, 0x0cd6)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cd7, 0x0cdd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0cde), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0cdf), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ce0, 0x0ce3)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ce4, 0x0ce5)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ce6, 0x0cef)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0cf0), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cf1, 0x0cf2)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cf3, 0x0d01)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d02, 0x0d03)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0d04), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d05, 0x0d0c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0d0d), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d0e, 0x0d10)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0d11), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d12, 0x0d3a)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d3b, 0x0d3c)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d3d, 0x0d44)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0d45), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d46, 0x0d48)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0d49), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d4a, 0x0d4e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d4f, 0x0d56)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0d57), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d58, 0x0d5f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d60, 0x0d63)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d64, 0x0d65)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d66, 0x0d6f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d70, 0x0d75)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d76, 0x0d78)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0d79), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d7a, 0x0d7f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d80, 0x0d81)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d82, 0x0d83)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0d84), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d85, 0x0d96)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d97, 0x0d99)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d9a, 0x0db1)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0db2), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0db3, 0x0dbb)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0dbc), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0dbd), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0dbe, 0x0dbf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0dc0, 0x0dc6)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0dc7, 0x0dc9)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0dca), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0dcb, 0x0dce)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0dcf, 0x0dd4)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0dd5), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0dd6), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0dd7), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0dd8, 0x0ddf)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0de0, 0x0df1)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0df2, 0x0df3)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0df4), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0df5, 0x0e00)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e01, 0x0e32)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0e33), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e34, 0x0e3a)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e3b, 0x0e3e)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0e3f), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e40, 0x0e4e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0e4f), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e50, 0x0e59)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e5a, 0x0e5b)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e5c, 0x0e80)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e81, 0x0e82)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0e83), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0e84), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e85, 0x0e86)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e87, 0x0e88)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0e89), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0e8a), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e8b, 0x0e8c)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0e8d), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e8e, 0x0e93)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e94, 0x0e97)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0e98), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e99, 0x0e9f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0ea0), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ea1, 0x0ea3)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0ea4), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0ea5), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0ea6), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0ea7), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ea8, 0x0ea9)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0eaa, 0x0eab)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0eac), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ead, 0x0eb2)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0eb3), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0eb4, 0x0eb9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0eba), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ebb, 0x0ebd)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ebe, 0x0ebf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ec0, 0x0ec4)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0ec5), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0ec6), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0ec7), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ec8, 0x0ecd)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ece, 0x0ecf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ed0, 0x0ed9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0eda, 0x0edb)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0edc, 0x0edd)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ede, 0x0edf)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ee0, 0x0eff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x0f00), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f01, 0x0f0a)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0f0b), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f0c, 0x0f17)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f18, 0x0f19)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f1a, 0x0f1f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f20, 0x0f29)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f2a, 0x0f34)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0f35), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0f36), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0f37), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0f38), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0f39), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f3a, 0x0f3d)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f3e, 0x0f42)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0f43), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f44, 0x0f47)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0f48), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f49, 0x0f4c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0f4d), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f4e, 0x0f51)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0f52), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f53, 0x0f56)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0f57), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f58, 0x0f5b)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0f5c), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f5d, 0x0f68)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0f69), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f6a, 0x0f6c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f6d, 0x0f70)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f71, 0x0f72)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0f73), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0f74), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f75, 0x0f79)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f7a, 0x0f80)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0f81), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f82, 0x0f84)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0f85), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f86, 0x0f92)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0f93), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f94, 0x0f97)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0f98), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f99, 0x0f9c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0f9d), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f9e, 0x0fa1)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0fa2), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0fa3, 0x0fa6)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0fa7), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0fa8, 0x0fab)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0fac), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0fad, 0x0fb8)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0fb9), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0fba, 0x0fbc)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x0fbd), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0fbe, 0x0fc5)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0fc6), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0fc7, 0x0fcc)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x0fcd), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0fce, 0x0fda)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0fdb, 0x0fff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1000, 0x1049)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x104a, 0x104f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1050, 0x109d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x109e, 0x109f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a0, 0x10c5)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x10c6), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x10c7), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10c8, 0x10cc)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x10cd), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10ce, 0x10cf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10d0, 0x10fa)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10fb, 0x10fc)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10fd, 0x10ff)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1100, 0x11ff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1200, 0x1248)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1249), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x124a, 0x124d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x124e, 0x124f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1250, 0x1256)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1257), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1258), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1259), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x125a, 0x125d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x125e, 0x125f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1260, 0x1288)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1289), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x128a, 0x128d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x128e, 0x128f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1290, 0x12b0)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x12b1), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12b2, 0x12b5)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12b6, 0x12b7)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12b8, 0x12be)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x12bf), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x12c0), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x12c1), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12c2, 0x12c5)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12c6, 0x12c7)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12c8, 0x12d6)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x12d7), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12d8, 0x1310)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1311), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1312, 0x1315)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1316, 0x1317)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1318, 0x135a)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x135b, 0x135c)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x135d, 0x135f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1360, 0x137c)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x137d, 0x137f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1380, 0x138f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1390, 0x1399)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x139a, 0x139f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x13a0, 0x13f4)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x13f5, 0x13ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1400), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1401, 0x166c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x166d, 0x166e)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x166f, 0x167f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1680), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1681, 0x169a)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x169b, 0x169c)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x169d, 0x169f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16a0, 0x16ea)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16eb, 0x16f0)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16f1, 0x16ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1700, 0x170c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x170d), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x170e, 0x1714)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1715, 0x171f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1720, 0x1734)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1735, 0x1736)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1737, 0x173f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1740, 0x1753)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1754, 0x175f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1760, 0x176c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x176d), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x176e, 0x1770)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1771), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1772, 0x1773)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1774, 0x177f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1780, 0x17b3)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x17b4, 0x17b5)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x17b6, 0x17d3)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x17d4, 0x17d6)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x17d7), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x17d8, 0x17db)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x17dc, 0x17dd)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x17de, 0x17df)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x17e0, 0x17e9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x17ea, 0x17ef)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x17f0, 0x17f9)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x17fa, 0x17ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1800, 0x180a)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x180b, 0x180e)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Single(0x180f), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1810, 0x1819)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x181a, 0x181f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1820, 0x1877)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1878, 0x187f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1880, 0x18aa)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x18ab, 0x18af)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x18b0, 0x18f5)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x18f6, 0x18ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1900, 0x191c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x191d, 0x191f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1920, 0x192b)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x192c, 0x192f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1930, 0x193b)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x193c, 0x193f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1940), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1941, 0x1943)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1944, 0x1945)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1946, 0x196d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x196e, 0x196f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1970, 0x1974)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1975, 0x197f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1980, 0x19ab)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x19ac, 0x19af)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x19b0, 0x19c9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x19ca, 0x19cf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x19d0, 0x19d9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x19da), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x19db, 0x19dd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x19de, 0x19ff)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1a00, 0x1a1b)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1a1c, 0x1a1d)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1a1e, 0x1a1f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1a20, 0x1a5e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1a5f), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1a60, 0x1a7c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1a7d, 0x1a7e)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1a7f, 0x1a89)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1a8a, 0x1a8f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1a90, 0x1a99)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1a9a, 0x1a9f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1aa0, 0x1aa6)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1aa7), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1aa8, 0x1aad)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1aae, 0x1aff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b00, 0x1b4b)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b4c, 0x1b4f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b50, 0x1b59)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b5a, 0x1b6a)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b6b, 0x1b73)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b74, 0x1b7c)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b7d, 0x1b7f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b80, 0x1bf3)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1bf4, 0x1bfb)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1bfc, 0x1bff)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1c00, 0x1c37)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1c38, 0x1c3a)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1c3b, 0x1c3f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1c40, 0x1c49)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1c4a, 0x1c4c)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1c4d, 0x1c7d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1c7e, 0x1c7f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1c80, 0x1cbf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cc0, 0x1cc7)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cc8, 0x1ccf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cd0, 0x1cd2)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1cd3), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cd4, 0x1cf6)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cf7, 0x1cff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d00, 0x1d2b)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d2c, 0x1d2e)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1d2f), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d30, 0x1d3a)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1d3b), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d3c, 0x1d4d)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1d4e), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d4f, 0x1d6a)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d6b, 0x1d77)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1d78), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d79, 0x1d9a)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d9b, 0x1dbf)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1dc0, 0x1de6)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1de7, 0x1dfb)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1dfc, 0x1e99)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e9a, 0x1e9b)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e9c, 0x1f15)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f16, 0x1f17)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f18, 0x1f1d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f1e, 0x1f1f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f20, 0x1f45)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f46, 0x1f47)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f48, 0x1f4d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f4e, 0x1f4f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f50, 0x1f57)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1f58), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1f59), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1f5a), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1f5b), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1f5c), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1f5d), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1f5e), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f5f, 0x1f70)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1f71), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1f72), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1f73), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1f74), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1f75), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1f76), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1f77), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1f78), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1f79), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1f7a), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1f7b), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1f7c), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1f7d), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f7e, 0x1f7f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f80, 0x1f87)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f88, 0x1f8f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f90, 0x1f97)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f98, 0x1f9f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fa0, 0x1fa7)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fa8, 0x1faf)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fb0, 0x1fb4)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1fb5), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fb6, 0x1fba)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fbb, 0x1fc1)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fc2, 0x1fc4)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1fc5), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fc6, 0x1fc8)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1fc9), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1fca), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fcb, 0x1fcf)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fd0, 0x1fd2)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1fd3), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fd4, 0x1fd5)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fd6, 0x1fda)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1fdb), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1fdc), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fdd, 0x1fdf)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fe0, 0x1fe2)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1fe3), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fe4, 0x1fea)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1feb), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1fec), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fed, 0x1fef)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ff0, 0x1ff1)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ff2, 0x1ff4)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1ff5), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ff6, 0x1ff8)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1ff9), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ffa), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ffb, 0x1ffe)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1fff), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2000, 0x200a)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x200b), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x200c, 0x200d)), (DerivedPropertyValue::ContextJ, DerivedPropertyValue::ContextJ)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x200e, 0x200f)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2010, 0x2027)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2028, 0x202e)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x202f, 0x205f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2060, 0x2064)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Single(0x2065), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2066, 0x206f)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2070, 0x2071)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2072, 0x2073)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2074, 0x208e)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x208f), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2090, 0x209c)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x209d, 0x209f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x20a0, 0x20ba)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x20bb, 0x20cf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x20d0, 0x20dc)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x20dd, 0x20e0)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x20e1), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x20e2, 0x20e4)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x20e5, 0x20f0)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x20f1, 0x20ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2100, 0x2131)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x2132), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2133, 0x214d)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x214e), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x214f, 0x2182)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2183, 0x2184)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2185, 0x2189)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x218a, 0x218f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2190, 0x23f3)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x23f4, 0x23ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2400, 0x2426)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2427, 0x243f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2440, 0x244a)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x244b, 0x245f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2460, 0x26ff)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x2700), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2701, 0x2b4c)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2b4d, 0x2b4f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2b50, 0x2b59)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2b5a, 0x2bff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2c00, 0x2c2e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x2c2f), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2c30, 0x2c5e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x2c5f), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2c60, 0x2c7b)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2c7c, 0x2c7d)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2c7e, 0x2ce4)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2ce5, 0x2cea)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2ceb, 0x2cf3)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2cf4, 0x2cf8)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2cf9, 0x2cff)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2d00, 0x2d25)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x2d26), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x2d27), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2d28, 0x2d2c)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x2d2d), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2d2e, 0x2d2f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2d30, 0x2d67)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2d68, 0x2d6e)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2d6f, 0x2d70)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2d71, 0x2d7e)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2d7f, 0x2d96)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2d97, 0x2d9f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2da0, 0x2da6)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x2da7), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2da8, 0x2dae)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x2daf), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2db0, 0x2db6)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x2db7), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2db8, 0x2dbe)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x2dbf), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2dc0, 0x2dc6)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x2dc7), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2dc8, 0x2dce)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x2dcf), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2dd0, 0x2dd6)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x2dd7), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2dd8, 0x2dde)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x2ddf), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2de0, 0x2dff)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2e00, 0x2e2e)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x2e2f), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2e30, 0x2e3b)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2e3c, 0x2e7f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2e80, 0x2e99)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x2e9a), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2e9b, 0x2ef3)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2ef4, 0x2eff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2f00, 0x2fd5)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2fd6, 0x2fef)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2ff0, 0x2ffb)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2ffc, 0x2fff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3000, 0x3004)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3005, 0x3007)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3008, 0x3029)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x302a, 0x302d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x302e, 0x302f)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Single(0x3030), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3031, 0x3035)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3036, 0x303a)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x303b), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Single(0x303c), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x303d, 0x303f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x3040), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3041, 0x3096)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3097, 0x3098)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3099, 0x309a)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x309b, 0x309c)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x309d, 0x309e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x309f, 0x30a0)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x30a1, 0x30fa)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x30fb), (DerivedPropertyValue::ContextO, DerivedPropertyValue::ContextO)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x30fc, 0x30fe)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x30ff), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3100, 0x3104)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3105, 0x312d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x312e, 0x3130)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3131, 0x3163)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x3164), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3165, 0x318e)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x318f), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3190, 0x319f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x31a0, 0x31ba)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x31bb, 0x31bf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x31c0, 0x31e3)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x31e4, 0x31ef)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x31f0, 0x31ff)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3200, 0x321e)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x321f), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3220, 0x32fe)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x32ff), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3300, 0x33ff)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3400, 0x4db5)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x4db6, 0x4dbf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x4dc0, 0x4dff)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x4e00, 0x9fcc)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x9fcd, 0x9fff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa000, 0xa48c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa48d, 0xa48f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa490, 0xa4c6)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa4c7, 0xa4cf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa4d0, 0xa4fd)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa4fe, 0xa4ff)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa500, 0xa60c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa60d, 0xa60f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa610, 0xa62b)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa62c, 0xa63f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa640, 0xa66f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa670, 0xa673)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa674, 0xa67d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0xa67e), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa67f, 0xa697)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa698, 0xa69e)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa69f, 0xa6e5)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa6e6, 0xa6ef)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa6f0, 0xa6f1)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa6f2, 0xa6f7)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa6f8, 0xa6ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa700, 0xa716)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa717, 0xa71f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa720, 0xa721)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa722, 0xa76f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0xa770), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa771, 0xa788)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa789, 0xa78a)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa78b, 0xa78e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0xa78f), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa790, 0xa793)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa794, 0xa79f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa7a0, 0xa7aa)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa7ab, 0xa7f7)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa7f8, 0xa7f9)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa7fa, 0xa827)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa828, 0xa82b)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa82c, 0xa82f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa830, 0xa839)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa83a, 0xa83f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa840, 0xa873)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa874, 0xa877)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa878, 0xa87f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa880, 0xa8c4)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa8c5, 0xa8cd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa8ce, 0xa8cf)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa8d0, 0xa8d9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa8da, 0xa8df)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa8e0, 0xa8f7)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa8f8, 0xa8fa)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xa8fb), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa8fc, 0xa8ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa900, 0xa92d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa92e, 0xa92f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa930, 0xa953)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa954, 0xa95e)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0xa95f), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa960, 0xa97c)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa97d, 0xa97f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa980, 0xa9c0)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa9c1, 0xa9cd)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xa9ce), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa9cf, 0xa9d9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa9da, 0xa9dd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa9de, 0xa9df)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa9e0, 0xa9ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa00, 0xaa36)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa37, 0xaa3f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa40, 0xaa4d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa4e, 0xaa4f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa50, 0xaa59)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa5a, 0xaa5b)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa5c, 0xaa5f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa60, 0xaa76)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa77, 0xaa79)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa7a, 0xaa7b)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa7c, 0xaa7f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa80, 0xaac2)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaac3, 0xaada)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaadb, 0xaadd)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaade, 0xaadf)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaae0, 0xaaef)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaaf0, 0xaaf1)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaaf2, 0xaaf6)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaaf7, 0xab00)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab01, 0xab06)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab07, 0xab08)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab09, 0xab0e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab0f, 0xab10)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab11, 0xab16)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab17, 0xab1f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab20, 0xab26)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0xab27), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab28, 0xab2e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab2f, 0xabbf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xabc0, 0xabea)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0xabeb), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xabec, 0xabed)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xabee, 0xabef)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xabf0, 0xabf9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xabfa, 0xabff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xac00, 0xd7a3)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xd7a4, 0xd7af)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xd7b0, 0xd7c6)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xd7c7, 0xd7ca)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xd7cb, 0xd7fb)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xd7fc, 0xd7ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xd800, 0xf8ff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xf900, 0xfa0d)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfa0e, 0xfa0f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0xfa10), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xfa11), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0xfa12), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfa13, 0xfa14)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfa15, 0xfa1e)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xfa1f), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0xfa20), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xfa21), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0xfa22), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfa23, 0xfa24)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfa25, 0xfa26)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfa27, 0xfa29)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfa2a, 0xfa6d)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfa6e, 0xfa6f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfa70, 0xfad9)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfada, 0xfaff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb00, 0xfb06)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb07, 0xfb12)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb13, 0xfb17)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb18, 0xfb1c)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0xfb1d), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xfb1e), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb1f, 0xfb36)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xfb37), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb38, 0xfb3c)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xfb3d), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0xfb3e), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xfb3f), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb40, 0xfb41)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xfb42), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb43, 0xfb44)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xfb45), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb46, 0xfbc1)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfbc2, 0xfbd2)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfbd3, 0xfd3f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfd40, 0xfd4f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfd50, 0xfd8f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfd90, 0xfd91)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfd92, 0xfdc7)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfdc8, 0xfdcf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfdd0, 0xfdef)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfdf0, 0xfdfd)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfdfe, 0xfdff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe00, 0xfe0f)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe10, 0xfe19)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe1a, 0xfe1f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe20, 0xfe26)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe27, 0xfe2f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe30, 0xfe52)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xfe53), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe54, 0xfe66)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xfe67), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe68, 0xfe6b)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe6c, 0xfe6f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe70, 0xfe72)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xfe73), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0xfe74), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xfe75), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe76, 0xfefc)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfefd, 0xfefe)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0xfeff), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Single(0xff00), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xff01, 0xff9f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xffa0), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffa1, 0xffbe)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffbf, 0xffc1)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffc2, 0xffc7)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffc8, 0xffc9)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffca, 0xffcf)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffd0, 0xffd1)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffd2, 0xffd7)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffd8, 0xffd9)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffda, 0xffdc)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffdd, 0xffdf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffe0, 0xffe6)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0xffe7), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffe8, 0xffee)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffef, 0xfff8)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfff9, 0xfffb)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfffc, 0xfffd)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfffe, 0xffff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10000, 0x1000b)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1000c), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1000d, 0x10026)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x10027), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10028, 0x1003a)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1003b), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1003c, 0x1003d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1003e), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1003f, 0x1004d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1004e, 0x1004f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10050, 0x1005d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1005e, 0x1007f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10080, 0x100fa)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x100fb, 0x100ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10100, 0x10102)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10103, 0x10106)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10107, 0x10133)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10134, 0x10136)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10137, 0x1018a)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1018b, 0x1018f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10190, 0x1019b)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1019c, 0x101cf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x101d0, 0x101fc)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x101fd), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x101fe, 0x1027f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10280, 0x1029c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1029d, 0x1029f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x102a0, 0x102d0)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x102d1, 0x102ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10300, 0x1031e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1031f), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10320, 0x10323)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10324, 0x1032f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10330, 0x10340)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x10341), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10342, 0x10349)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1034a), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1034b, 0x1037f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10380, 0x1039d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1039e), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1039f), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x103a0, 0x103c3)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x103c4, 0x103c7)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x103c8, 0x103cf)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x103d0, 0x103d5)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x103d6, 0x103ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10400, 0x1049d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1049e, 0x1049f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x104a0, 0x104a9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x104aa, 0x107ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10800, 0x10805)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10806, 0x10807)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x10808), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x10809), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1080a, 0x10835)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x10836), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10837, 0x10838)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10839, 0x1083b)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1083c), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1083d, 0x1083e)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1083f, 0x10855)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x10856), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10857, 0x1085f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10860, 0x108ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10900, 0x10915)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10916, 0x1091b)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1091c, 0x1091e)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1091f), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10920, 0x10939)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1093a, 0x1093e)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1093f), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10940, 0x1097f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10980, 0x109b7)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x109b8, 0x109bd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x109be, 0x109bf)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x109c0, 0x109ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a00, 0x10a03)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x10a04), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a05, 0x10a06)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a07, 0x10a0b)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a0c, 0x10a13)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x10a14), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a15, 0x10a17)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x10a18), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a19, 0x10a33)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a34, 0x10a37)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a38, 0x10a3a)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a3b, 0x10a3e)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x10a3f), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a40, 0x10a47)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a48, 0x10a4f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a50, 0x10a58)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a59, 0x10a5f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a60, 0x10a7c)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a7d, 0x10a7f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a80, 0x10aff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b00, 0x10b35)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b36, 0x10b38)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b39, 0x10b3f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b40, 0x10b55)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b56, 0x10b57)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b58, 0x10b5f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b60, 0x10b72)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b73, 0x10b77)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b78, 0x10b7f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b80, 0x10bff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10c00, 0x10c48)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10c49, 0x10e5f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10e60, 0x10e7e)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10e7f, 0x10fff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11000, 0x11046)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11047, 0x1104d)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1104e, 0x11051)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11052, 0x11065)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11066, 0x1106f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11070, 0x1107f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11080, 0x110ba)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x110bb, 0x110bc)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x110bd), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x110be, 0x110c1)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x110c2, 0x110cf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x110d0, 0x110e8)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x110e9, 0x110ef)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x110f0, 0x110f9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x110fa, 0x110ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11100, 0x11134)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x11135), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11136, 0x1113f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11140, 0x11143)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11144, 0x1117f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11180, 0x111c4)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x111c5, 0x111c8)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x111c9, 0x111cf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x111d0, 0x111d9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x111da, 0x1167f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11680, 0x116b7)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x116b8, 0x116bf)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x116c0, 0x116c9)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x116ca, 0x11fff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12000, 0x1236e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1236f, 0x123ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12400, 0x12462)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12463, 0x1246f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12470, 0x12473)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12474, 0x12fff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x13000, 0x1342e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1342f, 0x167ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16800, 0x16a38)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16a39, 0x16eff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16f00, 0x16f44)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16f45, 0x16f4f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16f50, 0x16f7e)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16f7f, 0x16f8e)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16f8f, 0x16f9f)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16fa0, 0x1afff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b000, 0x1b001)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b002, 0x1cfff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d000, 0x1d0f5)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d0f6, 0x1d0ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d100, 0x1d126)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d127, 0x1d128)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d129, 0x1d164)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d165, 0x1d169)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d16a, 0x1d16c)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d16d, 0x1d172)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d173, 0x1d17a)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d17b, 0x1d182)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d183, 0x1d184)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d185, 0x1d18b)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d18c, 0x1d1a9)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d1aa, 0x1d1ad)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d1ae, 0x1d1dd)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d1de, 0x1d1ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d200, 0x1d241)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d242, 0x1d244)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Single(0x1d245), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d246, 0x1d2ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d300, 0x1d356)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d357, 0x1d35f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d360, 0x1d371)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d372, 0x1d3ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d400, 0x1d454)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1d455), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d456, 0x1d49c)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1d49d), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d49e, 0x1d49f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d4a0, 0x1d4a1)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1d4a2), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d4a3, 0x1d4a4)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d4a5, 0x1d4a6)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d4a7, 0x1d4a8)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d4a9, 0x1d4ac)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1d4ad), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d4ae, 0x1d4b9)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1d4ba), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1d4bb), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1d4bc), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d4bd, 0x1d4c3)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1d4c4), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d4c5, 0x1d505)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1d506), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d507, 0x1d50a)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d50b, 0x1d50c)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d50d, 0x1d514)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1d515), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d516, 0x1d51c)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1d51d), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d51e, 0x1d539)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1d53a), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d53b, 0x1d53e)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1d53f), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d540, 0x1d544)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1d545), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1d546), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d547, 0x1d549)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d54a, 0x1d550)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1d551), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d552, 0x1d6a5)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d6a6, 0x1d6a7)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d6a8, 0x1d7cb)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d7cc, 0x1d7cd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d7ce, 0x1d7ff)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d800, 0x1edff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee00, 0x1ee03)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee04), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee05, 0x1ee1f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee20), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee21, 0x1ee22)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee23), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1ee24), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee25, 0x1ee26)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1ee27), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee28), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee29, 0x1ee32)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee33), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee34, 0x1ee37)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee38), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1ee39), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee3a), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1ee3b), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee3c, 0x1ee41)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1ee42), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee43, 0x1ee46)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1ee47), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee48), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1ee49), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee4a), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1ee4b), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee4c), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee4d, 0x1ee4f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee50), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee51, 0x1ee52)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee53), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1ee54), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee55, 0x1ee56)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1ee57), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee58), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1ee59), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee5a), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1ee5b), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee5c), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1ee5d), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee5e), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1ee5f), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee60), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee61, 0x1ee62)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee63), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1ee64), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee65, 0x1ee66)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee67, 0x1ee6a)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee6b), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee6c, 0x1ee72)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee73), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee74, 0x1ee77)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee78), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee79, 0x1ee7c)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee7d), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1ee7e), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee7f), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee80, 0x1ee89)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1ee8a), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee8b, 0x1ee9b)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee9c, 0x1eea0)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1eea1, 0x1eea3)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1eea4), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1eea5, 0x1eea9)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1eeaa), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1eeab, 0x1eebb)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1eebc, 0x1eeef)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1eef0, 0x1eef1)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1eef2, 0x1efff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f000, 0x1f02b)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f02c, 0x1f02f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f030, 0x1f093)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f094, 0x1f09f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f0a0, 0x1f0ae)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f0af, 0x1f0b0)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f0b1, 0x1f0be)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f0bf, 0x1f0c0)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f0c1, 0x1f0cf)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1f0d0), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f0d1, 0x1f0df)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f0e0, 0x1f0ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f100, 0x1f10a)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f10b, 0x1f10f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f110, 0x1f12e)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1f12f), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f130, 0x1f16b)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f16c, 0x1f16f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f170, 0x1f19a)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f19b, 0x1f1e5)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f1e6, 0x1f202)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f203, 0x1f20f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f210, 0x1f23a)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f23b, 0x1f23f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f240, 0x1f248)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f249, 0x1f24f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f250, 0x1f251)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f252, 0x1f2ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f300, 0x1f320)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f321, 0x1f32f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f330, 0x1f335)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1f336), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f337, 0x1f37c)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f37d, 0x1f37f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f380, 0x1f393)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f394, 0x1f39f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f3a0, 0x1f3c4)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1f3c5), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f3c6, 0x1f3ca)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f3cb, 0x1f3df)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f3e0, 0x1f3f0)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f3f1, 0x1f3ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f400, 0x1f43e)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1f43f), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0x1f440), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1f441), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f442, 0x1f4f7)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Single(0x1f4f8), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f4f9, 0x1f4fc)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f4fd, 0x1f4ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f500, 0x1f53d)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f53e, 0x1f53f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f540, 0x1f543)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f544, 0x1f54f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f550, 0x1f567)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f568, 0x1f5fa)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f5fb, 0x1f640)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f641, 0x1f644)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f645, 0x1f64f)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f650, 0x1f67f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f680, 0x1f6c5)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f6c6, 0x1f6ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f700, 0x1f773)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f774, 0x1fffd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fffe, 0x1ffff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x20000, 0x2a6d6)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2a6d7, 0x2a6ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2a700, 0x2b734)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2b735, 0x2b73f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2b740, 0x2b81d)), (DerivedPropertyValue::PValid, DerivedPropertyValue::PValid)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2b81e, 0x2f7ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2f800, 0x2fa1d)), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2fa1e, 0x2fffd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2fffe, 0x2ffff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x30000, 0x3fffd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3fffe, 0x3ffff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x40000, 0x4fffd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x4fffe, 0x4ffff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x50000, 0x5fffd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x5fffe, 0x5ffff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x60000, 0x6fffd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x6fffe, 0x6ffff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x70000, 0x7fffd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x7fffe, 0x7ffff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x80000, 0x8fffd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x8fffe, 0x8ffff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x90000, 0x9fffd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x9fffe, 0x9ffff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa0000, 0xafffd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xafffe, 0xaffff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xb0000, 0xbfffd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xbfffe, 0xbffff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xc0000, 0xcfffd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xcfffe, 0xcffff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xd0000, 0xdfffd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xdfffe, 0xdffff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Single(0xe0000), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Single(0xe0001), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xe0002, 0xe001f)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xe0020, 0xe007f)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xe0080, 0xe00ff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xe0100, 0xe01ef)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xe01f0, 0xefffd)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xefffe, 0x10ffff)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+];
+
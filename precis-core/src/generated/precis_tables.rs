@@ -0,0 +1,2759 @@
+// File generated with precis-tools version 0.1.9
+
+const EXCEPTIONS: &[(Codepoints, DerivedPropertyValue)] = &[
+	(Codepoints::Single(0x00B7), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x00DF), DerivedPropertyValue::PValid),
+	(Codepoints::Single(0x0375), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x03C2), DerivedPropertyValue::PValid),
+	(Codepoints::Single(0x05F3), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x05F4), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x0640), DerivedPropertyValue::Disallowed),
+	(Codepoints::Single(0x0660), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x0661), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x0662), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x0663), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x0664), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x0665), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x0666), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x0667), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x0668), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x0669), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x06F0), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x06F1), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x06F2), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x06F3), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x06F4), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x06F5), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x06F6), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x06F7), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x06F8), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x06F9), DerivedPropertyValue::ContextO),
+	(Codepoints::Single(0x06FD), DerivedPropertyValue::PValid),
+	(Codepoints::Single(0x06FE), DerivedPropertyValue::PValid),
+	(Codepoints::Single(0x07FA), DerivedPropertyValue::Disallowed),
+	(Codepoints::Single(0x0F0B), DerivedPropertyValue::PValid),
+	(Codepoints::Single(0x3007), DerivedPropertyValue::PValid),
+	(Codepoints::Single(0x302E), DerivedPropertyValue::Disallowed),
+	(Codepoints::Single(0x302F), DerivedPropertyValue::Disallowed),
+	(Codepoints::Single(0x3031), DerivedPropertyValue::Disallowed),
+	(Codepoints::Single(0x3032), DerivedPropertyValue::Disallowed),
+	(Codepoints::Single(0x3033), DerivedPropertyValue::Disallowed),
+	(Codepoints::Single(0x3034), DerivedPropertyValue::Disallowed),
+	(Codepoints::Single(0x3035), DerivedPropertyValue::Disallowed),
+	(Codepoints::Single(0x303B), DerivedPropertyValue::Disallowed),
+	(Codepoints::Single(0x30FB), DerivedPropertyValue::ContextO),
+];
+
+const BACKWARD_COMPATIBLE: &[(Codepoints, DerivedPropertyValue)] = &[
+];
+
+// Encoded as ranges: ranges 8 bytes, bitset 16 bytes, trie 28 bytes
+const ASCII7: CodepointSet = CodepointSet::Ranges(&[
+	(0x0021, 0x007e),
+]);
+
+// Encoded as trie: ranges 4888 bytes, bitset 15088 bytes, trie 4272 bytes
+const LOWERCASE_LETTER: CodepointSet = CodepointSet::Trie {
+	index: &[
+		0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 0, 0, 11, 12, 13,
+		14, 15, 16, 17, 18, 19, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 21, 22, 23, 0, 24, 24, 25, 24, 26, 27, 28, 29,
+		0, 0, 0, 0, 30, 31, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		14, 33, 24, 34, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 37, 0, 38, 39, 40, 41,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		44, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61,
+	],
+	leaves: &[
+		0x0000000000000000, 0x07fffffe00000000, 0x0020000000000000, 0xff7fffff80000000,
+		0x55aaaaaaaaaaaaaa, 0xd4aaaaaaaaaaab55, 0xe6512d2a4e243129, 0xaa29aaaab5555240,
+		0x93faaaaaaaaaaaaa, 0xffffffffffffaa85, 0x0000ffffffefffff, 0x388a000000000000,
+		0xfffff00000010000, 0x192faaaaaae37fff, 0xffff000000000000, 0xaaaaaaaaffffffff,
+		0xaaaaaaaaaaaaa802, 0xaaaaaaaaaaaad554, 0x000000aaaaaaaaaa, 0xfffffffe00000000,
+		0x00000000000000ff, 0x00000fffffffffff, 0xfefff80000000000, 0x0000000007ffffff,
+		0xaaaaaaaaaaaaaaaa, 0xaaaaaaaabfeaaaaa, 0x00ff00ff003f00ff, 0x3fff00ff00ff003f,
+		0x40df00ff00ff00ff, 0x00dc00ff00cf00dc, 0x321080000008c400, 0x00000000000043c0,
+		0x0000000000000010, 0x0fda15627fffffff, 0x0008501aaaaaaaaa, 0x000020bfffffffff,
+		0x00002aaaaaaaaaaa, 0x0000000000aaaaaa, 0xaaabaaa800000000, 0x95feaaaaaaaaaaaa,
+		0x000002aa000a50aa, 0x0400000000000000, 0x0000000000f8007f, 0x0000000007fffffe,
+		0xffffff0000000000, 0x000000000000ffff, 0x000ffffffc000000, 0x000000ffffdfc000,
+		0xebc000000ffffffc, 0xfffffc000000ffef, 0x00ffffffc000000f, 0x00000ffffffc0000,
+		0xfc000000ffffffc0, 0xffffc000000fffff, 0x0ffffffc000000ff, 0x0000ffffffc00000,
+		0x0000003ffffffc00, 0xf0000003f7fffffc, 0xffc000000fdfffff, 0xffff0000003f7fff,
+		0xfffffc000000fdff, 0x0000000000000bf7,
+	],
+};
+
+// Encoded as trie: ranges 4864 bytes, bitset 15096 bytes, trie 4216 bytes
+const UPPERCASE_LETTER: CodepointSet = CodepointSet::Trie {
+	index: &[
+		0, 1, 0, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 9, 10, 11,
+		12, 13, 14, 15, 16, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 18, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 20, 20, 21, 20, 22, 23, 24, 25,
+		0, 0, 0, 0, 26, 27, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		29, 30, 20, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 33, 0, 34, 35, 36, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+		39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54,
+	],
+	leaves: &[
+		0x0000000000000000, 0x0000000007fffffe, 0x000000007f7fffff, 0xaa55555555555555,
+		0x2b555555555554aa, 0x11aed2d5b1dbced6, 0x55d255554aaaa490, 0x6c05555555555555,
+		0x000000000000557a, 0x0045000000000000, 0x00000ffbfffed740, 0xe6905555551c8000,
+		0x0000ffffffffffff, 0x5555555500000000, 0x5555555555555401, 0x5555555555552aab,
+		0xfffe005555555555, 0x00000000007fffff, 0xffffffff00000000, 0x00000000000020bf,
+		0x5555555555555555, 0x5555555540155555, 0xff00ff003f00ff00, 0x0000ff00aa003f00,
+		0x0f00000000000000, 0x0f001f000f000f00, 0xc00f3d503e273884, 0x0000000000000020,
+		0x0000000000000008, 0x00007fffffffffff, 0xc025ea9d00000000, 0x0004280555555555,
+		0x0000155555555555, 0x0000000000555555, 0x5554555400000000, 0x6a00555555555555,
+		0x0000055500052855, 0x07fffffe00000000, 0x000000ffffffffff, 0xfff0000003ffffff,
+		0xffffff0000003fff, 0x003fde64d0000003, 0x000003ffffff0000, 0x7b0000001fdfe7b0,
+		0xfffff0000001fc5f, 0x03ffffff0000003f, 0x00003ffffff00000, 0xf0000003ffffff00,
+		0xffff0000003fffff, 0xffffff00000003ff, 0x07fffffc00000001, 0x001ffffff0000000,
+		0x00007fffffc00000, 0x000001ffffff0000, 0x0000000000000400,
+	],
+};
+
+// Encoded as ranges: ranges 2968 bytes, bitset 24368 bytes, trie 7330 bytes
+const OTHER_LETTER: CodepointSet = CodepointSet::Ranges(&[
+	(0x00aa, 0x00aa),
+	(0x00ba, 0x00ba),
+	(0x01bb, 0x01bb),
+	(0x01c0, 0x01c3),
+	(0x0294, 0x0294),
+	(0x05d0, 0x05ea),
+	(0x05f0, 0x05f2),
+	(0x0620, 0x063f),
+	(0x0641, 0x064a),
+	(0x066e, 0x066f),
+	(0x0671, 0x06d3),
+	(0x06d5, 0x06d5),
+	(0x06ee, 0x06ef),
+	(0x06fa, 0x06fc),
+	(0x06ff, 0x06ff),
+	(0x0710, 0x0710),
+	(0x0712, 0x072f),
+	(0x074d, 0x07a5),
+	(0x07b1, 0x07b1),
+	(0x07ca, 0x07ea),
+	(0x0800, 0x0815),
+	(0x0840, 0x0858),
+	(0x08a0, 0x08a0),
+	(0x08a2, 0x08ac),
+	(0x0904, 0x0939),
+	(0x093d, 0x093d),
+	(0x0950, 0x0950),
+	(0x0958, 0x0961),
+	(0x0972, 0x0977),
+	(0x0979, 0x097f),
+	(0x0985, 0x098c),
+	(0x098f, 0x0990),
+	(0x0993, 0x09a8),
+	(0x09aa, 0x09b0),
+	(0x09b2, 0x09b2),
+	(0x09b6, 0x09b9),
+	(0x09bd, 0x09bd),
+	(0x09ce, 0x09ce),
+	(0x09dc, 0x09dd),
+	(0x09df, 0x09e1),
+	(0x09f0, 0x09f1),
+	(0x0a05, 0x0a0a),
+	(0x0a0f, 0x0a10),
+	(0x0a13, 0x0a28),
+	(0x0a2a, 0x0a30),
+	(0x0a32, 0x0a33),
+	(0x0a35, 0x0a36),
+	(0x0a38, 0x0a39),
+	(0x0a59, 0x0a5c),
+	(0x0a5e, 0x0a5e),
+	(0x0a72, 0x0a74),
+	(0x0a85, 0x0a8d),
+	(0x0a8f, 0x0a91),
+	(0x0a93, 0x0aa8),
+	(0x0aaa, 0x0ab0),
+	(0x0ab2, 0x0ab3),
+	(0x0ab5, 0x0ab9),
+	(0x0abd, 0x0abd),
+	(0x0ad0, 0x0ad0),
+	(0x0ae0, 0x0ae1),
+	(0x0b05, 0x0b0c),
+	(0x0b0f, 0x0b10),
+	(0x0b13, 0x0b28),
+	(0x0b2a, 0x0b30),
+	(0x0b32, 0x0b33),
+	(0x0b35, 0x0b39),
+	(0x0b3d, 0x0b3d),
+	(0x0b5c, 0x0b5d),
+	(0x0b5f, 0x0b61),
+	(0x0b71, 0x0b71),
+	(0x0b83, 0x0b83),
+	(0x0b85, 0x0b8a),
+	(0x0b8e, 0x0b90),
+	(0x0b92, 0x0b95),
+	(0x0b99, 0x0b9a),
+	(0x0b9c, 0x0b9c),
+	(0x0b9e, 0x0b9f),
+	(0x0ba3, 0x0ba4),
+	(0x0ba8, 0x0baa),
+	(0x0bae, 0x0bb9),
+	(0x0bd0, 0x0bd0),
+	(0x0c05, 0x0c0c),
+	(0x0c0e, 0x0c10),
+	(0x0c12, 0x0c28),
+	(0x0c2a, 0x0c33),
+	(0x0c35, 0x0c39),
+	(0x0c3d, 0x0c3d),
+	(0x0c58, 0x0c59),
+	(0x0c60, 0x0c61),
+	(0x0c85, 0x0c8c),
+	(0x0c8e, 0x0c90),
+	(0x0c92, 0x0ca8),
+	(0x0caa, 0x0cb3),
+	(0x0cb5, 0x0cb9),
+	(0x0cbd, 0x0cbd),
+	(0x0cde, 0x0cde),
+	(0x0ce0, 0x0ce1),
+	(0x0cf1, 0x0cf2),
+	(0x0d05, 0x0d0c),
+	(0x0d0e, 0x0d10),
+	(0x0d12, 0x0d3a),
+	(0x0d3d, 0x0d3d),
+	(0x0d4e, 0x0d4e),
+	(0x0d60, 0x0d61),
+	(0x0d7a, 0x0d7f),
+	(0x0d85, 0x0d96),
+	(0x0d9a, 0x0db1),
+	(0x0db3, 0x0dbb),
+	(0x0dbd, 0x0dbd),
+	(0x0dc0, 0x0dc6),
+	(0x0e01, 0x0e30),
+	(0x0e32, 0x0e33),
+	(0x0e40, 0x0e45),
+	(0x0e81, 0x0e82),
+	(0x0e84, 0x0e84),
+	(0x0e87, 0x0e88),
+	(0x0e8a, 0x0e8a),
+	(0x0e8d, 0x0e8d),
+	(0x0e94, 0x0e97),
+	(0x0e99, 0x0e9f),
+	(0x0ea1, 0x0ea3),
+	(0x0ea5, 0x0ea5),
+	(0x0ea7, 0x0ea7),
+	(0x0eaa, 0x0eab),
+	(0x0ead, 0x0eb0),
+	(0x0eb2, 0x0eb3),
+	(0x0ebd, 0x0ebd),
+	(0x0ec0, 0x0ec4),
+	(0x0edc, 0x0edf),
+	(0x0f00, 0x0f00),
+	(0x0f40, 0x0f47),
+	(0x0f49, 0x0f6c),
+	(0x0f88, 0x0f8c),
+	(0x1000, 0x102a),
+	(0x103f, 0x103f),
+	(0x1050, 0x1055),
+	(0x105a, 0x105d),
+	(0x1061, 0x1061),
+	(0x1065, 0x1066),
+	(0x106e, 0x1070),
+	(0x1075, 0x1081),
+	(0x108e, 0x108e),
+	(0x10d0, 0x10fa),
+	(0x10fd, 0x1248),
+	(0x124a, 0x124d),
+	(0x1250, 0x1256),
+	(0x1258, 0x1258),
+	(0x125a, 0x125d),
+	(0x1260, 0x1288),
+	(0x128a, 0x128d),
+	(0x1290, 0x12b0),
+	(0x12b2, 0x12b5),
+	(0x12b8, 0x12be),
+	(0x12c0, 0x12c0),
+	(0x12c2, 0x12c5),
+	(0x12c8, 0x12d6),
+	(0x12d8, 0x1310),
+	(0x1312, 0x1315),
+	(0x1318, 0x135a),
+	(0x1380, 0x138f),
+	(0x13a0, 0x13f4),
+	(0x1401, 0x166c),
+	(0x166f, 0x167f),
+	(0x1681, 0x169a),
+	(0x16a0, 0x16ea),
+	(0x1700, 0x170c),
+	(0x170e, 0x1711),
+	(0x1720, 0x1731),
+	(0x1740, 0x1751),
+	(0x1760, 0x176c),
+	(0x176e, 0x1770),
+	(0x1780, 0x17b3),
+	(0x17dc, 0x17dc),
+	(0x1820, 0x1842),
+	(0x1844, 0x1877),
+	(0x1880, 0x18a8),
+	(0x18aa, 0x18aa),
+	(0x18b0, 0x18f5),
+	(0x1900, 0x191c),
+	(0x1950, 0x196d),
+	(0x1970, 0x1974),
+	(0x1980, 0x19ab),
+	(0x19c1, 0x19c7),
+	(0x1a00, 0x1a16),
+	(0x1a20, 0x1a54),
+	(0x1b05, 0x1b33),
+	(0x1b45, 0x1b4b),
+	(0x1b83, 0x1ba0),
+	(0x1bae, 0x1baf),
+	(0x1bba, 0x1be5),
+	(0x1c00, 0x1c23),
+	(0x1c4d, 0x1c4f),
+	(0x1c5a, 0x1c77),
+	(0x1ce9, 0x1cec),
+	(0x1cee, 0x1cf1),
+	(0x1cf5, 0x1cf6),
+	(0x2135, 0x2138),
+	(0x2d30, 0x2d67),
+	(0x2d80, 0x2d96),
+	(0x2da0, 0x2da6),
+	(0x2da8, 0x2dae),
+	(0x2db0, 0x2db6),
+	(0x2db8, 0x2dbe),
+	(0x2dc0, 0x2dc6),
+	(0x2dc8, 0x2dce),
+	(0x2dd0, 0x2dd6),
+	(0x2dd8, 0x2dde),
+	(0x3006, 0x3006),
+	(0x303c, 0x303c),
+	(0x3041, 0x3096),
+	(0x309f, 0x309f),
+	(0x30a1, 0x30fa),
+	(0x30ff, 0x30ff),
+	(0x3105, 0x312d),
+	(0x3131, 0x318e),
+	(0x31a0, 0x31ba),
+	(0x31f0, 0x31ff),
+	(0x3400, 0x4db5),
+	(0x4e00, 0x9fcc),
+	(0xa000, 0xa014),
+	(0xa016, 0xa48c),
+	(0xa4d0, 0xa4f7),
+	(0xa500, 0xa60b),
+	(0xa610, 0xa61f),
+	(0xa62a, 0xa62b),
+	(0xa66e, 0xa66e),
+	(0xa6a0, 0xa6e5),
+	(0xa7fb, 0xa801),
+	(0xa803, 0xa805),
+	(0xa807, 0xa80a),
+	(0xa80c, 0xa822),
+	(0xa840, 0xa873),
+	(0xa882, 0xa8b3),
+	(0xa8f2, 0xa8f7),
+	(0xa8fb, 0xa8fb),
+	(0xa90a, 0xa925),
+	(0xa930, 0xa946),
+	(0xa960, 0xa97c),
+	(0xa984, 0xa9b2),
+	(0xaa00, 0xaa28),
+	(0xaa40, 0xaa42),
+	(0xaa44, 0xaa4b),
+	(0xaa60, 0xaa6f),
+	(0xaa71, 0xaa76),
+	(0xaa7a, 0xaa7a),
+	(0xaa80, 0xaaaf),
+	(0xaab1, 0xaab1),
+	(0xaab5, 0xaab6),
+	(0xaab9, 0xaabd),
+	(0xaac0, 0xaac0),
+	(0xaac2, 0xaac2),
+	(0xaadb, 0xaadc),
+	(0xaae0, 0xaaea),
+	(0xaaf2, 0xaaf2),
+	(0xab01, 0xab06),
+	(0xab09, 0xab0e),
+	(0xab11, 0xab16),
+	(0xab20, 0xab26),
+	(0xab28, 0xab2e),
+	(0xabc0, 0xabe2),
+	(0xac00, 0xd7a3),
+	(0xd7b0, 0xd7c6),
+	(0xd7cb, 0xd7fb),
+	(0xf900, 0xfa6d),
+	(0xfa70, 0xfad9),
+	(0xfb1d, 0xfb1d),
+	(0xfb1f, 0xfb28),
+	(0xfb2a, 0xfb36),
+	(0xfb38, 0xfb3c),
+	(0xfb3e, 0xfb3e),
+	(0xfb40, 0xfb41),
+	(0xfb43, 0xfb44),
+	(0xfb46, 0xfbb1),
+	(0xfbd3, 0xfd3d),
+	(0xfd50, 0xfd8f),
+	(0xfd92, 0xfdc7),
+	(0xfdf0, 0xfdfb),
+	(0xfe70, 0xfe74),
+	(0xfe76, 0xfefc),
+	(0xff66, 0xff6f),
+	(0xff71, 0xff9d),
+	(0xffa0, 0xffbe),
+	(0xffc2, 0xffc7),
+	(0xffca, 0xffcf),
+	(0xffd2, 0xffd7),
+	(0xffda, 0xffdc),
+	(0x10000, 0x1000b),
+	(0x1000d, 0x10026),
+	(0x10028, 0x1003a),
+	(0x1003c, 0x1003d),
+	(0x1003f, 0x1004d),
+	(0x10050, 0x1005d),
+	(0x10080, 0x100fa),
+	(0x10280, 0x1029c),
+	(0x102a0, 0x102d0),
+	(0x10300, 0x1031e),
+	(0x10330, 0x10340),
+	(0x10342, 0x10349),
+	(0x10380, 0x1039d),
+	(0x103a0, 0x103c3),
+	(0x103c8, 0x103cf),
+	(0x10450, 0x1049d),
+	(0x10800, 0x10805),
+	(0x10808, 0x10808),
+	(0x1080a, 0x10835),
+	(0x10837, 0x10838),
+	(0x1083c, 0x1083c),
+	(0x1083f, 0x10855),
+	(0x10900, 0x10915),
+	(0x10920, 0x10939),
+	(0x10980, 0x109b7),
+	(0x109be, 0x109bf),
+	(0x10a00, 0x10a00),
+	(0x10a10, 0x10a13),
+	(0x10a15, 0x10a17),
+	(0x10a19, 0x10a33),
+	(0x10a60, 0x10a7c),
+	(0x10b00, 0x10b35),
+	(0x10b40, 0x10b55),
+	(0x10b60, 0x10b72),
+	(0x10c00, 0x10c48),
+	(0x11003, 0x11037),
+	(0x11083, 0x110af),
+	(0x110d0, 0x110e8),
+	(0x11103, 0x11126),
+	(0x11183, 0x111b2),
+	(0x111c1, 0x111c4),
+	(0x11680, 0x116aa),
+	(0x12000, 0x1236e),
+	(0x13000, 0x1342e),
+	(0x16800, 0x16a38),
+	(0x16f00, 0x16f44),
+	(0x16f50, 0x16f50),
+	(0x1b000, 0x1b001),
+	(0x1ee00, 0x1ee03),
+	(0x1ee05, 0x1ee1f),
+	(0x1ee21, 0x1ee22),
+	(0x1ee24, 0x1ee24),
+	(0x1ee27, 0x1ee27),
+	(0x1ee29, 0x1ee32),
+	(0x1ee34, 0x1ee37),
+	(0x1ee39, 0x1ee39),
+	(0x1ee3b, 0x1ee3b),
+	(0x1ee42, 0x1ee42),
+	(0x1ee47, 0x1ee47),
+	(0x1ee49, 0x1ee49),
+	(0x1ee4b, 0x1ee4b),
+	(0x1ee4d, 0x1ee4f),
+	(0x1ee51, 0x1ee52),
+	(0x1ee54, 0x1ee54),
+	(0x1ee57, 0x1ee57),
+	(0x1ee59, 0x1ee59),
+	(0x1ee5b, 0x1ee5b),
+	(0x1ee5d, 0x1ee5d),
+	(0x1ee5f, 0x1ee5f),
+	(0x1ee61, 0x1ee62),
+	(0x1ee64, 0x1ee64),
+	(0x1ee67, 0x1ee6a),
+	(0x1ee6c, 0x1ee72),
+	(0x1ee74, 0x1ee77),
+	(0x1ee79, 0x1ee7c),
+	(0x1ee7e, 0x1ee7e),
+	(0x1ee80, 0x1ee89),
+	(0x1ee8b, 0x1ee9b),
+	(0x1eea1, 0x1eea3),
+	(0x1eea5, 0x1eea9),
+	(0x1eeab, 0x1eebb),
+	(0x20000, 0x2a6d6),
+	(0x2a700, 0x2b734),
+	(0x2b740, 0x2b81d),
+	(0x2f800, 0x2fa1d),
+]);
+
+// Encoded as ranges: ranges 336 bytes, bitset 15104 bytes, trie 3856 bytes
+const DECIMAL_NUMBER: CodepointSet = CodepointSet::Ranges(&[
+	(0x0030, 0x0039),
+	(0x0660, 0x0669),
+	(0x06f0, 0x06f9),
+	(0x07c0, 0x07c9),
+	(0x0966, 0x096f),
+	(0x09e6, 0x09ef),
+	(0x0a66, 0x0a6f),
+	(0x0ae6, 0x0aef),
+	(0x0b66, 0x0b6f),
+	(0x0be6, 0x0bef),
+	(0x0c66, 0x0c6f),
+	(0x0ce6, 0x0cef),
+	(0x0d66, 0x0d6f),
+	(0x0e50, 0x0e59),
+	(0x0ed0, 0x0ed9),
+	(0x0f20, 0x0f29),
+	(0x1040, 0x1049),
+	(0x1090, 0x1099),
+	(0x17e0, 0x17e9),
+	(0x1810, 0x1819),
+	(0x1946, 0x194f),
+	(0x19d0, 0x19d9),
+	(0x1a80, 0x1a89),
+	(0x1a90, 0x1a99),
+	(0x1b50, 0x1b59),
+	(0x1bb0, 0x1bb9),
+	(0x1c40, 0x1c49),
+	(0x1c50, 0x1c59),
+	(0xa620, 0xa629),
+	(0xa8d0, 0xa8d9),
+	(0xa900, 0xa909),
+	(0xa9d0, 0xa9d9),
+	(0xaa50, 0xaa59),
+	(0xabf0, 0xabf9),
+	(0xff10, 0xff19),
+	(0x104a0, 0x104a9),
+	(0x11066, 0x1106f),
+	(0x110f0, 0x110f9),
+	(0x11136, 0x1113f),
+	(0x111d0, 0x111d9),
+	(0x116c0, 0x116c9),
+	(0x1d7ce, 0x1d7ff),
+]);
+
+// Encoded as ranges: ranges 416 bytes, bitset 11680 bytes, trie 3238 bytes
+const MODIFIER_LETTER: CodepointSet = CodepointSet::Ranges(&[
+	(0x02b0, 0x02c1),
+	(0x02c6, 0x02d1),
+	(0x02e0, 0x02e4),
+	(0x02ec, 0x02ec),
+	(0x02ee, 0x02ee),
+	(0x0374, 0x0374),
+	(0x037a, 0x037a),
+	(0x0559, 0x0559),
+	(0x0640, 0x0640),
+	(0x06e5, 0x06e6),
+	(0x07f4, 0x07f5),
+	(0x07fa, 0x07fa),
+	(0x081a, 0x081a),
+	(0x0824, 0x0824),
+	(0x0828, 0x0828),
+	(0x0971, 0x0971),
+	(0x0e46, 0x0e46),
+	(0x0ec6, 0x0ec6),
+	(0x10fc, 0x10fc),
+	(0x17d7, 0x17d7),
+	(0x1843, 0x1843),
+	(0x1aa7, 0x1aa7),
+	(0x1c78, 0x1c7d),
+	(0x1d2c, 0x1d6a),
+	(0x1d78, 0x1d78),
+	(0x1d9b, 0x1dbf),
+	(0x2071, 0x2071),
+	(0x207f, 0x207f),
+	(0x2090, 0x209c),
+	(0x2c7c, 0x2c7d),
+	(0x2d6f, 0x2d6f),
+	(0x2e2f, 0x2e2f),
+	(0x3005, 0x3005),
+	(0x3031, 0x3035),
+	(0x303b, 0x303b),
+	(0x309d, 0x309e),
+	(0x30fc, 0x30fe),
+	(0xa015, 0xa015),
+	(0xa4f8, 0xa4fd),
+	(0xa60c, 0xa60c),
+	(0xa67f, 0xa67f),
+	(0xa717, 0xa71f),
+	(0xa770, 0xa770),
+	(0xa788, 0xa788),
+	(0xa7f8, 0xa7f9),
+	(0xa9cf, 0xa9cf),
+	(0xaa70, 0xaa70),
+	(0xaadd, 0xaadd),
+	(0xaaf3, 0xaaf4),
+	(0xff70, 0xff70),
+	(0xff9e, 0xff9f),
+	(0x16f93, 0x16f9f),
+]);
+
+// Encoded as ranges: ranges 1768 bytes, bitset 114656 bytes, trie 29432 bytes
+const NONSPACING_MARK: CodepointSet = CodepointSet::Ranges(&[
+	(0x0300, 0x036f),
+	(0x0483, 0x0487),
+	(0x0591, 0x05bd),
+	(0x05bf, 0x05bf),
+	(0x05c1, 0x05c2),
+	(0x05c4, 0x05c5),
+	(0x05c7, 0x05c7),
+	(0x0610, 0x061a),
+	(0x064b, 0x065f),
+	(0x0670, 0x0670),
+	(0x06d6, 0x06dc),
+	(0x06df, 0x06e4),
+	(0x06e7, 0x06e8),
+	(0x06ea, 0x06ed),
+	(0x0711, 0x0711),
+	(0x0730, 0x074a),
+	(0x07a6, 0x07b0),
+	(0x07eb, 0x07f3),
+	(0x0816, 0x0819),
+	(0x081b, 0x0823),
+	(0x0825, 0x0827),
+	(0x0829, 0x082d),
+	(0x0859, 0x085b),
+	(0x08e4, 0x08fe),
+	(0x0900, 0x0902),
+	(0x093a, 0x093a),
+	(0x093c, 0x093c),
+	(0x0941, 0x0948),
+	(0x094d, 0x094d),
+	(0x0951, 0x0957),
+	(0x0962, 0x0963),
+	(0x0981, 0x0981),
+	(0x09bc, 0x09bc),
+	(0x09c1, 0x09c4),
+	(0x09cd, 0x09cd),
+	(0x09e2, 0x09e3),
+	(0x0a01, 0x0a02),
+	(0x0a3c, 0x0a3c),
+	(0x0a41, 0x0a42),
+	(0x0a47, 0x0a48),
+	(0x0a4b, 0x0a4d),
+	(0x0a51, 0x0a51),
+	(0x0a70, 0x0a71),
+	(0x0a75, 0x0a75),
+	(0x0a81, 0x0a82),
+	(0x0abc, 0x0abc),
+	(0x0ac1, 0x0ac5),
+	(0x0ac7, 0x0ac8),
+	(0x0acd, 0x0acd),
+	(0x0ae2, 0x0ae3),
+	(0x0b01, 0x0b01),
+	(0x0b3c, 0x0b3c),
+	(0x0b3f, 0x0b3f),
+	(0x0b41, 0x0b44),
+	(0x0b4d, 0x0b4d),
+	(0x0b56, 0x0b56),
+	(0x0b62, 0x0b63),
+	(0x0b82, 0x0b82),
+	(0x0bc0, 0x0bc0),
+	(0x0bcd, 0x0bcd),
+	(0x0c3e, 0x0c40),
+	(0x0c46, 0x0c48),
+	(0x0c4a, 0x0c4d),
+	(0x0c55, 0x0c56),
+	(0x0c62, 0x0c63),
+	(0x0cbc, 0x0cbc),
+	(0x0cbf, 0x0cbf),
+	(0x0cc6, 0x0cc6),
+	(0x0ccc, 0x0ccd),
+	(0x0ce2, 0x0ce3),
+	(0x0d41, 0x0d44),
+	(0x0d4d, 0x0d4d),
+	(0x0d62, 0x0d63),
+	(0x0dca, 0x0dca),
+	(0x0dd2, 0x0dd4),
+	(0x0dd6, 0x0dd6),
+	(0x0e31, 0x0e31),
+	(0x0e34, 0x0e3a),
+	(0x0e47, 0x0e4e),
+	(0x0eb1, 0x0eb1),
+	(0x0eb4, 0x0eb9),
+	(0x0ebb, 0x0ebc),
+	(0x0ec8, 0x0ecd),
+	(0x0f18, 0x0f19),
+	(0x0f35, 0x0f35),
+	(0x0f37, 0x0f37),
+	(0x0f39, 0x0f39),
+	(0x0f71, 0x0f7e),
+	(0x0f80, 0x0f84),
+	(0x0f86, 0x0f87),
+	(0x0f8d, 0x0f97),
+	(0x0f99, 0x0fbc),
+	(0x0fc6, 0x0fc6),
+	(0x102d, 0x1030),
+	(0x1032, 0x1037),
+	(0x1039, 0x103a),
+	(0x103d, 0x103e),
+	(0x1058, 0x1059),
+	(0x105e, 0x1060),
+	(0x1071, 0x1074),
+	(0x1082, 0x1082),
+	(0x1085, 0x1086),
+	(0x108d, 0x108d),
+	(0x109d, 0x109d),
+	(0x135d, 0x135f),
+	(0x1712, 0x1714),
+	(0x1732, 0x1734),
+	(0x1752, 0x1753),
+	(0x1772, 0x1773),
+	(0x17b4, 0x17b5),
+	(0x17b7, 0x17bd),
+	(0x17c6, 0x17c6),
+	(0x17c9, 0x17d3),
+	(0x17dd, 0x17dd),
+	(0x180b, 0x180d),
+	(0x18a9, 0x18a9),
+	(0x1920, 0x1922),
+	(0x1927, 0x1928),
+	(0x1932, 0x1932),
+	(0x1939, 0x193b),
+	(0x1a17, 0x1a18),
+	(0x1a1b, 0x1a1b),
+	(0x1a56, 0x1a56),
+	(0x1a58, 0x1a5e),
+	(0x1a60, 0x1a60),
+	(0x1a62, 0x1a62),
+	(0x1a65, 0x1a6c),
+	(0x1a73, 0x1a7c),
+	(0x1a7f, 0x1a7f),
+	(0x1b00, 0x1b03),
+	(0x1b34, 0x1b34),
+	(0x1b36, 0x1b3a),
+	(0x1b3c, 0x1b3c),
+	(0x1b42, 0x1b42),
+	(0x1b6b, 0x1b73),
+	(0x1b80, 0x1b81),
+	(0x1ba2, 0x1ba5),
+	(0x1ba8, 0x1ba9),
+	(0x1bab, 0x1bab),
+	(0x1be6, 0x1be6),
+	(0x1be8, 0x1be9),
+	(0x1bed, 0x1bed),
+	(0x1bef, 0x1bf1),
+	(0x1c2c, 0x1c33),
+	(0x1c36, 0x1c37),
+	(0x1cd0, 0x1cd2),
+	(0x1cd4, 0x1ce0),
+	(0x1ce2, 0x1ce8),
+	(0x1ced, 0x1ced),
+	(0x1cf4, 0x1cf4),
+	(0x1dc0, 0x1de6),
+	(0x1dfc, 0x1dff),
+	(0x20d0, 0x20dc),
+	(0x20e1, 0x20e1),
+	(0x20e5, 0x20f0),
+	(0x2cef, 0x2cf1),
+	(0x2d7f, 0x2d7f),
+	(0x2de0, 0x2dff),
+	(0x302a, 0x302d),
+	(0x3099, 0x309a),
+	(0xa66f, 0xa66f),
+	(0xa674, 0xa67d),
+	(0xa69f, 0xa69f),
+	(0xa6f0, 0xa6f1),
+	(0xa802, 0xa802),
+	(0xa806, 0xa806),
+	(0xa80b, 0xa80b),
+	(0xa825, 0xa826),
+	(0xa8c4, 0xa8c4),
+	(0xa8e0, 0xa8f1),
+	(0xa926, 0xa92d),
+	(0xa947, 0xa951),
+	(0xa980, 0xa982),
+	(0xa9b3, 0xa9b3),
+	(0xa9b6, 0xa9b9),
+	(0xa9bc, 0xa9bc),
+	(0xaa29, 0xaa2e),
+	(0xaa31, 0xaa32),
+	(0xaa35, 0xaa36),
+	(0xaa43, 0xaa43),
+	(0xaa4c, 0xaa4c),
+	(0xaab0, 0xaab0),
+	(0xaab2, 0xaab4),
+	(0xaab7, 0xaab8),
+	(0xaabe, 0xaabf),
+	(0xaac1, 0xaac1),
+	(0xaaec, 0xaaed),
+	(0xaaf6, 0xaaf6),
+	(0xabe5, 0xabe5),
+	(0xabe8, 0xabe8),
+	(0xabed, 0xabed),
+	(0xfb1e, 0xfb1e),
+	(0xfe00, 0xfe0f),
+	(0xfe20, 0xfe26),
+	(0x101fd, 0x101fd),
+	(0x10a01, 0x10a03),
+	(0x10a05, 0x10a06),
+	(0x10a0c, 0x10a0f),
+	(0x10a38, 0x10a3a),
+	(0x10a3f, 0x10a3f),
+	(0x11001, 0x11001),
+	(0x11038, 0x11046),
+	(0x11080, 0x11081),
+	(0x110b3, 0x110b6),
+	(0x110b9, 0x110ba),
+	(0x11100, 0x11102),
+	(0x11127, 0x1112b),
+	(0x1112d, 0x11134),
+	(0x11180, 0x11181),
+	(0x111b6, 0x111be),
+	(0x116ab, 0x116ab),
+	(0x116ad, 0x116ad),
+	(0x116b0, 0x116b5),
+	(0x116b7, 0x116b7),
+	(0x16f8f, 0x16f92),
+	(0x1d167, 0x1d169),
+	(0x1d17b, 0x1d182),
+	(0x1d185, 0x1d18b),
+	(0x1d1aa, 0x1d1ad),
+	(0x1d242, 0x1d244),
+	(0xe0100, 0xe01ef),
+]);
+
+// Encoded as ranges: ranges 1008 bytes, bitset 14608 bytes, trie 4132 bytes
+const SPACING_MARK: CodepointSet = CodepointSet::Ranges(&[
+	(0x0903, 0x0903),
+	(0x093b, 0x093b),
+	(0x093e, 0x0940),
+	(0x0949, 0x094c),
+	(0x094e, 0x094f),
+	(0x0982, 0x0983),
+	(0x09be, 0x09c0),
+	(0x09c7, 0x09c8),
+	(0x09cb, 0x09cc),
+	(0x09d7, 0x09d7),
+	(0x0a03, 0x0a03),
+	(0x0a3e, 0x0a40),
+	(0x0a83, 0x0a83),
+	(0x0abe, 0x0ac0),
+	(0x0ac9, 0x0ac9),
+	(0x0acb, 0x0acc),
+	(0x0b02, 0x0b03),
+	(0x0b3e, 0x0b3e),
+	(0x0b40, 0x0b40),
+	(0x0b47, 0x0b48),
+	(0x0b4b, 0x0b4c),
+	(0x0b57, 0x0b57),
+	(0x0bbe, 0x0bbf),
+	(0x0bc1, 0x0bc2),
+	(0x0bc6, 0x0bc8),
+	(0x0bca, 0x0bcc),
+	(0x0bd7, 0x0bd7),
+	(0x0c01, 0x0c03),
+	(0x0c41, 0x0c44),
+	(0x0c82, 0x0c83),
+	(0x0cbe, 0x0cbe),
+	(0x0cc0, 0x0cc4),
+	(0x0cc7, 0x0cc8),
+	(0x0cca, 0x0ccb),
+	(0x0cd5, 0x0cd6),
+	(0x0d02, 0x0d03),
+	(0x0d3e, 0x0d40),
+	(0x0d46, 0x0d48),
+	(0x0d4a, 0x0d4c),
+	(0x0d57, 0x0d57),
+	(0x0d82, 0x0d83),
+	(0x0dcf, 0x0dd1),
+	(0x0dd8, 0x0ddf),
+	(0x0df2, 0x0df3),
+	(0x0f3e, 0x0f3f),
+	(0x0f7f, 0x0f7f),
+	(0x102b, 0x102c),
+	(0x1031, 0x1031),
+	(0x1038, 0x1038),
+	(0x103b, 0x103c),
+	(0x1056, 0x1057),
+	(0x1062, 0x1064),
+	(0x1067, 0x106d),
+	(0x1083, 0x1084),
+	(0x1087, 0x108c),
+	(0x108f, 0x108f),
+	(0x109a, 0x109c),
+	(0x17b6, 0x17b6),
+	(0x17be, 0x17c5),
+	(0x17c7, 0x17c8),
+	(0x1923, 0x1926),
+	(0x1929, 0x192b),
+	(0x1930, 0x1931),
+	(0x1933, 0x1938),
+	(0x19b0, 0x19c0),
+	(0x19c8, 0x19c9),
+	(0x1a19, 0x1a1a),
+	(0x1a55, 0x1a55),
+	(0x1a57, 0x1a57),
+	(0x1a61, 0x1a61),
+	(0x1a63, 0x1a64),
+	(0x1a6d, 0x1a72),
+	(0x1b04, 0x1b04),
+	(0x1b35, 0x1b35),
+	(0x1b3b, 0x1b3b),
+	(0x1b3d, 0x1b41),
+	(0x1b43, 0x1b44),
+	(0x1b82, 0x1b82),
+	(0x1ba1, 0x1ba1),
+	(0x1ba6, 0x1ba7),
+	(0x1baa, 0x1baa),
+	(0x1bac, 0x1bad),
+	(0x1be7, 0x1be7),
+	(0x1bea, 0x1bec),
+	(0x1bee, 0x1bee),
+	(0x1bf2, 0x1bf3),
+	(0x1c24, 0x1c2b),
+	(0x1c34, 0x1c35),
+	(0x1ce1, 0x1ce1),
+	(0x1cf2, 0x1cf3),
+	(0x302e, 0x302f),
+	(0xa823, 0xa824),
+	(0xa827, 0xa827),
+	(0xa880, 0xa881),
+	(0xa8b4, 0xa8c3),
+	(0xa952, 0xa953),
+	(0xa983, 0xa983),
+	(0xa9b4, 0xa9b5),
+	(0xa9ba, 0xa9bb),
+	(0xa9bd, 0xa9c0),
+	(0xaa2f, 0xaa30),
+	(0xaa33, 0xaa34),
+	(0xaa4d, 0xaa4d),
+	(0xaa7b, 0xaa7b),
+	(0xaaeb, 0xaaeb),
+	(0xaaee, 0xaaef),
+	(0xaaf5, 0xaaf5),
+	(0xabe3, 0xabe4),
+	(0xabe6, 0xabe7),
+	(0xabe9, 0xabea),
+	(0xabec, 0xabec),
+	(0x11000, 0x11000),
+	(0x11002, 0x11002),
+	(0x11082, 0x11082),
+	(0x110b0, 0x110b2),
+	(0x110b7, 0x110b8),
+	(0x1112c, 0x1112c),
+	(0x11182, 0x11182),
+	(0x111b3, 0x111b5),
+	(0x111bf, 0x111c0),
+	(0x116ac, 0x116ac),
+	(0x116ae, 0x116af),
+	(0x116b6, 0x116b6),
+	(0x16f51, 0x16f7e),
+	(0x1d165, 0x1d166),
+	(0x1d16d, 0x1d172),
+]);
+
+// Encoded as ranges: ranges 4304 bytes, bitset 130968 bytes, trie 34344 bytes
+const UNASSIGNED: CodepointSet = CodepointSet::Ranges(&[
+	(0x0378, 0x0379),
+	(0x037f, 0x0383),
+	(0x038b, 0x038b),
+	(0x038d, 0x038d),
+	(0x03a2, 0x03a2),
+	(0x0528, 0x0530),
+	(0x0557, 0x0558),
+	(0x0560, 0x0560),
+	(0x0588, 0x0588),
+	(0x058b, 0x058e),
+	(0x0590, 0x0590),
+	(0x05c8, 0x05cf),
+	(0x05eb, 0x05ef),
+	(0x05f5, 0x05ff),
+	(0x0605, 0x0605),
+	(0x061d, 0x061d),
+	(0x070e, 0x070e),
+	(0x074b, 0x074c),
+	(0x07b2, 0x07bf),
+	(0x07fb, 0x07ff),
+	(0x082e, 0x082f),
+	(0x083f, 0x083f),
+	(0x085c, 0x085d),
+	(0x085f, 0x089f),
+	(0x08a1, 0x08a1),
+	(0x08ad, 0x08e3),
+	(0x08ff, 0x08ff),
+	(0x0978, 0x0978),
+	(0x0980, 0x0980),
+	(0x0984, 0x0984),
+	(0x098d, 0x098e),
+	(0x0991, 0x0992),
+	(0x09a9, 0x09a9),
+	(0x09b1, 0x09b1),
+	(0x09b3, 0x09b5),
+	(0x09ba, 0x09bb),
+	(0x09c5, 0x09c6),
+	(0x09c9, 0x09ca),
+	(0x09cf, 0x09d6),
+	(0x09d8, 0x09db),
+	(0x09de, 0x09de),
+	(0x09e4, 0x09e5),
+	(0x09fc, 0x0a00),
+	(0x0a04, 0x0a04),
+	(0x0a0b, 0x0a0e),
+	(0x0a11, 0x0a12),
+	(0x0a29, 0x0a29),
+	(0x0a31, 0x0a31),
+	(0x0a34, 0x0a34),
+	(0x0a37, 0x0a37),
+	(0x0a3a, 0x0a3b),
+	(0x0a3d, 0x0a3d),
+	(0x0a43, 0x0a46),
+	(0x0a49, 0x0a4a),
+	(0x0a4e, 0x0a50),
+	(0x0a52, 0x0a58),
+	(0x0a5d, 0x0a5d),
+	(0x0a5f, 0x0a65),
+	(0x0a76, 0x0a80),
+	(0x0a84, 0x0a84),
+	(0x0a8e, 0x0a8e),
+	(0x0a92, 0x0a92),
+	(0x0aa9, 0x0aa9),
+	(0x0ab1, 0x0ab1),
+	(0x0ab4, 0x0ab4),
+	(0x0aba, 0x0abb),
+	(0x0ac6, 0x0ac6),
+	(0x0aca, 0x0aca),
+	(0x0ace, 0x0acf),
+	(0x0ad1, 0x0adf),
+	(0x0ae4, 0x0ae5),
+	(0x0af2, 0x0b00),
+	(0x0b04, 0x0b04),
+	(0x0b0d, 0x0b0e),
+	(0x0b11, 0x0b12),
+	(0x0b29, 0x0b29),
+	(0x0b31, 0x0b31),
+	(0x0b34, 0x0b34),
+	(0x0b3a, 0x0b3b),
+	(0x0b45, 0x0b46),
+	(0x0b49, 0x0b4a),
+	(0x0b4e, 0x0b55),
+	(0x0b58, 0x0b5b),
+	(0x0b5e, 0x0b5e),
+	(0x0b64, 0x0b65),
+	(0x0b78, 0x0b81),
+	(0x0b84, 0x0b84),
+	(0x0b8b, 0x0b8d),
+	(0x0b91, 0x0b91),
+	(0x0b96, 0x0b98),
+	(0x0b9b, 0x0b9b),
+	(0x0b9d, 0x0b9d),
+	(0x0ba0, 0x0ba2),
+	(0x0ba5, 0x0ba7),
+	(0x0bab, 0x0bad),
+	(0x0bba, 0x0bbd),
+	(0x0bc3, 0x0bc5),
+	(0x0bc9, 0x0bc9),
+	(0x0bce, 0x0bcf),
+	(0x0bd1, 0x0bd6),
+	(0x0bd8, 0x0be5),
+	(0x0bfb, 0x0c00),
+	(0x0c04, 0x0c04),
+	(0x0c0d, 0x0c0d),
+	(0x0c11, 0x0c11),
+	(0x0c29, 0x0c29),
+	(0x0c34, 0x0c34),
+	(0x0c3a, 0x0c3c),
+	(0x0c45, 0x0c45),
+	(0x0c49, 0x0c49),
+	(0x0c4e, 0x0c54),
+	(0x0c57, 0x0c57),
+	(0x0c5a, 0x0c5f),
+	(0x0c64, 0x0c65),
+	(0x0c70, 0x0c77),
+	(0x0c80, 0x0c81),
+	(0x0c84, 0x0c84),
+	(0x0c8d, 0x0c8d),
+	(0x0c91, 0x0c91),
+	(0x0ca9, 0x0ca9),
+	(0x0cb4, 0x0cb4),
+	(0x0cba, 0x0cbb),
+	(0x0cc5, 0x0cc5),
+	(0x0cc9, 0x0cc9),
+	(0x0cce, 0x0cd4),
+	(0x0cd7, 0x0cdd),
+	(0x0cdf, 0x0cdf),
+	(0x0ce4, 0x0ce5),
+	(0x0cf0, 0x0cf0),
+	(0x0cf3, 0x0d01),
+	(0x0d04, 0x0d04),
+	(0x0d0d, 0x0d0d),
+	(0x0d11, 0x0d11),
+	(0x0d3b, 0x0d3c),
+	(0x0d45, 0x0d45),
+	(0x0d49, 0x0d49),
+	(0x0d4f, 0x0d56),
+	(0x0d58, 0x0d5f),
+	(0x0d64, 0x0d65),
+	(0x0d76, 0x0d78),
+	(0x0d80, 0x0d81),
+	(0x0d84, 0x0d84),
+	(0x0d97, 0x0d99),
+	(0x0db2, 0x0db2),
+	(0x0dbc, 0x0dbc),
+	(0x0dbe, 0x0dbf),
+	(0x0dc7, 0x0dc9),
+	(0x0dcb, 0x0dce),
+	(0x0dd5, 0x0dd5),
+	(0x0dd7, 0x0dd7),
+	(0x0de0, 0x0df1),
+	(0x0df5, 0x0e00),
+	(0x0e3b, 0x0e3e),
+	(0x0e5c, 0x0e80),
+	(0x0e83, 0x0e83),
+	(0x0e85, 0x0e86),
+	(0x0e89, 0x0e89),
+	(0x0e8b, 0x0e8c),
+	(0x0e8e, 0x0e93),
+	(0x0e98, 0x0e98),
+	(0x0ea0, 0x0ea0),
+	(0x0ea4, 0x0ea4),
+	(0x0ea6, 0x0ea6),
+	(0x0ea8, 0x0ea9),
+	(0x0eac, 0x0eac),
+	(0x0eba, 0x0eba),
+	(0x0ebe, 0x0ebf),
+	(0x0ec5, 0x0ec5),
+	(0x0ec7, 0x0ec7),
+	(0x0ece, 0x0ecf),
+	(0x0eda, 0x0edb),
+	(0x0ee0, 0x0eff),
+	(0x0f48, 0x0f48),
+	(0x0f6d, 0x0f70),
+	(0x0f98, 0x0f98),
+	(0x0fbd, 0x0fbd),
+	(0x0fcd, 0x0fcd),
+	(0x0fdb, 0x0fff),
+	(0x10c6, 0x10c6),
+	(0x10c8, 0x10cc),
+	(0x10ce, 0x10cf),
+	(0x1249, 0x1249),
+	(0x124e, 0x124f),
+	(0x1257, 0x1257),
+	(0x1259, 0x1259),
+	(0x125e, 0x125f),
+	(0x1289, 0x1289),
+	(0x128e, 0x128f),
+	(0x12b1, 0x12b1),
+	(0x12b6, 0x12b7),
+	(0x12bf, 0x12bf),
+	(0x12c1, 0x12c1),
+	(0x12c6, 0x12c7),
+	(0x12d7, 0x12d7),
+	(0x1311, 0x1311),
+	(0x1316, 0x1317),
+	(0x135b, 0x135c),
+	(0x137d, 0x137f),
+	(0x139a, 0x139f),
+	(0x13f5, 0x13ff),
+	(0x169d, 0x169f),
+	(0x16f1, 0x16ff),
+	(0x170d, 0x170d),
+	(0x1715, 0x171f),
+	(0x1737, 0x173f),
+	(0x1754, 0x175f),
+	(0x176d, 0x176d),
+	(0x1771, 0x1771),
+	(0x1774, 0x177f),
+	(0x17de, 0x17df),
+	(0x17ea, 0x17ef),
+	(0x17fa, 0x17ff),
+	(0x180f, 0x180f),
+	(0x181a, 0x181f),
+	(0x1878, 0x187f),
+	(0x18ab, 0x18af),
+	(0x18f6, 0x18ff),
+	(0x191d, 0x191f),
+	(0x192c, 0x192f),
+	(0x193c, 0x193f),
+	(0x1941, 0x1943),
+	(0x196e, 0x196f),
+	(0x1975, 0x197f),
+	(0x19ac, 0x19af),
+	(0x19ca, 0x19cf),
+	(0x19db, 0x19dd),
+	(0x1a1c, 0x1a1d),
+	(0x1a5f, 0x1a5f),
+	(0x1a7d, 0x1a7e),
+	(0x1a8a, 0x1a8f),
+	(0x1a9a, 0x1a9f),
+	(0x1aae, 0x1aff),
+	(0x1b4c, 0x1b4f),
+	(0x1b7d, 0x1b7f),
+	(0x1bf4, 0x1bfb),
+	(0x1c38, 0x1c3a),
+	(0x1c4a, 0x1c4c),
+	(0x1c80, 0x1cbf),
+	(0x1cc8, 0x1ccf),
+	(0x1cf7, 0x1cff),
+	(0x1de7, 0x1dfb),
+	(0x1f16, 0x1f17),
+	(0x1f1e, 0x1f1f),
+	(0x1f46, 0x1f47),
+	(0x1f4e, 0x1f4f),
+	(0x1f58, 0x1f58),
+	(0x1f5a, 0x1f5a),
+	(0x1f5c, 0x1f5c),
+	(0x1f5e, 0x1f5e),
+	(0x1f7e, 0x1f7f),
+	(0x1fb5, 0x1fb5),
+	(0x1fc5, 0x1fc5),
+	(0x1fd4, 0x1fd5),
+	(0x1fdc, 0x1fdc),
+	(0x1ff0, 0x1ff1),
+	(0x1ff5, 0x1ff5),
+	(0x1fff, 0x1fff),
+	(0x2065, 0x2065),
+	(0x2072, 0x2073),
+	(0x208f, 0x208f),
+	(0x209d, 0x209f),
+	(0x20bb, 0x20cf),
+	(0x20f1, 0x20ff),
+	(0x218a, 0x218f),
+	(0x23f4, 0x23ff),
+	(0x2427, 0x243f),
+	(0x244b, 0x245f),
+	(0x2700, 0x2700),
+	(0x2b4d, 0x2b4f),
+	(0x2b5a, 0x2bff),
+	(0x2c2f, 0x2c2f),
+	(0x2c5f, 0x2c5f),
+	(0x2cf4, 0x2cf8),
+	(0x2d26, 0x2d26),
+	(0x2d28, 0x2d2c),
+	(0x2d2e, 0x2d2f),
+	(0x2d68, 0x2d6e),
+	(0x2d71, 0x2d7e),
+	(0x2d97, 0x2d9f),
+	(0x2da7, 0x2da7),
+	(0x2daf, 0x2daf),
+	(0x2db7, 0x2db7),
+	(0x2dbf, 0x2dbf),
+	(0x2dc7, 0x2dc7),
+	(0x2dcf, 0x2dcf),
+	(0x2dd7, 0x2dd7),
+	(0x2ddf, 0x2ddf),
+	(0x2e3c, 0x2e7f),
+	(0x2e9a, 0x2e9a),
+	(0x2ef4, 0x2eff),
+	(0x2fd6, 0x2fef),
+	(0x2ffc, 0x2fff),
+	(0x3040, 0x3040),
+	(0x3097, 0x3098),
+	(0x3100, 0x3104),
+	(0x312e, 0x3130),
+	(0x318f, 0x318f),
+	(0x31bb, 0x31bf),
+	(0x31e4, 0x31ef),
+	(0x321f, 0x321f),
+	(0x32ff, 0x32ff),
+	(0x4db6, 0x4dbf),
+	(0x9fcd, 0x9fff),
+	(0xa48d, 0xa48f),
+	(0xa4c7, 0xa4cf),
+	(0xa62c, 0xa63f),
+	(0xa698, 0xa69e),
+	(0xa6f8, 0xa6ff),
+	(0xa78f, 0xa78f),
+	(0xa794, 0xa79f),
+	(0xa7ab, 0xa7f7),
+	(0xa82c, 0xa82f),
+	(0xa83a, 0xa83f),
+	(0xa878, 0xa87f),
+	(0xa8c5, 0xa8cd),
+	(0xa8da, 0xa8df),
+	(0xa8fc, 0xa8ff),
+	(0xa954, 0xa95e),
+	(0xa97d, 0xa97f),
+	(0xa9ce, 0xa9ce),
+	(0xa9da, 0xa9dd),
+	(0xa9e0, 0xa9ff),
+	(0xaa37, 0xaa3f),
+	(0xaa4e, 0xaa4f),
+	(0xaa5a, 0xaa5b),
+	(0xaa7c, 0xaa7f),
+	(0xaac3, 0xaada),
+	(0xaaf7, 0xab00),
+	(0xab07, 0xab08),
+	(0xab0f, 0xab10),
+	(0xab17, 0xab1f),
+	(0xab27, 0xab27),
+	(0xab2f, 0xabbf),
+	(0xabee, 0xabef),
+	(0xabfa, 0xabff),
+	(0xd7a4, 0xd7af),
+	(0xd7c7, 0xd7ca),
+	(0xd7fc, 0xd7ff),
+	(0xfa6e, 0xfa6f),
+	(0xfada, 0xfaff),
+	(0xfb07, 0xfb12),
+	(0xfb18, 0xfb1c),
+	(0xfb37, 0xfb37),
+	(0xfb3d, 0xfb3d),
+	(0xfb3f, 0xfb3f),
+	(0xfb42, 0xfb42),
+	(0xfb45, 0xfb45),
+	(0xfbc2, 0xfbd2),
+	(0xfd40, 0xfd4f),
+	(0xfd90, 0xfd91),
+	(0xfdc8, 0xfdef),
+	(0xfdfe, 0xfdff),
+	(0xfe1a, 0xfe1f),
+	(0xfe27, 0xfe2f),
+	(0xfe53, 0xfe53),
+	(0xfe67, 0xfe67),
+	(0xfe6c, 0xfe6f),
+	(0xfe75, 0xfe75),
+	(0xfefd, 0xfefe),
+	(0xff00, 0xff00),
+	(0xffbf, 0xffc1),
+	(0xffc8, 0xffc9),
+	(0xffd0, 0xffd1),
+	(0xffd8, 0xffd9),
+	(0xffdd, 0xffdf),
+	(0xffe7, 0xffe7),
+	(0xffef, 0xfff8),
+	(0xfffe, 0xffff),
+	(0x1000c, 0x1000c),
+	(0x10027, 0x10027),
+	(0x1003b, 0x1003b),
+	(0x1003e, 0x1003e),
+	(0x1004e, 0x1004f),
+	(0x1005e, 0x1007f),
+	(0x100fb, 0x100ff),
+	(0x10103, 0x10106),
+	(0x10134, 0x10136),
+	(0x1018b, 0x1018f),
+	(0x1019c, 0x101cf),
+	(0x101fe, 0x1027f),
+	(0x1029d, 0x1029f),
+	(0x102d1, 0x102ff),
+	(0x1031f, 0x1031f),
+	(0x10324, 0x1032f),
+	(0x1034b, 0x1037f),
+	(0x1039e, 0x1039e),
+	(0x103c4, 0x103c7),
+	(0x103d6, 0x103ff),
+	(0x1049e, 0x1049f),
+	(0x104aa, 0x107ff),
+	(0x10806, 0x10807),
+	(0x10809, 0x10809),
+	(0x10836, 0x10836),
+	(0x10839, 0x1083b),
+	(0x1083d, 0x1083e),
+	(0x10856, 0x10856),
+	(0x10860, 0x108ff),
+	(0x1091c, 0x1091e),
+	(0x1093a, 0x1093e),
+	(0x10940, 0x1097f),
+	(0x109b8, 0x109bd),
+	(0x109c0, 0x109ff),
+	(0x10a04, 0x10a04),
+	(0x10a07, 0x10a0b),
+	(0x10a14, 0x10a14),
+	(0x10a18, 0x10a18),
+	(0x10a34, 0x10a37),
+	(0x10a3b, 0x10a3e),
+	(0x10a48, 0x10a4f),
+	(0x10a59, 0x10a5f),
+	(0x10a80, 0x10aff),
+	(0x10b36, 0x10b38),
+	(0x10b56, 0x10b57),
+	(0x10b73, 0x10b77),
+	(0x10b80, 0x10bff),
+	(0x10c49, 0x10e5f),
+	(0x10e7f, 0x10fff),
+	(0x1104e, 0x11051),
+	(0x11070, 0x1107f),
+	(0x110c2, 0x110cf),
+	(0x110e9, 0x110ef),
+	(0x110fa, 0x110ff),
+	(0x11135, 0x11135),
+	(0x11144, 0x1117f),
+	(0x111c9, 0x111cf),
+	(0x111da, 0x1167f),
+	(0x116b8, 0x116bf),
+	(0x116ca, 0x11fff),
+	(0x1236f, 0x123ff),
+	(0x12463, 0x1246f),
+	(0x12474, 0x12fff),
+	(0x1342f, 0x167ff),
+	(0x16a39, 0x16eff),
+	(0x16f45, 0x16f4f),
+	(0x16f7f, 0x16f8e),
+	(0x16fa0, 0x1afff),
+	(0x1b002, 0x1cfff),
+	(0x1d0f6, 0x1d0ff),
+	(0x1d127, 0x1d128),
+	(0x1d1de, 0x1d1ff),
+	(0x1d246, 0x1d2ff),
+	(0x1d357, 0x1d35f),
+	(0x1d372, 0x1d3ff),
+	(0x1d455, 0x1d455),
+	(0x1d49d, 0x1d49d),
+	(0x1d4a0, 0x1d4a1),
+	(0x1d4a3, 0x1d4a4),
+	(0x1d4a7, 0x1d4a8),
+	(0x1d4ad, 0x1d4ad),
+	(0x1d4ba, 0x1d4ba),
+	(0x1d4bc, 0x1d4bc),
+	(0x1d4c4, 0x1d4c4),
+	(0x1d506, 0x1d506),
+	(0x1d50b, 0x1d50c),
+	(0x1d515, 0x1d515),
+	(0x1d51d, 0x1d51d),
+	(0x1d53a, 0x1d53a),
+	(0x1d53f, 0x1d53f),
+	(0x1d545, 0x1d545),
+	(0x1d547, 0x1d549),
+	(0x1d551, 0x1d551),
+	(0x1d6a6, 0x1d6a7),
+	(0x1d7cc, 0x1d7cd),
+	(0x1d800, 0x1edff),
+	(0x1ee04, 0x1ee04),
+	(0x1ee20, 0x1ee20),
+	(0x1ee23, 0x1ee23),
+	(0x1ee25, 0x1ee26),
+	(0x1ee28, 0x1ee28),
+	(0x1ee33, 0x1ee33),
+	(0x1ee38, 0x1ee38),
+	(0x1ee3a, 0x1ee3a),
+	(0x1ee3c, 0x1ee41),
+	(0x1ee43, 0x1ee46),
+	(0x1ee48, 0x1ee48),
+	(0x1ee4a, 0x1ee4a),
+	(0x1ee4c, 0x1ee4c),
+	(0x1ee50, 0x1ee50),
+	(0x1ee53, 0x1ee53),
+	(0x1ee55, 0x1ee56),
+	(0x1ee58, 0x1ee58),
+	(0x1ee5a, 0x1ee5a),
+	(0x1ee5c, 0x1ee5c),
+	(0x1ee5e, 0x1ee5e),
+	(0x1ee60, 0x1ee60),
+	(0x1ee63, 0x1ee63),
+	(0x1ee65, 0x1ee66),
+	(0x1ee6b, 0x1ee6b),
+	(0x1ee73, 0x1ee73),
+	(0x1ee78, 0x1ee78),
+	(0x1ee7d, 0x1ee7d),
+	(0x1ee7f, 0x1ee7f),
+	(0x1ee8a, 0x1ee8a),
+	(0x1ee9c, 0x1eea0),
+	(0x1eea4, 0x1eea4),
+	(0x1eeaa, 0x1eeaa),
+	(0x1eebc, 0x1eeef),
+	(0x1eef2, 0x1efff),
+	(0x1f02c, 0x1f02f),
+	(0x1f094, 0x1f09f),
+	(0x1f0af, 0x1f0b0),
+	(0x1f0bf, 0x1f0c0),
+	(0x1f0d0, 0x1f0d0),
+	(0x1f0e0, 0x1f0ff),
+	(0x1f10b, 0x1f10f),
+	(0x1f12f, 0x1f12f),
+	(0x1f16c, 0x1f16f),
+	(0x1f19b, 0x1f1e5),
+	(0x1f203, 0x1f20f),
+	(0x1f23b, 0x1f23f),
+	(0x1f249, 0x1f24f),
+	(0x1f252, 0x1f2ff),
+	(0x1f321, 0x1f32f),
+	(0x1f336, 0x1f336),
+	(0x1f37d, 0x1f37f),
+	(0x1f394, 0x1f39f),
+	(0x1f3c5, 0x1f3c5),
+	(0x1f3cb, 0x1f3df),
+	(0x1f3f1, 0x1f3ff),
+	(0x1f43f, 0x1f43f),
+	(0x1f441, 0x1f441),
+	(0x1f4f8, 0x1f4f8),
+	(0x1f4fd, 0x1f4ff),
+	(0x1f53e, 0x1f53f),
+	(0x1f544, 0x1f54f),
+	(0x1f568, 0x1f5fa),
+	(0x1f641, 0x1f644),
+	(0x1f650, 0x1f67f),
+	(0x1f6c6, 0x1f6ff),
+	(0x1f774, 0x1ffff),
+	(0x2a6d7, 0x2a6ff),
+	(0x2b735, 0x2b73f),
+	(0x2b81e, 0x2f7ff),
+	(0x2fa1e, 0xe0000),
+	(0xe0002, 0xe001f),
+	(0xe0080, 0xe00ff),
+	(0xe01f0, 0xeffff),
+	(0xffffe, 0xfffff),
+]);
+
+// Encoded as ranges: ranges 16 bytes, bitset 24 bytes, trie 30 bytes
+const CONTROL: CodepointSet = CodepointSet::Ranges(&[
+	(0x0000, 0x001f),
+	(0x007f, 0x009f),
+]);
+
+// Encoded as ranges: ranges 56 bytes, bitset 1536 bytes, trie 426 bytes
+const SPACE_SEPARATOR: CodepointSet = CodepointSet::Ranges(&[
+	(0x0020, 0x0020),
+	(0x00a0, 0x00a0),
+	(0x1680, 0x1680),
+	(0x2000, 0x200a),
+	(0x202f, 0x202f),
+	(0x205f, 0x205f),
+	(0x3000, 0x3000),
+]);
+
+// Encoded as ranges: ranges 512 bytes, bitset 15840 bytes, trie 4256 bytes
+const MATH_SYMBOL: CodepointSet = CodepointSet::Ranges(&[
+	(0x002b, 0x002b),
+	(0x003c, 0x003e),
+	(0x007c, 0x007c),
+	(0x007e, 0x007e),
+	(0x00ac, 0x00ac),
+	(0x00b1, 0x00b1),
+	(0x00d7, 0x00d7),
+	(0x00f7, 0x00f7),
+	(0x03f6, 0x03f6),
+	(0x0606, 0x0608),
+	(0x2044, 0x2044),
+	(0x2052, 0x2052),
+	(0x207a, 0x207c),
+	(0x208a, 0x208c),
+	(0x2118, 0x2118),
+	(0x2140, 0x2144),
+	(0x214b, 0x214b),
+	(0x2190, 0x2194),
+	(0x219a, 0x219b),
+	(0x21a0, 0x21a0),
+	(0x21a3, 0x21a3),
+	(0x21a6, 0x21a6),
+	(0x21ae, 0x21ae),
+	(0x21ce, 0x21cf),
+	(0x21d2, 0x21d2),
+	(0x21d4, 0x21d4),
+	(0x21f4, 0x22ff),
+	(0x2320, 0x2321),
+	(0x237c, 0x237c),
+	(0x239b, 0x23b3),
+	(0x23dc, 0x23e1),
+	(0x25b7, 0x25b7),
+	(0x25c1, 0x25c1),
+	(0x25f8, 0x25ff),
+	(0x266f, 0x266f),
+	(0x27c0, 0x27c4),
+	(0x27c7, 0x27e5),
+	(0x27f0, 0x27ff),
+	(0x2900, 0x2982),
+	(0x2999, 0x29d7),
+	(0x29dc, 0x29fb),
+	(0x29fe, 0x2aff),
+	(0x2b30, 0x2b44),
+	(0x2b47, 0x2b4c),
+	(0xfb29, 0xfb29),
+	(0xfe62, 0xfe62),
+	(0xfe64, 0xfe66),
+	(0xff0b, 0xff0b),
+	(0xff1c, 0xff1e),
+	(0xff5c, 0xff5c),
+	(0xff5e, 0xff5e),
+	(0xffe2, 0xffe2),
+	(0xffe9, 0xffec),
+	(0x1d6c1, 0x1d6c1),
+	(0x1d6db, 0x1d6db),
+	(0x1d6fb, 0x1d6fb),
+	(0x1d715, 0x1d715),
+	(0x1d735, 0x1d735),
+	(0x1d74f, 0x1d74f),
+	(0x1d76f, 0x1d76f),
+	(0x1d789, 0x1d789),
+	(0x1d7a9, 0x1d7a9),
+	(0x1d7c3, 0x1d7c3),
+	(0x1eef0, 0x1eef1),
+]);
+
+// Encoded as ranges: ranges 136 bytes, bitset 8192 bytes, trie 2176 bytes
+const CURRENCY_SYMBOL: CodepointSet = CodepointSet::Ranges(&[
+	(0x0024, 0x0024),
+	(0x00a2, 0x00a5),
+	(0x058f, 0x058f),
+	(0x060b, 0x060b),
+	(0x09f2, 0x09f3),
+	(0x09fb, 0x09fb),
+	(0x0af1, 0x0af1),
+	(0x0bf9, 0x0bf9),
+	(0x0e3f, 0x0e3f),
+	(0x17db, 0x17db),
+	(0x20a0, 0x20ba),
+	(0xa838, 0xa838),
+	(0xfdfc, 0xfdfc),
+	(0xfe69, 0xfe69),
+	(0xff04, 0xff04),
+	(0xffe0, 0xffe1),
+	(0xffe5, 0xffe6),
+]);
+
+// Encoded as ranges: ranges 216 bytes, bitset 8184 bytes, trie 2176 bytes
+const MODIFIER_SYMBOL: CodepointSet = CodepointSet::Ranges(&[
+	(0x005e, 0x005e),
+	(0x0060, 0x0060),
+	(0x00a8, 0x00a8),
+	(0x00af, 0x00af),
+	(0x00b4, 0x00b4),
+	(0x00b8, 0x00b8),
+	(0x02c2, 0x02c5),
+	(0x02d2, 0x02df),
+	(0x02e5, 0x02eb),
+	(0x02ed, 0x02ed),
+	(0x02ef, 0x02ff),
+	(0x0375, 0x0375),
+	(0x0384, 0x0385),
+	(0x1fbd, 0x1fbd),
+	(0x1fbf, 0x1fc1),
+	(0x1fcd, 0x1fcf),
+	(0x1fdd, 0x1fdf),
+	(0x1fed, 0x1fef),
+	(0x1ffd, 0x1ffe),
+	(0x309b, 0x309c),
+	(0xa700, 0xa716),
+	(0xa720, 0xa721),
+	(0xa789, 0xa78a),
+	(0xfbb2, 0xfbc1),
+	(0xff3e, 0xff3e),
+	(0xff40, 0xff40),
+	(0xffe3, 0xffe3),
+]);
+
+// Encoded as ranges: ranges 1224 bytes, bitset 16096 bytes, trie 4724 bytes
+const OTHER_SYMBOL: CodepointSet = CodepointSet::Ranges(&[
+	(0x00a6, 0x00a6),
+	(0x00a9, 0x00a9),
+	(0x00ae, 0x00ae),
+	(0x00b0, 0x00b0),
+	(0x0482, 0x0482),
+	(0x060e, 0x060f),
+	(0x06de, 0x06de),
+	(0x06e9, 0x06e9),
+	(0x06fd, 0x06fe),
+	(0x07f6, 0x07f6),
+	(0x09fa, 0x09fa),
+	(0x0b70, 0x0b70),
+	(0x0bf3, 0x0bf8),
+	(0x0bfa, 0x0bfa),
+	(0x0c7f, 0x0c7f),
+	(0x0d79, 0x0d79),
+	(0x0f01, 0x0f03),
+	(0x0f13, 0x0f13),
+	(0x0f15, 0x0f17),
+	(0x0f1a, 0x0f1f),
+	(0x0f34, 0x0f34),
+	(0x0f36, 0x0f36),
+	(0x0f38, 0x0f38),
+	(0x0fbe, 0x0fc5),
+	(0x0fc7, 0x0fcc),
+	(0x0fce, 0x0fcf),
+	(0x0fd5, 0x0fd8),
+	(0x109e, 0x109f),
+	(0x1390, 0x1399),
+	(0x1940, 0x1940),
+	(0x19de, 0x19ff),
+	(0x1b61, 0x1b6a),
+	(0x1b74, 0x1b7c),
+	(0x2100, 0x2101),
+	(0x2103, 0x2106),
+	(0x2108, 0x2109),
+	(0x2114, 0x2114),
+	(0x2116, 0x2117),
+	(0x211e, 0x2123),
+	(0x2125, 0x2125),
+	(0x2127, 0x2127),
+	(0x2129, 0x2129),
+	(0x212e, 0x212e),
+	(0x213a, 0x213b),
+	(0x214a, 0x214a),
+	(0x214c, 0x214d),
+	(0x214f, 0x214f),
+	(0x2195, 0x2199),
+	(0x219c, 0x219f),
+	(0x21a1, 0x21a2),
+	(0x21a4, 0x21a5),
+	(0x21a7, 0x21ad),
+	(0x21af, 0x21cd),
+	(0x21d0, 0x21d1),
+	(0x21d3, 0x21d3),
+	(0x21d5, 0x21f3),
+	(0x2300, 0x2307),
+	(0x230c, 0x231f),
+	(0x2322, 0x2328),
+	(0x232b, 0x237b),
+	(0x237d, 0x239a),
+	(0x23b4, 0x23db),
+	(0x23e2, 0x23f3),
+	(0x2400, 0x2426),
+	(0x2440, 0x244a),
+	(0x249c, 0x24e9),
+	(0x2500, 0x25b6),
+	(0x25b8, 0x25c0),
+	(0x25c2, 0x25f7),
+	(0x2600, 0x266e),
+	(0x2670, 0x26ff),
+	(0x2701, 0x2767),
+	(0x2794, 0x27bf),
+	(0x2800, 0x28ff),
+	(0x2b00, 0x2b2f),
+	(0x2b45, 0x2b46),
+	(0x2b50, 0x2b59),
+	(0x2ce5, 0x2cea),
+	(0x2e80, 0x2e99),
+	(0x2e9b, 0x2ef3),
+	(0x2f00, 0x2fd5),
+	(0x2ff0, 0x2ffb),
+	(0x3004, 0x3004),
+	(0x3012, 0x3013),
+	(0x3020, 0x3020),
+	(0x3036, 0x3037),
+	(0x303e, 0x303f),
+	(0x3190, 0x3191),
+	(0x3196, 0x319f),
+	(0x31c0, 0x31e3),
+	(0x3200, 0x321e),
+	(0x322a, 0x3247),
+	(0x3250, 0x3250),
+	(0x3260, 0x327f),
+	(0x328a, 0x32b0),
+	(0x32c0, 0x32fe),
+	(0x3300, 0x33ff),
+	(0x4dc0, 0x4dff),
+	(0xa490, 0xa4c6),
+	(0xa828, 0xa82b),
+	(0xa836, 0xa837),
+	(0xa839, 0xa839),
+	(0xaa77, 0xaa79),
+	(0xfdfd, 0xfdfd),
+	(0xffe4, 0xffe4),
+	(0xffe8, 0xffe8),
+	(0xffed, 0xffee),
+	(0xfffc, 0xfffd),
+	(0x10137, 0x1013f),
+	(0x10179, 0x10189),
+	(0x10190, 0x1019b),
+	(0x101d0, 0x101fc),
+	(0x1d000, 0x1d0f5),
+	(0x1d100, 0x1d126),
+	(0x1d129, 0x1d164),
+	(0x1d16a, 0x1d16c),
+	(0x1d183, 0x1d184),
+	(0x1d18c, 0x1d1a9),
+	(0x1d1ae, 0x1d1dd),
+	(0x1d200, 0x1d241),
+	(0x1d245, 0x1d245),
+	(0x1d300, 0x1d356),
+	(0x1f000, 0x1f02b),
+	(0x1f030, 0x1f093),
+	(0x1f0a0, 0x1f0ae),
+	(0x1f0b1, 0x1f0be),
+	(0x1f0c1, 0x1f0cf),
+	(0x1f0d1, 0x1f0df),
+	(0x1f110, 0x1f12e),
+	(0x1f130, 0x1f16b),
+	(0x1f170, 0x1f19a),
+	(0x1f1e6, 0x1f202),
+	(0x1f210, 0x1f23a),
+	(0x1f240, 0x1f248),
+	(0x1f250, 0x1f251),
+	(0x1f300, 0x1f320),
+	(0x1f330, 0x1f335),
+	(0x1f337, 0x1f37c),
+	(0x1f380, 0x1f393),
+	(0x1f3a0, 0x1f3c4),
+	(0x1f3c6, 0x1f3ca),
+	(0x1f3e0, 0x1f3f0),
+	(0x1f400, 0x1f43e),
+	(0x1f440, 0x1f440),
+	(0x1f442, 0x1f4f7),
+	(0x1f4f9, 0x1f4fc),
+	(0x1f500, 0x1f53d),
+	(0x1f540, 0x1f543),
+	(0x1f550, 0x1f567),
+	(0x1f5fb, 0x1f640),
+	(0x1f645, 0x1f64f),
+	(0x1f680, 0x1f6c5),
+	(0x1f700, 0x1f773),
+]);
+
+// Encoded as ranges: ranges 48 bytes, bitset 8160 bytes, trie 2090 bytes
+const CONNECTOR_PUNCTUATION: CodepointSet = CodepointSet::Ranges(&[
+	(0x005f, 0x005f),
+	(0x203f, 0x2040),
+	(0x2054, 0x2054),
+	(0xfe33, 0xfe34),
+	(0xfe4d, 0xfe4f),
+	(0xff3f, 0xff3f),
+]);
+
+// Encoded as ranges: ranges 128 bytes, bitset 8160 bytes, trie 2138 bytes
+const DASH_PUNCTUATION: CodepointSet = CodepointSet::Ranges(&[
+	(0x002d, 0x002d),
+	(0x058a, 0x058a),
+	(0x05be, 0x05be),
+	(0x1400, 0x1400),
+	(0x1806, 0x1806),
+	(0x2010, 0x2015),
+	(0x2e17, 0x2e17),
+	(0x2e1a, 0x2e1a),
+	(0x2e3a, 0x2e3b),
+	(0x301c, 0x301c),
+	(0x3030, 0x3030),
+	(0x30a0, 0x30a0),
+	(0xfe31, 0xfe32),
+	(0xfe58, 0xfe58),
+	(0xfe63, 0xfe63),
+	(0xff0d, 0xff0d),
+]);
+
+// Encoded as ranges: ranges 592 bytes, bitset 8168 bytes, trie 2204 bytes
+const OPEN_PUNCTUATION: CodepointSet = CodepointSet::Ranges(&[
+	(0x0028, 0x0028),
+	(0x005b, 0x005b),
+	(0x007b, 0x007b),
+	(0x0f3a, 0x0f3a),
+	(0x0f3c, 0x0f3c),
+	(0x169b, 0x169b),
+	(0x201a, 0x201a),
+	(0x201e, 0x201e),
+	(0x2045, 0x2045),
+	(0x207d, 0x207d),
+	(0x208d, 0x208d),
+	(0x2308, 0x2308),
+	(0x230a, 0x230a),
+	(0x2329, 0x2329),
+	(0x2768, 0x2768),
+	(0x276a, 0x276a),
+	(0x276c, 0x276c),
+	(0x276e, 0x276e),
+	(0x2770, 0x2770),
+	(0x2772, 0x2772),
+	(0x2774, 0x2774),
+	(0x27c5, 0x27c5),
+	(0x27e6, 0x27e6),
+	(0x27e8, 0x27e8),
+	(0x27ea, 0x27ea),
+	(0x27ec, 0x27ec),
+	(0x27ee, 0x27ee),
+	(0x2983, 0x2983),
+	(0x2985, 0x2985),
+	(0x2987, 0x2987),
+	(0x2989, 0x2989),
+	(0x298b, 0x298b),
+	(0x298d, 0x298d),
+	(0x298f, 0x298f),
+	(0x2991, 0x2991),
+	(0x2993, 0x2993),
+	(0x2995, 0x2995),
+	(0x2997, 0x2997),
+	(0x29d8, 0x29d8),
+	(0x29da, 0x29da),
+	(0x29fc, 0x29fc),
+	(0x2e22, 0x2e22),
+	(0x2e24, 0x2e24),
+	(0x2e26, 0x2e26),
+	(0x2e28, 0x2e28),
+	(0x3008, 0x3008),
+	(0x300a, 0x300a),
+	(0x300c, 0x300c),
+	(0x300e, 0x300e),
+	(0x3010, 0x3010),
+	(0x3014, 0x3014),
+	(0x3016, 0x3016),
+	(0x3018, 0x3018),
+	(0x301a, 0x301a),
+	(0x301d, 0x301d),
+	(0xfd3e, 0xfd3e),
+	(0xfe17, 0xfe17),
+	(0xfe35, 0xfe35),
+	(0xfe37, 0xfe37),
+	(0xfe39, 0xfe39),
+	(0xfe3b, 0xfe3b),
+	(0xfe3d, 0xfe3d),
+	(0xfe3f, 0xfe3f),
+	(0xfe41, 0xfe41),
+	(0xfe43, 0xfe43),
+	(0xfe47, 0xfe47),
+	(0xfe59, 0xfe59),
+	(0xfe5b, 0xfe5b),
+	(0xfe5d, 0xfe5d),
+	(0xff08, 0xff08),
+	(0xff3b, 0xff3b),
+	(0xff5b, 0xff5b),
+	(0xff5f, 0xff5f),
+	(0xff62, 0xff62),
+]);
+
+// Encoded as ranges: ranges 576 bytes, bitset 8168 bytes, trie 2196 bytes
+const CLOSE_PUNCTUATION: CodepointSet = CodepointSet::Ranges(&[
+	(0x0029, 0x0029),
+	(0x005d, 0x005d),
+	(0x007d, 0x007d),
+	(0x0f3b, 0x0f3b),
+	(0x0f3d, 0x0f3d),
+	(0x169c, 0x169c),
+	(0x2046, 0x2046),
+	(0x207e, 0x207e),
+	(0x208e, 0x208e),
+	(0x2309, 0x2309),
+	(0x230b, 0x230b),
+	(0x232a, 0x232a),
+	(0x2769, 0x2769),
+	(0x276b, 0x276b),
+	(0x276d, 0x276d),
+	(0x276f, 0x276f),
+	(0x2771, 0x2771),
+	(0x2773, 0x2773),
+	(0x2775, 0x2775),
+	(0x27c6, 0x27c6),
+	(0x27e7, 0x27e7),
+	(0x27e9, 0x27e9),
+	(0x27eb, 0x27eb),
+	(0x27ed, 0x27ed),
+	(0x27ef, 0x27ef),
+	(0x2984, 0x2984),
+	(0x2986, 0x2986),
+	(0x2988, 0x2988),
+	(0x298a, 0x298a),
+	(0x298c, 0x298c),
+	(0x298e, 0x298e),
+	(0x2990, 0x2990),
+	(0x2992, 0x2992),
+	(0x2994, 0x2994),
+	(0x2996, 0x2996),
+	(0x2998, 0x2998),
+	(0x29d9, 0x29d9),
+	(0x29db, 0x29db),
+	(0x29fd, 0x29fd),
+	(0x2e23, 0x2e23),
+	(0x2e25, 0x2e25),
+	(0x2e27, 0x2e27),
+	(0x2e29, 0x2e29),
+	(0x3009, 0x3009),
+	(0x300b, 0x300b),
+	(0x300d, 0x300d),
+	(0x300f, 0x300f),
+	(0x3011, 0x3011),
+	(0x3015, 0x3015),
+	(0x3017, 0x3017),
+	(0x3019, 0x3019),
+	(0x301b, 0x301b),
+	(0x301e, 0x301f),
+	(0xfd3f, 0xfd3f),
+	(0xfe18, 0xfe18),
+	(0xfe36, 0xfe36),
+	(0xfe38, 0xfe38),
+	(0xfe3a, 0xfe3a),
+	(0xfe3c, 0xfe3c),
+	(0xfe3e, 0xfe3e),
+	(0xfe40, 0xfe40),
+	(0xfe42, 0xfe42),
+	(0xfe44, 0xfe44),
+	(0xfe48, 0xfe48),
+	(0xfe5a, 0xfe5a),
+	(0xfe5c, 0xfe5c),
+	(0xfe5e, 0xfe5e),
+	(0xff09, 0xff09),
+	(0xff3d, 0xff3d),
+	(0xff5d, 0xff5d),
+	(0xff60, 0xff60),
+	(0xff63, 0xff63),
+]);
+
+// Encoded as ranges: ranges 88 bytes, bitset 1456 bytes, trie 402 bytes
+const INITIAL_PUNCTUATION: CodepointSet = CodepointSet::Ranges(&[
+	(0x00ab, 0x00ab),
+	(0x2018, 0x2018),
+	(0x201b, 0x201c),
+	(0x201f, 0x201f),
+	(0x2039, 0x2039),
+	(0x2e02, 0x2e02),
+	(0x2e04, 0x2e04),
+	(0x2e09, 0x2e09),
+	(0x2e0c, 0x2e0c),
+	(0x2e1c, 0x2e1c),
+	(0x2e20, 0x2e20),
+]);
+
+// Encoded as ranges: ranges 80 bytes, bitset 1456 bytes, trie 402 bytes
+const FINAL_PUNCTUATION: CodepointSet = CodepointSet::Ranges(&[
+	(0x00bb, 0x00bb),
+	(0x2019, 0x2019),
+	(0x201d, 0x201d),
+	(0x203a, 0x203a),
+	(0x2e03, 0x2e03),
+	(0x2e05, 0x2e05),
+	(0x2e0a, 0x2e0a),
+	(0x2e0d, 0x2e0d),
+	(0x2e1d, 0x2e1d),
+	(0x2e21, 0x2e21),
+]);
+
+// Encoded as ranges: ranges 1080 bytes, bitset 9360 bytes, trie 2908 bytes
+const OTHER_PUNCTUATION: CodepointSet = CodepointSet::Ranges(&[
+	(0x0021, 0x0023),
+	(0x0025, 0x0027),
+	(0x002a, 0x002a),
+	(0x002c, 0x002c),
+	(0x002e, 0x002f),
+	(0x003a, 0x003b),
+	(0x003f, 0x0040),
+	(0x005c, 0x005c),
+	(0x00a1, 0x00a1),
+	(0x00a7, 0x00a7),
+	(0x00b6, 0x00b7),
+	(0x00bf, 0x00bf),
+	(0x037e, 0x037e),
+	(0x0387, 0x0387),
+	(0x055a, 0x055f),
+	(0x0589, 0x0589),
+	(0x05c0, 0x05c0),
+	(0x05c3, 0x05c3),
+	(0x05c6, 0x05c6),
+	(0x05f3, 0x05f4),
+	(0x0609, 0x060a),
+	(0x060c, 0x060d),
+	(0x061b, 0x061b),
+	(0x061e, 0x061f),
+	(0x066a, 0x066d),
+	(0x06d4, 0x06d4),
+	(0x0700, 0x070d),
+	(0x07f7, 0x07f9),
+	(0x0830, 0x083e),
+	(0x085e, 0x085e),
+	(0x0964, 0x0965),
+	(0x0970, 0x0970),
+	(0x0af0, 0x0af0),
+	(0x0df4, 0x0df4),
+	(0x0e4f, 0x0e4f),
+	(0x0e5a, 0x0e5b),
+	(0x0f04, 0x0f12),
+	(0x0f14, 0x0f14),
+	(0x0f85, 0x0f85),
+	(0x0fd0, 0x0fd4),
+	(0x0fd9, 0x0fda),
+	(0x104a, 0x104f),
+	(0x10fb, 0x10fb),
+	(0x1360, 0x1368),
+	(0x166d, 0x166e),
+	(0x16eb, 0x16ed),
+	(0x1735, 0x1736),
+	(0x17d4, 0x17d6),
+	(0x17d8, 0x17da),
+	(0x1800, 0x1805),
+	(0x1807, 0x180a),
+	(0x1944, 0x1945),
+	(0x1a1e, 0x1a1f),
+	(0x1aa0, 0x1aa6),
+	(0x1aa8, 0x1aad),
+	(0x1b5a, 0x1b60),
+	(0x1bfc, 0x1bff),
+	(0x1c3b, 0x1c3f),
+	(0x1c7e, 0x1c7f),
+	(0x1cc0, 0x1cc7),
+	(0x1cd3, 0x1cd3),
+	(0x2016, 0x2017),
+	(0x2020, 0x2027),
+	(0x2030, 0x2038),
+	(0x203b, 0x203e),
+	(0x2041, 0x2043),
+	(0x2047, 0x2051),
+	(0x2053, 0x2053),
+	(0x2055, 0x205e),
+	(0x2cf9, 0x2cfc),
+	(0x2cfe, 0x2cff),
+	(0x2d70, 0x2d70),
+	(0x2e00, 0x2e01),
+	(0x2e06, 0x2e08),
+	(0x2e0b, 0x2e0b),
+	(0x2e0e, 0x2e16),
+	(0x2e18, 0x2e19),
+	(0x2e1b, 0x2e1b),
+	(0x2e1e, 0x2e1f),
+	(0x2e2a, 0x2e2e),
+	(0x2e30, 0x2e39),
+	(0x3001, 0x3003),
+	(0x303d, 0x303d),
+	(0x30fb, 0x30fb),
+	(0xa4fe, 0xa4ff),
+	(0xa60d, 0xa60f),
+	(0xa673, 0xa673),
+	(0xa67e, 0xa67e),
+	(0xa6f2, 0xa6f7),
+	(0xa874, 0xa877),
+	(0xa8ce, 0xa8cf),
+	(0xa8f8, 0xa8fa),
+	(0xa92e, 0xa92f),
+	(0xa95f, 0xa95f),
+	(0xa9c1, 0xa9cd),
+	(0xa9de, 0xa9df),
+	(0xaa5c, 0xaa5f),
+	(0xaade, 0xaadf),
+	(0xaaf0, 0xaaf1),
+	(0xabeb, 0xabeb),
+	(0xfe10, 0xfe16),
+	(0xfe19, 0xfe19),
+	(0xfe30, 0xfe30),
+	(0xfe45, 0xfe46),
+	(0xfe49, 0xfe4c),
+	(0xfe50, 0xfe52),
+	(0xfe54, 0xfe57),
+	(0xfe5f, 0xfe61),
+	(0xfe68, 0xfe68),
+	(0xfe6a, 0xfe6b),
+	(0xff01, 0xff03),
+	(0xff05, 0xff07),
+	(0xff0a, 0xff0a),
+	(0xff0c, 0xff0c),
+	(0xff0e, 0xff0f),
+	(0xff1a, 0xff1b),
+	(0xff1f, 0xff20),
+	(0xff3c, 0xff3c),
+	(0xff61, 0xff61),
+	(0xff64, 0xff65),
+	(0x10100, 0x10102),
+	(0x1039f, 0x1039f),
+	(0x103d0, 0x103d0),
+	(0x10857, 0x10857),
+	(0x1091f, 0x1091f),
+	(0x1093f, 0x1093f),
+	(0x10a50, 0x10a58),
+	(0x10a7f, 0x10a7f),
+	(0x10b39, 0x10b3f),
+	(0x11047, 0x1104d),
+	(0x110bb, 0x110bc),
+	(0x110be, 0x110c1),
+	(0x11140, 0x11143),
+	(0x111c5, 0x111c8),
+	(0x12470, 0x12473),
+]);
+
+// Encoded as ranges: ranges 80 bytes, bitset 968 bytes, trie 288 bytes
+const TITLECASE_LETTER: CodepointSet = CodepointSet::Ranges(&[
+	(0x01c5, 0x01c5),
+	(0x01c8, 0x01c8),
+	(0x01cb, 0x01cb),
+	(0x01f2, 0x01f2),
+	(0x1f88, 0x1f8f),
+	(0x1f98, 0x1f9f),
+	(0x1fa8, 0x1faf),
+	(0x1fbc, 0x1fbc),
+	(0x1fcc, 0x1fcc),
+	(0x1ffc, 0x1ffc),
+]);
+
+// Encoded as ranges: ranges 96 bytes, bitset 8624 bytes, trie 2428 bytes
+const LETTER_NUMBER: CodepointSet = CodepointSet::Ranges(&[
+	(0x16ee, 0x16f0),
+	(0x2160, 0x2182),
+	(0x2185, 0x2188),
+	(0x3007, 0x3007),
+	(0x3021, 0x3029),
+	(0x3038, 0x303a),
+	(0xa6e6, 0xa6ef),
+	(0x10140, 0x10174),
+	(0x10341, 0x10341),
+	(0x1034a, 0x1034a),
+	(0x103d1, 0x103d5),
+	(0x12400, 0x12462),
+]);
+
+// Encoded as ranges: ranges 336 bytes, bitset 15888 bytes, trie 4274 bytes
+const OTHER_NUMBER: CodepointSet = CodepointSet::Ranges(&[
+	(0x00b2, 0x00b3),
+	(0x00b9, 0x00b9),
+	(0x00bc, 0x00be),
+	(0x09f4, 0x09f9),
+	(0x0b72, 0x0b77),
+	(0x0bf0, 0x0bf2),
+	(0x0c78, 0x0c7e),
+	(0x0d70, 0x0d75),
+	(0x0f2a, 0x0f33),
+	(0x1369, 0x137c),
+	(0x17f0, 0x17f9),
+	(0x19da, 0x19da),
+	(0x2070, 0x2070),
+	(0x2074, 0x2079),
+	(0x2080, 0x2089),
+	(0x2150, 0x215f),
+	(0x2189, 0x2189),
+	(0x2460, 0x249b),
+	(0x24ea, 0x24ff),
+	(0x2776, 0x2793),
+	(0x2cfd, 0x2cfd),
+	(0x3192, 0x3195),
+	(0x3220, 0x3229),
+	(0x3248, 0x324f),
+	(0x3251, 0x325f),
+	(0x3280, 0x3289),
+	(0x32b1, 0x32bf),
+	(0xa830, 0xa835),
+	(0x10107, 0x10133),
+	(0x10175, 0x10178),
+	(0x1018a, 0x1018a),
+	(0x10320, 0x10323),
+	(0x10858, 0x1085f),
+	(0x10916, 0x1091b),
+	(0x10a40, 0x10a47),
+	(0x10a7d, 0x10a7e),
+	(0x10b58, 0x10b5f),
+	(0x10b78, 0x10b7f),
+	(0x10e60, 0x10e7e),
+	(0x11052, 0x11065),
+	(0x1d360, 0x1d371),
+	(0x1f100, 0x1f10a),
+]);
+
+// Encoded as ranges: ranges 32 bytes, bitset 5184 bytes, trie 1364 bytes
+const ENCLOSING_MARK: CodepointSet = CodepointSet::Ranges(&[
+	(0x0488, 0x0489),
+	(0x20dd, 0x20e0),
+	(0x20e2, 0x20e4),
+	(0xa670, 0xa672),
+]);
+
+// Encoded as ranges: ranges 16 bytes, bitset 4880 bytes, trie 1388 bytes
+const LEADING_JAMO: CodepointSet = CodepointSet::Ranges(&[
+	(0x1100, 0x115f),
+	(0xa960, 0xa97c),
+]);
+
+// Encoded as ranges: ranges 16 bytes, bitset 6352 bytes, trie 1768 bytes
+const VOWEL_JAMO: CodepointSet = CodepointSet::Ranges(&[
+	(0x1160, 0x11a7),
+	(0xd7b0, 0xd7c6),
+]);
+
+// Encoded as ranges: ranges 16 bytes, bitset 6352 bytes, trie 1760 bytes
+const TRAILING_JAMO: CodepointSet = CodepointSet::Ranges(&[
+	(0x11a8, 0x11ff),
+	(0xd7cb, 0xd7fb),
+]);
+
+// Encoded as bitset: ranges 8 bytes, bitset 8 bytes, trie 274 bytes
+const JOIN_CONTROL: CodepointSet = CodepointSet::Bitset {
+	start: 0x200c,
+	bits: &[
+		0x0000000000000003,
+	],
+};
+
+// Encoded as ranges: ranges 144 bytes, bitset 131144 bytes, trie 34840 bytes
+const NONCHARACTER_CODE_POINT: CodepointSet = CodepointSet::Ranges(&[
+	(0xfdd0, 0xfdef),
+	(0xfffe, 0xffff),
+	(0x1fffe, 0x1ffff),
+	(0x2fffe, 0x2ffff),
+	(0x3fffe, 0x3ffff),
+	(0x4fffe, 0x4ffff),
+	(0x5fffe, 0x5ffff),
+	(0x6fffe, 0x6ffff),
+	(0x7fffe, 0x7ffff),
+	(0x8fffe, 0x8ffff),
+	(0x9fffe, 0x9ffff),
+	(0xafffe, 0xaffff),
+	(0xbfffe, 0xbffff),
+	(0xcfffe, 0xcffff),
+	(0xdfffe, 0xdffff),
+	(0xefffe, 0xeffff),
+	(0xffffe, 0xfffff),
+	(0x10fffe, 0x10ffff),
+]);
+
+// Encoded as ranges: ranges 128 bytes, bitset 115184 bytes, trie 28928 bytes
+const DEFAULT_IGNORABLE_CODE_POINT: CodepointSet = CodepointSet::Ranges(&[
+	(0x00ad, 0x00ad),
+	(0x034f, 0x034f),
+	(0x061c, 0x061c),
+	(0x115f, 0x1160),
+	(0x17b4, 0x17b5),
+	(0x180b, 0x180e),
+	(0x200b, 0x200f),
+	(0x202a, 0x202e),
+	(0x2060, 0x206f),
+	(0x3164, 0x3164),
+	(0xfe00, 0xfe0f),
+	(0xfeff, 0xfeff),
+	(0xffa0, 0xffa0),
+	(0xfff0, 0xfff8),
+	(0x1d173, 0x1d17a),
+	(0xe0000, 0xe0fff),
+]);
+
+// Encoded as ranges: ranges 1976 bytes, bitset 24368 bytes, trie 6826 bytes
+const HAS_COMPAT: CodepointSet = CodepointSet::Ranges(&[
+	(0x00a0, 0x00a0),
+	(0x00a8, 0x00a8),
+	(0x00aa, 0x00aa),
+	(0x00af, 0x00af),
+	(0x00b2, 0x00b5),
+	(0x00b8, 0x00ba),
+	(0x00bc, 0x00be),
+	(0x0132, 0x0133),
+	(0x013f, 0x0140),
+	(0x0149, 0x0149),
+	(0x017f, 0x017f),
+	(0x01c4, 0x01cc),
+	(0x01f1, 0x01f3),
+	(0x02b0, 0x02b8),
+	(0x02d8, 0x02dd),
+	(0x02e0, 0x02e4),
+	(0x0340, 0x0341),
+	(0x0343, 0x0344),
+	(0x0374, 0x0374),
+	(0x037a, 0x037a),
+	(0x037e, 0x037e),
+	(0x0384, 0x0385),
+	(0x0387, 0x0387),
+	(0x03d0, 0x03d6),
+	(0x03f0, 0x03f2),
+	(0x03f4, 0x03f5),
+	(0x03f9, 0x03f9),
+	(0x0587, 0x0587),
+	(0x0675, 0x0678),
+	(0x0958, 0x095f),
+	(0x09dc, 0x09dd),
+	(0x09df, 0x09df),
+	(0x0a33, 0x0a33),
+	(0x0a36, 0x0a36),
+	(0x0a59, 0x0a5b),
+	(0x0a5e, 0x0a5e),
+	(0x0b5c, 0x0b5d),
+	(0x0e33, 0x0e33),
+	(0x0eb3, 0x0eb3),
+	(0x0edc, 0x0edd),
+	(0x0f0c, 0x0f0c),
+	(0x0f43, 0x0f43),
+	(0x0f4d, 0x0f4d),
+	(0x0f52, 0x0f52),
+	(0x0f57, 0x0f57),
+	(0x0f5c, 0x0f5c),
+	(0x0f69, 0x0f69),
+	(0x0f73, 0x0f73),
+	(0x0f75, 0x0f79),
+	(0x0f81, 0x0f81),
+	(0x0f93, 0x0f93),
+	(0x0f9d, 0x0f9d),
+	(0x0fa2, 0x0fa2),
+	(0x0fa7, 0x0fa7),
+	(0x0fac, 0x0fac),
+	(0x0fb9, 0x0fb9),
+	(0x10fc, 0x10fc),
+	(0x1d2c, 0x1d2e),
+	(0x1d30, 0x1d3a),
+	(0x1d3c, 0x1d4d),
+	(0x1d4f, 0x1d6a),
+	(0x1d78, 0x1d78),
+	(0x1d9b, 0x1dbf),
+	(0x1e9a, 0x1e9b),
+	(0x1f71, 0x1f71),
+	(0x1f73, 0x1f73),
+	(0x1f75, 0x1f75),
+	(0x1f77, 0x1f77),
+	(0x1f79, 0x1f79),
+	(0x1f7b, 0x1f7b),
+	(0x1f7d, 0x1f7d),
+	(0x1fbb, 0x1fbb),
+	(0x1fbd, 0x1fc1),
+	(0x1fc9, 0x1fc9),
+	(0x1fcb, 0x1fcb),
+	(0x1fcd, 0x1fcf),
+	(0x1fd3, 0x1fd3),
+	(0x1fdb, 0x1fdb),
+	(0x1fdd, 0x1fdf),
+	(0x1fe3, 0x1fe3),
+	(0x1feb, 0x1feb),
+	(0x1fed, 0x1fef),
+	(0x1ff9, 0x1ff9),
+	(0x1ffb, 0x1ffb),
+	(0x1ffd, 0x1ffe),
+	(0x2000, 0x200a),
+	(0x2011, 0x2011),
+	(0x2017, 0x2017),
+	(0x2024, 0x2026),
+	(0x202f, 0x202f),
+	(0x2033, 0x2034),
+	(0x2036, 0x2037),
+	(0x203c, 0x203c),
+	(0x203e, 0x203e),
+	(0x2047, 0x2049),
+	(0x2057, 0x2057),
+	(0x205f, 0x205f),
+	(0x2070, 0x2071),
+	(0x2074, 0x208e),
+	(0x2090, 0x209c),
+	(0x20a8, 0x20a8),
+	(0x2100, 0x2103),
+	(0x2105, 0x2107),
+	(0x2109, 0x2113),
+	(0x2115, 0x2116),
+	(0x2119, 0x211d),
+	(0x2120, 0x2122),
+	(0x2124, 0x2124),
+	(0x2126, 0x2126),
+	(0x2128, 0x2128),
+	(0x212a, 0x212d),
+	(0x212f, 0x2131),
+	(0x2133, 0x2139),
+	(0x213b, 0x2140),
+	(0x2145, 0x2149),
+	(0x2150, 0x217f),
+	(0x2189, 0x2189),
+	(0x222c, 0x222d),
+	(0x222f, 0x2230),
+	(0x2329, 0x232a),
+	(0x2460, 0x24ea),
+	(0x2a0c, 0x2a0c),
+	(0x2a74, 0x2a76),
+	(0x2adc, 0x2adc),
+	(0x2c7c, 0x2c7d),
+	(0x2d6f, 0x2d6f),
+	(0x2e9f, 0x2e9f),
+	(0x2ef3, 0x2ef3),
+	(0x2f00, 0x2fd5),
+	(0x3000, 0x3000),
+	(0x3036, 0x3036),
+	(0x3038, 0x303a),
+	(0x309b, 0x309c),
+	(0x309f, 0x309f),
+	(0x30ff, 0x30ff),
+	(0x3131, 0x318e),
+	(0x3192, 0x319f),
+	(0x3200, 0x321e),
+	(0x3220, 0x3247),
+	(0x3250, 0x327e),
+	(0x3280, 0x32fe),
+	(0x3300, 0x33ff),
+	(0xa770, 0xa770),
+	(0xa7f8, 0xa7f9),
+	(0xf900, 0xfa0d),
+	(0xfa10, 0xfa10),
+	(0xfa12, 0xfa12),
+	(0xfa15, 0xfa1e),
+	(0xfa20, 0xfa20),
+	(0xfa22, 0xfa22),
+	(0xfa25, 0xfa26),
+	(0xfa2a, 0xfa6d),
+	(0xfa70, 0xfad9),
+	(0xfb00, 0xfb06),
+	(0xfb13, 0xfb17),
+	(0xfb1d, 0xfb1d),
+	(0xfb1f, 0xfb36),
+	(0xfb38, 0xfb3c),
+	(0xfb3e, 0xfb3e),
+	(0xfb40, 0xfb41),
+	(0xfb43, 0xfb44),
+	(0xfb46, 0xfbb1),
+	(0xfbd3, 0xfd3d),
+	(0xfd50, 0xfd8f),
+	(0xfd92, 0xfdc7),
+	(0xfdf0, 0xfdfc),
+	(0xfe10, 0xfe19),
+	(0xfe30, 0xfe44),
+	(0xfe47, 0xfe52),
+	(0xfe54, 0xfe66),
+	(0xfe68, 0xfe6b),
+	(0xfe70, 0xfe72),
+	(0xfe74, 0xfe74),
+	(0xfe76, 0xfefc),
+	(0xff01, 0xffbe),
+	(0xffc2, 0xffc7),
+	(0xffca, 0xffcf),
+	(0xffd2, 0xffd7),
+	(0xffda, 0xffdc),
+	(0xffe0, 0xffe6),
+	(0xffe8, 0xffee),
+	(0x1d15e, 0x1d164),
+	(0x1d1bb, 0x1d1c0),
+	(0x1d400, 0x1d454),
+	(0x1d456, 0x1d49c),
+	(0x1d49e, 0x1d49f),
+	(0x1d4a2, 0x1d4a2),
+	(0x1d4a5, 0x1d4a6),
+	(0x1d4a9, 0x1d4ac),
+	(0x1d4ae, 0x1d4b9),
+	(0x1d4bb, 0x1d4bb),
+	(0x1d4bd, 0x1d4c3),
+	(0x1d4c5, 0x1d505),
+	(0x1d507, 0x1d50a),
+	(0x1d50d, 0x1d514),
+	(0x1d516, 0x1d51c),
+	(0x1d51e, 0x1d539),
+	(0x1d53b, 0x1d53e),
+	(0x1d540, 0x1d544),
+	(0x1d546, 0x1d546),
+	(0x1d54a, 0x1d550),
+	(0x1d552, 0x1d6a5),
+	(0x1d6a8, 0x1d7cb),
+	(0x1d7ce, 0x1d7ff),
+	(0x1ee00, 0x1ee03),
+	(0x1ee05, 0x1ee1f),
+	(0x1ee21, 0x1ee22),
+	(0x1ee24, 0x1ee24),
+	(0x1ee27, 0x1ee27),
+	(0x1ee29, 0x1ee32),
+	(0x1ee34, 0x1ee37),
+	(0x1ee39, 0x1ee39),
+	(0x1ee3b, 0x1ee3b),
+	(0x1ee42, 0x1ee42),
+	(0x1ee47, 0x1ee47),
+	(0x1ee49, 0x1ee49),
+	(0x1ee4b, 0x1ee4b),
+	(0x1ee4d, 0x1ee4f),
+	(0x1ee51, 0x1ee52),
+	(0x1ee54, 0x1ee54),
+	(0x1ee57, 0x1ee57),
+	(0x1ee59, 0x1ee59),
+	(0x1ee5b, 0x1ee5b),
+	(0x1ee5d, 0x1ee5d),
+	(0x1ee5f, 0x1ee5f),
+	(0x1ee61, 0x1ee62),
+	(0x1ee64, 0x1ee64),
+	(0x1ee67, 0x1ee6a),
+	(0x1ee6c, 0x1ee72),
+	(0x1ee74, 0x1ee77),
+	(0x1ee79, 0x1ee7c),
+	(0x1ee7e, 0x1ee7e),
+	(0x1ee80, 0x1ee89),
+	(0x1ee8b, 0x1ee9b),
+	(0x1eea1, 0x1eea3),
+	(0x1eea5, 0x1eea9),
+	(0x1eeab, 0x1eebb),
+	(0x1f100, 0x1f10a),
+	(0x1f110, 0x1f12e),
+	(0x1f130, 0x1f14f),
+	(0x1f16a, 0x1f16b),
+	(0x1f190, 0x1f190),
+	(0x1f200, 0x1f202),
+	(0x1f210, 0x1f23a),
+	(0x1f240, 0x1f248),
+	(0x1f250, 0x1f251),
+	(0x2f800, 0x2fa1d),
+]);
+
@@ -32,7 +32,27 @@
 
 #![deny(missing_docs)]
 
-include!(concat!(env!("OUT_DIR"), "/public.rs"));
+// Includes a generated table, committed in `src/generated` or regenerated
+// by the build script when the `regenerate` feature is enabled
+#[cfg(not(feature = "regenerate"))]
+macro_rules! include_table {
+    ($file:literal) => {
+        include!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/generated/",
+            $file
+        ));
+    };
+}
+
+#[cfg(feature = "regenerate")]
+macro_rules! include_table {
+    ($file:literal) => {
+        include!(concat!(env!("OUT_DIR"), "/", $file));
+    };
+}
+
+include_table!("public.rs");
 
 mod common;
 
@@ -41,6 +61,7 @@ pub mod context;
 pub use crate::error::CodepointInfo;
 pub use crate::error::Error;
 pub use crate::error::UnexpectedError;
+pub use crate::error::{LengthInfo, LengthLimit, LengthUnit};
 pub use crate::stringclasses::FreeformClass;
 pub use crate::stringclasses::IdentifierClass;
 pub use crate::stringclasses::StringClass;
@@ -7,26 +7,35 @@ use crate::context;
 use crate::DerivedPropertyValue;
 use crate::{CodepointInfo, Error, UnexpectedError};
 
-/// Interface for specific classes to deal with specific Unicode
-/// code groups defined in RFC 8264.
-/// Next callbacks will be invoked to calculate the derived property
-/// according to the algorithm defined in [`rfc8264`](https://datatracker.ietf.org/doc/html/rfc8264#section-8)
-pub trait SpecificDerivedPropertyValue {
-    /// Callback invoked when the Unicode code point belongs to
-    /// [Spaces](https://datatracker.ietf.org/doc/html/rfc8264#section-9.14)
-    fn on_spaces(&self) -> DerivedPropertyValue;
-    /// Callback invoked when the Unicode code point belongs to
-    /// [Symbols](https://datatracker.ietf.org/doc/html/rfc8264#section-9.15)
-    fn on_symbols(&self) -> DerivedPropertyValue;
-    /// Callback invoked when the Unicode code point belongs to
-    /// [Punctuation](https://datatracker.ietf.org/doc/html/rfc8264#section-9.16)
-    fn on_punctuation(&self) -> DerivedPropertyValue;
-    /// Callback invoked when the Unicode code point belongs to
-    /// [`HasCompat`](https://datatracker.ietf.org/doc/html/rfc8264#section-9.17)
-    fn on_has_compat(&self) -> DerivedPropertyValue;
-    /// Callback invoked when the Unicode code point belongs to
-    /// [`OtherLetterDigits`](https://datatracker.ietf.org/doc/html/rfc8264#section-9.18)
-    fn on_other_letter_digits(&self) -> DerivedPropertyValue;
+// String class whose derived property values are computed. The values
+// that depend on the string class are picked from this enum so that they
+// can be computed in const context.
+#[derive(Copy, Clone)]
+enum Class {
+    Identifier,
+    Freeform,
+}
+
+impl Class {
+    // `ID_DIS` or `FREE_PVAL`
+    const fn specific_value(self) -> DerivedPropertyValue {
+        match self {
+            Class::Identifier => DerivedPropertyValue::SpecClassDis,
+            Class::Freeform => DerivedPropertyValue::SpecClassPval,
+        }
+    }
+
+    #[cfg(feature = "iana-tables")]
+    const fn registry_value(
+        self,
+        id: DerivedPropertyValue,
+        free: DerivedPropertyValue,
+    ) -> DerivedPropertyValue {
+        match self {
+            Class::Identifier => id,
+            Class::Freeform => free,
+        }
+    }
 }
 
 /// Implements the algorithm to calculate the value of the derived property.
@@ -52,16 +61,14 @@ pub trait SpecificDerivedPropertyValue {
 ///
 /// # Arguments
 /// * `cp` - Unicode code point
-/// * `obj` - Object implementing the [`SpecificDerivedPropertyValue`] trait.
+/// * `class` - String class whose derived property value is computed.
 ///
 /// # Return
 /// This function returns the derived property value as defined in
 /// [RFC 8264](https://datatracker.ietf.org/doc/html/rfc8264#section-8)
 #[allow(clippy::if_same_then_else)]
-fn get_derived_property_value(
-    cp: u32,
-    obj: &dyn SpecificDerivedPropertyValue,
-) -> DerivedPropertyValue {
+#[cfg_attr(feature = "iana-tables", allow(dead_code))]
+const fn compute_derived_property_value(cp: u32, class: Class) -> DerivedPropertyValue {
     match common::get_exception_val(cp) {
         Some(val) => *val,
         None => match common::get_backward_compatible_val(cp) {
@@ -80,17 +87,17 @@ fn get_derived_property_value(
                 } else if common::is_control(cp) {
                     DerivedPropertyValue::Disallowed
                 } else if common::has_compat(cp) {
-                    obj.on_has_compat()
+                    class.specific_value()
                 } else if common::is_letter_digit(cp) {
                     DerivedPropertyValue::PValid
                 } else if common::is_other_letter_digit(cp) {
-                    obj.on_other_letter_digits()
+                    class.specific_value()
                 } else if common::is_space(cp) {
-                    obj.on_spaces()
+                    class.specific_value()
                 } else if common::is_symbol(cp) {
-                    obj.on_symbols()
+                    class.specific_value()
                 } else if common::is_punctuation(cp) {
-                    obj.on_punctuation()
+                    class.specific_value()
                 } else {
                     DerivedPropertyValue::Disallowed
                 }
@@ -99,6 +106,26 @@ fn get_derived_property_value(
     }
 }
 
+/// Returns the derived property value of the code point for the string class.
+#[cfg(not(feature = "iana-tables"))]
+#[inline]
+const fn get_derived_property_value(cp: u32, class: Class) -> DerivedPropertyValue {
+    compute_derived_property_value(cp, class)
+}
+
+/// Returns the derived property value of the code point for the string class
+/// as it is published in the `IANA` registry. The table is generated at build
+/// time from the registry `CSV` file instead of being computed from the
+/// Unicode Character Database.
+#[cfg(feature = "iana-tables")]
+#[inline]
+const fn get_derived_property_value(cp: u32, class: Class) -> DerivedPropertyValue {
+    match common::get_registry_vals(cp) {
+        Some((id, free)) => class.registry_value(*id, *free),
+        None => DerivedPropertyValue::Unassigned,
+    }
+}
+
 fn allowed_by_context_rule(
     label: &str,
     val: DerivedPropertyValue,
@@ -190,36 +217,35 @@ pub trait StringClass {
 /// assert_eq!(id.get_value_from_char('S'), DerivedPropertyValue::PValid);
 /// // Character 0x1170 is OldHangulJamo (I)
 /// assert_eq!(id.get_value_from_codepoint(0x1170), DerivedPropertyValue::Disallowed);
+/// // Values can also be computed in const context
+/// const ROMAN_NUMERAL_FOUR: DerivedPropertyValue = IdentifierClass {}.get_value_from_char('Ⅳ');
+/// assert_eq!(ROMAN_NUMERAL_FOUR, DerivedPropertyValue::SpecClassDis);
 /// ```
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub struct IdentifierClass {}
 
-impl SpecificDerivedPropertyValue for IdentifierClass {
-    // `ID_DIS` mapped to `SPEC_CLASS_DIS`
-    fn on_has_compat(&self) -> DerivedPropertyValue {
-        DerivedPropertyValue::SpecClassDis
+impl IdentifierClass {
+    /// Gets the derived property value of a Unicode character, like
+    /// [`StringClass::get_value_from_char`], but it can be used in const context.
+    pub const fn get_value_from_char(&self, c: char) -> DerivedPropertyValue {
+        get_derived_property_value(c as u32, Class::Identifier)
     }
-    fn on_other_letter_digits(&self) -> DerivedPropertyValue {
-        DerivedPropertyValue::SpecClassDis
-    }
-    fn on_spaces(&self) -> DerivedPropertyValue {
-        DerivedPropertyValue::SpecClassDis
-    }
-    fn on_symbols(&self) -> DerivedPropertyValue {
-        DerivedPropertyValue::SpecClassDis
-    }
-    fn on_punctuation(&self) -> DerivedPropertyValue {
-        DerivedPropertyValue::SpecClassDis
+
+    /// Gets the derived property value of a Unicode code point, like
+    /// [`StringClass::get_value_from_codepoint`], but it can be used in const
+    /// context.
+    pub const fn get_value_from_codepoint(&self, cp: u32) -> DerivedPropertyValue {
+        get_derived_property_value(cp, Class::Identifier)
     }
 }
 
 impl StringClass for IdentifierClass {
     fn get_value_from_char(&self, c: char) -> DerivedPropertyValue {
-        get_derived_property_value(c as u32, self)
+        IdentifierClass::get_value_from_char(self, c)
     }
 
     fn get_value_from_codepoint(&self, cp: u32) -> DerivedPropertyValue {
-        get_derived_property_value(cp, self)
+        IdentifierClass::get_value_from_codepoint(self, cp)
     }
 }
 
@@ -235,35 +261,35 @@ impl StringClass for IdentifierClass {
 /// assert_eq!(ff.get_value_from_char('S'), DerivedPropertyValue::PValid);
 /// // Character 0x1170 is OldHangulJamo (I)
 /// assert_eq!(ff.get_value_from_codepoint(0x1170), DerivedPropertyValue::Disallowed);
+/// // Values can also be computed in const context
+/// const ROMAN_NUMERAL_FOUR: DerivedPropertyValue = FreeformClass {}.get_value_from_char('Ⅳ');
+/// assert_eq!(ROMAN_NUMERAL_FOUR, DerivedPropertyValue::SpecClassPval);
 /// ```
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub struct FreeformClass {}
 
-impl SpecificDerivedPropertyValue for FreeformClass {
-    fn on_has_compat(&self) -> DerivedPropertyValue {
-        DerivedPropertyValue::SpecClassPval
+impl FreeformClass {
+    /// Gets the derived property value of a Unicode character, like
+    /// [`StringClass::get_value_from_char`], but it can be used in const context.
+    pub const fn get_value_from_char(&self, c: char) -> DerivedPropertyValue {
+        get_derived_property_value(c as u32, Class::Freeform)
     }
-    fn on_other_letter_digits(&self) -> DerivedPropertyValue {
-        DerivedPropertyValue::SpecClassPval
-    }
-    fn on_spaces(&self) -> DerivedPropertyValue {
-        DerivedPropertyValue::SpecClassPval
-    }
-    fn on_symbols(&self) -> DerivedPropertyValue {
-        DerivedPropertyValue::SpecClassPval
-    }
-    fn on_punctuation(&self) -> DerivedPropertyValue {
-        DerivedPropertyValue::SpecClassPval
+
+    /// Gets the derived property value of a Unicode code point, like
+    /// [`StringClass::get_value_from_codepoint`], but it can be used in const
+    /// context.
+    pub const fn get_value_from_codepoint(&self, cp: u32) -> DerivedPropertyValue {
+        get_derived_property_value(cp, Class::Freeform)
     }
 }
 
 impl StringClass for FreeformClass {
     fn get_value_from_char(&self, c: char) -> DerivedPropertyValue {
-        get_derived_property_value(c as u32, self)
+        FreeformClass::get_value_from_char(self, c)
     }
 
     fn get_value_from_codepoint(&self, cp: u32) -> DerivedPropertyValue {
-        get_derived_property_value(cp, self)
+        FreeformClass::get_value_from_codepoint(self, cp)
     }
 }
 
@@ -599,4 +625,60 @@ mod test_string_classes {
         let result = id_class.allows("l\u{00b7}l");
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn const_derived_property_values() {
+        const ID: [DerivedPropertyValue; 5] = [
+            IdentifierClass {}.get_value_from_char('a'),
+            IdentifierClass {}.get_value_from_char('\u{2163}'),
+            IdentifierClass {}.get_value_from_codepoint(0x200d),
+            IdentifierClass {}.get_value_from_codepoint(0x1170),
+            IdentifierClass {}.get_value_from_codepoint(0x0378),
+        ];
+        const FREE: [DerivedPropertyValue; 2] = [
+            FreeformClass {}.get_value_from_char('\u{2163}'),
+            FreeformClass {}.get_value_from_char(' '),
+        ];
+        const _: () = assert!(matches!(
+            IdentifierClass {}.get_value_from_char('\u{00b7}'),
+            DerivedPropertyValue::ContextO
+        ));
+
+        assert_eq!(
+            ID,
+            [
+                DerivedPropertyValue::PValid,
+                DerivedPropertyValue::SpecClassDis,
+                DerivedPropertyValue::ContextJ,
+                DerivedPropertyValue::Disallowed,
+                DerivedPropertyValue::Unassigned,
+            ]
+        );
+        assert_eq!(
+            FREE,
+            [
+                DerivedPropertyValue::SpecClassPval,
+                DerivedPropertyValue::SpecClassPval,
+            ]
+        );
+    }
+
+    #[cfg(feature = "iana-tables")]
+    #[test]
+    fn registry_matches_computed_values() {
+        for cp in 0..=0x10ffff {
+            assert_eq!(
+                get_derived_property_value(cp, Class::Identifier),
+                compute_derived_property_value(cp, Class::Identifier),
+                "IdentifierClass mismatch for U+{:04X}",
+                cp
+            );
+            assert_eq!(
+                get_derived_property_value(cp, Class::Freeform),
+                compute_derived_property_value(cp, Class::Freeform),
+                "FreeformClass mismatch for U+{:04X}",
+                cp
+            );
+        }
+    }
 }
@@ -0,0 +1,56 @@
+// Checks that the tables committed in `src/generated` are those generated
+// from the files in `resources`. Run with `PRECIS_UPDATE_TABLES=1` to
+// regenerate them after updating the generators or the Unicode files.
+#[path = "../codegen.rs"]
+mod codegen;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const UPDATE_TABLES_ENV: &str = "PRECIS_UPDATE_TABLES";
+
+fn generate(out: &Path) {
+    let base_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let ucd = base_dir.join("resources/ucd");
+    let csv = base_dir.join(format!(
+        "resources/csv/precis-tables-{}.csv",
+        codegen::UNICODE_VERSION
+    ));
+    fs::create_dir_all(out).unwrap();
+    codegen::generate_code(&ucd, &csv, out);
+}
+
+fn file_names(dir: &Path) -> Vec<PathBuf> {
+    let mut names: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| PathBuf::from(entry.unwrap().file_name()))
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn generated_tables_are_up_to_date() {
+    let committed = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/generated");
+    if env::var_os(UPDATE_TABLES_ENV).is_some() {
+        generate(&committed);
+        return;
+    }
+
+    let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("precis-core-tables");
+    let _ = fs::remove_dir_all(&out);
+    generate(&out);
+
+    assert_eq!(file_names(&out), file_names(&committed));
+    for name in file_names(&out) {
+        let expected = fs::read(out.join(&name)).unwrap();
+        let actual = fs::read(committed.join(&name)).unwrap();
+        assert!(
+            expected == actual,
+            "src/generated/{} is out of date, run the tests with {}=1 to regenerate it",
+            name.display(),
+            UPDATE_TABLES_ENV
+        );
+    }
+}
@@ -0,0 +1,246 @@
+//! Procedural macros to enforce string literals with the PRECIS profiles
+//! implemented in [`precis-profiles`](https://docs.rs/precis-profiles) at
+//! compile time.
+//!
+//! The [`precis!`] macro enforces a string literal with a profile while the
+//! crate is compiled and expands to the enforced string, so hard-coded
+//! values such as reserved usernames or system nicknames are guaranteed to
+//! be in their enforced form without doing any work at runtime.
+//!
+//! ```rust
+//! use precis_macros::precis;
+//!
+//! const ADMIN: &str = precis!(UsernameCaseMapped, "Admin");
+//! assert_eq!(ADMIN, "admin");
+//!
+//! const SYSTEM: &str = precis!(Nickname, "  Système  ");
+//! assert_eq!(SYSTEM, "Système");
+//! ```
+//!
+//! Literals rejected by the profile fail to compile, reporting the code point
+//! that was not allowed:
+//!
+//! ```compile_fail
+//! use precis_macros::precis;
+//!
+//! // error: "root\u{2000}" is rejected by UsernameCaseMapped: bad codepoint: ...
+//! const ROOT: &str = precis!(UsernameCaseMapped, "root\u{2000}");
+//! ```
+
+#![deny(missing_docs)]
+
+use precis_core::profile::Profile;
+use precis_core::Error;
+use precis_profiles::{Nickname, OpaqueString, UsernameCaseMapped, UsernameCasePreserved};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, LitStr, Path, Token};
+
+// Profiles that can be used with the `precis!` macro
+const PROFILES: [&str; 4] = [
+    "UsernameCaseMapped",
+    "UsernameCasePreserved",
+    "OpaqueString",
+    "Nickname",
+];
+
+struct PrecisLiteral {
+    profile: Path,
+    literal: LitStr,
+}
+
+impl Parse for PrecisLiteral {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let profile = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let literal = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { profile, literal })
+    }
+}
+
+fn enforce(name: &str, s: &str) -> Option<Result<String, Error>> {
+    let res = match name {
+        "UsernameCaseMapped" => UsernameCaseMapped::new().enforce(s),
+        "UsernameCasePreserved" => UsernameCasePreserved::new().enforce(s),
+        "OpaqueString" => OpaqueString::new().enforce(s),
+        "Nickname" => Nickname::new().enforce(s),
+        _ => return None,
+    };
+    Some(res.map(|s| s.into_owned()))
+}
+
+// Gets the name of the profile from either a bare identifier, such as
+// `Nickname`, or its path in `precis_profiles`, such as
+// `precis_profiles::Nickname`.
+fn profile_name(path: &Path) -> syn::Result<String> {
+    let segments: Vec<_> = path.segments.iter().collect();
+    let segment = match segments.as_slice() {
+        [name] if path.leading_colon.is_none() => name,
+        [krate, name] if path.leading_colon.is_none() && krate.ident == "precis_profiles" => {
+            if !krate.arguments.is_none() {
+                return Err(syn::Error::new_spanned(
+                    &krate.arguments,
+                    "unexpected arguments in PRECIS profile path",
+                ));
+            }
+            name
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                path,
+                "expected a PRECIS profile name, such as `Nickname`, or its path \
+                 in `precis_profiles`, such as `precis_profiles::Nickname`",
+            ))
+        }
+    };
+    if !segment.arguments.is_none() {
+        return Err(syn::Error::new_spanned(
+            &segment.arguments,
+            "unexpected arguments in PRECIS profile path",
+        ));
+    }
+    Ok(segment.ident.to_string())
+}
+
+fn expand(input: PrecisLiteral) -> syn::Result<LitStr> {
+    let name = profile_name(&input.profile)?;
+    let value = input.literal.value();
+    match enforce(&name, &value) {
+        Some(Ok(enforced)) => Ok(LitStr::new(&enforced, input.literal.span())),
+        // The display of derived property values ends with a new line
+        Some(Err(e)) => Err(syn::Error::new(
+            input.literal.span(),
+            format!(
+                "{:?} is rejected by {}: {}",
+                value,
+                name,
+                e.to_string().trim_end()
+            ),
+        )),
+        None => Err(syn::Error::new_spanned(
+            &input.profile,
+            format!(
+                "unknown PRECIS profile `{}`, expected one of: {}",
+                name,
+                PROFILES.join(", ")
+            ),
+        )),
+    }
+}
+
+/// Enforces a string literal with a PRECIS profile at compile time and
+/// expands to the enforced `&'static str`.
+///
+/// The first argument is the name of the profile, one of
+/// `UsernameCaseMapped`, `UsernameCasePreserved`, `OpaqueString` or
+/// `Nickname`, optionally with its path in `precis_profiles`, such as
+/// `precis_profiles::Nickname`. The second argument is the string literal to
+/// enforce. Compilation fails with the [`Error`] returned by the profile,
+/// including the [`CodepointInfo`](precis_core::CodepointInfo) of the
+/// rejected code point, if the literal can not be enforced.
+///
+/// ```rust
+/// use precis_macros::precis;
+///
+/// assert_eq!(precis!(UsernameCasePreserved, "Juliet"), "Juliet");
+/// assert_eq!(precis!(precis_profiles::OpaqueString, "correct horse"), "correct horse");
+/// ```
+#[proc_macro]
+pub fn precis(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as PrecisLiteral);
+    match expand(input) {
+        Ok(lit) => quote!(#lit).into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_str(input: &str) -> Result<String, String> {
+        let input = syn::parse_str::<PrecisLiteral>(input).map_err(|e| e.to_string())?;
+        expand(input)
+            .map(|lit| lit.value())
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn enforced_literals() {
+        assert_eq!(
+            expand_str(r#"UsernameCaseMapped, "Admin""#),
+            Ok(String::from("admin"))
+        );
+        assert_eq!(
+            expand_str(r#"precis_profiles::Nickname, "  Système  ","#),
+            Ok(String::from("Système"))
+        );
+    }
+
+    #[test]
+    fn rejected_literals() {
+        assert_eq!(
+            expand_str(r#"UsernameCaseMapped, "root\u{2000}""#),
+            Err(String::from(
+                "\"root\\u{2000}\" is rejected by UsernameCaseMapped: bad codepoint: \
+                 code point 0x2000, position: 4, property: SpecClassDis"
+            ))
+        );
+        assert_eq!(
+            expand_str(r#"Nickname, "   ""#),
+            Err(String::from(
+                "\"   \" is rejected by Nickname: invalid label"
+            ))
+        );
+    }
+
+    #[test]
+    fn bad_input() {
+        assert_eq!(
+            expand_str(r#"Username, "admin""#),
+            Err(String::from(
+                "unknown PRECIS profile `Username`, expected one of: \
+                 UsernameCaseMapped, UsernameCasePreserved, OpaqueString, Nickname"
+            ))
+        );
+        assert_eq!(
+            expand_str("UsernameCaseMapped, ADMIN"),
+            Err(String::from("expected string literal"))
+        );
+        assert!(expand_str(r#"UsernameCaseMapped "admin""#).is_err());
+    }
+
+    #[test]
+    fn bad_profile_paths() {
+        let expected = Err(String::from(
+            "expected a PRECIS profile name, such as `Nickname`, or its path \
+             in `precis_profiles`, such as `precis_profiles::Nickname`",
+        ));
+        assert_eq!(expand_str(r#"my_crate::Nickname, "Juliet""#), expected);
+        assert_eq!(
+            expand_str(r#"::precis_profiles::Nickname, "Juliet""#),
+            expected
+        );
+        assert_eq!(
+            expand_str(r#"precis_profiles::nicknames::Nickname, "Juliet""#),
+            expected
+        );
+        assert_eq!(
+            expand_str(r#"Nickname<u8>, "Juliet""#),
+            Err(String::from("unexpected arguments in PRECIS profile path"))
+        );
+        assert_eq!(
+            expand_str(r#"precis_profiles<u8>::Nickname, "Juliet""#),
+            Err(String::from("unexpected arguments in PRECIS profile path"))
+        );
+        assert_eq!(
+            expand_str(r#"precis_profiles::Username, "Juliet""#),
+            Err(String::from(
+                "unknown PRECIS profile `Username`, expected one of: \
+                 UsernameCaseMapped, UsernameCasePreserved, OpaqueString, Nickname"
+            ))
+        );
+    }
+}
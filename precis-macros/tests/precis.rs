@@ -0,0 +1,41 @@
+use precis_core::profile::PrecisFastInvocation;
+use precis_macros::precis;
+use precis_profiles::{Nickname, OpaqueString, UsernameCaseMapped, UsernameCasePreserved};
+
+const RESERVED_USERNAMES: [&str; 3] = [
+    precis!(UsernameCaseMapped, "Admin"),
+    precis!(UsernameCaseMapped, "ROOT"),
+    precis!(UsernameCaseMapped, "Système"),
+];
+
+#[test]
+fn enforced_literals() {
+    assert_eq!(RESERVED_USERNAMES, ["admin", "root", "système"]);
+    assert_eq!(precis!(UsernameCasePreserved, "Juliet"), "Juliet");
+    assert_eq!(precis!(OpaqueString, "correct horse"), "correct horse");
+    assert_eq!(
+        precis!(Nickname, "  Guybrush   Threepwood "),
+        "Guybrush Threepwood"
+    );
+    assert_eq!(precis!(precis_profiles::Nickname, "Système",), "Système");
+}
+
+#[test]
+fn same_as_runtime_enforcement() {
+    assert_eq!(
+        precis!(UsernameCaseMapped, "\u{FF2A}\u{FF55}liet"),
+        UsernameCaseMapped::enforce("\u{FF2A}\u{FF55}liet").unwrap()
+    );
+    assert_eq!(
+        precis!(UsernameCasePreserved, "\u{FF2A}\u{FF55}liet"),
+        UsernameCasePreserved::enforce("\u{FF2A}\u{FF55}liet").unwrap()
+    );
+    assert_eq!(
+        precis!(OpaqueString, "\u{212B}ngstr\u{00F6}m\u{00A0}"),
+        OpaqueString::enforce("\u{212B}ngstr\u{00F6}m\u{00A0}").unwrap()
+    );
+    assert_eq!(
+        precis!(Nickname, "\u{212B}ngstr\u{00F6}m\u{00A0}"),
+        Nickname::enforce("\u{212B}ngstr\u{00F6}m\u{00A0}").unwrap()
+    );
+}
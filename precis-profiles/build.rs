@@ -1,53 +1,44 @@
 // build.rs
-use precis_tools::{
-    BidiClassGen, GeneralCategoryGen, RustCodeGen, UcdFileGen, UcdTableGen, UnicodeVersionGen,
-    WidthMappingTableGen,
-};
+#[cfg(feature = "regenerate")]
+mod codegen;
+
+#[cfg(feature = "regenerate")]
+use codegen::generate_code;
+#[cfg(feature = "regenerate")]
 use std::env;
+#[cfg(feature = "regenerate")]
 use std::path::Path;
 
-const UNICODE_VERSION: &str = "17.0.0";
-
-fn generate_code(ucd: &Path, out: &Path) {
-    let mut gen = RustCodeGen::new(Path::new(&out).join("bidi_class.rs")).unwrap();
-    let mut ucd_gen = UcdFileGen::new(ucd);
-    let mut gc_gen = GeneralCategoryGen::new();
-    gc_gen.add(Box::new(BidiClassGen::new("Bidi_Class_Table")));
-    ucd_gen.add(Box::new(gc_gen));
-    gen.add(Box::new(ucd_gen));
-    gen.generate_code().unwrap();
-
-    let mut gen = RustCodeGen::new(Path::new(&out).join("unicode_version.rs")).unwrap();
-    gen.add(Box::new(UnicodeVersionGen::new(UNICODE_VERSION)));
-    gen.generate_code().unwrap();
-
-    let mut gen = RustCodeGen::new(Path::new(&out).join("space_separator.rs")).unwrap();
-    let mut ucd_gen = UcdFileGen::new(ucd);
-    let mut gc_gen = GeneralCategoryGen::new();
-    gc_gen.add(Box::new(UcdTableGen::new("Zs", "space_separator")));
-    ucd_gen.add(Box::new(gc_gen));
-    gen.add(Box::new(ucd_gen));
-    gen.generate_code().unwrap();
-
-    let mut gen = RustCodeGen::new(Path::new(&out).join("width_mapping.rs")).unwrap();
-    let mut ucd_gen = UcdFileGen::new(ucd);
-    let mut gc_gen = GeneralCategoryGen::new();
-    gc_gen.add(Box::new(WidthMappingTableGen::new("wide_narrow_mapping")));
-    ucd_gen.add(Box::new(gc_gen));
-    gen.add(Box::new(ucd_gen));
-    gen.generate_code().unwrap();
-}
-
 #[cfg(feature = "networking")]
-mod download_ucd {
-
-    use crate::*;
-    use std::fs;
-
-    pub fn create_dir(path: &Path) {
-        if !path.is_dir() {
-            fs::create_dir(path).unwrap();
-        }
+mod networking {
+
+    use crate::codegen::UNICODE_VERSION;
+    use precis_tools::download::{Fetcher, Manifest};
+    use precis_tools::Error;
+    use std::env;
+    use std::path::Path;
+
+    pub fn download_files(out: &Path) -> Result<(), Error> {
+        let base_dir = env::var_os("CARGO_MANIFEST_DIR").unwrap();
+        let manifest = Manifest::from_path(
+            Path::new(&base_dir).join(format!("resources/sha256-{}.txt", UNICODE_VERSION)),
+        )?;
+        let fetcher = Fetcher::from_env(UNICODE_VERSION).with_manifest(manifest);
+
+        let ucd_path = Path::new(&out).join("ucd");
+        fetcher.get_ucd_file(&ucd_path, "UnicodeData.txt")?;
+        fetcher.get_ucd_file(&ucd_path, "Scripts.txt")?;
+        fetcher.get_ucd_file(&ucd_path, "ScriptExtensions.txt")?;
+        fetcher.get_ucd_file(&ucd_path, "PropertyValueAliases.txt")?;
+        fetcher.get_ucd_file(&ucd_path, "DerivedCoreProperties.txt")?;
+        fetcher.get_ucd_file(&ucd_path, "extracted/DerivedBidiClass.txt")?;
+        fetcher.get_ucd_file(&ucd_path, "emoji/emoji-data.txt")?;
+
+        let security_path = Path::new(&out).join("security");
+        fetcher.get_security_file(&security_path, "confusables.txt")?;
+
+        let emoji_path = Path::new(&out).join("emoji");
+        fetcher.get_emoji_file(&emoji_path, "emoji-test.txt")
     }
 }
 
@@ -56,25 +47,67 @@ fn main() {
     let out_dir = env::var_os("OUT_DIR").unwrap();
     let out_path = Path::new(&out_dir);
     let ucd_path = Path::new(&out_dir).join("ucd");
+    let security_path = Path::new(&out_dir).join("security");
+    let emoji_path = Path::new(&out_dir).join("emoji");
 
-    download_ucd::create_dir(&ucd_path);
+    if let Err(e) = networking::download_files(out_path) {
+        panic!("Failed to fetch the Unicode files: {}", e);
+    }
 
-    precis_tools::download::get_ucd_file(UNICODE_VERSION, &ucd_path, "UnicodeData.txt").unwrap();
+    let base_dir = env::var_os("CARGO_MANIFEST_DIR").unwrap();
+    let stringprep_path = Path::new(&base_dir).join("resources/stringprep");
 
-    generate_code(&ucd_path, out_path);
+    generate_code(
+        &ucd_path,
+        &stringprep_path,
+        &security_path,
+        &emoji_path,
+        out_path,
+    );
 
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=codegen.rs");
+    println!(
+        "cargo:rerun-if-changed=resources/sha256-{}.txt",
+        codegen::UNICODE_VERSION
+    );
+    println!(
+        "cargo:rerun-if-env-changed={}",
+        precis_tools::download::CACHE_DIR_ENV
+    );
+    println!(
+        "cargo:rerun-if-env-changed={}",
+        precis_tools::download::MIRROR_URL_ENV
+    );
 }
 
-#[cfg(not(feature = "networking"))]
+#[cfg(all(feature = "regenerate", not(feature = "networking")))]
 fn main() {
     let out_dir = env::var_os("OUT_DIR").unwrap();
     let out_path = Path::new(&out_dir);
 
     let base_dir = env::var_os("CARGO_MANIFEST_DIR").unwrap();
     let ucd_path = Path::new(&base_dir).join("resources/ucd");
+    let stringprep_path = Path::new(&base_dir).join("resources/stringprep");
+    let security_path = Path::new(&base_dir).join("resources/security");
+    let emoji_path = Path::new(&base_dir).join("resources/emoji");
+
+    generate_code(
+        &ucd_path,
+        &stringprep_path,
+        &security_path,
+        &emoji_path,
+        out_path,
+    );
 
-    generate_code(&ucd_path, out_path);
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=codegen.rs");
+    println!("cargo:rerun-if-changed=resources");
+}
 
+// Tables are included from `src/generated` unless the `regenerate`
+// feature is enabled
+#[cfg(not(feature = "regenerate"))]
+fn main() {
     println!("cargo:rerun-if-changed=build.rs");
 }
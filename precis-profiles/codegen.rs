@@ -0,0 +1,124 @@
+// Table generation shared by the build script, when the `regenerate`
+// feature is enabled, and by the test checking that the tables committed
+// in `src/generated` are up to date.
+use precis_tools::{
+    BidiClassGen, ConfusableMappingGen, ConfusablesGen, EmojiZwjSequencesGen, GeneralCategoryGen,
+    RustCodeGen, ScriptsGen, StringprepGen, StringprepMappingGen, Ucd, UcdFileGen, UcdTableGen,
+    UnicodeGen, UnicodeVersionGen, WidthMappingTableGen,
+};
+use std::path::Path;
+use ucd_parse::{CoreProperty, EmojiProperty};
+
+pub const UNICODE_VERSION: &str = "17.0.0";
+
+fn generate_ucd_code(ucd: &Path, emoji: &Path, out: &Path) {
+    // Files shared by several tables are only parsed once
+    let ucd = Ucd::new(ucd);
+
+    // Complete table read from `extracted/DerivedBidiClass.txt`, including
+    // the default values of the unassigned code points
+    let mut gen = RustCodeGen::new(Path::new(&out).join("bidi_class.rs")).unwrap();
+    let mut ucd_gen = UcdFileGen::from_ucd(&ucd);
+    ucd_gen.add(Box::new(BidiClassGen::new("Bidi_Class_Table")));
+    gen.add(Box::new(ucd_gen));
+    gen.generate_code().unwrap();
+
+    let mut gen = RustCodeGen::new(Path::new(&out).join("unicode_version.rs")).unwrap();
+    gen.add(Box::new(UnicodeVersionGen::new(UNICODE_VERSION)));
+    gen.generate_code().unwrap();
+
+    let mut gen = RustCodeGen::new(Path::new(&out).join("space_separator.rs")).unwrap();
+    let mut ucd_gen = UcdFileGen::from_ucd(&ucd);
+    let mut gc_gen = GeneralCategoryGen::new();
+    gc_gen.add(Box::new(UcdTableGen::new("Zs", "space_separator")));
+    ucd_gen.add(Box::new(gc_gen));
+    gen.add(Box::new(ucd_gen));
+    gen.generate_code().unwrap();
+
+    // Invisible code points removed from the confusable skeletons
+    let mut gen = RustCodeGen::new(Path::new(&out).join("default_ignorable.rs")).unwrap();
+    let mut ucd_gen = UcdFileGen::from_ucd(&ucd);
+    let mut core_prop_gen: UnicodeGen<CoreProperty> = UnicodeGen::new();
+    core_prop_gen.add(Box::new(UcdTableGen::new(
+        "Default_Ignorable_Code_Point",
+        "default_ignorable_code_point",
+    )));
+    ucd_gen.add(Box::new(core_prop_gen));
+    gen.add(Box::new(ucd_gen));
+    gen.generate_code().unwrap();
+
+    let mut gen = RustCodeGen::new(Path::new(&out).join("width_mapping.rs")).unwrap();
+    let mut ucd_gen = UcdFileGen::from_ucd(&ucd);
+    let mut gc_gen = GeneralCategoryGen::new();
+    gc_gen.add(Box::new(WidthMappingTableGen::new("wide_narrow_mapping")));
+    ucd_gen.add(Box::new(gc_gen));
+    gen.add(Box::new(ucd_gen));
+    gen.generate_code().unwrap();
+
+    let mut gen = RustCodeGen::new(Path::new(&out).join("emoji.rs")).unwrap();
+    let mut ucd_gen = UcdFileGen::from_ucd(&ucd);
+    let mut emoji_gen: UnicodeGen<EmojiProperty> = UnicodeGen::new();
+    emoji_gen.add(Box::new(UcdTableGen::new(
+        "Extended_Pictographic",
+        "extended_pictographic",
+    )));
+    emoji_gen.add(Box::new(UcdTableGen::new(
+        "Emoji_Modifier",
+        "emoji_modifier",
+    )));
+    ucd_gen.add(Box::new(emoji_gen));
+    gen.add(Box::new(ucd_gen));
+    // The emoji sequences are not part of the UCD
+    let mut file_gen = UcdFileGen::new(emoji);
+    file_gen.add(Box::new(EmojiZwjSequencesGen::new(
+        "rgi_emoji_zwj_sequences",
+    )));
+    gen.add(Box::new(file_gen));
+    gen.generate_code().unwrap();
+
+    let mut gen = RustCodeGen::new(Path::new(&out).join("scripts.rs")).unwrap();
+    let mut ucd_gen = UcdFileGen::from_ucd(&ucd);
+    ucd_gen.add(Box::new(ScriptsGen::new("scripts", "script_extensions")));
+    gen.add(Box::new(ucd_gen));
+    gen.generate_code().unwrap();
+}
+
+// Tables defined in rfc3454 used by the legacy stringprep profiles
+const STRINGPREP_TABLES: [&str; 14] = [
+    "A.1", "B.1", "C.1.1", "C.1.2", "C.2.1", "C.2.2", "C.3", "C.4", "C.5", "C.6", "C.7", "C.8",
+    "C.9", "D.1",
+];
+
+fn generate_stringprep_code(stringprep: &Path, out: &Path) {
+    let mut gen = RustCodeGen::new(Path::new(&out).join("stringprep_tables.rs")).unwrap();
+    let mut file_gen = UcdFileGen::new(stringprep);
+    let mut sp_gen = StringprepGen::new();
+    for table in STRINGPREP_TABLES {
+        let name = format!("stringprep_{}", table.replace('.', "_"));
+        sp_gen.add(Box::new(UcdTableGen::new(table, &name)));
+    }
+    sp_gen.add(Box::new(UcdTableGen::new("D.2", "stringprep_D_2")));
+    sp_gen.add(Box::new(StringprepMappingGen::new("B.2", "stringprep_B_2")));
+    file_gen.add(Box::new(sp_gen));
+    gen.add(Box::new(file_gen));
+    gen.generate_code().unwrap();
+}
+
+fn generate_confusables_code(security: &Path, out: &Path) {
+    let mut gen = RustCodeGen::new(Path::new(&out).join("confusables.rs")).unwrap();
+    let mut file_gen = UcdFileGen::new(security);
+    let mut confusables_gen = ConfusablesGen::new();
+    confusables_gen.add(Box::new(ConfusableMappingGen::new("confusables")));
+    file_gen.add(Box::new(confusables_gen));
+    gen.add(Box::new(file_gen));
+    gen.generate_code().unwrap();
+}
+
+/// Generates every table from the `UCD` files in `ucd`, the `rfc3454`
+/// tables in `stringprep`, the security files in `security` and the emoji
+/// sequences in `emoji` into the directory `out`.
+pub fn generate_code(ucd: &Path, stringprep: &Path, security: &Path, emoji: &Path, out: &Path) {
+    generate_ucd_code(ucd, emoji, out);
+    generate_stringprep_code(stringprep, out);
+    generate_confusables_code(security, out);
+}
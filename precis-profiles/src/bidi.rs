@@ -1,4 +1,4 @@
-include!(concat!(env!("OUT_DIR"), "/bidi_class.rs"));
+include_table!("bidi_class.rs");
 
 use precis_core::Codepoints;
 
@@ -6,14 +6,15 @@ use precis_core::Codepoints;
 fn bidi_class_cp(cp: u32) -> BidiClass {
     match BIDI_CLASS_TABLE.binary_search_by(|(cps, _)| cps.partial_cmp(&cp).unwrap()) {
         Ok(idx) => BIDI_CLASS_TABLE[idx].1,
-        // `UCD/extracted/DerivedBidiClass.txt`: "All code points not explicitly listed
-        // for `Bidi_Class` have the value `Left_To_Right` (L)."
+        // The table covers all code points, including the defaults of the
+        // unassigned ones in `UCD/extracted/DerivedBidiClass.txt`. Values
+        // beyond the Unicode code space are `Left_To_Right` (L).
         Err(_) => BidiClass::L,
     }
 }
 
 #[inline]
-fn bidi_class(c: char) -> BidiClass {
+pub(crate) fn bidi_class(c: char) -> BidiClass {
     bidi_class_cp(c as u32)
 }
 
@@ -237,9 +238,16 @@ mod bidi_tests {
         assert_eq!(bidi_class(NSM), BidiClass::NSM);
         assert_eq!(bidi_class(WS), BidiClass::WS);
 
-        // All code points not explicitly listed `Bidi_Class`
-        // have the value `Left_To_Right` (L).
-        assert_eq!(bidi_class('\u{e0080}'), BidiClass::L);
+        // Unassigned code points take the default values
+        // listed in `DerivedBidiClass.txt`
+        assert_eq!(bidi_class('\u{50000}'), BidiClass::L);
+        assert_eq!(bidi_class('\u{05ff}'), BidiClass::R);
+        assert_eq!(bidi_class('\u{07fb}'), BidiClass::R);
+        assert_eq!(bidi_class('\u{086f}'), BidiClass::AL);
+        assert_eq!(bidi_class('\u{20cf}'), BidiClass::ET);
+        assert_eq!(bidi_class('\u{1efff}'), BidiClass::R);
+        assert_eq!(bidi_class('\u{e0080}'), BidiClass::BN);
+        assert_eq!(bidi_class('\u{fdd0}'), BidiClass::BN);
     }
 
     #[test]
@@ -552,8 +560,12 @@ mod bidi_tests {
         assert_eq!(bidi_class_cp(R as u32), BidiClass::R);
         assert_eq!(bidi_class_cp(AL as u32), BidiClass::AL);
 
-        // Test default case (unassigned codepoints default to L)
-        assert_eq!(bidi_class_cp(0xE0080), BidiClass::L);
+        // Test default cases of unassigned codepoints
+        assert_eq!(bidi_class_cp(0x50000), BidiClass::L);
+        assert_eq!(bidi_class_cp(0xE0080), BidiClass::BN);
+
+        // Values beyond the Unicode code space default to L
+        assert_eq!(bidi_class_cp(0x110000), BidiClass::L);
 
         // Test all major BiDi classes
         assert_eq!(bidi_class_cp(AN as u32), BidiClass::AN);
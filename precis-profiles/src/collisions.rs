@@ -0,0 +1,844 @@
+//! Bulk detection of strings that become equal, or are rejected, when
+//! they are enforced with a profile.
+//!
+//! Applications moving stored identifiers, such as the names of existing
+//! accounts, to a `PRECIS` profile need to know which of them will collide
+//! and which will no longer be accepted before the migration takes place.
+//! A [`CollisionDetector`] enforces every input with a profile, groups the
+//! inputs by their enforced form and keeps the rejected inputs apart.
+//!
+//! Inputs are kept in memory up to a configurable limit. Once the limit
+//! is reached, they are sorted and written to temporary files which are
+//! merged when the results are read, so datasets larger than the
+//! available memory can be checked. Temporary files are removed when the
+//! [`Report`] is dropped. Only the first members of each group are kept
+//! in memory when the groups are read, so a single group with many inputs
+//! does not exhaust it either.
+//! # Example
+//! ```rust
+//! # use precis_profiles::collisions::CollisionDetector;
+//! # use precis_profiles::UsernameCaseMapped;
+//! # use precis_core::Error;
+//! let detector = CollisionDetector::new(UsernameCaseMapped::new());
+//! let report = detector.detect(["Juliet", "romeo", "", "JULIET"]).unwrap();
+//!
+//! let groups = report.groups().unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+//! assert_eq!(groups.len(), 1);
+//! assert_eq!(groups[0].key, "juliet");
+//! assert_eq!(groups[0].size, 2);
+//! assert_eq!(groups[0].members[0].input, "Juliet");
+//! assert_eq!(groups[0].members[1].input, "JULIET");
+//!
+//! let rejects = report.rejects().unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+//! assert_eq!(rejects.len(), 1);
+//! assert_eq!(rejects[0].index, 2);
+//! assert_eq!(rejects[0].error, Error::Invalid);
+//! ```
+
+use precis_core::profile::Profile;
+use precis_core::{
+    CodepointInfo, DerivedPropertyValue, Error, LengthInfo, LengthLimit, LengthUnit,
+    UnexpectedError,
+};
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use std::{env, mem, process};
+
+// Default amount of memory used to keep the inputs before they are
+// written to disk
+const DEFAULT_MEMORY_LIMIT: usize = 64 * 1024 * 1024;
+
+// Maximum number of runs merged at once. Runs are merged in several passes
+// when there are more, so the number of open files stays bounded.
+const MERGE_FAN_IN: usize = 64;
+
+// Default number of members kept for each group
+const DEFAULT_MAX_MEMBERS: usize = 1024;
+
+// Approximated memory used by each record besides its strings
+const RECORD_OVERHEAD: usize = mem::size_of::<Record>();
+
+static SPILL_FILE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Input string that belongs to an [`EquivalenceGroup`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    /// Position of the input in the sequence of inputs, starting at zero
+    pub index: u64,
+    /// The input string
+    pub input: String,
+}
+
+/// Inputs that are equal once they are enforced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EquivalenceGroup {
+    /// The enforced form shared by all the members
+    pub key: String,
+    /// The first inputs of the group, in the same order they were
+    /// provided. There are always two members at least, and no more than
+    /// the limit set with [`CollisionDetector::with_max_members`].
+    pub members: Vec<Member>,
+    /// Number of inputs in the group, including those not kept in
+    /// `members`
+    pub size: u64,
+}
+
+/// Input rejected by the profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reject {
+    /// Position of the input in the sequence of inputs, starting at zero
+    pub index: u64,
+    /// The input string
+    pub input: String,
+    /// Error returned when the input was enforced
+    pub error: Error,
+}
+
+/// Finds the inputs that become equal, or are rejected, when they are
+/// enforced with a profile.
+/// # Example
+/// ```rust
+/// # use precis_profiles::collisions::CollisionDetector;
+/// # use precis_profiles::Nickname;
+/// // Use at most 1 MiB of memory before spilling inputs to disk
+/// let detector = CollisionDetector::new(Nickname::new())
+///     .with_memory_limit(1024 * 1024)
+///     .with_max_members(16)
+///     .with_spill_dir(std::env::temp_dir());
+/// let report = detector.detect(["Guybrush  Threepwood", "Guybrush Threepwood"]).unwrap();
+/// assert_eq!(report.inputs(), 2);
+/// assert_eq!(report.groups().unwrap().count(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CollisionDetector<P> {
+    profile: P,
+    memory_limit: usize,
+    spill_dir: PathBuf,
+    max_members: usize,
+    merge_fan_in: usize,
+}
+
+impl<P: Profile> CollisionDetector<P> {
+    /// Creates a detector that enforces the inputs with `profile`. Up to
+    /// 64 MiB of memory are used before inputs are written to files in the
+    /// directory returned by [`env::temp_dir`], and up to 1024 members are
+    /// kept for each group.
+    pub fn new(profile: P) -> Self {
+        Self {
+            profile,
+            memory_limit: DEFAULT_MEMORY_LIMIT,
+            spill_dir: env::temp_dir(),
+            max_members: DEFAULT_MAX_MEMBERS,
+            merge_fan_in: MERGE_FAN_IN,
+        }
+    }
+
+    /// Sets the approximated amount of memory, in bytes, used to keep the
+    /// inputs before they are written to disk.
+    pub fn with_memory_limit(mut self, bytes: usize) -> Self {
+        self.memory_limit = bytes;
+        self
+    }
+
+    /// Sets the number of members kept for each [`EquivalenceGroup`].
+    /// Members beyond the limit are only counted in
+    /// [`EquivalenceGroup::size`]. At least two members are always kept.
+    pub fn with_max_members(mut self, members: usize) -> Self {
+        self.max_members = members.max(2);
+        self
+    }
+
+    /// Sets the directory where the temporary files are created.
+    pub fn with_spill_dir<D: Into<PathBuf>>(mut self, dir: D) -> Self {
+        self.spill_dir = dir.into();
+        self
+    }
+
+    /// Returns the profile used to enforce the inputs.
+    pub fn profile(&self) -> &P {
+        &self.profile
+    }
+
+    /// Enforces all the inputs with the profile.
+    /// # Arguments:
+    /// * `inputs`: Strings to check
+    /// # Returns
+    /// A [`Report`] to read the equivalence groups and the rejected inputs,
+    /// or an error if the temporary files could not be written.
+    pub fn detect<I, S>(&self, inputs: I) -> io::Result<Report<'_, P>>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut report = Report {
+            profile: &self.profile,
+            spill_dir: &self.spill_dir,
+            max_members: self.max_members,
+            merge_fan_in: self.merge_fan_in,
+            inputs: 0,
+            rejected: 0,
+            runs: Vec::new(),
+            records: Vec::new(),
+            rejects: Vec::new(),
+            rejects_file: None,
+        };
+        let mut used = 0;
+
+        for (index, input) in (0u64..).zip(inputs) {
+            let input = input.as_ref();
+            report.inputs += 1;
+            match self.profile.enforce(input) {
+                Ok(key) => {
+                    used += RECORD_OVERHEAD + key.len() + input.len();
+                    report.records.push(Record {
+                        key: key.into_owned(),
+                        index,
+                        input: String::from(input),
+                    });
+                }
+                Err(error) => {
+                    used += RECORD_OVERHEAD + input.len();
+                    report.rejected += 1;
+                    report.rejects.push((index, String::from(input), error));
+                }
+            }
+
+            if used >= self.memory_limit {
+                report.spill()?;
+                used = 0;
+            }
+        }
+
+        report.records.sort_unstable();
+        Ok(report)
+    }
+}
+
+/// Results of a [`CollisionDetector`].
+#[derive(Debug)]
+pub struct Report<'p, P> {
+    profile: &'p P,
+    spill_dir: &'p Path,
+    max_members: usize,
+    merge_fan_in: usize,
+    inputs: u64,
+    rejected: u64,
+    // Sorted runs written to disk
+    runs: Vec<SpillFile>,
+    // Sorted records not written to disk
+    records: Vec<Record>,
+    // Rejected inputs, and their errors, not written to disk
+    rejects: Vec<(u64, String, Error)>,
+    rejects_file: Option<SpillFile>,
+}
+
+impl<P: Profile> Report<'_, P> {
+    /// Returns the number of inputs checked.
+    pub fn inputs(&self) -> u64 {
+        self.inputs
+    }
+
+    /// Returns the number of inputs rejected by the profile.
+    pub fn rejected(&self) -> u64 {
+        self.rejected
+    }
+
+    /// Returns the profile used to enforce the inputs.
+    pub fn profile(&self) -> &P {
+        self.profile
+    }
+
+    /// Returns the [`EquivalenceGroup`]s sorted by their enforced form.
+    /// Inputs that do not collide with any other input are not reported.
+    pub fn groups(&self) -> io::Result<Groups<'_>> {
+        // Merge runs in several passes until they can all be open at once
+        let mut merged = Vec::new();
+        while merged.len() > self.merge_fan_in
+            || (merged.is_empty() && self.runs.len() > self.merge_fan_in)
+        {
+            let runs = if merged.is_empty() {
+                &self.runs
+            } else {
+                &merged
+            };
+            let mut next = Vec::new();
+            for chunk in runs.chunks(self.merge_fan_in) {
+                let file = SpillFile::create(self.spill_dir)?;
+                let mut writer = BufWriter::new(file.append()?);
+                for record in Merge::new(run_sources(chunk)?) {
+                    record?.write(&mut writer)?;
+                }
+                writer.flush()?;
+                next.push(file);
+            }
+            merged = next;
+        }
+
+        let runs = if merged.is_empty() {
+            &self.runs
+        } else {
+            &merged
+        };
+        let mut sources = run_sources(runs)?;
+        sources.push(Box::new(self.records.iter().cloned().map(Ok)));
+        Ok(Groups {
+            merge: Merge::new(sources),
+            max_members: self.max_members,
+            _runs: merged,
+        })
+    }
+
+    /// Returns the [`Reject`]s in the same order as the inputs.
+    pub fn rejects(&self) -> io::Result<Rejects<'_>> {
+        let in_memory = self.rejects.iter().map(|(index, input, error)| {
+            Ok(Reject {
+                index: *index,
+                input: input.clone(),
+                error: error.clone(),
+            })
+        });
+        let mut rejects: Box<dyn Iterator<Item = io::Result<Reject>> + '_> = Box::new(in_memory);
+        if let Some(file) = &self.rejects_file {
+            let mut reader = file.reader()?;
+            let spilled = std::iter::from_fn(move || read_reject(&mut reader).transpose());
+            rejects = Box::new(spilled.chain(rejects));
+        }
+        Ok(Rejects { rejects })
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        let dir = self.spill_dir;
+        if !self.records.is_empty() {
+            self.records.sort_unstable();
+            let file = SpillFile::create(dir)?;
+            let mut writer = BufWriter::new(file.append()?);
+            for record in self.records.drain(..) {
+                record.write(&mut writer)?;
+            }
+            writer.flush()?;
+            self.runs.push(file);
+        }
+
+        if !self.rejects.is_empty() {
+            if self.rejects_file.is_none() {
+                self.rejects_file = Some(SpillFile::create(dir)?);
+            }
+            let file = self.rejects_file.as_ref().unwrap();
+            let mut writer = BufWriter::new(file.append()?);
+            for (index, input, error) in self.rejects.drain(..) {
+                write_reject(&mut writer, index, &input, &error)?;
+            }
+            writer.flush()?;
+        }
+
+        self.records.shrink_to_fit();
+        self.rejects.shrink_to_fit();
+        Ok(())
+    }
+}
+
+type Source<'a> = Box<dyn Iterator<Item = io::Result<Record>> + 'a>;
+
+// Opens a reader for each run
+fn run_sources(runs: &[SpillFile]) -> io::Result<Vec<Source<'static>>> {
+    let mut sources: Vec<Source<'static>> = Vec::new();
+    for run in runs {
+        let mut reader = run.reader()?;
+        sources.push(Box::new(std::iter::from_fn(move || {
+            Record::read(&mut reader).transpose()
+        })));
+    }
+    Ok(sources)
+}
+
+// Merges sorted sources into a single sorted sequence of records
+struct Merge<'a> {
+    sources: Vec<Source<'a>>,
+    // Next record of each source, smallest first
+    heap: BinaryHeap<Reverse<(Record, usize)>>,
+    error: Option<io::Error>,
+}
+
+impl<'a> Merge<'a> {
+    fn new(sources: Vec<Source<'a>>) -> Self {
+        let mut merge = Self {
+            sources,
+            heap: BinaryHeap::new(),
+            error: None,
+        };
+        for source in 0..merge.sources.len() {
+            merge.fill(source);
+        }
+        merge
+    }
+
+    fn fill(&mut self, source: usize) {
+        match self.sources[source].next() {
+            Some(Ok(record)) => self.heap.push(Reverse((record, source))),
+            Some(Err(e)) => self.error = Some(e),
+            None => {}
+        }
+    }
+
+    fn peek(&self) -> Option<&Record> {
+        self.heap.peek().map(|Reverse((record, _))| record)
+    }
+}
+
+impl Iterator for Merge<'_> {
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(e) = self.error.take() {
+            self.heap.clear();
+            return Some(Err(e));
+        }
+        let Reverse((record, source)) = self.heap.pop()?;
+        self.fill(source);
+        Some(Ok(record))
+    }
+}
+
+/// Iterator over the [`EquivalenceGroup`]s of a [`Report`].
+pub struct Groups<'a> {
+    merge: Merge<'a>,
+    max_members: usize,
+    // Runs produced by intermediate merge passes, removed once the
+    // iterator, and so their readers, are dropped
+    _runs: Vec<SpillFile>,
+}
+
+impl Iterator for Groups<'_> {
+    type Item = io::Result<EquivalenceGroup>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let first = match self.merge.next()? {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e)),
+            };
+            let mut group = EquivalenceGroup {
+                key: first.key,
+                members: vec![Member {
+                    index: first.index,
+                    input: first.input,
+                }],
+                size: 1,
+            };
+            // Members beyond the limit are counted and dropped
+            while self.merge.peek().is_some_and(|r| r.key == group.key) {
+                let record = match self.merge.next()? {
+                    Ok(record) => record,
+                    Err(e) => return Some(Err(e)),
+                };
+                group.size += 1;
+                if group.members.len() < self.max_members {
+                    group.members.push(Member {
+                        index: record.index,
+                        input: record.input,
+                    });
+                }
+            }
+
+            if group.size > 1 {
+                return Some(Ok(group));
+            }
+        }
+    }
+}
+
+/// Iterator over the [`Reject`]s of a [`Report`].
+pub struct Rejects<'a> {
+    rejects: Box<dyn Iterator<Item = io::Result<Reject>> + 'a>,
+}
+
+impl Iterator for Rejects<'_> {
+    type Item = io::Result<Reject>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rejects.next()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Record {
+    key: String,
+    index: u64,
+    input: String,
+}
+
+impl Ord for Record {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key).then(self.index.cmp(&other.index))
+    }
+}
+
+impl PartialOrd for Record {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Record {
+    fn write<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        w.write_all(&self.index.to_le_bytes())?;
+        write_str(w, &self.key)?;
+        write_str(w, &self.input)
+    }
+
+    fn read<R: Read>(r: &mut R) -> io::Result<Option<Self>> {
+        let Some(index) = read_index(r)? else {
+            return Ok(None);
+        };
+        Ok(Some(Self {
+            index,
+            key: read_str(r)?,
+            input: read_str(r)?,
+        }))
+    }
+}
+
+fn write_reject<W: Write>(w: &mut W, index: u64, input: &str, error: &Error) -> io::Result<()> {
+    w.write_all(&index.to_le_bytes())?;
+    write_str(w, input)?;
+    write_error(w, error)
+}
+
+fn read_reject<R: Read>(r: &mut R) -> io::Result<Option<Reject>> {
+    let Some(index) = read_index(r)? else {
+        return Ok(None);
+    };
+    Ok(Some(Reject {
+        index,
+        input: read_str(r)?,
+        error: read_error(r)?,
+    }))
+}
+
+// Errors are written as a tag byte followed by their fields
+fn write_error<W: Write>(w: &mut W, error: &Error) -> io::Result<()> {
+    match error {
+        Error::Invalid => w.write_all(&[0]),
+        Error::BadCodepoint(info) => {
+            w.write_all(&[1])?;
+            write_codepoint_info(w, info)
+        }
+        Error::BadLength(info) => {
+            let unit = match info.unit {
+                LengthUnit::Bytes => 0,
+                LengthUnit::Codepoints => 1,
+                LengthUnit::Graphemes => 2,
+            };
+            let (limit, n) = match info.limit {
+                LengthLimit::Min(n) => (0, n),
+                LengthLimit::Max(n) => (1, n),
+            };
+            w.write_all(&[2, unit, limit])?;
+            write_usize(w, info.length)?;
+            write_usize(w, n)
+        }
+        Error::MixedScript(info) => {
+            w.write_all(&[3])?;
+            write_codepoint_info(w, info)
+        }
+        Error::Unexpected(UnexpectedError::ContextRuleNotApplicable(info)) => {
+            w.write_all(&[4])?;
+            write_codepoint_info(w, info)
+        }
+        Error::Unexpected(UnexpectedError::MissingContextRule(info)) => {
+            w.write_all(&[5])?;
+            write_codepoint_info(w, info)
+        }
+        Error::Unexpected(UnexpectedError::ProfileRuleNotApplicable) => w.write_all(&[6]),
+        Error::Unexpected(UnexpectedError::Undefined) => w.write_all(&[7]),
+    }
+}
+
+fn read_error<R: Read>(r: &mut R) -> io::Result<Error> {
+    Ok(match read_u8(r)? {
+        0 => Error::Invalid,
+        1 => Error::BadCodepoint(read_codepoint_info(r)?),
+        2 => {
+            let unit = match read_u8(r)? {
+                0 => LengthUnit::Bytes,
+                1 => LengthUnit::Codepoints,
+                2 => LengthUnit::Graphemes,
+                _ => return Err(invalid_data("unknown length unit")),
+            };
+            let min = match read_u8(r)? {
+                0 => true,
+                1 => false,
+                _ => return Err(invalid_data("unknown length limit")),
+            };
+            let length = read_usize(r)?;
+            let n = read_usize(r)?;
+            let limit = if min {
+                LengthLimit::Min(n)
+            } else {
+                LengthLimit::Max(n)
+            };
+            Error::BadLength(LengthInfo::new(unit, length, limit))
+        }
+        3 => Error::MixedScript(read_codepoint_info(r)?),
+        4 => Error::Unexpected(UnexpectedError::ContextRuleNotApplicable(
+            read_codepoint_info(r)?,
+        )),
+        5 => Error::Unexpected(UnexpectedError::MissingContextRule(read_codepoint_info(r)?)),
+        6 => Error::Unexpected(UnexpectedError::ProfileRuleNotApplicable),
+        7 => Error::Unexpected(UnexpectedError::Undefined),
+        _ => return Err(invalid_data("unknown error")),
+    })
+}
+
+fn write_codepoint_info<W: Write>(w: &mut W, info: &CodepointInfo) -> io::Result<()> {
+    let property = match info.property {
+        DerivedPropertyValue::PValid => 0,
+        DerivedPropertyValue::SpecClassPval => 1,
+        DerivedPropertyValue::SpecClassDis => 2,
+        DerivedPropertyValue::ContextJ => 3,
+        DerivedPropertyValue::ContextO => 4,
+        DerivedPropertyValue::Disallowed => 5,
+        DerivedPropertyValue::Unassigned => 6,
+    };
+    w.write_all(&info.cp.to_le_bytes())?;
+    write_usize(w, info.position)?;
+    w.write_all(&[property])
+}
+
+fn read_codepoint_info<R: Read>(r: &mut R) -> io::Result<CodepointInfo> {
+    let mut cp = [0u8; 4];
+    r.read_exact(&mut cp)?;
+    let position = read_usize(r)?;
+    let property = match read_u8(r)? {
+        0 => DerivedPropertyValue::PValid,
+        1 => DerivedPropertyValue::SpecClassPval,
+        2 => DerivedPropertyValue::SpecClassDis,
+        3 => DerivedPropertyValue::ContextJ,
+        4 => DerivedPropertyValue::ContextO,
+        5 => DerivedPropertyValue::Disallowed,
+        6 => DerivedPropertyValue::Unassigned,
+        _ => return Err(invalid_data("unknown derived property value")),
+    };
+    Ok(CodepointInfo::new(
+        u32::from_le_bytes(cp),
+        position,
+        property,
+    ))
+}
+
+fn write_usize<W: Write>(w: &mut W, n: usize) -> io::Result<()> {
+    w.write_all(&(n as u64).to_le_bytes())
+}
+
+fn read_usize<R: Read>(r: &mut R) -> io::Result<usize> {
+    let mut buf = [0u8; 8];
+    r.read_exact(&mut buf)?;
+    usize::try_from(u64::from_le_bytes(buf)).map_err(|e| invalid_data(&e.to_string()))
+}
+
+fn read_u8<R: Read>(r: &mut R) -> io::Result<u8> {
+    let mut buf = [0u8; 1];
+    r.read_exact(&mut buf)?;
+    Ok(buf[0])
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn write_str<W: Write>(w: &mut W, s: &str) -> io::Result<()> {
+    w.write_all(&(s.len() as u64).to_le_bytes())?;
+    w.write_all(s.as_bytes())
+}
+
+// Returns `None` at the end of the file
+fn read_index<R: Read>(r: &mut R) -> io::Result<Option<u64>> {
+    let mut buf = [0u8; 8];
+    match r.read_exact(&mut buf) {
+        Ok(()) => Ok(Some(u64::from_le_bytes(buf))),
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+fn read_str<R: Read>(r: &mut R) -> io::Result<String> {
+    let mut buf = [0u8; 8];
+    r.read_exact(&mut buf)?;
+    let mut bytes = vec![0u8; u64::from_le_bytes(buf) as usize];
+    r.read_exact(&mut bytes)?;
+    String::from_utf8(bytes).map_err(|e| invalid_data(&e.to_string()))
+}
+
+// Temporary file removed when dropped
+#[derive(Debug)]
+struct SpillFile {
+    path: PathBuf,
+}
+
+impl SpillFile {
+    fn create(dir: &Path) -> io::Result<Self> {
+        let path = dir.join(format!(
+            "precis-collisions-{}-{}.tmp",
+            process::id(),
+            SPILL_FILE_ID.fetch_add(1, AtomicOrdering::Relaxed)
+        ));
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        Ok(Self { path })
+    }
+
+    fn append(&self) -> io::Result<File> {
+        OpenOptions::new().append(true).open(&self.path)
+    }
+
+    fn reader(&self) -> io::Result<BufReader<File>> {
+        File::open(&self.path).map(BufReader::new)
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod test_collisions {
+    use crate::collisions::*;
+    use crate::UsernameCaseMapped;
+
+    #[test]
+    fn record_encoding() {
+        let record = Record {
+            key: String::from("juliet"),
+            index: 42,
+            input: String::from("Juliet\n\t"),
+        };
+        let mut buf = Vec::new();
+        record.write(&mut buf).unwrap();
+        let errors = [
+            Error::Invalid,
+            Error::BadCodepoint(CodepointInfo::new(
+                0x00a0,
+                8,
+                DerivedPropertyValue::SpecClassDis,
+            )),
+            Error::BadLength(LengthInfo::new(
+                LengthUnit::Graphemes,
+                1025,
+                LengthLimit::Max(1024),
+            )),
+            Error::BadLength(LengthInfo::new(LengthUnit::Bytes, 0, LengthLimit::Min(1))),
+            Error::MixedScript(CodepointInfo::new(0x0430, 1, DerivedPropertyValue::PValid)),
+            Error::Unexpected(UnexpectedError::ContextRuleNotApplicable(
+                CodepointInfo::new(0x200c, 0, DerivedPropertyValue::ContextJ),
+            )),
+            Error::Unexpected(UnexpectedError::MissingContextRule(CodepointInfo::new(
+                0x0660,
+                3,
+                DerivedPropertyValue::ContextO,
+            ))),
+            Error::Unexpected(UnexpectedError::ProfileRuleNotApplicable),
+            Error::Unexpected(UnexpectedError::Undefined),
+        ];
+        for (index, error) in (0u64..).zip(&errors) {
+            write_reject(&mut buf, index, "", error).unwrap();
+        }
+
+        let mut r = buf.as_slice();
+        assert_eq!(Record::read(&mut r).unwrap(), Some(record));
+        for (index, error) in (0u64..).zip(errors) {
+            assert_eq!(
+                read_reject(&mut r).unwrap(),
+                Some(Reject {
+                    index,
+                    input: String::new(),
+                    error
+                })
+            );
+        }
+        assert_eq!(Record::read(&mut r).unwrap(), None);
+
+        // Truncated records are errors
+        let mut r = &buf[..12];
+        assert!(Record::read(&mut r).is_err());
+
+        // Unknown errors are rejected
+        let mut buf = Vec::new();
+        write_reject(&mut buf, 0, "", &Error::Invalid).unwrap();
+        *buf.last_mut().unwrap() = 0xff;
+        assert!(read_reject(&mut buf.as_slice()).is_err());
+    }
+
+    #[test]
+    fn spill_files_removed() {
+        let dir = env::temp_dir().join(format!("precis-spill-test-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let detector = CollisionDetector::new(UsernameCaseMapped::new())
+            .with_memory_limit(0)
+            .with_spill_dir(&dir);
+        let report = detector.detect(["a", "A", ""]).unwrap();
+        // One run per input and a file for the rejects
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 3);
+        assert_eq!(report.groups().unwrap().count(), 1);
+        drop(report);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn multi_pass_merge() {
+        let dir = env::temp_dir().join(format!("precis-merge-test-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        // Ten different keys written with different cases
+        let inputs: Vec<String> = (0..300)
+            .map(|i| match i % 3 {
+                0 => format!("user{}", i % 10),
+                1 => format!("USER{}", i % 10),
+                _ => format!("User{}", i % 10),
+            })
+            .collect();
+
+        for fan_in in [MERGE_FAN_IN, 4, 2] {
+            let mut detector = CollisionDetector::new(UsernameCaseMapped::new())
+                .with_memory_limit(0)
+                .with_spill_dir(&dir);
+            detector.merge_fan_in = fan_in;
+            let report = detector.detect(&inputs).unwrap();
+            // One run per input
+            assert_eq!(report.runs.len(), inputs.len());
+
+            let groups = report.groups().unwrap();
+            // Only the runs of the last pass are open
+            assert!(groups.merge.sources.len() <= fan_in + 1);
+            let groups = groups.collect::<Result<Vec<_>, _>>().unwrap();
+            assert_eq!(groups.len(), 10);
+            for (n, group) in groups.iter().enumerate() {
+                assert_eq!(group.key, format!("user{}", n));
+                assert_eq!(group.members.len(), 30);
+                assert_eq!(group.size, 30);
+                assert!(group.members.windows(2).all(|m| m[0].index < m[1].index));
+                assert!(group
+                    .members
+                    .iter()
+                    .all(|m| m.input == inputs[m.index as usize]));
+            }
+
+            // Runs of the intermediate passes are removed
+            assert_eq!(fs::read_dir(&dir).unwrap().count(), inputs.len());
+            drop(report);
+            assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        }
+
+        fs::remove_dir(&dir).unwrap();
+    }
+}
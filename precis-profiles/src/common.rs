@@ -1,7 +1,8 @@
-include!(concat!(env!("OUT_DIR"), "/space_separator.rs"));
+include_table!("space_separator.rs");
 
-use precis_core::Codepoints;
-use precis_core::Error;
+use caseless::Caseless;
+use precis_core::CodepointSet;
+use precis_core::{Error, LengthInfo, LengthLimit, LengthUnit};
 use std::borrow::Cow;
 use unicode_normalization::UnicodeNormalization;
 
@@ -9,10 +10,7 @@ pub(crate) const SPACE: char = '\u{0020}';
 
 #[inline]
 pub(crate) fn is_space_separator(c: char) -> bool {
-    let cp = c as u32;
-    SPACE_SEPARATOR
-        .binary_search_by(|cps| cps.partial_cmp(&cp).unwrap())
-        .is_ok()
+    SPACE_SEPARATOR.contains(c as u32)
 }
 
 #[inline]
@@ -20,6 +18,41 @@ pub(crate) fn is_non_ascii_space(c: char) -> bool {
     c != SPACE && is_space_separator(c)
 }
 
+/// Checks that a string does not exceed a maximum size in bytes.
+///
+/// # Arguments
+/// * `s` - Input string
+/// * `max_size` - Maximum size in bytes, if any
+///
+/// # Returns
+/// Error::BadLength if the string is bigger than `max_size`
+#[inline]
+pub(crate) fn check_size(s: &str, max_size: Option<usize>) -> Result<(), Error> {
+    match max_size {
+        Some(max) if s.len() > max => Err(Error::BadLength(LengthInfo::new(
+            LengthUnit::Bytes,
+            s.len(),
+            LengthLimit::Max(max),
+        ))),
+        _ => Ok(()),
+    }
+}
+
+// Collects the code points produced by a normalization or mapping step.
+// The size is checked as the output grows, so that inputs that expand a lot
+// are rejected before allocating memory for the whole output.
+fn collect_limited<I>(it: I, capacity: usize, max_size: Option<usize>) -> Result<String, Error>
+where
+    I: Iterator<Item = char>,
+{
+    let mut res = String::with_capacity(max_size.map_or(capacity, |max| capacity.min(max)));
+    for c in it {
+        res.push(c);
+        check_size(&res, max_size)?;
+    }
+    Ok(res)
+}
+
 /// Helper function to transform a string starting from the first position where
 /// a predicate matches, avoiding allocation if no transformation is needed.
 ///
@@ -27,6 +60,7 @@ pub(crate) fn is_non_ascii_space(c: char) -> bool {
 /// * `s` - Input string
 /// * `predicate` - Function to find the first position to transform
 /// * `transform` - Function to transform each character from that position
+/// * `max_size` - Maximum size in bytes of the transformed string, if any
 ///
 /// # Returns
 /// Original string if predicate never matches, or transformed string otherwise
@@ -34,6 +68,7 @@ pub(crate) fn transform_from_first_match<'a, T, P, F>(
     s: T,
     predicate: P,
     mut transform: F,
+    max_size: Option<usize>,
 ) -> Result<Cow<'a, str>, Error>
 where
     T: Into<Cow<'a, str>>,
@@ -42,12 +77,16 @@ where
 {
     let s = s.into();
     match s.find(predicate) {
-        None => Ok(s),
+        None => {
+            check_size(&s, max_size)?;
+            Ok(s)
+        }
         Some(pos) => {
             let mut res = String::from(&s[..pos]);
             res.reserve(s.len() - res.len());
             for c in s[pos..].chars() {
                 transform(c, &mut res);
+                check_size(&res, max_size)?;
             }
             Ok(res.into())
         }
@@ -70,7 +109,10 @@ where
     (!s.is_empty()).then_some(s).ok_or(Error::Invalid)
 }
 
-pub(crate) fn normalization_form_nfkc<'a, T>(s: T) -> Result<Cow<'a, str>, Error>
+pub(crate) fn normalization_form_nfkc<'a, T>(
+    s: T,
+    max_size: Option<usize>,
+) -> Result<Cow<'a, str>, Error>
 where
     T: Into<Cow<'a, str>>,
 {
@@ -79,13 +121,17 @@ where
     // due to the extra iteration over the string
     let s = s.into();
     if unicode_normalization::is_nfkc(&s) {
+        check_size(&s, max_size)?;
         Ok(s)
     } else {
-        Ok(s.nfkc().collect::<String>().into())
+        Ok(collect_limited(s.nfkc(), s.len(), max_size)?.into())
     }
 }
 
-pub(crate) fn normalization_form_nfc<'a, T>(s: T) -> Result<Cow<'a, str>, Error>
+pub(crate) fn normalization_form_nfc<'a, T>(
+    s: T,
+    max_size: Option<usize>,
+) -> Result<Cow<'a, str>, Error>
 where
     T: Into<Cow<'a, str>>,
 {
@@ -94,23 +140,42 @@ where
     // due to the extra iteration over the string
     let s = s.into();
     if unicode_normalization::is_nfc(&s) {
+        check_size(&s, max_size)?;
         Ok(s)
     } else {
-        Ok(s.nfc().collect::<String>().into())
+        Ok(collect_limited(s.nfc(), s.len(), max_size)?.into())
     }
 }
 
-pub(crate) fn case_mapping_rule<'a, T>(s: T) -> Result<Cow<'a, str>, Error>
+pub(crate) fn case_mapping_rule<'a, T>(s: T, max_size: Option<usize>) -> Result<Cow<'a, str>, Error>
 where
     T: Into<Cow<'a, str>>,
 {
-    transform_from_first_match(s, char::is_uppercase, |c, res| {
-        if c.is_lowercase() {
-            res.push(c);
-        } else {
-            c.to_lowercase().for_each(|x| res.push(x));
-        }
-    })
+    transform_from_first_match(
+        s,
+        char::is_uppercase,
+        |c, res| {
+            if c.is_lowercase() {
+                res.push(c);
+            } else {
+                c.to_lowercase().for_each(|x| res.push(x));
+            }
+        },
+        max_size,
+    )
+}
+
+pub(crate) fn case_folding_rule<'a, T>(s: T, max_size: Option<usize>) -> Result<Cow<'a, str>, Error>
+where
+    T: Into<Cow<'a, str>>,
+{
+    let s = s.into();
+    if s.chars().default_case_fold().eq(s.chars()) {
+        check_size(&s, max_size)?;
+        Ok(s)
+    } else {
+        Ok(collect_limited(s.chars().default_case_fold(), s.len(), max_size)?.into())
+    }
 }
 
 #[cfg(test)]
@@ -119,38 +184,94 @@ mod profile_rules {
 
     #[test]
     fn test_normalization_rule() {
-        let res = normalization_form_nfc("");
+        let res = normalization_form_nfc("", None);
         assert_eq!(res, Ok(Cow::from("")));
 
-        let res = normalization_form_nfc("\u{212b}");
+        let res = normalization_form_nfc("\u{212b}", None);
         assert_eq!(res, Ok(Cow::from("\u{00c5}")));
 
-        let res = normalization_form_nfc("a\u{212b}");
+        let res = normalization_form_nfc("a\u{212b}", None);
         assert_eq!(res, Ok(Cow::from("a\u{00c5}")));
 
-        let res = normalization_form_nfc("\u{212b}a");
+        let res = normalization_form_nfc("\u{212b}a", None);
         assert_eq!(res, Ok(Cow::from("\u{00c5}a")));
 
-        let res = normalization_form_nfc("\u{212b}\u{2126}\u{1e0b}\u{0323}");
+        let res = normalization_form_nfc("\u{212b}\u{2126}\u{1e0b}\u{0323}", None);
         assert_eq!(res, Ok(Cow::from("\u{00c5}\u{03a9}\u{1e0d}\u{0307}")));
     }
 
     #[test]
     fn test_case_mapping_rule() {
-        let res = case_mapping_rule("");
+        let res = case_mapping_rule("", None);
         assert_eq!(res, Ok(Cow::from("")));
 
-        let res = case_mapping_rule("T");
+        let res = case_mapping_rule("T", None);
         assert_eq!(res, Ok(Cow::from("t")));
 
-        let res = case_mapping_rule("aT");
+        let res = case_mapping_rule("aT", None);
         assert_eq!(res, Ok(Cow::from("at")));
 
-        let res = case_mapping_rule("Ta");
+        let res = case_mapping_rule("Ta", None);
         assert_eq!(res, Ok(Cow::from("ta")));
 
-        let res = case_mapping_rule("TestUserName");
+        let res = case_mapping_rule("TestUserName", None);
+        assert_eq!(res, Ok(Cow::from("testusername")));
+    }
+
+    #[test]
+    fn test_case_folding_rule() {
+        let res = case_folding_rule("", None);
+        assert_eq!(res, Ok(Cow::from("")));
+
+        let res = case_folding_rule("TestUserName", None);
         assert_eq!(res, Ok(Cow::from("testusername")));
+
+        // Full case folding maps some code points to several ones
+        let res = case_folding_rule("Stra\u{00df}e", None);
+        assert_eq!(res, Ok(Cow::from("strasse")));
+
+        // Final sigma is folded to the non-final form
+        let res = case_folding_rule("\u{03bf}\u{03c2}", None);
+        assert_eq!(res, Ok(Cow::from("\u{03bf}\u{03c3}")));
+    }
+
+    #[test]
+    fn test_size_limits() {
+        let err = |length, max| {
+            Error::BadLength(LengthInfo::new(
+                LengthUnit::Bytes,
+                length,
+                LengthLimit::Max(max),
+            ))
+        };
+
+        assert_eq!(check_size("abc", None), Ok(()));
+        assert_eq!(check_size("abc", Some(3)), Ok(()));
+        assert_eq!(check_size("abcd", Some(3)), Err(err(4, 3)));
+
+        // Normalized strings are checked too
+        assert_eq!(normalization_form_nfkc("abcd", Some(3)), Err(err(4, 3)));
+        assert_eq!(normalization_form_nfc("abcd", Some(3)), Err(err(4, 3)));
+
+        // U+FDFA expands to 18 code points, processing stops as soon
+        // as the output exceeds the limit
+        assert_eq!(
+            normalization_form_nfkc("\u{fdfa}", Some(18)),
+            Err(err(20, 18))
+        );
+        let res = normalization_form_nfkc("\u{fdfa}", Some(33));
+        assert_eq!(res.map(|s| s.chars().count()), Ok(18));
+
+        // U+0130 (2 bytes) is lower cased to `i` followed by U+0307 (3 bytes)
+        assert_eq!(case_mapping_rule("\u{0130}", Some(2)), Err(err(3, 2)));
+        assert_eq!(
+            case_mapping_rule("\u{0130}", Some(3)),
+            Ok(Cow::from("i\u{0307}"))
+        );
+        assert_eq!(
+            case_folding_rule("\u{00df}\u{00df}", Some(3)),
+            Err(err(4, 3))
+        );
     }
 
     #[test]
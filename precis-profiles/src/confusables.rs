@@ -0,0 +1,140 @@
+//! Confusable detection as described in
+//! [`UTS #39`](https://www.unicode.org/reports/tr39/#Confusable_Detection).
+//!
+//! Two strings can be different after they are enforced by a profile and yet
+//! look the same to users, e.g. `paypal` written with a Cyrillic `а`
+//! (`U+0430`). Such strings have the same *skeleton*, computed by
+//! decomposing the string, removing the default ignorable code points and
+//! replacing each code point with its prototype from the `confusables.txt`
+//! data file.
+//!
+//! Applications registering identifiers can store the skeleton of each
+//! enforced identifier and reject new identifiers whose skeleton is already
+//! taken.
+//! ```rust
+//! # use precis_profiles::confusables::Confusables;
+//! # use precis_profiles::UsernameCaseMapped;
+//! let profile = UsernameCaseMapped::new();
+//!
+//! assert_eq!(profile.confusable_with("paypal", "p\u{0430}ypal"), Ok(true));
+//! assert_eq!(profile.confusable_with("PayPal", "paypa1"), Ok(true));
+//! assert_eq!(profile.confusable_with("paypal", "ebay"), Ok(false));
+//! ```
+
+include_table!("confusables.rs");
+include_table!("default_ignorable.rs");
+
+use precis_core::profile::Profile;
+use precis_core::CodepointSet;
+use precis_core::Error;
+use unicode_normalization::UnicodeNormalization;
+
+// Code points with the `Default_Ignorable_Code_Point` property are
+// invisible, so they are removed from skeletons.
+#[inline]
+fn is_default_ignorable(c: char) -> bool {
+    DEFAULT_IGNORABLE_CODE_POINT.contains(c as u32)
+}
+
+fn prototype(c: char) -> Option<&'static [u32]> {
+    let cp = c as u32;
+    CONFUSABLES
+        .binary_search_by(|(source, _)| source.cmp(&cp))
+        .ok()
+        .map(|i| CONFUSABLES[i].1)
+}
+
+/// Computes the skeleton of a string as defined in
+/// [`UTS #39`](https://www.unicode.org/reports/tr39/#def-skeleton).
+/// The skeleton is only meant to be compared with other skeletons,
+/// it should not be displayed to users.
+/// # Example
+/// ```rust
+/// # use precis_profiles::confusables::skeleton;
+/// assert_eq!(skeleton("p\u{0430}ypal"), skeleton("paypal"));
+/// // Default ignorable code points, such as SOFT HYPHEN, are removed
+/// assert_eq!(skeleton("pay\u{00ad}pal"), skeleton("paypal"));
+/// assert_ne!(skeleton("paypal"), skeleton("ebay"));
+/// ```
+pub fn skeleton<S: AsRef<str>>(s: S) -> String {
+    let mut res = String::with_capacity(s.as_ref().len());
+    for c in s.as_ref().nfd().filter(|c| !is_default_ignorable(*c)) {
+        match prototype(c) {
+            Some(cps) => cps
+                .iter()
+                .filter_map(|cp| char::from_u32(*cp))
+                .for_each(|c| res.push(c)),
+            None => res.push(c),
+        }
+    }
+    res.nfd().collect()
+}
+
+/// Confusable detection for strings enforced by a [`Profile`]. This trait
+/// is implemented for every profile.
+pub trait Confusables: Profile {
+    /// Enforces a string and returns the skeleton of the result.
+    /// # Returns
+    /// The skeleton, or the error returned by the profile if the string
+    /// can not be enforced.
+    fn skeleton<S: AsRef<str>>(&self, s: S) -> Result<String, Error> {
+        let s = self.enforce(s.as_ref())?;
+        Ok(skeleton(s))
+    }
+
+    /// Checks whether two strings are confusable once they are enforced,
+    /// that is, whether their enforced forms have the same skeleton.
+    /// Strings that are equal after enforcement are confusable as well.
+    /// # Returns
+    /// The error returned by the profile if any of the strings can not
+    /// be enforced.
+    fn confusable_with<A, B>(&self, a: A, b: B) -> Result<bool, Error>
+    where
+        A: AsRef<str>,
+        B: AsRef<str>,
+    {
+        Ok(Confusables::skeleton(self, a)? == Confusables::skeleton(self, b)?)
+    }
+}
+
+impl<P: Profile> Confusables for P {}
+
+#[cfg(test)]
+mod test_confusables {
+    use crate::confusables::*;
+
+    #[test]
+    fn prototypes() {
+        assert_eq!(prototype('a'), None);
+        assert_eq!(prototype('\u{0430}'), Some(&[0x61][..]));
+        assert_eq!(prototype('1'), Some(&[0x6c][..]));
+        assert_eq!(prototype('"'), Some(&[0x27, 0x27][..]));
+    }
+
+    #[test]
+    fn default_ignorables() {
+        assert!(is_default_ignorable('\u{00ad}'));
+        assert!(is_default_ignorable('\u{200d}'));
+        assert!(is_default_ignorable('\u{fe0f}'));
+        assert!(is_default_ignorable('\u{e0fff}'));
+        assert!(!is_default_ignorable('a'));
+        assert!(!is_default_ignorable(' '));
+        assert!(!is_default_ignorable('\u{180a}'));
+        assert!(!is_default_ignorable('\u{e1000}'));
+    }
+
+    #[test]
+    fn skeletons() {
+        assert_eq!(skeleton(""), "");
+        assert_eq!(skeleton("paypal"), "paypal");
+        assert_eq!(skeleton("p\u{0430}yp\u{0430}l"), "paypal");
+        assert_eq!(skeleton("paypa1"), "paypal");
+        // Skeletons are decomposed
+        assert_eq!(skeleton("\u{00e9}"), "e\u{0301}");
+        assert_eq!(skeleton("\u{0435}\u{0301}"), "e\u{0301}");
+        // Default ignorable code points are removed
+        assert_eq!(skeleton("pay\u{00ad}pal"), skeleton("paypal"));
+        assert_eq!(skeleton("p\u{200b}ayp\u{2060}al\u{feff}"), "paypal");
+        assert_eq!(skeleton("\u{200d}"), "");
+    }
+}
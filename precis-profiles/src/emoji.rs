@@ -0,0 +1,194 @@
+include_table!("emoji.rs");
+
+use precis_core::CodepointSet;
+use precis_core::{CodepointInfo, Error, StringClass};
+
+const ZERO_WIDTH_JOINER: char = '\u{200d}';
+const VARIATION_SELECTOR_16: char = '\u{fe0f}';
+
+#[inline]
+fn contains(table: &CodepointSet, c: char) -> bool {
+    table.contains(c as u32)
+}
+
+#[inline]
+pub(crate) fn is_extended_pictographic(c: char) -> bool {
+    contains(&EXTENDED_PICTOGRAPHIC, c)
+}
+
+#[inline]
+pub(crate) fn is_emoji_modifier(c: char) -> bool {
+    contains(&EMOJI_MODIFIER, c)
+}
+
+// Returns the length of the emoji ZWJ element starting at `pos`, if any.
+// An element is an `Extended_Pictographic` code point, optionally followed
+// by an emoji presentation selector (`U+FE0F`) or an emoji modifier.
+fn element_len(chars: &[char], pos: usize) -> Option<usize> {
+    let c = *chars.get(pos)?;
+    if !is_extended_pictographic(c) {
+        return None;
+    }
+    match chars.get(pos + 1) {
+        Some(&next) if next == VARIATION_SELECTOR_16 || is_emoji_modifier(next) => Some(2),
+        _ => Some(1),
+    }
+}
+
+// Returns whether `sequence` is listed in the `RGI_Emoji_ZWJ_Sequence`
+// table, which is sorted so it can be binary searched
+fn is_rgi_zwj_sequence(sequence: &[char]) -> bool {
+    RGI_EMOJI_ZWJ_SEQUENCES
+        .binary_search_by(|rgi| rgi.iter().copied().cmp(sequence.iter().map(|c| *c as u32)))
+        .is_ok()
+}
+
+// Marks the `ZWJ` and `U+FE0F` code points that are part of an emoji `ZWJ`
+// sequence recommended for general interchange as defined in
+// [`UTS #51`](https://www.unicode.org/reports/tr51/#def_rgi_emoji_zwj_sequence).
+// Emoji elements joined by `ZWJ` are matched against the `RGI` sequences,
+// longest first, so joiners outside those sequences are left unmarked.
+fn sequence_joiners(chars: &[char]) -> Vec<bool> {
+    let mut joiners = vec![false; chars.len()];
+    let mut pos = 0;
+    while pos < chars.len() {
+        let Some(len) = element_len(chars, pos) else {
+            pos += 1;
+            continue;
+        };
+
+        // Ends of the elements joined to the one starting at `pos`
+        let mut ends = vec![pos + len];
+        while let Some(&end) = ends.last() {
+            if chars.get(end) != Some(&ZERO_WIDTH_JOINER) {
+                break;
+            }
+            match element_len(chars, end + 1) {
+                Some(len) => ends.push(end + 1 + len),
+                None => break,
+            }
+        }
+
+        let rgi_end = ends[1..]
+            .iter()
+            .rev()
+            .find(|end| is_rgi_zwj_sequence(&chars[pos..**end]));
+        match rgi_end {
+            Some(&end) => {
+                (pos..end)
+                    .filter(|i| {
+                        chars[*i] == ZERO_WIDTH_JOINER || chars[*i] == VARIATION_SELECTOR_16
+                    })
+                    .for_each(|i| joiners[i] = true);
+                pos = end;
+            }
+            None => pos += len,
+        }
+    }
+    joiners
+}
+
+/// Checks that a string only contains code points allowed by `class`,
+/// except for the `ZWJ` and `U+FE0F` code points used inside `RGI` emoji
+/// `ZWJ` sequences. Code points outside those sequences keep their neighbours,
+/// so the context rules applied by `class` are not affected.
+/// # Returns
+/// The same errors as [`StringClass::allows`], with positions referring
+/// to the original string.
+pub(crate) fn allows_zwj_sequences<C>(class: &C, s: &str) -> Result<(), Error>
+where
+    C: StringClass,
+{
+    if !s.contains(ZERO_WIDTH_JOINER) {
+        return class.allows(s);
+    }
+
+    let chars: Vec<char> = s.chars().collect();
+    let joiners = sequence_joiners(&chars);
+    let mut positions = Vec::with_capacity(chars.len());
+    let filtered: String = chars
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !joiners[*i])
+        .map(|(i, c)| {
+            positions.push(i);
+            c
+        })
+        .collect();
+
+    class.allows(&filtered).map_err(|e| match e {
+        Error::BadCodepoint(info) => Error::BadCodepoint(CodepointInfo::new(
+            info.cp,
+            positions[info.position],
+            info.property,
+        )),
+        e => e,
+    })
+}
+
+#[cfg(test)]
+mod test_emoji {
+    use crate::emoji::*;
+
+    #[test]
+    fn emoji_properties() {
+        assert!(is_extended_pictographic('\u{1f469}'));
+        assert!(is_extended_pictographic('\u{1f4bb}'));
+        assert!(!is_extended_pictographic('a'));
+        assert!(!is_extended_pictographic(ZERO_WIDTH_JOINER));
+        assert!(is_emoji_modifier('\u{1f3fd}'));
+        assert!(!is_emoji_modifier('\u{1f469}'));
+
+        assert!(is_rgi_zwj_sequence(&['\u{1f469}', '\u{200d}', '\u{1f4bb}']));
+        assert!(!is_rgi_zwj_sequence(&['\u{1f469}', '\u{200d}']));
+        assert!(!is_rgi_zwj_sequence(&[
+            '\u{1f355}',
+            '\u{200d}',
+            '\u{1f680}'
+        ]));
+    }
+
+    #[test]
+    fn zwj_sequences() {
+        let joiners = |s: &str| sequence_joiners(&s.chars().collect::<Vec<char>>());
+
+        // Woman technologist
+        assert_eq!(joiners("\u{1f469}\u{200d}\u{1f4bb}"), [false, true, false]);
+        // Rainbow flag
+        assert_eq!(
+            joiners("\u{1f3f3}\u{fe0f}\u{200d}\u{1f308}"),
+            [false, true, true, false]
+        );
+        // Woman technologist with medium skin tone
+        assert_eq!(
+            joiners("a\u{1f469}\u{1f3fd}\u{200d}\u{1f4bb}"),
+            [false, false, false, true, false]
+        );
+        // Only the longest RGI sequence is marked
+        assert_eq!(
+            joiners("\u{1f469}\u{200d}\u{1f4bb}\u{200d}\u{1f680}"),
+            [false, true, false, false, false]
+        );
+        assert_eq!(
+            joiners("\u{1f355}\u{200d}\u{1f469}\u{200d}\u{1f4bb}"),
+            [false, false, false, true, false]
+        );
+        // Emoji elements joined in sequences that are not RGI
+        assert_eq!(joiners("\u{1f355}\u{200d}\u{1f680}"), [false, false, false]);
+        assert_eq!(joiners("\u{1f3f3}\u{200d}\u{1f308}"), [false, false, false]);
+        // Presentation selectors outside sequences are not marked
+        assert_eq!(joiners("\u{2764}\u{fe0f}"), [false, false]);
+        // Joiners that do not join two emoji elements
+        assert_eq!(joiners("\u{200d}\u{1f469}"), [false, false]);
+        assert_eq!(joiners("\u{1f469}\u{200d}"), [false, false]);
+        assert_eq!(joiners("a\u{200d}b"), [false, false, false]);
+        assert_eq!(
+            joiners("\u{1f469}\u{200d}\u{200d}\u{1f4bb}"),
+            [false, false, false, false]
+        );
+        assert_eq!(
+            joiners("\u{1f469}\u{200d}\u{1f4bb}\u{200d}"),
+            [false, true, false, false]
+        );
+    }
+}
@@ -0,0 +1,6360 @@
+// File generated with precis-tools version 0.1.9
+
+static CONFUSABLES: [(u32, &[u32]); 6355] = [
+	(0x0022, &[0x0027, 0x0027]),
+	(0x0025, &[0x00ba, 0x002f, 0x2080]),
+	(0x0030, &[0x004f]),
+	(0x0031, &[0x006c]),
+	(0x0049, &[0x006c]),
+	(0x0060, &[0x0027]),
+	(0x006d, &[0x0072, 0x006e]),
+	(0x007c, &[0x006c]),
+	(0x00a0, &[0x0020]),
+	(0x00a2, &[0x0063, 0x0338]),
+	(0x00a5, &[0x0059, 0x0335]),
+	(0x00af, &[0x02c9]),
+	(0x00b4, &[0x0027]),
+	(0x00b5, &[0x03bc]),
+	(0x00b8, &[0x002c]),
+	(0x00c6, &[0x0041, 0x0045]),
+	(0x00c7, &[0x0043, 0x0326]),
+	(0x00d0, &[0x0044, 0x0335]),
+	(0x00d7, &[0x0078]),
+	(0x00d8, &[0x004f, 0x0338]),
+	(0x00e6, &[0x0061, 0x0065]),
+	(0x00e7, &[0x0063, 0x0326]),
+	(0x00f0, &[0x2202, 0x0335]),
+	(0x00f6, &[0x0629]),
+	(0x00f8, &[0x006f, 0x0338]),
+	(0x0110, &[0x0044, 0x0335]),
+	(0x0111, &[0x0064, 0x0335]),
+	(0x011a, &[0x0114]),
+	(0x011b, &[0x0115]),
+	(0x0126, &[0x0048, 0x0335]),
+	(0x0127, &[0x0068, 0x0335]),
+	(0x0131, &[0x0069]),
+	(0x0132, &[0x006c, 0x004a]),
+	(0x0133, &[0x0069, 0x006a]),
+	(0x013f, &[0x006c, 0x00b7]),
+	(0x0140, &[0x006c, 0x00b7]),
+	(0x0141, &[0x004c, 0x0338]),
+	(0x0142, &[0x006c, 0x0338]),
+	(0x0146, &[0x0272]),
+	(0x0149, &[0x0027, 0x006e]),
+	(0x0150, &[0x00d6]),
+	(0x0152, &[0x004f, 0x0045]),
+	(0x0153, &[0x006f, 0x0065]),
+	(0x0163, &[0x01ab]),
+	(0x0166, &[0x0054, 0x0335]),
+	(0x0167, &[0x0074, 0x0335]),
+	(0x017f, &[0x0066]),
+	(0x0180, &[0x0062, 0x0335]),
+	(0x0181, &[0x0027, 0x0042]),
+	(0x0182, &[0x0062, 0x0304]),
+	(0x0183, &[0x0062, 0x0304]),
+	(0x0184, &[0x0062]),
+	(0x0187, &[0x0043, 0x0027]),
+	(0x0189, &[0x0044, 0x0335]),
+	(0x018a, &[0x0027, 0x0044]),
+	(0x018c, &[0x0064, 0x0304]),
+	(0x018d, &[0x0067]),
+	(0x0191, &[0x0046, 0x0326]),
+	(0x0192, &[0x0066, 0x0326]),
+	(0x0193, &[0x0047, 0x0027]),
+	(0x0196, &[0x006c]),
+	(0x0197, &[0x006c, 0x0335]),
+	(0x0198, &[0x004b, 0x0027]),
+	(0x0199, &[0x006b, 0x0314]),
+	(0x019a, &[0x006c, 0x0335]),
+	(0x019b, &[0x03bb, 0x0338]),
+	(0x019d, &[0x004e, 0x0326]),
+	(0x019e, &[0x006e, 0x0329]),
+	(0x019f, &[0x004f, 0x0335]),
+	(0x01a0, &[0x004f, 0x0027]),
+	(0x01a1, &[0x006f, 0x0027]),
+	(0x01a4, &[0x0027, 0x0050]),
+	(0x01a5, &[0x0070, 0x0314]),
+	(0x01a6, &[0x0052]),
+	(0x01a7, &[0x0032]),
+	(0x01ac, &[0x0027, 0x0054]),
+	(0x01ad, &[0x0074, 0x0314]),
+	(0x01ae, &[0x0054, 0x0328]),
+	(0x01b3, &[0x0027, 0x0059]),
+	(0x01b4, &[0x0079, 0x0314]),
+	(0x01b5, &[0x005a, 0x0335]),
+	(0x01b6, &[0x007a, 0x0335]),
+	(0x01b7, &[0x0033]),
+	(0x01bb, &[0x0032, 0x0335]),
+	(0x01bc, &[0x0035]),
+	(0x01bd, &[0x0073]),
+	(0x01bf, &[0x00fe]),
+	(0x01c0, &[0x006c]),
+	(0x01c1, &[0x006c, 0x006c]),
+	(0x01c3, &[0x0021]),
+	(0x01c4, &[0x0044, 0x017d]),
+	(0x01c5, &[0x0044, 0x017e]),
+	(0x01c6, &[0x0064, 0x017e]),
+	(0x01c7, &[0x004c, 0x004a]),
+	(0x01c8, &[0x004c, 0x006a]),
+	(0x01c9, &[0x006c, 0x006a]),
+	(0x01ca, &[0x004e, 0x004a]),
+	(0x01cb, &[0x004e, 0x006a]),
+	(0x01cc, &[0x006e, 0x006a]),
+	(0x01cd, &[0x0102]),
+	(0x01ce, &[0x0103]),
+	(0x01cf, &[0x012c]),
+	(0x01d0, &[0x012d]),
+	(0x01d1, &[0x014e]),
+	(0x01d2, &[0x014f]),
+	(0x01d3, &[0x016c]),
+	(0x01d4, &[0x016d]),
+	(0x01e4, &[0x0047, 0x0335]),
+	(0x01e5, &[0x0067, 0x0335]),
+	(0x01e6, &[0x011e]),
+	(0x01e7, &[0x011f]),
+	(0x01f1, &[0x0044, 0x005a]),
+	(0x01f2, &[0x0044, 0x007a]),
+	(0x01f3, &[0x0064, 0x007a]),
+	(0x01f5, &[0x0123]),
+	(0x01fe, &[0x004f, 0x0338, 0x0301]),
+	(0x021a, &[0x0162]),
+	(0x021b, &[0x01ab]),
+	(0x021c, &[0x0033]),
+	(0x0222, &[0x0038]),
+	(0x0223, &[0x0038]),
+	(0x0224, &[0x005a, 0x0326]),
+	(0x0225, &[0x007a, 0x0326]),
+	(0x0226, &[0x00c5]),
+	(0x0227, &[0x00e5]),
+	(0x023c, &[0x0063, 0x0338]),
+	(0x023e, &[0x0054, 0x0338]),
+	(0x0241, &[0x003f]),
+	(0x0244, &[0x0055, 0x0335]),
+	(0x0246, &[0x0045, 0x0338]),
+	(0x0247, &[0x0065, 0x0338]),
+	(0x0248, &[0x004a, 0x0335]),
+	(0x0249, &[0x006a, 0x0335]),
+	(0x024d, &[0x0072, 0x0335]),
+	(0x024e, &[0x0059, 0x0335]),
+	(0x024f, &[0x0079, 0x0335]),
+	(0x0251, &[0x0061]),
+	(0x0253, &[0x0062, 0x0314]),
+	(0x0256, &[0x0064, 0x0328]),
+	(0x0257, &[0x0064, 0x0314]),
+	(0x0259, &[0x01dd]),
+	(0x025a, &[0x01dd, 0x02de]),
+	(0x025b, &[0xa793]),
+	(0x0260, &[0x0067, 0x0314]),
+	(0x0261, &[0x0067]),
+	(0x0263, &[0x0079]),
+	(0x0266, &[0x0068, 0x0314]),
+	(0x0268, &[0x0069, 0x0335]),
+	(0x0269, &[0x0069]),
+	(0x026a, &[0x0069]),
+	(0x026b, &[0x006c, 0x0334]),
+	(0x026d, &[0x006c, 0x0328]),
+	(0x026e, &[0x006c, 0x021d]),
+	(0x026f, &[0x0077]),
+	(0x0271, &[0x0072, 0x006e, 0x0326]),
+	(0x0273, &[0x006e, 0x0328]),
+	(0x0275, &[0x006f, 0x0335]),
+	(0x0276, &[0x006f, 0x1d07]),
+	(0x027c, &[0x0072, 0x0329]),
+	(0x027d, &[0x0072, 0x0328]),
+	(0x0282, &[0x0073, 0x0328]),
+	(0x028b, &[0x0075]),
+	(0x028f, &[0x0079]),
+	(0x0290, &[0x007a, 0x0328]),
+	(0x0292, &[0x021d]),
+	(0x0294, &[0x003f]),
+	(0x02a0, &[0x0071, 0x0314]),
+	(0x02a3, &[0x0064, 0x007a]),
+	(0x02a4, &[0x0064, 0x021d]),
+	(0x02a5, &[0x0064, 0x0291]),
+	(0x02a6, &[0x0074, 0x0073]),
+	(0x02a7, &[0x0074, 0x0283]),
+	(0x02a8, &[0x0074, 0x0255]),
+	(0x02a9, &[0x0066, 0x014b]),
+	(0x02aa, &[0x006c, 0x0073]),
+	(0x02ab, &[0x006c, 0x007a]),
+	(0x02b3, &[0x18f4]),
+	(0x02b9, &[0x0027]),
+	(0x02ba, &[0x0027, 0x0027]),
+	(0x02bb, &[0x0027]),
+	(0x02bc, &[0x0027]),
+	(0x02bd, &[0x0027]),
+	(0x02be, &[0x0027]),
+	(0x02bf, &[0x0559]),
+	(0x02c2, &[0x003c]),
+	(0x02c3, &[0x003e]),
+	(0x02c4, &[0x005e]),
+	(0x02c6, &[0x005e]),
+	(0x02c8, &[0x0027]),
+	(0x02ca, &[0x0027]),
+	(0x02cb, &[0x0027]),
+	(0x02d0, &[0x003a]),
+	(0x02d3, &[0x0559]),
+	(0x02d7, &[0x002d]),
+	(0x02d8, &[0x02c7]),
+	(0x02d9, &[0x0971]),
+	(0x02da, &[0x00b0]),
+	(0x02db, &[0x0069]),
+	(0x02dc, &[0x007e]),
+	(0x02dd, &[0x0027, 0x0027]),
+	(0x02e1, &[0x18f3]),
+	(0x02e2, &[0x18f5]),
+	(0x02e4, &[0x02c1]),
+	(0x02ee, &[0x0027, 0x0027]),
+	(0x02f4, &[0x0027]),
+	(0x02f6, &[0x0027, 0x0027]),
+	(0x02f8, &[0x003a]),
+	(0x02fb, &[0x02ea]),
+	(0x0305, &[0x0304]),
+	(0x030c, &[0x0306]),
+	(0x030d, &[0x0670]),
+	(0x0310, &[0x0306, 0x0307]),
+	(0x0311, &[0x0302]),
+	(0x0315, &[0x0313]),
+	(0x0317, &[0x0650]),
+	(0x0320, &[0x0331]),
+	(0x0321, &[0x0326]),
+	(0x0322, &[0x0328]),
+	(0x0327, &[0x0326]),
+	(0x0336, &[0x0335]),
+	(0x0337, &[0x0338]),
+	(0x0339, &[0x0326]),
+	(0x0340, &[0x0300]),
+	(0x0341, &[0x0301]),
+	(0x0342, &[0x0303]),
+	(0x0343, &[0x0313]),
+	(0x0345, &[0x0328]),
+	(0x0347, &[0x0333]),
+	(0x0357, &[0x0350]),
+	(0x0358, &[0x0307]),
+	(0x0366, &[0x030a]),
+	(0x036e, &[0x0306]),
+	(0x0370, &[0x2c75]),
+	(0x0374, &[0x0027]),
+	(0x0375, &[0x02cf]),
+	(0x0376, &[0x0418]),
+	(0x0377, &[0x1d0e]),
+	(0x037a, &[0x0069]),
+	(0x037b, &[0x0254]),
+	(0x037d, &[0xa73f]),
+	(0x037e, &[0x003b]),
+	(0x037f, &[0x004a]),
+	(0x0384, &[0x0027]),
+	(0x0387, &[0x00b7]),
+	(0x0391, &[0x0041]),
+	(0x0392, &[0x0042]),
+	(0x0395, &[0x0045]),
+	(0x0396, &[0x005a]),
+	(0x0397, &[0x0048]),
+	(0x0398, &[0x004f, 0x0335]),
+	(0x0399, &[0x006c]),
+	(0x039a, &[0x004b]),
+	(0x039b, &[0x0245]),
+	(0x039c, &[0x004d]),
+	(0x039d, &[0x004e]),
+	(0x039f, &[0x004f]),
+	(0x03a1, &[0x0050]),
+	(0x03a3, &[0x01a9]),
+	(0x03a4, &[0x0054]),
+	(0x03a5, &[0x0059]),
+	(0x03a7, &[0x0058]),
+	(0x03b1, &[0x0061]),
+	(0x03b2, &[0x00df]),
+	(0x03b3, &[0x0079]),
+	(0x03b4, &[0x1e9f]),
+	(0x03b5, &[0xa793]),
+	(0x03b7, &[0x006e, 0x0329]),
+	(0x03b8, &[0x004f, 0x0335]),
+	(0x03b9, &[0x0069]),
+	(0x03ba, &[0x0138]),
+	(0x03bd, &[0x0076]),
+	(0x03bf, &[0x006f]),
+	(0x03c1, &[0x0070]),
+	(0x03c3, &[0x006f]),
+	(0x03c4, &[0x1d1b]),
+	(0x03c5, &[0x0075]),
+	(0x03c6, &[0x0278]),
+	(0x03d0, &[0x00df]),
+	(0x03d1, &[0x004f, 0x0335]),
+	(0x03d2, &[0x0059]),
+	(0x03d5, &[0x0278]),
+	(0x03d6, &[0x03c0]),
+	(0x03db, &[0x03c2]),
+	(0x03dc, &[0x0046]),
+	(0x03e8, &[0x0032]),
+	(0x03e9, &[0x01a8]),
+	(0x03f0, &[0x0138]),
+	(0x03f1, &[0x0070]),
+	(0x03f2, &[0x0063]),
+	(0x03f3, &[0x006a]),
+	(0x03f4, &[0x004f, 0x0335]),
+	(0x03f5, &[0xa793]),
+	(0x03f7, &[0x00de]),
+	(0x03f8, &[0x00fe]),
+	(0x03f9, &[0x0043]),
+	(0x03fa, &[0x004d]),
+	(0x03fd, &[0x0186]),
+	(0x03ff, &[0xa73e]),
+	(0x0404, &[0xa792]),
+	(0x0405, &[0x0053]),
+	(0x0406, &[0x006c]),
+	(0x0408, &[0x004a]),
+	(0x0410, &[0x0041]),
+	(0x0411, &[0x0062, 0x0304]),
+	(0x0412, &[0x0042]),
+	(0x0413, &[0x0393]),
+	(0x0415, &[0x0045]),
+	(0x0417, &[0x0033]),
+	(0x0419, &[0x040d]),
+	(0x041a, &[0x004b]),
+	(0x041b, &[0x0245]),
+	(0x041c, &[0x004d]),
+	(0x041d, &[0x0048]),
+	(0x041e, &[0x004f]),
+	(0x041f, &[0x03a0]),
+	(0x0420, &[0x0050]),
+	(0x0421, &[0x0043]),
+	(0x0422, &[0x0054]),
+	(0x0423, &[0x0059]),
+	(0x0424, &[0x03a6]),
+	(0x0425, &[0x0058]),
+	(0x042b, &[0x0062, 0x006c]),
+	(0x042c, &[0x0062]),
+	(0x042e, &[0x006c, 0x004f]),
+	(0x0430, &[0x0061]),
+	(0x0431, &[0x0036]),
+	(0x0432, &[0x0299]),
+	(0x0433, &[0x0072]),
+	(0x0435, &[0x0065]),
+	(0x0437, &[0x025c]),
+	(0x0438, &[0x1d0e]),
+	(0x043a, &[0x0138]),
+	(0x043c, &[0x028d]),
+	(0x043d, &[0x029c]),
+	(0x043e, &[0x006f]),
+	(0x043f, &[0x03c0]),
+	(0x0440, &[0x0070]),
+	(0x0441, &[0x0063]),
+	(0x0442, &[0x1d1b]),
+	(0x0443, &[0x0079]),
+	(0x0444, &[0x0278]),
+	(0x0445, &[0x0078]),
+	(0x044a, &[0x02c9, 0x0062]),
+	(0x044b, &[0x0185, 0x0069]),
+	(0x044c, &[0x0185]),
+	(0x044f, &[0x1d19]),
+	(0x0454, &[0xa793]),
+	(0x0455, &[0x0073]),
+	(0x0456, &[0x0069]),
+	(0x0458, &[0x006a]),
+	(0x045b, &[0x0068, 0x0335]),
+	(0x045d, &[0x0439]),
+	(0x0461, &[0x0077]),
+	(0x0462, &[0x0062, 0x0335]),
+	(0x0463, &[0x0062, 0x0335]),
+	(0x0470, &[0x03a8]),
+	(0x0471, &[0x03c8]),
+	(0x0472, &[0x004f, 0x0335]),
+	(0x0473, &[0x006f, 0x0335]),
+	(0x0474, &[0x0056]),
+	(0x0475, &[0x0076]),
+	(0x047c, &[0x0460, 0x0486, 0x0487]),
+	(0x047d, &[0x0077, 0x0486, 0x0487]),
+	(0x048a, &[0x040d, 0x0326]),
+	(0x048b, &[0x0439, 0x0326]),
+	(0x048c, &[0x0062, 0x0335]),
+	(0x048d, &[0x0062, 0x0335]),
+	(0x0490, &[0x0393, 0x0027]),
+	(0x0491, &[0x0072, 0x0027]),
+	(0x0492, &[0x0393, 0x0335]),
+	(0x0493, &[0x0072, 0x0335]),
+	(0x0496, &[0x0416, 0x0329]),
+	(0x0497, &[0x0436, 0x0329]),
+	(0x0498, &[0x0033, 0x0326]),
+	(0x0499, &[0x025c, 0x0326]),
+	(0x049a, &[0x004b, 0x0329]),
+	(0x049b, &[0x0138, 0x0329]),
+	(0x049e, &[0x004b, 0x0335]),
+	(0x049f, &[0x0138, 0x0335]),
+	(0x04a2, &[0x0048, 0x0329]),
+	(0x04a3, &[0x029c, 0x0329]),
+	(0x04aa, &[0x0043, 0x0326]),
+	(0x04ab, &[0x0063, 0x0326]),
+	(0x04ac, &[0x0054, 0x0329]),
+	(0x04ad, &[0x1d1b, 0x0329]),
+	(0x04ae, &[0x0059]),
+	(0x04af, &[0x0079]),
+	(0x04b0, &[0x0059, 0x0335]),
+	(0x04b1, &[0x0079, 0x0335]),
+	(0x04b2, &[0x0058, 0x0329]),
+	(0x04bb, &[0x0068]),
+	(0x04bd, &[0x0065]),
+	(0x04be, &[0x04bc, 0x0328]),
+	(0x04bf, &[0x0065, 0x0328]),
+	(0x04c0, &[0x006c]),
+	(0x04c5, &[0x0245, 0x0326]),
+	(0x04c6, &[0x043b, 0x0326]),
+	(0x04c7, &[0x0048, 0x0326]),
+	(0x04c8, &[0x029c, 0x0326]),
+	(0x04c9, &[0x0048, 0x0326]),
+	(0x04ca, &[0x029c, 0x0326]),
+	(0x04cb, &[0x04b6]),
+	(0x04cc, &[0x04b7]),
+	(0x04cd, &[0x004d, 0x0326]),
+	(0x04ce, &[0x028d, 0x0326]),
+	(0x04cf, &[0x0069]),
+	(0x04d4, &[0x0041, 0x0045]),
+	(0x04d5, &[0x0061, 0x0065]),
+	(0x04d8, &[0x018f]),
+	(0x04d9, &[0x01dd]),
+	(0x04e0, &[0x0033]),
+	(0x04e1, &[0x021d]),
+	(0x04e8, &[0x004f, 0x0335]),
+	(0x04e9, &[0x006f, 0x0335]),
+	(0x0501, &[0x0064]),
+	(0x050a, &[0x01f6]),
+	(0x050c, &[0x0047]),
+	(0x050d, &[0x0262]),
+	(0x0510, &[0x0190]),
+	(0x0511, &[0xa793]),
+	(0x051b, &[0x0071]),
+	(0x051c, &[0x0057]),
+	(0x051d, &[0x0077]),
+	(0x053b, &[0x12ae]),
+	(0x0544, &[0x1206]),
+	(0x054a, &[0x1323]),
+	(0x054c, &[0x1261]),
+	(0x054d, &[0x0055]),
+	(0x054f, &[0x0053]),
+	(0x0553, &[0x03a6]),
+	(0x0555, &[0x004f]),
+	(0x055a, &[0x0027]),
+	(0x055d, &[0x0027]),
+	(0x0561, &[0x0077]),
+	(0x0563, &[0x0071]),
+	(0x0566, &[0x0071]),
+	(0x056e, &[0x1e9f]),
+	(0x0570, &[0x0068]),
+	(0x0575, &[0x0237]),
+	(0x0578, &[0x006e]),
+	(0x057a, &[0x0270]),
+	(0x057c, &[0x006e]),
+	(0x057d, &[0x0075]),
+	(0x0581, &[0x0067]),
+	(0x0584, &[0x0066]),
+	(0x0585, &[0x006f]),
+	(0x0587, &[0x0565, 0x0582]),
+	(0x0589, &[0x003a]),
+	(0x059c, &[0x0301]),
+	(0x059d, &[0x0301]),
+	(0x05a4, &[0x059a]),
+	(0x05a8, &[0x0599]),
+	(0x05ad, &[0x0596]),
+	(0x05ae, &[0x0598]),
+	(0x05af, &[0x030a]),
+	(0x05b4, &[0x0323]),
+	(0x05b9, &[0x0307]),
+	(0x05ba, &[0x0307]),
+	(0x05c0, &[0x006c]),
+	(0x05c1, &[0x0307]),
+	(0x05c2, &[0x0307]),
+	(0x05c3, &[0x003a]),
+	(0x05c4, &[0x0307]),
+	(0x05c5, &[0x0323]),
+	(0x05d5, &[0x006c]),
+	(0x05d8, &[0x0076]),
+	(0x05d9, &[0x0027]),
+	(0x05df, &[0x006c]),
+	(0x05e1, &[0x006f]),
+	(0x05f0, &[0x006c, 0x006c]),
+	(0x05f1, &[0x006c, 0x0027]),
+	(0x05f2, &[0x0027, 0x0027]),
+	(0x05f3, &[0x0027]),
+	(0x05f4, &[0x0027, 0x0027]),
+	(0x0609, &[0x00ba, 0x002f, 0x2080, 0x2080]),
+	(0x060a, &[0x00ba, 0x002f, 0x2080, 0x2080, 0x2080]),
+	(0x060d, &[0x002c]),
+	(0x060f, &[0x0639]),
+	(0x0618, &[0x0301]),
+	(0x0619, &[0x0313]),
+	(0x061a, &[0x0650]),
+	(0x0623, &[0x006c, 0x0674]),
+	(0x0624, &[0x0648, 0x0674]),
+	(0x0625, &[0x006c, 0x0655]),
+	(0x0626, &[0x0649, 0x0674]),
+	(0x0627, &[0x006c]),
+	(0x062b, &[0x0649, 0x06db]),
+	(0x0634, &[0x0633, 0x06db]),
+	(0x063d, &[0x0649, 0x0302]),
+	(0x063f, &[0x0649, 0x06db]),
+	(0x0647, &[0x006f]),
+	(0x064a, &[0x0649]),
+	(0x064b, &[0x030b]),
+	(0x064e, &[0x0301]),
+	(0x064f, &[0x0313]),
+	(0x0652, &[0x030a]),
+	(0x0653, &[0x0303]),
+	(0x0656, &[0x0329]),
+	(0x0657, &[0x0312]),
+	(0x0658, &[0x0306]),
+	(0x0659, &[0x0304]),
+	(0x065a, &[0x0306]),
+	(0x065b, &[0x0302]),
+	(0x065c, &[0x0323]),
+	(0x065d, &[0x0314]),
+	(0x065f, &[0x0655]),
+	(0x0660, &[0x002e]),
+	(0x0661, &[0x006c]),
+	(0x0665, &[0x006f]),
+	(0x0667, &[0x0056]),
+	(0x0668, &[0x0245]),
+	(0x066a, &[0x00ba, 0x002f, 0x2080]),
+	(0x066b, &[0x002c]),
+	(0x066c, &[0x060c]),
+	(0x066d, &[0x002a]),
+	(0x066e, &[0x0649]),
+	(0x066f, &[0x06a1]),
+	(0x0672, &[0x006c, 0x0674]),
+	(0x0673, &[0x006c, 0x0655]),
+	(0x0675, &[0x006c, 0x0674]),
+	(0x0676, &[0x0648, 0x0674]),
+	(0x0677, &[0x0648, 0x0313, 0x0674]),
+	(0x0678, &[0x0649, 0x0674]),
+	(0x0679, &[0x0649, 0x0615]),
+	(0x067e, &[0x0649, 0x06db]),
+	(0x0681, &[0x062d, 0x0654]),
+	(0x0685, &[0x062d, 0x06db]),
+	(0x0688, &[0x062f, 0x0615]),
+	(0x068b, &[0x068a, 0x0615]),
+	(0x068e, &[0x062f, 0x06db]),
+	(0x0691, &[0x0631, 0x0615]),
+	(0x0692, &[0x0631, 0x0306]),
+	(0x0698, &[0x0631, 0x06db]),
+	(0x069e, &[0x0635, 0x06db]),
+	(0x069f, &[0x0637, 0x06db]),
+	(0x06a4, &[0x06a1, 0x06db]),
+	(0x06a7, &[0x0641]),
+	(0x06a8, &[0x06a1, 0x06db]),
+	(0x06a9, &[0x0643]),
+	(0x06aa, &[0x0643]),
+	(0x06ad, &[0x0643, 0x06db]),
+	(0x06b4, &[0x06af, 0x06db]),
+	(0x06b5, &[0x0644, 0x0306]),
+	(0x06b7, &[0x0644, 0x06db]),
+	(0x06ba, &[0x0649]),
+	(0x06bb, &[0x0649, 0x0615]),
+	(0x06bd, &[0x0649, 0x06db]),
+	(0x06be, &[0x006f]),
+	(0x06c1, &[0x006f]),
+	(0x06c2, &[0x06c0]),
+	(0x06c3, &[0x0629]),
+	(0x06c6, &[0x0648, 0x0306]),
+	(0x06c7, &[0x0648, 0x0313]),
+	(0x06c8, &[0x0648, 0x0670]),
+	(0x06c9, &[0x0648, 0x0302]),
+	(0x06cb, &[0x0648, 0x06db]),
+	(0x06cc, &[0x0649]),
+	(0x06ce, &[0x0649, 0x0306]),
+	(0x06d0, &[0x067b]),
+	(0x06d1, &[0x0649, 0x06db]),
+	(0x06d2, &[0x0649]),
+	(0x06d4, &[0x002d]),
+	(0x06d5, &[0x006f]),
+	(0x06df, &[0x030a]),
+	(0x06e8, &[0x0306, 0x0307]),
+	(0x06ec, &[0x0307]),
+	(0x06ee, &[0x062f, 0x0302]),
+	(0x06ef, &[0x0631, 0x0302]),
+	(0x06f0, &[0x002e]),
+	(0x06f1, &[0x006c]),
+	(0x06f2, &[0x0662]),
+	(0x06f3, &[0x0663]),
+	(0x06f4, &[0x0664]),
+	(0x06f5, &[0x006f]),
+	(0x06f6, &[0x0666]),
+	(0x06f7, &[0x0056]),
+	(0x06f8, &[0x0245]),
+	(0x06f9, &[0x0669]),
+	(0x06fd, &[0x0621, 0x0348]),
+	(0x06fe, &[0x0645, 0x0348]),
+	(0x06ff, &[0x006f, 0x0302]),
+	(0x0701, &[0x002e]),
+	(0x0702, &[0x002e]),
+	(0x0703, &[0x003a]),
+	(0x0704, &[0x003a]),
+	(0x0740, &[0x0307]),
+	(0x0741, &[0x0307]),
+	(0x0742, &[0x073c]),
+	(0x0747, &[0x0301]),
+	(0x0751, &[0x0628, 0x06db]),
+	(0x0756, &[0x0649, 0x0306]),
+	(0x0762, &[0x06ac]),
+	(0x0763, &[0x0643, 0x06db]),
+	(0x0767, &[0x0754]),
+	(0x0768, &[0x0646, 0x0615]),
+	(0x0769, &[0x0646, 0x0306]),
+	(0x076c, &[0x0631, 0x0654]),
+	(0x0771, &[0x0697, 0x0615]),
+	(0x0772, &[0x062d, 0x0654]),
+	(0x077e, &[0x0633, 0x0302]),
+	(0x07c0, &[0x004f]),
+	(0x07ca, &[0x006c]),
+	(0x07eb, &[0x0304]),
+	(0x07ed, &[0x0307]),
+	(0x07ee, &[0x0302]),
+	(0x07f3, &[0x0308]),
+	(0x07f4, &[0x0027]),
+	(0x07f5, &[0x0027]),
+	(0x07fa, &[0x005f]),
+	(0x08a1, &[0x0628, 0x0654]),
+	(0x08a4, &[0x06a2, 0x06db]),
+	(0x08a7, &[0x0645, 0x06db]),
+	(0x08a8, &[0x0649, 0x0654]),
+	(0x08a9, &[0x0754]),
+	(0x08ae, &[0x062f, 0x0324, 0x0323]),
+	(0x08af, &[0x0635, 0x0324, 0x0323]),
+	(0x08b0, &[0x06af]),
+	(0x08b1, &[0x0648]),
+	(0x08b2, &[0x0632, 0x0302]),
+	(0x08b6, &[0x0628, 0x06e2]),
+	(0x08b7, &[0x0649, 0x06db, 0x06e2]),
+	(0x08b9, &[0x0631, 0x0306, 0x0307]),
+	(0x08ba, &[0x0649, 0x0306, 0x0307]),
+	(0x08bb, &[0x06a1]),
+	(0x08bc, &[0x06a1]),
+	(0x08bd, &[0x0649]),
+	(0x08e5, &[0x064c]),
+	(0x08e8, &[0x064c]),
+	(0x08ea, &[0x0307]),
+	(0x08eb, &[0x0308]),
+	(0x08ed, &[0x0323]),
+	(0x08ee, &[0x0324]),
+	(0x08f0, &[0x030b]),
+	(0x08f1, &[0x064c]),
+	(0x08f2, &[0x064d]),
+	(0x08f3, &[0x0313]),
+	(0x08f8, &[0x0350]),
+	(0x08f9, &[0x0354]),
+	(0x08fa, &[0x0355]),
+	(0x08ff, &[0x0350]),
+	(0x0900, &[0x0352]),
+	(0x0901, &[0x0306, 0x0307]),
+	(0x0902, &[0x0307]),
+	(0x0903, &[0x003a]),
+	(0x0904, &[0x0905, 0x0946]),
+	(0x0906, &[0x0905, 0x093e]),
+	(0x0908, &[0x0930, 0x094d, 0x0907]),
+	(0x090d, &[0x090f, 0x0945]),
+	(0x090e, &[0x090f, 0x0946]),
+	(0x0910, &[0x090f, 0x0947]),
+	(0x0911, &[0x0905, 0x0949]),
+	(0x0912, &[0x0905, 0x093e, 0x0946]),
+	(0x0913, &[0x0905, 0x093e, 0x0947]),
+	(0x0914, &[0x0905, 0x093e, 0x0948]),
+	(0x093c, &[0x0323]),
+	(0x0952, &[0x0331]),
+	(0x0953, &[0x0300]),
+	(0x0954, &[0x0301]),
+	(0x0965, &[0x0964, 0x0964]),
+	(0x0966, &[0x006f]),
+	(0x0967, &[0x0669]),
+	(0x097d, &[0x003f]),
+	(0x0981, &[0x0306, 0x0307]),
+	(0x0986, &[0x0985, 0x09be]),
+	(0x09bc, &[0x0323]),
+	(0x09e0, &[0x098b, 0x09c3]),
+	(0x09e1, &[0x098b, 0x09c3]),
+	(0x09e6, &[0x004f]),
+	(0x09ea, &[0x0038]),
+	(0x09ed, &[0x0039]),
+	(0x0a02, &[0x0307]),
+	(0x0a03, &[0x0983]),
+	(0x0a06, &[0x0a05, 0x0a3e]),
+	(0x0a07, &[0x0a72, 0x0a3f]),
+	(0x0a08, &[0x0a72, 0x0a40]),
+	(0x0a09, &[0x0a73, 0x0a41]),
+	(0x0a0a, &[0x0a73, 0x0a42]),
+	(0x0a0f, &[0x0a72, 0x0a47]),
+	(0x0a10, &[0x0a05, 0x0a48]),
+	(0x0a14, &[0x0a05, 0x0a4c]),
+	(0x0a3c, &[0x0323]),
+	(0x0a4b, &[0x0946]),
+	(0x0a4d, &[0x094d]),
+	(0x0a66, &[0x006f]),
+	(0x0a67, &[0x0039]),
+	(0x0a6a, &[0x0038]),
+	(0x0a81, &[0x0306, 0x0307]),
+	(0x0a82, &[0x0307]),
+	(0x0a83, &[0x003a]),
+	(0x0a86, &[0x0a85, 0x0abe]),
+	(0x0a8d, &[0x0a85, 0x0ac5]),
+	(0x0a8f, &[0x0a85, 0x0ac7]),
+	(0x0a90, &[0x0a85, 0x0ac8]),
+	(0x0a91, &[0x0a85, 0x0abe, 0x0ac5]),
+	(0x0a93, &[0x0a85, 0x0abe, 0x0ac7]),
+	(0x0a94, &[0x0a85, 0x0abe, 0x0ac8]),
+	(0x0abc, &[0x0323]),
+	(0x0abd, &[0x093d]),
+	(0x0ac1, &[0x0941]),
+	(0x0ac2, &[0x0942]),
+	(0x0acd, &[0x094d]),
+	(0x0ae6, &[0x006f]),
+	(0x0ae8, &[0x0968]),
+	(0x0ae9, &[0x0969]),
+	(0x0aea, &[0x096a]),
+	(0x0aee, &[0x096e]),
+	(0x0af0, &[0x0970]),
+	(0x0b01, &[0x0306, 0x0307]),
+	(0x0b03, &[0x0038]),
+	(0x0b06, &[0x0b05, 0x0b3e]),
+	(0x0b20, &[0x004f]),
+	(0x0b3c, &[0x0323]),
+	(0x0b66, &[0x004f]),
+	(0x0b68, &[0x0039]),
+	(0x0b82, &[0x030a]),
+	(0x0b8a, &[0x0b89, 0x0bb3]),
+	(0x0b9c, &[0x0b90]),
+	(0x0bb0, &[0x0b88]),
+	(0x0bbe, &[0x0b88]),
+	(0x0bc8, &[0x0ba9]),
+	(0x0bca, &[0x0bc6, 0x0b88]),
+	(0x0bcb, &[0x0bc7, 0x0b88]),
+	(0x0bcc, &[0x0bc6, 0x0bb3]),
+	(0x0bcd, &[0x0307]),
+	(0x0bd7, &[0x0bb3]),
+	(0x0be6, &[0x006f]),
+	(0x0be7, &[0x0b95]),
+	(0x0be8, &[0x0b89]),
+	(0x0bea, &[0x0b9a]),
+	(0x0beb, &[0x0b88, 0x0bc1]),
+	(0x0bec, &[0x0b9a, 0x0bc1]),
+	(0x0bed, &[0x0b8e]),
+	(0x0bee, &[0x0b85]),
+	(0x0bf0, &[0x0baf]),
+	(0x0bf2, &[0x0b9a, 0x0bc2]),
+	(0x0bf4, &[0x0bae, 0x0bc0]),
+	(0x0bf5, &[0x0bf3]),
+	(0x0bf7, &[0x0b8e, 0x0bb5]),
+	(0x0bf8, &[0x0bb7]),
+	(0x0bfa, &[0x0ba8, 0x0bc0]),
+	(0x0c00, &[0x0306, 0x0307]),
+	(0x0c02, &[0x006f]),
+	(0x0c03, &[0x0983]),
+	(0x0c13, &[0x0c12, 0x0c55]),
+	(0x0c14, &[0x0c12, 0x0c4c]),
+	(0x0c20, &[0x0c30, 0x05bc]),
+	(0x0c22, &[0x0c21, 0x0323]),
+	(0x0c25, &[0x0c27, 0x05bc]),
+	(0x0c2d, &[0x0c2c, 0x0323]),
+	(0x0c2e, &[0x0c35, 0x0c41]),
+	(0x0c37, &[0x0c35, 0x0323]),
+	(0x0c39, &[0x0c35, 0x0c3e]),
+	(0x0c42, &[0x0c41, 0x0c3e]),
+	(0x0c44, &[0x0c43, 0x0c3e]),
+	(0x0c60, &[0x0c0b, 0x0c3e]),
+	(0x0c61, &[0x0c0c, 0x0c3e]),
+	(0x0c66, &[0x006f]),
+	(0x0c81, &[0x0306, 0x0307]),
+	(0x0c82, &[0x006f]),
+	(0x0c83, &[0x0983]),
+	(0x0c85, &[0x0c05]),
+	(0x0c86, &[0x0c06]),
+	(0x0c87, &[0x0c07]),
+	(0x0c92, &[0x0c12]),
+	(0x0c93, &[0x0c12, 0x0c55]),
+	(0x0c94, &[0x0c12, 0x0c4c]),
+	(0x0c9c, &[0x0c1c]),
+	(0x0c9e, &[0x0c1e]),
+	(0x0ca3, &[0x0c23]),
+	(0x0caf, &[0x0c2f]),
+	(0x0cb1, &[0x0c31]),
+	(0x0cb2, &[0x0c32]),
+	(0x0ce1, &[0x0c8c, 0x0cbe]),
+	(0x0ce6, &[0x006f]),
+	(0x0ce7, &[0x0c67]),
+	(0x0ce8, &[0x0c68]),
+	(0x0cef, &[0x0c6f]),
+	(0x0d01, &[0x0306, 0x0307]),
+	(0x0d02, &[0x006f]),
+	(0x0d03, &[0x0983]),
+	(0x0d08, &[0x0d07, 0x0d57]),
+	(0x0d09, &[0x0b89]),
+	(0x0d0a, &[0x0b89, 0x0d57]),
+	(0x0d0c, &[0x0d28, 0x0d41]),
+	(0x0d10, &[0x0d0e, 0x0d46]),
+	(0x0d13, &[0x0d12, 0x0d3e]),
+	(0x0d14, &[0x0d12, 0x0d57]),
+	(0x0d19, &[0x0d28, 0x0d41]),
+	(0x0d1c, &[0x0b90]),
+	(0x0d20, &[0x006f]),
+	(0x0d23, &[0x0ba3]),
+	(0x0d31, &[0x0d30]),
+	(0x0d34, &[0x0bb4]),
+	(0x0d36, &[0x0bb6]),
+	(0x0d3a, &[0x0b9f, 0x0bbf]),
+	(0x0d3f, &[0x0bbf]),
+	(0x0d40, &[0x0bbf]),
+	(0x0d42, &[0x0d41]),
+	(0x0d43, &[0x0d41]),
+	(0x0d48, &[0x0d46, 0x0d46]),
+	(0x0d4e, &[0x0971]),
+	(0x0d5a, &[0x0d28, 0x0d4d, 0x0d2e]),
+	(0x0d5f, &[0x006f, 0x0d30, 0x006f]),
+	(0x0d61, &[0x0d1e]),
+	(0x0d66, &[0x006f]),
+	(0x0d6a, &[0x0d30, 0x0d4d]),
+	(0x0d6b, &[0x0d26, 0x0d4d, 0x0d30]),
+	(0x0d6c, &[0x0d28, 0x0d4d, 0x0d28]),
+	(0x0d6d, &[0x0039]),
+	(0x0d6e, &[0x0d35, 0x0d4d, 0x0d30]),
+	(0x0d6f, &[0x0d28, 0x0d4d]),
+	(0x0d76, &[0x0d39, 0x0d4d, 0x0d2e]),
+	(0x0d79, &[0x0d28, 0x0d41]),
+	(0x0d7b, &[0x0d28, 0x0d4d]),
+	(0x0d7c, &[0x0d30, 0x0d4d]),
+	(0x0d82, &[0x006f]),
+	(0x0d83, &[0x0983]),
+	(0x0de9, &[0x0de8, 0x0dcf]),
+	(0x0dea, &[0x0da2]),
+	(0x0deb, &[0x0daf]),
+	(0x0def, &[0x0de8, 0x0dd3]),
+	(0x0e03, &[0x0e02]),
+	(0x0e0b, &[0x0e0a]),
+	(0x0e0f, &[0x0e0e]),
+	(0x0e14, &[0x0e04]),
+	(0x0e15, &[0x0e04]),
+	(0x0e17, &[0x0e11]),
+	(0x0e21, &[0x0e06]),
+	(0x0e26, &[0x0e20]),
+	(0x0e33, &[0x030a, 0x0e32]),
+	(0x0e41, &[0x0e40, 0x0e40]),
+	(0x0e45, &[0x0e32]),
+	(0x0e4d, &[0x030a]),
+	(0x0e50, &[0x006f]),
+	(0x0e88, &[0x0e08]),
+	(0x0e8d, &[0x0e22]),
+	(0x0e9a, &[0x0e1a]),
+	(0x0e9b, &[0x0e1b]),
+	(0x0e9d, &[0x0e1d]),
+	(0x0e9e, &[0x0e1e]),
+	(0x0e9f, &[0x0e1f]),
+	(0x0eb3, &[0x030a, 0x0eb2]),
+	(0x0eb8, &[0x0e38]),
+	(0x0eb9, &[0x0e39]),
+	(0x0ec8, &[0x0e48]),
+	(0x0ec9, &[0x0e49]),
+	(0x0eca, &[0x0e4a]),
+	(0x0ecb, &[0x0e4b]),
+	(0x0ecd, &[0x030a]),
+	(0x0ed0, &[0x006f]),
+	(0x0edc, &[0x0eab, 0x0e99]),
+	(0x0edd, &[0x0eab, 0x0ea1]),
+	(0x0f00, &[0x0f68, 0x0f7c, 0x0f7e]),
+	(0x0f02, &[0x0f60, 0x0f74, 0x0f82, 0x0f7f]),
+	(0x0f03, &[0x0f60, 0x0f74, 0x0f82, 0x0f14]),
+	(0x0f0c, &[0x0f0b]),
+	(0x0f0e, &[0x0f0d, 0x0f0d]),
+	(0x0f1b, &[0x0f1a, 0x0f1a]),
+	(0x0f1e, &[0x0f1d, 0x0f1d]),
+	(0x0f1f, &[0x0f1a, 0x0f1d]),
+	(0x0f37, &[0x0325]),
+	(0x0f6a, &[0x0f62]),
+	(0x0f77, &[0x0fb2, 0x0f71, 0x0f80]),
+	(0x0f79, &[0x0fb3, 0x0f71, 0x0f80]),
+	(0x0fce, &[0x0f1d, 0x0f1a]),
+	(0x0fd5, &[0x5350]),
+	(0x0fd6, &[0x534d]),
+	(0x1000, &[0x1002, 0x102c]),
+	(0x1010, &[0x006f, 0x102c]),
+	(0x101d, &[0x006f]),
+	(0x101f, &[0x1015, 0x102c]),
+	(0x1029, &[0x101e, 0x103c]),
+	(0x102a, &[0x101e, 0x103c, 0x1031, 0x102c, 0x103a]),
+	(0x1036, &[0x030a]),
+	(0x1038, &[0x0983]),
+	(0x1040, &[0x006f]),
+	(0x104b, &[0x104a, 0x104a]),
+	(0x1065, &[0x1041]),
+	(0x1066, &[0x1015, 0x103e]),
+	(0x106f, &[0x1015, 0x102c, 0x103e]),
+	(0x1070, &[0x1003, 0x103e]),
+	(0x107e, &[0x107d, 0x103e]),
+	(0x1081, &[0x1002, 0x103e]),
+	(0x109e, &[0x1083, 0x030a]),
+	(0x10a0, &[0xa786]),
+	(0x10e7, &[0x0079]),
+	(0x10f3, &[0x021d]),
+	(0x10ff, &[0x006f]),
+	(0x1101, &[0x1100, 0x1100]),
+	(0x1104, &[0x1103, 0x1103]),
+	(0x1108, &[0x1107, 0x1107]),
+	(0x110a, &[0x1109, 0x1109]),
+	(0x110d, &[0x110c, 0x110c]),
+	(0x1113, &[0x1102, 0x1100]),
+	(0x1114, &[0x1102, 0x1102]),
+	(0x1115, &[0x1102, 0x1103]),
+	(0x1116, &[0x1102, 0x1107]),
+	(0x1117, &[0x1103, 0x1100]),
+	(0x1118, &[0x1105, 0x1102]),
+	(0x1119, &[0x1105, 0x1105]),
+	(0x111a, &[0x1105, 0x1112]),
+	(0x111b, &[0x1105, 0x110b]),
+	(0x111c, &[0x1106, 0x1107]),
+	(0x111d, &[0x1106, 0x110b]),
+	(0x111e, &[0x1107, 0x1100]),
+	(0x111f, &[0x1107, 0x1102]),
+	(0x1120, &[0x1107, 0x1103]),
+	(0x1121, &[0x1107, 0x1109]),
+	(0x1122, &[0x1107, 0x1109, 0x1100]),
+	(0x1123, &[0x1107, 0x1109, 0x1103]),
+	(0x1124, &[0x1107, 0x1109, 0x1107]),
+	(0x1125, &[0x1107, 0x1109, 0x1109]),
+	(0x1126, &[0x1107, 0x1109, 0x110c]),
+	(0x1127, &[0x1107, 0x110c]),
+	(0x1128, &[0x1107, 0x110e]),
+	(0x1129, &[0x1107, 0x1110]),
+	(0x112a, &[0x1107, 0x1111]),
+	(0x112b, &[0x1107, 0x110b]),
+	(0x112c, &[0x1107, 0x1107, 0x110b]),
+	(0x112d, &[0x1109, 0x1100]),
+	(0x112e, &[0x1109, 0x1102]),
+	(0x112f, &[0x1109, 0x1103]),
+	(0x1130, &[0x1109, 0x1105]),
+	(0x1131, &[0x1109, 0x1106]),
+	(0x1132, &[0x1109, 0x1107]),
+	(0x1133, &[0x1109, 0x1107, 0x1100]),
+	(0x1134, &[0x1109, 0x1109, 0x1109]),
+	(0x1135, &[0x1109, 0x110b]),
+	(0x1136, &[0x1109, 0x110c]),
+	(0x1137, &[0x1109, 0x110e]),
+	(0x1138, &[0x1109, 0x110f]),
+	(0x1139, &[0x1109, 0x1110]),
+	(0x113a, &[0x1109, 0x1111]),
+	(0x113b, &[0x1105, 0x1112]),
+	(0x113d, &[0x113c, 0x113c]),
+	(0x113f, &[0x113e, 0x113e]),
+	(0x1141, &[0x110b, 0x1100]),
+	(0x1142, &[0x110b, 0x1103]),
+	(0x1143, &[0x110b, 0x1106]),
+	(0x1144, &[0x110b, 0x1107]),
+	(0x1145, &[0x110b, 0x1109]),
+	(0x1146, &[0x110b, 0x1140]),
+	(0x1147, &[0x110b, 0x110b]),
+	(0x1148, &[0x110b, 0x110c]),
+	(0x1149, &[0x110b, 0x110e]),
+	(0x114a, &[0x110b, 0x1110]),
+	(0x114b, &[0x110b, 0x1111]),
+	(0x114d, &[0x110c, 0x110b]),
+	(0x114f, &[0x114e, 0x114e]),
+	(0x1151, &[0x1150, 0x1150]),
+	(0x1152, &[0x110e, 0x110f]),
+	(0x1153, &[0x110e, 0x1112]),
+	(0x1156, &[0x1111, 0x1107]),
+	(0x1157, &[0x1111, 0x110b]),
+	(0x1158, &[0x1112, 0x1112]),
+	(0x115a, &[0x1100, 0x1103]),
+	(0x115b, &[0x1102, 0x1109]),
+	(0x115c, &[0x1102, 0x110c]),
+	(0x115d, &[0x1102, 0x1112]),
+	(0x115e, &[0x1103, 0x1105]),
+	(0x1162, &[0x1161, 0x4e28]),
+	(0x1164, &[0x1163, 0x4e28]),
+	(0x1166, &[0x1165, 0x4e28]),
+	(0x1168, &[0x1167, 0x4e28]),
+	(0x116a, &[0x1169, 0x1161]),
+	(0x116b, &[0x1169, 0x1161, 0x4e28]),
+	(0x116c, &[0x1169, 0x4e28]),
+	(0x116f, &[0x116e, 0x1165]),
+	(0x1170, &[0x116e, 0x1165, 0x4e28]),
+	(0x1171, &[0x116e, 0x4e28]),
+	(0x1173, &[0x30fc]),
+	(0x1174, &[0x30fc, 0x4e28]),
+	(0x1175, &[0x4e28]),
+	(0x1176, &[0x1161, 0x1169]),
+	(0x1177, &[0x1161, 0x116e]),
+	(0x1178, &[0x1163, 0x1169]),
+	(0x1179, &[0x1163, 0x116d]),
+	(0x117a, &[0x1165, 0x1169]),
+	(0x117b, &[0x1165, 0x116e]),
+	(0x117c, &[0x1165, 0x30fc]),
+	(0x117d, &[0x1167, 0x1169]),
+	(0x117e, &[0x1167, 0x116e]),
+	(0x117f, &[0x1169, 0x1165]),
+	(0x1180, &[0x1169, 0x1165, 0x4e28]),
+	(0x1181, &[0x1169, 0x1167, 0x4e28]),
+	(0x1182, &[0x1169, 0x1169]),
+	(0x1183, &[0x1169, 0x116e]),
+	(0x1184, &[0x116d, 0x1163]),
+	(0x1185, &[0x116d, 0x1163, 0x4e28]),
+	(0x1186, &[0x116d, 0x1163]),
+	(0x1187, &[0x116d, 0x1169]),
+	(0x1188, &[0x116d, 0x4e28]),
+	(0x1189, &[0x116e, 0x1161]),
+	(0x118a, &[0x116e, 0x1161, 0x4e28]),
+	(0x118b, &[0x116e, 0x1165, 0x30fc]),
+	(0x118c, &[0x116e, 0x1167, 0x4e28]),
+	(0x118d, &[0x116e, 0x116e]),
+	(0x118e, &[0x1172, 0x1161]),
+	(0x118f, &[0x1172, 0x1165]),
+	(0x1190, &[0x1172, 0x1165, 0x4e28]),
+	(0x1191, &[0x1172, 0x1167]),
+	(0x1192, &[0x1172, 0x1167, 0x4e28]),
+	(0x1193, &[0x1172, 0x116e]),
+	(0x1194, &[0x1172, 0x4e28]),
+	(0x1195, &[0x30fc, 0x116e]),
+	(0x1196, &[0x30fc, 0x30fc]),
+	(0x1197, &[0x30fc, 0x4e28, 0x116e]),
+	(0x1198, &[0x4e28, 0x1161]),
+	(0x1199, &[0x4e28, 0x1163]),
+	(0x119a, &[0x4e28, 0x1169]),
+	(0x119b, &[0x4e28, 0x116e]),
+	(0x119c, &[0x4e28, 0x30fc]),
+	(0x119d, &[0x4e28, 0x119e]),
+	(0x119f, &[0x119e, 0x1165]),
+	(0x11a0, &[0x119e, 0x116e]),
+	(0x11a1, &[0x119e, 0x4e28]),
+	(0x11a2, &[0x119e, 0x119e]),
+	(0x11a3, &[0x1161, 0x30fc]),
+	(0x11a4, &[0x1163, 0x116e]),
+	(0x11a5, &[0x1167, 0x1163]),
+	(0x11a6, &[0x1169, 0x1163]),
+	(0x11a7, &[0x1169, 0x1163, 0x4e28]),
+	(0x11a8, &[0x1100]),
+	(0x11a9, &[0x1100, 0x1100]),
+	(0x11aa, &[0x1100, 0x1109]),
+	(0x11ab, &[0x1102]),
+	(0x11ac, &[0x1102, 0x110c]),
+	(0x11ad, &[0x1102, 0x1112]),
+	(0x11ae, &[0x1103]),
+	(0x11af, &[0x1105]),
+	(0x11b0, &[0x1105, 0x1100]),
+	(0x11b1, &[0x1105, 0x1106]),
+	(0x11b2, &[0x1105, 0x1107]),
+	(0x11b3, &[0x1105, 0x1109]),
+	(0x11b4, &[0x1105, 0x1110]),
+	(0x11b5, &[0x1105, 0x1111]),
+	(0x11b6, &[0x1105, 0x1112]),
+	(0x11b7, &[0x1106]),
+	(0x11b8, &[0x1107]),
+	(0x11b9, &[0x1107, 0x1109]),
+	(0x11ba, &[0x1109]),
+	(0x11bb, &[0x1109, 0x1109]),
+	(0x11bc, &[0x110b]),
+	(0x11bd, &[0x110c]),
+	(0x11be, &[0x110e]),
+	(0x11bf, &[0x110f]),
+	(0x11c0, &[0x1110]),
+	(0x11c1, &[0x1111]),
+	(0x11c2, &[0x1112]),
+	(0x11c3, &[0x1100, 0x1105]),
+	(0x11c4, &[0x1100, 0x1109, 0x1100]),
+	(0x11c5, &[0x1102, 0x1100]),
+	(0x11c6, &[0x1102, 0x1103]),
+	(0x11c7, &[0x1102, 0x1109]),
+	(0x11c8, &[0x1102, 0x1140]),
+	(0x11c9, &[0x1102, 0x1110]),
+	(0x11ca, &[0x1103, 0x1100]),
+	(0x11cb, &[0x1103, 0x1105]),
+	(0x11cc, &[0x1105, 0x1100, 0x1109]),
+	(0x11cd, &[0x1105, 0x1102]),
+	(0x11ce, &[0x1105, 0x1103]),
+	(0x11cf, &[0x1105, 0x1103, 0x1112]),
+	(0x11d0, &[0x1105, 0x1105]),
+	(0x11d1, &[0x1105, 0x1106, 0x1100]),
+	(0x11d2, &[0x1105, 0x1106, 0x1109]),
+	(0x11d3, &[0x1105, 0x1107, 0x1109]),
+	(0x11d4, &[0x1105, 0x1107, 0x1112]),
+	(0x11d5, &[0x1105, 0x1107, 0x110b]),
+	(0x11d6, &[0x1105, 0x1109, 0x1109]),
+	(0x11d7, &[0x1105, 0x1140]),
+	(0x11d8, &[0x1105, 0x110f]),
+	(0x11d9, &[0x1105, 0x1159]),
+	(0x11da, &[0x1106, 0x1100]),
+	(0x11db, &[0x1106, 0x1105]),
+	(0x11dc, &[0x1106, 0x1107]),
+	(0x11dd, &[0x1106, 0x1109]),
+	(0x11de, &[0x1106, 0x1109, 0x1109]),
+	(0x11df, &[0x1106, 0x1140]),
+	(0x11e0, &[0x1106, 0x110e]),
+	(0x11e1, &[0x1106, 0x1112]),
+	(0x11e2, &[0x1106, 0x110b]),
+	(0x11e3, &[0x1107, 0x1105]),
+	(0x11e4, &[0x1107, 0x1111]),
+	(0x11e5, &[0x1107, 0x1112]),
+	(0x11e6, &[0x1107, 0x110b]),
+	(0x11e7, &[0x1109, 0x1100]),
+	(0x11e8, &[0x1109, 0x1103]),
+	(0x11e9, &[0x1109, 0x1105]),
+	(0x11ea, &[0x1109, 0x1107]),
+	(0x11eb, &[0x1140]),
+	(0x11ec, &[0x110b, 0x1100]),
+	(0x11ed, &[0x110b, 0x1100, 0x1100]),
+	(0x11ee, &[0x110b, 0x110b]),
+	(0x11ef, &[0x110b, 0x110f]),
+	(0x11f0, &[0x114c]),
+	(0x11f1, &[0x110b, 0x1109]),
+	(0x11f2, &[0x110b, 0x1140]),
+	(0x11f3, &[0x1111, 0x1107]),
+	(0x11f4, &[0x1111, 0x110b]),
+	(0x11f5, &[0x1112, 0x1102]),
+	(0x11f6, &[0x1112, 0x1105]),
+	(0x11f7, &[0x1112, 0x1106]),
+	(0x11f8, &[0x1112, 0x1107]),
+	(0x11f9, &[0x1159]),
+	(0x11fa, &[0x1100, 0x1102]),
+	(0x11fb, &[0x1100, 0x1107]),
+	(0x11fc, &[0x1100, 0x110e]),
+	(0x11fd, &[0x1100, 0x110f]),
+	(0x11fe, &[0x1100, 0x1112]),
+	(0x11ff, &[0x1102, 0x1102]),
+	(0x1200, &[0x0055]),
+	(0x1223, &[0x0270]),
+	(0x1240, &[0x03a6]),
+	(0x1260, &[0x0548]),
+	(0x1294, &[0x0571]),
+	(0x12d0, &[0x004f]),
+	(0x13a0, &[0x0044]),
+	(0x13a1, &[0x0052]),
+	(0x13a2, &[0x0054]),
+	(0x13a4, &[0x004f, 0x0027]),
+	(0x13a5, &[0x0069]),
+	(0x13a8, &[0x2c75]),
+	(0x13a9, &[0x0059]),
+	(0x13aa, &[0x0041]),
+	(0x13ab, &[0x004a]),
+	(0x13ac, &[0x0045]),
+	(0x13ae, &[0x003f]),
+	(0x13b0, &[0x2c75]),
+	(0x13b1, &[0x0393]),
+	(0x13b3, &[0x0057]),
+	(0x13b7, &[0x004d]),
+	(0x13bb, &[0x0048]),
+	(0x13bd, &[0x0059]),
+	(0x13be, &[0x004f, 0x0335]),
+	(0x13bf, &[0x01ab]),
+	(0x13c0, &[0x0047]),
+	(0x13c2, &[0x0068]),
+	(0x13c3, &[0x005a]),
+	(0x13c7, &[0x0460]),
+	(0x13cb, &[0x0190]),
+	(0x13cc, &[0x0055, 0x0335]),
+	(0x13ce, &[0x0034]),
+	(0x13cf, &[0x0062]),
+	(0x13d2, &[0x0052]),
+	(0x13d4, &[0x0057]),
+	(0x13d5, &[0x0053]),
+	(0x13d9, &[0x0056]),
+	(0x13da, &[0x0053]),
+	(0x13de, &[0x004c]),
+	(0x13df, &[0x0043]),
+	(0x13e2, &[0x0050]),
+	(0x13e6, &[0x004b]),
+	(0x13e7, &[0x0064]),
+	(0x13eb, &[0x004f, 0x0335]),
+	(0x13ee, &[0x0036]),
+	(0x13f0, &[0x00df]),
+	(0x13f2, &[0x0068, 0x0314]),
+	(0x13f3, &[0x0047]),
+	(0x13f4, &[0x0042]),
+	(0x13fb, &[0x0262]),
+	(0x13fc, &[0x0299]),
+	(0x1400, &[0x003d]),
+	(0x1403, &[0x0394]),
+	(0x140c, &[0x00b7, 0x1401]),
+	(0x140d, &[0x1401, 0x00b7]),
+	(0x140e, &[0x00b7, 0x0394]),
+	(0x140f, &[0x0394, 0x00b7]),
+	(0x1410, &[0x00b7, 0x1404]),
+	(0x1411, &[0x1404, 0x00b7]),
+	(0x1412, &[0x00b7, 0x1405]),
+	(0x1413, &[0x1405, 0x00b7]),
+	(0x1414, &[0x00b7, 0x1406]),
+	(0x1415, &[0x1406, 0x00b7]),
+	(0x1417, &[0x00b7, 0x140a]),
+	(0x1418, &[0x140a, 0x00b7]),
+	(0x1419, &[0x00b7, 0x140b]),
+	(0x141a, &[0x140b, 0x00b7]),
+	(0x1427, &[0x00b7]),
+	(0x142b, &[0x1401, 0x1420]),
+	(0x142c, &[0x0394, 0x1420]),
+	(0x142d, &[0x1405, 0x1420]),
+	(0x142e, &[0x140a, 0x1420]),
+	(0x142f, &[0x0056]),
+	(0x1431, &[0x0245]),
+	(0x1433, &[0x003e]),
+	(0x1437, &[0x00b7, 0x003e]),
+	(0x1438, &[0x003c]),
+	(0x143a, &[0x00b7, 0x0056]),
+	(0x143b, &[0x0056, 0x00b7]),
+	(0x143c, &[0x00b7, 0x0245]),
+	(0x143d, &[0x0245, 0x00b7]),
+	(0x143e, &[0x00b7, 0x1432]),
+	(0x143f, &[0x1432, 0x00b7]),
+	(0x1440, &[0x00b7, 0x003e]),
+	(0x1441, &[0x003e, 0x00b7]),
+	(0x1442, &[0x00b7, 0x1434]),
+	(0x1443, &[0x1434, 0x00b7]),
+	(0x1444, &[0x00b7, 0x003c]),
+	(0x1445, &[0x003c, 0x00b7]),
+	(0x1446, &[0x00b7, 0x1439]),
+	(0x1447, &[0x1439, 0x00b7]),
+	(0x144a, &[0x0027]),
+	(0x144c, &[0x0055]),
+	(0x144e, &[0x0548]),
+	(0x1454, &[0x00b7, 0x1450]),
+	(0x1457, &[0x00b7, 0x0055]),
+	(0x1458, &[0x0055, 0x00b7]),
+	(0x1459, &[0x00b7, 0x0548]),
+	(0x145a, &[0x0548, 0x00b7]),
+	(0x145b, &[0x00b7, 0x144f]),
+	(0x145c, &[0x144f, 0x00b7]),
+	(0x145d, &[0x00b7, 0x1450]),
+	(0x145e, &[0x1450, 0x00b7]),
+	(0x145f, &[0x00b7, 0x1451]),
+	(0x1460, &[0x1451, 0x00b7]),
+	(0x1461, &[0x00b7, 0x1455]),
+	(0x1462, &[0x1455, 0x00b7]),
+	(0x1463, &[0x00b7, 0x1456]),
+	(0x1464, &[0x1456, 0x00b7]),
+	(0x1467, &[0x0055, 0x0027]),
+	(0x1468, &[0x0548, 0x0027]),
+	(0x1469, &[0x1450, 0x0027]),
+	(0x146a, &[0x1455, 0x0027]),
+	(0x146d, &[0x0050]),
+	(0x146f, &[0x0064]),
+	(0x1472, &[0x0062]),
+	(0x1473, &[0x0062, 0x0307]),
+	(0x1474, &[0x00b7, 0x146b]),
+	(0x1475, &[0x146b, 0x00b7]),
+	(0x1476, &[0x00b7, 0x0050]),
+	(0x1477, &[0x0070, 0x00b7]),
+	(0x1478, &[0x00b7, 0x146e]),
+	(0x1479, &[0x146e, 0x00b7]),
+	(0x147a, &[0x00b7, 0x0064]),
+	(0x147b, &[0x0064, 0x00b7]),
+	(0x147c, &[0x00b7, 0x1470]),
+	(0x147d, &[0x1470, 0x00b7]),
+	(0x147e, &[0x00b7, 0x0062]),
+	(0x147f, &[0x0062, 0x00b7]),
+	(0x1480, &[0x00b7, 0x0062, 0x0307]),
+	(0x1481, &[0x0062, 0x0307, 0x00b7]),
+	(0x1485, &[0x146b, 0x0027]),
+	(0x1486, &[0x0050, 0x0027]),
+	(0x1487, &[0x0064, 0x0027]),
+	(0x1488, &[0x0062, 0x0027]),
+	(0x148d, &[0x004a]),
+	(0x1492, &[0x00b7, 0x1489]),
+	(0x1493, &[0x1489, 0x00b7]),
+	(0x1494, &[0x00b7, 0x148b]),
+	(0x1495, &[0x148b, 0x00b7]),
+	(0x1496, &[0x00b7, 0x148c]),
+	(0x1497, &[0x148c, 0x00b7]),
+	(0x1498, &[0x00b7, 0x004a]),
+	(0x1499, &[0x004a, 0x00b7]),
+	(0x149a, &[0x00b7, 0x148e]),
+	(0x149b, &[0x148e, 0x00b7]),
+	(0x149c, &[0x00b7, 0x1490]),
+	(0x149d, &[0x1490, 0x00b7]),
+	(0x149e, &[0x00b7, 0x1491]),
+	(0x149f, &[0x1491, 0x00b7]),
+	(0x14a5, &[0x0393]),
+	(0x14aa, &[0x004c]),
+	(0x14ac, &[0x00b7, 0x14a3]),
+	(0x14ad, &[0x14a3, 0x00b7]),
+	(0x14ae, &[0x00b7, 0x0393]),
+	(0x14af, &[0x0393, 0x00b7]),
+	(0x14b0, &[0x00b7, 0x14a6]),
+	(0x14b1, &[0x14a6, 0x00b7]),
+	(0x14b2, &[0x00b7, 0x14a7]),
+	(0x14b3, &[0x14a7, 0x00b7]),
+	(0x14b4, &[0x00b7, 0x14a8]),
+	(0x14b5, &[0x14a8, 0x00b7]),
+	(0x14b6, &[0x00b7, 0x004c]),
+	(0x14b7, &[0x006c, 0x00b7]),
+	(0x14b8, &[0x00b7, 0x14ab]),
+	(0x14b9, &[0x14ab, 0x00b7]),
+	(0x14bf, &[0x0032]),
+	(0x14c9, &[0x00b7, 0x14c0]),
+	(0x14ca, &[0x14c0, 0x00b7]),
+	(0x14cb, &[0x00b7, 0x14c7]),
+	(0x14cc, &[0x14c7, 0x00b7]),
+	(0x14cd, &[0x00b7, 0x14c8]),
+	(0x14ce, &[0x14c8, 0x00b7]),
+	(0x14d1, &[0x1421]),
+	(0x14dc, &[0x00b7, 0x14d3]),
+	(0x14dd, &[0x14d3, 0x00b7]),
+	(0x14de, &[0x00b7, 0x14d5]),
+	(0x14df, &[0x14d5, 0x00b7]),
+	(0x14e0, &[0x00b7, 0x14d6]),
+	(0x14e1, &[0x14d6, 0x00b7]),
+	(0x14e2, &[0x00b7, 0x14d7]),
+	(0x14e3, &[0x14d7, 0x00b7]),
+	(0x14e4, &[0x00b7, 0x14d8]),
+	(0x14e5, &[0x14d8, 0x00b7]),
+	(0x14e6, &[0x00b7, 0x14da]),
+	(0x14e7, &[0x14da, 0x00b7]),
+	(0x14e8, &[0x00b7, 0x14db]),
+	(0x14e9, &[0x14db, 0x00b7]),
+	(0x14f6, &[0x00b7, 0x14ed]),
+	(0x14f7, &[0x14ed, 0x00b7]),
+	(0x14f8, &[0x00b7, 0x14ef]),
+	(0x14f9, &[0x14ef, 0x00b7]),
+	(0x14fa, &[0x00b7, 0x14f0]),
+	(0x14fb, &[0x14f0, 0x00b7]),
+	(0x14fc, &[0x00b7, 0x14f1]),
+	(0x14fd, &[0x14f1, 0x00b7]),
+	(0x14fe, &[0x00b7, 0x14f2]),
+	(0x14ff, &[0x14f2, 0x00b7]),
+	(0x1500, &[0x00b7, 0x14f4]),
+	(0x1501, &[0x14f4, 0x00b7]),
+	(0x1502, &[0x00b7, 0x14f5]),
+	(0x1503, &[0x14f5, 0x00b7]),
+	(0x150c, &[0x150b, 0x003c]),
+	(0x150d, &[0x150b, 0x1455]),
+	(0x150e, &[0x150b, 0x0062]),
+	(0x150f, &[0x150b, 0x1490]),
+	(0x1517, &[0x00b7, 0x1510]),
+	(0x1518, &[0x1510, 0x00b7]),
+	(0x1519, &[0x00b7, 0x1511]),
+	(0x151a, &[0x1511, 0x00b7]),
+	(0x151b, &[0x00b7, 0x1512]),
+	(0x151c, &[0x1512, 0x00b7]),
+	(0x151d, &[0x00b7, 0x1513]),
+	(0x151e, &[0x1513, 0x00b7]),
+	(0x151f, &[0x00b7, 0x1514]),
+	(0x1520, &[0x1514, 0x00b7]),
+	(0x1521, &[0x00b7, 0x1515]),
+	(0x1522, &[0x1515, 0x00b7]),
+	(0x1523, &[0x00b7, 0x1516]),
+	(0x1524, &[0x1516, 0x00b7]),
+	(0x152f, &[0x00b7, 0x0034]),
+	(0x1530, &[0x0034, 0x00b7]),
+	(0x1531, &[0x00b7, 0x1528]),
+	(0x1532, &[0x1528, 0x00b7]),
+	(0x1533, &[0x00b7, 0x1529]),
+	(0x1534, &[0x1529, 0x00b7]),
+	(0x1535, &[0x00b7, 0x152a]),
+	(0x1536, &[0x152a, 0x00b7]),
+	(0x1537, &[0x00b7, 0x152b]),
+	(0x1538, &[0x152b, 0x00b7]),
+	(0x1539, &[0x00b7, 0x152d]),
+	(0x153a, &[0x152d, 0x00b7]),
+	(0x153b, &[0x00b7, 0x152e]),
+	(0x153c, &[0x152e, 0x00b7]),
+	(0x1540, &[0x1429]),
+	(0x1541, &[0x0078]),
+	(0x154e, &[0x00b7, 0x154c]),
+	(0x154f, &[0x154c, 0x00b7]),
+	(0x155b, &[0x00b7, 0x155a]),
+	(0x155c, &[0x155a, 0x00b7]),
+	(0x1568, &[0x00b7, 0x1567]),
+	(0x1569, &[0x1567, 0x00b7]),
+	(0x1577, &[0x1e9f]),
+	(0x157c, &[0x0048]),
+	(0x157d, &[0x0078]),
+	(0x157e, &[0x1550, 0x146c]),
+	(0x157f, &[0x1550, 0x0050]),
+	(0x1580, &[0x1550, 0x146e]),
+	(0x1581, &[0x1550, 0x0064]),
+	(0x1582, &[0x1550, 0x1470]),
+	(0x1583, &[0x1550, 0x0062]),
+	(0x1584, &[0x1550, 0x0062, 0x0307]),
+	(0x1585, &[0x1550, 0x1483]),
+	(0x1587, &[0x0052]),
+	(0x158e, &[0x1595, 0x148a]),
+	(0x158f, &[0x1595, 0x148b]),
+	(0x1590, &[0x1595, 0x148c]),
+	(0x1591, &[0x1595, 0x004a]),
+	(0x1592, &[0x1595, 0x148e]),
+	(0x1593, &[0x1595, 0x1490]),
+	(0x1594, &[0x1595, 0x1491]),
+	(0x15af, &[0x0062]),
+	(0x15b4, &[0x0046]),
+	(0x15b5, &[0x2132]),
+	(0x15b7, &[0xa7fb]),
+	(0x15c4, &[0x2c6f]),
+	(0x15c5, &[0x0041]),
+	(0x15de, &[0x0044]),
+	(0x15ea, &[0x0044]),
+	(0x15ef, &[0x0460]),
+	(0x15f0, &[0x004d]),
+	(0x15f7, &[0x0042]),
+	(0x1602, &[0x1490]),
+	(0x1603, &[0x1489]),
+	(0x1604, &[0x14d3]),
+	(0x1607, &[0x14da]),
+	(0x1622, &[0x1543]),
+	(0x1623, &[0x1546]),
+	(0x1624, &[0x154a]),
+	(0x162e, &[0x01b1]),
+	(0x162f, &[0x03a9]),
+	(0x1634, &[0x01b1]),
+	(0x1635, &[0x03a9]),
+	(0x166d, &[0x0058]),
+	(0x166e, &[0x0078]),
+	(0x166f, &[0x1550, 0x146b]),
+	(0x1670, &[0x1595, 0x1489]),
+	(0x1671, &[0x1596, 0x148b]),
+	(0x1672, &[0x1596, 0x148c]),
+	(0x1673, &[0x1596, 0x004a]),
+	(0x1674, &[0x1596, 0x148e]),
+	(0x1675, &[0x1596, 0x1490]),
+	(0x1676, &[0x1596, 0x1491]),
+	(0x1677, &[0x15a7, 0x00b7]),
+	(0x1678, &[0x15a8, 0x00b7]),
+	(0x1679, &[0x15a9, 0x00b7]),
+	(0x167a, &[0x15aa, 0x00b7]),
+	(0x167b, &[0x15ab, 0x00b7]),
+	(0x167c, &[0x15ac, 0x00b7]),
+	(0x167d, &[0x15ad, 0x00b7]),
+	(0x1680, &[0x0020]),
+	(0x16b2, &[0x003c]),
+	(0x16b7, &[0x0058]),
+	(0x16c1, &[0x006c]),
+	(0x16c2, &[0x16bd]),
+	(0x16cc, &[0x0027]),
+	(0x16d5, &[0x004b]),
+	(0x16d6, &[0x004d]),
+	(0x16d8, &[0x03a8]),
+	(0x16e1, &[0x16bc]),
+	(0x16eb, &[0x00b7]),
+	(0x16ec, &[0x003a]),
+	(0x16ed, &[0x002b]),
+	(0x16f0, &[0x03a6]),
+	(0x1734, &[0x1715]),
+	(0x1735, &[0x002f]),
+	(0x17a3, &[0x17a2]),
+	(0x17b7, &[0x0e34]),
+	(0x17b8, &[0x0e35]),
+	(0x17b9, &[0x0e36]),
+	(0x17ba, &[0x0e37]),
+	(0x17c6, &[0x030a]),
+	(0x17cb, &[0x0e48]),
+	(0x17d3, &[0x030a]),
+	(0x17d4, &[0x0e2f]),
+	(0x17d5, &[0x0e5a]),
+	(0x17d9, &[0x0e4f]),
+	(0x17da, &[0x0e5b]),
+	(0x1803, &[0x003a]),
+	(0x1809, &[0x003a]),
+	(0x1855, &[0x1835]),
+	(0x1896, &[0x185c]),
+	(0x18b3, &[0x00b7, 0x18b1]),
+	(0x18b6, &[0x00b7, 0x18b4]),
+	(0x18b9, &[0x00b7, 0x18b8]),
+	(0x18c2, &[0x00b7, 0x18c0]),
+	(0x18c6, &[0x00b7, 0x14c2]),
+	(0x18c7, &[0x14c2, 0x00b7]),
+	(0x18c8, &[0x00b7, 0x14c3]),
+	(0x18c9, &[0x14c3, 0x00b7]),
+	(0x18ca, &[0x00b7, 0x14c4]),
+	(0x18cb, &[0x14c4, 0x00b7]),
+	(0x18cc, &[0x00b7, 0x14c5]),
+	(0x18cd, &[0x14c5, 0x00b7]),
+	(0x18ce, &[0x00b7, 0x1543]),
+	(0x18cf, &[0x00b7, 0x1546]),
+	(0x18d0, &[0x00b7, 0x1547]),
+	(0x18d1, &[0x00b7, 0x1548]),
+	(0x18d2, &[0x00b7, 0x1549]),
+	(0x18d3, &[0x00b7, 0x154b]),
+	(0x18db, &[0x18f5]),
+	(0x18dc, &[0x18df, 0x141e]),
+	(0x18dd, &[0x141e, 0x18df]),
+	(0x18e0, &[0x1543, 0x00b7]),
+	(0x18e3, &[0x155e, 0x00b7]),
+	(0x18e4, &[0x1566, 0x00b7]),
+	(0x18e5, &[0x156b, 0x00b7]),
+	(0x18e8, &[0x1586, 0x00b7]),
+	(0x18ea, &[0x1597, 0x00b7]),
+	(0x18ed, &[0x0460, 0x00b7]),
+	(0x18f0, &[0x15f4, 0x00b7]),
+	(0x18f2, &[0x161b, 0x00b7]),
+	(0x19d0, &[0x199e]),
+	(0x19d1, &[0x19b1]),
+	(0x1a80, &[0x1a45]),
+	(0x1a90, &[0x1a45]),
+	(0x1aa9, &[0x1aa8, 0x1aa8]),
+	(0x1aab, &[0x1aaa, 0x1aa8]),
+	(0x1ab4, &[0x06db]),
+	(0x1ab7, &[0x0328]),
+	(0x1b52, &[0x1b0d]),
+	(0x1b53, &[0x1b11]),
+	(0x1b58, &[0x1b28]),
+	(0x1b5c, &[0x1b50]),
+	(0x1b5f, &[0x1b5e, 0x1b5e]),
+	(0x1c3c, &[0x1c3b, 0x1c3b]),
+	(0x1c7f, &[0x1c7e, 0x1c7e]),
+	(0x1cd0, &[0x0302]),
+	(0x1cd2, &[0x0304]),
+	(0x1cd3, &[0x0027, 0x0027]),
+	(0x1cd5, &[0x032b]),
+	(0x1cd8, &[0x032e]),
+	(0x1cd9, &[0x032d]),
+	(0x1cda, &[0x030e]),
+	(0x1cdc, &[0x0329]),
+	(0x1cdd, &[0x0323]),
+	(0x1cde, &[0x0324]),
+	(0x1ced, &[0x0316]),
+	(0x1d04, &[0x0063]),
+	(0x1d08, &[0x025c]),
+	(0x1d0b, &[0x0138]),
+	(0x1d0d, &[0x028d]),
+	(0x1d0f, &[0x006f]),
+	(0x1d10, &[0x0254]),
+	(0x1d11, &[0x006f]),
+	(0x1d14, &[0x01dd, 0x006f]),
+	(0x1d1c, &[0x0075]),
+	(0x1d20, &[0x0076]),
+	(0x1d21, &[0x0077]),
+	(0x1d22, &[0x007a]),
+	(0x1d24, &[0x01a8]),
+	(0x1d26, &[0x0072]),
+	(0x1d27, &[0x028c]),
+	(0x1d28, &[0x03c0]),
+	(0x1d29, &[0x1d18]),
+	(0x1d2b, &[0x043b]),
+	(0x1d3e, &[0x18d6]),
+	(0x1d52, &[0x00ba]),
+	(0x1d6b, &[0x0075, 0x0065]),
+	(0x1d6e, &[0x0066, 0x0334]),
+	(0x1d6f, &[0x0072, 0x006e, 0x0334]),
+	(0x1d70, &[0x006e, 0x0334]),
+	(0x1d72, &[0x0072, 0x0334]),
+	(0x1d73, &[0x027e, 0x0334]),
+	(0x1d74, &[0x0073, 0x0334]),
+	(0x1d75, &[0x0074, 0x0334]),
+	(0x1d76, &[0x007a, 0x0334]),
+	(0x1d78, &[0x1d34]),
+	(0x1d7b, &[0x0069, 0x0335]),
+	(0x1d7c, &[0x0069, 0x0335]),
+	(0x1d7d, &[0x0070, 0x0335]),
+	(0x1d7e, &[0x0075, 0x0335]),
+	(0x1d7f, &[0x028a, 0x0335]),
+	(0x1d83, &[0x0067]),
+	(0x1d8c, &[0x0079]),
+	(0x1d90, &[0x024b]),
+	(0x1d9f, &[0x1d4b]),
+	(0x1da2, &[0x1d4d]),
+	(0x1dba, &[0x18d4]),
+	(0x1dbb, &[0x1646]),
+	(0x1dee, &[0x2dec]),
+	(0x1e43, &[0xab51]),
+	(0x1e9a, &[0x1ea3]),
+	(0x1e9d, &[0x0066]),
+	(0x1e9e, &[0x00df]),
+	(0x1eff, &[0x0079]),
+	(0x1f7d, &[0x1ff4]),
+	(0x1fbd, &[0x0027]),
+	(0x1fbe, &[0x0069]),
+	(0x1fbf, &[0x0027]),
+	(0x1fc0, &[0x007e]),
+	(0x1fef, &[0x0027]),
+	(0x1ff6, &[0x13ef]),
+	(0x1ffd, &[0x0027]),
+	(0x1ffe, &[0x0027]),
+	(0x2000, &[0x0020]),
+	(0x2001, &[0x0020]),
+	(0x2002, &[0x0020]),
+	(0x2003, &[0x0020]),
+	(0x2004, &[0x0020]),
+	(0x2005, &[0x0020]),
+	(0x2006, &[0x0020]),
+	(0x2007, &[0x0020]),
+	(0x2008, &[0x0020]),
+	(0x2009, &[0x0020]),
+	(0x200a, &[0x0020]),
+	(0x2010, &[0x002d]),
+	(0x2011, &[0x002d]),
+	(0x2012, &[0x002d]),
+	(0x2013, &[0x002d]),
+	(0x2014, &[0x30fc]),
+	(0x2015, &[0x30fc]),
+	(0x2016, &[0x006c, 0x006c]),
+	(0x2018, &[0x0027]),
+	(0x2019, &[0x0027]),
+	(0x201a, &[0x002c]),
+	(0x201b, &[0x0027]),
+	(0x201c, &[0x0027, 0x0027]),
+	(0x201d, &[0x0027, 0x0027]),
+	(0x201f, &[0x0027, 0x0027]),
+	(0x2022, &[0x00b7]),
+	(0x2024, &[0x002e]),
+	(0x2025, &[0x002e, 0x002e]),
+	(0x2026, &[0x002e, 0x002e, 0x002e]),
+	(0x2027, &[0x00b7]),
+	(0x2028, &[0x0020]),
+	(0x2029, &[0x0020]),
+	(0x202f, &[0x0020]),
+	(0x2030, &[0x00ba, 0x002f, 0x2080, 0x2080]),
+	(0x2031, &[0x00ba, 0x002f, 0x2080, 0x2080, 0x2080]),
+	(0x2032, &[0x0027]),
+	(0x2033, &[0x0027, 0x0027]),
+	(0x2034, &[0x0027, 0x0027, 0x0027]),
+	(0x2035, &[0x0027]),
+	(0x2036, &[0x0027, 0x0027]),
+	(0x2037, &[0x0027, 0x0027, 0x0027]),
+	(0x2039, &[0x003c]),
+	(0x203a, &[0x003e]),
+	(0x203c, &[0x0021, 0x0021]),
+	(0x203e, &[0x02c9]),
+	(0x2041, &[0x002f]),
+	(0x2043, &[0x002d]),
+	(0x2044, &[0x002f]),
+	(0x2047, &[0x003f, 0x003f]),
+	(0x2048, &[0x003f, 0x0021]),
+	(0x2049, &[0x0021, 0x003f]),
+	(0x204e, &[0x002a]),
+	(0x2052, &[0x00ba, 0x002f, 0x2080]),
+	(0x2053, &[0x007e]),
+	(0x2057, &[0x0027, 0x0027, 0x0027, 0x0027]),
+	(0x205a, &[0x003a]),
+	(0x205d, &[0x2d57]),
+	(0x205e, &[0x2d42]),
+	(0x205f, &[0x0020]),
+	(0x2070, &[0x00ba]),
+	(0x2079, &[0xa770]),
+	(0x20a1, &[0x0043, 0x20eb]),
+	(0x20a4, &[0x00a3]),
+	(0x20a5, &[0x0072, 0x006e, 0x0338]),
+	(0x20a8, &[0x0052, 0x0073]),
+	(0x20a9, &[0x0057, 0x0335]),
+	(0x20ab, &[0x0064, 0x0335, 0x0331]),
+	(0x20ac, &[0xa792]),
+	(0x20ad, &[0x004b, 0x0335]),
+	(0x20ae, &[0x0054, 0x20eb]),
+	(0x20b6, &[0x006c, 0x0074]),
+	(0x20bd, &[0x0554]),
+	(0x20db, &[0x06db]),
+	(0x2100, &[0x0061, 0x002f, 0x0063]),
+	(0x2101, &[0x0061, 0x002f, 0x0073]),
+	(0x2102, &[0x0043]),
+	(0x2103, &[0x00b0, 0x0043]),
+	(0x2105, &[0x0063, 0x002f, 0x006f]),
+	(0x2106, &[0x0063, 0x002f, 0x0075]),
+	(0x2107, &[0x0190]),
+	(0x2108, &[0x042d]),
+	(0x2109, &[0x00b0, 0x0046]),
+	(0x210a, &[0x0067]),
+	(0x210b, &[0x0048]),
+	(0x210c, &[0x0048]),
+	(0x210d, &[0x0048]),
+	(0x210e, &[0x0068]),
+	(0x210f, &[0x0068, 0x0335]),
+	(0x2110, &[0x006c]),
+	(0x2111, &[0x006c]),
+	(0x2112, &[0x004c]),
+	(0x2113, &[0x006c]),
+	(0x2115, &[0x004e]),
+	(0x2116, &[0x004e, 0x006f]),
+	(0x2119, &[0x0050]),
+	(0x211a, &[0x0051]),
+	(0x211b, &[0x0052]),
+	(0x211c, &[0x0052]),
+	(0x211d, &[0x0052]),
+	(0x2121, &[0x0054, 0x0045, 0x004c]),
+	(0x2124, &[0x005a]),
+	(0x2126, &[0x03a9]),
+	(0x2127, &[0x01b1]),
+	(0x2128, &[0x005a]),
+	(0x2129, &[0x027f]),
+	(0x212a, &[0x004b]),
+	(0x212c, &[0x0042]),
+	(0x212d, &[0x0043]),
+	(0x212e, &[0x0065]),
+	(0x212f, &[0x0065]),
+	(0x2130, &[0x0045]),
+	(0x2131, &[0x0046]),
+	(0x2133, &[0x004d]),
+	(0x2134, &[0x006f]),
+	(0x2135, &[0x05d0]),
+	(0x2136, &[0x05d1]),
+	(0x2137, &[0x05d2]),
+	(0x2138, &[0x05d3]),
+	(0x2139, &[0x0069]),
+	(0x213b, &[0x0046, 0x0041, 0x0058]),
+	(0x213c, &[0x03c0]),
+	(0x213d, &[0x0079]),
+	(0x213e, &[0x0393]),
+	(0x213f, &[0x03a0]),
+	(0x2140, &[0x01a9]),
+	(0x2141, &[0xa4e8]),
+	(0x2142, &[0xa4f6]),
+	(0x2143, &[0x16f00]),
+	(0x2145, &[0x0044]),
+	(0x2146, &[0x0064]),
+	(0x2147, &[0x0065]),
+	(0x2148, &[0x0069]),
+	(0x2149, &[0x006a]),
+	(0x2160, &[0x006c]),
+	(0x2161, &[0x006c, 0x006c]),
+	(0x2162, &[0x006c, 0x006c, 0x006c]),
+	(0x2163, &[0x006c, 0x0056]),
+	(0x2164, &[0x0056]),
+	(0x2165, &[0x0056, 0x006c]),
+	(0x2166, &[0x0056, 0x006c, 0x006c]),
+	(0x2167, &[0x0056, 0x006c, 0x006c, 0x006c]),
+	(0x2168, &[0x006c, 0x0058]),
+	(0x2169, &[0x0058]),
+	(0x216a, &[0x0058, 0x006c]),
+	(0x216b, &[0x0058, 0x006c, 0x006c]),
+	(0x216c, &[0x004c]),
+	(0x216d, &[0x0043]),
+	(0x216e, &[0x0044]),
+	(0x216f, &[0x004d]),
+	(0x2170, &[0x0069]),
+	(0x2171, &[0x0069, 0x0069]),
+	(0x2172, &[0x0069, 0x0069, 0x0069]),
+	(0x2173, &[0x0069, 0x0076]),
+	(0x2174, &[0x0076]),
+	(0x2175, &[0x0076, 0x0069]),
+	(0x2176, &[0x0076, 0x0069, 0x0069]),
+	(0x2177, &[0x0076, 0x0069, 0x0069, 0x0069]),
+	(0x2178, &[0x0069, 0x0078]),
+	(0x2179, &[0x0078]),
+	(0x217a, &[0x0078, 0x0069]),
+	(0x217b, &[0x0078, 0x0069, 0x0069]),
+	(0x217c, &[0x006c]),
+	(0x217d, &[0x0063]),
+	(0x217e, &[0x0064]),
+	(0x217f, &[0x0072, 0x006e]),
+	(0x2183, &[0x0186]),
+	(0x2184, &[0x0254]),
+	(0x2191, &[0x16cf]),
+	(0x2195, &[0x16e8]),
+	(0x21b5, &[0x21b2]),
+	(0x21ba, &[0x1f10e]),
+	(0x21be, &[0x16da]),
+	(0x21bf, &[0x16d0]),
+	(0x2200, &[0x2c6f]),
+	(0x2203, &[0x018e]),
+	(0x2206, &[0x0394]),
+	(0x220f, &[0x03a0]),
+	(0x2211, &[0x01a9]),
+	(0x2212, &[0x002d]),
+	(0x2214, &[0x002b, 0x0307]),
+	(0x2215, &[0x002f]),
+	(0x2216, &[0x005c]),
+	(0x2217, &[0x002a]),
+	(0x2218, &[0x00b0]),
+	(0x2219, &[0x00b7]),
+	(0x221e, &[0x006f, 0x006f]),
+	(0x2223, &[0x006c]),
+	(0x2225, &[0x006c, 0x006c]),
+	(0x2228, &[0x0076]),
+	(0x2229, &[0x0548]),
+	(0x222a, &[0x0055]),
+	(0x222b, &[0x0283]),
+	(0x222c, &[0x0283, 0x0283]),
+	(0x222d, &[0x0283, 0x0283, 0x0283]),
+	(0x222f, &[0x222e, 0x222e]),
+	(0x2230, &[0x222e, 0x222e, 0x222e]),
+	(0x2236, &[0x003a]),
+	(0x2238, &[0x002d, 0x0307]),
+	(0x223c, &[0x007e]),
+	(0x2250, &[0x003d, 0x0307]),
+	(0x2251, &[0x003d, 0x0307, 0x0323]),
+	(0x2257, &[0x003d, 0x030a]),
+	(0x2259, &[0x003d, 0x0302]),
+	(0x225a, &[0x003d, 0x0306]),
+	(0x225e, &[0x003d, 0x036b]),
+	(0x2263, &[0x2261]),
+	(0x226a, &[0x003c, 0x003c]),
+	(0x226b, &[0x003e, 0x003e]),
+	(0x2282, &[0x1455]),
+	(0x2283, &[0x1450]),
+	(0x2295, &[0x102a8]),
+	(0x2296, &[0x004f, 0x0335]),
+	(0x2299, &[0x0298]),
+	(0x229d, &[0x004f, 0x0335]),
+	(0x22a4, &[0x0054]),
+	(0x22a5, &[0xa4d5]),
+	(0x22c0, &[0x2227]),
+	(0x22c1, &[0x0076]),
+	(0x22c2, &[0x0548]),
+	(0x22c3, &[0x0055]),
+	(0x22c4, &[0x16dc]),
+	(0x22c5, &[0x00b7]),
+	(0x22c8, &[0x16de]),
+	(0x22d6, &[0x003c, 0x00b7]),
+	(0x22d7, &[0x00b7, 0x003e]),
+	(0x22d8, &[0x003c, 0x003c, 0x003c]),
+	(0x22d9, &[0x003e, 0x003e, 0x003e]),
+	(0x22ee, &[0x2d57]),
+	(0x22ef, &[0x00b7, 0x00b7, 0x00b7]),
+	(0x22f4, &[0xa793]),
+	(0x22ff, &[0x0045]),
+	(0x2300, &[0x2205]),
+	(0x2325, &[0x2324]),
+	(0x2329, &[0x276c]),
+	(0x232a, &[0x276d]),
+	(0x2341, &[0x303c]),
+	(0x2359, &[0x0394, 0x0332]),
+	(0x235a, &[0x16dc, 0x0332]),
+	(0x235c, &[0x00b0, 0x0332]),
+	(0x235f, &[0x229b]),
+	(0x2361, &[0x0054, 0x0308]),
+	(0x2362, &[0x2207, 0x0308]),
+	(0x2363, &[0x22c6, 0x0308]),
+	(0x2364, &[0x00b0, 0x0308]),
+	(0x2365, &[0x0629]),
+	(0x2368, &[0x007e, 0x0308]),
+	(0x2369, &[0x1435]),
+	(0x236b, &[0x2207, 0x0334]),
+	(0x236c, &[0x004f, 0x0335]),
+	(0x2373, &[0x0069]),
+	(0x2374, &[0x0070]),
+	(0x2375, &[0x03c9]),
+	(0x2376, &[0x0061, 0x0332]),
+	(0x2377, &[0xa793, 0x0332]),
+	(0x2378, &[0x0069, 0x0332]),
+	(0x2379, &[0x03c9, 0x0332]),
+	(0x237a, &[0x0061]),
+	(0x237f, &[0x16bd]),
+	(0x239c, &[0x4e28]),
+	(0x239f, &[0x4e28]),
+	(0x23a2, &[0x4e28]),
+	(0x23a5, &[0x4e28]),
+	(0x23aa, &[0x4e28]),
+	(0x23ae, &[0x4e28]),
+	(0x23c1, &[0x2355]),
+	(0x23c2, &[0x234e]),
+	(0x23c3, &[0x234b]),
+	(0x23c6, &[0x236d]),
+	(0x23e8, &[0x2081, 0x2080]),
+	(0x23fc, &[0x23fb]),
+	(0x23fd, &[0x006c]),
+	(0x23fe, &[0x263e]),
+	(0x244a, &[0x005c, 0x005c]),
+	(0x2460, &[0x2780]),
+	(0x2461, &[0x2781]),
+	(0x2462, &[0x2782]),
+	(0x2463, &[0x2783]),
+	(0x2464, &[0x2784]),
+	(0x2465, &[0x2785]),
+	(0x2466, &[0x2786]),
+	(0x2467, &[0x2787]),
+	(0x2468, &[0x2788]),
+	(0x2469, &[0x2789]),
+	(0x2474, &[0x0028, 0x006c, 0x0029]),
+	(0x2475, &[0x0028, 0x0032, 0x0029]),
+	(0x2476, &[0x0028, 0x0033, 0x0029]),
+	(0x2477, &[0x0028, 0x0034, 0x0029]),
+	(0x2478, &[0x0028, 0x0035, 0x0029]),
+	(0x2479, &[0x0028, 0x0036, 0x0029]),
+	(0x247a, &[0x0028, 0x0037, 0x0029]),
+	(0x247b, &[0x0028, 0x0038, 0x0029]),
+	(0x247c, &[0x0028, 0x0039, 0x0029]),
+	(0x247d, &[0x0028, 0x006c, 0x004f, 0x0029]),
+	(0x247e, &[0x0028, 0x006c, 0x006c, 0x0029]),
+	(0x247f, &[0x0028, 0x006c, 0x0032, 0x0029]),
+	(0x2480, &[0x0028, 0x006c, 0x0033, 0x0029]),
+	(0x2481, &[0x0028, 0x006c, 0x0034, 0x0029]),
+	(0x2482, &[0x0028, 0x006c, 0x0035, 0x0029]),
+	(0x2483, &[0x0028, 0x006c, 0x0036, 0x0029]),
+	(0x2484, &[0x0028, 0x006c, 0x0037, 0x0029]),
+	(0x2485, &[0x0028, 0x006c, 0x0038, 0x0029]),
+	(0x2486, &[0x0028, 0x006c, 0x0039, 0x0029]),
+	(0x2487, &[0x0028, 0x0032, 0x004f, 0x0029]),
+	(0x2488, &[0x006c, 0x002e]),
+	(0x2489, &[0x0032, 0x002e]),
+	(0x248a, &[0x0033, 0x002e]),
+	(0x248b, &[0x0034, 0x002e]),
+	(0x248c, &[0x0035, 0x002e]),
+	(0x248d, &[0x0036, 0x002e]),
+	(0x248e, &[0x0037, 0x002e]),
+	(0x248f, &[0x0038, 0x002e]),
+	(0x2490, &[0x0039, 0x002e]),
+	(0x2491, &[0x006c, 0x004f, 0x002e]),
+	(0x2492, &[0x006c, 0x006c, 0x002e]),
+	(0x2493, &[0x006c, 0x0032, 0x002e]),
+	(0x2494, &[0x006c, 0x0033, 0x002e]),
+	(0x2495, &[0x006c, 0x0034, 0x002e]),
+	(0x2496, &[0x006c, 0x0035, 0x002e]),
+	(0x2497, &[0x006c, 0x0036, 0x002e]),
+	(0x2498, &[0x006c, 0x0037, 0x002e]),
+	(0x2499, &[0x006c, 0x0038, 0x002e]),
+	(0x249a, &[0x006c, 0x0039, 0x002e]),
+	(0x249b, &[0x0032, 0x004f, 0x002e]),
+	(0x249c, &[0x0028, 0x0061, 0x0029]),
+	(0x249d, &[0x0028, 0x0062, 0x0029]),
+	(0x249e, &[0x0028, 0x0063, 0x0029]),
+	(0x249f, &[0x0028, 0x0064, 0x0029]),
+	(0x24a0, &[0x0028, 0x0065, 0x0029]),
+	(0x24a1, &[0x0028, 0x0066, 0x0029]),
+	(0x24a2, &[0x0028, 0x0067, 0x0029]),
+	(0x24a3, &[0x0028, 0x0068, 0x0029]),
+	(0x24a4, &[0x0028, 0x0069, 0x0029]),
+	(0x24a5, &[0x0028, 0x006a, 0x0029]),
+	(0x24a6, &[0x0028, 0x006b, 0x0029]),
+	(0x24a7, &[0x0028, 0x006c, 0x0029]),
+	(0x24a8, &[0x0028, 0x0072, 0x006e, 0x0029]),
+	(0x24a9, &[0x0028, 0x006e, 0x0029]),
+	(0x24aa, &[0x0028, 0x006f, 0x0029]),
+	(0x24ab, &[0x0028, 0x0070, 0x0029]),
+	(0x24ac, &[0x0028, 0x0071, 0x0029]),
+	(0x24ad, &[0x0028, 0x0072, 0x0029]),
+	(0x24ae, &[0x0028, 0x0073, 0x0029]),
+	(0x24af, &[0x0028, 0x0074, 0x0029]),
+	(0x24b0, &[0x0028, 0x0075, 0x0029]),
+	(0x24b1, &[0x0028, 0x0076, 0x0029]),
+	(0x24b2, &[0x0028, 0x0077, 0x0029]),
+	(0x24b3, &[0x0028, 0x0078, 0x0029]),
+	(0x24b4, &[0x0028, 0x0079, 0x0029]),
+	(0x24b5, &[0x0028, 0x007a, 0x0029]),
+	(0x24b8, &[0x00a9]),
+	(0x24c5, &[0x2117]),
+	(0x24c7, &[0x00ae]),
+	(0x24db, &[0x24be]),
+	(0x24ea, &[0x1f10d]),
+	(0x2500, &[0x30fc]),
+	(0x2501, &[0x30fc]),
+	(0x2503, &[0x2502]),
+	(0x250f, &[0x250c]),
+	(0x2523, &[0x251c]),
+	(0x2571, &[0x002f]),
+	(0x2573, &[0x0058]),
+	(0x2588, &[0x220e]),
+	(0x2590, &[0x258c]),
+	(0x2594, &[0x02c9]),
+	(0x2597, &[0x2596]),
+	(0x259d, &[0x2598]),
+	(0x25a0, &[0x220e]),
+	(0x25b1, &[0x23e5]),
+	(0x25b3, &[0x0394]),
+	(0x25b7, &[0x22b3]),
+	(0x25b8, &[0x25b6]),
+	(0x25ba, &[0x25b6]),
+	(0x25bd, &[0x102bc]),
+	(0x25c1, &[0x22b2]),
+	(0x25c7, &[0x16dc]),
+	(0x25ca, &[0x16dc]),
+	(0x25cb, &[0x00b0]),
+	(0x25ce, &[0x233e]),
+	(0x25e0, &[0x2312]),
+	(0x25e6, &[0x00b0]),
+	(0x2609, &[0x0298]),
+	(0x2610, &[0x25a1]),
+	(0x2625, &[0x1099e]),
+	(0x2630, &[0x2cb6]),
+	(0x2638, &[0x2388]),
+	(0x264e, &[0x224f]),
+	(0x2662, &[0x16dc]),
+	(0x2669, &[0x1d158, 0x1d165]),
+	(0x266a, &[0x1d158, 0x1d165, 0x1d16e]),
+	(0x26ac, &[0x0970]),
+	(0x2768, &[0x0028]),
+	(0x2769, &[0x0029]),
+	(0x276e, &[0x003c]),
+	(0x276f, &[0x003e]),
+	(0x2772, &[0x0028]),
+	(0x2773, &[0x0029]),
+	(0x2774, &[0x007b]),
+	(0x2775, &[0x007d]),
+	(0x2795, &[0x002b]),
+	(0x2796, &[0x002d]),
+	(0x2797, &[0x00f7]),
+	(0x27c2, &[0xa4d5]),
+	(0x27c8, &[0x005c, 0x1455]),
+	(0x27c9, &[0x1450, 0x002f]),
+	(0x27cb, &[0x002f]),
+	(0x27cd, &[0x005c]),
+	(0x27d9, &[0x0054]),
+	(0x27e8, &[0x276c]),
+	(0x27e9, &[0x276d]),
+	(0x292b, &[0x0078]),
+	(0x292c, &[0x0078]),
+	(0x2963, &[0x16d0, 0x16da]),
+	(0x2965, &[0x21c3, 0x21c2]),
+	(0x296e, &[0x16d0, 0x21c2]),
+	(0x296f, &[0x21c3, 0x16da]),
+	(0x2999, &[0x2d42]),
+	(0x29b0, &[0x2349]),
+	(0x29be, &[0x233e]),
+	(0x29c4, &[0x303c]),
+	(0x29c5, &[0x2342]),
+	(0x29c7, &[0x233b]),
+	(0x29d6, &[0x102c0]),
+	(0x29d9, &[0x299a]),
+	(0x29f4, &[0x003a, 0x2192]),
+	(0x29f5, &[0x005c]),
+	(0x29f6, &[0x002f, 0x0304]),
+	(0x29f8, &[0x002f]),
+	(0x29f9, &[0x005c]),
+	(0x2a00, &[0x0298]),
+	(0x2a01, &[0x102a8]),
+	(0x2a02, &[0x2297]),
+	(0x2a03, &[0x228d]),
+	(0x2a04, &[0x228e]),
+	(0x2a05, &[0x2293]),
+	(0x2a06, &[0x2294]),
+	(0x2a0c, &[0x0283, 0x0283, 0x0283, 0x0283]),
+	(0x2a1d, &[0x16de]),
+	(0x2a20, &[0x003e, 0x003e]),
+	(0x2a21, &[0x16da]),
+	(0x2a22, &[0x002b, 0x030a]),
+	(0x2a23, &[0x002b, 0x0302]),
+	(0x2a24, &[0x002b, 0x0303]),
+	(0x2a25, &[0x002b, 0x0323]),
+	(0x2a26, &[0x002b, 0x0330]),
+	(0x2a27, &[0x002b, 0x2082]),
+	(0x2a29, &[0x002d, 0x0313]),
+	(0x2a2a, &[0x002d, 0x0323]),
+	(0x2a2f, &[0x0078]),
+	(0x2a30, &[0x0078, 0x0307]),
+	(0x2a3d, &[0x2319]),
+	(0x2a3e, &[0x2a1f]),
+	(0x2a3f, &[0x2210]),
+	(0x2a6a, &[0x007e, 0x0307]),
+	(0x2a6e, &[0x003d, 0x20f0]),
+	(0x2a74, &[0x003a, 0x003a, 0x003d]),
+	(0x2a75, &[0x003d, 0x003d]),
+	(0x2a76, &[0x003d, 0x003d, 0x003d]),
+	(0x2aa5, &[0x003e, 0x003c]),
+	(0x2aaa, &[0x15d5]),
+	(0x2aab, &[0x15d2]),
+	(0x2ad7, &[0x1450, 0x1455]),
+	(0x2afb, &[0x002f, 0x002f, 0x002f]),
+	(0x2afd, &[0x002f, 0x002f]),
+	(0x2bec, &[0x219e]),
+	(0x2bed, &[0x219f]),
+	(0x2bee, &[0x21a0]),
+	(0x2bef, &[0x21a1]),
+	(0x2c67, &[0x0048, 0x0329]),
+	(0x2c69, &[0x004b, 0x0329]),
+	(0x2c84, &[0x0393]),
+	(0x2c85, &[0x0072]),
+	(0x2c86, &[0x0394]),
+	(0x2c88, &[0xa792]),
+	(0x2c89, &[0xa793]),
+	(0x2c8e, &[0x0048]),
+	(0x2c92, &[0x006c]),
+	(0x2c94, &[0x004b]),
+	(0x2c95, &[0x0138]),
+	(0x2c96, &[0x03bb]),
+	(0x2c98, &[0x004d]),
+	(0x2c9a, &[0x004e]),
+	(0x2c9e, &[0x004f]),
+	(0x2c9f, &[0x006f]),
+	(0x2ca0, &[0x03a0]),
+	(0x2ca2, &[0x0050]),
+	(0x2ca3, &[0x0070]),
+	(0x2ca4, &[0x0043]),
+	(0x2ca5, &[0x0063]),
+	(0x2ca6, &[0x0054]),
+	(0x2ca8, &[0x0059]),
+	(0x2caa, &[0x03a6]),
+	(0x2cab, &[0x0278]),
+	(0x2cac, &[0x0058]),
+	(0x2cad, &[0x03c7]),
+	(0x2cae, &[0x03a8]),
+	(0x2cb1, &[0x03c9]),
+	(0x2cb4, &[0x003c, 0x00b7]),
+	(0x2cba, &[0x002d]),
+	(0x2cbc, &[0x0428]),
+	(0x2cbd, &[0x0448]),
+	(0x2cc6, &[0x002f]),
+	(0x2cca, &[0x0039]),
+	(0x2ccc, &[0x0033]),
+	(0x2ccd, &[0x021d]),
+	(0x2cd0, &[0x004c]),
+	(0x2cd1, &[0x029f]),
+	(0x2cd2, &[0x0036]),
+	(0x2cdc, &[0x03ec]),
+	(0x2ce4, &[0x03d7]),
+	(0x2ce9, &[0x2627]),
+	(0x2cf9, &[0x005c, 0x005c]),
+	(0x2d31, &[0x004f, 0x0335]),
+	(0x2d37, &[0x0245]),
+	(0x2d38, &[0x0056]),
+	(0x2d39, &[0x0045]),
+	(0x2d3a, &[0x018e]),
+	(0x2d41, &[0x004f, 0x0338]),
+	(0x2d48, &[0x00b7, 0x00b7, 0x00b7]),
+	(0x2d49, &[0x01a9]),
+	(0x2d4f, &[0x006c]),
+	(0x2d51, &[0x0021]),
+	(0x2d54, &[0x004f]),
+	(0x2d55, &[0x0051]),
+	(0x2d59, &[0x0298]),
+	(0x2d5d, &[0x0058]),
+	(0x2d60, &[0x0394]),
+	(0x2d63, &[0x16ef]),
+	(0x2de8, &[0x1ddf]),
+	(0x2dea, &[0x030a]),
+	(0x2ded, &[0x0368]),
+	(0x2def, &[0x036f]),
+	(0x2df6, &[0x0363]),
+	(0x2df7, &[0x0364]),
+	(0x2e1a, &[0x002d, 0x0308]),
+	(0x2e1e, &[0x007e, 0x0307]),
+	(0x2e1f, &[0x007e, 0x0323]),
+	(0x2e26, &[0x1455]),
+	(0x2e27, &[0x1450]),
+	(0x2e28, &[0x0028, 0x0028]),
+	(0x2e29, &[0x0029, 0x0029]),
+	(0x2e2a, &[0x2235]),
+	(0x2e2b, &[0x2234]),
+	(0x2e2c, &[0x2237]),
+	(0x2e2e, &[0x061f]),
+	(0x2e30, &[0x00b0]),
+	(0x2e31, &[0x00b7]),
+	(0x2e32, &[0x060c]),
+	(0x2e35, &[0x061b]),
+	(0x2e39, &[0x1e9f]),
+	(0x2e3d, &[0x2d42]),
+	(0x2e3f, &[0x00b6]),
+	(0x2e40, &[0x003d]),
+	(0x2e82, &[0x4e5b]),
+	(0x2e83, &[0x4e5a]),
+	(0x2e85, &[0x4ebb]),
+	(0x2e89, &[0x5202]),
+	(0x2e8b, &[0x353e]),
+	(0x2e8e, &[0x5140]),
+	(0x2e8f, &[0x5c23]),
+	(0x2e90, &[0x5c22]),
+	(0x2e92, &[0x5df3]),
+	(0x2e93, &[0x5e7a]),
+	(0x2e94, &[0x5f51]),
+	(0x2e96, &[0x5fc4]),
+	(0x2e97, &[0x38fa]),
+	(0x2e98, &[0x624c]),
+	(0x2e99, &[0x6535]),
+	(0x2e9b, &[0x65e1]),
+	(0x2e9e, &[0x6b7a]),
+	(0x2e9f, &[0x6bcd]),
+	(0x2ea0, &[0x6c11]),
+	(0x2ea1, &[0x6c35]),
+	(0x2ea2, &[0x6c3a]),
+	(0x2ea3, &[0x706c]),
+	(0x2ea4, &[0x722b]),
+	(0x2ea6, &[0x4e2c]),
+	(0x2ea8, &[0x72ad]),
+	(0x2eab, &[0x7f52]),
+	(0x2ead, &[0x793b]),
+	(0x2eaf, &[0x7cf9]),
+	(0x2eb1, &[0x7f53]),
+	(0x2eb2, &[0x7f52]),
+	(0x2eb9, &[0x8002]),
+	(0x2eba, &[0x8080]),
+	(0x2ebe, &[0x8279]),
+	(0x2ebf, &[0x8279]),
+	(0x2ec0, &[0x8279]),
+	(0x2ec1, &[0x864e]),
+	(0x2ec2, &[0x8864]),
+	(0x2ec3, &[0x8980]),
+	(0x2ec4, &[0x897f]),
+	(0x2ec5, &[0x89c1]),
+	(0x2ec8, &[0x8ba0]),
+	(0x2ec9, &[0x8d1d]),
+	(0x2ecb, &[0x8f66]),
+	(0x2ecc, &[0x8fb6]),
+	(0x2ecd, &[0x8fb6]),
+	(0x2ecf, &[0x961d]),
+	(0x2ed0, &[0x9485]),
+	(0x2ed1, &[0x9577]),
+	(0x2ed2, &[0x9578]),
+	(0x2ed3, &[0x957f]),
+	(0x2ed4, &[0x95e8]),
+	(0x2ed6, &[0x961d]),
+	(0x2ed8, &[0x9752]),
+	(0x2ed9, &[0x97e6]),
+	(0x2eda, &[0x9875]),
+	(0x2edb, &[0x98ce]),
+	(0x2edc, &[0x98de]),
+	(0x2edd, &[0x98df]),
+	(0x2edf, &[0x98e0]),
+	(0x2ee0, &[0x9963]),
+	(0x2ee2, &[0x9a6c]),
+	(0x2ee4, &[0x9b3c]),
+	(0x2ee5, &[0x9c7c]),
+	(0x2ee8, &[0x9ea6]),
+	(0x2ee9, &[0x9ec4]),
+	(0x2eeb, &[0x6589]),
+	(0x2eec, &[0x9f50]),
+	(0x2eed, &[0x6b6f]),
+	(0x2eee, &[0x9f7f]),
+	(0x2eef, &[0x7adc]),
+	(0x2ef0, &[0x9f99]),
+	(0x2ef2, &[0x4e80]),
+	(0x2ef3, &[0x9f9f]),
+	(0x2f00, &[0x30fc]),
+	(0x2f01, &[0x4e28]),
+	(0x2f02, &[0x005c]),
+	(0x2f03, &[0x002f]),
+	(0x2f04, &[0x4e59]),
+	(0x2f05, &[0x4e85]),
+	(0x2f06, &[0x4e8c]),
+	(0x2f07, &[0x4ea0]),
+	(0x2f08, &[0x4eba]),
+	(0x2f09, &[0x513f]),
+	(0x2f0a, &[0x5165]),
+	(0x2f0b, &[0x516b]),
+	(0x2f0c, &[0x5182]),
+	(0x2f0d, &[0x5196]),
+	(0x2f0e, &[0x51ab]),
+	(0x2f0f, &[0x51e0]),
+	(0x2f10, &[0x51f5]),
+	(0x2f11, &[0x5200]),
+	(0x2f12, &[0x529b]),
+	(0x2f13, &[0x52f9]),
+	(0x2f14, &[0x5315]),
+	(0x2f15, &[0x531a]),
+	(0x2f16, &[0x5338]),
+	(0x2f17, &[0x5341]),
+	(0x2f18, &[0x535c]),
+	(0x2f19, &[0x5369]),
+	(0x2f1a, &[0x5382]),
+	(0x2f1b, &[0x53b6]),
+	(0x2f1c, &[0x53c8]),
+	(0x2f1d, &[0x53e3]),
+	(0x2f1e, &[0x53e3]),
+	(0x2f1f, &[0x571f]),
+	(0x2f20, &[0x571f]),
+	(0x2f21, &[0x5902]),
+	(0x2f22, &[0x590a]),
+	(0x2f23, &[0x5915]),
+	(0x2f24, &[0x5927]),
+	(0x2f25, &[0x5973]),
+	(0x2f26, &[0x5b50]),
+	(0x2f27, &[0x5b80]),
+	(0x2f28, &[0x5bf8]),
+	(0x2f29, &[0x5c0f]),
+	(0x2f2a, &[0x5c22]),
+	(0x2f2b, &[0x5c38]),
+	(0x2f2c, &[0x5c6e]),
+	(0x2f2d, &[0x5c71]),
+	(0x2f2e, &[0x5ddb]),
+	(0x2f2f, &[0x5de5]),
+	(0x2f30, &[0x5df1]),
+	(0x2f31, &[0x5dfe]),
+	(0x2f32, &[0x5e72]),
+	(0x2f33, &[0x5e7a]),
+	(0x2f34, &[0x5e7f]),
+	(0x2f35, &[0x5ef4]),
+	(0x2f36, &[0x5efe]),
+	(0x2f37, &[0x5f0b]),
+	(0x2f38, &[0x5f13]),
+	(0x2f39, &[0x5f50]),
+	(0x2f3a, &[0x5f61]),
+	(0x2f3b, &[0x5f73]),
+	(0x2f3c, &[0x5fc3]),
+	(0x2f3d, &[0x6208]),
+	(0x2f3e, &[0x6236]),
+	(0x2f3f, &[0x624b]),
+	(0x2f40, &[0x652f]),
+	(0x2f41, &[0x6534]),
+	(0x2f42, &[0x6587]),
+	(0x2f43, &[0x6597]),
+	(0x2f44, &[0x65a4]),
+	(0x2f45, &[0x65b9]),
+	(0x2f46, &[0x65e0]),
+	(0x2f47, &[0x65e5]),
+	(0x2f48, &[0x66f0]),
+	(0x2f49, &[0x6708]),
+	(0x2f4a, &[0x6728]),
+	(0x2f4b, &[0x6b20]),
+	(0x2f4c, &[0x6b62]),
+	(0x2f4d, &[0x6b79]),
+	(0x2f4e, &[0x6bb3]),
+	(0x2f4f, &[0x6bcb]),
+	(0x2f50, &[0x6bd4]),
+	(0x2f51, &[0x6bdb]),
+	(0x2f52, &[0x6c0f]),
+	(0x2f53, &[0x6c14]),
+	(0x2f54, &[0x6c34]),
+	(0x2f55, &[0x706b]),
+	(0x2f56, &[0x722a]),
+	(0x2f57, &[0x7236]),
+	(0x2f58, &[0x723b]),
+	(0x2f59, &[0x723f]),
+	(0x2f5a, &[0x7247]),
+	(0x2f5b, &[0x7259]),
+	(0x2f5c, &[0x725b]),
+	(0x2f5d, &[0x72ac]),
+	(0x2f5e, &[0x7384]),
+	(0x2f5f, &[0x7389]),
+	(0x2f60, &[0x74dc]),
+	(0x2f61, &[0x74e6]),
+	(0x2f62, &[0x7518]),
+	(0x2f63, &[0x751f]),
+	(0x2f64, &[0x7528]),
+	(0x2f65, &[0x7530]),
+	(0x2f66, &[0x758b]),
+	(0x2f67, &[0x7592]),
+	(0x2f68, &[0x7676]),
+	(0x2f69, &[0x767d]),
+	(0x2f6a, &[0x76ae]),
+	(0x2f6b, &[0x76bf]),
+	(0x2f6c, &[0x76ee]),
+	(0x2f6d, &[0x77db]),
+	(0x2f6e, &[0x77e2]),
+	(0x2f6f, &[0x77f3]),
+	(0x2f70, &[0x793a]),
+	(0x2f71, &[0x79b8]),
+	(0x2f72, &[0x79be]),
+	(0x2f73, &[0x7a74]),
+	(0x2f74, &[0x7acb]),
+	(0x2f75, &[0x7af9]),
+	(0x2f76, &[0x7c73]),
+	(0x2f77, &[0x7cf8]),
+	(0x2f78, &[0x7f36]),
+	(0x2f79, &[0x7f51]),
+	(0x2f7a, &[0x7f8a]),
+	(0x2f7b, &[0x7fbd]),
+	(0x2f7c, &[0x8001]),
+	(0x2f7d, &[0x800c]),
+	(0x2f7e, &[0x8012]),
+	(0x2f7f, &[0x8033]),
+	(0x2f80, &[0x807f]),
+	(0x2f81, &[0x8089]),
+	(0x2f82, &[0x81e3]),
+	(0x2f83, &[0x81ea]),
+	(0x2f84, &[0x81f3]),
+	(0x2f85, &[0x81fc]),
+	(0x2f86, &[0x820c]),
+	(0x2f87, &[0x821b]),
+	(0x2f88, &[0x821f]),
+	(0x2f89, &[0x826e]),
+	(0x2f8a, &[0x8272]),
+	(0x2f8b, &[0x8278]),
+	(0x2f8c, &[0x864d]),
+	(0x2f8d, &[0x866b]),
+	(0x2f8e, &[0x8840]),
+	(0x2f8f, &[0x884c]),
+	(0x2f90, &[0x8863]),
+	(0x2f91, &[0x897e]),
+	(0x2f92, &[0x898b]),
+	(0x2f93, &[0x89d2]),
+	(0x2f94, &[0x8a00]),
+	(0x2f95, &[0x8c37]),
+	(0x2f96, &[0x8c46]),
+	(0x2f97, &[0x8c55]),
+	(0x2f98, &[0x8c78]),
+	(0x2f99, &[0x8c9d]),
+	(0x2f9a, &[0x8d64]),
+	(0x2f9b, &[0x8d70]),
+	(0x2f9c, &[0x8db3]),
+	(0x2f9d, &[0x8eab]),
+	(0x2f9e, &[0x8eca]),
+	(0x2f9f, &[0x8f9b]),
+	(0x2fa0, &[0x8fb0]),
+	(0x2fa1, &[0x8fb5]),
+	(0x2fa2, &[0x9091]),
+	(0x2fa3, &[0x9149]),
+	(0x2fa4, &[0x91c6]),
+	(0x2fa5, &[0x91cc]),
+	(0x2fa6, &[0x91d1]),
+	(0x2fa7, &[0x9577]),
+	(0x2fa8, &[0x9580]),
+	(0x2fa9, &[0x961c]),
+	(0x2faa, &[0x96b6]),
+	(0x2fab, &[0x96b9]),
+	(0x2fac, &[0x96e8]),
+	(0x2fad, &[0x9751]),
+	(0x2fae, &[0x975e]),
+	(0x2faf, &[0x9762]),
+	(0x2fb0, &[0x9769]),
+	(0x2fb1, &[0x97cb]),
+	(0x2fb2, &[0x97ed]),
+	(0x2fb3, &[0x97f3]),
+	(0x2fb4, &[0x9801]),
+	(0x2fb5, &[0x98a8]),
+	(0x2fb6, &[0x98db]),
+	(0x2fb7, &[0x98df]),
+	(0x2fb8, &[0x9996]),
+	(0x2fb9, &[0x9999]),
+	(0x2fba, &[0x99ac]),
+	(0x2fbb, &[0x9aa8]),
+	(0x2fbc, &[0x9ad8]),
+	(0x2fbd, &[0x9adf]),
+	(0x2fbe, &[0x9b25]),
+	(0x2fbf, &[0x9b2f]),
+	(0x2fc0, &[0x9b32]),
+	(0x2fc1, &[0x9b3c]),
+	(0x2fc2, &[0x9b5a]),
+	(0x2fc3, &[0x9ce5]),
+	(0x2fc4, &[0x9e75]),
+	(0x2fc5, &[0x9e7f]),
+	(0x2fc6, &[0x9ea5]),
+	(0x2fc7, &[0x9ebb]),
+	(0x2fc8, &[0x9ec3]),
+	(0x2fc9, &[0x9ecd]),
+	(0x2fca, &[0x9ed1]),
+	(0x2fcb, &[0x9ef9]),
+	(0x2fcc, &[0x9efd]),
+	(0x2fcd, &[0x9f0e]),
+	(0x2fce, &[0x9f13]),
+	(0x2fcf, &[0x9f20]),
+	(0x2fd0, &[0x9f3b]),
+	(0x2fd1, &[0x9f4a]),
+	(0x2fd2, &[0x9f52]),
+	(0x2fd3, &[0x9f8d]),
+	(0x2fd4, &[0x9f9c]),
+	(0x2fd5, &[0x9fa0]),
+	(0x3002, &[0x02f3]),
+	(0x3003, &[0x0027, 0x0027]),
+	(0x3007, &[0x004f]),
+	(0x3008, &[0x276c]),
+	(0x3009, &[0x276d]),
+	(0x3012, &[0x20b8]),
+	(0x3014, &[0x0028]),
+	(0x3015, &[0x0029]),
+	(0x301a, &[0x27e6]),
+	(0x301b, &[0x27e7]),
+	(0x302c, &[0x0309]),
+	(0x302d, &[0x0325]),
+	(0x3033, &[0x002f]),
+	(0x3036, &[0x20b8]),
+	(0x3038, &[0x5341]),
+	(0x3039, &[0x5344]),
+	(0x303a, &[0x5345]),
+	(0x304f, &[0x276c]),
+	(0x309a, &[0x030a]),
+	(0x309b, &[0xff9e]),
+	(0x309c, &[0xff9f]),
+	(0x30a0, &[0x003d]),
+	(0x30a4, &[0x4ebb]),
+	(0x30a8, &[0x5de5]),
+	(0x30ab, &[0x529b]),
+	(0x30bf, &[0x5915]),
+	(0x30c8, &[0x535c]),
+	(0x30cb, &[0x4e8c]),
+	(0x30ce, &[0x002f]),
+	(0x30cf, &[0x516b]),
+	(0x30d8, &[0x3078]),
+	(0x30ed, &[0x53e3]),
+	(0x30fb, &[0x00b7]),
+	(0x3126, &[0x513f]),
+	(0x3131, &[0x1100]),
+	(0x3132, &[0x1100, 0x1100]),
+	(0x3133, &[0x1100, 0x1109]),
+	(0x3134, &[0x1102]),
+	(0x3135, &[0x1102, 0x110c]),
+	(0x3136, &[0x1102, 0x1112]),
+	(0x3137, &[0x1103]),
+	(0x3138, &[0x1103, 0x1103]),
+	(0x3139, &[0x1105]),
+	(0x313a, &[0x1105, 0x1100]),
+	(0x313b, &[0x1105, 0x1106]),
+	(0x313c, &[0x1105, 0x1107]),
+	(0x313d, &[0x1105, 0x1109]),
+	(0x313e, &[0x1105, 0x1110]),
+	(0x313f, &[0x1105, 0x1111]),
+	(0x3140, &[0x1105, 0x1112]),
+	(0x3141, &[0x1106]),
+	(0x3142, &[0x1107]),
+	(0x3143, &[0x1107, 0x1107]),
+	(0x3144, &[0x1107, 0x1109]),
+	(0x3145, &[0x1109]),
+	(0x3146, &[0x1109, 0x1109]),
+	(0x3147, &[0x110b]),
+	(0x3148, &[0x110c]),
+	(0x3149, &[0x110c, 0x110c]),
+	(0x314a, &[0x110e]),
+	(0x314b, &[0x110f]),
+	(0x314c, &[0x1110]),
+	(0x314d, &[0x1111]),
+	(0x314e, &[0x1112]),
+	(0x314f, &[0x1161]),
+	(0x3150, &[0x1161, 0x4e28]),
+	(0x3151, &[0x1163]),
+	(0x3152, &[0x1163, 0x4e28]),
+	(0x3153, &[0x1165]),
+	(0x3154, &[0x1165, 0x4e28]),
+	(0x3155, &[0x1167]),
+	(0x3156, &[0x1167, 0x4e28]),
+	(0x3157, &[0x1169]),
+	(0x3158, &[0x1169, 0x1161]),
+	(0x3159, &[0x1169, 0x1161, 0x4e28]),
+	(0x315a, &[0x1169, 0x4e28]),
+	(0x315b, &[0x116d]),
+	(0x315c, &[0x116e]),
+	(0x315d, &[0x116e, 0x1165]),
+	(0x315e, &[0x116e, 0x1165, 0x4e28]),
+	(0x315f, &[0x116e, 0x4e28]),
+	(0x3160, &[0x1172]),
+	(0x3161, &[0x30fc]),
+	(0x3162, &[0x30fc, 0x4e28]),
+	(0x3163, &[0x4e28]),
+	(0x3164, &[0x1160]),
+	(0x3165, &[0x1102, 0x1102]),
+	(0x3166, &[0x1102, 0x1103]),
+	(0x3167, &[0x1102, 0x1109]),
+	(0x3168, &[0x1102, 0x1140]),
+	(0x3169, &[0x1105, 0x1100, 0x1109]),
+	(0x316a, &[0x1105, 0x1103]),
+	(0x316b, &[0x1105, 0x1107, 0x1109]),
+	(0x316c, &[0x1105, 0x1140]),
+	(0x316d, &[0x1105, 0x1159]),
+	(0x316e, &[0x1106, 0x1107]),
+	(0x316f, &[0x1106, 0x1109]),
+	(0x3170, &[0x1106, 0x1140]),
+	(0x3171, &[0x1106, 0x110b]),
+	(0x3172, &[0x1107, 0x1100]),
+	(0x3173, &[0x1107, 0x1103]),
+	(0x3174, &[0x1107, 0x1109, 0x1100]),
+	(0x3175, &[0x1107, 0x1109, 0x1103]),
+	(0x3176, &[0x1107, 0x110c]),
+	(0x3177, &[0x1107, 0x1110]),
+	(0x3178, &[0x1107, 0x110b]),
+	(0x3179, &[0x1107, 0x1107, 0x110b]),
+	(0x317a, &[0x1109, 0x1100]),
+	(0x317b, &[0x1109, 0x1102]),
+	(0x317c, &[0x1109, 0x1103]),
+	(0x317d, &[0x1109, 0x1107]),
+	(0x317e, &[0x1109, 0x110c]),
+	(0x317f, &[0x1140]),
+	(0x3180, &[0x110b, 0x110b]),
+	(0x3181, &[0x114c]),
+	(0x3182, &[0x110b, 0x1109]),
+	(0x3183, &[0x110b, 0x1140]),
+	(0x3184, &[0x1111, 0x110b]),
+	(0x3185, &[0x1112, 0x1112]),
+	(0x3186, &[0x1159]),
+	(0x3187, &[0x116d, 0x1163]),
+	(0x3188, &[0x116d, 0x1163, 0x4e28]),
+	(0x3189, &[0x116d, 0x4e28]),
+	(0x318a, &[0x1172, 0x1167]),
+	(0x318b, &[0x1172, 0x1167, 0x4e28]),
+	(0x318c, &[0x1172, 0x4e28]),
+	(0x318d, &[0x119e]),
+	(0x318e, &[0x119e, 0x4e28]),
+	(0x31d0, &[0x30fc]),
+	(0x31d1, &[0x4e28]),
+	(0x31d3, &[0x002f]),
+	(0x31d4, &[0x005c]),
+	(0x31d6, &[0x4e5b]),
+	(0x31da, &[0x4e85]),
+	(0x31db, &[0x276c]),
+	(0x31df, &[0x4e5a]),
+	(0x31e0, &[0x4e59]),
+	(0x3200, &[0x0028, 0x1100, 0x0029]),
+	(0x3201, &[0x0028, 0x1102, 0x0029]),
+	(0x3202, &[0x0028, 0x1103, 0x0029]),
+	(0x3203, &[0x0028, 0x1105, 0x0029]),
+	(0x3204, &[0x0028, 0x1106, 0x0029]),
+	(0x3205, &[0x0028, 0x1107, 0x0029]),
+	(0x3206, &[0x0028, 0x1109, 0x0029]),
+	(0x3207, &[0x0028, 0x110b, 0x0029]),
+	(0x3208, &[0x0028, 0x110c, 0x0029]),
+	(0x3209, &[0x0028, 0x110e, 0x0029]),
+	(0x320a, &[0x0028, 0x110f, 0x0029]),
+	(0x320b, &[0x0028, 0x1110, 0x0029]),
+	(0x320c, &[0x0028, 0x1111, 0x0029]),
+	(0x320d, &[0x0028, 0x1112, 0x0029]),
+	(0x320e, &[0x0028, 0xac00, 0x0029]),
+	(0x320f, &[0x0028, 0xb098, 0x0029]),
+	(0x3210, &[0x0028, 0xb2e4, 0x0029]),
+	(0x3211, &[0x0028, 0xb77c, 0x0029]),
+	(0x3212, &[0x0028, 0xb9c8, 0x0029]),
+	(0x3213, &[0x0028, 0xbc14, 0x0029]),
+	(0x3214, &[0x0028, 0xc0ac, 0x0029]),
+	(0x3215, &[0x0028, 0xc544, 0x0029]),
+	(0x3216, &[0x0028, 0xc790, 0x0029]),
+	(0x3217, &[0x0028, 0xcc28, 0x0029]),
+	(0x3218, &[0x0028, 0xce74, 0x0029]),
+	(0x3219, &[0x0028, 0xd0c0, 0x0029]),
+	(0x321a, &[0x0028, 0xd30c, 0x0029]),
+	(0x321b, &[0x0028, 0xd558, 0x0029]),
+	(0x321c, &[0x0028, 0xc8fc, 0x0029]),
+	(0x321d, &[0x0028, 0xc624, 0xc804, 0x0029]),
+	(0x321e, &[0x0028, 0xc624, 0xd6c4, 0x0029]),
+	(0x3220, &[0x0028, 0x30fc, 0x0029]),
+	(0x3221, &[0x0028, 0x4e8c, 0x0029]),
+	(0x3222, &[0x0028, 0x4e09, 0x0029]),
+	(0x3223, &[0x0028, 0x56db, 0x0029]),
+	(0x3224, &[0x0028, 0x4e94, 0x0029]),
+	(0x3225, &[0x0028, 0x516d, 0x0029]),
+	(0x3226, &[0x0028, 0x4e03, 0x0029]),
+	(0x3227, &[0x0028, 0x516b, 0x0029]),
+	(0x3228, &[0x0028, 0x4e5d, 0x0029]),
+	(0x3229, &[0x0028, 0x5341, 0x0029]),
+	(0x322a, &[0x0028, 0x6708, 0x0029]),
+	(0x322b, &[0x0028, 0x706b, 0x0029]),
+	(0x322c, &[0x0028, 0x6c34, 0x0029]),
+	(0x322d, &[0x0028, 0x6728, 0x0029]),
+	(0x322e, &[0x0028, 0x91d1, 0x0029]),
+	(0x322f, &[0x0028, 0x571f, 0x0029]),
+	(0x3230, &[0x0028, 0x65e5, 0x0029]),
+	(0x3231, &[0x0028, 0x682a, 0x0029]),
+	(0x3232, &[0x0028, 0x6709, 0x0029]),
+	(0x3233, &[0x0028, 0x793e, 0x0029]),
+	(0x3234, &[0x0028, 0x540d, 0x0029]),
+	(0x3235, &[0x0028, 0x7279, 0x0029]),
+	(0x3236, &[0x0028, 0x8ca1, 0x0029]),
+	(0x3237, &[0x0028, 0x795d, 0x0029]),
+	(0x3238, &[0x0028, 0x52b4, 0x0029]),
+	(0x3239, &[0x0028, 0x4ee3, 0x0029]),
+	(0x323a, &[0x0028, 0x547c, 0x0029]),
+	(0x323b, &[0x0028, 0x5b66, 0x0029]),
+	(0x323c, &[0x0028, 0x76e3, 0x0029]),
+	(0x323d, &[0x0028, 0x4f01, 0x0029]),
+	(0x323e, &[0x0028, 0x8cc7, 0x0029]),
+	(0x323f, &[0x0028, 0x5354, 0x0029]),
+	(0x3240, &[0x0028, 0x796d, 0x0029]),
+	(0x3241, &[0x0028, 0x4f11, 0x0029]),
+	(0x3242, &[0x0028, 0x81ea, 0x0029]),
+	(0x3243, &[0x0028, 0x81f3, 0x0029]),
+	(0x32c0, &[0x006c, 0x6708]),
+	(0x32c1, &[0x0032, 0x6708]),
+	(0x32c2, &[0x0033, 0x6708]),
+	(0x32c3, &[0x0034, 0x6708]),
+	(0x32c4, &[0x0035, 0x6708]),
+	(0x32c5, &[0x0036, 0x6708]),
+	(0x32c6, &[0x0037, 0x6708]),
+	(0x32c7, &[0x0038, 0x6708]),
+	(0x32c8, &[0x0039, 0x6708]),
+	(0x32c9, &[0x006c, 0x004f, 0x6708]),
+	(0x32ca, &[0x006c, 0x006c, 0x6708]),
+	(0x32cb, &[0x006c, 0x0032, 0x6708]),
+	(0x3358, &[0x004f, 0x70b9]),
+	(0x3359, &[0x006c, 0x70b9]),
+	(0x335a, &[0x0032, 0x70b9]),
+	(0x335b, &[0x0033, 0x70b9]),
+	(0x335c, &[0x0034, 0x70b9]),
+	(0x335d, &[0x0035, 0x70b9]),
+	(0x335e, &[0x0036, 0x70b9]),
+	(0x335f, &[0x0037, 0x70b9]),
+	(0x3360, &[0x0038, 0x70b9]),
+	(0x3361, &[0x0039, 0x70b9]),
+	(0x3362, &[0x006c, 0x004f, 0x70b9]),
+	(0x3363, &[0x006c, 0x006c, 0x70b9]),
+	(0x3364, &[0x006c, 0x0032, 0x70b9]),
+	(0x3365, &[0x006c, 0x0033, 0x70b9]),
+	(0x3366, &[0x006c, 0x0034, 0x70b9]),
+	(0x3367, &[0x006c, 0x0035, 0x70b9]),
+	(0x3368, &[0x006c, 0x0036, 0x70b9]),
+	(0x3369, &[0x006c, 0x0037, 0x70b9]),
+	(0x336a, &[0x006c, 0x0038, 0x70b9]),
+	(0x336b, &[0x006c, 0x0039, 0x70b9]),
+	(0x336c, &[0x0032, 0x004f, 0x70b9]),
+	(0x336d, &[0x0032, 0x006c, 0x70b9]),
+	(0x336e, &[0x0032, 0x0032, 0x70b9]),
+	(0x336f, &[0x0032, 0x0033, 0x70b9]),
+	(0x3370, &[0x0032, 0x0034, 0x70b9]),
+	(0x33e0, &[0x006c, 0x65e5]),
+	(0x33e1, &[0x0032, 0x65e5]),
+	(0x33e2, &[0x0033, 0x65e5]),
+	(0x33e3, &[0x0034, 0x65e5]),
+	(0x33e4, &[0x0035, 0x65e5]),
+	(0x33e5, &[0x0036, 0x65e5]),
+	(0x33e6, &[0x0037, 0x65e5]),
+	(0x33e7, &[0x0038, 0x65e5]),
+	(0x33e8, &[0x0039, 0x65e5]),
+	(0x33e9, &[0x006c, 0x004f, 0x65e5]),
+	(0x33ea, &[0x006c, 0x006c, 0x65e5]),
+	(0x33eb, &[0x006c, 0x0032, 0x65e5]),
+	(0x33ec, &[0x006c, 0x0033, 0x65e5]),
+	(0x33ed, &[0x006c, 0x0034, 0x65e5]),
+	(0x33ee, &[0x006c, 0x0035, 0x65e5]),
+	(0x33ef, &[0x006c, 0x0036, 0x65e5]),
+	(0x33f0, &[0x006c, 0x0037, 0x65e5]),
+	(0x33f1, &[0x006c, 0x0038, 0x65e5]),
+	(0x33f2, &[0x006c, 0x0039, 0x65e5]),
+	(0x33f3, &[0x0032, 0x004f, 0x65e5]),
+	(0x33f4, &[0x0032, 0x006c, 0x65e5]),
+	(0x33f5, &[0x0032, 0x0032, 0x65e5]),
+	(0x33f6, &[0x0032, 0x0033, 0x65e5]),
+	(0x33f7, &[0x0032, 0x0034, 0x65e5]),
+	(0x33f8, &[0x0032, 0x0035, 0x65e5]),
+	(0x33f9, &[0x0032, 0x0036, 0x65e5]),
+	(0x33fa, &[0x0032, 0x0037, 0x65e5]),
+	(0x33fb, &[0x0032, 0x0038, 0x65e5]),
+	(0x33fc, &[0x0032, 0x0039, 0x65e5]),
+	(0x33fd, &[0x0033, 0x004f, 0x65e5]),
+	(0x33fe, &[0x0033, 0x006c, 0x65e5]),
+	(0x39b3, &[0x363d]),
+	(0x439b, &[0x3588]),
+	(0x4420, &[0x3b3b]),
+	(0x4e00, &[0x30fc]),
+	(0x4e36, &[0x005c]),
+	(0x4e3f, &[0x002f]),
+	(0x5002, &[0x4f75]),
+	(0x503c, &[0x5024]),
+	(0x555f, &[0x5553]),
+	(0x56d7, &[0x53e3]),
+	(0x586b, &[0x5861]),
+	(0x58eb, &[0x571f]),
+	(0x58ff, &[0x58ab]),
+	(0x5b00, &[0x5aaf]),
+	(0x5e32, &[0x5e21]),
+	(0x5e50, &[0x3b3a]),
+	(0x6238, &[0x6236]),
+	(0x6409, &[0x3a41]),
+	(0x6663, &[0x403f]),
+	(0x6669, &[0x665a]),
+	(0x66f6, &[0x3ada]),
+	(0x6726, &[0x4443]),
+	(0x67ff, &[0x676e]),
+	(0x69e9, &[0x3ba3]),
+	(0x6a27, &[0x699d]),
+	(0x6f59, &[0x6e88]),
+	(0x784f, &[0x7814]),
+	(0x7d76, &[0x7d55]),
+	(0x80a6, &[0x670c]),
+	(0x80ca, &[0x6710]),
+	(0x80d0, &[0x670f]),
+	(0x80f6, &[0x3b35]),
+	(0x8101, &[0x6713]),
+	(0x8127, &[0x6718]),
+	(0x8141, &[0x80fc]),
+	(0x81a7, &[0x6723]),
+	(0x853f, &[0x848d]),
+	(0x8641, &[0x8637]),
+	(0x8a1e, &[0x46b6]),
+	(0x8a7d, &[0x8a2e]),
+	(0x8b8f, &[0x8b86]),
+	(0x8c63, &[0x8c5c]),
+	(0x8d86, &[0x8d7f]),
+	(0x8dfa, &[0x8de5]),
+	(0x8e9b, &[0x8e97]),
+	(0x8f27, &[0x8eff]),
+	(0x90de, &[0x90ce]),
+	(0x93ae, &[0x93ad]),
+	(0x96b8, &[0x96b7]),
+	(0x9e43, &[0x9e42]),
+	(0x9ed2, &[0x9ed1]),
+	(0x9fc3, &[0x4039]),
+	(0xa494, &[0xa2cd]),
+	(0xa49c, &[0xa0c0]),
+	(0xa49e, &[0xa04a]),
+	(0xa4a7, &[0xa458]),
+	(0xa4a8, &[0xa132]),
+	(0xa4ac, &[0xa050]),
+	(0xa4b0, &[0xa3c2]),
+	(0xa4ba, &[0xa3bf]),
+	(0xa4be, &[0xa2b1]),
+	(0xa4bf, &[0xa259]),
+	(0xa4c0, &[0xa3ab]),
+	(0xa4c2, &[0xa3b5]),
+	(0xa4d0, &[0x0042]),
+	(0xa4d1, &[0x0050]),
+	(0xa4d2, &[0x0064]),
+	(0xa4d3, &[0x0044]),
+	(0xa4d4, &[0x0054]),
+	(0xa4d6, &[0x0047]),
+	(0xa4d7, &[0x004b]),
+	(0xa4d9, &[0x004a]),
+	(0xa4da, &[0x0043]),
+	(0xa4db, &[0x0186]),
+	(0xa4dc, &[0x005a]),
+	(0xa4dd, &[0x0046]),
+	(0xa4de, &[0x2132]),
+	(0xa4df, &[0x004d]),
+	(0xa4e0, &[0x004e]),
+	(0xa4e1, &[0x004c]),
+	(0xa4e2, &[0x0053]),
+	(0xa4e3, &[0x0052]),
+	(0xa4e5, &[0x0245]),
+	(0xa4e6, &[0x0056]),
+	(0xa4e7, &[0x0048]),
+	(0xa4ea, &[0x0057]),
+	(0xa4eb, &[0x0058]),
+	(0xa4ec, &[0x0059]),
+	(0xa4ed, &[0x1660]),
+	(0xa4ee, &[0x0041]),
+	(0xa4ef, &[0x2c6f]),
+	(0xa4f0, &[0x0045]),
+	(0xa4f1, &[0x018e]),
+	(0xa4f2, &[0x006c]),
+	(0xa4f3, &[0x004f]),
+	(0xa4f4, &[0x0055]),
+	(0xa4f5, &[0x0548]),
+	(0xa4f7, &[0x15e1]),
+	(0xa4f8, &[0x002e]),
+	(0xa4f9, &[0x002c]),
+	(0xa4fa, &[0x002e, 0x002e]),
+	(0xa4fb, &[0x002e, 0x002c]),
+	(0xa4fd, &[0x003a]),
+	(0xa4fe, &[0x002d, 0x002e]),
+	(0xa4ff, &[0x003d]),
+	(0xa60e, &[0x002e]),
+	(0xa644, &[0x0032]),
+	(0xa645, &[0x01a8]),
+	(0xa647, &[0x0069]),
+	(0xa64d, &[0x03c9]),
+	(0xa650, &[0x042a, 0x006c]),
+	(0xa651, &[0x02c9, 0x0062, 0x0069]),
+	(0xa668, &[0x0298]),
+	(0xa66f, &[0x20e9]),
+	(0xa67c, &[0x0306]),
+	(0xa67e, &[0x02c7]),
+	(0xa695, &[0x0068, 0x0314]),
+	(0xa698, &[0x004f, 0x004f]),
+	(0xa699, &[0x006f, 0x006f]),
+	(0xa69a, &[0x102a8]),
+	(0xa6a1, &[0x0418]),
+	(0xa6b0, &[0x16b9]),
+	(0xa6b1, &[0x2c75]),
+	(0xa6cd, &[0x02a1]),
+	(0xa6ce, &[0x0245]),
+	(0xa6db, &[0x03a0]),
+	(0xa6df, &[0x0056]),
+	(0xa6eb, &[0x003f]),
+	(0xa6ef, &[0x0032]),
+	(0xa6f0, &[0x0302]),
+	(0xa6f1, &[0x0304]),
+	(0xa6f4, &[0xa6f3, 0xa6f3]),
+	(0xa714, &[0x02eb]),
+	(0xa716, &[0x02ea]),
+	(0xa728, &[0x0054, 0x0033]),
+	(0xa729, &[0x0074, 0x021d]),
+	(0xa731, &[0x0073]),
+	(0xa732, &[0x0041, 0x0041]),
+	(0xa733, &[0x0061, 0x0061]),
+	(0xa734, &[0x0041, 0x004f]),
+	(0xa735, &[0x0061, 0x006f]),
+	(0xa736, &[0x0041, 0x0055]),
+	(0xa737, &[0x0061, 0x0075]),
+	(0xa738, &[0x0041, 0x0056]),
+	(0xa739, &[0x0061, 0x0076]),
+	(0xa73a, &[0x0041, 0x0056]),
+	(0xa73b, &[0x0061, 0x0076]),
+	(0xa73c, &[0x0041, 0x0059]),
+	(0xa73d, &[0x0061, 0x0079]),
+	(0xa740, &[0x004b, 0x0335]),
+	(0xa74a, &[0x004f, 0x0335]),
+	(0xa74b, &[0x006f, 0x0335]),
+	(0xa74e, &[0x004f, 0x004f]),
+	(0xa74f, &[0x006f, 0x006f]),
+	(0xa75a, &[0x0032]),
+	(0xa761, &[0x0077, 0x0326]),
+	(0xa76a, &[0x0033]),
+	(0xa76b, &[0x021d]),
+	(0xa76e, &[0x0039]),
+	(0xa777, &[0x0074, 0x0066]),
+	(0xa778, &[0x0026]),
+	(0xa77a, &[0xa779]),
+	(0xa789, &[0x003a]),
+	(0xa78c, &[0x0027]),
+	(0xa78f, &[0x00b7]),
+	(0xa795, &[0xa727]),
+	(0xa798, &[0x0046]),
+	(0xa799, &[0x0066]),
+	(0xa79a, &[0x10412]),
+	(0xa79b, &[0x1043a]),
+	(0xa79d, &[0x029a]),
+	(0xa79e, &[0xa4e4]),
+	(0xa79f, &[0x0075]),
+	(0xa7ab, &[0x0033]),
+	(0xa7b1, &[0xa4d5]),
+	(0xa7b2, &[0x004a]),
+	(0xa7b3, &[0x0058]),
+	(0xa7b4, &[0x0042]),
+	(0xa7b5, &[0x00df]),
+	(0xa7b6, &[0xa64c]),
+	(0xa7b7, &[0x03c9]),
+	(0xa7d6, &[0x00df]),
+	(0xa7da, &[0x0245]),
+	(0xa7db, &[0x03bb]),
+	(0xa7dc, &[0x0245, 0x0338]),
+	(0xa7f7, &[0x30fc]),
+	(0xa830, &[0x0964]),
+	(0xa960, &[0x1103, 0x1106]),
+	(0xa961, &[0x1103, 0x1107]),
+	(0xa962, &[0x1103, 0x1109]),
+	(0xa963, &[0x1103, 0x110c]),
+	(0xa964, &[0x1105, 0x1100]),
+	(0xa965, &[0x1105, 0x1100, 0x1100]),
+	(0xa966, &[0x1105, 0x1103]),
+	(0xa967, &[0x1105, 0x1103, 0x1103]),
+	(0xa968, &[0x1105, 0x1106]),
+	(0xa969, &[0x1105, 0x1107]),
+	(0xa96a, &[0x1105, 0x1107, 0x1107]),
+	(0xa96b, &[0x1105, 0x1107, 0x110b]),
+	(0xa96c, &[0x1105, 0x1109]),
+	(0xa96d, &[0x1105, 0x110c]),
+	(0xa96e, &[0x1105, 0x110f]),
+	(0xa96f, &[0x1106, 0x1100]),
+	(0xa970, &[0x1106, 0x1103]),
+	(0xa971, &[0x1106, 0x1109]),
+	(0xa972, &[0x1107, 0x1109, 0x1110]),
+	(0xa973, &[0x1107, 0x110f]),
+	(0xa974, &[0x1107, 0x1112]),
+	(0xa975, &[0x1109, 0x1109, 0x1107]),
+	(0xa976, &[0x110b, 0x1105]),
+	(0xa977, &[0x110b, 0x1112]),
+	(0xa978, &[0x110c, 0x110c, 0x1112]),
+	(0xa979, &[0x1110, 0x1110]),
+	(0xa97a, &[0x1111, 0x1112]),
+	(0xa97b, &[0x1112, 0x1109]),
+	(0xa97c, &[0x1159, 0x1159]),
+	(0xa992, &[0x2c3f]),
+	(0xa9a3, &[0xa99d]),
+	(0xa9c6, &[0xa9d0]),
+	(0xa9cf, &[0x0662]),
+	(0xaa53, &[0xaa01]),
+	(0xaa56, &[0xaa23]),
+	(0xab32, &[0x0065]),
+	(0xab35, &[0x0066]),
+	(0xab3d, &[0x006f]),
+	(0xab3e, &[0x006f, 0x0338]),
+	(0xab3f, &[0x0254, 0x0338]),
+	(0xab41, &[0x01dd, 0x006f, 0x0338]),
+	(0xab42, &[0x01dd, 0x006f, 0x0335]),
+	(0xab47, &[0x0072]),
+	(0xab48, &[0x0072]),
+	(0xab4d, &[0x0283]),
+	(0xab4e, &[0x0075]),
+	(0xab52, &[0x0075]),
+	(0xab53, &[0x03c7]),
+	(0xab55, &[0x03c7]),
+	(0xab5a, &[0x0079]),
+	(0xab60, &[0x0459]),
+	(0xab62, &[0x0254, 0x0065]),
+	(0xab63, &[0x0075, 0x006f]),
+	(0xab70, &[0x1d05]),
+	(0xab71, &[0x0280]),
+	(0xab72, &[0x1d1b]),
+	(0xab74, &[0x006f, 0x031b]),
+	(0xab75, &[0x0069]),
+	(0xab7a, &[0x1d00]),
+	(0xab7b, &[0x1d0a]),
+	(0xab7c, &[0x1d07]),
+	(0xab7e, &[0x0242]),
+	(0xab80, &[0x2c76]),
+	(0xab81, &[0x0072]),
+	(0xab83, &[0x0077]),
+	(0xab87, &[0x028d]),
+	(0xab8b, &[0x029c]),
+	(0xab8e, &[0x006f, 0x0335]),
+	(0xab90, &[0x0262]),
+	(0xab93, &[0x007a]),
+	(0xab9b, &[0xa793]),
+	(0xab9c, &[0x0075, 0x0335]),
+	(0xab9f, &[0x0185]),
+	(0xaba2, &[0x0280]),
+	(0xaba9, &[0x0076]),
+	(0xabaa, &[0x0073]),
+	(0xabae, &[0x029f]),
+	(0xabaf, &[0x0063]),
+	(0xabb2, &[0x1d18]),
+	(0xabb6, &[0x0138]),
+	(0xabbb, &[0x006f, 0x0335]),
+	(0xd7b0, &[0x1169, 0x1167]),
+	(0xd7b1, &[0x1169, 0x1169, 0x4e28]),
+	(0xd7b2, &[0x116d, 0x1161]),
+	(0xd7b3, &[0x116d, 0x1161, 0x4e28]),
+	(0xd7b4, &[0x116d, 0x1165]),
+	(0xd7b5, &[0x116e, 0x1167]),
+	(0xd7b6, &[0x116e, 0x4e28, 0x4e28]),
+	(0xd7b7, &[0x1172, 0x1161, 0x4e28]),
+	(0xd7b8, &[0x1172, 0x1169]),
+	(0xd7b9, &[0x30fc, 0x1161]),
+	(0xd7ba, &[0x30fc, 0x1165]),
+	(0xd7bb, &[0x30fc, 0x1165, 0x4e28]),
+	(0xd7bc, &[0x30fc, 0x1169]),
+	(0xd7bd, &[0x4e28, 0x1163, 0x1169]),
+	(0xd7be, &[0x4e28, 0x1163, 0x4e28]),
+	(0xd7bf, &[0x4e28, 0x1167]),
+	(0xd7c0, &[0x4e28, 0x1167, 0x4e28]),
+	(0xd7c1, &[0x4e28, 0x1169, 0x4e28]),
+	(0xd7c2, &[0x4e28, 0x116d]),
+	(0xd7c3, &[0x4e28, 0x1172]),
+	(0xd7c4, &[0x4e28, 0x4e28]),
+	(0xd7c5, &[0x119e, 0x1161]),
+	(0xd7c6, &[0x119e, 0x1165, 0x4e28]),
+	(0xd7cb, &[0x1102, 0x1105]),
+	(0xd7cc, &[0x1102, 0x110e]),
+	(0xd7cd, &[0x1103, 0x1103]),
+	(0xd7ce, &[0x1103, 0x1103, 0x1107]),
+	(0xd7cf, &[0x1103, 0x1107]),
+	(0xd7d0, &[0x1103, 0x1109]),
+	(0xd7d1, &[0x1103, 0x1109, 0x1100]),
+	(0xd7d2, &[0x1103, 0x110c]),
+	(0xd7d3, &[0x1103, 0x110e]),
+	(0xd7d4, &[0x1103, 0x1110]),
+	(0xd7d5, &[0x1105, 0x1100, 0x1100]),
+	(0xd7d6, &[0x1105, 0x1100, 0x1112]),
+	(0xd7d7, &[0x1105, 0x1105, 0x110f]),
+	(0xd7d8, &[0x1105, 0x1106, 0x1112]),
+	(0xd7d9, &[0x1105, 0x1107, 0x1103]),
+	(0xd7da, &[0x1105, 0x1107, 0x1111]),
+	(0xd7db, &[0x1105, 0x114c]),
+	(0xd7dc, &[0x1105, 0x1159, 0x1112]),
+	(0xd7dd, &[0x1105, 0x110b]),
+	(0xd7de, &[0x1106, 0x1102]),
+	(0xd7df, &[0x1106, 0x1102, 0x1102]),
+	(0xd7e0, &[0x1106, 0x1106]),
+	(0xd7e1, &[0x1106, 0x1107, 0x1109]),
+	(0xd7e2, &[0x1106, 0x110c]),
+	(0xd7e3, &[0x1107, 0x1103]),
+	(0xd7e4, &[0x1107, 0x1105, 0x1111]),
+	(0xd7e5, &[0x1107, 0x1106]),
+	(0xd7e6, &[0x1107, 0x1107]),
+	(0xd7e7, &[0x1107, 0x1109, 0x1103]),
+	(0xd7e8, &[0x1107, 0x110c]),
+	(0xd7e9, &[0x1107, 0x110e]),
+	(0xd7ea, &[0x1109, 0x1106]),
+	(0xd7eb, &[0x1109, 0x1107, 0x110b]),
+	(0xd7ec, &[0x1109, 0x1109, 0x1100]),
+	(0xd7ed, &[0x1109, 0x1109, 0x1103]),
+	(0xd7ee, &[0x1109, 0x1140]),
+	(0xd7ef, &[0x1109, 0x110c]),
+	(0xd7f0, &[0x1109, 0x110e]),
+	(0xd7f1, &[0x1109, 0x1110]),
+	(0xd7f2, &[0x1105, 0x1112]),
+	(0xd7f3, &[0x1140, 0x1107]),
+	(0xd7f4, &[0x1140, 0x1107, 0x110b]),
+	(0xd7f5, &[0x114c, 0x1106]),
+	(0xd7f6, &[0x114c, 0x1112]),
+	(0xd7f7, &[0x110c, 0x1107]),
+	(0xd7f8, &[0x110c, 0x1107, 0x1107]),
+	(0xd7f9, &[0x110c, 0x110c]),
+	(0xd7fa, &[0x1111, 0x1109]),
+	(0xd7fb, &[0x1111, 0x1110]),
+	(0xf900, &[0x8c48]),
+	(0xf901, &[0x66f4]),
+	(0xf902, &[0x8eca]),
+	(0xf903, &[0x8cc8]),
+	(0xf904, &[0x6ed1]),
+	(0xf905, &[0x4e32]),
+	(0xf906, &[0x53e5]),
+	(0xf907, &[0x9f9c]),
+	(0xf908, &[0x9f9c]),
+	(0xf909, &[0x5951]),
+	(0xf90a, &[0x91d1]),
+	(0xf90b, &[0x5587]),
+	(0xf90c, &[0x5948]),
+	(0xf90d, &[0x61f6]),
+	(0xf90e, &[0x7669]),
+	(0xf90f, &[0x7f85]),
+	(0xf910, &[0x863f]),
+	(0xf911, &[0x87ba]),
+	(0xf912, &[0x88f8]),
+	(0xf913, &[0x908f]),
+	(0xf914, &[0x6a02]),
+	(0xf915, &[0x6d1b]),
+	(0xf916, &[0x70d9]),
+	(0xf917, &[0x73de]),
+	(0xf918, &[0x843d]),
+	(0xf919, &[0x916a]),
+	(0xf91a, &[0x99f1]),
+	(0xf91b, &[0x4e82]),
+	(0xf91c, &[0x5375]),
+	(0xf91d, &[0x6b04]),
+	(0xf91e, &[0x721b]),
+	(0xf91f, &[0x862d]),
+	(0xf920, &[0x9e1e]),
+	(0xf921, &[0x5d50]),
+	(0xf922, &[0x6feb]),
+	(0xf923, &[0x85cd]),
+	(0xf924, &[0x8964]),
+	(0xf925, &[0x62c9]),
+	(0xf926, &[0x81d8]),
+	(0xf927, &[0x881f]),
+	(0xf928, &[0x5eca]),
+	(0xf929, &[0x6717]),
+	(0xf92a, &[0x6d6a]),
+	(0xf92b, &[0x72fc]),
+	(0xf92c, &[0x90ce]),
+	(0xf92d, &[0x4f86]),
+	(0xf92e, &[0x51b7]),
+	(0xf92f, &[0x52de]),
+	(0xf930, &[0x64c4]),
+	(0xf931, &[0x6ad3]),
+	(0xf932, &[0x7210]),
+	(0xf933, &[0x76e7]),
+	(0xf934, &[0x8001]),
+	(0xf935, &[0x8606]),
+	(0xf936, &[0x865c]),
+	(0xf937, &[0x8def]),
+	(0xf938, &[0x9732]),
+	(0xf939, &[0x9b6f]),
+	(0xf93a, &[0x9dfa]),
+	(0xf93b, &[0x788c]),
+	(0xf93c, &[0x797f]),
+	(0xf93d, &[0x7da0]),
+	(0xf93e, &[0x83c9]),
+	(0xf93f, &[0x9304]),
+	(0xf940, &[0x9e7f]),
+	(0xf941, &[0x8ad6]),
+	(0xf942, &[0x58df]),
+	(0xf943, &[0x5f04]),
+	(0xf944, &[0x7c60]),
+	(0xf945, &[0x807e]),
+	(0xf946, &[0x7262]),
+	(0xf947, &[0x78ca]),
+	(0xf948, &[0x8cc2]),
+	(0xf949, &[0x96f7]),
+	(0xf94a, &[0x58d8]),
+	(0xf94b, &[0x5c62]),
+	(0xf94c, &[0x6a13]),
+	(0xf94d, &[0x6dda]),
+	(0xf94e, &[0x6f0f]),
+	(0xf94f, &[0x7d2f]),
+	(0xf950, &[0x7e37]),
+	(0xf951, &[0x964b]),
+	(0xf952, &[0x52d2]),
+	(0xf953, &[0x808b]),
+	(0xf954, &[0x51dc]),
+	(0xf955, &[0x51cc]),
+	(0xf956, &[0x7a1c]),
+	(0xf957, &[0x7dbe]),
+	(0xf958, &[0x83f1]),
+	(0xf959, &[0x9675]),
+	(0xf95a, &[0x8b80]),
+	(0xf95b, &[0x62cf]),
+	(0xf95c, &[0x6a02]),
+	(0xf95d, &[0x8afe]),
+	(0xf95e, &[0x4e39]),
+	(0xf95f, &[0x5be7]),
+	(0xf960, &[0x6012]),
+	(0xf961, &[0x7387]),
+	(0xf962, &[0x7570]),
+	(0xf963, &[0x5317]),
+	(0xf964, &[0x78fb]),
+	(0xf965, &[0x4fbf]),
+	(0xf966, &[0x5fa9]),
+	(0xf967, &[0x4e0d]),
+	(0xf968, &[0x6ccc]),
+	(0xf969, &[0x6578]),
+	(0xf96a, &[0x7d22]),
+	(0xf96b, &[0x53c3]),
+	(0xf96c, &[0x585e]),
+	(0xf96d, &[0x7701]),
+	(0xf96e, &[0x8449]),
+	(0xf96f, &[0x8aaa]),
+	(0xf970, &[0x6bba]),
+	(0xf971, &[0x8fb0]),
+	(0xf972, &[0x6c88]),
+	(0xf973, &[0x62fe]),
+	(0xf974, &[0x82e5]),
+	(0xf975, &[0x63a0]),
+	(0xf976, &[0x7565]),
+	(0xf977, &[0x4eae]),
+	(0xf978, &[0x5169]),
+	(0xf979, &[0x51c9]),
+	(0xf97a, &[0x6881]),
+	(0xf97b, &[0x7ce7]),
+	(0xf97c, &[0x826f]),
+	(0xf97d, &[0x8ad2]),
+	(0xf97e, &[0x91cf]),
+	(0xf97f, &[0x52f5]),
+	(0xf980, &[0x5442]),
+	(0xf981, &[0x5973]),
+	(0xf982, &[0x5eec]),
+	(0xf983, &[0x65c5]),
+	(0xf984, &[0x6ffe]),
+	(0xf985, &[0x792a]),
+	(0xf986, &[0x95ad]),
+	(0xf987, &[0x9a6a]),
+	(0xf988, &[0x9e97]),
+	(0xf989, &[0x9ece]),
+	(0xf98a, &[0x529b]),
+	(0xf98b, &[0x66c6]),
+	(0xf98c, &[0x6b77]),
+	(0xf98d, &[0x8f62]),
+	(0xf98e, &[0x5e74]),
+	(0xf98f, &[0x6190]),
+	(0xf990, &[0x6200]),
+	(0xf991, &[0x649a]),
+	(0xf992, &[0x6f23]),
+	(0xf993, &[0x7149]),
+	(0xf994, &[0x7489]),
+	(0xf995, &[0x79ca]),
+	(0xf996, &[0x7df4]),
+	(0xf997, &[0x806f]),
+	(0xf998, &[0x8f26]),
+	(0xf999, &[0x84ee]),
+	(0xf99a, &[0x9023]),
+	(0xf99b, &[0x934a]),
+	(0xf99c, &[0x5217]),
+	(0xf99d, &[0x52a3]),
+	(0xf99e, &[0x54bd]),
+	(0xf99f, &[0x70c8]),
+	(0xf9a0, &[0x88c2]),
+	(0xf9a1, &[0x8aaa]),
+	(0xf9a2, &[0x5ec9]),
+	(0xf9a3, &[0x5ff5]),
+	(0xf9a4, &[0x637b]),
+	(0xf9a5, &[0x6bae]),
+	(0xf9a6, &[0x7c3e]),
+	(0xf9a7, &[0x7375]),
+	(0xf9a8, &[0x4ee4]),
+	(0xf9a9, &[0x56f9]),
+	(0xf9aa, &[0x5be7]),
+	(0xf9ab, &[0x5dba]),
+	(0xf9ac, &[0x601c]),
+	(0xf9ad, &[0x73b2]),
+	(0xf9ae, &[0x7469]),
+	(0xf9af, &[0x7f9a]),
+	(0xf9b0, &[0x8046]),
+	(0xf9b1, &[0x9234]),
+	(0xf9b2, &[0x96f6]),
+	(0xf9b3, &[0x9748]),
+	(0xf9b4, &[0x9818]),
+	(0xf9b5, &[0x4f8b]),
+	(0xf9b6, &[0x79ae]),
+	(0xf9b7, &[0x91b4]),
+	(0xf9b8, &[0x96b7]),
+	(0xf9b9, &[0x60e1]),
+	(0xf9ba, &[0x4e86]),
+	(0xf9bb, &[0x50da]),
+	(0xf9bc, &[0x5bee]),
+	(0xf9bd, &[0x5c3f]),
+	(0xf9be, &[0x6599]),
+	(0xf9bf, &[0x6a02]),
+	(0xf9c0, &[0x71ce]),
+	(0xf9c1, &[0x7642]),
+	(0xf9c2, &[0x84fc]),
+	(0xf9c3, &[0x907c]),
+	(0xf9c4, &[0x9f8d]),
+	(0xf9c5, &[0x6688]),
+	(0xf9c6, &[0x962e]),
+	(0xf9c7, &[0x5289]),
+	(0xf9c8, &[0x677b]),
+	(0xf9c9, &[0x67f3]),
+	(0xf9ca, &[0x6d41]),
+	(0xf9cb, &[0x6e9c]),
+	(0xf9cc, &[0x7409]),
+	(0xf9cd, &[0x7559]),
+	(0xf9ce, &[0x786b]),
+	(0xf9cf, &[0x7d10]),
+	(0xf9d0, &[0x985e]),
+	(0xf9d1, &[0x516d]),
+	(0xf9d2, &[0x622e]),
+	(0xf9d3, &[0x9678]),
+	(0xf9d4, &[0x502b]),
+	(0xf9d5, &[0x5d19]),
+	(0xf9d6, &[0x6dea]),
+	(0xf9d7, &[0x8f2a]),
+	(0xf9d8, &[0x5f8b]),
+	(0xf9d9, &[0x6144]),
+	(0xf9da, &[0x6817]),
+	(0xf9db, &[0x7387]),
+	(0xf9dc, &[0x9686]),
+	(0xf9dd, &[0x5229]),
+	(0xf9de, &[0x540f]),
+	(0xf9df, &[0x5c65]),
+	(0xf9e0, &[0x6613]),
+	(0xf9e1, &[0x674e]),
+	(0xf9e2, &[0x68a8]),
+	(0xf9e3, &[0x6ce5]),
+	(0xf9e4, &[0x7406]),
+	(0xf9e5, &[0x75e2]),
+	(0xf9e6, &[0x7f79]),
+	(0xf9e7, &[0x88cf]),
+	(0xf9e8, &[0x88e1]),
+	(0xf9e9, &[0x91cc]),
+	(0xf9ea, &[0x96e2]),
+	(0xf9eb, &[0x533f]),
+	(0xf9ec, &[0x6eba]),
+	(0xf9ed, &[0x541d]),
+	(0xf9ee, &[0x71d0]),
+	(0xf9ef, &[0x7498]),
+	(0xf9f0, &[0x85fa]),
+	(0xf9f1, &[0x96a3]),
+	(0xf9f2, &[0x9c57]),
+	(0xf9f3, &[0x9e9f]),
+	(0xf9f4, &[0x6797]),
+	(0xf9f5, &[0x6dcb]),
+	(0xf9f6, &[0x81e8]),
+	(0xf9f7, &[0x7acb]),
+	(0xf9f8, &[0x7b20]),
+	(0xf9f9, &[0x7c92]),
+	(0xf9fa, &[0x72c0]),
+	(0xf9fb, &[0x7099]),
+	(0xf9fc, &[0x8b58]),
+	(0xf9fd, &[0x4ec0]),
+	(0xf9fe, &[0x8336]),
+	(0xf9ff, &[0x523a]),
+	(0xfa00, &[0x5207]),
+	(0xfa01, &[0x5ea6]),
+	(0xfa02, &[0x62d3]),
+	(0xfa03, &[0x7cd6]),
+	(0xfa04, &[0x5b85]),
+	(0xfa05, &[0x6d1e]),
+	(0xfa06, &[0x66b4]),
+	(0xfa07, &[0x8f3b]),
+	(0xfa08, &[0x884c]),
+	(0xfa09, &[0x964d]),
+	(0xfa0a, &[0x898b]),
+	(0xfa0b, &[0x5ed3]),
+	(0xfa0c, &[0x5140]),
+	(0xfa0d, &[0x55c0]),
+	(0xfa10, &[0x585a]),
+	(0xfa12, &[0x6674]),
+	(0xfa15, &[0x51de]),
+	(0xfa16, &[0x732a]),
+	(0xfa17, &[0x76ca]),
+	(0xfa18, &[0x793c]),
+	(0xfa19, &[0x795e]),
+	(0xfa1a, &[0x7965]),
+	(0xfa1b, &[0x798f]),
+	(0xfa1c, &[0x9756]),
+	(0xfa1d, &[0x7cbe]),
+	(0xfa1e, &[0x7fbd]),
+	(0xfa20, &[0x8612]),
+	(0xfa22, &[0x8af8]),
+	(0xfa25, &[0x9038]),
+	(0xfa26, &[0x90fd]),
+	(0xfa2a, &[0x98ef]),
+	(0xfa2b, &[0x98fc]),
+	(0xfa2c, &[0x9928]),
+	(0xfa2d, &[0x9db4]),
+	(0xfa2e, &[0x90ce]),
+	(0xfa2f, &[0x96b7]),
+	(0xfa30, &[0x4fae]),
+	(0xfa31, &[0x50e7]),
+	(0xfa32, &[0x514d]),
+	(0xfa33, &[0x52c9]),
+	(0xfa34, &[0x52e4]),
+	(0xfa35, &[0x5351]),
+	(0xfa36, &[0x559d]),
+	(0xfa37, &[0x5606]),
+	(0xfa38, &[0x5668]),
+	(0xfa39, &[0x5840]),
+	(0xfa3a, &[0x58a8]),
+	(0xfa3b, &[0x5c64]),
+	(0xfa3c, &[0x5c6e]),
+	(0xfa3d, &[0x6094]),
+	(0xfa3e, &[0x6168]),
+	(0xfa3f, &[0x618e]),
+	(0xfa40, &[0x61f2]),
+	(0xfa41, &[0x654f]),
+	(0xfa42, &[0x65e2]),
+	(0xfa43, &[0x6691]),
+	(0xfa44, &[0x6885]),
+	(0xfa45, &[0x6d77]),
+	(0xfa46, &[0x6e1a]),
+	(0xfa47, &[0x6f22]),
+	(0xfa48, &[0x716e]),
+	(0xfa49, &[0x722b]),
+	(0xfa4a, &[0x7422]),
+	(0xfa4b, &[0x7891]),
+	(0xfa4c, &[0x793e]),
+	(0xfa4d, &[0x7949]),
+	(0xfa4e, &[0x7948]),
+	(0xfa4f, &[0x7950]),
+	(0xfa50, &[0x7956]),
+	(0xfa51, &[0x795d]),
+	(0xfa52, &[0x798d]),
+	(0xfa53, &[0x798e]),
+	(0xfa54, &[0x7a40]),
+	(0xfa55, &[0x7a81]),
+	(0xfa56, &[0x7bc0]),
+	(0xfa57, &[0x7df4]),
+	(0xfa58, &[0x7e09]),
+	(0xfa59, &[0x7e41]),
+	(0xfa5a, &[0x7f72]),
+	(0xfa5b, &[0x8005]),
+	(0xfa5c, &[0x81ed]),
+	(0xfa5d, &[0x8279]),
+	(0xfa5e, &[0x8279]),
+	(0xfa5f, &[0x8457]),
+	(0xfa60, &[0x8910]),
+	(0xfa61, &[0x8996]),
+	(0xfa62, &[0x8b01]),
+	(0xfa63, &[0x8b39]),
+	(0xfa64, &[0x8cd3]),
+	(0xfa65, &[0x8d08]),
+	(0xfa66, &[0x8fb6]),
+	(0xfa67, &[0x9038]),
+	(0xfa68, &[0x96e3]),
+	(0xfa69, &[0x97ff]),
+	(0xfa6a, &[0x983b]),
+	(0xfa6b, &[0x6075]),
+	(0xfa6c, &[0x242ee]),
+	(0xfa6d, &[0x8218]),
+	(0xfa70, &[0x4e26]),
+	(0xfa71, &[0x51b5]),
+	(0xfa72, &[0x5168]),
+	(0xfa73, &[0x4f80]),
+	(0xfa74, &[0x5145]),
+	(0xfa75, &[0x5180]),
+	(0xfa76, &[0x52c7]),
+	(0xfa77, &[0x52fa]),
+	(0xfa78, &[0x559d]),
+	(0xfa79, &[0x5555]),
+	(0xfa7a, &[0x5599]),
+	(0xfa7b, &[0x55e2]),
+	(0xfa7c, &[0x585a]),
+	(0xfa7d, &[0x58b3]),
+	(0xfa7e, &[0x5944]),
+	(0xfa7f, &[0x5954]),
+	(0xfa80, &[0x5a62]),
+	(0xfa81, &[0x5b28]),
+	(0xfa82, &[0x5ed2]),
+	(0xfa83, &[0x5ed9]),
+	(0xfa84, &[0x5f69]),
+	(0xfa85, &[0x5fad]),
+	(0xfa86, &[0x60d8]),
+	(0xfa87, &[0x614e]),
+	(0xfa88, &[0x6108]),
+	(0xfa89, &[0x618e]),
+	(0xfa8a, &[0x6160]),
+	(0xfa8b, &[0x61f2]),
+	(0xfa8c, &[0x6234]),
+	(0xfa8d, &[0x63c4]),
+	(0xfa8e, &[0x641c]),
+	(0xfa8f, &[0x6452]),
+	(0xfa90, &[0x6556]),
+	(0xfa91, &[0x6674]),
+	(0xfa92, &[0x6717]),
+	(0xfa93, &[0x671b]),
+	(0xfa94, &[0x6756]),
+	(0xfa95, &[0x6b79]),
+	(0xfa96, &[0x6bba]),
+	(0xfa97, &[0x6d41]),
+	(0xfa98, &[0x6edb]),
+	(0xfa99, &[0x6ecb]),
+	(0xfa9a, &[0x6f22]),
+	(0xfa9b, &[0x701e]),
+	(0xfa9c, &[0x716e]),
+	(0xfa9d, &[0x77a7]),
+	(0xfa9e, &[0x7235]),
+	(0xfa9f, &[0x72af]),
+	(0xfaa0, &[0x732a]),
+	(0xfaa1, &[0x7471]),
+	(0xfaa2, &[0x7506]),
+	(0xfaa3, &[0x753b]),
+	(0xfaa4, &[0x761d]),
+	(0xfaa5, &[0x761f]),
+	(0xfaa6, &[0x76ca]),
+	(0xfaa7, &[0x76db]),
+	(0xfaa8, &[0x76f4]),
+	(0xfaa9, &[0x774a]),
+	(0xfaaa, &[0x7740]),
+	(0xfaab, &[0x78cc]),
+	(0xfaac, &[0x7ab1]),
+	(0xfaad, &[0x7bc0]),
+	(0xfaae, &[0x7c7b]),
+	(0xfaaf, &[0x7d5b]),
+	(0xfab0, &[0x7df4]),
+	(0xfab1, &[0x7f3e]),
+	(0xfab2, &[0x8005]),
+	(0xfab3, &[0x8352]),
+	(0xfab4, &[0x83ef]),
+	(0xfab5, &[0x8779]),
+	(0xfab6, &[0x8941]),
+	(0xfab7, &[0x8986]),
+	(0xfab8, &[0x8996]),
+	(0xfab9, &[0x8abf]),
+	(0xfaba, &[0x8af8]),
+	(0xfabb, &[0x8acb]),
+	(0xfabc, &[0x8b01]),
+	(0xfabd, &[0x8afe]),
+	(0xfabe, &[0x8aed]),
+	(0xfabf, &[0x8b39]),
+	(0xfac0, &[0x8b8a]),
+	(0xfac1, &[0x8d08]),
+	(0xfac2, &[0x8f38]),
+	(0xfac3, &[0x9072]),
+	(0xfac4, &[0x9199]),
+	(0xfac5, &[0x9276]),
+	(0xfac6, &[0x967c]),
+	(0xfac7, &[0x96e3]),
+	(0xfac8, &[0x9756]),
+	(0xfac9, &[0x97db]),
+	(0xfaca, &[0x97ff]),
+	(0xfacb, &[0x980b]),
+	(0xfacc, &[0x983b]),
+	(0xfacd, &[0x9b12]),
+	(0xface, &[0x9f9c]),
+	(0xfacf, &[0x2284a]),
+	(0xfad0, &[0x22844]),
+	(0xfad1, &[0x233d5]),
+	(0xfad2, &[0x3b9d]),
+	(0xfad3, &[0x4018]),
+	(0xfad4, &[0x4039]),
+	(0xfad5, &[0x25249]),
+	(0xfad6, &[0x25cd0]),
+	(0xfad7, &[0x27ed3]),
+	(0xfad8, &[0x9f43]),
+	(0xfad9, &[0x9f8e]),
+	(0xfb00, &[0x0066, 0x0066]),
+	(0xfb01, &[0x0066, 0x0069]),
+	(0xfb02, &[0x0066, 0x006c]),
+	(0xfb03, &[0x0066, 0x0066, 0x0069]),
+	(0xfb04, &[0x0066, 0x0066, 0x006c]),
+	(0xfb06, &[0x0073, 0x0074]),
+	(0xfb13, &[0x0574, 0x0576]),
+	(0xfb14, &[0x0574, 0x0565]),
+	(0xfb15, &[0x0574, 0x056b]),
+	(0xfb16, &[0x057e, 0x0576]),
+	(0xfb17, &[0x0574, 0x056d]),
+	(0xfb20, &[0x05e2]),
+	(0xfb21, &[0x05d0]),
+	(0xfb22, &[0x05d3]),
+	(0xfb23, &[0x05d4]),
+	(0xfb24, &[0x05db]),
+	(0xfb25, &[0x05dc]),
+	(0xfb26, &[0x05dd]),
+	(0xfb27, &[0x05e8]),
+	(0xfb28, &[0x05ea]),
+	(0xfb29, &[0x002d, 0x0307]),
+	(0xfb2b, &[0xfb2a]),
+	(0xfb2d, &[0xfb2c]),
+	(0xfb2f, &[0xfb2e]),
+	(0xfb30, &[0xfb2e]),
+	(0xfb39, &[0xfb1d]),
+	(0xfb49, &[0xfb2a]),
+	(0xfb4f, &[0x05d0, 0x05dc]),
+	(0xfb50, &[0x0671]),
+	(0xfb51, &[0x0671]),
+	(0xfb52, &[0x067b]),
+	(0xfb53, &[0x067b]),
+	(0xfb54, &[0x067b]),
+	(0xfb55, &[0x067b]),
+	(0xfb56, &[0x0649, 0x06db]),
+	(0xfb57, &[0x0649, 0x06db]),
+	(0xfb58, &[0x0649, 0x06db]),
+	(0xfb59, &[0x0649, 0x06db]),
+	(0xfb5a, &[0x0680]),
+	(0xfb5b, &[0x0680]),
+	(0xfb5c, &[0x0680]),
+	(0xfb5d, &[0x0680]),
+	(0xfb5e, &[0x067a]),
+	(0xfb5f, &[0x067a]),
+	(0xfb60, &[0x067a]),
+	(0xfb61, &[0x067a]),
+	(0xfb62, &[0x067f]),
+	(0xfb63, &[0x067f]),
+	(0xfb64, &[0x067f]),
+	(0xfb65, &[0x067f]),
+	(0xfb66, &[0x0649, 0x0615]),
+	(0xfb67, &[0x0649, 0x0615]),
+	(0xfb68, &[0x0649, 0x0615]),
+	(0xfb69, &[0x0649, 0x0615]),
+	(0xfb6a, &[0x06a1, 0x06db]),
+	(0xfb6b, &[0x06a1, 0x06db]),
+	(0xfb6c, &[0x06a1, 0x06db]),
+	(0xfb6d, &[0x06a1, 0x06db]),
+	(0xfb6e, &[0x06a6]),
+	(0xfb6f, &[0x06a6]),
+	(0xfb70, &[0x06a6]),
+	(0xfb71, &[0x06a6]),
+	(0xfb72, &[0x0684]),
+	(0xfb73, &[0x0684]),
+	(0xfb74, &[0x0684]),
+	(0xfb75, &[0x0684]),
+	(0xfb76, &[0x0683]),
+	(0xfb77, &[0x0683]),
+	(0xfb78, &[0x0683]),
+	(0xfb79, &[0x0683]),
+	(0xfb7a, &[0x0686]),
+	(0xfb7b, &[0x0686]),
+	(0xfb7c, &[0x0686]),
+	(0xfb7d, &[0x0686]),
+	(0xfb7e, &[0x0687]),
+	(0xfb7f, &[0x0687]),
+	(0xfb80, &[0x0687]),
+	(0xfb81, &[0x0687]),
+	(0xfb82, &[0x068d]),
+	(0xfb83, &[0x068d]),
+	(0xfb84, &[0x068c]),
+	(0xfb85, &[0x068c]),
+	(0xfb86, &[0x062f, 0x06db]),
+	(0xfb87, &[0x062f, 0x06db]),
+	(0xfb88, &[0x062f, 0x0615]),
+	(0xfb89, &[0x062f, 0x0615]),
+	(0xfb8a, &[0x0631, 0x06db]),
+	(0xfb8b, &[0x0631, 0x06db]),
+	(0xfb8c, &[0x0631, 0x0615]),
+	(0xfb8d, &[0x0631, 0x0615]),
+	(0xfb8e, &[0x0643]),
+	(0xfb8f, &[0x0643]),
+	(0xfb90, &[0x0643]),
+	(0xfb91, &[0x0643]),
+	(0xfb92, &[0x06af]),
+	(0xfb93, &[0x06af]),
+	(0xfb94, &[0x06af]),
+	(0xfb95, &[0x06af]),
+	(0xfb96, &[0x06b3]),
+	(0xfb97, &[0x06b3]),
+	(0xfb98, &[0x06b3]),
+	(0xfb99, &[0x06b3]),
+	(0xfb9a, &[0x06b1]),
+	(0xfb9b, &[0x06b1]),
+	(0xfb9c, &[0x06b1]),
+	(0xfb9d, &[0x06b1]),
+	(0xfb9e, &[0x0649]),
+	(0xfb9f, &[0x0649]),
+	(0xfba0, &[0x0649, 0x0615]),
+	(0xfba1, &[0x0649, 0x0615]),
+	(0xfba2, &[0x0649, 0x0615]),
+	(0xfba3, &[0x0649, 0x0615]),
+	(0xfba4, &[0x06c0]),
+	(0xfba5, &[0x06c0]),
+	(0xfba6, &[0x006f]),
+	(0xfba7, &[0x006f]),
+	(0xfba8, &[0x006f]),
+	(0xfba9, &[0x006f]),
+	(0xfbaa, &[0x006f]),
+	(0xfbab, &[0x006f]),
+	(0xfbac, &[0x006f]),
+	(0xfbad, &[0x006f]),
+	(0xfbae, &[0x0649]),
+	(0xfbaf, &[0x0649]),
+	(0xfbb0, &[0x06d3]),
+	(0xfbb1, &[0x06d3]),
+	(0xfbd3, &[0x0643, 0x06db]),
+	(0xfbd4, &[0x0643, 0x06db]),
+	(0xfbd5, &[0x0643, 0x06db]),
+	(0xfbd6, &[0x0643, 0x06db]),
+	(0xfbd7, &[0x0648, 0x0313]),
+	(0xfbd8, &[0x0648, 0x0313]),
+	(0xfbd9, &[0x0648, 0x0306]),
+	(0xfbda, &[0x0648, 0x0306]),
+	(0xfbdb, &[0x0648, 0x0670]),
+	(0xfbdc, &[0x0648, 0x0670]),
+	(0xfbdd, &[0x0648, 0x0313, 0x0674]),
+	(0xfbde, &[0x0648, 0x06db]),
+	(0xfbdf, &[0x0648, 0x06db]),
+	(0xfbe0, &[0x06c5]),
+	(0xfbe1, &[0x06c5]),
+	(0xfbe2, &[0x0648, 0x0302]),
+	(0xfbe3, &[0x0648, 0x0302]),
+	(0xfbe4, &[0x067b]),
+	(0xfbe5, &[0x067b]),
+	(0xfbe6, &[0x067b]),
+	(0xfbe7, &[0x067b]),
+	(0xfbe8, &[0x0649]),
+	(0xfbe9, &[0x0649]),
+	(0xfbea, &[0x0649, 0x0674, 0x006c]),
+	(0xfbeb, &[0x0649, 0x0674, 0x006c]),
+	(0xfbec, &[0x0649, 0x0674, 0x006f]),
+	(0xfbed, &[0x0649, 0x0674, 0x006f]),
+	(0xfbee, &[0x0649, 0x0674, 0x0648]),
+	(0xfbef, &[0x0649, 0x0674, 0x0648]),
+	(0xfbf0, &[0x0649, 0x0674, 0x0648, 0x0313]),
+	(0xfbf1, &[0x0649, 0x0674, 0x0648, 0x0313]),
+	(0xfbf2, &[0x0649, 0x0674, 0x0648, 0x0306]),
+	(0xfbf3, &[0x0649, 0x0674, 0x0648, 0x0306]),
+	(0xfbf4, &[0x0649, 0x0674, 0x0648, 0x0670]),
+	(0xfbf5, &[0x0649, 0x0674, 0x0648, 0x0670]),
+	(0xfbf6, &[0x0649, 0x0674, 0x067b]),
+	(0xfbf7, &[0x0649, 0x0674, 0x067b]),
+	(0xfbf8, &[0x0649, 0x0674, 0x067b]),
+	(0xfbf9, &[0x0649, 0x0674, 0x0649]),
+	(0xfbfa, &[0x0649, 0x0674, 0x0649]),
+	(0xfbfb, &[0x0649, 0x0674, 0x0649]),
+	(0xfbfc, &[0x0649]),
+	(0xfbfd, &[0x0649]),
+	(0xfbfe, &[0x0649]),
+	(0xfbff, &[0x0649]),
+	(0xfc00, &[0x0649, 0x0674, 0x062c]),
+	(0xfc01, &[0x0649, 0x0674, 0x062d]),
+	(0xfc02, &[0x0649, 0x0674, 0x0645]),
+	(0xfc03, &[0x0649, 0x0674, 0x0649]),
+	(0xfc04, &[0x0649, 0x0674, 0x0649]),
+	(0xfc05, &[0x0628, 0x062c]),
+	(0xfc06, &[0x0628, 0x062d]),
+	(0xfc07, &[0x0628, 0x062e]),
+	(0xfc08, &[0x0628, 0x0645]),
+	(0xfc09, &[0x0628, 0x0649]),
+	(0xfc0a, &[0x0628, 0x0649]),
+	(0xfc0b, &[0x062a, 0x062c]),
+	(0xfc0c, &[0x062a, 0x062d]),
+	(0xfc0d, &[0x062a, 0x062e]),
+	(0xfc0e, &[0x062a, 0x0645]),
+	(0xfc0f, &[0x062a, 0x0649]),
+	(0xfc10, &[0x062a, 0x0649]),
+	(0xfc11, &[0x0649, 0x06db, 0x062c]),
+	(0xfc12, &[0x0649, 0x06db, 0x0645]),
+	(0xfc13, &[0x0649, 0x06db, 0x0649]),
+	(0xfc14, &[0x0649, 0x06db, 0x0649]),
+	(0xfc15, &[0x062c, 0x062d]),
+	(0xfc16, &[0x062c, 0x0645]),
+	(0xfc17, &[0x062d, 0x062c]),
+	(0xfc18, &[0x062d, 0x0645]),
+	(0xfc19, &[0x062e, 0x062c]),
+	(0xfc1a, &[0x062e, 0x062d]),
+	(0xfc1b, &[0x062e, 0x0645]),
+	(0xfc1c, &[0x0633, 0x062c]),
+	(0xfc1d, &[0x0633, 0x062d]),
+	(0xfc1e, &[0x0633, 0x062e]),
+	(0xfc1f, &[0x0633, 0x0645]),
+	(0xfc20, &[0x0635, 0x062d]),
+	(0xfc21, &[0x0635, 0x0645]),
+	(0xfc22, &[0x0636, 0x062c]),
+	(0xfc23, &[0x0636, 0x062d]),
+	(0xfc24, &[0x0636, 0x062e]),
+	(0xfc25, &[0x0636, 0x0645]),
+	(0xfc26, &[0x0637, 0x062d]),
+	(0xfc27, &[0x0637, 0x0645]),
+	(0xfc28, &[0x0638, 0x0645]),
+	(0xfc29, &[0x0639, 0x062c]),
+	(0xfc2a, &[0x0639, 0x0645]),
+	(0xfc2b, &[0x063a, 0x062c]),
+	(0xfc2c, &[0x063a, 0x0645]),
+	(0xfc2d, &[0x0641, 0x062c]),
+	(0xfc2e, &[0x0641, 0x062d]),
+	(0xfc2f, &[0x0641, 0x062e]),
+	(0xfc30, &[0x0641, 0x0645]),
+	(0xfc31, &[0x0641, 0x0649]),
+	(0xfc32, &[0x0641, 0x0649]),
+	(0xfc33, &[0x0642, 0x062d]),
+	(0xfc34, &[0x0642, 0x0645]),
+	(0xfc35, &[0x0642, 0x0649]),
+	(0xfc36, &[0x0642, 0x0649]),
+	(0xfc37, &[0x0643, 0x006c]),
+	(0xfc38, &[0x0643, 0x062c]),
+	(0xfc39, &[0x0643, 0x062d]),
+	(0xfc3a, &[0x0643, 0x062e]),
+	(0xfc3b, &[0x0643, 0x0644]),
+	(0xfc3c, &[0x0643, 0x0645]),
+	(0xfc3d, &[0x0643, 0x0649]),
+	(0xfc3e, &[0x0643, 0x0649]),
+	(0xfc3f, &[0x0644, 0x062c]),
+	(0xfc40, &[0x0644, 0x062d]),
+	(0xfc41, &[0x0644, 0x062e]),
+	(0xfc42, &[0x0644, 0x0645]),
+	(0xfc43, &[0x0644, 0x0649]),
+	(0xfc44, &[0x0644, 0x0649]),
+	(0xfc45, &[0x0645, 0x062c]),
+	(0xfc46, &[0x0645, 0x062d]),
+	(0xfc47, &[0x0645, 0x062e]),
+	(0xfc48, &[0x0645, 0x0645]),
+	(0xfc49, &[0x0645, 0x0649]),
+	(0xfc4a, &[0x0645, 0x0649]),
+	(0xfc4b, &[0x0628, 0x062e]),
+	(0xfc4c, &[0x0646, 0x062d]),
+	(0xfc4d, &[0x0646, 0x062e]),
+	(0xfc4e, &[0x0646, 0x0645]),
+	(0xfc4f, &[0x0646, 0x0649]),
+	(0xfc50, &[0x0646, 0x0649]),
+	(0xfc51, &[0x006f, 0x062c]),
+	(0xfc52, &[0x006f, 0x0645]),
+	(0xfc53, &[0x006f, 0x0649]),
+	(0xfc54, &[0x006f, 0x0649]),
+	(0xfc55, &[0x0649, 0x062c]),
+	(0xfc56, &[0x0649, 0x062d]),
+	(0xfc57, &[0x0649, 0x062e]),
+	(0xfc58, &[0x0649, 0x0645]),
+	(0xfc59, &[0x0649, 0x0649]),
+	(0xfc5a, &[0x0649, 0x0649]),
+	(0xfc5b, &[0x0630, 0x0670]),
+	(0xfc5c, &[0x0631, 0x0670]),
+	(0xfc5d, &[0x0649, 0x0670]),
+	(0xfc5e, &[0xfe72, 0x0651]),
+	(0xfc5f, &[0xfe74, 0x0651]),
+	(0xfc60, &[0xfe76, 0x0651]),
+	(0xfc61, &[0xfe78, 0x0651]),
+	(0xfc62, &[0xfe7a, 0x0651]),
+	(0xfc63, &[0xfe7c, 0x0670]),
+	(0xfc64, &[0x0649, 0x0674, 0x0631]),
+	(0xfc65, &[0x0649, 0x0674, 0x0632]),
+	(0xfc66, &[0x0649, 0x0674, 0x0645]),
+	(0xfc67, &[0x0649, 0x0674, 0x0646]),
+	(0xfc68, &[0x0649, 0x0674, 0x0649]),
+	(0xfc69, &[0x0649, 0x0674, 0x0649]),
+	(0xfc6a, &[0x0628, 0x0631]),
+	(0xfc6b, &[0x0628, 0x0632]),
+	(0xfc6c, &[0x0628, 0x0645]),
+	(0xfc6d, &[0x0628, 0x0646]),
+	(0xfc6e, &[0x0628, 0x0649]),
+	(0xfc6f, &[0x0628, 0x0649]),
+	(0xfc70, &[0x062a, 0x0631]),
+	(0xfc71, &[0x062a, 0x0632]),
+	(0xfc72, &[0x062a, 0x0645]),
+	(0xfc73, &[0x062a, 0x0646]),
+	(0xfc74, &[0x062a, 0x0649]),
+	(0xfc75, &[0x062a, 0x0649]),
+	(0xfc76, &[0x0649, 0x06db, 0x0631]),
+	(0xfc77, &[0x0649, 0x06db, 0x0632]),
+	(0xfc78, &[0x0649, 0x06db, 0x0645]),
+	(0xfc79, &[0x0649, 0x06db, 0x0646]),
+	(0xfc7a, &[0x0649, 0x06db, 0x0649]),
+	(0xfc7b, &[0x0649, 0x06db, 0x0649]),
+	(0xfc7c, &[0x0641, 0x0649]),
+	(0xfc7d, &[0x0641, 0x0649]),
+	(0xfc7e, &[0x0642, 0x0649]),
+	(0xfc7f, &[0x0642, 0x0649]),
+	(0xfc80, &[0x0643, 0x006c]),
+	(0xfc81, &[0x0643, 0x0644]),
+	(0xfc82, &[0x0643, 0x0645]),
+	(0xfc83, &[0x0643, 0x0649]),
+	(0xfc84, &[0x0643, 0x0649]),
+	(0xfc85, &[0x0644, 0x0645]),
+	(0xfc86, &[0x0644, 0x0649]),
+	(0xfc87, &[0x0644, 0x0649]),
+	(0xfc88, &[0x0645, 0x006c]),
+	(0xfc89, &[0x0645, 0x0645]),
+	(0xfc8a, &[0x0646, 0x0631]),
+	(0xfc8b, &[0x0646, 0x0632]),
+	(0xfc8c, &[0x0646, 0x0645]),
+	(0xfc8d, &[0x0646, 0x0646]),
+	(0xfc8e, &[0x0646, 0x0649]),
+	(0xfc8f, &[0x0646, 0x0649]),
+	(0xfc90, &[0x0649, 0x0670]),
+	(0xfc91, &[0x0649, 0x0631]),
+	(0xfc92, &[0x0649, 0x0632]),
+	(0xfc93, &[0x0649, 0x0645]),
+	(0xfc94, &[0x0649, 0x0646]),
+	(0xfc95, &[0x0649, 0x0649]),
+	(0xfc96, &[0x0649, 0x0649]),
+	(0xfc97, &[0x0649, 0x0674, 0x062c]),
+	(0xfc98, &[0x0649, 0x0674, 0x062d]),
+	(0xfc99, &[0x0649, 0x0674, 0x062e]),
+	(0xfc9a, &[0x0649, 0x0674, 0x0645]),
+	(0xfc9b, &[0x0649, 0x0674, 0x006f]),
+	(0xfc9c, &[0x0628, 0x062c]),
+	(0xfc9d, &[0x0628, 0x062d]),
+	(0xfc9e, &[0x0628, 0x062e]),
+	(0xfc9f, &[0x0628, 0x0645]),
+	(0xfca0, &[0x0628, 0x006f]),
+	(0xfca1, &[0x062a, 0x062c]),
+	(0xfca2, &[0x062a, 0x062d]),
+	(0xfca3, &[0x062a, 0x062e]),
+	(0xfca4, &[0x062a, 0x0645]),
+	(0xfca5, &[0x062a, 0x006f]),
+	(0xfca6, &[0x0649, 0x06db, 0x0645]),
+	(0xfca7, &[0x062c, 0x062d]),
+	(0xfca8, &[0x062c, 0x0645]),
+	(0xfca9, &[0x062d, 0x062c]),
+	(0xfcaa, &[0x062d, 0x0645]),
+	(0xfcab, &[0x062e, 0x062c]),
+	(0xfcac, &[0x062e, 0x0645]),
+	(0xfcad, &[0x0633, 0x062c]),
+	(0xfcae, &[0x0633, 0x062d]),
+	(0xfcaf, &[0x0633, 0x062e]),
+	(0xfcb0, &[0x0633, 0x0645]),
+	(0xfcb1, &[0x0635, 0x062d]),
+	(0xfcb2, &[0x0635, 0x062e]),
+	(0xfcb3, &[0x0635, 0x0645]),
+	(0xfcb4, &[0x0636, 0x062c]),
+	(0xfcb5, &[0x0636, 0x062d]),
+	(0xfcb6, &[0x0636, 0x062e]),
+	(0xfcb7, &[0x0636, 0x0645]),
+	(0xfcb8, &[0x0637, 0x062d]),
+	(0xfcb9, &[0x0638, 0x0645]),
+	(0xfcba, &[0x0639, 0x062c]),
+	(0xfcbb, &[0x0639, 0x0645]),
+	(0xfcbc, &[0x063a, 0x062c]),
+	(0xfcbd, &[0x063a, 0x0645]),
+	(0xfcbe, &[0x0641, 0x062c]),
+	(0xfcbf, &[0x0641, 0x062d]),
+	(0xfcc0, &[0x0641, 0x062e]),
+	(0xfcc1, &[0x0641, 0x0645]),
+	(0xfcc2, &[0x0642, 0x062d]),
+	(0xfcc3, &[0x0642, 0x0645]),
+	(0xfcc4, &[0x0643, 0x062c]),
+	(0xfcc5, &[0x0643, 0x062d]),
+	(0xfcc6, &[0x0643, 0x062e]),
+	(0xfcc7, &[0x0643, 0x0644]),
+	(0xfcc8, &[0x0643, 0x0645]),
+	(0xfcc9, &[0x0644, 0x062c]),
+	(0xfcca, &[0x0644, 0x062d]),
+	(0xfccb, &[0x0644, 0x062e]),
+	(0xfccc, &[0x0644, 0x0645]),
+	(0xfccd, &[0x0644, 0x006f]),
+	(0xfcce, &[0x0645, 0x062c]),
+	(0xfccf, &[0x0645, 0x062d]),
+	(0xfcd0, &[0x0645, 0x062e]),
+	(0xfcd1, &[0x0645, 0x0645]),
+	(0xfcd2, &[0x0628, 0x062e]),
+	(0xfcd3, &[0x0646, 0x062d]),
+	(0xfcd4, &[0x0646, 0x062e]),
+	(0xfcd5, &[0x0646, 0x0645]),
+	(0xfcd6, &[0x0646, 0x006f]),
+	(0xfcd7, &[0x006f, 0x062c]),
+	(0xfcd8, &[0x006f, 0x0645]),
+	(0xfcd9, &[0x006f, 0x0670]),
+	(0xfcda, &[0x0649, 0x062c]),
+	(0xfcdb, &[0x0649, 0x062d]),
+	(0xfcdc, &[0x0649, 0x062e]),
+	(0xfcdd, &[0x0649, 0x0645]),
+	(0xfcde, &[0x0649, 0x006f]),
+	(0xfcdf, &[0x0649, 0x0674, 0x0645]),
+	(0xfce0, &[0x0649, 0x0674, 0x006f]),
+	(0xfce1, &[0x0628, 0x0645]),
+	(0xfce2, &[0x0628, 0x006f]),
+	(0xfce3, &[0x062a, 0x0645]),
+	(0xfce4, &[0x062a, 0x006f]),
+	(0xfce5, &[0x0649, 0x06db, 0x0645]),
+	(0xfce6, &[0x0649, 0x06db, 0x006f]),
+	(0xfce7, &[0x0633, 0x0645]),
+	(0xfce8, &[0x0633, 0x006f]),
+	(0xfce9, &[0x0633, 0x06db, 0x0645]),
+	(0xfcea, &[0x0633, 0x06db, 0x006f]),
+	(0xfceb, &[0x0643, 0x0644]),
+	(0xfcec, &[0x0643, 0x0645]),
+	(0xfced, &[0x0644, 0x0645]),
+	(0xfcee, &[0x0646, 0x0645]),
+	(0xfcef, &[0x0646, 0x006f]),
+	(0xfcf0, &[0x0649, 0x0645]),
+	(0xfcf1, &[0x0649, 0x006f]),
+	(0xfcf2, &[0xfe77, 0x0651]),
+	(0xfcf3, &[0xfe79, 0x0651]),
+	(0xfcf4, &[0xfe7b, 0x0651]),
+	(0xfcf5, &[0x0637, 0x0649]),
+	(0xfcf6, &[0x0637, 0x0649]),
+	(0xfcf7, &[0x0639, 0x0649]),
+	(0xfcf8, &[0x0639, 0x0649]),
+	(0xfcf9, &[0x063a, 0x0649]),
+	(0xfcfa, &[0x063a, 0x0649]),
+	(0xfcfb, &[0x0633, 0x0649]),
+	(0xfcfc, &[0x0633, 0x0649]),
+	(0xfcfd, &[0x0633, 0x06db, 0x0649]),
+	(0xfcfe, &[0x0633, 0x06db, 0x0649]),
+	(0xfcff, &[0x062d, 0x0649]),
+	(0xfd00, &[0x062d, 0x0649]),
+	(0xfd01, &[0x062c, 0x0649]),
+	(0xfd02, &[0x062c, 0x0649]),
+	(0xfd03, &[0x062e, 0x0649]),
+	(0xfd04, &[0x062e, 0x0649]),
+	(0xfd05, &[0x0635, 0x0649]),
+	(0xfd06, &[0x0635, 0x0649]),
+	(0xfd07, &[0x0636, 0x0649]),
+	(0xfd08, &[0x0636, 0x0649]),
+	(0xfd09, &[0x0633, 0x06db, 0x062c]),
+	(0xfd0a, &[0x0633, 0x06db, 0x062d]),
+	(0xfd0b, &[0x0633, 0x06db, 0x062e]),
+	(0xfd0c, &[0x0633, 0x06db, 0x0645]),
+	(0xfd0d, &[0x0633, 0x06db, 0x0631]),
+	(0xfd0e, &[0x0633, 0x0631]),
+	(0xfd0f, &[0x0635, 0x0631]),
+	(0xfd10, &[0x0636, 0x0631]),
+	(0xfd11, &[0x0637, 0x0649]),
+	(0xfd12, &[0x0637, 0x0649]),
+	(0xfd13, &[0x0639, 0x0649]),
+	(0xfd14, &[0x0639, 0x0649]),
+	(0xfd15, &[0x063a, 0x0649]),
+	(0xfd16, &[0x063a, 0x0649]),
+	(0xfd17, &[0x0633, 0x0649]),
+	(0xfd18, &[0x0633, 0x0649]),
+	(0xfd19, &[0x0633, 0x06db, 0x0649]),
+	(0xfd1a, &[0x0633, 0x06db, 0x0649]),
+	(0xfd1b, &[0x062d, 0x0649]),
+	(0xfd1c, &[0x062d, 0x0649]),
+	(0xfd1d, &[0x062c, 0x0649]),
+	(0xfd1e, &[0x062c, 0x0649]),
+	(0xfd1f, &[0x062e, 0x0649]),
+	(0xfd20, &[0x062e, 0x0649]),
+	(0xfd21, &[0x0635, 0x0649]),
+	(0xfd22, &[0x0635, 0x0649]),
+	(0xfd23, &[0x0636, 0x0649]),
+	(0xfd24, &[0x0636, 0x0649]),
+	(0xfd25, &[0x0633, 0x06db, 0x062c]),
+	(0xfd26, &[0x0633, 0x06db, 0x062d]),
+	(0xfd27, &[0x0633, 0x06db, 0x062e]),
+	(0xfd28, &[0x0633, 0x06db, 0x0645]),
+	(0xfd29, &[0x0633, 0x06db, 0x0631]),
+	(0xfd2a, &[0x0633, 0x0631]),
+	(0xfd2b, &[0x0635, 0x0631]),
+	(0xfd2c, &[0x0636, 0x0631]),
+	(0xfd2d, &[0x0633, 0x06db, 0x062c]),
+	(0xfd2e, &[0x0633, 0x06db, 0x062d]),
+	(0xfd2f, &[0x0633, 0x06db, 0x062e]),
+	(0xfd30, &[0x0633, 0x06db, 0x0645]),
+	(0xfd31, &[0x0633, 0x006f]),
+	(0xfd32, &[0x0633, 0x06db, 0x006f]),
+	(0xfd33, &[0x0637, 0x0645]),
+	(0xfd34, &[0x0633, 0x062c]),
+	(0xfd35, &[0x0633, 0x062d]),
+	(0xfd36, &[0x0633, 0x062e]),
+	(0xfd37, &[0x0633, 0x06db, 0x062c]),
+	(0xfd38, &[0x0633, 0x06db, 0x062d]),
+	(0xfd39, &[0x0633, 0x06db, 0x062e]),
+	(0xfd3a, &[0x0637, 0x0645]),
+	(0xfd3b, &[0x0638, 0x0645]),
+	(0xfd3c, &[0x006c, 0x030b]),
+	(0xfd3d, &[0x006c, 0x030b]),
+	(0xfd3e, &[0x0028]),
+	(0xfd3f, &[0x0029]),
+	(0xfd50, &[0x062a, 0x062c, 0x0645]),
+	(0xfd51, &[0x062a, 0x062d, 0x062c]),
+	(0xfd52, &[0x062a, 0x062d, 0x062c]),
+	(0xfd53, &[0x062a, 0x062d, 0x0645]),
+	(0xfd54, &[0x062a, 0x062e, 0x0645]),
+	(0xfd55, &[0x062a, 0x0645, 0x062c]),
+	(0xfd56, &[0x062a, 0x0645, 0x062d]),
+	(0xfd57, &[0x062a, 0x0645, 0x062e]),
+	(0xfd58, &[0x062c, 0x0645, 0x062d]),
+	(0xfd59, &[0x062c, 0x0645, 0x062d]),
+	(0xfd5a, &[0x062d, 0x0645, 0x0649]),
+	(0xfd5b, &[0x062d, 0x0645, 0x0649]),
+	(0xfd5c, &[0x0633, 0x062d, 0x062c]),
+	(0xfd5d, &[0x0633, 0x062c, 0x062d]),
+	(0xfd5e, &[0x0633, 0x062c, 0x0649]),
+	(0xfd5f, &[0x0633, 0x0645, 0x062d]),
+	(0xfd60, &[0x0633, 0x0645, 0x062d]),
+	(0xfd61, &[0x0633, 0x0645, 0x062c]),
+	(0xfd62, &[0x0633, 0x0645, 0x0645]),
+	(0xfd63, &[0x0633, 0x0645, 0x0645]),
+	(0xfd64, &[0x0635, 0x062d, 0x062d]),
+	(0xfd65, &[0x0635, 0x062d, 0x062d]),
+	(0xfd66, &[0x0635, 0x0645, 0x0645]),
+	(0xfd67, &[0x0633, 0x06db, 0x062d, 0x0645]),
+	(0xfd68, &[0x0633, 0x06db, 0x062d, 0x0645]),
+	(0xfd69, &[0x0633, 0x06db, 0x062c, 0x0649]),
+	(0xfd6a, &[0x0633, 0x06db, 0x0645, 0x062e]),
+	(0xfd6b, &[0x0633, 0x06db, 0x0645, 0x062e]),
+	(0xfd6c, &[0x0633, 0x06db, 0x0645, 0x0645]),
+	(0xfd6d, &[0x0633, 0x06db, 0x0645, 0x0645]),
+	(0xfd6e, &[0x0636, 0x062d, 0x0649]),
+	(0xfd6f, &[0x0636, 0x062e, 0x0645]),
+	(0xfd70, &[0x0636, 0x062e, 0x0645]),
+	(0xfd71, &[0x0637, 0x0645, 0x062d]),
+	(0xfd72, &[0x0637, 0x0645, 0x062d]),
+	(0xfd73, &[0x0637, 0x0645, 0x0645]),
+	(0xfd74, &[0x0637, 0x0645, 0x0649]),
+	(0xfd75, &[0x0639, 0x062c, 0x0645]),
+	(0xfd76, &[0x0639, 0x0645, 0x0645]),
+	(0xfd77, &[0x0639, 0x0645, 0x0645]),
+	(0xfd78, &[0x0639, 0x0645, 0x0649]),
+	(0xfd79, &[0x063a, 0x0645, 0x0645]),
+	(0xfd7a, &[0x063a, 0x0645, 0x0649]),
+	(0xfd7b, &[0x063a, 0x0645, 0x0649]),
+	(0xfd7c, &[0x0641, 0x062e, 0x0645]),
+	(0xfd7d, &[0x0641, 0x062e, 0x0645]),
+	(0xfd7e, &[0x0642, 0x0645, 0x062d]),
+	(0xfd7f, &[0x0642, 0x0645, 0x0645]),
+	(0xfd80, &[0x0644, 0x062d, 0x0645]),
+	(0xfd81, &[0x0644, 0x062d, 0x0649]),
+	(0xfd82, &[0x0644, 0x062d, 0x0649]),
+	(0xfd83, &[0x0644, 0x062c, 0x062c]),
+	(0xfd84, &[0x0644, 0x062c, 0x062c]),
+	(0xfd85, &[0x0644, 0x062e, 0x0645]),
+	(0xfd86, &[0x0644, 0x062e, 0x0645]),
+	(0xfd87, &[0x0644, 0x0645, 0x062d]),
+	(0xfd88, &[0x0644, 0x0645, 0x062d]),
+	(0xfd89, &[0x0645, 0x062d, 0x062c]),
+	(0xfd8a, &[0x0645, 0x062d, 0x0645]),
+	(0xfd8b, &[0x0645, 0x062d, 0x0649]),
+	(0xfd8c, &[0x0645, 0x062c, 0x062d]),
+	(0xfd8d, &[0x0645, 0x062c, 0x0645]),
+	(0xfd8e, &[0x0645, 0x062e, 0x062c]),
+	(0xfd8f, &[0x0645, 0x062e, 0x0645]),
+	(0xfd92, &[0x0645, 0x062c, 0x062e]),
+	(0xfd93, &[0x006f, 0x0645, 0x062c]),
+	(0xfd94, &[0x006f, 0x0645, 0x0645]),
+	(0xfd95, &[0x0646, 0x062d, 0x0645]),
+	(0xfd96, &[0x0646, 0x062d, 0x0649]),
+	(0xfd97, &[0x0646, 0x062c, 0x0645]),
+	(0xfd98, &[0x0646, 0x062c, 0x0645]),
+	(0xfd99, &[0x0646, 0x062c, 0x0649]),
+	(0xfd9a, &[0x0646, 0x0645, 0x0649]),
+	(0xfd9b, &[0x0646, 0x0645, 0x0649]),
+	(0xfd9c, &[0x0649, 0x0645, 0x0645]),
+	(0xfd9d, &[0x0649, 0x0645, 0x0645]),
+	(0xfd9e, &[0x0628, 0x062e, 0x0649]),
+	(0xfd9f, &[0x062a, 0x062c, 0x0649]),
+	(0xfda0, &[0x062a, 0x062c, 0x0649]),
+	(0xfda1, &[0x062a, 0x062e, 0x0649]),
+	(0xfda2, &[0x062a, 0x062e, 0x0649]),
+	(0xfda3, &[0x062a, 0x0645, 0x0649]),
+	(0xfda4, &[0x062a, 0x0645, 0x0649]),
+	(0xfda5, &[0x062c, 0x0645, 0x0649]),
+	(0xfda6, &[0x062c, 0x062d, 0x0649]),
+	(0xfda7, &[0x062c, 0x0645, 0x0649]),
+	(0xfda8, &[0x0633, 0x062e, 0x0649]),
+	(0xfda9, &[0x0635, 0x062d, 0x0649]),
+	(0xfdaa, &[0x0633, 0x06db, 0x062d, 0x0649]),
+	(0xfdab, &[0x0636, 0x062d, 0x0649]),
+	(0xfdac, &[0x0644, 0x062c, 0x0649]),
+	(0xfdad, &[0x0644, 0x0645, 0x0649]),
+	(0xfdae, &[0x0649, 0x062d, 0x0649]),
+	(0xfdaf, &[0x0649, 0x062c, 0x0649]),
+	(0xfdb0, &[0x0649, 0x0645, 0x0649]),
+	(0xfdb1, &[0x0645, 0x0645, 0x0649]),
+	(0xfdb2, &[0x0642, 0x0645, 0x0649]),
+	(0xfdb3, &[0x0646, 0x062d, 0x0649]),
+	(0xfdb4, &[0x0642, 0x0645, 0x062d]),
+	(0xfdb5, &[0x0644, 0x062d, 0x0645]),
+	(0xfdb6, &[0x0639, 0x0645, 0x0649]),
+	(0xfdb7, &[0x0643, 0x0645, 0x0649]),
+	(0xfdb8, &[0x0646, 0x062c, 0x062d]),
+	(0xfdb9, &[0x0645, 0x062e, 0x0649]),
+	(0xfdba, &[0x0644, 0x062c, 0x0645]),
+	(0xfdbb, &[0x0643, 0x0645, 0x0645]),
+	(0xfdbc, &[0x0644, 0x062c, 0x0645]),
+	(0xfdbd, &[0x0646, 0x062c, 0x062d]),
+	(0xfdbe, &[0x062c, 0x062d, 0x0649]),
+	(0xfdbf, &[0x062d, 0x062c, 0x0649]),
+	(0xfdc0, &[0x0645, 0x062c, 0x0649]),
+	(0xfdc1, &[0x0641, 0x0645, 0x0649]),
+	(0xfdc2, &[0x0628, 0x062d, 0x0649]),
+	(0xfdc3, &[0x0643, 0x0645, 0x0645]),
+	(0xfdc4, &[0x0639, 0x062c, 0x0645]),
+	(0xfdc5, &[0x0635, 0x0645, 0x0645]),
+	(0xfdc6, &[0x0633, 0x062e, 0x0649]),
+	(0xfdc7, &[0x0646, 0x062c, 0x0649]),
+	(0xfdf0, &[0x0635, 0x0644, 0x0649]),
+	(0xfdf1, &[0x0642, 0x0644, 0x0649]),
+	(0xfdf2, &[0x006c, 0x0644, 0x0644, 0x0651, 0x0670, 0x006f]),
+	(0xfdf3, &[0x006c, 0x0643, 0x0628, 0x0631]),
+	(0xfdf4, &[0x0645, 0x062d, 0x0645, 0x062f]),
+	(0xfdf5, &[0x0635, 0x0644, 0x0639, 0x0645]),
+	(0xfdf6, &[0x0631, 0x0633, 0x0648, 0x0644]),
+	(0xfdf7, &[0x0639, 0x0644, 0x0649, 0x006f]),
+	(0xfdf8, &[0x0648, 0x0633, 0x0644, 0x0645]),
+	(0xfdf9, &[0x0635, 0x0644, 0x0649]),
+	(0xfdfa, &[0x0635, 0x0644, 0x0649, 0x0020, 0x006c, 0x0644, 0x0644, 0x006f, 0x0020, 0x0639, 0x0644, 0x0649, 0x006f, 0x0020, 0x0648, 0x0633, 0x0644, 0x0645]),
+	(0xfdfb, &[0x062c, 0x0644, 0x0020, 0x062c, 0x0644, 0x006c, 0x0644, 0x006f]),
+	(0xfdfc, &[0x0631, 0x0649, 0x006c, 0x0644]),
+	(0xfe19, &[0x2d57]),
+	(0xfe30, &[0x003a]),
+	(0xfe31, &[0x2502]),
+	(0xfe34, &[0x2307]),
+	(0xfe35, &[0x23dc]),
+	(0xfe36, &[0x23dd]),
+	(0xfe37, &[0x23de]),
+	(0xfe38, &[0x23df]),
+	(0xfe39, &[0x23e0]),
+	(0xfe3a, &[0x23e1]),
+	(0xfe49, &[0x02c9]),
+	(0xfe4a, &[0x02c9]),
+	(0xfe4b, &[0x02c9]),
+	(0xfe4c, &[0x02c9]),
+	(0xfe4d, &[0x005f]),
+	(0xfe4e, &[0x005f]),
+	(0xfe4f, &[0x005f]),
+	(0xfe58, &[0x002d]),
+	(0xfe68, &[0x005c]),
+	(0xfe80, &[0x0621]),
+	(0xfe81, &[0x0622]),
+	(0xfe82, &[0x0622]),
+	(0xfe83, &[0x006c, 0x0674]),
+	(0xfe84, &[0x006c, 0x0674]),
+	(0xfe85, &[0x0648, 0x0674]),
+	(0xfe86, &[0x0648, 0x0674]),
+	(0xfe87, &[0x006c, 0x0655]),
+	(0xfe88, &[0x006c, 0x0655]),
+	(0xfe89, &[0x0649, 0x0674]),
+	(0xfe8a, &[0x0649, 0x0674]),
+	(0xfe8b, &[0x0649, 0x0674]),
+	(0xfe8c, &[0x0649, 0x0674]),
+	(0xfe8d, &[0x006c]),
+	(0xfe8e, &[0x006c]),
+	(0xfe8f, &[0x0628]),
+	(0xfe90, &[0x0628]),
+	(0xfe91, &[0x0628]),
+	(0xfe92, &[0x0628]),
+	(0xfe93, &[0x0629]),
+	(0xfe94, &[0x0629]),
+	(0xfe95, &[0x062a]),
+	(0xfe96, &[0x062a]),
+	(0xfe97, &[0x062a]),
+	(0xfe98, &[0x062a]),
+	(0xfe99, &[0x0649, 0x06db]),
+	(0xfe9a, &[0x0649, 0x06db]),
+	(0xfe9b, &[0x0649, 0x06db]),
+	(0xfe9c, &[0x0649, 0x06db]),
+	(0xfe9d, &[0x062c]),
+	(0xfe9e, &[0x062c]),
+	(0xfe9f, &[0x062c]),
+	(0xfea0, &[0x062c]),
+	(0xfea1, &[0x062d]),
+	(0xfea2, &[0x062d]),
+	(0xfea3, &[0x062d]),
+	(0xfea4, &[0x062d]),
+	(0xfea5, &[0x062e]),
+	(0xfea6, &[0x062e]),
+	(0xfea7, &[0x062e]),
+	(0xfea8, &[0x062e]),
+	(0xfea9, &[0x062f]),
+	(0xfeaa, &[0x062f]),
+	(0xfeab, &[0x0630]),
+	(0xfeac, &[0x0630]),
+	(0xfead, &[0x0631]),
+	(0xfeae, &[0x0631]),
+	(0xfeaf, &[0x0632]),
+	(0xfeb0, &[0x0632]),
+	(0xfeb1, &[0x0633]),
+	(0xfeb2, &[0x0633]),
+	(0xfeb3, &[0x0633]),
+	(0xfeb4, &[0x0633]),
+	(0xfeb5, &[0x0633, 0x06db]),
+	(0xfeb6, &[0x0633, 0x06db]),
+	(0xfeb7, &[0x0633, 0x06db]),
+	(0xfeb8, &[0x0633, 0x06db]),
+	(0xfeb9, &[0x0635]),
+	(0xfeba, &[0x0635]),
+	(0xfebb, &[0x0635]),
+	(0xfebc, &[0x0635]),
+	(0xfebd, &[0x0636]),
+	(0xfebe, &[0x0636]),
+	(0xfebf, &[0x0636]),
+	(0xfec0, &[0x0636]),
+	(0xfec1, &[0x0637]),
+	(0xfec2, &[0x0637]),
+	(0xfec3, &[0x0637]),
+	(0xfec4, &[0x0637]),
+	(0xfec5, &[0x0638]),
+	(0xfec6, &[0x0638]),
+	(0xfec7, &[0x0638]),
+	(0xfec8, &[0x0638]),
+	(0xfec9, &[0x0639]),
+	(0xfeca, &[0x0639]),
+	(0xfecb, &[0x0639]),
+	(0xfecc, &[0x0639]),
+	(0xfecd, &[0x063a]),
+	(0xfece, &[0x063a]),
+	(0xfecf, &[0x063a]),
+	(0xfed0, &[0x063a]),
+	(0xfed1, &[0x0641]),
+	(0xfed2, &[0x0641]),
+	(0xfed3, &[0x0641]),
+	(0xfed4, &[0x0641]),
+	(0xfed5, &[0x0642]),
+	(0xfed6, &[0x0642]),
+	(0xfed7, &[0x0642]),
+	(0xfed8, &[0x0642]),
+	(0xfed9, &[0x0643]),
+	(0xfeda, &[0x0643]),
+	(0xfedb, &[0x0643]),
+	(0xfedc, &[0x0643]),
+	(0xfedd, &[0x0644]),
+	(0xfede, &[0x0644]),
+	(0xfedf, &[0x0644]),
+	(0xfee0, &[0x0644]),
+	(0xfee1, &[0x0645]),
+	(0xfee2, &[0x0645]),
+	(0xfee3, &[0x0645]),
+	(0xfee4, &[0x0645]),
+	(0xfee5, &[0x0646]),
+	(0xfee6, &[0x0646]),
+	(0xfee7, &[0x0646]),
+	(0xfee8, &[0x0646]),
+	(0xfee9, &[0x006f]),
+	(0xfeea, &[0x006f]),
+	(0xfeeb, &[0x006f]),
+	(0xfeec, &[0x006f]),
+	(0xfeed, &[0x0648]),
+	(0xfeee, &[0x0648]),
+	(0xfeef, &[0x0649]),
+	(0xfef0, &[0x0649]),
+	(0xfef1, &[0x0649]),
+	(0xfef2, &[0x0649]),
+	(0xfef3, &[0x0649]),
+	(0xfef4, &[0x0649]),
+	(0xfef5, &[0x0644, 0x0622]),
+	(0xfef6, &[0x0644, 0x0622]),
+	(0xfef7, &[0x0644, 0x006c, 0x0674]),
+	(0xfef8, &[0x0644, 0x006c, 0x0674]),
+	(0xfef9, &[0x0644, 0x006c, 0x0655]),
+	(0xfefa, &[0x0644, 0x006c, 0x0655]),
+	(0xfefb, &[0x0644, 0x006c]),
+	(0xfefc, &[0x0644, 0x006c]),
+	(0xff01, &[0x0021]),
+	(0xff02, &[0x0027, 0x0027]),
+	(0xff07, &[0x0027]),
+	(0xff0d, &[0x30fc]),
+	(0xff1a, &[0x003a]),
+	(0xff21, &[0x0041]),
+	(0xff22, &[0x0042]),
+	(0xff23, &[0x0043]),
+	(0xff25, &[0x0045]),
+	(0xff28, &[0x0048]),
+	(0xff29, &[0x006c]),
+	(0xff2a, &[0x004a]),
+	(0xff2b, &[0x004b]),
+	(0xff2d, &[0x004d]),
+	(0xff2e, &[0x004e]),
+	(0xff2f, &[0x004f]),
+	(0xff30, &[0x0050]),
+	(0xff33, &[0x0053]),
+	(0xff34, &[0x0054]),
+	(0xff38, &[0x0058]),
+	(0xff39, &[0x0059]),
+	(0xff3a, &[0x005a]),
+	(0xff3b, &[0x0028]),
+	(0xff3c, &[0x005c]),
+	(0xff3d, &[0x0029]),
+	(0xff3e, &[0xfe3f]),
+	(0xff40, &[0x0027]),
+	(0xff41, &[0x0061]),
+	(0xff43, &[0x0063]),
+	(0xff45, &[0x0065]),
+	(0xff47, &[0x0067]),
+	(0xff48, &[0x0068]),
+	(0xff49, &[0x0069]),
+	(0xff4a, &[0x006a]),
+	(0xff4c, &[0x006c]),
+	(0xff4f, &[0x006f]),
+	(0xff50, &[0x0070]),
+	(0xff53, &[0x0073]),
+	(0xff56, &[0x0076]),
+	(0xff58, &[0x0078]),
+	(0xff59, &[0x0079]),
+	(0xff5c, &[0x2502]),
+	(0xff5e, &[0x301c]),
+	(0xff65, &[0x00b7]),
+	(0xffe3, &[0x02c9]),
+	(0xffe8, &[0x006c]),
+	(0xffed, &[0x25aa]),
+	(0x10101, &[0x00b7]),
+	(0x1018e, &[0x004e, 0x030a]),
+	(0x10196, &[0x0058, 0x0335]),
+	(0x10197, &[0x0056, 0x0335]),
+	(0x10198, &[0x006c, 0x0335, 0x006c, 0x0335, 0x0053, 0x0335]),
+	(0x10199, &[0x006c, 0x0335, 0x006c, 0x0335]),
+	(0x101a0, &[0x2ce8]),
+	(0x10282, &[0x0042]),
+	(0x10285, &[0x0394]),
+	(0x10286, &[0x0045]),
+	(0x10287, &[0x0046]),
+	(0x1028a, &[0x006c]),
+	(0x1028d, &[0x0245]),
+	(0x10290, &[0x0058]),
+	(0x10292, &[0x004f]),
+	(0x10294, &[0x16dc]),
+	(0x10295, &[0x0050]),
+	(0x10296, &[0x0053]),
+	(0x10297, &[0x0054]),
+	(0x1029b, &[0x002b]),
+	(0x102a0, &[0x0041]),
+	(0x102a1, &[0x0042]),
+	(0x102a2, &[0x0043]),
+	(0x102a3, &[0x0394]),
+	(0x102a5, &[0x0046]),
+	(0x102ab, &[0x004f]),
+	(0x102ad, &[0x03d8]),
+	(0x102b0, &[0x004d]),
+	(0x102b1, &[0x0054]),
+	(0x102b2, &[0x0059]),
+	(0x102b3, &[0x03a6]),
+	(0x102b4, &[0x0058]),
+	(0x102b5, &[0x03a8]),
+	(0x102b6, &[0x03a9]),
+	(0x102b8, &[0x2d40]),
+	(0x102cf, &[0x0048]),
+	(0x102e1, &[0x062f]),
+	(0x102e4, &[0x0648]),
+	(0x102e8, &[0x0637]),
+	(0x102f2, &[0x0635]),
+	(0x102f5, &[0x005a]),
+	(0x10301, &[0x0042]),
+	(0x10302, &[0x0043]),
+	(0x10309, &[0x006c]),
+	(0x10311, &[0x004d]),
+	(0x10312, &[0x03d8]),
+	(0x10315, &[0x0054]),
+	(0x10317, &[0x0058]),
+	(0x1031a, &[0x0038]),
+	(0x1031f, &[0x002a]),
+	(0x10320, &[0x006c]),
+	(0x10322, &[0x0058]),
+	(0x103d1, &[0x10382]),
+	(0x103d3, &[0x10393]),
+	(0x10401, &[0x0190]),
+	(0x10404, &[0x004f]),
+	(0x10411, &[0xa4f6]),
+	(0x10415, &[0x0043]),
+	(0x1041b, &[0x004c]),
+	(0x1041f, &[0x2c70]),
+	(0x10420, &[0x0053]),
+	(0x10423, &[0x0186]),
+	(0x10425, &[0x0418]),
+	(0x10429, &[0xa793]),
+	(0x1042a, &[0x029a]),
+	(0x1042c, &[0x006f]),
+	(0x1043d, &[0x0063]),
+	(0x1043f, &[0x0277]),
+	(0x10442, &[0x025e]),
+	(0x10443, &[0x029f]),
+	(0x10448, &[0x0073]),
+	(0x1044b, &[0x0254]),
+	(0x1044d, &[0x1d0e]),
+	(0x104a0, &[0x10486]),
+	(0x104b0, &[0x0245]),
+	(0x104b4, &[0x0052]),
+	(0x104bc, &[0x04c3]),
+	(0x104c2, &[0x004f]),
+	(0x104c3, &[0x0298]),
+	(0x104c4, &[0x00de]),
+	(0x104cd, &[0x040b]),
+	(0x104ce, &[0x0055]),
+	(0x104d0, &[0x16e6]),
+	(0x104d1, &[0x03a8]),
+	(0x104d2, &[0x0037]),
+	(0x104d8, &[0x028c]),
+	(0x104db, &[0x03bb]),
+	(0x104ea, &[0x006f]),
+	(0x104eb, &[0xa669]),
+	(0x104f6, &[0x0075]),
+	(0x104f9, &[0x03c8]),
+	(0x10513, &[0x004e]),
+	(0x10516, &[0x004f]),
+	(0x10518, &[0x004b]),
+	(0x1051c, &[0x0043]),
+	(0x1051d, &[0x0056]),
+	(0x10525, &[0x0046]),
+	(0x10526, &[0x004c]),
+	(0x10527, &[0x0058]),
+	(0x10a3a, &[0x0323]),
+	(0x10a50, &[0x002e]),
+	(0x10a57, &[0x10a56, 0x10a56]),
+	(0x10cfa, &[0x10ca5]),
+	(0x10cfc, &[0x10c82]),
+	(0x110bb, &[0x0970]),
+	(0x111c7, &[0x0970]),
+	(0x111ca, &[0x0323]),
+	(0x111cb, &[0x093a]),
+	(0x111db, &[0xa8fc]),
+	(0x111dc, &[0xa8fb]),
+	(0x111de, &[0x2248]),
+	(0x11300, &[0x030a]),
+	(0x11413, &[0x11434, 0x11442, 0x11412]),
+	(0x11419, &[0x11434, 0x11442, 0x11418]),
+	(0x11424, &[0x11434, 0x11442, 0x11423]),
+	(0x1142a, &[0x11434, 0x11442, 0x11429]),
+	(0x1142d, &[0x11434, 0x11442, 0x1142c]),
+	(0x1142f, &[0x11434, 0x11442, 0x1142e]),
+	(0x1144c, &[0x1144b, 0x1144b]),
+	(0x11492, &[0x0998]),
+	(0x11494, &[0x099a]),
+	(0x11496, &[0x099c]),
+	(0x11498, &[0x099e]),
+	(0x11499, &[0x099f]),
+	(0x1149b, &[0x09a1]),
+	(0x1149d, &[0x09b2]),
+	(0x1149e, &[0x09a4]),
+	(0x1149f, &[0x09a5]),
+	(0x114a0, &[0x09a6]),
+	(0x114a1, &[0x09a7]),
+	(0x114a2, &[0x09a8]),
+	(0x114a3, &[0x09aa]),
+	(0x114a7, &[0x09ae]),
+	(0x114a8, &[0x09af]),
+	(0x114a9, &[0x09ac]),
+	(0x114aa, &[0x09a3]),
+	(0x114ab, &[0x09b0]),
+	(0x114ad, &[0x09b7]),
+	(0x114ae, &[0x09b8]),
+	(0x114b0, &[0x09be]),
+	(0x114b1, &[0x09bf]),
+	(0x114b9, &[0x09c7]),
+	(0x114bc, &[0x09cb]),
+	(0x114bd, &[0x09d7]),
+	(0x114be, &[0x09cc]),
+	(0x114bf, &[0x0306, 0x0307]),
+	(0x114c1, &[0x0983]),
+	(0x114c2, &[0x09cd]),
+	(0x114c3, &[0x0323]),
+	(0x114c4, &[0x09bd]),
+	(0x114c5, &[0x0077, 0x0307]),
+	(0x114d0, &[0x004f]),
+	(0x114d1, &[0x09e7]),
+	(0x114d2, &[0x09e8]),
+	(0x114d6, &[0x09ec]),
+	(0x115d8, &[0x11582]),
+	(0x115d9, &[0x11582]),
+	(0x115da, &[0x11583]),
+	(0x115db, &[0x11584]),
+	(0x115dc, &[0x115b2]),
+	(0x115dd, &[0x115b3]),
+	(0x11642, &[0x11641, 0x11641]),
+	(0x11700, &[0x0072, 0x006e]),
+	(0x11706, &[0x0076]),
+	(0x1170a, &[0x0077]),
+	(0x1170e, &[0x0077]),
+	(0x1170f, &[0x0077]),
+	(0x118a0, &[0x0056]),
+	(0x118a2, &[0x0046]),
+	(0x118a3, &[0x004c]),
+	(0x118a4, &[0x0059]),
+	(0x118a6, &[0x0045]),
+	(0x118a8, &[0x2207]),
+	(0x118a9, &[0x005a]),
+	(0x118ac, &[0x0039]),
+	(0x118ae, &[0x0045]),
+	(0x118af, &[0x0034]),
+	(0x118b2, &[0x004c]),
+	(0x118b5, &[0x004f]),
+	(0x118b7, &[0x16dc]),
+	(0x118b8, &[0x0055]),
+	(0x118bb, &[0x0035]),
+	(0x118bc, &[0x0054]),
+	(0x118c0, &[0x0076]),
+	(0x118c1, &[0x0073]),
+	(0x118c2, &[0x0046]),
+	(0x118c3, &[0x0069]),
+	(0x118c4, &[0x007a]),
+	(0x118c6, &[0x0037]),
+	(0x118c8, &[0x006f]),
+	(0x118ca, &[0x0033]),
+	(0x118cc, &[0x0039]),
+	(0x118ce, &[0xa793]),
+	(0x118d5, &[0x0036]),
+	(0x118d6, &[0x0039]),
+	(0x118d7, &[0x006f]),
+	(0x118d8, &[0x0075]),
+	(0x118dc, &[0x0079]),
+	(0x118e0, &[0x004f]),
+	(0x118e3, &[0x0072, 0x006e]),
+	(0x118e4, &[0x0669]),
+	(0x118e5, &[0x005a]),
+	(0x118e6, &[0x0057]),
+	(0x118e9, &[0x0043]),
+	(0x118ec, &[0x0058]),
+	(0x118ef, &[0x0057]),
+	(0x118f2, &[0x0043]),
+	(0x11ae6, &[0x11ae5, 0x11aef]),
+	(0x11ae7, &[0x11ae5, 0x11af0]),
+	(0x11ae8, &[0x11ae5, 0x11ae5]),
+	(0x11ae9, &[0x11ae5, 0x11ae5, 0x11aef]),
+	(0x11aea, &[0x11ae5, 0x11ae5, 0x11af0]),
+	(0x11aec, &[0x11aeb, 0x11aef]),
+	(0x11aed, &[0x11aeb, 0x11aeb]),
+	(0x11aee, &[0x11aeb, 0x11aeb, 0x11aef]),
+	(0x11af4, &[0x11af3, 0x11aef]),
+	(0x11af5, &[0x11af3, 0x11af0]),
+	(0x11af6, &[0x11af3, 0x11af3]),
+	(0x11af7, &[0x11af3, 0x11af3, 0x11aef]),
+	(0x11af8, &[0x11af3, 0x11af3, 0x11af0]),
+	(0x11c42, &[0x11c41, 0x11c41]),
+	(0x11cb2, &[0x11caa]),
+	(0x12038, &[0x1039a]),
+	(0x132f9, &[0x1099e]),
+	(0x16f07, &[0x0393]),
+	(0x16f08, &[0x0056]),
+	(0x16f0a, &[0x0054]),
+	(0x16f16, &[0x004c]),
+	(0x16f1a, &[0x0394]),
+	(0x16f1c, &[0xa658]),
+	(0x16f26, &[0xa4f6]),
+	(0x16f28, &[0x006c]),
+	(0x16f2d, &[0x0190]),
+	(0x16f35, &[0x0052]),
+	(0x16f3a, &[0x0053]),
+	(0x16f3b, &[0x0033]),
+	(0x16f3d, &[0x0245]),
+	(0x16f3f, &[0x003e]),
+	(0x16f40, &[0x0041]),
+	(0x16f42, &[0x0055]),
+	(0x16f43, &[0x0059]),
+	(0x16f51, &[0x0027]),
+	(0x16f52, &[0x0027]),
+	(0x1ccd6, &[0x0041]),
+	(0x1ccd7, &[0x0042]),
+	(0x1ccd8, &[0x0043]),
+	(0x1ccd9, &[0x0044]),
+	(0x1ccda, &[0x0045]),
+	(0x1ccdb, &[0x0046]),
+	(0x1ccdc, &[0x0047]),
+	(0x1ccdd, &[0x0048]),
+	(0x1ccde, &[0x006c]),
+	(0x1ccdf, &[0x004a]),
+	(0x1cce0, &[0x004b]),
+	(0x1cce1, &[0x004c]),
+	(0x1cce2, &[0x004d]),
+	(0x1cce3, &[0x004e]),
+	(0x1cce4, &[0x004f]),
+	(0x1cce5, &[0x0050]),
+	(0x1cce6, &[0x0051]),
+	(0x1cce7, &[0x0052]),
+	(0x1cce8, &[0x0053]),
+	(0x1cce9, &[0x0054]),
+	(0x1ccea, &[0x0055]),
+	(0x1cceb, &[0x0056]),
+	(0x1ccec, &[0x0057]),
+	(0x1cced, &[0x0058]),
+	(0x1ccee, &[0x0059]),
+	(0x1ccef, &[0x005a]),
+	(0x1ccf0, &[0x004f]),
+	(0x1ccf1, &[0x006c]),
+	(0x1ccf2, &[0x0032]),
+	(0x1ccf3, &[0x0033]),
+	(0x1ccf4, &[0x0034]),
+	(0x1ccf5, &[0x0035]),
+	(0x1ccf6, &[0x0036]),
+	(0x1ccf7, &[0x0037]),
+	(0x1ccf8, &[0x0038]),
+	(0x1ccf9, &[0x0039]),
+	(0x1d114, &[0x007b]),
+	(0x1d16d, &[0x002e]),
+	(0x1d202, &[0x04fe]),
+	(0x1d206, &[0x0033]),
+	(0x1d20b, &[0x0418]),
+	(0x1d20d, &[0x0056]),
+	(0x1d20f, &[0x005c]),
+	(0x1d212, &[0x0037]),
+	(0x1d213, &[0x0046]),
+	(0x1d214, &[0x102bc]),
+	(0x1d215, &[0xa4f6]),
+	(0x1d216, &[0x0052]),
+	(0x1d217, &[0x2c6f]),
+	(0x1d21a, &[0x004f, 0x0335]),
+	(0x1d21b, &[0x2144]),
+	(0x1d21c, &[0xa4d5]),
+	(0x1d221, &[0x0190]),
+	(0x1d222, &[0x0460]),
+	(0x1d22a, &[0x004c]),
+	(0x1d22b, &[0xa4f6]),
+	(0x1d230, &[0xa7fb]),
+	(0x1d236, &[0x003c]),
+	(0x1d237, &[0x003e]),
+	(0x1d238, &[0x228f]),
+	(0x1d239, &[0x2290]),
+	(0x1d23a, &[0x002f]),
+	(0x1d23b, &[0x005c]),
+	(0x1d23f, &[0x16cb]),
+	(0x1d245, &[0x0548]),
+	(0x1d400, &[0x0041]),
+	(0x1d401, &[0x0042]),
+	(0x1d402, &[0x0043]),
+	(0x1d403, &[0x0044]),
+	(0x1d404, &[0x0045]),
+	(0x1d405, &[0x0046]),
+	(0x1d406, &[0x0047]),
+	(0x1d407, &[0x0048]),
+	(0x1d408, &[0x006c]),
+	(0x1d409, &[0x004a]),
+	(0x1d40a, &[0x004b]),
+	(0x1d40b, &[0x004c]),
+	(0x1d40c, &[0x004d]),
+	(0x1d40d, &[0x004e]),
+	(0x1d40e, &[0x004f]),
+	(0x1d40f, &[0x0050]),
+	(0x1d410, &[0x0051]),
+	(0x1d411, &[0x0052]),
+	(0x1d412, &[0x0053]),
+	(0x1d413, &[0x0054]),
+	(0x1d414, &[0x0055]),
+	(0x1d415, &[0x0056]),
+	(0x1d416, &[0x0057]),
+	(0x1d417, &[0x0058]),
+	(0x1d418, &[0x0059]),
+	(0x1d419, &[0x005a]),
+	(0x1d41a, &[0x0061]),
+	(0x1d41b, &[0x0062]),
+	(0x1d41c, &[0x0063]),
+	(0x1d41d, &[0x0064]),
+	(0x1d41e, &[0x0065]),
+	(0x1d41f, &[0x0066]),
+	(0x1d420, &[0x0067]),
+	(0x1d421, &[0x0068]),
+	(0x1d422, &[0x0069]),
+	(0x1d423, &[0x006a]),
+	(0x1d424, &[0x006b]),
+	(0x1d425, &[0x006c]),
+	(0x1d426, &[0x0072, 0x006e]),
+	(0x1d427, &[0x006e]),
+	(0x1d428, &[0x006f]),
+	(0x1d429, &[0x0070]),
+	(0x1d42a, &[0x0071]),
+	(0x1d42b, &[0x0072]),
+	(0x1d42c, &[0x0073]),
+	(0x1d42d, &[0x0074]),
+	(0x1d42e, &[0x0075]),
+	(0x1d42f, &[0x0076]),
+	(0x1d430, &[0x0077]),
+	(0x1d431, &[0x0078]),
+	(0x1d432, &[0x0079]),
+	(0x1d433, &[0x007a]),
+	(0x1d434, &[0x0041]),
+	(0x1d435, &[0x0042]),
+	(0x1d436, &[0x0043]),
+	(0x1d437, &[0x0044]),
+	(0x1d438, &[0x0045]),
+	(0x1d439, &[0x0046]),
+	(0x1d43a, &[0x0047]),
+	(0x1d43b, &[0x0048]),
+	(0x1d43c, &[0x006c]),
+	(0x1d43d, &[0x004a]),
+	(0x1d43e, &[0x004b]),
+	(0x1d43f, &[0x004c]),
+	(0x1d440, &[0x004d]),
+	(0x1d441, &[0x004e]),
+	(0x1d442, &[0x004f]),
+	(0x1d443, &[0x0050]),
+	(0x1d444, &[0x0051]),
+	(0x1d445, &[0x0052]),
+	(0x1d446, &[0x0053]),
+	(0x1d447, &[0x0054]),
+	(0x1d448, &[0x0055]),
+	(0x1d449, &[0x0056]),
+	(0x1d44a, &[0x0057]),
+	(0x1d44b, &[0x0058]),
+	(0x1d44c, &[0x0059]),
+	(0x1d44d, &[0x005a]),
+	(0x1d44e, &[0x0061]),
+	(0x1d44f, &[0x0062]),
+	(0x1d450, &[0x0063]),
+	(0x1d451, &[0x0064]),
+	(0x1d452, &[0x0065]),
+	(0x1d453, &[0x0066]),
+	(0x1d454, &[0x0067]),
+	(0x1d456, &[0x0069]),
+	(0x1d457, &[0x006a]),
+	(0x1d458, &[0x006b]),
+	(0x1d459, &[0x006c]),
+	(0x1d45a, &[0x0072, 0x006e]),
+	(0x1d45b, &[0x006e]),
+	(0x1d45c, &[0x006f]),
+	(0x1d45d, &[0x0070]),
+	(0x1d45e, &[0x0071]),
+	(0x1d45f, &[0x0072]),
+	(0x1d460, &[0x0073]),
+	(0x1d461, &[0x0074]),
+	(0x1d462, &[0x0075]),
+	(0x1d463, &[0x0076]),
+	(0x1d464, &[0x0077]),
+	(0x1d465, &[0x0078]),
+	(0x1d466, &[0x0079]),
+	(0x1d467, &[0x007a]),
+	(0x1d468, &[0x0041]),
+	(0x1d469, &[0x0042]),
+	(0x1d46a, &[0x0043]),
+	(0x1d46b, &[0x0044]),
+	(0x1d46c, &[0x0045]),
+	(0x1d46d, &[0x0046]),
+	(0x1d46e, &[0x0047]),
+	(0x1d46f, &[0x0048]),
+	(0x1d470, &[0x006c]),
+	(0x1d471, &[0x004a]),
+	(0x1d472, &[0x004b]),
+	(0x1d473, &[0x004c]),
+	(0x1d474, &[0x004d]),
+	(0x1d475, &[0x004e]),
+	(0x1d476, &[0x004f]),
+	(0x1d477, &[0x0050]),
+	(0x1d478, &[0x0051]),
+	(0x1d479, &[0x0052]),
+	(0x1d47a, &[0x0053]),
+	(0x1d47b, &[0x0054]),
+	(0x1d47c, &[0x0055]),
+	(0x1d47d, &[0x0056]),
+	(0x1d47e, &[0x0057]),
+	(0x1d47f, &[0x0058]),
+	(0x1d480, &[0x0059]),
+	(0x1d481, &[0x005a]),
+	(0x1d482, &[0x0061]),
+	(0x1d483, &[0x0062]),
+	(0x1d484, &[0x0063]),
+	(0x1d485, &[0x0064]),
+	(0x1d486, &[0x0065]),
+	(0x1d487, &[0x0066]),
+	(0x1d488, &[0x0067]),
+	(0x1d489, &[0x0068]),
+	(0x1d48a, &[0x0069]),
+	(0x1d48b, &[0x006a]),
+	(0x1d48c, &[0x006b]),
+	(0x1d48d, &[0x006c]),
+	(0x1d48e, &[0x0072, 0x006e]),
+	(0x1d48f, &[0x006e]),
+	(0x1d490, &[0x006f]),
+	(0x1d491, &[0x0070]),
+	(0x1d492, &[0x0071]),
+	(0x1d493, &[0x0072]),
+	(0x1d494, &[0x0073]),
+	(0x1d495, &[0x0074]),
+	(0x1d496, &[0x0075]),
+	(0x1d497, &[0x0076]),
+	(0x1d498, &[0x0077]),
+	(0x1d499, &[0x0078]),
+	(0x1d49a, &[0x0079]),
+	(0x1d49b, &[0x007a]),
+	(0x1d49c, &[0x0041]),
+	(0x1d49e, &[0x0043]),
+	(0x1d49f, &[0x0044]),
+	(0x1d4a2, &[0x0047]),
+	(0x1d4a5, &[0x004a]),
+	(0x1d4a6, &[0x004b]),
+	(0x1d4a9, &[0x004e]),
+	(0x1d4aa, &[0x004f]),
+	(0x1d4ab, &[0x0050]),
+	(0x1d4ac, &[0x0051]),
+	(0x1d4ae, &[0x0053]),
+	(0x1d4af, &[0x0054]),
+	(0x1d4b0, &[0x0055]),
+	(0x1d4b1, &[0x0056]),
+	(0x1d4b2, &[0x0057]),
+	(0x1d4b3, &[0x0058]),
+	(0x1d4b4, &[0x0059]),
+	(0x1d4b5, &[0x005a]),
+	(0x1d4b6, &[0x0061]),
+	(0x1d4b7, &[0x0062]),
+	(0x1d4b8, &[0x0063]),
+	(0x1d4b9, &[0x0064]),
+	(0x1d4bb, &[0x0066]),
+	(0x1d4bd, &[0x0068]),
+	(0x1d4be, &[0x0069]),
+	(0x1d4bf, &[0x006a]),
+	(0x1d4c0, &[0x006b]),
+	(0x1d4c1, &[0x006c]),
+	(0x1d4c2, &[0x0072, 0x006e]),
+	(0x1d4c3, &[0x006e]),
+	(0x1d4c5, &[0x0070]),
+	(0x1d4c6, &[0x0071]),
+	(0x1d4c7, &[0x0072]),
+	(0x1d4c8, &[0x0073]),
+	(0x1d4c9, &[0x0074]),
+	(0x1d4ca, &[0x0075]),
+	(0x1d4cb, &[0x0076]),
+	(0x1d4cc, &[0x0077]),
+	(0x1d4cd, &[0x0078]),
+	(0x1d4ce, &[0x0079]),
+	(0x1d4cf, &[0x007a]),
+	(0x1d4d0, &[0x0041]),
+	(0x1d4d1, &[0x0042]),
+	(0x1d4d2, &[0x0043]),
+	(0x1d4d3, &[0x0044]),
+	(0x1d4d4, &[0x0045]),
+	(0x1d4d5, &[0x0046]),
+	(0x1d4d6, &[0x0047]),
+	(0x1d4d7, &[0x0048]),
+	(0x1d4d8, &[0x006c]),
+	(0x1d4d9, &[0x004a]),
+	(0x1d4da, &[0x004b]),
+	(0x1d4db, &[0x004c]),
+	(0x1d4dc, &[0x004d]),
+	(0x1d4dd, &[0x004e]),
+	(0x1d4de, &[0x004f]),
+	(0x1d4df, &[0x0050]),
+	(0x1d4e0, &[0x0051]),
+	(0x1d4e1, &[0x0052]),
+	(0x1d4e2, &[0x0053]),
+	(0x1d4e3, &[0x0054]),
+	(0x1d4e4, &[0x0055]),
+	(0x1d4e5, &[0x0056]),
+	(0x1d4e6, &[0x0057]),
+	(0x1d4e7, &[0x0058]),
+	(0x1d4e8, &[0x0059]),
+	(0x1d4e9, &[0x005a]),
+	(0x1d4ea, &[0x0061]),
+	(0x1d4eb, &[0x0062]),
+	(0x1d4ec, &[0x0063]),
+	(0x1d4ed, &[0x0064]),
+	(0x1d4ee, &[0x0065]),
+	(0x1d4ef, &[0x0066]),
+	(0x1d4f0, &[0x0067]),
+	(0x1d4f1, &[0x0068]),
+	(0x1d4f2, &[0x0069]),
+	(0x1d4f3, &[0x006a]),
+	(0x1d4f4, &[0x006b]),
+	(0x1d4f5, &[0x006c]),
+	(0x1d4f6, &[0x0072, 0x006e]),
+	(0x1d4f7, &[0x006e]),
+	(0x1d4f8, &[0x006f]),
+	(0x1d4f9, &[0x0070]),
+	(0x1d4fa, &[0x0071]),
+	(0x1d4fb, &[0x0072]),
+	(0x1d4fc, &[0x0073]),
+	(0x1d4fd, &[0x0074]),
+	(0x1d4fe, &[0x0075]),
+	(0x1d4ff, &[0x0076]),
+	(0x1d500, &[0x0077]),
+	(0x1d501, &[0x0078]),
+	(0x1d502, &[0x0079]),
+	(0x1d503, &[0x007a]),
+	(0x1d504, &[0x0041]),
+	(0x1d505, &[0x0042]),
+	(0x1d507, &[0x0044]),
+	(0x1d508, &[0x0045]),
+	(0x1d509, &[0x0046]),
+	(0x1d50a, &[0x0047]),
+	(0x1d50d, &[0x004a]),
+	(0x1d50e, &[0x004b]),
+	(0x1d50f, &[0x004c]),
+	(0x1d510, &[0x004d]),
+	(0x1d511, &[0x004e]),
+	(0x1d512, &[0x004f]),
+	(0x1d513, &[0x0050]),
+	(0x1d514, &[0x0051]),
+	(0x1d516, &[0x0053]),
+	(0x1d517, &[0x0054]),
+	(0x1d518, &[0x0055]),
+	(0x1d519, &[0x0056]),
+	(0x1d51a, &[0x0057]),
+	(0x1d51b, &[0x0058]),
+	(0x1d51c, &[0x0059]),
+	(0x1d51e, &[0x0061]),
+	(0x1d51f, &[0x0062]),
+	(0x1d520, &[0x0063]),
+	(0x1d521, &[0x0064]),
+	(0x1d522, &[0x0065]),
+	(0x1d523, &[0x0066]),
+	(0x1d524, &[0x0067]),
+	(0x1d525, &[0x0068]),
+	(0x1d526, &[0x0069]),
+	(0x1d527, &[0x006a]),
+	(0x1d528, &[0x006b]),
+	(0x1d529, &[0x006c]),
+	(0x1d52a, &[0x0072, 0x006e]),
+	(0x1d52b, &[0x006e]),
+	(0x1d52c, &[0x006f]),
+	(0x1d52d, &[0x0070]),
+	(0x1d52e, &[0x0071]),
+	(0x1d52f, &[0x0072]),
+	(0x1d530, &[0x0073]),
+	(0x1d531, &[0x0074]),
+	(0x1d532, &[0x0075]),
+	(0x1d533, &[0x0076]),
+	(0x1d534, &[0x0077]),
+	(0x1d535, &[0x0078]),
+	(0x1d536, &[0x0079]),
+	(0x1d537, &[0x007a]),
+	(0x1d538, &[0x0041]),
+	(0x1d539, &[0x0042]),
+	(0x1d53b, &[0x0044]),
+	(0x1d53c, &[0x0045]),
+	(0x1d53d, &[0x0046]),
+	(0x1d53e, &[0x0047]),
+	(0x1d540, &[0x006c]),
+	(0x1d541, &[0x004a]),
+	(0x1d542, &[0x004b]),
+	(0x1d543, &[0x004c]),
+	(0x1d544, &[0x004d]),
+	(0x1d546, &[0x004f]),
+	(0x1d54a, &[0x0053]),
+	(0x1d54b, &[0x0054]),
+	(0x1d54c, &[0x0055]),
+	(0x1d54d, &[0x0056]),
+	(0x1d54e, &[0x0057]),
+	(0x1d54f, &[0x0058]),
+	(0x1d550, &[0x0059]),
+	(0x1d552, &[0x0061]),
+	(0x1d553, &[0x0062]),
+	(0x1d554, &[0x0063]),
+	(0x1d555, &[0x0064]),
+	(0x1d556, &[0x0065]),
+	(0x1d557, &[0x0066]),
+	(0x1d558, &[0x0067]),
+	(0x1d559, &[0x0068]),
+	(0x1d55a, &[0x0069]),
+	(0x1d55b, &[0x006a]),
+	(0x1d55c, &[0x006b]),
+	(0x1d55d, &[0x006c]),
+	(0x1d55e, &[0x0072, 0x006e]),
+	(0x1d55f, &[0x006e]),
+	(0x1d560, &[0x006f]),
+	(0x1d561, &[0x0070]),
+	(0x1d562, &[0x0071]),
+	(0x1d563, &[0x0072]),
+	(0x1d564, &[0x0073]),
+	(0x1d565, &[0x0074]),
+	(0x1d566, &[0x0075]),
+	(0x1d567, &[0x0076]),
+	(0x1d568, &[0x0077]),
+	(0x1d569, &[0x0078]),
+	(0x1d56a, &[0x0079]),
+	(0x1d56b, &[0x007a]),
+	(0x1d56c, &[0x0041]),
+	(0x1d56d, &[0x0042]),
+	(0x1d56e, &[0x0043]),
+	(0x1d56f, &[0x0044]),
+	(0x1d570, &[0x0045]),
+	(0x1d571, &[0x0046]),
+	(0x1d572, &[0x0047]),
+	(0x1d573, &[0x0048]),
+	(0x1d574, &[0x006c]),
+	(0x1d575, &[0x004a]),
+	(0x1d576, &[0x004b]),
+	(0x1d577, &[0x004c]),
+	(0x1d578, &[0x004d]),
+	(0x1d579, &[0x004e]),
+	(0x1d57a, &[0x004f]),
+	(0x1d57b, &[0x0050]),
+	(0x1d57c, &[0x0051]),
+	(0x1d57d, &[0x0052]),
+	(0x1d57e, &[0x0053]),
+	(0x1d57f, &[0x0054]),
+	(0x1d580, &[0x0055]),
+	(0x1d581, &[0x0056]),
+	(0x1d582, &[0x0057]),
+	(0x1d583, &[0x0058]),
+	(0x1d584, &[0x0059]),
+	(0x1d585, &[0x005a]),
+	(0x1d586, &[0x0061]),
+	(0x1d587, &[0x0062]),
+	(0x1d588, &[0x0063]),
+	(0x1d589, &[0x0064]),
+	(0x1d58a, &[0x0065]),
+	(0x1d58b, &[0x0066]),
+	(0x1d58c, &[0x0067]),
+	(0x1d58d, &[0x0068]),
+	(0x1d58e, &[0x0069]),
+	(0x1d58f, &[0x006a]),
+	(0x1d590, &[0x006b]),
+	(0x1d591, &[0x006c]),
+	(0x1d592, &[0x0072, 0x006e]),
+	(0x1d593, &[0x006e]),
+	(0x1d594, &[0x006f]),
+	(0x1d595, &[0x0070]),
+	(0x1d596, &[0x0071]),
+	(0x1d597, &[0x0072]),
+	(0x1d598, &[0x0073]),
+	(0x1d599, &[0x0074]),
+	(0x1d59a, &[0x0075]),
+	(0x1d59b, &[0x0076]),
+	(0x1d59c, &[0x0077]),
+	(0x1d59d, &[0x0078]),
+	(0x1d59e, &[0x0079]),
+	(0x1d59f, &[0x007a]),
+	(0x1d5a0, &[0x0041]),
+	(0x1d5a1, &[0x0042]),
+	(0x1d5a2, &[0x0043]),
+	(0x1d5a3, &[0x0044]),
+	(0x1d5a4, &[0x0045]),
+	(0x1d5a5, &[0x0046]),
+	(0x1d5a6, &[0x0047]),
+	(0x1d5a7, &[0x0048]),
+	(0x1d5a8, &[0x006c]),
+	(0x1d5a9, &[0x004a]),
+	(0x1d5aa, &[0x004b]),
+	(0x1d5ab, &[0x004c]),
+	(0x1d5ac, &[0x004d]),
+	(0x1d5ad, &[0x004e]),
+	(0x1d5ae, &[0x004f]),
+	(0x1d5af, &[0x0050]),
+	(0x1d5b0, &[0x0051]),
+	(0x1d5b1, &[0x0052]),
+	(0x1d5b2, &[0x0053]),
+	(0x1d5b3, &[0x0054]),
+	(0x1d5b4, &[0x0055]),
+	(0x1d5b5, &[0x0056]),
+	(0x1d5b6, &[0x0057]),
+	(0x1d5b7, &[0x0058]),
+	(0x1d5b8, &[0x0059]),
+	(0x1d5b9, &[0x005a]),
+	(0x1d5ba, &[0x0061]),
+	(0x1d5bb, &[0x0062]),
+	(0x1d5bc, &[0x0063]),
+	(0x1d5bd, &[0x0064]),
+	(0x1d5be, &[0x0065]),
+	(0x1d5bf, &[0x0066]),
+	(0x1d5c0, &[0x0067]),
+	(0x1d5c1, &[0x0068]),
+	(0x1d5c2, &[0x0069]),
+	(0x1d5c3, &[0x006a]),
+	(0x1d5c4, &[0x006b]),
+	(0x1d5c5, &[0x006c]),
+	(0x1d5c6, &[0x0072, 0x006e]),
+	(0x1d5c7, &[0x006e]),
+	(0x1d5c8, &[0x006f]),
+	(0x1d5c9, &[0x0070]),
+	(0x1d5ca, &[0x0071]),
+	(0x1d5cb, &[0x0072]),
+	(0x1d5cc, &[0x0073]),
+	(0x1d5cd, &[0x0074]),
+	(0x1d5ce, &[0x0075]),
+	(0x1d5cf, &[0x0076]),
+	(0x1d5d0, &[0x0077]),
+	(0x1d5d1, &[0x0078]),
+	(0x1d5d2, &[0x0079]),
+	(0x1d5d3, &[0x007a]),
+	(0x1d5d4, &[0x0041]),
+	(0x1d5d5, &[0x0042]),
+	(0x1d5d6, &[0x0043]),
+	(0x1d5d7, &[0x0044]),
+	(0x1d5d8, &[0x0045]),
+	(0x1d5d9, &[0x0046]),
+	(0x1d5da, &[0x0047]),
+	(0x1d5db, &[0x0048]),
+	(0x1d5dc, &[0x006c]),
+	(0x1d5dd, &[0x004a]),
+	(0x1d5de, &[0x004b]),
+	(0x1d5df, &[0x004c]),
+	(0x1d5e0, &[0x004d]),
+	(0x1d5e1, &[0x004e]),
+	(0x1d5e2, &[0x004f]),
+	(0x1d5e3, &[0x0050]),
+	(0x1d5e4, &[0x0051]),
+	(0x1d5e5, &[0x0052]),
+	(0x1d5e6, &[0x0053]),
+	(0x1d5e7, &[0x0054]),
+	(0x1d5e8, &[0x0055]),
+	(0x1d5e9, &[0x0056]),
+	(0x1d5ea, &[0x0057]),
+	(0x1d5eb, &[0x0058]),
+	(0x1d5ec, &[0x0059]),
+	(0x1d5ed, &[0x005a]),
+	(0x1d5ee, &[0x0061]),
+	(0x1d5ef, &[0x0062]),
+	(0x1d5f0, &[0x0063]),
+	(0x1d5f1, &[0x0064]),
+	(0x1d5f2, &[0x0065]),
+	(0x1d5f3, &[0x0066]),
+	(0x1d5f4, &[0x0067]),
+	(0x1d5f5, &[0x0068]),
+	(0x1d5f6, &[0x0069]),
+	(0x1d5f7, &[0x006a]),
+	(0x1d5f8, &[0x006b]),
+	(0x1d5f9, &[0x006c]),
+	(0x1d5fa, &[0x0072, 0x006e]),
+	(0x1d5fb, &[0x006e]),
+	(0x1d5fc, &[0x006f]),
+	(0x1d5fd, &[0x0070]),
+	(0x1d5fe, &[0x0071]),
+	(0x1d5ff, &[0x0072]),
+	(0x1d600, &[0x0073]),
+	(0x1d601, &[0x0074]),
+	(0x1d602, &[0x0075]),
+	(0x1d603, &[0x0076]),
+	(0x1d604, &[0x0077]),
+	(0x1d605, &[0x0078]),
+	(0x1d606, &[0x0079]),
+	(0x1d607, &[0x007a]),
+	(0x1d608, &[0x0041]),
+	(0x1d609, &[0x0042]),
+	(0x1d60a, &[0x0043]),
+	(0x1d60b, &[0x0044]),
+	(0x1d60c, &[0x0045]),
+	(0x1d60d, &[0x0046]),
+	(0x1d60e, &[0x0047]),
+	(0x1d60f, &[0x0048]),
+	(0x1d610, &[0x006c]),
+	(0x1d611, &[0x004a]),
+	(0x1d612, &[0x004b]),
+	(0x1d613, &[0x004c]),
+	(0x1d614, &[0x004d]),
+	(0x1d615, &[0x004e]),
+	(0x1d616, &[0x004f]),
+	(0x1d617, &[0x0050]),
+	(0x1d618, &[0x0051]),
+	(0x1d619, &[0x0052]),
+	(0x1d61a, &[0x0053]),
+	(0x1d61b, &[0x0054]),
+	(0x1d61c, &[0x0055]),
+	(0x1d61d, &[0x0056]),
+	(0x1d61e, &[0x0057]),
+	(0x1d61f, &[0x0058]),
+	(0x1d620, &[0x0059]),
+	(0x1d621, &[0x005a]),
+	(0x1d622, &[0x0061]),
+	(0x1d623, &[0x0062]),
+	(0x1d624, &[0x0063]),
+	(0x1d625, &[0x0064]),
+	(0x1d626, &[0x0065]),
+	(0x1d627, &[0x0066]),
+	(0x1d628, &[0x0067]),
+	(0x1d629, &[0x0068]),
+	(0x1d62a, &[0x0069]),
+	(0x1d62b, &[0x006a]),
+	(0x1d62c, &[0x006b]),
+	(0x1d62d, &[0x006c]),
+	(0x1d62e, &[0x0072, 0x006e]),
+	(0x1d62f, &[0x006e]),
+	(0x1d630, &[0x006f]),
+	(0x1d631, &[0x0070]),
+	(0x1d632, &[0x0071]),
+	(0x1d633, &[0x0072]),
+	(0x1d634, &[0x0073]),
+	(0x1d635, &[0x0074]),
+	(0x1d636, &[0x0075]),
+	(0x1d637, &[0x0076]),
+	(0x1d638, &[0x0077]),
+	(0x1d639, &[0x0078]),
+	(0x1d63a, &[0x0079]),
+	(0x1d63b, &[0x007a]),
+	(0x1d63c, &[0x0041]),
+	(0x1d63d, &[0x0042]),
+	(0x1d63e, &[0x0043]),
+	(0x1d63f, &[0x0044]),
+	(0x1d640, &[0x0045]),
+	(0x1d641, &[0x0046]),
+	(0x1d642, &[0x0047]),
+	(0x1d643, &[0x0048]),
+	(0x1d644, &[0x006c]),
+	(0x1d645, &[0x004a]),
+	(0x1d646, &[0x004b]),
+	(0x1d647, &[0x004c]),
+	(0x1d648, &[0x004d]),
+	(0x1d649, &[0x004e]),
+	(0x1d64a, &[0x004f]),
+	(0x1d64b, &[0x0050]),
+	(0x1d64c, &[0x0051]),
+	(0x1d64d, &[0x0052]),
+	(0x1d64e, &[0x0053]),
+	(0x1d64f, &[0x0054]),
+	(0x1d650, &[0x0055]),
+	(0x1d651, &[0x0056]),
+	(0x1d652, &[0x0057]),
+	(0x1d653, &[0x0058]),
+	(0x1d654, &[0x0059]),
+	(0x1d655, &[0x005a]),
+	(0x1d656, &[0x0061]),
+	(0x1d657, &[0x0062]),
+	(0x1d658, &[0x0063]),
+	(0x1d659, &[0x0064]),
+	(0x1d65a, &[0x0065]),
+	(0x1d65b, &[0x0066]),
+	(0x1d65c, &[0x0067]),
+	(0x1d65d, &[0x0068]),
+	(0x1d65e, &[0x0069]),
+	(0x1d65f, &[0x006a]),
+	(0x1d660, &[0x006b]),
+	(0x1d661, &[0x006c]),
+	(0x1d662, &[0x0072, 0x006e]),
+	(0x1d663, &[0x006e]),
+	(0x1d664, &[0x006f]),
+	(0x1d665, &[0x0070]),
+	(0x1d666, &[0x0071]),
+	(0x1d667, &[0x0072]),
+	(0x1d668, &[0x0073]),
+	(0x1d669, &[0x0074]),
+	(0x1d66a, &[0x0075]),
+	(0x1d66b, &[0x0076]),
+	(0x1d66c, &[0x0077]),
+	(0x1d66d, &[0x0078]),
+	(0x1d66e, &[0x0079]),
+	(0x1d66f, &[0x007a]),
+	(0x1d670, &[0x0041]),
+	(0x1d671, &[0x0042]),
+	(0x1d672, &[0x0043]),
+	(0x1d673, &[0x0044]),
+	(0x1d674, &[0x0045]),
+	(0x1d675, &[0x0046]),
+	(0x1d676, &[0x0047]),
+	(0x1d677, &[0x0048]),
+	(0x1d678, &[0x006c]),
+	(0x1d679, &[0x004a]),
+	(0x1d67a, &[0x004b]),
+	(0x1d67b, &[0x004c]),
+	(0x1d67c, &[0x004d]),
+	(0x1d67d, &[0x004e]),
+	(0x1d67e, &[0x004f]),
+	(0x1d67f, &[0x0050]),
+	(0x1d680, &[0x0051]),
+	(0x1d681, &[0x0052]),
+	(0x1d682, &[0x0053]),
+	(0x1d683, &[0x0054]),
+	(0x1d684, &[0x0055]),
+	(0x1d685, &[0x0056]),
+	(0x1d686, &[0x0057]),
+	(0x1d687, &[0x0058]),
+	(0x1d688, &[0x0059]),
+	(0x1d689, &[0x005a]),
+	(0x1d68a, &[0x0061]),
+	(0x1d68b, &[0x0062]),
+	(0x1d68c, &[0x0063]),
+	(0x1d68d, &[0x0064]),
+	(0x1d68e, &[0x0065]),
+	(0x1d68f, &[0x0066]),
+	(0x1d690, &[0x0067]),
+	(0x1d691, &[0x0068]),
+	(0x1d692, &[0x0069]),
+	(0x1d693, &[0x006a]),
+	(0x1d694, &[0x006b]),
+	(0x1d695, &[0x006c]),
+	(0x1d696, &[0x0072, 0x006e]),
+	(0x1d697, &[0x006e]),
+	(0x1d698, &[0x006f]),
+	(0x1d699, &[0x0070]),
+	(0x1d69a, &[0x0071]),
+	(0x1d69b, &[0x0072]),
+	(0x1d69c, &[0x0073]),
+	(0x1d69d, &[0x0074]),
+	(0x1d69e, &[0x0075]),
+	(0x1d69f, &[0x0076]),
+	(0x1d6a0, &[0x0077]),
+	(0x1d6a1, &[0x0078]),
+	(0x1d6a2, &[0x0079]),
+	(0x1d6a3, &[0x007a]),
+	(0x1d6a4, &[0x0069]),
+	(0x1d6a5, &[0x0237]),
+	(0x1d6a8, &[0x0041]),
+	(0x1d6a9, &[0x0042]),
+	(0x1d6aa, &[0x0393]),
+	(0x1d6ab, &[0x0394]),
+	(0x1d6ac, &[0x0045]),
+	(0x1d6ad, &[0x005a]),
+	(0x1d6ae, &[0x0048]),
+	(0x1d6af, &[0x004f, 0x0335]),
+	(0x1d6b0, &[0x006c]),
+	(0x1d6b1, &[0x004b]),
+	(0x1d6b2, &[0x0245]),
+	(0x1d6b3, &[0x004d]),
+	(0x1d6b4, &[0x004e]),
+	(0x1d6b5, &[0x039e]),
+	(0x1d6b6, &[0x004f]),
+	(0x1d6b7, &[0x03a0]),
+	(0x1d6b8, &[0x0050]),
+	(0x1d6b9, &[0x004f, 0x0335]),
+	(0x1d6ba, &[0x01a9]),
+	(0x1d6bb, &[0x0054]),
+	(0x1d6bc, &[0x0059]),
+	(0x1d6bd, &[0x03a6]),
+	(0x1d6be, &[0x0058]),
+	(0x1d6bf, &[0x03a8]),
+	(0x1d6c0, &[0x03a9]),
+	(0x1d6c1, &[0x2207]),
+	(0x1d6c2, &[0x0061]),
+	(0x1d6c3, &[0x00df]),
+	(0x1d6c4, &[0x0079]),
+	(0x1d6c5, &[0x1e9f]),
+	(0x1d6c6, &[0xa793]),
+	(0x1d6c7, &[0x03b6]),
+	(0x1d6c8, &[0x006e, 0x0329]),
+	(0x1d6c9, &[0x004f, 0x0335]),
+	(0x1d6ca, &[0x0069]),
+	(0x1d6cb, &[0x0138]),
+	(0x1d6cc, &[0x03bb]),
+	(0x1d6cd, &[0x03bc]),
+	(0x1d6ce, &[0x0076]),
+	(0x1d6cf, &[0x03be]),
+	(0x1d6d0, &[0x006f]),
+	(0x1d6d1, &[0x03c0]),
+	(0x1d6d2, &[0x0070]),
+	(0x1d6d3, &[0x03c2]),
+	(0x1d6d4, &[0x006f]),
+	(0x1d6d5, &[0x1d1b]),
+	(0x1d6d6, &[0x0075]),
+	(0x1d6d7, &[0x0278]),
+	(0x1d6d8, &[0x03c7]),
+	(0x1d6d9, &[0x03c8]),
+	(0x1d6da, &[0x03c9]),
+	(0x1d6db, &[0x2202]),
+	(0x1d6dc, &[0xa793]),
+	(0x1d6dd, &[0x004f, 0x0335]),
+	(0x1d6de, &[0x0138]),
+	(0x1d6df, &[0x0278]),
+	(0x1d6e0, &[0x0070]),
+	(0x1d6e1, &[0x03c0]),
+	(0x1d6e2, &[0x0041]),
+	(0x1d6e3, &[0x0042]),
+	(0x1d6e4, &[0x0393]),
+	(0x1d6e5, &[0x0394]),
+	(0x1d6e6, &[0x0045]),
+	(0x1d6e7, &[0x005a]),
+	(0x1d6e8, &[0x0048]),
+	(0x1d6e9, &[0x004f, 0x0335]),
+	(0x1d6ea, &[0x006c]),
+	(0x1d6eb, &[0x004b]),
+	(0x1d6ec, &[0x0245]),
+	(0x1d6ed, &[0x004d]),
+	(0x1d6ee, &[0x004e]),
+	(0x1d6ef, &[0x039e]),
+	(0x1d6f0, &[0x004f]),
+	(0x1d6f1, &[0x03a0]),
+	(0x1d6f2, &[0x0050]),
+	(0x1d6f3, &[0x004f, 0x0335]),
+	(0x1d6f4, &[0x01a9]),
+	(0x1d6f5, &[0x0054]),
+	(0x1d6f6, &[0x0059]),
+	(0x1d6f7, &[0x03a6]),
+	(0x1d6f8, &[0x0058]),
+	(0x1d6f9, &[0x03a8]),
+	(0x1d6fa, &[0x03a9]),
+	(0x1d6fb, &[0x2207]),
+	(0x1d6fc, &[0x0061]),
+	(0x1d6fd, &[0x00df]),
+	(0x1d6fe, &[0x0079]),
+	(0x1d6ff, &[0x1e9f]),
+	(0x1d700, &[0xa793]),
+	(0x1d701, &[0x03b6]),
+	(0x1d702, &[0x006e, 0x0329]),
+	(0x1d703, &[0x004f, 0x0335]),
+	(0x1d704, &[0x0069]),
+	(0x1d705, &[0x0138]),
+	(0x1d706, &[0x03bb]),
+	(0x1d707, &[0x03bc]),
+	(0x1d708, &[0x0076]),
+	(0x1d709, &[0x03be]),
+	(0x1d70a, &[0x006f]),
+	(0x1d70b, &[0x03c0]),
+	(0x1d70c, &[0x0070]),
+	(0x1d70d, &[0x03c2]),
+	(0x1d70e, &[0x006f]),
+	(0x1d70f, &[0x1d1b]),
+	(0x1d710, &[0x0075]),
+	(0x1d711, &[0x0278]),
+	(0x1d712, &[0x03c7]),
+	(0x1d713, &[0x03c8]),
+	(0x1d714, &[0x03c9]),
+	(0x1d715, &[0x2202]),
+	(0x1d716, &[0xa793]),
+	(0x1d717, &[0x004f, 0x0335]),
+	(0x1d718, &[0x0138]),
+	(0x1d719, &[0x0278]),
+	(0x1d71a, &[0x0070]),
+	(0x1d71b, &[0x03c0]),
+	(0x1d71c, &[0x0041]),
+	(0x1d71d, &[0x0042]),
+	(0x1d71e, &[0x0393]),
+	(0x1d71f, &[0x0394]),
+	(0x1d720, &[0x0045]),
+	(0x1d721, &[0x005a]),
+	(0x1d722, &[0x0048]),
+	(0x1d723, &[0x004f, 0x0335]),
+	(0x1d724, &[0x006c]),
+	(0x1d725, &[0x004b]),
+	(0x1d726, &[0x0245]),
+	(0x1d727, &[0x004d]),
+	(0x1d728, &[0x004e]),
+	(0x1d729, &[0x039e]),
+	(0x1d72a, &[0x004f]),
+	(0x1d72b, &[0x03a0]),
+	(0x1d72c, &[0x0050]),
+	(0x1d72d, &[0x004f, 0x0335]),
+	(0x1d72e, &[0x01a9]),
+	(0x1d72f, &[0x0054]),
+	(0x1d730, &[0x0059]),
+	(0x1d731, &[0x03a6]),
+	(0x1d732, &[0x0058]),
+	(0x1d733, &[0x03a8]),
+	(0x1d734, &[0x03a9]),
+	(0x1d735, &[0x2207]),
+	(0x1d736, &[0x0061]),
+	(0x1d737, &[0x00df]),
+	(0x1d738, &[0x0079]),
+	(0x1d739, &[0x1e9f]),
+	(0x1d73a, &[0xa793]),
+	(0x1d73b, &[0x03b6]),
+	(0x1d73c, &[0x006e, 0x0329]),
+	(0x1d73d, &[0x004f, 0x0335]),
+	(0x1d73e, &[0x0069]),
+	(0x1d73f, &[0x0138]),
+	(0x1d740, &[0x03bb]),
+	(0x1d741, &[0x03bc]),
+	(0x1d742, &[0x0076]),
+	(0x1d743, &[0x03be]),
+	(0x1d744, &[0x006f]),
+	(0x1d745, &[0x03c0]),
+	(0x1d746, &[0x0070]),
+	(0x1d747, &[0x03c2]),
+	(0x1d748, &[0x006f]),
+	(0x1d749, &[0x1d1b]),
+	(0x1d74a, &[0x0075]),
+	(0x1d74b, &[0x0278]),
+	(0x1d74c, &[0x03c7]),
+	(0x1d74d, &[0x03c8]),
+	(0x1d74e, &[0x03c9]),
+	(0x1d74f, &[0x2202]),
+	(0x1d750, &[0xa793]),
+	(0x1d751, &[0x004f, 0x0335]),
+	(0x1d752, &[0x0138]),
+	(0x1d753, &[0x0278]),
+	(0x1d754, &[0x0070]),
+	(0x1d755, &[0x03c0]),
+	(0x1d756, &[0x0041]),
+	(0x1d757, &[0x0042]),
+	(0x1d758, &[0x0393]),
+	(0x1d759, &[0x0394]),
+	(0x1d75a, &[0x0045]),
+	(0x1d75b, &[0x005a]),
+	(0x1d75c, &[0x0048]),
+	(0x1d75d, &[0x004f, 0x0335]),
+	(0x1d75e, &[0x006c]),
+	(0x1d75f, &[0x004b]),
+	(0x1d760, &[0x0245]),
+	(0x1d761, &[0x004d]),
+	(0x1d762, &[0x004e]),
+	(0x1d763, &[0x039e]),
+	(0x1d764, &[0x004f]),
+	(0x1d765, &[0x03a0]),
+	(0x1d766, &[0x0050]),
+	(0x1d767, &[0x004f, 0x0335]),
+	(0x1d768, &[0x01a9]),
+	(0x1d769, &[0x0054]),
+	(0x1d76a, &[0x0059]),
+	(0x1d76b, &[0x03a6]),
+	(0x1d76c, &[0x0058]),
+	(0x1d76d, &[0x03a8]),
+	(0x1d76e, &[0x03a9]),
+	(0x1d76f, &[0x2207]),
+	(0x1d770, &[0x0061]),
+	(0x1d771, &[0x00df]),
+	(0x1d772, &[0x0079]),
+	(0x1d773, &[0x1e9f]),
+	(0x1d774, &[0xa793]),
+	(0x1d775, &[0x03b6]),
+	(0x1d776, &[0x006e, 0x0329]),
+	(0x1d777, &[0x004f, 0x0335]),
+	(0x1d778, &[0x0069]),
+	(0x1d779, &[0x0138]),
+	(0x1d77a, &[0x03bb]),
+	(0x1d77b, &[0x03bc]),
+	(0x1d77c, &[0x0076]),
+	(0x1d77d, &[0x03be]),
+	(0x1d77e, &[0x006f]),
+	(0x1d77f, &[0x03c0]),
+	(0x1d780, &[0x0070]),
+	(0x1d781, &[0x03c2]),
+	(0x1d782, &[0x006f]),
+	(0x1d783, &[0x1d1b]),
+	(0x1d784, &[0x0075]),
+	(0x1d785, &[0x0278]),
+	(0x1d786, &[0x03c7]),
+	(0x1d787, &[0x03c8]),
+	(0x1d788, &[0x03c9]),
+	(0x1d789, &[0x2202]),
+	(0x1d78a, &[0xa793]),
+	(0x1d78b, &[0x004f, 0x0335]),
+	(0x1d78c, &[0x0138]),
+	(0x1d78d, &[0x0278]),
+	(0x1d78e, &[0x0070]),
+	(0x1d78f, &[0x03c0]),
+	(0x1d790, &[0x0041]),
+	(0x1d791, &[0x0042]),
+	(0x1d792, &[0x0393]),
+	(0x1d793, &[0x0394]),
+	(0x1d794, &[0x0045]),
+	(0x1d795, &[0x005a]),
+	(0x1d796, &[0x0048]),
+	(0x1d797, &[0x004f, 0x0335]),
+	(0x1d798, &[0x006c]),
+	(0x1d799, &[0x004b]),
+	(0x1d79a, &[0x0245]),
+	(0x1d79b, &[0x004d]),
+	(0x1d79c, &[0x004e]),
+	(0x1d79d, &[0x039e]),
+	(0x1d79e, &[0x004f]),
+	(0x1d79f, &[0x03a0]),
+	(0x1d7a0, &[0x0050]),
+	(0x1d7a1, &[0x004f, 0x0335]),
+	(0x1d7a2, &[0x01a9]),
+	(0x1d7a3, &[0x0054]),
+	(0x1d7a4, &[0x0059]),
+	(0x1d7a5, &[0x03a6]),
+	(0x1d7a6, &[0x0058]),
+	(0x1d7a7, &[0x03a8]),
+	(0x1d7a8, &[0x03a9]),
+	(0x1d7a9, &[0x2207]),
+	(0x1d7aa, &[0x0061]),
+	(0x1d7ab, &[0x00df]),
+	(0x1d7ac, &[0x0079]),
+	(0x1d7ad, &[0x1e9f]),
+	(0x1d7ae, &[0xa793]),
+	(0x1d7af, &[0x03b6]),
+	(0x1d7b0, &[0x006e, 0x0329]),
+	(0x1d7b1, &[0x004f, 0x0335]),
+	(0x1d7b2, &[0x0069]),
+	(0x1d7b3, &[0x0138]),
+	(0x1d7b4, &[0x03bb]),
+	(0x1d7b5, &[0x03bc]),
+	(0x1d7b6, &[0x0076]),
+	(0x1d7b7, &[0x03be]),
+	(0x1d7b8, &[0x006f]),
+	(0x1d7b9, &[0x03c0]),
+	(0x1d7ba, &[0x0070]),
+	(0x1d7bb, &[0x03c2]),
+	(0x1d7bc, &[0x006f]),
+	(0x1d7bd, &[0x1d1b]),
+	(0x1d7be, &[0x0075]),
+	(0x1d7bf, &[0x0278]),
+	(0x1d7c0, &[0x03c7]),
+	(0x1d7c1, &[0x03c8]),
+	(0x1d7c2, &[0x03c9]),
+	(0x1d7c3, &[0x2202]),
+	(0x1d7c4, &[0xa793]),
+	(0x1d7c5, &[0x004f, 0x0335]),
+	(0x1d7c6, &[0x0138]),
+	(0x1d7c7, &[0x0278]),
+	(0x1d7c8, &[0x0070]),
+	(0x1d7c9, &[0x03c0]),
+	(0x1d7ca, &[0x0046]),
+	(0x1d7cb, &[0x03dd]),
+	(0x1d7ce, &[0x004f]),
+	(0x1d7cf, &[0x006c]),
+	(0x1d7d0, &[0x0032]),
+	(0x1d7d1, &[0x0033]),
+	(0x1d7d2, &[0x0034]),
+	(0x1d7d3, &[0x0035]),
+	(0x1d7d4, &[0x0036]),
+	(0x1d7d5, &[0x0037]),
+	(0x1d7d6, &[0x0038]),
+	(0x1d7d7, &[0x0039]),
+	(0x1d7d8, &[0x004f]),
+	(0x1d7d9, &[0x006c]),
+	(0x1d7da, &[0x0032]),
+	(0x1d7db, &[0x0033]),
+	(0x1d7dc, &[0x0034]),
+	(0x1d7dd, &[0x0035]),
+	(0x1d7de, &[0x0036]),
+	(0x1d7df, &[0x0037]),
+	(0x1d7e0, &[0x0038]),
+	(0x1d7e1, &[0x0039]),
+	(0x1d7e2, &[0x004f]),
+	(0x1d7e3, &[0x006c]),
+	(0x1d7e4, &[0x0032]),
+	(0x1d7e5, &[0x0033]),
+	(0x1d7e6, &[0x0034]),
+	(0x1d7e7, &[0x0035]),
+	(0x1d7e8, &[0x0036]),
+	(0x1d7e9, &[0x0037]),
+	(0x1d7ea, &[0x0038]),
+	(0x1d7eb, &[0x0039]),
+	(0x1d7ec, &[0x004f]),
+	(0x1d7ed, &[0x006c]),
+	(0x1d7ee, &[0x0032]),
+	(0x1d7ef, &[0x0033]),
+	(0x1d7f0, &[0x0034]),
+	(0x1d7f1, &[0x0035]),
+	(0x1d7f2, &[0x0036]),
+	(0x1d7f3, &[0x0037]),
+	(0x1d7f4, &[0x0038]),
+	(0x1d7f5, &[0x0039]),
+	(0x1d7f6, &[0x004f]),
+	(0x1d7f7, &[0x006c]),
+	(0x1d7f8, &[0x0032]),
+	(0x1d7f9, &[0x0033]),
+	(0x1d7fa, &[0x0034]),
+	(0x1d7fb, &[0x0035]),
+	(0x1d7fc, &[0x0036]),
+	(0x1d7fd, &[0x0037]),
+	(0x1d7fe, &[0x0038]),
+	(0x1d7ff, &[0x0039]),
+	(0x1e8c7, &[0x006c]),
+	(0x1e8c8, &[0x2220]),
+	(0x1e8c9, &[0x0663]),
+	(0x1e8cb, &[0x0038]),
+	(0x1e8cc, &[0x2202]),
+	(0x1e8cd, &[0x2202, 0x0335]),
+	(0x1ee00, &[0x006c]),
+	(0x1ee01, &[0x0628]),
+	(0x1ee02, &[0x062c]),
+	(0x1ee03, &[0x062f]),
+	(0x1ee05, &[0x0648]),
+	(0x1ee06, &[0x0632]),
+	(0x1ee07, &[0x062d]),
+	(0x1ee08, &[0x0637]),
+	(0x1ee09, &[0x0649]),
+	(0x1ee0a, &[0x0643]),
+	(0x1ee0b, &[0x0644]),
+	(0x1ee0c, &[0x0645]),
+	(0x1ee0d, &[0x0646]),
+	(0x1ee0e, &[0x0633]),
+	(0x1ee0f, &[0x0639]),
+	(0x1ee10, &[0x0641]),
+	(0x1ee11, &[0x0635]),
+	(0x1ee12, &[0x0642]),
+	(0x1ee13, &[0x0631]),
+	(0x1ee14, &[0x0633, 0x06db]),
+	(0x1ee15, &[0x062a]),
+	(0x1ee16, &[0x0649, 0x06db]),
+	(0x1ee17, &[0x062e]),
+	(0x1ee18, &[0x0630]),
+	(0x1ee19, &[0x0636]),
+	(0x1ee1a, &[0x0638]),
+	(0x1ee1b, &[0x063a]),
+	(0x1ee1c, &[0x0649]),
+	(0x1ee1d, &[0x0649]),
+	(0x1ee1e, &[0x06a1]),
+	(0x1ee1f, &[0x06a1]),
+	(0x1ee21, &[0x0628]),
+	(0x1ee22, &[0x062c]),
+	(0x1ee24, &[0x006f]),
+	(0x1ee27, &[0x062d]),
+	(0x1ee29, &[0x0649]),
+	(0x1ee2a, &[0x0643]),
+	(0x1ee2b, &[0x0644]),
+	(0x1ee2c, &[0x0645]),
+	(0x1ee2d, &[0x0646]),
+	(0x1ee2e, &[0x0633]),
+	(0x1ee2f, &[0x0639]),
+	(0x1ee30, &[0x0641]),
+	(0x1ee31, &[0x0635]),
+	(0x1ee32, &[0x0642]),
+	(0x1ee34, &[0x0633, 0x06db]),
+	(0x1ee35, &[0x062a]),
+	(0x1ee36, &[0x0649, 0x06db]),
+	(0x1ee37, &[0x062e]),
+	(0x1ee39, &[0x0636]),
+	(0x1ee3b, &[0x063a]),
+	(0x1ee42, &[0x062c]),
+	(0x1ee47, &[0x062d]),
+	(0x1ee49, &[0x0649]),
+	(0x1ee4b, &[0x0644]),
+	(0x1ee4d, &[0x0646]),
+	(0x1ee4e, &[0x0633]),
+	(0x1ee4f, &[0x0639]),
+	(0x1ee51, &[0x0635]),
+	(0x1ee52, &[0x0642]),
+	(0x1ee54, &[0x0633, 0x06db]),
+	(0x1ee57, &[0x062e]),
+	(0x1ee59, &[0x0636]),
+	(0x1ee5b, &[0x063a]),
+	(0x1ee5d, &[0x0649]),
+	(0x1ee5f, &[0x06a1]),
+	(0x1ee61, &[0x0628]),
+	(0x1ee62, &[0x062c]),
+	(0x1ee64, &[0x006f]),
+	(0x1ee67, &[0x062d]),
+	(0x1ee68, &[0x0637]),
+	(0x1ee69, &[0x0649]),
+	(0x1ee6a, &[0x0643]),
+	(0x1ee6c, &[0x0645]),
+	(0x1ee6d, &[0x0646]),
+	(0x1ee6e, &[0x0633]),
+	(0x1ee6f, &[0x0639]),
+	(0x1ee70, &[0x0641]),
+	(0x1ee71, &[0x0635]),
+	(0x1ee72, &[0x0642]),
+	(0x1ee74, &[0x0633, 0x06db]),
+	(0x1ee75, &[0x062a]),
+	(0x1ee76, &[0x0649, 0x06db]),
+	(0x1ee77, &[0x062e]),
+	(0x1ee79, &[0x0636]),
+	(0x1ee7a, &[0x0638]),
+	(0x1ee7b, &[0x063a]),
+	(0x1ee7c, &[0x0649]),
+	(0x1ee7e, &[0x06a1]),
+	(0x1ee80, &[0x006c]),
+	(0x1ee81, &[0x0628]),
+	(0x1ee82, &[0x062c]),
+	(0x1ee83, &[0x062f]),
+	(0x1ee84, &[0x006f]),
+	(0x1ee85, &[0x0648]),
+	(0x1ee86, &[0x0632]),
+	(0x1ee87, &[0x062d]),
+	(0x1ee88, &[0x0637]),
+	(0x1ee89, &[0x0649]),
+	(0x1ee8b, &[0x0644]),
+	(0x1ee8c, &[0x0645]),
+	(0x1ee8d, &[0x0646]),
+	(0x1ee8e, &[0x0633]),
+	(0x1ee8f, &[0x0639]),
+	(0x1ee90, &[0x0641]),
+	(0x1ee91, &[0x0635]),
+	(0x1ee92, &[0x0642]),
+	(0x1ee93, &[0x0631]),
+	(0x1ee94, &[0x0633, 0x06db]),
+	(0x1ee95, &[0x062a]),
+	(0x1ee96, &[0x0649, 0x06db]),
+	(0x1ee97, &[0x062e]),
+	(0x1ee98, &[0x0630]),
+	(0x1ee99, &[0x0636]),
+	(0x1ee9a, &[0x0638]),
+	(0x1ee9b, &[0x063a]),
+	(0x1eea1, &[0x0628]),
+	(0x1eea2, &[0x062c]),
+	(0x1eea3, &[0x062f]),
+	(0x1eea5, &[0x0648]),
+	(0x1eea6, &[0x0632]),
+	(0x1eea7, &[0x062d]),
+	(0x1eea8, &[0x0637]),
+	(0x1eea9, &[0x0649]),
+	(0x1eeab, &[0x0644]),
+	(0x1eeac, &[0x0645]),
+	(0x1eead, &[0x0646]),
+	(0x1eeae, &[0x0633]),
+	(0x1eeaf, &[0x0639]),
+	(0x1eeb0, &[0x0641]),
+	(0x1eeb1, &[0x0635]),
+	(0x1eeb2, &[0x0642]),
+	(0x1eeb3, &[0x0631]),
+	(0x1eeb4, &[0x0633, 0x06db]),
+	(0x1eeb5, &[0x062a]),
+	(0x1eeb6, &[0x0649, 0x06db]),
+	(0x1eeb7, &[0x062e]),
+	(0x1eeb8, &[0x0630]),
+	(0x1eeb9, &[0x0636]),
+	(0x1eeba, &[0x0638]),
+	(0x1eebb, &[0x063a]),
+	(0x1f100, &[0x004f, 0x002e]),
+	(0x1f101, &[0x004f, 0x002c]),
+	(0x1f102, &[0x006c, 0x002c]),
+	(0x1f103, &[0x0032, 0x002c]),
+	(0x1f104, &[0x0033, 0x002c]),
+	(0x1f105, &[0x0034, 0x002c]),
+	(0x1f106, &[0x0035, 0x002c]),
+	(0x1f107, &[0x0036, 0x002c]),
+	(0x1f108, &[0x0037, 0x002c]),
+	(0x1f109, &[0x0038, 0x002c]),
+	(0x1f10a, &[0x0039, 0x002c]),
+	(0x1f10f, &[0x0024, 0x20e0]),
+	(0x1f110, &[0x0028, 0x0041, 0x0029]),
+	(0x1f111, &[0x0028, 0x0042, 0x0029]),
+	(0x1f112, &[0x0028, 0x0043, 0x0029]),
+	(0x1f113, &[0x0028, 0x0044, 0x0029]),
+	(0x1f114, &[0x0028, 0x0045, 0x0029]),
+	(0x1f115, &[0x0028, 0x0046, 0x0029]),
+	(0x1f116, &[0x0028, 0x0047, 0x0029]),
+	(0x1f117, &[0x0028, 0x0048, 0x0029]),
+	(0x1f118, &[0x0028, 0x006c, 0x0029]),
+	(0x1f119, &[0x0028, 0x004a, 0x0029]),
+	(0x1f11a, &[0x0028, 0x004b, 0x0029]),
+	(0x1f11b, &[0x0028, 0x004c, 0x0029]),
+	(0x1f11c, &[0x0028, 0x004d, 0x0029]),
+	(0x1f11d, &[0x0028, 0x004e, 0x0029]),
+	(0x1f11e, &[0x0028, 0x004f, 0x0029]),
+	(0x1f11f, &[0x0028, 0x0050, 0x0029]),
+	(0x1f120, &[0x0028, 0x0051, 0x0029]),
+	(0x1f121, &[0x0028, 0x0052, 0x0029]),
+	(0x1f122, &[0x0028, 0x0053, 0x0029]),
+	(0x1f123, &[0x0028, 0x0054, 0x0029]),
+	(0x1f124, &[0x0028, 0x0055, 0x0029]),
+	(0x1f125, &[0x0028, 0x0056, 0x0029]),
+	(0x1f126, &[0x0028, 0x0057, 0x0029]),
+	(0x1f127, &[0x0028, 0x0058, 0x0029]),
+	(0x1f128, &[0x0028, 0x0059, 0x0029]),
+	(0x1f129, &[0x0028, 0x005a, 0x0029]),
+	(0x1f12a, &[0x0028, 0x0053, 0x0029]),
+	(0x1f16d, &[0x33c4, 0x0009, 0x20dd]),
+	(0x1f16e, &[0x0043, 0x20e0]),
+	(0x1f240, &[0x0028, 0x672c, 0x0029]),
+	(0x1f241, &[0x0028, 0x4e09, 0x0029]),
+	(0x1f242, &[0x0028, 0x4e8c, 0x0029]),
+	(0x1f243, &[0x0028, 0x5b89, 0x0029]),
+	(0x1f244, &[0x0028, 0x70b9, 0x0029]),
+	(0x1f245, &[0x0028, 0x6253, 0x0029]),
+	(0x1f246, &[0x0028, 0x76d7, 0x0029]),
+	(0x1f247, &[0x0028, 0x52dd, 0x0029]),
+	(0x1f248, &[0x0028, 0x6557, 0x0029]),
+	(0x1f312, &[0x263d]),
+	(0x1f318, &[0x263e]),
+	(0x1f319, &[0x263d]),
+	(0x1f700, &[0x0051, 0x0045]),
+	(0x1f701, &[0xa658]),
+	(0x1f702, &[0x0394]),
+	(0x1f704, &[0x102bc]),
+	(0x1f707, &[0x0041, 0x0052]),
+	(0x1f708, &[0x0056, 0x1de4]),
+	(0x1f70a, &[0x2629]),
+	(0x1f714, &[0x004f, 0x0335]),
+	(0x1f728, &[0x102a8]),
+	(0x1f73a, &[0x29df]),
+	(0x1f74c, &[0x0043]),
+	(0x1f754, &[0x16dc]),
+	(0x1f755, &[0x22a1]),
+	(0x1f75c, &[0x0073, 0x0073, 0x0073]),
+	(0x1f75e, &[0x224f]),
+	(0x1f768, &[0x0054]),
+	(0x1f76b, &[0x004d, 0x0042]),
+	(0x1f76c, &[0x0056, 0x0042]),
+	(0x1f771, &[0x22a0]),
+	(0x1fbf0, &[0x004f]),
+	(0x1fbf1, &[0x006c]),
+	(0x1fbf2, &[0x0032]),
+	(0x1fbf3, &[0x0033]),
+	(0x1fbf4, &[0x0034]),
+	(0x1fbf5, &[0x0035]),
+	(0x1fbf6, &[0x0036]),
+	(0x1fbf7, &[0x0037]),
+	(0x1fbf8, &[0x0038]),
+	(0x1fbf9, &[0x0039]),
+	(0x21fe8, &[0x276c]),
+	(0x2f800, &[0x4e3d]),
+	(0x2f801, &[0x4e38]),
+	(0x2f802, &[0x4e41]),
+	(0x2f803, &[0x20122]),
+	(0x2f804, &[0x4f60]),
+	(0x2f805, &[0x4fae]),
+	(0x2f806, &[0x4fbb]),
+	(0x2f807, &[0x4f75]),
+	(0x2f808, &[0x507a]),
+	(0x2f809, &[0x5099]),
+	(0x2f80a, &[0x50e7]),
+	(0x2f80b, &[0x50cf]),
+	(0x2f80c, &[0x349e]),
+	(0x2f80d, &[0x2063a]),
+	(0x2f80e, &[0x514d]),
+	(0x2f80f, &[0x5154]),
+	(0x2f810, &[0x5164]),
+	(0x2f811, &[0x5177]),
+	(0x2f812, &[0x2051c]),
+	(0x2f813, &[0x34b9]),
+	(0x2f814, &[0x5167]),
+	(0x2f815, &[0x518d]),
+	(0x2f816, &[0x2054b]),
+	(0x2f817, &[0x5197]),
+	(0x2f818, &[0x51a4]),
+	(0x2f819, &[0x4ecc]),
+	(0x2f81a, &[0x51ac]),
+	(0x2f81b, &[0x51b5]),
+	(0x2f81c, &[0x291df]),
+	(0x2f81d, &[0x51f5]),
+	(0x2f81e, &[0x5203]),
+	(0x2f81f, &[0x34df]),
+	(0x2f820, &[0x523b]),
+	(0x2f821, &[0x5246]),
+	(0x2f822, &[0x5272]),
+	(0x2f823, &[0x5277]),
+	(0x2f824, &[0x3515]),
+	(0x2f825, &[0x52c7]),
+	(0x2f826, &[0x52c9]),
+	(0x2f827, &[0x52e4]),
+	(0x2f828, &[0x52fa]),
+	(0x2f829, &[0x5305]),
+	(0x2f82a, &[0x5306]),
+	(0x2f82b, &[0x5317]),
+	(0x2f82c, &[0x5349]),
+	(0x2f82d, &[0x5351]),
+	(0x2f82e, &[0x535a]),
+	(0x2f82f, &[0x5373]),
+	(0x2f830, &[0x537d]),
+	(0x2f831, &[0x537f]),
+	(0x2f832, &[0x537f]),
+	(0x2f833, &[0x537f]),
+	(0x2f834, &[0x20a2c]),
+	(0x2f835, &[0x7070]),
+	(0x2f836, &[0x53ca]),
+	(0x2f837, &[0x53df]),
+	(0x2f838, &[0x20b63]),
+	(0x2f839, &[0x53eb]),
+	(0x2f83a, &[0x53f1]),
+	(0x2f83b, &[0x5406]),
+	(0x2f83c, &[0x549e]),
+	(0x2f83d, &[0x5438]),
+	(0x2f83e, &[0x5448]),
+	(0x2f83f, &[0x5468]),
+	(0x2f840, &[0x54a2]),
+	(0x2f841, &[0x54f6]),
+	(0x2f842, &[0x5510]),
+	(0x2f843, &[0x5553]),
+	(0x2f844, &[0x5563]),
+	(0x2f845, &[0x5584]),
+	(0x2f846, &[0x5584]),
+	(0x2f847, &[0x5599]),
+	(0x2f848, &[0x55ab]),
+	(0x2f849, &[0x55b3]),
+	(0x2f84a, &[0x55c2]),
+	(0x2f84b, &[0x5716]),
+	(0x2f84c, &[0x5606]),
+	(0x2f84d, &[0x5717]),
+	(0x2f84e, &[0x5651]),
+	(0x2f84f, &[0x5674]),
+	(0x2f850, &[0x5207]),
+	(0x2f851, &[0x58ee]),
+	(0x2f852, &[0x57ce]),
+	(0x2f853, &[0x57f4]),
+	(0x2f854, &[0x580d]),
+	(0x2f855, &[0x578b]),
+	(0x2f856, &[0x5832]),
+	(0x2f857, &[0x5831]),
+	(0x2f858, &[0x58ac]),
+	(0x2f859, &[0x214e4]),
+	(0x2f85a, &[0x58f2]),
+	(0x2f85b, &[0x58f7]),
+	(0x2f85c, &[0x5906]),
+	(0x2f85d, &[0x591a]),
+	(0x2f85e, &[0x5922]),
+	(0x2f85f, &[0x5962]),
+	(0x2f860, &[0x216a8]),
+	(0x2f861, &[0x216ea]),
+	(0x2f862, &[0x59ec]),
+	(0x2f863, &[0x5a1b]),
+	(0x2f864, &[0x5a27]),
+	(0x2f865, &[0x59d8]),
+	(0x2f866, &[0x5a66]),
+	(0x2f867, &[0x36ee]),
+	(0x2f868, &[0x36fc]),
+	(0x2f869, &[0x5b08]),
+	(0x2f86a, &[0x5b3e]),
+	(0x2f86b, &[0x5b3e]),
+	(0x2f86c, &[0x219c8]),
+	(0x2f86d, &[0x5bc3]),
+	(0x2f86e, &[0x5bd8]),
+	(0x2f86f, &[0x5be7]),
+	(0x2f870, &[0x5bf3]),
+	(0x2f871, &[0x21b18]),
+	(0x2f872, &[0x5bff]),
+	(0x2f873, &[0x5c06]),
+	(0x2f874, &[0x5f53]),
+	(0x2f875, &[0x5c22]),
+	(0x2f876, &[0x3781]),
+	(0x2f877, &[0x5c60]),
+	(0x2f878, &[0x5c6e]),
+	(0x2f879, &[0x5cc0]),
+	(0x2f87a, &[0x5c8d]),
+	(0x2f87b, &[0x21de4]),
+	(0x2f87c, &[0x5d43]),
+	(0x2f87d, &[0x21de6]),
+	(0x2f87e, &[0x5d6e]),
+	(0x2f87f, &[0x5d6b]),
+	(0x2f880, &[0x5d7c]),
+	(0x2f881, &[0x5de1]),
+	(0x2f882, &[0x5de2]),
+	(0x2f883, &[0x382f]),
+	(0x2f884, &[0x5dfd]),
+	(0x2f885, &[0x5e28]),
+	(0x2f886, &[0x5e3d]),
+	(0x2f887, &[0x5e69]),
+	(0x2f888, &[0x3862]),
+	(0x2f889, &[0x22183]),
+	(0x2f88a, &[0x387c]),
+	(0x2f88b, &[0x5eb0]),
+	(0x2f88c, &[0x5eb3]),
+	(0x2f88d, &[0x5eb6]),
+	(0x2f88e, &[0x5eca]),
+	(0x2f88f, &[0x2a392]),
+	(0x2f890, &[0x5efe]),
+	(0x2f891, &[0x22331]),
+	(0x2f892, &[0x22331]),
+	(0x2f893, &[0x8201]),
+	(0x2f894, &[0x5f22]),
+	(0x2f895, &[0x5f22]),
+	(0x2f896, &[0x38c7]),
+	(0x2f897, &[0x232b8]),
+	(0x2f898, &[0x261da]),
+	(0x2f899, &[0x5f62]),
+	(0x2f89a, &[0x5f6b]),
+	(0x2f89b, &[0x38e3]),
+	(0x2f89c, &[0x5f9a]),
+	(0x2f89d, &[0x5fcd]),
+	(0x2f89e, &[0x5fd7]),
+	(0x2f89f, &[0x5ff9]),
+	(0x2f8a0, &[0x6081]),
+	(0x2f8a1, &[0x393a]),
+	(0x2f8a2, &[0x391c]),
+	(0x2f8a3, &[0x6094]),
+	(0x2f8a4, &[0x226d4]),
+	(0x2f8a5, &[0x60c7]),
+	(0x2f8a6, &[0x6148]),
+	(0x2f8a7, &[0x614c]),
+	(0x2f8a8, &[0x614e]),
+	(0x2f8a9, &[0x614c]),
+	(0x2f8aa, &[0x617a]),
+	(0x2f8ab, &[0x618e]),
+	(0x2f8ac, &[0x61b2]),
+	(0x2f8ad, &[0x61a4]),
+	(0x2f8ae, &[0x61af]),
+	(0x2f8af, &[0x61de]),
+	(0x2f8b0, &[0x61f2]),
+	(0x2f8b1, &[0x61f6]),
+	(0x2f8b2, &[0x6210]),
+	(0x2f8b3, &[0x621b]),
+	(0x2f8b4, &[0x625d]),
+	(0x2f8b5, &[0x62b1]),
+	(0x2f8b6, &[0x62d4]),
+	(0x2f8b7, &[0x6350]),
+	(0x2f8b8, &[0x22b0c]),
+	(0x2f8b9, &[0x633d]),
+	(0x2f8ba, &[0x62fc]),
+	(0x2f8bb, &[0x6368]),
+	(0x2f8bc, &[0x6383]),
+	(0x2f8bd, &[0x63e4]),
+	(0x2f8be, &[0x22bf1]),
+	(0x2f8bf, &[0x6422]),
+	(0x2f8c0, &[0x63c5]),
+	(0x2f8c1, &[0x63a9]),
+	(0x2f8c2, &[0x3a2e]),
+	(0x2f8c3, &[0x6469]),
+	(0x2f8c4, &[0x647e]),
+	(0x2f8c5, &[0x649d]),
+	(0x2f8c6, &[0x6477]),
+	(0x2f8c7, &[0x3a6c]),
+	(0x2f8c8, &[0x654f]),
+	(0x2f8c9, &[0x656c]),
+	(0x2f8ca, &[0x2300a]),
+	(0x2f8cb, &[0x65e3]),
+	(0x2f8cc, &[0x66f8]),
+	(0x2f8cd, &[0x6649]),
+	(0x2f8ce, &[0x3b19]),
+	(0x2f8cf, &[0x6691]),
+	(0x2f8d0, &[0x3b08]),
+	(0x2f8d1, &[0x3ae4]),
+	(0x2f8d2, &[0x5192]),
+	(0x2f8d3, &[0x5195]),
+	(0x2f8d4, &[0x6700]),
+	(0x2f8d5, &[0x669c]),
+	(0x2f8d6, &[0x80ad]),
+	(0x2f8d7, &[0x43d9]),
+	(0x2f8d8, &[0x6717]),
+	(0x2f8d9, &[0x671b]),
+	(0x2f8da, &[0x6721]),
+	(0x2f8db, &[0x675e]),
+	(0x2f8dc, &[0x6753]),
+	(0x2f8dd, &[0x233c3]),
+	(0x2f8de, &[0x3b49]),
+	(0x2f8df, &[0x67fa]),
+	(0x2f8e0, &[0x6785]),
+	(0x2f8e1, &[0x6852]),
+	(0x2f8e2, &[0x6885]),
+	(0x2f8e3, &[0x2346d]),
+	(0x2f8e4, &[0x688e]),
+	(0x2f8e5, &[0x681f]),
+	(0x2f8e6, &[0x6914]),
+	(0x2f8e7, &[0x3b9d]),
+	(0x2f8e8, &[0x6942]),
+	(0x2f8e9, &[0x69a3]),
+	(0x2f8ea, &[0x69ea]),
+	(0x2f8eb, &[0x6aa8]),
+	(0x2f8ec, &[0x236a3]),
+	(0x2f8ed, &[0x6adb]),
+	(0x2f8ee, &[0x3c18]),
+	(0x2f8ef, &[0x6b21]),
+	(0x2f8f0, &[0x238a7]),
+	(0x2f8f1, &[0x6b54]),
+	(0x2f8f2, &[0x3c4e]),
+	(0x2f8f3, &[0x6b72]),
+	(0x2f8f4, &[0x6b9f]),
+	(0x2f8f5, &[0x6bba]),
+	(0x2f8f6, &[0x6bbb]),
+	(0x2f8f7, &[0x23a8d]),
+	(0x2f8f8, &[0x21d0b]),
+	(0x2f8f9, &[0x23afa]),
+	(0x2f8fa, &[0x6c4e]),
+	(0x2f8fb, &[0x23cbc]),
+	(0x2f8fc, &[0x6cbf]),
+	(0x2f8fd, &[0x6ccd]),
+	(0x2f8fe, &[0x6c67]),
+	(0x2f8ff, &[0x6d16]),
+	(0x2f900, &[0x6d3e]),
+	(0x2f901, &[0x6d77]),
+	(0x2f902, &[0x6d41]),
+	(0x2f903, &[0x6d69]),
+	(0x2f904, &[0x6d78]),
+	(0x2f905, &[0x6d85]),
+	(0x2f906, &[0x23d1e]),
+	(0x2f907, &[0x6d34]),
+	(0x2f908, &[0x6e2f]),
+	(0x2f909, &[0x6e6e]),
+	(0x2f90a, &[0x3d33]),
+	(0x2f90b, &[0x6ecb]),
+	(0x2f90c, &[0x6ec7]),
+	(0x2f90d, &[0x23ed1]),
+	(0x2f90e, &[0x6df9]),
+	(0x2f90f, &[0x6f6e]),
+	(0x2f910, &[0x23f5e]),
+	(0x2f911, &[0x23f8e]),
+	(0x2f912, &[0x6fc6]),
+	(0x2f913, &[0x7039]),
+	(0x2f914, &[0x701e]),
+	(0x2f915, &[0x701b]),
+	(0x2f916, &[0x3d96]),
+	(0x2f917, &[0x704a]),
+	(0x2f918, &[0x707d]),
+	(0x2f919, &[0x7077]),
+	(0x2f91a, &[0x70ad]),
+	(0x2f91b, &[0x20525]),
+	(0x2f91c, &[0x7145]),
+	(0x2f91d, &[0x24263]),
+	(0x2f91e, &[0x719c]),
+	(0x2f91f, &[0x243ab]),
+	(0x2f920, &[0x7228]),
+	(0x2f921, &[0x7235]),
+	(0x2f922, &[0x7250]),
+	(0x2f923, &[0x24608]),
+	(0x2f924, &[0x7280]),
+	(0x2f925, &[0x7295]),
+	(0x2f926, &[0x24735]),
+	(0x2f927, &[0x24814]),
+	(0x2f928, &[0x737a]),
+	(0x2f929, &[0x738b]),
+	(0x2f92a, &[0x3eac]),
+	(0x2f92b, &[0x73a5]),
+	(0x2f92c, &[0x3eb8]),
+	(0x2f92d, &[0x3eb8]),
+	(0x2f92e, &[0x7447]),
+	(0x2f92f, &[0x745c]),
+	(0x2f930, &[0x7471]),
+	(0x2f931, &[0x7485]),
+	(0x2f932, &[0x74ca]),
+	(0x2f933, &[0x3f1b]),
+	(0x2f934, &[0x7524]),
+	(0x2f935, &[0x24c36]),
+	(0x2f936, &[0x753e]),
+	(0x2f937, &[0x24c92]),
+	(0x2f938, &[0x7570]),
+	(0x2f939, &[0x2219f]),
+	(0x2f93a, &[0x7610]),
+	(0x2f93b, &[0x24fa1]),
+	(0x2f93c, &[0x24fb8]),
+	(0x2f93d, &[0x25044]),
+	(0x2f93e, &[0x3ffc]),
+	(0x2f93f, &[0x4008]),
+	(0x2f940, &[0x76f4]),
+	(0x2f941, &[0x250f3]),
+	(0x2f942, &[0x250f2]),
+	(0x2f943, &[0x25119]),
+	(0x2f944, &[0x25133]),
+	(0x2f945, &[0x771e]),
+	(0x2f946, &[0x771f]),
+	(0x2f947, &[0x771f]),
+	(0x2f948, &[0x774a]),
+	(0x2f949, &[0x4039]),
+	(0x2f94a, &[0x778b]),
+	(0x2f94b, &[0x4046]),
+	(0x2f94c, &[0x4096]),
+	(0x2f94d, &[0x2541d]),
+	(0x2f94e, &[0x784e]),
+	(0x2f94f, &[0x788c]),
+	(0x2f950, &[0x78cc]),
+	(0x2f951, &[0x40e3]),
+	(0x2f952, &[0x25626]),
+	(0x2f953, &[0x7956]),
+	(0x2f954, &[0x2569a]),
+	(0x2f955, &[0x256c5]),
+	(0x2f956, &[0x798f]),
+	(0x2f957, &[0x79eb]),
+	(0x2f958, &[0x412f]),
+	(0x2f959, &[0x7a40]),
+	(0x2f95a, &[0x7a4a]),
+	(0x2f95b, &[0x7a4f]),
+	(0x2f95c, &[0x2597c]),
+	(0x2f95d, &[0x25aa7]),
+	(0x2f95e, &[0x25aa7]),
+	(0x2f95f, &[0x7aee]),
+	(0x2f960, &[0x4202]),
+	(0x2f961, &[0x25bab]),
+	(0x2f962, &[0x7bc6]),
+	(0x2f963, &[0x7bc9]),
+	(0x2f964, &[0x4227]),
+	(0x2f965, &[0x25c80]),
+	(0x2f966, &[0x7cd2]),
+	(0x2f967, &[0x42a0]),
+	(0x2f968, &[0x7ce8]),
+	(0x2f969, &[0x7ce3]),
+	(0x2f96a, &[0x7d00]),
+	(0x2f96b, &[0x25f86]),
+	(0x2f96c, &[0x7d63]),
+	(0x2f96d, &[0x4301]),
+	(0x2f96e, &[0x7dc7]),
+	(0x2f96f, &[0x7e02]),
+	(0x2f970, &[0x7e45]),
+	(0x2f971, &[0x4334]),
+	(0x2f972, &[0x26228]),
+	(0x2f973, &[0x26247]),
+	(0x2f974, &[0x4359]),
+	(0x2f975, &[0x262d9]),
+	(0x2f976, &[0x7f7a]),
+	(0x2f977, &[0x2633e]),
+	(0x2f978, &[0x7f95]),
+	(0x2f979, &[0x7ffa]),
+	(0x2f97a, &[0x8005]),
+	(0x2f97b, &[0x264da]),
+	(0x2f97c, &[0x26523]),
+	(0x2f97d, &[0x8060]),
+	(0x2f97e, &[0x265a8]),
+	(0x2f97f, &[0x8070]),
+	(0x2f980, &[0x2335f]),
+	(0x2f981, &[0x43d5]),
+	(0x2f982, &[0x80b2]),
+	(0x2f983, &[0x8103]),
+	(0x2f984, &[0x440b]),
+	(0x2f985, &[0x813e]),
+	(0x2f986, &[0x5ab5]),
+	(0x2f987, &[0x267a7]),
+	(0x2f988, &[0x267b5]),
+	(0x2f989, &[0x23393]),
+	(0x2f98a, &[0x2339c]),
+	(0x2f98b, &[0x8201]),
+	(0x2f98c, &[0x8204]),
+	(0x2f98d, &[0x8f9e]),
+	(0x2f98e, &[0x446b]),
+	(0x2f98f, &[0x8291]),
+	(0x2f990, &[0x828b]),
+	(0x2f991, &[0x829d]),
+	(0x2f992, &[0x52b3]),
+	(0x2f993, &[0x82b1]),
+	(0x2f994, &[0x82b3]),
+	(0x2f995, &[0x82bd]),
+	(0x2f996, &[0x82e6]),
+	(0x2f997, &[0x26b3c]),
+	(0x2f998, &[0x82e5]),
+	(0x2f999, &[0x831d]),
+	(0x2f99a, &[0x8363]),
+	(0x2f99b, &[0x83ad]),
+	(0x2f99c, &[0x8323]),
+	(0x2f99d, &[0x83bd]),
+	(0x2f99e, &[0x83e7]),
+	(0x2f99f, &[0x8457]),
+	(0x2f9a0, &[0x8353]),
+	(0x2f9a1, &[0x83ca]),
+	(0x2f9a2, &[0x83cc]),
+	(0x2f9a3, &[0x83dc]),
+	(0x2f9a4, &[0x26c36]),
+	(0x2f9a5, &[0x26d6b]),
+	(0x2f9a6, &[0x26cd5]),
+	(0x2f9a7, &[0x452b]),
+	(0x2f9a8, &[0x84f1]),
+	(0x2f9a9, &[0x84f3]),
+	(0x2f9aa, &[0x8516]),
+	(0x2f9ab, &[0x273ca]),
+	(0x2f9ac, &[0x8564]),
+	(0x2f9ad, &[0x26f2c]),
+	(0x2f9ae, &[0x455d]),
+	(0x2f9af, &[0x4561]),
+	(0x2f9b0, &[0x26fb1]),
+	(0x2f9b1, &[0x270d2]),
+	(0x2f9b2, &[0x456b]),
+	(0x2f9b3, &[0x8650]),
+	(0x2f9b4, &[0x865c]),
+	(0x2f9b5, &[0x8667]),
+	(0x2f9b6, &[0x8669]),
+	(0x2f9b7, &[0x86a9]),
+	(0x2f9b8, &[0x8688]),
+	(0x2f9b9, &[0x870e]),
+	(0x2f9ba, &[0x86e2]),
+	(0x2f9bb, &[0x8779]),
+	(0x2f9bc, &[0x8728]),
+	(0x2f9bd, &[0x876b]),
+	(0x2f9be, &[0x8786]),
+	(0x2f9bf, &[0x45d7]),
+	(0x2f9c0, &[0x87e1]),
+	(0x2f9c1, &[0x8801]),
+	(0x2f9c2, &[0x45f9]),
+	(0x2f9c3, &[0x8860]),
+	(0x2f9c4, &[0x8863]),
+	(0x2f9c5, &[0x27667]),
+	(0x2f9c6, &[0x88d7]),
+	(0x2f9c7, &[0x88de]),
+	(0x2f9c8, &[0x4635]),
+	(0x2f9c9, &[0x88fa]),
+	(0x2f9ca, &[0x34bb]),
+	(0x2f9cb, &[0x278ae]),
+	(0x2f9cc, &[0x27966]),
+	(0x2f9cd, &[0x46be]),
+	(0x2f9ce, &[0x46c7]),
+	(0x2f9cf, &[0x8aa0]),
+	(0x2f9d0, &[0x8aed]),
+	(0x2f9d1, &[0x8b8a]),
+	(0x2f9d2, &[0x8c55]),
+	(0x2f9d3, &[0x27ca8]),
+	(0x2f9d4, &[0x8cab]),
+	(0x2f9d5, &[0x8cc1]),
+	(0x2f9d6, &[0x8d1b]),
+	(0x2f9d7, &[0x8d77]),
+	(0x2f9d8, &[0x27f2f]),
+	(0x2f9d9, &[0x20804]),
+	(0x2f9da, &[0x8dcb]),
+	(0x2f9db, &[0x8dbc]),
+	(0x2f9dc, &[0x8df0]),
+	(0x2f9dd, &[0x208de]),
+	(0x2f9de, &[0x8ed4]),
+	(0x2f9df, &[0x8f38]),
+	(0x2f9e0, &[0x285d2]),
+	(0x2f9e1, &[0x285ed]),
+	(0x2f9e2, &[0x9094]),
+	(0x2f9e3, &[0x90f1]),
+	(0x2f9e4, &[0x9111]),
+	(0x2f9e5, &[0x2872e]),
+	(0x2f9e6, &[0x911b]),
+	(0x2f9e7, &[0x9238]),
+	(0x2f9e8, &[0x92d7]),
+	(0x2f9e9, &[0x92d8]),
+	(0x2f9ea, &[0x927c]),
+	(0x2f9eb, &[0x93f9]),
+	(0x2f9ec, &[0x9415]),
+	(0x2f9ed, &[0x28bfa]),
+	(0x2f9ee, &[0x958b]),
+	(0x2f9ef, &[0x4995]),
+	(0x2f9f0, &[0x95b7]),
+	(0x2f9f1, &[0x28d77]),
+	(0x2f9f2, &[0x49e6]),
+	(0x2f9f3, &[0x96c3]),
+	(0x2f9f4, &[0x5db2]),
+	(0x2f9f5, &[0x9723]),
+	(0x2f9f6, &[0x29145]),
+	(0x2f9f7, &[0x2921a]),
+	(0x2f9f8, &[0x4a6e]),
+	(0x2f9f9, &[0x4a76]),
+	(0x2f9fa, &[0x97e0]),
+	(0x2f9fb, &[0x2940a]),
+	(0x2f9fc, &[0x4ab2]),
+	(0x2f9fd, &[0x29496]),
+	(0x2f9fe, &[0x980b]),
+	(0x2f9ff, &[0x980b]),
+	(0x2fa00, &[0x9829]),
+	(0x2fa01, &[0x295b6]),
+	(0x2fa02, &[0x98e2]),
+	(0x2fa03, &[0x4b33]),
+	(0x2fa04, &[0x9929]),
+	(0x2fa05, &[0x99a7]),
+	(0x2fa06, &[0x99c2]),
+	(0x2fa07, &[0x99fe]),
+	(0x2fa08, &[0x4bce]),
+	(0x2fa09, &[0x29b30]),
+	(0x2fa0a, &[0x9b12]),
+	(0x2fa0b, &[0x9c40]),
+	(0x2fa0c, &[0x9cfd]),
+	(0x2fa0d, &[0x4cce]),
+	(0x2fa0e, &[0x4ced]),
+	(0x2fa0f, &[0x9d67]),
+	(0x2fa10, &[0x2a0ce]),
+	(0x2fa11, &[0x4cf8]),
+	(0x2fa12, &[0x2a105]),
+	(0x2fa13, &[0x2a20e]),
+	(0x2fa14, &[0x2a291]),
+	(0x2fa15, &[0x9ebb]),
+	(0x2fa16, &[0x4d56]),
+	(0x2fa17, &[0x9ef9]),
+	(0x2fa18, &[0x9efe]),
+	(0x2fa19, &[0x9f05]),
+	(0x2fa1a, &[0x9f0f]),
+	(0x2fa1b, &[0x9f16]),
+	(0x2fa1c, &[0x9f3b]),
+	(0x2fa1d, &[0x2a600]),
+];
+
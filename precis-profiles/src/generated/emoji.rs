@@ -0,0 +1,1709 @@
+// File generated with precis-tools version 0.1.9
+
+// Encoded as ranges: ranges 624 bytes, bitset 16368 bytes, trie 4416 bytes
+const EXTENDED_PICTOGRAPHIC: CodepointSet = CodepointSet::Ranges(&[
+	(0x00a9, 0x00a9),
+	(0x00ae, 0x00ae),
+	(0x203c, 0x203c),
+	(0x2049, 0x2049),
+	(0x2122, 0x2122),
+	(0x2139, 0x2139),
+	(0x2194, 0x2199),
+	(0x21a9, 0x21aa),
+	(0x231a, 0x231b),
+	(0x2328, 0x2328),
+	(0x2388, 0x2388),
+	(0x23cf, 0x23cf),
+	(0x23e9, 0x23f3),
+	(0x23f8, 0x23fa),
+	(0x24c2, 0x24c2),
+	(0x25aa, 0x25ab),
+	(0x25b6, 0x25b6),
+	(0x25c0, 0x25c0),
+	(0x25fb, 0x25fe),
+	(0x2600, 0x2605),
+	(0x2607, 0x2612),
+	(0x2614, 0x2685),
+	(0x2690, 0x2705),
+	(0x2708, 0x2712),
+	(0x2714, 0x2714),
+	(0x2716, 0x2716),
+	(0x271d, 0x271d),
+	(0x2721, 0x2721),
+	(0x2728, 0x2728),
+	(0x2733, 0x2734),
+	(0x2744, 0x2744),
+	(0x2747, 0x2747),
+	(0x274c, 0x274c),
+	(0x274e, 0x274e),
+	(0x2753, 0x2755),
+	(0x2757, 0x2757),
+	(0x2763, 0x2767),
+	(0x2795, 0x2797),
+	(0x27a1, 0x27a1),
+	(0x27b0, 0x27b0),
+	(0x27bf, 0x27bf),
+	(0x2934, 0x2935),
+	(0x2b05, 0x2b07),
+	(0x2b1b, 0x2b1c),
+	(0x2b50, 0x2b50),
+	(0x2b55, 0x2b55),
+	(0x3030, 0x3030),
+	(0x303d, 0x303d),
+	(0x3297, 0x3297),
+	(0x3299, 0x3299),
+	(0x1f000, 0x1f0ff),
+	(0x1f10d, 0x1f10f),
+	(0x1f12f, 0x1f12f),
+	(0x1f16c, 0x1f171),
+	(0x1f17e, 0x1f17f),
+	(0x1f18e, 0x1f18e),
+	(0x1f191, 0x1f19a),
+	(0x1f1ad, 0x1f1e5),
+	(0x1f201, 0x1f20f),
+	(0x1f21a, 0x1f21a),
+	(0x1f22f, 0x1f22f),
+	(0x1f232, 0x1f23a),
+	(0x1f23c, 0x1f23f),
+	(0x1f249, 0x1f3fa),
+	(0x1f400, 0x1f53d),
+	(0x1f546, 0x1f64f),
+	(0x1f680, 0x1f6ff),
+	(0x1f774, 0x1f77f),
+	(0x1f7d5, 0x1f7ff),
+	(0x1f80c, 0x1f80f),
+	(0x1f848, 0x1f84f),
+	(0x1f85a, 0x1f85f),
+	(0x1f888, 0x1f88f),
+	(0x1f8ae, 0x1f8ff),
+	(0x1f90c, 0x1f93a),
+	(0x1f93c, 0x1f945),
+	(0x1f947, 0x1faff),
+	(0x1fc00, 0x1fffd),
+]);
+
+// Encoded as bitset: ranges 8 bytes, bitset 8 bytes, trie 4016 bytes
+const EMOJI_MODIFIER: CodepointSet = CodepointSet::Bitset {
+	start: 0x1f3fb,
+	bits: &[
+		0x000000000000001f,
+	],
+};
+
+static RGI_EMOJI_ZWJ_SEQUENCES: [&[u32]; 1614] = [
+	&[0x26d3, 0xfe0f, 0x200d, 0x1f4a5],
+	&[0x26f9, 0xfe0f, 0x200d, 0x2640, 0xfe0f],
+	&[0x26f9, 0xfe0f, 0x200d, 0x2642, 0xfe0f],
+	&[0x26f9, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x26f9, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x26f9, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x26f9, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x26f9, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x26f9, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x26f9, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x26f9, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x26f9, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x26f9, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x2764, 0xfe0f, 0x200d, 0x1f525],
+	&[0x2764, 0xfe0f, 0x200d, 0x1fa79],
+	&[0x1f344, 0x200d, 0x1f7eb],
+	&[0x1f34b, 0x200d, 0x1f7e9],
+	&[0x1f3c3, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3c3, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3c3, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3c3, 0x1f3fb, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3c3, 0x1f3fb, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x1f3fb, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3c3, 0x1f3fc, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3c3, 0x1f3fc, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x1f3fc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3c3, 0x1f3fd, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3c3, 0x1f3fd, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x1f3fd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3c3, 0x1f3fe, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3c3, 0x1f3fe, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x1f3fe, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3c3, 0x1f3ff, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3c3, 0x1f3ff, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c3, 0x1f3ff, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f3c4, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3c4, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3c4, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3c4, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3c4, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3c4, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3c4, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3c4, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3c4, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3c4, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3c4, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3c4, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3ca, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3ca, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3ca, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3ca, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3ca, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3ca, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3ca, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3ca, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3ca, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3ca, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3ca, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3ca, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3cb, 0xfe0f, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3cb, 0xfe0f, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3cb, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3cb, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3cb, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3cb, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3cb, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3cb, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3cb, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3cb, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3cb, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3cb, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3cc, 0xfe0f, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3cc, 0xfe0f, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3cc, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3cc, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3cc, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3cc, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3cc, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3cc, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3cc, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3cc, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3cc, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f3cc, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f3f3, 0xfe0f, 0x200d, 0x26a7, 0xfe0f],
+	&[0x1f3f3, 0xfe0f, 0x200d, 0x1f308],
+	&[0x1f3f4, 0x200d, 0x2620, 0xfe0f],
+	&[0x1f408, 0x200d, 0x2b1b],
+	&[0x1f415, 0x200d, 0x1f9ba],
+	&[0x1f426, 0x200d, 0x2b1b],
+	&[0x1f426, 0x200d, 0x1f525],
+	&[0x1f43b, 0x200d, 0x2744, 0xfe0f],
+	&[0x1f441, 0xfe0f, 0x200d, 0x1f5e8, 0xfe0f],
+	&[0x1f468, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f468, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f468, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f468, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468],
+	&[0x1f468, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468],
+	&[0x1f468, 0x200d, 0x1f33e],
+	&[0x1f468, 0x200d, 0x1f373],
+	&[0x1f468, 0x200d, 0x1f37c],
+	&[0x1f468, 0x200d, 0x1f393],
+	&[0x1f468, 0x200d, 0x1f3a4],
+	&[0x1f468, 0x200d, 0x1f3a8],
+	&[0x1f468, 0x200d, 0x1f3eb],
+	&[0x1f468, 0x200d, 0x1f3ed],
+	&[0x1f468, 0x200d, 0x1f466],
+	&[0x1f468, 0x200d, 0x1f466, 0x200d, 0x1f466],
+	&[0x1f468, 0x200d, 0x1f467],
+	&[0x1f468, 0x200d, 0x1f467, 0x200d, 0x1f466],
+	&[0x1f468, 0x200d, 0x1f467, 0x200d, 0x1f467],
+	&[0x1f468, 0x200d, 0x1f468, 0x200d, 0x1f466],
+	&[0x1f468, 0x200d, 0x1f468, 0x200d, 0x1f466, 0x200d, 0x1f466],
+	&[0x1f468, 0x200d, 0x1f468, 0x200d, 0x1f467],
+	&[0x1f468, 0x200d, 0x1f468, 0x200d, 0x1f467, 0x200d, 0x1f466],
+	&[0x1f468, 0x200d, 0x1f468, 0x200d, 0x1f467, 0x200d, 0x1f467],
+	&[0x1f468, 0x200d, 0x1f469, 0x200d, 0x1f466],
+	&[0x1f468, 0x200d, 0x1f469, 0x200d, 0x1f466, 0x200d, 0x1f466],
+	&[0x1f468, 0x200d, 0x1f469, 0x200d, 0x1f467],
+	&[0x1f468, 0x200d, 0x1f469, 0x200d, 0x1f467, 0x200d, 0x1f466],
+	&[0x1f468, 0x200d, 0x1f469, 0x200d, 0x1f467, 0x200d, 0x1f467],
+	&[0x1f468, 0x200d, 0x1f4bb],
+	&[0x1f468, 0x200d, 0x1f4bc],
+	&[0x1f468, 0x200d, 0x1f527],
+	&[0x1f468, 0x200d, 0x1f52c],
+	&[0x1f468, 0x200d, 0x1f680],
+	&[0x1f468, 0x200d, 0x1f692],
+	&[0x1f468, 0x200d, 0x1f9af],
+	&[0x1f468, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x200d, 0x1f9b0],
+	&[0x1f468, 0x200d, 0x1f9b1],
+	&[0x1f468, 0x200d, 0x1f9b2],
+	&[0x1f468, 0x200d, 0x1f9b3],
+	&[0x1f468, 0x200d, 0x1f9bc],
+	&[0x1f468, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x200d, 0x1f9bd],
+	&[0x1f468, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f33e],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f373],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f37c],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f393],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f3a4],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f3a8],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f3eb],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f3ed],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f4bb],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f4bc],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f527],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f52c],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f680],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f692],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f9af],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f9b0],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f9b1],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f9b2],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f9b3],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f9bc],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f9bd],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fb, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f33e],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f373],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f37c],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f393],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f3a4],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f3a8],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f3eb],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f3ed],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f4bb],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f4bc],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f527],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f52c],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f680],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f692],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f9af],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f9b0],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f9b1],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f9b2],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f9b3],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f9bc],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f9bd],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fc, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f33e],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f373],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f37c],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f393],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f3a4],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f3a8],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f3eb],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f3ed],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f4bb],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f4bc],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f527],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f52c],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f680],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f692],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f9af],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f9b0],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f9b1],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f9b2],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f9b3],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f9bc],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f9bd],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fd, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f33e],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f373],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f37c],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f393],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f3a4],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f3a8],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f3eb],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f3ed],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f4bb],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f4bc],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f527],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f52c],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f680],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f692],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f9af],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f9b0],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f9b1],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f9b2],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f9b3],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f9bc],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f9bd],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3fe, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f33e],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f373],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f37c],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f393],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f3a4],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f3a8],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f3eb],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f3ed],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f430, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f4bb],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f4bc],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f527],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f52c],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f680],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f692],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f9af],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f9b0],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f9b1],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f9b2],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f9b3],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f9bc],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f9bd],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f468, 0x1f3ff, 0x200d, 0x1faef, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f469, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f469, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f469, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f469, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468],
+	&[0x1f469, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469],
+	&[0x1f469, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468],
+	&[0x1f469, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469],
+	&[0x1f469, 0x200d, 0x1f33e],
+	&[0x1f469, 0x200d, 0x1f373],
+	&[0x1f469, 0x200d, 0x1f37c],
+	&[0x1f469, 0x200d, 0x1f393],
+	&[0x1f469, 0x200d, 0x1f3a4],
+	&[0x1f469, 0x200d, 0x1f3a8],
+	&[0x1f469, 0x200d, 0x1f3eb],
+	&[0x1f469, 0x200d, 0x1f3ed],
+	&[0x1f469, 0x200d, 0x1f466],
+	&[0x1f469, 0x200d, 0x1f466, 0x200d, 0x1f466],
+	&[0x1f469, 0x200d, 0x1f467],
+	&[0x1f469, 0x200d, 0x1f467, 0x200d, 0x1f466],
+	&[0x1f469, 0x200d, 0x1f467, 0x200d, 0x1f467],
+	&[0x1f469, 0x200d, 0x1f469, 0x200d, 0x1f466],
+	&[0x1f469, 0x200d, 0x1f469, 0x200d, 0x1f466, 0x200d, 0x1f466],
+	&[0x1f469, 0x200d, 0x1f469, 0x200d, 0x1f467],
+	&[0x1f469, 0x200d, 0x1f469, 0x200d, 0x1f467, 0x200d, 0x1f466],
+	&[0x1f469, 0x200d, 0x1f469, 0x200d, 0x1f467, 0x200d, 0x1f467],
+	&[0x1f469, 0x200d, 0x1f4bb],
+	&[0x1f469, 0x200d, 0x1f4bc],
+	&[0x1f469, 0x200d, 0x1f527],
+	&[0x1f469, 0x200d, 0x1f52c],
+	&[0x1f469, 0x200d, 0x1f680],
+	&[0x1f469, 0x200d, 0x1f692],
+	&[0x1f469, 0x200d, 0x1f9af],
+	&[0x1f469, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x200d, 0x1f9b0],
+	&[0x1f469, 0x200d, 0x1f9b1],
+	&[0x1f469, 0x200d, 0x1f9b2],
+	&[0x1f469, 0x200d, 0x1f9b3],
+	&[0x1f469, 0x200d, 0x1f9bc],
+	&[0x1f469, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x200d, 0x1f9bd],
+	&[0x1f469, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f33e],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f373],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f37c],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f393],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f3a4],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f3a8],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f3eb],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f3ed],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f4bb],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f4bc],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f527],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f52c],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f680],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f692],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f9af],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f9b0],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f9b1],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f9b2],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f9b3],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f9bc],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f9bd],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fb, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f33e],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f373],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f37c],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f393],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f3a4],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f3a8],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f3eb],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f3ed],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f4bb],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f4bc],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f527],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f52c],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f680],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f692],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f9af],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f9b0],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f9b1],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f9b2],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f9b3],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f9bc],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f9bd],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fc, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f33e],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f373],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f37c],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f393],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f3a4],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f3a8],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f3eb],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f3ed],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f4bb],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f4bc],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f527],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f52c],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f680],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f692],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f9af],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f9b0],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f9b1],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f9b2],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f9b3],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f9bc],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f9bd],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fd, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f33e],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f373],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f37c],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f393],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f3a4],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f3a8],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f3eb],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f3ed],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f4bb],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f4bc],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f527],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f52c],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f680],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f692],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f9af],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f9b0],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f9b1],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f9b2],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f9b3],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f9bc],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f9bd],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3fe, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f468, 0x1f3ff],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f469, 0x1f3ff],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f33e],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f373],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f37c],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f393],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f3a4],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f3a8],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f3eb],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f3ed],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f430, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f4bb],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f4bc],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f527],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f52c],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f680],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f692],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fb],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fc],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fd],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f468, 0x1f3fe],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f9af],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f9b0],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f9b1],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f9b2],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f9b3],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f9bc],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f9bd],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3fb],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3fc],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3fd],
+	&[0x1f469, 0x1f3ff, 0x200d, 0x1faef, 0x200d, 0x1f469, 0x1f3fe],
+	&[0x1f46e, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f46e, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f46e, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f46e, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f46e, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f46e, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f46e, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f46e, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f46e, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f46e, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f46e, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f46e, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f46f, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f46f, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f46f, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f46f, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f46f, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f46f, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f46f, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f46f, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f46f, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f46f, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f46f, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f46f, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f470, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f470, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f470, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f470, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f470, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f470, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f470, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f470, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f470, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f470, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f470, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f470, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f471, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f471, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f471, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f471, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f471, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f471, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f471, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f471, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f471, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f471, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f471, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f471, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f473, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f473, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f473, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f473, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f473, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f473, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f473, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f473, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f473, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f473, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f473, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f473, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f477, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f477, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f477, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f477, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f477, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f477, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f477, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f477, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f477, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f477, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f477, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f477, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f481, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f481, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f481, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f481, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f481, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f481, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f481, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f481, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f481, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f481, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f481, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f481, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f482, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f482, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f482, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f482, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f482, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f482, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f482, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f482, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f482, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f482, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f482, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f482, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f486, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f486, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f486, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f486, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f486, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f486, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f486, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f486, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f486, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f486, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f486, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f486, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f487, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f487, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f487, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f487, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f487, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f487, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f487, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f487, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f487, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f487, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f487, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f487, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f575, 0xfe0f, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f575, 0xfe0f, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f575, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f575, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f575, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f575, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f575, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f575, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f575, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f575, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f575, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f575, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f62e, 0x200d, 0x1f4a8],
+	&[0x1f635, 0x200d, 0x1f4ab],
+	&[0x1f636, 0x200d, 0x1f32b, 0xfe0f],
+	&[0x1f642, 0x200d, 0x2194, 0xfe0f],
+	&[0x1f642, 0x200d, 0x2195, 0xfe0f],
+	&[0x1f645, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f645, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f645, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f645, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f645, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f645, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f645, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f645, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f645, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f645, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f645, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f645, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f646, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f646, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f646, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f646, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f646, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f646, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f646, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f646, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f646, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f646, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f646, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f646, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f647, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f647, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f647, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f647, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f647, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f647, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f647, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f647, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f647, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f647, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f647, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f647, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64b, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64b, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64b, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64b, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64b, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64b, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64b, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64b, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64b, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64b, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64b, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64b, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64d, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64d, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64d, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64d, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64d, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64d, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64d, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64d, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64d, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64d, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64d, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64d, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64e, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64e, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64e, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64e, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64e, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64e, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64e, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64e, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64e, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64e, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f64e, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f64e, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6a3, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6a3, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6a3, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6a3, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6a3, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6a3, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6a3, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6a3, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6a3, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6a3, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6a3, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6a3, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b4, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b4, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b4, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b4, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b4, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b4, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b4, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b4, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b4, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b4, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b4, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b4, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b5, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b5, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b5, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b5, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b5, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b5, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b5, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b5, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b5, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b5, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b5, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b5, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b6, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b6, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b6, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b6, 0x1f3fb, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b6, 0x1f3fb, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x1f3fb, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b6, 0x1f3fc, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b6, 0x1f3fc, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x1f3fc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b6, 0x1f3fd, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b6, 0x1f3fd, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x1f3fd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b6, 0x1f3fe, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b6, 0x1f3fe, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x1f3fe, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f6b6, 0x1f3ff, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f6b6, 0x1f3ff, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f6b6, 0x1f3ff, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f926, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f926, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f926, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f926, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f926, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f926, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f926, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f926, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f926, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f926, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f926, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f926, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f935, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f935, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f935, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f935, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f935, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f935, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f935, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f935, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f935, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f935, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f935, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f935, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f937, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f937, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f937, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f937, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f937, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f937, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f937, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f937, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f937, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f937, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f937, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f937, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f938, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f938, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f938, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f938, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f938, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f938, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f938, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f938, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f938, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f938, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f938, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f938, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f939, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f939, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f939, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f939, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f939, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f939, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f939, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f939, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f939, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f939, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f939, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f939, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93c, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93c, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93c, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93c, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93c, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93c, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93c, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93c, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93c, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93c, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93c, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93c, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93d, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93d, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93d, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93d, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93d, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93d, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93d, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93d, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93d, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93d, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93d, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93d, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93e, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93e, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93e, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93e, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93e, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93e, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93e, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93e, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93e, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93e, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f93e, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f93e, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9b8, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9b8, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9b8, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9b8, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9b8, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9b8, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9b8, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9b8, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9b8, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9b8, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9b8, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9b8, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9b9, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9b9, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9b9, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9b9, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9b9, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9b9, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9b9, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9b9, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9b9, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9b9, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9b9, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9b9, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9cd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9cd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9cd, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9cd, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9cd, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9cd, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9cd, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9cd, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9cd, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9cd, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9cd, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9cd, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9ce, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9ce, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9ce, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9ce, 0x1f3fb, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9ce, 0x1f3fb, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x1f3fb, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9ce, 0x1f3fc, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9ce, 0x1f3fc, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x1f3fc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9ce, 0x1f3fd, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9ce, 0x1f3fd, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x1f3fd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9ce, 0x1f3fe, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9ce, 0x1f3fe, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x1f3fe, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9ce, 0x1f3ff, 0x200d, 0x2640, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9ce, 0x1f3ff, 0x200d, 0x2642, 0xfe0f, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9ce, 0x1f3ff, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9cf, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9cf, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9cf, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9cf, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9cf, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9cf, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9cf, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9cf, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9cf, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9cf, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9cf, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9cf, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d1, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f9d1, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f9d1, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f9d1, 0x200d, 0x1f33e],
+	&[0x1f9d1, 0x200d, 0x1f373],
+	&[0x1f9d1, 0x200d, 0x1f37c],
+	&[0x1f9d1, 0x200d, 0x1f384],
+	&[0x1f9d1, 0x200d, 0x1f393],
+	&[0x1f9d1, 0x200d, 0x1f3a4],
+	&[0x1f9d1, 0x200d, 0x1f3a8],
+	&[0x1f9d1, 0x200d, 0x1f3eb],
+	&[0x1f9d1, 0x200d, 0x1f3ed],
+	&[0x1f9d1, 0x200d, 0x1f4bb],
+	&[0x1f9d1, 0x200d, 0x1f4bc],
+	&[0x1f9d1, 0x200d, 0x1f527],
+	&[0x1f9d1, 0x200d, 0x1f52c],
+	&[0x1f9d1, 0x200d, 0x1f680],
+	&[0x1f9d1, 0x200d, 0x1f692],
+	&[0x1f9d1, 0x200d, 0x1f91d, 0x200d, 0x1f9d1],
+	&[0x1f9d1, 0x200d, 0x1f9af],
+	&[0x1f9d1, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x200d, 0x1f9b0],
+	&[0x1f9d1, 0x200d, 0x1f9b1],
+	&[0x1f9d1, 0x200d, 0x1f9b2],
+	&[0x1f9d1, 0x200d, 0x1f9b3],
+	&[0x1f9d1, 0x200d, 0x1f9bc],
+	&[0x1f9d1, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x200d, 0x1f9bd],
+	&[0x1f9d1, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x200d, 0x1f9d1, 0x200d, 0x1f9d2],
+	&[0x1f9d1, 0x200d, 0x1f9d1, 0x200d, 0x1f9d2, 0x200d, 0x1f9d2],
+	&[0x1f9d1, 0x200d, 0x1f9d2],
+	&[0x1f9d1, 0x200d, 0x1f9d2, 0x200d, 0x1f9d2],
+	&[0x1f9d1, 0x200d, 0x1fa70],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f33e],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f373],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f37c],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f384],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f393],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f3a4],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f3a8],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f3eb],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f3ed],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f4bb],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f4bc],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f527],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f52c],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f680],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f692],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f9af],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f9b0],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f9b1],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f9b2],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f9b3],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f9bc],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f9bd],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1fa70],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3fb, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f33e],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f373],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f37c],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f384],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f393],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f3a4],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f3a8],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f3eb],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f3ed],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f4bb],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f4bc],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f527],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f52c],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f680],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f692],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f9af],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f9b0],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f9b1],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f9b2],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f9b3],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f9bc],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f9bd],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1fa70],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3fc, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f33e],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f373],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f37c],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f384],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f393],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f3a4],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f3a8],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f3eb],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f3ed],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f4bb],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f4bc],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f527],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f52c],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f680],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f692],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f9af],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f9b0],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f9b1],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f9b2],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f9b3],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f9bc],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f9bd],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1fa70],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3fd, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f33e],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f373],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f37c],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f384],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f393],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f3a4],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f3a8],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f3eb],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f3ed],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f4bb],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f4bc],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f527],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f52c],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f680],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f692],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f9af],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f9b0],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f9b1],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f9b2],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f9b3],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f9bc],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f9bd],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1fa70],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3fe, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x2695, 0xfe0f],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x2696, 0xfe0f],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x2708, 0xfe0f],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f48b, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x2764, 0xfe0f, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f33e],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f373],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f37c],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f384],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f393],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f3a4],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f3a8],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f3eb],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f3ed],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f430, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f4bb],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f4bc],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f527],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f52c],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f680],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f692],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f91d, 0x200d, 0x1f9d1, 0x1f3ff],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f9af],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f9af, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f9b0],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f9b1],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f9b2],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f9b3],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f9bc],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f9bc, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f9bd],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1f9bd, 0x200d, 0x27a1, 0xfe0f],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1fa70],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3fb],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3fc],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3fd],
+	&[0x1f9d1, 0x1f3ff, 0x200d, 0x1faef, 0x200d, 0x1f9d1, 0x1f3fe],
+	&[0x1f9d4, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d4, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d4, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d4, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d4, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d4, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d4, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d4, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d4, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d4, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d4, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d4, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d6, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d6, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d6, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d6, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d6, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d6, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d6, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d6, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d6, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d6, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d6, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d6, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d7, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d7, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d7, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d7, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d7, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d7, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d7, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d7, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d7, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d7, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d7, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d7, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d8, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d8, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d8, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d8, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d8, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d8, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d8, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d8, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d8, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d8, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d8, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d8, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d9, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d9, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d9, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d9, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d9, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d9, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d9, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d9, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d9, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d9, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9d9, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9d9, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9da, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9da, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9da, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9da, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9da, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9da, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9da, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9da, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9da, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9da, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9da, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9da, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9db, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9db, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9db, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9db, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9db, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9db, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9db, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9db, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9db, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9db, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9db, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9db, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9dc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9dc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9dc, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9dc, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9dc, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9dc, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9dc, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9dc, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9dc, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9dc, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9dc, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9dc, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9dd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9dd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9dd, 0x1f3fb, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9dd, 0x1f3fb, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9dd, 0x1f3fc, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9dd, 0x1f3fc, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9dd, 0x1f3fd, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9dd, 0x1f3fd, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9dd, 0x1f3fe, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9dd, 0x1f3fe, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9dd, 0x1f3ff, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9dd, 0x1f3ff, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9de, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9de, 0x200d, 0x2642, 0xfe0f],
+	&[0x1f9df, 0x200d, 0x2640, 0xfe0f],
+	&[0x1f9df, 0x200d, 0x2642, 0xfe0f],
+	&[0x1faf1, 0x1f3fb, 0x200d, 0x1faf2, 0x1f3fc],
+	&[0x1faf1, 0x1f3fb, 0x200d, 0x1faf2, 0x1f3fd],
+	&[0x1faf1, 0x1f3fb, 0x200d, 0x1faf2, 0x1f3fe],
+	&[0x1faf1, 0x1f3fb, 0x200d, 0x1faf2, 0x1f3ff],
+	&[0x1faf1, 0x1f3fc, 0x200d, 0x1faf2, 0x1f3fb],
+	&[0x1faf1, 0x1f3fc, 0x200d, 0x1faf2, 0x1f3fd],
+	&[0x1faf1, 0x1f3fc, 0x200d, 0x1faf2, 0x1f3fe],
+	&[0x1faf1, 0x1f3fc, 0x200d, 0x1faf2, 0x1f3ff],
+	&[0x1faf1, 0x1f3fd, 0x200d, 0x1faf2, 0x1f3fb],
+	&[0x1faf1, 0x1f3fd, 0x200d, 0x1faf2, 0x1f3fc],
+	&[0x1faf1, 0x1f3fd, 0x200d, 0x1faf2, 0x1f3fe],
+	&[0x1faf1, 0x1f3fd, 0x200d, 0x1faf2, 0x1f3ff],
+	&[0x1faf1, 0x1f3fe, 0x200d, 0x1faf2, 0x1f3fb],
+	&[0x1faf1, 0x1f3fe, 0x200d, 0x1faf2, 0x1f3fc],
+	&[0x1faf1, 0x1f3fe, 0x200d, 0x1faf2, 0x1f3fd],
+	&[0x1faf1, 0x1f3fe, 0x200d, 0x1faf2, 0x1f3ff],
+	&[0x1faf1, 0x1f3ff, 0x200d, 0x1faf2, 0x1f3fb],
+	&[0x1faf1, 0x1f3ff, 0x200d, 0x1faf2, 0x1f3fc],
+	&[0x1faf1, 0x1f3ff, 0x200d, 0x1faf2, 0x1f3fd],
+	&[0x1faf1, 0x1f3ff, 0x200d, 0x1faf2, 0x1f3fe],
+];
+
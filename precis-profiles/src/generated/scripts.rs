@@ -0,0 +1,1525 @@
+// File generated with precis-tools version 0.1.9
+
+/// Represents values of the Unicode character property
+/// [Script](http://www.unicode.org/reports/tr24/). Code points not
+/// assigned to any script have the value `Unknown`.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Script {
+	/// `Adlam` (`Adlm`)
+	Adlam,
+	/// `Ahom` (`Ahom`)
+	Ahom,
+	/// `Anatolian_Hieroglyphs` (`Hluw`)
+	AnatolianHieroglyphs,
+	/// `Arabic` (`Arab`)
+	Arabic,
+	/// `Armenian` (`Armn`)
+	Armenian,
+	/// `Avestan` (`Avst`)
+	Avestan,
+	/// `Balinese` (`Bali`)
+	Balinese,
+	/// `Bamum` (`Bamu`)
+	Bamum,
+	/// `Bassa_Vah` (`Bass`)
+	BassaVah,
+	/// `Batak` (`Batk`)
+	Batak,
+	/// `Bengali` (`Beng`)
+	Bengali,
+	/// `Beria_Erfe` (`Berf`)
+	BeriaErfe,
+	/// `Bhaiksuki` (`Bhks`)
+	Bhaiksuki,
+	/// `Bopomofo` (`Bopo`)
+	Bopomofo,
+	/// `Brahmi` (`Brah`)
+	Brahmi,
+	/// `Braille` (`Brai`)
+	Braille,
+	/// `Buginese` (`Bugi`)
+	Buginese,
+	/// `Buhid` (`Buhd`)
+	Buhid,
+	/// `Canadian_Aboriginal` (`Cans`)
+	CanadianAboriginal,
+	/// `Carian` (`Cari`)
+	Carian,
+	/// `Caucasian_Albanian` (`Aghb`)
+	CaucasianAlbanian,
+	/// `Chakma` (`Cakm`)
+	Chakma,
+	/// `Cham` (`Cham`)
+	Cham,
+	/// `Cherokee` (`Cher`)
+	Cherokee,
+	/// `Chorasmian` (`Chrs`)
+	Chorasmian,
+	/// `Common` (`Zyyy`)
+	Common,
+	/// `Coptic` (`Copt`)
+	Coptic,
+	/// `Cuneiform` (`Xsux`)
+	Cuneiform,
+	/// `Cypriot` (`Cprt`)
+	Cypriot,
+	/// `Cypro_Minoan` (`Cpmn`)
+	CyproMinoan,
+	/// `Cyrillic` (`Cyrl`)
+	Cyrillic,
+	/// `Deseret` (`Dsrt`)
+	Deseret,
+	/// `Devanagari` (`Deva`)
+	Devanagari,
+	/// `Dives_Akuru` (`Diak`)
+	DivesAkuru,
+	/// `Dogra` (`Dogr`)
+	Dogra,
+	/// `Duployan` (`Dupl`)
+	Duployan,
+	/// `Egyptian_Hieroglyphs` (`Egyp`)
+	EgyptianHieroglyphs,
+	/// `Elbasan` (`Elba`)
+	Elbasan,
+	/// `Elymaic` (`Elym`)
+	Elymaic,
+	/// `Ethiopic` (`Ethi`)
+	Ethiopic,
+	/// `Garay` (`Gara`)
+	Garay,
+	/// `Georgian` (`Geor`)
+	Georgian,
+	/// `Glagolitic` (`Glag`)
+	Glagolitic,
+	/// `Gothic` (`Goth`)
+	Gothic,
+	/// `Grantha` (`Gran`)
+	Grantha,
+	/// `Greek` (`Grek`)
+	Greek,
+	/// `Gujarati` (`Gujr`)
+	Gujarati,
+	/// `Gunjala_Gondi` (`Gong`)
+	GunjalaGondi,
+	/// `Gurmukhi` (`Guru`)
+	Gurmukhi,
+	/// `Gurung_Khema` (`Gukh`)
+	GurungKhema,
+	/// `Han` (`Hani`)
+	Han,
+	/// `Hangul` (`Hang`)
+	Hangul,
+	/// `Hanifi_Rohingya` (`Rohg`)
+	HanifiRohingya,
+	/// `Hanunoo` (`Hano`)
+	Hanunoo,
+	/// `Hatran` (`Hatr`)
+	Hatran,
+	/// `Hebrew` (`Hebr`)
+	Hebrew,
+	/// `Hiragana` (`Hira`)
+	Hiragana,
+	/// `Imperial_Aramaic` (`Armi`)
+	ImperialAramaic,
+	/// `Inherited` (`Zinh`)
+	Inherited,
+	/// `Inscriptional_Pahlavi` (`Phli`)
+	InscriptionalPahlavi,
+	/// `Inscriptional_Parthian` (`Prti`)
+	InscriptionalParthian,
+	/// `Javanese` (`Java`)
+	Javanese,
+	/// `Kaithi` (`Kthi`)
+	Kaithi,
+	/// `Kannada` (`Knda`)
+	Kannada,
+	/// `Katakana` (`Kana`)
+	Katakana,
+	/// `Kawi` (`Kawi`)
+	Kawi,
+	/// `Kayah_Li` (`Kali`)
+	KayahLi,
+	/// `Kharoshthi` (`Khar`)
+	Kharoshthi,
+	/// `Khitan_Small_Script` (`Kits`)
+	KhitanSmallScript,
+	/// `Khmer` (`Khmr`)
+	Khmer,
+	/// `Khojki` (`Khoj`)
+	Khojki,
+	/// `Khudawadi` (`Sind`)
+	Khudawadi,
+	/// `Kirat_Rai` (`Krai`)
+	KiratRai,
+	/// `Lao` (`Laoo`)
+	Lao,
+	/// `Latin` (`Latn`)
+	Latin,
+	/// `Lepcha` (`Lepc`)
+	Lepcha,
+	/// `Limbu` (`Limb`)
+	Limbu,
+	/// `Linear_A` (`Lina`)
+	LinearA,
+	/// `Linear_B` (`Linb`)
+	LinearB,
+	/// `Lisu` (`Lisu`)
+	Lisu,
+	/// `Lycian` (`Lyci`)
+	Lycian,
+	/// `Lydian` (`Lydi`)
+	Lydian,
+	/// `Mahajani` (`Mahj`)
+	Mahajani,
+	/// `Makasar` (`Maka`)
+	Makasar,
+	/// `Malayalam` (`Mlym`)
+	Malayalam,
+	/// `Mandaic` (`Mand`)
+	Mandaic,
+	/// `Manichaean` (`Mani`)
+	Manichaean,
+	/// `Marchen` (`Marc`)
+	Marchen,
+	/// `Masaram_Gondi` (`Gonm`)
+	MasaramGondi,
+	/// `Medefaidrin` (`Medf`)
+	Medefaidrin,
+	/// `Meetei_Mayek` (`Mtei`)
+	MeeteiMayek,
+	/// `Mende_Kikakui` (`Mend`)
+	MendeKikakui,
+	/// `Meroitic_Cursive` (`Merc`)
+	MeroiticCursive,
+	/// `Meroitic_Hieroglyphs` (`Mero`)
+	MeroiticHieroglyphs,
+	/// `Miao` (`Plrd`)
+	Miao,
+	/// `Modi` (`Modi`)
+	Modi,
+	/// `Mongolian` (`Mong`)
+	Mongolian,
+	/// `Mro` (`Mroo`)
+	Mro,
+	/// `Multani` (`Mult`)
+	Multani,
+	/// `Myanmar` (`Mymr`)
+	Myanmar,
+	/// `Nabataean` (`Nbat`)
+	Nabataean,
+	/// `Nag_Mundari` (`Nagm`)
+	NagMundari,
+	/// `Nandinagari` (`Nand`)
+	Nandinagari,
+	/// `New_Tai_Lue` (`Talu`)
+	NewTaiLue,
+	/// `Newa` (`Newa`)
+	Newa,
+	/// `Nko` (`Nkoo`)
+	Nko,
+	/// `Nushu` (`Nshu`)
+	Nushu,
+	/// `Nyiakeng_Puachue_Hmong` (`Hmnp`)
+	NyiakengPuachueHmong,
+	/// `Ogham` (`Ogam`)
+	Ogham,
+	/// `Ol_Chiki` (`Olck`)
+	OlChiki,
+	/// `Ol_Onal` (`Onao`)
+	OlOnal,
+	/// `Old_Hungarian` (`Hung`)
+	OldHungarian,
+	/// `Old_Italic` (`Ital`)
+	OldItalic,
+	/// `Old_North_Arabian` (`Narb`)
+	OldNorthArabian,
+	/// `Old_Permic` (`Perm`)
+	OldPermic,
+	/// `Old_Persian` (`Xpeo`)
+	OldPersian,
+	/// `Old_Sogdian` (`Sogo`)
+	OldSogdian,
+	/// `Old_South_Arabian` (`Sarb`)
+	OldSouthArabian,
+	/// `Old_Turkic` (`Orkh`)
+	OldTurkic,
+	/// `Old_Uyghur` (`Ougr`)
+	OldUyghur,
+	/// `Oriya` (`Orya`)
+	Oriya,
+	/// `Osage` (`Osge`)
+	Osage,
+	/// `Osmanya` (`Osma`)
+	Osmanya,
+	/// `Pahawh_Hmong` (`Hmng`)
+	PahawhHmong,
+	/// `Palmyrene` (`Palm`)
+	Palmyrene,
+	/// `Pau_Cin_Hau` (`Pauc`)
+	PauCinHau,
+	/// `Phags_Pa` (`Phag`)
+	PhagsPa,
+	/// `Phoenician` (`Phnx`)
+	Phoenician,
+	/// `Psalter_Pahlavi` (`Phlp`)
+	PsalterPahlavi,
+	/// `Rejang` (`Rjng`)
+	Rejang,
+	/// `Runic` (`Runr`)
+	Runic,
+	/// `Samaritan` (`Samr`)
+	Samaritan,
+	/// `Saurashtra` (`Saur`)
+	Saurashtra,
+	/// `Sharada` (`Shrd`)
+	Sharada,
+	/// `Shavian` (`Shaw`)
+	Shavian,
+	/// `Siddham` (`Sidd`)
+	Siddham,
+	/// `Sidetic` (`Sidt`)
+	Sidetic,
+	/// `SignWriting` (`Sgnw`)
+	SignWriting,
+	/// `Sinhala` (`Sinh`)
+	Sinhala,
+	/// `Sogdian` (`Sogd`)
+	Sogdian,
+	/// `Sora_Sompeng` (`Sora`)
+	SoraSompeng,
+	/// `Soyombo` (`Soyo`)
+	Soyombo,
+	/// `Sundanese` (`Sund`)
+	Sundanese,
+	/// `Sunuwar` (`Sunu`)
+	Sunuwar,
+	/// `Syloti_Nagri` (`Sylo`)
+	SylotiNagri,
+	/// `Syriac` (`Syrc`)
+	Syriac,
+	/// `Tagalog` (`Tglg`)
+	Tagalog,
+	/// `Tagbanwa` (`Tagb`)
+	Tagbanwa,
+	/// `Tai_Le` (`Tale`)
+	TaiLe,
+	/// `Tai_Tham` (`Lana`)
+	TaiTham,
+	/// `Tai_Viet` (`Tavt`)
+	TaiViet,
+	/// `Tai_Yo` (`Tayo`)
+	TaiYo,
+	/// `Takri` (`Takr`)
+	Takri,
+	/// `Tamil` (`Taml`)
+	Tamil,
+	/// `Tangsa` (`Tnsa`)
+	Tangsa,
+	/// `Tangut` (`Tang`)
+	Tangut,
+	/// `Telugu` (`Telu`)
+	Telugu,
+	/// `Thaana` (`Thaa`)
+	Thaana,
+	/// `Thai` (`Thai`)
+	Thai,
+	/// `Tibetan` (`Tibt`)
+	Tibetan,
+	/// `Tifinagh` (`Tfng`)
+	Tifinagh,
+	/// `Tirhuta` (`Tirh`)
+	Tirhuta,
+	/// `Todhri` (`Todr`)
+	Todhri,
+	/// `Tolong_Siki` (`Tols`)
+	TolongSiki,
+	/// `Toto` (`Toto`)
+	Toto,
+	/// `Tulu_Tigalari` (`Tutg`)
+	TuluTigalari,
+	/// `Ugaritic` (`Ugar`)
+	Ugaritic,
+	/// `Unknown` (`Zzzz`)
+	Unknown,
+	/// `Vai` (`Vaii`)
+	Vai,
+	/// `Vithkuqi` (`Vith`)
+	Vithkuqi,
+	/// `Wancho` (`Wcho`)
+	Wancho,
+	/// `Warang_Citi` (`Wara`)
+	WarangCiti,
+	/// `Yezidi` (`Yezi`)
+	Yezidi,
+	/// `Yi` (`Yiii`)
+	Yi,
+	/// `Zanabazar_Square` (`Zanb`)
+	ZanabazarSquare,
+}
+
+static SCRIPTS: [(Codepoints, Script); 984] = [
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0000, 0x0040)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0041, 0x005a)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x005b, 0x0060)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0061, 0x007a)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x007b, 0x00a9)), Script::Common),
+	(Codepoints::Single(0x00aa), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x00ab, 0x00b9)), Script::Common),
+	(Codepoints::Single(0x00ba), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x00bb, 0x00bf)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x00c0, 0x00d6)), Script::Latin),
+	(Codepoints::Single(0x00d7), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x00d8, 0x00f6)), Script::Latin),
+	(Codepoints::Single(0x00f7), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x00f8, 0x02b8)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x02b9, 0x02df)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x02e0, 0x02e4)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x02e5, 0x02e9)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x02ea, 0x02eb)), Script::Bopomofo),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x02ec, 0x02ff)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0300, 0x036f)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0370, 0x0373)), Script::Greek),
+	(Codepoints::Single(0x0374), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0375, 0x0377)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x037a, 0x037d)), Script::Greek),
+	(Codepoints::Single(0x037e), Script::Common),
+	(Codepoints::Single(0x037f), Script::Greek),
+	(Codepoints::Single(0x0384), Script::Greek),
+	(Codepoints::Single(0x0385), Script::Common),
+	(Codepoints::Single(0x0386), Script::Greek),
+	(Codepoints::Single(0x0387), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0388, 0x038a)), Script::Greek),
+	(Codepoints::Single(0x038c), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x038e, 0x03a1)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x03a3, 0x03e1)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x03e2, 0x03ef)), Script::Coptic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x03f0, 0x03ff)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0400, 0x0484)), Script::Cyrillic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0485, 0x0486)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0487, 0x052f)), Script::Cyrillic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0531, 0x0556)), Script::Armenian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0559, 0x058a)), Script::Armenian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x058d, 0x058f)), Script::Armenian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0591, 0x05c7)), Script::Hebrew),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x05d0, 0x05ea)), Script::Hebrew),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x05ef, 0x05f4)), Script::Hebrew),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0600, 0x0604)), Script::Arabic),
+	(Codepoints::Single(0x0605), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0606, 0x060b)), Script::Arabic),
+	(Codepoints::Single(0x060c), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x060d, 0x061a)), Script::Arabic),
+	(Codepoints::Single(0x061b), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x061c, 0x061e)), Script::Arabic),
+	(Codepoints::Single(0x061f), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0620, 0x063f)), Script::Arabic),
+	(Codepoints::Single(0x0640), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0641, 0x064a)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x064b, 0x0655)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0656, 0x066f)), Script::Arabic),
+	(Codepoints::Single(0x0670), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0671, 0x06dc)), Script::Arabic),
+	(Codepoints::Single(0x06dd), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x06de, 0x06ff)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0700, 0x070d)), Script::Syriac),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x070f, 0x074a)), Script::Syriac),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x074d, 0x074f)), Script::Syriac),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0750, 0x077f)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0780, 0x07b1)), Script::Thaana),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x07c0, 0x07fa)), Script::Nko),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x07fd, 0x07ff)), Script::Nko),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0800, 0x082d)), Script::Samaritan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0830, 0x083e)), Script::Samaritan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0840, 0x085b)), Script::Mandaic),
+	(Codepoints::Single(0x085e), Script::Mandaic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0860, 0x086a)), Script::Syriac),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0870, 0x0891)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0897, 0x08e1)), Script::Arabic),
+	(Codepoints::Single(0x08e2), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x08e3, 0x08ff)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0900, 0x0950)), Script::Devanagari),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0951, 0x0954)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0955, 0x0963)), Script::Devanagari),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0964, 0x0965)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0966, 0x097f)), Script::Devanagari),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0980, 0x0983)), Script::Bengali),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0985, 0x098c)), Script::Bengali),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x098f, 0x0990)), Script::Bengali),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0993, 0x09a8)), Script::Bengali),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09aa, 0x09b0)), Script::Bengali),
+	(Codepoints::Single(0x09b2), Script::Bengali),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09b6, 0x09b9)), Script::Bengali),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09bc, 0x09c4)), Script::Bengali),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09c7, 0x09c8)), Script::Bengali),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09cb, 0x09ce)), Script::Bengali),
+	(Codepoints::Single(0x09d7), Script::Bengali),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09dc, 0x09dd)), Script::Bengali),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09df, 0x09e3)), Script::Bengali),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09e6, 0x09fe)), Script::Bengali),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a01, 0x0a03)), Script::Gurmukhi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a05, 0x0a0a)), Script::Gurmukhi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a0f, 0x0a10)), Script::Gurmukhi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a13, 0x0a28)), Script::Gurmukhi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a2a, 0x0a30)), Script::Gurmukhi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a32, 0x0a33)), Script::Gurmukhi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a35, 0x0a36)), Script::Gurmukhi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a38, 0x0a39)), Script::Gurmukhi),
+	(Codepoints::Single(0x0a3c), Script::Gurmukhi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a3e, 0x0a42)), Script::Gurmukhi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a47, 0x0a48)), Script::Gurmukhi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a4b, 0x0a4d)), Script::Gurmukhi),
+	(Codepoints::Single(0x0a51), Script::Gurmukhi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a59, 0x0a5c)), Script::Gurmukhi),
+	(Codepoints::Single(0x0a5e), Script::Gurmukhi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a66, 0x0a76)), Script::Gurmukhi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a81, 0x0a83)), Script::Gujarati),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a85, 0x0a8d)), Script::Gujarati),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a8f, 0x0a91)), Script::Gujarati),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a93, 0x0aa8)), Script::Gujarati),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0aaa, 0x0ab0)), Script::Gujarati),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ab2, 0x0ab3)), Script::Gujarati),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ab5, 0x0ab9)), Script::Gujarati),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0abc, 0x0ac5)), Script::Gujarati),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ac7, 0x0ac9)), Script::Gujarati),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0acb, 0x0acd)), Script::Gujarati),
+	(Codepoints::Single(0x0ad0), Script::Gujarati),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ae0, 0x0ae3)), Script::Gujarati),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ae6, 0x0af1)), Script::Gujarati),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0af9, 0x0aff)), Script::Gujarati),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b01, 0x0b03)), Script::Oriya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b05, 0x0b0c)), Script::Oriya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b0f, 0x0b10)), Script::Oriya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b13, 0x0b28)), Script::Oriya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b2a, 0x0b30)), Script::Oriya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b32, 0x0b33)), Script::Oriya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b35, 0x0b39)), Script::Oriya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b3c, 0x0b44)), Script::Oriya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b47, 0x0b48)), Script::Oriya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b4b, 0x0b4d)), Script::Oriya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b55, 0x0b57)), Script::Oriya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b5c, 0x0b5d)), Script::Oriya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b5f, 0x0b63)), Script::Oriya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b66, 0x0b77)), Script::Oriya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b82, 0x0b83)), Script::Tamil),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b85, 0x0b8a)), Script::Tamil),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b8e, 0x0b90)), Script::Tamil),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b92, 0x0b95)), Script::Tamil),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b99, 0x0b9a)), Script::Tamil),
+	(Codepoints::Single(0x0b9c), Script::Tamil),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0b9e, 0x0b9f)), Script::Tamil),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ba3, 0x0ba4)), Script::Tamil),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ba8, 0x0baa)), Script::Tamil),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0bae, 0x0bb9)), Script::Tamil),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0bbe, 0x0bc2)), Script::Tamil),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0bc6, 0x0bc8)), Script::Tamil),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0bca, 0x0bcd)), Script::Tamil),
+	(Codepoints::Single(0x0bd0), Script::Tamil),
+	(Codepoints::Single(0x0bd7), Script::Tamil),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0be6, 0x0bfa)), Script::Tamil),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c00, 0x0c0c)), Script::Telugu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c0e, 0x0c10)), Script::Telugu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c12, 0x0c28)), Script::Telugu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c2a, 0x0c39)), Script::Telugu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c3c, 0x0c44)), Script::Telugu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c46, 0x0c48)), Script::Telugu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c4a, 0x0c4d)), Script::Telugu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c55, 0x0c56)), Script::Telugu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c58, 0x0c5a)), Script::Telugu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c5c, 0x0c5d)), Script::Telugu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c60, 0x0c63)), Script::Telugu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c66, 0x0c6f)), Script::Telugu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c77, 0x0c7f)), Script::Telugu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c80, 0x0c8c)), Script::Kannada),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c8e, 0x0c90)), Script::Kannada),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0c92, 0x0ca8)), Script::Kannada),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0caa, 0x0cb3)), Script::Kannada),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cb5, 0x0cb9)), Script::Kannada),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cbc, 0x0cc4)), Script::Kannada),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cc6, 0x0cc8)), Script::Kannada),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cca, 0x0ccd)), Script::Kannada),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cd5, 0x0cd6)), Script::Kannada),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cdc, 0x0cde)), Script::Kannada),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ce0, 0x0ce3)), Script::Kannada),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ce6, 0x0cef)), Script::Kannada),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0cf1, 0x0cf3)), Script::Kannada),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d00, 0x0d0c)), Script::Malayalam),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d0e, 0x0d10)), Script::Malayalam),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d12, 0x0d44)), Script::Malayalam),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d46, 0x0d48)), Script::Malayalam),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d4a, 0x0d4f)), Script::Malayalam),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d54, 0x0d63)), Script::Malayalam),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d66, 0x0d7f)), Script::Malayalam),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d81, 0x0d83)), Script::Sinhala),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d85, 0x0d96)), Script::Sinhala),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0d9a, 0x0db1)), Script::Sinhala),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0db3, 0x0dbb)), Script::Sinhala),
+	(Codepoints::Single(0x0dbd), Script::Sinhala),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0dc0, 0x0dc6)), Script::Sinhala),
+	(Codepoints::Single(0x0dca), Script::Sinhala),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0dcf, 0x0dd4)), Script::Sinhala),
+	(Codepoints::Single(0x0dd6), Script::Sinhala),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0dd8, 0x0ddf)), Script::Sinhala),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0de6, 0x0def)), Script::Sinhala),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0df2, 0x0df4)), Script::Sinhala),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e01, 0x0e3a)), Script::Thai),
+	(Codepoints::Single(0x0e3f), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e40, 0x0e5b)), Script::Thai),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e81, 0x0e82)), Script::Lao),
+	(Codepoints::Single(0x0e84), Script::Lao),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e86, 0x0e8a)), Script::Lao),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0e8c, 0x0ea3)), Script::Lao),
+	(Codepoints::Single(0x0ea5), Script::Lao),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ea7, 0x0ebd)), Script::Lao),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ec0, 0x0ec4)), Script::Lao),
+	(Codepoints::Single(0x0ec6), Script::Lao),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ec8, 0x0ece)), Script::Lao),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ed0, 0x0ed9)), Script::Lao),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0edc, 0x0edf)), Script::Lao),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f00, 0x0f47)), Script::Tibetan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f49, 0x0f6c)), Script::Tibetan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f71, 0x0f97)), Script::Tibetan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0f99, 0x0fbc)), Script::Tibetan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0fbe, 0x0fcc)), Script::Tibetan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0fce, 0x0fd4)), Script::Tibetan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0fd5, 0x0fd8)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0fd9, 0x0fda)), Script::Tibetan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1000, 0x109f)), Script::Myanmar),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a0, 0x10c5)), Script::Georgian),
+	(Codepoints::Single(0x10c7), Script::Georgian),
+	(Codepoints::Single(0x10cd), Script::Georgian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10d0, 0x10fa)), Script::Georgian),
+	(Codepoints::Single(0x10fb), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10fc, 0x10ff)), Script::Georgian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1100, 0x11ff)), Script::Hangul),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1200, 0x1248)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x124a, 0x124d)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1250, 0x1256)), Script::Ethiopic),
+	(Codepoints::Single(0x1258), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x125a, 0x125d)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1260, 0x1288)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x128a, 0x128d)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1290, 0x12b0)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12b2, 0x12b5)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12b8, 0x12be)), Script::Ethiopic),
+	(Codepoints::Single(0x12c0), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12c2, 0x12c5)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12c8, 0x12d6)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12d8, 0x1310)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1312, 0x1315)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1318, 0x135a)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x135d, 0x137c)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1380, 0x1399)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x13a0, 0x13f5)), Script::Cherokee),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x13f8, 0x13fd)), Script::Cherokee),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1400, 0x167f)), Script::CanadianAboriginal),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1680, 0x169c)), Script::Ogham),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16a0, 0x16ea)), Script::Runic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16eb, 0x16ed)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16ee, 0x16f8)), Script::Runic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1700, 0x1715)), Script::Tagalog),
+	(Codepoints::Single(0x171f), Script::Tagalog),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1720, 0x1734)), Script::Hanunoo),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1735, 0x1736)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1740, 0x1753)), Script::Buhid),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1760, 0x176c)), Script::Tagbanwa),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x176e, 0x1770)), Script::Tagbanwa),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1772, 0x1773)), Script::Tagbanwa),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1780, 0x17dd)), Script::Khmer),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x17e0, 0x17e9)), Script::Khmer),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x17f0, 0x17f9)), Script::Khmer),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1800, 0x1801)), Script::Mongolian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1802, 0x1803)), Script::Common),
+	(Codepoints::Single(0x1804), Script::Mongolian),
+	(Codepoints::Single(0x1805), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1806, 0x1819)), Script::Mongolian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1820, 0x1878)), Script::Mongolian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1880, 0x18aa)), Script::Mongolian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x18b0, 0x18f5)), Script::CanadianAboriginal),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1900, 0x191e)), Script::Limbu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1920, 0x192b)), Script::Limbu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1930, 0x193b)), Script::Limbu),
+	(Codepoints::Single(0x1940), Script::Limbu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1944, 0x194f)), Script::Limbu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1950, 0x196d)), Script::TaiLe),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1970, 0x1974)), Script::TaiLe),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1980, 0x19ab)), Script::NewTaiLue),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x19b0, 0x19c9)), Script::NewTaiLue),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x19d0, 0x19da)), Script::NewTaiLue),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x19de, 0x19df)), Script::NewTaiLue),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x19e0, 0x19ff)), Script::Khmer),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1a00, 0x1a1b)), Script::Buginese),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1a1e, 0x1a1f)), Script::Buginese),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1a20, 0x1a5e)), Script::TaiTham),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1a60, 0x1a7c)), Script::TaiTham),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1a7f, 0x1a89)), Script::TaiTham),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1a90, 0x1a99)), Script::TaiTham),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1aa0, 0x1aad)), Script::TaiTham),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ab0, 0x1add)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ae0, 0x1aeb)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b00, 0x1b4c)), Script::Balinese),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b4e, 0x1b7f)), Script::Balinese),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b80, 0x1bbf)), Script::Sundanese),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1bc0, 0x1bf3)), Script::Batak),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1bfc, 0x1bff)), Script::Batak),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1c00, 0x1c37)), Script::Lepcha),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1c3b, 0x1c49)), Script::Lepcha),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1c4d, 0x1c4f)), Script::Lepcha),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1c50, 0x1c7f)), Script::OlChiki),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1c80, 0x1c8a)), Script::Cyrillic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1c90, 0x1cba)), Script::Georgian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cbd, 0x1cbf)), Script::Georgian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cc0, 0x1cc7)), Script::Sundanese),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cd0, 0x1cd2)), Script::Inherited),
+	(Codepoints::Single(0x1cd3), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cd4, 0x1ce0)), Script::Inherited),
+	(Codepoints::Single(0x1ce1), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ce2, 0x1ce8)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ce9, 0x1cec)), Script::Common),
+	(Codepoints::Single(0x1ced), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cee, 0x1cf3)), Script::Common),
+	(Codepoints::Single(0x1cf4), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cf5, 0x1cf7)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cf8, 0x1cf9)), Script::Inherited),
+	(Codepoints::Single(0x1cfa), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d00, 0x1d25)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d26, 0x1d2a)), Script::Greek),
+	(Codepoints::Single(0x1d2b), Script::Cyrillic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d2c, 0x1d5c)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d5d, 0x1d61)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d62, 0x1d65)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d66, 0x1d6a)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d6b, 0x1d77)), Script::Latin),
+	(Codepoints::Single(0x1d78), Script::Cyrillic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d79, 0x1dbe)), Script::Latin),
+	(Codepoints::Single(0x1dbf), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1dc0, 0x1dff)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e00, 0x1eff)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f00, 0x1f15)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f18, 0x1f1d)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f20, 0x1f45)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f48, 0x1f4d)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f50, 0x1f57)), Script::Greek),
+	(Codepoints::Single(0x1f59), Script::Greek),
+	(Codepoints::Single(0x1f5b), Script::Greek),
+	(Codepoints::Single(0x1f5d), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f5f, 0x1f7d)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f80, 0x1fb4)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fb6, 0x1fc4)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fc6, 0x1fd3)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fd6, 0x1fdb)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fdd, 0x1fef)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ff2, 0x1ff4)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ff6, 0x1ffe)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2000, 0x200b)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x200c, 0x200d)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x200e, 0x2064)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2066, 0x2070)), Script::Common),
+	(Codepoints::Single(0x2071), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2074, 0x207e)), Script::Common),
+	(Codepoints::Single(0x207f), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2080, 0x208e)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2090, 0x209c)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x20a0, 0x20c1)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x20d0, 0x20f0)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2100, 0x2125)), Script::Common),
+	(Codepoints::Single(0x2126), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2127, 0x2129)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x212a, 0x212b)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x212c, 0x2131)), Script::Common),
+	(Codepoints::Single(0x2132), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2133, 0x214d)), Script::Common),
+	(Codepoints::Single(0x214e), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x214f, 0x215f)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2160, 0x2188)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2189, 0x218b)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2190, 0x2429)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2440, 0x244a)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2460, 0x27ff)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2800, 0x28ff)), Script::Braille),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2900, 0x2b73)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2b76, 0x2bff)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2c00, 0x2c5f)), Script::Glagolitic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2c60, 0x2c7f)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2c80, 0x2cf3)), Script::Coptic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2cf9, 0x2cff)), Script::Coptic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2d00, 0x2d25)), Script::Georgian),
+	(Codepoints::Single(0x2d27), Script::Georgian),
+	(Codepoints::Single(0x2d2d), Script::Georgian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2d30, 0x2d67)), Script::Tifinagh),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2d6f, 0x2d70)), Script::Tifinagh),
+	(Codepoints::Single(0x2d7f), Script::Tifinagh),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2d80, 0x2d96)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2da0, 0x2da6)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2da8, 0x2dae)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2db0, 0x2db6)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2db8, 0x2dbe)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2dc0, 0x2dc6)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2dc8, 0x2dce)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2dd0, 0x2dd6)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2dd8, 0x2dde)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2de0, 0x2dff)), Script::Cyrillic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2e00, 0x2e5d)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2e80, 0x2e99)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2e9b, 0x2ef3)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2f00, 0x2fd5)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2ff0, 0x3004)), Script::Common),
+	(Codepoints::Single(0x3005), Script::Han),
+	(Codepoints::Single(0x3006), Script::Common),
+	(Codepoints::Single(0x3007), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3008, 0x3020)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3021, 0x3029)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x302a, 0x302d)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x302e, 0x302f)), Script::Hangul),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3030, 0x3037)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3038, 0x303b)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x303c, 0x303f)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3041, 0x3096)), Script::Hiragana),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3099, 0x309a)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x309b, 0x309c)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x309d, 0x309f)), Script::Hiragana),
+	(Codepoints::Single(0x30a0), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x30a1, 0x30fa)), Script::Katakana),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x30fb, 0x30fc)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x30fd, 0x30ff)), Script::Katakana),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3105, 0x312f)), Script::Bopomofo),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3131, 0x318e)), Script::Hangul),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3190, 0x319f)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x31a0, 0x31bf)), Script::Bopomofo),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x31c0, 0x31e5)), Script::Common),
+	(Codepoints::Single(0x31ef), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x31f0, 0x31ff)), Script::Katakana),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3200, 0x321e)), Script::Hangul),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3220, 0x325f)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3260, 0x327e)), Script::Hangul),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x327f, 0x32cf)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x32d0, 0x32fe)), Script::Katakana),
+	(Codepoints::Single(0x32ff), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3300, 0x3357)), Script::Katakana),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3358, 0x33ff)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3400, 0x4dbf)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x4dc0, 0x4dff)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x4e00, 0x9fff)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa000, 0xa48c)), Script::Yi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa490, 0xa4c6)), Script::Yi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa4d0, 0xa4ff)), Script::Lisu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa500, 0xa62b)), Script::Vai),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa640, 0xa69f)), Script::Cyrillic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa6a0, 0xa6f7)), Script::Bamum),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa700, 0xa721)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa722, 0xa787)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa788, 0xa78a)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa78b, 0xa7dc)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa7f1, 0xa7ff)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa800, 0xa82c)), Script::SylotiNagri),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa830, 0xa839)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa840, 0xa877)), Script::PhagsPa),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa880, 0xa8c5)), Script::Saurashtra),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa8ce, 0xa8d9)), Script::Saurashtra),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa8e0, 0xa8ff)), Script::Devanagari),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa900, 0xa92d)), Script::KayahLi),
+	(Codepoints::Single(0xa92e), Script::Common),
+	(Codepoints::Single(0xa92f), Script::KayahLi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa930, 0xa953)), Script::Rejang),
+	(Codepoints::Single(0xa95f), Script::Rejang),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa960, 0xa97c)), Script::Hangul),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa980, 0xa9cd)), Script::Javanese),
+	(Codepoints::Single(0xa9cf), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa9d0, 0xa9d9)), Script::Javanese),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa9de, 0xa9df)), Script::Javanese),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa9e0, 0xa9fe)), Script::Myanmar),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa00, 0xaa36)), Script::Cham),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa40, 0xaa4d)), Script::Cham),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa50, 0xaa59)), Script::Cham),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa5c, 0xaa5f)), Script::Cham),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa60, 0xaa7f)), Script::Myanmar),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaa80, 0xaac2)), Script::TaiViet),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaadb, 0xaadf)), Script::TaiViet),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xaae0, 0xaaf6)), Script::MeeteiMayek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab01, 0xab06)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab09, 0xab0e)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab11, 0xab16)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab20, 0xab26)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab28, 0xab2e)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab30, 0xab5a)), Script::Latin),
+	(Codepoints::Single(0xab5b), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab5c, 0xab64)), Script::Latin),
+	(Codepoints::Single(0xab65), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab66, 0xab69)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab6a, 0xab6b)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xab70, 0xabbf)), Script::Cherokee),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xabc0, 0xabed)), Script::MeeteiMayek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xabf0, 0xabf9)), Script::MeeteiMayek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xac00, 0xd7a3)), Script::Hangul),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xd7b0, 0xd7c6)), Script::Hangul),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xd7cb, 0xd7fb)), Script::Hangul),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xf900, 0xfa6d)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfa70, 0xfad9)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb00, 0xfb06)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb13, 0xfb17)), Script::Armenian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb1d, 0xfb36)), Script::Hebrew),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb38, 0xfb3c)), Script::Hebrew),
+	(Codepoints::Single(0xfb3e), Script::Hebrew),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb40, 0xfb41)), Script::Hebrew),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb43, 0xfb44)), Script::Hebrew),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb46, 0xfb4f)), Script::Hebrew),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfb50, 0xfd3d)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfd3e, 0xfd3f)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfd40, 0xfdcf)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfdf0, 0xfdff)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe00, 0xfe0f)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe10, 0xfe19)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe20, 0xfe2d)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe2e, 0xfe2f)), Script::Cyrillic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe30, 0xfe52)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe54, 0xfe66)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe68, 0xfe6b)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe70, 0xfe74)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe76, 0xfefc)), Script::Arabic),
+	(Codepoints::Single(0xfeff), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xff01, 0xff20)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xff21, 0xff3a)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xff3b, 0xff40)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xff41, 0xff5a)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xff5b, 0xff65)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xff66, 0xff6f)), Script::Katakana),
+	(Codepoints::Single(0xff70), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xff71, 0xff9d)), Script::Katakana),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xff9e, 0xff9f)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffa0, 0xffbe)), Script::Hangul),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffc2, 0xffc7)), Script::Hangul),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffca, 0xffcf)), Script::Hangul),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffd2, 0xffd7)), Script::Hangul),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffda, 0xffdc)), Script::Hangul),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffe0, 0xffe6)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xffe8, 0xffee)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfff9, 0xfffd)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10000, 0x1000b)), Script::LinearB),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1000d, 0x10026)), Script::LinearB),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10028, 0x1003a)), Script::LinearB),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1003c, 0x1003d)), Script::LinearB),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1003f, 0x1004d)), Script::LinearB),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10050, 0x1005d)), Script::LinearB),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10080, 0x100fa)), Script::LinearB),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10100, 0x10102)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10107, 0x10133)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10137, 0x1013f)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10140, 0x1018e)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10190, 0x1019c)), Script::Common),
+	(Codepoints::Single(0x101a0), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x101d0, 0x101fc)), Script::Common),
+	(Codepoints::Single(0x101fd), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10280, 0x1029c)), Script::Lycian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x102a0, 0x102d0)), Script::Carian),
+	(Codepoints::Single(0x102e0), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x102e1, 0x102fb)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10300, 0x10323)), Script::OldItalic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1032d, 0x1032f)), Script::OldItalic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10330, 0x1034a)), Script::Gothic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10350, 0x1037a)), Script::OldPermic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10380, 0x1039d)), Script::Ugaritic),
+	(Codepoints::Single(0x1039f), Script::Ugaritic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x103a0, 0x103c3)), Script::OldPersian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x103c8, 0x103d5)), Script::OldPersian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10400, 0x1044f)), Script::Deseret),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10450, 0x1047f)), Script::Shavian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10480, 0x1049d)), Script::Osmanya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x104a0, 0x104a9)), Script::Osmanya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x104b0, 0x104d3)), Script::Osage),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x104d8, 0x104fb)), Script::Osage),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10500, 0x10527)), Script::Elbasan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10530, 0x10563)), Script::CaucasianAlbanian),
+	(Codepoints::Single(0x1056f), Script::CaucasianAlbanian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10570, 0x1057a)), Script::Vithkuqi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1057c, 0x1058a)), Script::Vithkuqi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1058c, 0x10592)), Script::Vithkuqi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10594, 0x10595)), Script::Vithkuqi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10597, 0x105a1)), Script::Vithkuqi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x105a3, 0x105b1)), Script::Vithkuqi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x105b3, 0x105b9)), Script::Vithkuqi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x105bb, 0x105bc)), Script::Vithkuqi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x105c0, 0x105f3)), Script::Todhri),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10600, 0x10736)), Script::LinearA),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10740, 0x10755)), Script::LinearA),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10760, 0x10767)), Script::LinearA),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10780, 0x10785)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10787, 0x107b0)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x107b2, 0x107ba)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10800, 0x10805)), Script::Cypriot),
+	(Codepoints::Single(0x10808), Script::Cypriot),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1080a, 0x10835)), Script::Cypriot),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10837, 0x10838)), Script::Cypriot),
+	(Codepoints::Single(0x1083c), Script::Cypriot),
+	(Codepoints::Single(0x1083f), Script::Cypriot),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10840, 0x10855)), Script::ImperialAramaic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10857, 0x1085f)), Script::ImperialAramaic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10860, 0x1087f)), Script::Palmyrene),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10880, 0x1089e)), Script::Nabataean),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x108a7, 0x108af)), Script::Nabataean),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x108e0, 0x108f2)), Script::Hatran),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x108f4, 0x108f5)), Script::Hatran),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x108fb, 0x108ff)), Script::Hatran),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10900, 0x1091b)), Script::Phoenician),
+	(Codepoints::Single(0x1091f), Script::Phoenician),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10920, 0x10939)), Script::Lydian),
+	(Codepoints::Single(0x1093f), Script::Lydian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10940, 0x10959)), Script::Sidetic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10980, 0x1099f)), Script::MeroiticHieroglyphs),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x109a0, 0x109b7)), Script::MeroiticCursive),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x109bc, 0x109cf)), Script::MeroiticCursive),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x109d2, 0x109ff)), Script::MeroiticCursive),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a00, 0x10a03)), Script::Kharoshthi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a05, 0x10a06)), Script::Kharoshthi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a0c, 0x10a13)), Script::Kharoshthi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a15, 0x10a17)), Script::Kharoshthi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a19, 0x10a35)), Script::Kharoshthi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a38, 0x10a3a)), Script::Kharoshthi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a3f, 0x10a48)), Script::Kharoshthi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a50, 0x10a58)), Script::Kharoshthi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a60, 0x10a7f)), Script::OldSouthArabian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10a80, 0x10a9f)), Script::OldNorthArabian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10ac0, 0x10ae6)), Script::Manichaean),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10aeb, 0x10af6)), Script::Manichaean),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b00, 0x10b35)), Script::Avestan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b39, 0x10b3f)), Script::Avestan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b40, 0x10b55)), Script::InscriptionalParthian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b58, 0x10b5f)), Script::InscriptionalParthian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b60, 0x10b72)), Script::InscriptionalPahlavi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b78, 0x10b7f)), Script::InscriptionalPahlavi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b80, 0x10b91)), Script::PsalterPahlavi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10b99, 0x10b9c)), Script::PsalterPahlavi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10ba9, 0x10baf)), Script::PsalterPahlavi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10c00, 0x10c48)), Script::OldTurkic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10c80, 0x10cb2)), Script::OldHungarian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10cc0, 0x10cf2)), Script::OldHungarian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10cfa, 0x10cff)), Script::OldHungarian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10d00, 0x10d27)), Script::HanifiRohingya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10d30, 0x10d39)), Script::HanifiRohingya),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10d40, 0x10d65)), Script::Garay),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10d69, 0x10d85)), Script::Garay),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10d8e, 0x10d8f)), Script::Garay),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10e60, 0x10e7e)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10e80, 0x10ea9)), Script::Yezidi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10eab, 0x10ead)), Script::Yezidi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10eb0, 0x10eb1)), Script::Yezidi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10ec2, 0x10ec7)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10ed0, 0x10ed8)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10efa, 0x10eff)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10f00, 0x10f27)), Script::OldSogdian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10f30, 0x10f59)), Script::Sogdian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10f70, 0x10f89)), Script::OldUyghur),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10fb0, 0x10fcb)), Script::Chorasmian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10fe0, 0x10ff6)), Script::Elymaic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11000, 0x1104d)), Script::Brahmi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11052, 0x11075)), Script::Brahmi),
+	(Codepoints::Single(0x1107f), Script::Brahmi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11080, 0x110c2)), Script::Kaithi),
+	(Codepoints::Single(0x110cd), Script::Kaithi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x110d0, 0x110e8)), Script::SoraSompeng),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x110f0, 0x110f9)), Script::SoraSompeng),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11100, 0x11134)), Script::Chakma),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11136, 0x11147)), Script::Chakma),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11150, 0x11176)), Script::Mahajani),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11180, 0x111df)), Script::Sharada),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x111e1, 0x111f4)), Script::Sinhala),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11200, 0x11211)), Script::Khojki),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11213, 0x11241)), Script::Khojki),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11280, 0x11286)), Script::Multani),
+	(Codepoints::Single(0x11288), Script::Multani),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1128a, 0x1128d)), Script::Multani),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1128f, 0x1129d)), Script::Multani),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1129f, 0x112a9)), Script::Multani),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x112b0, 0x112ea)), Script::Khudawadi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x112f0, 0x112f9)), Script::Khudawadi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11300, 0x11303)), Script::Grantha),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11305, 0x1130c)), Script::Grantha),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1130f, 0x11310)), Script::Grantha),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11313, 0x11328)), Script::Grantha),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1132a, 0x11330)), Script::Grantha),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11332, 0x11333)), Script::Grantha),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11335, 0x11339)), Script::Grantha),
+	(Codepoints::Single(0x1133b), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1133c, 0x11344)), Script::Grantha),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11347, 0x11348)), Script::Grantha),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1134b, 0x1134d)), Script::Grantha),
+	(Codepoints::Single(0x11350), Script::Grantha),
+	(Codepoints::Single(0x11357), Script::Grantha),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1135d, 0x11363)), Script::Grantha),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11366, 0x1136c)), Script::Grantha),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11370, 0x11374)), Script::Grantha),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11380, 0x11389)), Script::TuluTigalari),
+	(Codepoints::Single(0x1138b), Script::TuluTigalari),
+	(Codepoints::Single(0x1138e), Script::TuluTigalari),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11390, 0x113b5)), Script::TuluTigalari),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x113b7, 0x113c0)), Script::TuluTigalari),
+	(Codepoints::Single(0x113c2), Script::TuluTigalari),
+	(Codepoints::Single(0x113c5), Script::TuluTigalari),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x113c7, 0x113ca)), Script::TuluTigalari),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x113cc, 0x113d5)), Script::TuluTigalari),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x113d7, 0x113d8)), Script::TuluTigalari),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x113e1, 0x113e2)), Script::TuluTigalari),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11400, 0x1145b)), Script::Newa),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1145d, 0x11461)), Script::Newa),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11480, 0x114c7)), Script::Tirhuta),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x114d0, 0x114d9)), Script::Tirhuta),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11580, 0x115b5)), Script::Siddham),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x115b8, 0x115dd)), Script::Siddham),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11600, 0x11644)), Script::Modi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11650, 0x11659)), Script::Modi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11660, 0x1166c)), Script::Mongolian),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11680, 0x116b9)), Script::Takri),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x116c0, 0x116c9)), Script::Takri),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x116d0, 0x116e3)), Script::Myanmar),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11700, 0x1171a)), Script::Ahom),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1171d, 0x1172b)), Script::Ahom),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11730, 0x11746)), Script::Ahom),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11800, 0x1183b)), Script::Dogra),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x118a0, 0x118f2)), Script::WarangCiti),
+	(Codepoints::Single(0x118ff), Script::WarangCiti),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11900, 0x11906)), Script::DivesAkuru),
+	(Codepoints::Single(0x11909), Script::DivesAkuru),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1190c, 0x11913)), Script::DivesAkuru),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11915, 0x11916)), Script::DivesAkuru),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11918, 0x11935)), Script::DivesAkuru),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11937, 0x11938)), Script::DivesAkuru),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1193b, 0x11946)), Script::DivesAkuru),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11950, 0x11959)), Script::DivesAkuru),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x119a0, 0x119a7)), Script::Nandinagari),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x119aa, 0x119d7)), Script::Nandinagari),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x119da, 0x119e4)), Script::Nandinagari),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11a00, 0x11a47)), Script::ZanabazarSquare),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11a50, 0x11aa2)), Script::Soyombo),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11ab0, 0x11abf)), Script::CanadianAboriginal),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11ac0, 0x11af8)), Script::PauCinHau),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11b00, 0x11b09)), Script::Devanagari),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11b60, 0x11b67)), Script::Sharada),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11bc0, 0x11be1)), Script::Sunuwar),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11bf0, 0x11bf9)), Script::Sunuwar),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11c00, 0x11c08)), Script::Bhaiksuki),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11c0a, 0x11c36)), Script::Bhaiksuki),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11c38, 0x11c45)), Script::Bhaiksuki),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11c50, 0x11c6c)), Script::Bhaiksuki),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11c70, 0x11c8f)), Script::Marchen),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11c92, 0x11ca7)), Script::Marchen),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11ca9, 0x11cb6)), Script::Marchen),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11d00, 0x11d06)), Script::MasaramGondi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11d08, 0x11d09)), Script::MasaramGondi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11d0b, 0x11d36)), Script::MasaramGondi),
+	(Codepoints::Single(0x11d3a), Script::MasaramGondi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11d3c, 0x11d3d)), Script::MasaramGondi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11d3f, 0x11d47)), Script::MasaramGondi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11d50, 0x11d59)), Script::MasaramGondi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11d60, 0x11d65)), Script::GunjalaGondi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11d67, 0x11d68)), Script::GunjalaGondi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11d6a, 0x11d8e)), Script::GunjalaGondi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11d90, 0x11d91)), Script::GunjalaGondi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11d93, 0x11d98)), Script::GunjalaGondi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11da0, 0x11da9)), Script::GunjalaGondi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11db0, 0x11ddb)), Script::TolongSiki),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11de0, 0x11de9)), Script::TolongSiki),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11ee0, 0x11ef8)), Script::Makasar),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11f00, 0x11f10)), Script::Kawi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11f12, 0x11f3a)), Script::Kawi),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11f3e, 0x11f5a)), Script::Kawi),
+	(Codepoints::Single(0x11fb0), Script::Lisu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11fc0, 0x11ff1)), Script::Tamil),
+	(Codepoints::Single(0x11fff), Script::Tamil),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12000, 0x12399)), Script::Cuneiform),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12400, 0x1246e)), Script::Cuneiform),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12470, 0x12474)), Script::Cuneiform),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12480, 0x12543)), Script::Cuneiform),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x12f90, 0x12ff2)), Script::CyproMinoan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x13000, 0x13455)), Script::EgyptianHieroglyphs),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x13460, 0x143fa)), Script::EgyptianHieroglyphs),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x14400, 0x14646)), Script::AnatolianHieroglyphs),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16100, 0x16139)), Script::GurungKhema),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16800, 0x16a38)), Script::Bamum),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16a40, 0x16a5e)), Script::Mro),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16a60, 0x16a69)), Script::Mro),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16a6e, 0x16a6f)), Script::Mro),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16a70, 0x16abe)), Script::Tangsa),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16ac0, 0x16ac9)), Script::Tangsa),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16ad0, 0x16aed)), Script::BassaVah),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16af0, 0x16af5)), Script::BassaVah),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16b00, 0x16b45)), Script::PahawhHmong),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16b50, 0x16b59)), Script::PahawhHmong),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16b5b, 0x16b61)), Script::PahawhHmong),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16b63, 0x16b77)), Script::PahawhHmong),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16b7d, 0x16b8f)), Script::PahawhHmong),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16d40, 0x16d79)), Script::KiratRai),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16e40, 0x16e9a)), Script::Medefaidrin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16ea0, 0x16eb8)), Script::BeriaErfe),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16ebb, 0x16ed3)), Script::BeriaErfe),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16f00, 0x16f4a)), Script::Miao),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16f4f, 0x16f87)), Script::Miao),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16f8f, 0x16f9f)), Script::Miao),
+	(Codepoints::Single(0x16fe0), Script::Tangut),
+	(Codepoints::Single(0x16fe1), Script::Nushu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16fe2, 0x16fe3)), Script::Han),
+	(Codepoints::Single(0x16fe4), Script::KhitanSmallScript),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16ff0, 0x16ff6)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x17000, 0x18aff)), Script::Tangut),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x18b00, 0x18cd5)), Script::KhitanSmallScript),
+	(Codepoints::Single(0x18cff), Script::KhitanSmallScript),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x18d00, 0x18d1e)), Script::Tangut),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x18d80, 0x18df2)), Script::Tangut),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1aff0, 0x1aff3)), Script::Katakana),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1aff5, 0x1affb)), Script::Katakana),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1affd, 0x1affe)), Script::Katakana),
+	(Codepoints::Single(0x1b000), Script::Katakana),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b001, 0x1b11f)), Script::Hiragana),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b120, 0x1b122)), Script::Katakana),
+	(Codepoints::Single(0x1b132), Script::Hiragana),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b150, 0x1b152)), Script::Hiragana),
+	(Codepoints::Single(0x1b155), Script::Katakana),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b164, 0x1b167)), Script::Katakana),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1b170, 0x1b2fb)), Script::Nushu),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1bc00, 0x1bc6a)), Script::Duployan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1bc70, 0x1bc7c)), Script::Duployan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1bc80, 0x1bc88)), Script::Duployan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1bc90, 0x1bc99)), Script::Duployan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1bc9c, 0x1bc9f)), Script::Duployan),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1bca0, 0x1bca3)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cc00, 0x1ccfc)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cd00, 0x1ceb3)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ceba, 0x1ced0)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cee0, 0x1cef0)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cf00, 0x1cf2d)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cf30, 0x1cf46)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cf50, 0x1cfc3)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d000, 0x1d0f5)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d100, 0x1d126)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d129, 0x1d166)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d167, 0x1d169)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d16a, 0x1d17a)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d17b, 0x1d182)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d183, 0x1d184)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d185, 0x1d18b)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d18c, 0x1d1a9)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d1aa, 0x1d1ad)), Script::Inherited),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d1ae, 0x1d1ea)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d200, 0x1d245)), Script::Greek),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d2c0, 0x1d2d3)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d2e0, 0x1d2f3)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d300, 0x1d356)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d360, 0x1d378)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d400, 0x1d454)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d456, 0x1d49c)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d49e, 0x1d49f)), Script::Common),
+	(Codepoints::Single(0x1d4a2), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d4a5, 0x1d4a6)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d4a9, 0x1d4ac)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d4ae, 0x1d4b9)), Script::Common),
+	(Codepoints::Single(0x1d4bb), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d4bd, 0x1d4c3)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d4c5, 0x1d505)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d507, 0x1d50a)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d50d, 0x1d514)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d516, 0x1d51c)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d51e, 0x1d539)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d53b, 0x1d53e)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d540, 0x1d544)), Script::Common),
+	(Codepoints::Single(0x1d546), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d54a, 0x1d550)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d552, 0x1d6a5)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d6a8, 0x1d7cb)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d7ce, 0x1d7ff)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d800, 0x1da8b)), Script::SignWriting),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1da9b, 0x1da9f)), Script::SignWriting),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1daa1, 0x1daaf)), Script::SignWriting),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1df00, 0x1df1e)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1df25, 0x1df2a)), Script::Latin),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e000, 0x1e006)), Script::Glagolitic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e008, 0x1e018)), Script::Glagolitic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e01b, 0x1e021)), Script::Glagolitic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e023, 0x1e024)), Script::Glagolitic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e026, 0x1e02a)), Script::Glagolitic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e030, 0x1e06d)), Script::Cyrillic),
+	(Codepoints::Single(0x1e08f), Script::Cyrillic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e100, 0x1e12c)), Script::NyiakengPuachueHmong),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e130, 0x1e13d)), Script::NyiakengPuachueHmong),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e140, 0x1e149)), Script::NyiakengPuachueHmong),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e14e, 0x1e14f)), Script::NyiakengPuachueHmong),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e290, 0x1e2ae)), Script::Toto),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e2c0, 0x1e2f9)), Script::Wancho),
+	(Codepoints::Single(0x1e2ff), Script::Wancho),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e4d0, 0x1e4f9)), Script::NagMundari),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e5d0, 0x1e5fa)), Script::OlOnal),
+	(Codepoints::Single(0x1e5ff), Script::OlOnal),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e6c0, 0x1e6de)), Script::TaiYo),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e6e0, 0x1e6f5)), Script::TaiYo),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e6fe, 0x1e6ff)), Script::TaiYo),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e7e0, 0x1e7e6)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e7e8, 0x1e7eb)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e7ed, 0x1e7ee)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e7f0, 0x1e7fe)), Script::Ethiopic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e800, 0x1e8c4)), Script::MendeKikakui),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e8c7, 0x1e8d6)), Script::MendeKikakui),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e900, 0x1e94b)), Script::Adlam),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e950, 0x1e959)), Script::Adlam),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1e95e, 0x1e95f)), Script::Adlam),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ec71, 0x1ecb4)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ed01, 0x1ed3d)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee00, 0x1ee03)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee05, 0x1ee1f)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee21, 0x1ee22)), Script::Arabic),
+	(Codepoints::Single(0x1ee24), Script::Arabic),
+	(Codepoints::Single(0x1ee27), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee29, 0x1ee32)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee34, 0x1ee37)), Script::Arabic),
+	(Codepoints::Single(0x1ee39), Script::Arabic),
+	(Codepoints::Single(0x1ee3b), Script::Arabic),
+	(Codepoints::Single(0x1ee42), Script::Arabic),
+	(Codepoints::Single(0x1ee47), Script::Arabic),
+	(Codepoints::Single(0x1ee49), Script::Arabic),
+	(Codepoints::Single(0x1ee4b), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee4d, 0x1ee4f)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee51, 0x1ee52)), Script::Arabic),
+	(Codepoints::Single(0x1ee54), Script::Arabic),
+	(Codepoints::Single(0x1ee57), Script::Arabic),
+	(Codepoints::Single(0x1ee59), Script::Arabic),
+	(Codepoints::Single(0x1ee5b), Script::Arabic),
+	(Codepoints::Single(0x1ee5d), Script::Arabic),
+	(Codepoints::Single(0x1ee5f), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee61, 0x1ee62)), Script::Arabic),
+	(Codepoints::Single(0x1ee64), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee67, 0x1ee6a)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee6c, 0x1ee72)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee74, 0x1ee77)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee79, 0x1ee7c)), Script::Arabic),
+	(Codepoints::Single(0x1ee7e), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee80, 0x1ee89)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ee8b, 0x1ee9b)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1eea1, 0x1eea3)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1eea5, 0x1eea9)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1eeab, 0x1eebb)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1eef0, 0x1eef1)), Script::Arabic),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f000, 0x1f02b)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f030, 0x1f093)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f0a0, 0x1f0ae)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f0b1, 0x1f0bf)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f0c1, 0x1f0cf)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f0d1, 0x1f0f5)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f100, 0x1f1ad)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f1e6, 0x1f1ff)), Script::Common),
+	(Codepoints::Single(0x1f200), Script::Hiragana),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f201, 0x1f202)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f210, 0x1f23b)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f240, 0x1f248)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f250, 0x1f251)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f260, 0x1f265)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f300, 0x1f6d8)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f6dc, 0x1f6ec)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f6f0, 0x1f6fc)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f700, 0x1f7d9)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f7e0, 0x1f7eb)), Script::Common),
+	(Codepoints::Single(0x1f7f0), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f800, 0x1f80b)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f810, 0x1f847)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f850, 0x1f859)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f860, 0x1f887)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f890, 0x1f8ad)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f8b0, 0x1f8bb)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f8c0, 0x1f8c1)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f8d0, 0x1f8d8)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f900, 0x1fa57)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fa60, 0x1fa6d)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fa70, 0x1fa7c)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fa80, 0x1fa8a)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fa8e, 0x1fac6)), Script::Common),
+	(Codepoints::Single(0x1fac8), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1facd, 0x1fadc)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fadf, 0x1faea)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1faef, 0x1faf8)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fb00, 0x1fb92)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1fb94, 0x1fbfa)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x20000, 0x2a6df)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2a700, 0x2b81d)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2b820, 0x2cead)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2ceb0, 0x2ebe0)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2ebf0, 0x2ee5d)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2f800, 0x2fa1d)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x30000, 0x3134a)), Script::Han),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x31350, 0x33479)), Script::Han),
+	(Codepoints::Single(0xe0001), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xe0020, 0xe007f)), Script::Common),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xe0100, 0xe01ef)), Script::Inherited),
+];
+
+static SCRIPT_EXTENSIONS: [(Codepoints, &[Script]); 176] = [
+	(Codepoints::Single(0x00b7), &[Script::Avestan, Script::Carian, Script::Coptic, Script::Duployan, Script::Elbasan, Script::Georgian, Script::Glagolitic, Script::Gothic, Script::Greek, Script::GunjalaGondi, Script::Han, Script::Latin, Script::Lydian, Script::Mahajani, Script::OldPermic, Script::Shavian]),
+	(Codepoints::Single(0x02bc), &[Script::Bengali, Script::Cyrillic, Script::Devanagari, Script::Latin, Script::Lisu, Script::Thai, Script::Toto]),
+	(Codepoints::Single(0x02c7), &[Script::Bopomofo, Script::Latin]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x02c9, 0x02cb)), &[Script::Bopomofo, Script::Latin]),
+	(Codepoints::Single(0x02cd), &[Script::Latin, Script::Lisu]),
+	(Codepoints::Single(0x02d7), &[Script::Latin, Script::Thai]),
+	(Codepoints::Single(0x02d9), &[Script::Bopomofo, Script::Latin]),
+	(Codepoints::Single(0x0300), &[Script::Cherokee, Script::Coptic, Script::Cyrillic, Script::Greek, Script::Latin, Script::OldPermic, Script::Sunuwar, Script::TaiLe]),
+	(Codepoints::Single(0x0301), &[Script::Cherokee, Script::Cyrillic, Script::Greek, Script::Latin, Script::Osage, Script::Sunuwar, Script::TaiLe, Script::Todhri]),
+	(Codepoints::Single(0x0302), &[Script::Cherokee, Script::Cyrillic, Script::Latin, Script::Tifinagh]),
+	(Codepoints::Single(0x0303), &[Script::Glagolitic, Script::Latin, Script::Sunuwar, Script::Syriac, Script::Thai]),
+	(Codepoints::Single(0x0304), &[Script::CaucasianAlbanian, Script::Cherokee, Script::Coptic, Script::Cyrillic, Script::Gothic, Script::Greek, Script::Latin, Script::Osage, Script::Syriac, Script::Tifinagh, Script::Todhri]),
+	(Codepoints::Single(0x0305), &[Script::Coptic, Script::Elbasan, Script::Glagolitic, Script::Gothic, Script::Katakana, Script::Latin]),
+	(Codepoints::Single(0x0306), &[Script::Cyrillic, Script::Greek, Script::Latin, Script::OldPermic, Script::Tifinagh]),
+	(Codepoints::Single(0x0307), &[Script::Coptic, Script::Duployan, Script::Hebrew, Script::Latin, Script::OldPermic, Script::Syriac, Script::TaiLe, Script::Tifinagh, Script::Todhri]),
+	(Codepoints::Single(0x0308), &[Script::Armenian, Script::Cyrillic, Script::Duployan, Script::Gothic, Script::Greek, Script::Hebrew, Script::Latin, Script::OldPermic, Script::Syriac, Script::TaiLe, Script::Tifinagh]),
+	(Codepoints::Single(0x0309), &[Script::Latin, Script::Tifinagh]),
+	(Codepoints::Single(0x030a), &[Script::Duployan, Script::Latin, Script::Syriac]),
+	(Codepoints::Single(0x030b), &[Script::Cherokee, Script::Cyrillic, Script::Latin, Script::Osage]),
+	(Codepoints::Single(0x030c), &[Script::Cherokee, Script::Latin, Script::TaiLe]),
+	(Codepoints::Single(0x030d), &[Script::Latin, Script::Sunuwar]),
+	(Codepoints::Single(0x030e), &[Script::Ethiopic, Script::Latin]),
+	(Codepoints::Single(0x0310), &[Script::Latin, Script::Sunuwar]),
+	(Codepoints::Single(0x0311), &[Script::Cyrillic, Script::Latin, Script::Todhri]),
+	(Codepoints::Single(0x0313), &[Script::Greek, Script::Latin, Script::OldPermic, Script::Todhri]),
+	(Codepoints::Single(0x0323), &[Script::Cherokee, Script::Duployan, Script::Katakana, Script::Latin, Script::Syriac, Script::Tifinagh]),
+	(Codepoints::Single(0x0324), &[Script::Cherokee, Script::Duployan, Script::Latin, Script::Syriac]),
+	(Codepoints::Single(0x0325), &[Script::Latin, Script::Syriac]),
+	(Codepoints::Single(0x032d), &[Script::Latin, Script::Sunuwar, Script::Syriac]),
+	(Codepoints::Single(0x032e), &[Script::Latin, Script::Syriac]),
+	(Codepoints::Single(0x0330), &[Script::Cherokee, Script::Latin, Script::Syriac]),
+	(Codepoints::Single(0x0331), &[Script::CaucasianAlbanian, Script::Cherokee, Script::Gothic, Script::Latin, Script::Sunuwar, Script::Syriac, Script::Thai]),
+	(Codepoints::Single(0x0342), &[Script::Greek]),
+	(Codepoints::Single(0x0345), &[Script::Greek]),
+	(Codepoints::Single(0x0358), &[Script::Latin, Script::Osage]),
+	(Codepoints::Single(0x035e), &[Script::CaucasianAlbanian, Script::Latin, Script::Todhri]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0363, 0x036f)), &[Script::Latin]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0374, 0x0375)), &[Script::Coptic, Script::Greek]),
+	(Codepoints::Single(0x0483), &[Script::Cyrillic, Script::OldPermic]),
+	(Codepoints::Single(0x0484), &[Script::Cyrillic, Script::Glagolitic]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0485, 0x0486)), &[Script::Cyrillic, Script::Latin]),
+	(Codepoints::Single(0x0487), &[Script::Cyrillic, Script::Glagolitic]),
+	(Codepoints::Single(0x0589), &[Script::Armenian, Script::Georgian, Script::Glagolitic]),
+	(Codepoints::Single(0x060c), &[Script::Arabic, Script::Garay, Script::HanifiRohingya, Script::Nko, Script::Syriac, Script::Thaana, Script::Yezidi]),
+	(Codepoints::Single(0x061b), &[Script::Arabic, Script::Garay, Script::HanifiRohingya, Script::Nko, Script::Syriac, Script::Thaana, Script::Yezidi]),
+	(Codepoints::Single(0x061c), &[Script::Arabic, Script::Syriac, Script::Thaana]),
+	(Codepoints::Single(0x061f), &[Script::Adlam, Script::Arabic, Script::Garay, Script::HanifiRohingya, Script::Nko, Script::Syriac, Script::Thaana, Script::Yezidi]),
+	(Codepoints::Single(0x0640), &[Script::Adlam, Script::Arabic, Script::HanifiRohingya, Script::Mandaic, Script::Manichaean, Script::OldUyghur, Script::PsalterPahlavi, Script::Sogdian, Script::Syriac]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x064b, 0x0655)), &[Script::Arabic, Script::Syriac]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0660, 0x0669)), &[Script::Arabic, Script::Thaana, Script::Yezidi]),
+	(Codepoints::Single(0x0670), &[Script::Arabic, Script::Syriac]),
+	(Codepoints::Single(0x06d4), &[Script::Arabic, Script::HanifiRohingya]),
+	(Codepoints::Single(0x0951), &[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Gujarati, Script::Gurmukhi, Script::Kannada, Script::Latin, Script::Malayalam, Script::Nandinagari, Script::Newa, Script::Oriya, Script::Sharada, Script::Tamil, Script::Telugu, Script::Tirhuta]),
+	(Codepoints::Single(0x0952), &[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Gujarati, Script::Gurmukhi, Script::Kannada, Script::Latin, Script::Malayalam, Script::Newa, Script::Oriya, Script::Tamil, Script::Telugu, Script::Tirhuta]),
+	(Codepoints::Single(0x0964), &[Script::Bengali, Script::Devanagari, Script::Dogra, Script::Grantha, Script::Gujarati, Script::GunjalaGondi, Script::Gurmukhi, Script::Kannada, Script::Khudawadi, Script::Mahajani, Script::Malayalam, Script::MasaramGondi, Script::Nandinagari, Script::OlOnal, Script::Oriya, Script::Sinhala, Script::SylotiNagri, Script::Takri, Script::Tamil, Script::Telugu, Script::Tirhuta]),
+	(Codepoints::Single(0x0965), &[Script::Bengali, Script::Devanagari, Script::Dogra, Script::Grantha, Script::Gujarati, Script::GunjalaGondi, Script::Gurmukhi, Script::GurungKhema, Script::Kannada, Script::Khudawadi, Script::Limbu, Script::Mahajani, Script::Malayalam, Script::MasaramGondi, Script::Nandinagari, Script::OlOnal, Script::Oriya, Script::Sinhala, Script::SylotiNagri, Script::Takri, Script::Tamil, Script::Telugu, Script::Tirhuta]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0966, 0x096f)), &[Script::Devanagari, Script::Dogra, Script::Kaithi, Script::Mahajani]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x09e6, 0x09ef)), &[Script::Bengali, Script::Chakma, Script::SylotiNagri]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0a66, 0x0a6f)), &[Script::Gurmukhi, Script::Multani]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ae6, 0x0aef)), &[Script::Gujarati, Script::Khojki]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0be6, 0x0bf3)), &[Script::Grantha, Script::Tamil]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x0ce6, 0x0cef)), &[Script::Kannada, Script::Nandinagari, Script::TuluTigalari]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1040, 0x1049)), &[Script::Chakma, Script::Myanmar, Script::TaiLe]),
+	(Codepoints::Single(0x10fb), &[Script::Georgian, Script::Glagolitic, Script::Latin]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x16eb, 0x16ed)), &[Script::Runic]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1735, 0x1736)), &[Script::Buhid, Script::Hanunoo, Script::Tagalog, Script::Tagbanwa]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1802, 0x1803)), &[Script::Mongolian, Script::PhagsPa]),
+	(Codepoints::Single(0x1805), &[Script::Mongolian, Script::PhagsPa]),
+	(Codepoints::Single(0x1cd0), &[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Kannada]),
+	(Codepoints::Single(0x1cd1), &[Script::Devanagari]),
+	(Codepoints::Single(0x1cd2), &[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Kannada]),
+	(Codepoints::Single(0x1cd3), &[Script::Devanagari, Script::Grantha, Script::Kannada]),
+	(Codepoints::Single(0x1cd4), &[Script::Devanagari]),
+	(Codepoints::Single(0x1cd5), &[Script::Bengali, Script::Devanagari, Script::Newa, Script::Telugu, Script::Tirhuta]),
+	(Codepoints::Single(0x1cd6), &[Script::Bengali, Script::Devanagari, Script::Telugu]),
+	(Codepoints::Single(0x1cd7), &[Script::Devanagari, Script::Newa, Script::Sharada]),
+	(Codepoints::Single(0x1cd8), &[Script::Bengali, Script::Devanagari, Script::Newa, Script::Telugu]),
+	(Codepoints::Single(0x1cd9), &[Script::Devanagari, Script::Sharada]),
+	(Codepoints::Single(0x1cda), &[Script::Devanagari, Script::Kannada, Script::Malayalam, Script::Oriya, Script::Tamil, Script::Telugu]),
+	(Codepoints::Single(0x1cdb), &[Script::Devanagari]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cdc, 0x1cdd)), &[Script::Devanagari, Script::Sharada]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cde, 0x1cdf)), &[Script::Devanagari]),
+	(Codepoints::Single(0x1ce0), &[Script::Devanagari, Script::Sharada]),
+	(Codepoints::Single(0x1ce1), &[Script::Bengali, Script::Devanagari]),
+	(Codepoints::Single(0x1ce2), &[Script::Devanagari, Script::Newa, Script::Tirhuta]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1ce3, 0x1ce8)), &[Script::Devanagari]),
+	(Codepoints::Single(0x1ce9), &[Script::Devanagari, Script::Nandinagari, Script::Newa]),
+	(Codepoints::Single(0x1cea), &[Script::Bengali, Script::Devanagari, Script::Sharada]),
+	(Codepoints::Single(0x1ceb), &[Script::Devanagari, Script::Newa]),
+	(Codepoints::Single(0x1cec), &[Script::Devanagari]),
+	(Codepoints::Single(0x1ced), &[Script::Bengali, Script::Devanagari, Script::Newa, Script::Sharada]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cee, 0x1cf1)), &[Script::Devanagari]),
+	(Codepoints::Single(0x1cf2), &[Script::Bengali, Script::Devanagari, Script::Grantha, Script::Kannada, Script::Malayalam, Script::Nandinagari, Script::Oriya, Script::Sinhala, Script::Telugu, Script::Tirhuta, Script::TuluTigalari]),
+	(Codepoints::Single(0x1cf3), &[Script::Devanagari, Script::Grantha]),
+	(Codepoints::Single(0x1cf4), &[Script::Devanagari, Script::Grantha, Script::Kannada, Script::TuluTigalari]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cf5, 0x1cf6)), &[Script::Bengali, Script::Devanagari]),
+	(Codepoints::Single(0x1cf7), &[Script::Bengali]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1cf8, 0x1cf9)), &[Script::Devanagari, Script::Grantha]),
+	(Codepoints::Single(0x1cfa), &[Script::Nandinagari]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1dc0, 0x1dc1)), &[Script::Greek]),
+	(Codepoints::Single(0x1df8), &[Script::Cyrillic, Script::Latin, Script::Syriac]),
+	(Codepoints::Single(0x1dfa), &[Script::Syriac]),
+	(Codepoints::Single(0x202f), &[Script::Latin, Script::Mongolian, Script::PhagsPa]),
+	(Codepoints::Single(0x204f), &[Script::Adlam, Script::Arabic]),
+	(Codepoints::Single(0x205a), &[Script::Carian, Script::Georgian, Script::Glagolitic, Script::Lycian, Script::OldHungarian, Script::OldTurkic]),
+	(Codepoints::Single(0x205d), &[Script::Carian, Script::Greek, Script::MeroiticHieroglyphs, Script::OldHungarian]),
+	(Codepoints::Single(0x20f0), &[Script::Devanagari, Script::Grantha, Script::Latin]),
+	(Codepoints::Single(0x2e17), &[Script::Coptic, Script::Latin]),
+	(Codepoints::Single(0x2e30), &[Script::Avestan, Script::OldTurkic]),
+	(Codepoints::Single(0x2e31), &[Script::Avestan, Script::Carian, Script::Georgian, Script::Kaithi, Script::Lydian, Script::OldHungarian, Script::Samaritan]),
+	(Codepoints::Single(0x2e3c), &[Script::Duployan]),
+	(Codepoints::Single(0x2e41), &[Script::Adlam, Script::Arabic, Script::OldHungarian]),
+	(Codepoints::Single(0x2e43), &[Script::Cyrillic, Script::Glagolitic]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x2ff0, 0x2fff)), &[Script::Han, Script::Tangut]),
+	(Codepoints::Single(0x3001), &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Mongolian, Script::Yi]),
+	(Codepoints::Single(0x3002), &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Mongolian, Script::PhagsPa, Script::Yi]),
+	(Codepoints::Single(0x3003), &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+	(Codepoints::Single(0x3006), &[Script::Han]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3008, 0x3009)), &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Mongolian, Script::Tibetan, Script::Yi]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x300a, 0x300b)), &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Lisu, Script::Mongolian, Script::Tibetan, Script::Yi]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x300c, 0x3011)), &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Yi]),
+	(Codepoints::Single(0x3013), &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3014, 0x301b)), &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Yi]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x301c, 0x301f)), &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x302a, 0x302d)), &[Script::Bopomofo, Script::Han]),
+	(Codepoints::Single(0x3030), &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3031, 0x3035)), &[Script::Hiragana, Script::Katakana]),
+	(Codepoints::Single(0x3037), &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x303c, 0x303d)), &[Script::Han, Script::Hiragana, Script::Katakana]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x303e, 0x303f)), &[Script::Han]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3099, 0x309c)), &[Script::Hiragana, Script::Katakana]),
+	(Codepoints::Single(0x30a0), &[Script::Hiragana, Script::Katakana]),
+	(Codepoints::Single(0x30fb), &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Yi]),
+	(Codepoints::Single(0x30fc), &[Script::Hiragana, Script::Katakana]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3190, 0x319f)), &[Script::Han]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x31c0, 0x31e5)), &[Script::Han]),
+	(Codepoints::Single(0x31ef), &[Script::Han, Script::Tangut]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3220, 0x3247)), &[Script::Han]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3280, 0x32b0)), &[Script::Han]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x32c0, 0x32cb)), &[Script::Han]),
+	(Codepoints::Single(0x32ff), &[Script::Han]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x3358, 0x3370)), &[Script::Han]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x337b, 0x337f)), &[Script::Han]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x33e0, 0x33fe)), &[Script::Han]),
+	(Codepoints::Single(0xa66f), &[Script::Cyrillic, Script::Glagolitic]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa700, 0xa707)), &[Script::Han, Script::Latin]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa830, 0xa832)), &[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Kaithi, Script::Kannada, Script::Khojki, Script::Khudawadi, Script::Mahajani, Script::Malayalam, Script::Modi, Script::Nandinagari, Script::Sharada, Script::Takri, Script::Tirhuta, Script::TuluTigalari]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa833, 0xa835)), &[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Kaithi, Script::Kannada, Script::Khojki, Script::Khudawadi, Script::Mahajani, Script::Modi, Script::Nandinagari, Script::Sharada, Script::Takri, Script::Tirhuta, Script::TuluTigalari]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xa836, 0xa837)), &[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Kaithi, Script::Khojki, Script::Khudawadi, Script::Mahajani, Script::Modi, Script::Takri, Script::Tirhuta]),
+	(Codepoints::Single(0xa838), &[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Kaithi, Script::Khojki, Script::Khudawadi, Script::Mahajani, Script::Modi, Script::Sharada, Script::Takri, Script::Tirhuta]),
+	(Codepoints::Single(0xa839), &[Script::Devanagari, Script::Dogra, Script::Gujarati, Script::Gurmukhi, Script::Kaithi, Script::Khojki, Script::Khudawadi, Script::Mahajani, Script::Modi, Script::Takri, Script::Tirhuta]),
+	(Codepoints::Single(0xa8f1), &[Script::Bengali, Script::Devanagari, Script::TuluTigalari]),
+	(Codepoints::Single(0xa8f3), &[Script::Devanagari, Script::Tamil]),
+	(Codepoints::Single(0xa92e), &[Script::KayahLi, Script::Latin, Script::Myanmar]),
+	(Codepoints::Single(0xa9cf), &[Script::Buginese, Script::Javanese]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfd3e, 0xfd3f)), &[Script::Arabic, Script::Nko]),
+	(Codepoints::Single(0xfdf2), &[Script::Arabic, Script::Thaana]),
+	(Codepoints::Single(0xfdfd), &[Script::Arabic, Script::Thaana]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xfe45, 0xfe46)), &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xff61, 0xff65)), &[Script::Bopomofo, Script::Han, Script::Hangul, Script::Hiragana, Script::Katakana, Script::Yi]),
+	(Codepoints::Single(0xff70), &[Script::Hiragana, Script::Katakana]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0xff9e, 0xff9f)), &[Script::Hiragana, Script::Katakana]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10100, 0x10101)), &[Script::Cypriot, Script::CyproMinoan, Script::LinearB]),
+	(Codepoints::Single(0x10102), &[Script::Cypriot, Script::LinearB]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10107, 0x10133)), &[Script::Cypriot, Script::LinearA, Script::LinearB]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x10137, 0x1013f)), &[Script::Cypriot, Script::LinearB]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x102e0, 0x102fb)), &[Script::Arabic, Script::Coptic]),
+	(Codepoints::Single(0x10af2), &[Script::Manichaean, Script::OldUyghur]),
+	(Codepoints::Single(0x11301), &[Script::Grantha, Script::Tamil]),
+	(Codepoints::Single(0x11303), &[Script::Grantha, Script::Tamil]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1133b, 0x1133c)), &[Script::Grantha, Script::Tamil]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x11fd0, 0x11fd1)), &[Script::Grantha, Script::Tamil]),
+	(Codepoints::Single(0x11fd3), &[Script::Grantha, Script::Tamil]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1bca0, 0x1bca3)), &[Script::Duployan]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1d360, 0x1d371)), &[Script::Han]),
+	(Codepoints::Range(std::ops::RangeInclusive::new(0x1f250, 0x1f251)), &[Script::Han]),
+];
+
@@ -0,0 +1,13 @@
+// File generated with precis-tools version 0.1.9
+
+// Encoded as ranges: ranges 56 bytes, bitset 1536 bytes, trie 426 bytes
+const SPACE_SEPARATOR: CodepointSet = CodepointSet::Ranges(&[
+	(0x0020, 0x0020),
+	(0x00a0, 0x00a0),
+	(0x1680, 0x1680),
+	(0x2000, 0x200a),
+	(0x202f, 0x202f),
+	(0x205f, 0x205f),
+	(0x3000, 0x3000),
+]);
+
@@ -0,0 +1,2304 @@
+// File generated with precis-tools version 0.1.9
+
+// Encoded as ranges: ranges 3168 bytes, bitset 122816 bytes, trie 31792 bytes
+const STRINGPREP_A_1: CodepointSet = CodepointSet::Ranges(&[
+	(0x0221, 0x0221),
+	(0x0234, 0x024f),
+	(0x02ae, 0x02af),
+	(0x02ef, 0x02ff),
+	(0x0350, 0x035f),
+	(0x0370, 0x0373),
+	(0x0376, 0x0379),
+	(0x037b, 0x037d),
+	(0x037f, 0x0383),
+	(0x038b, 0x038b),
+	(0x038d, 0x038d),
+	(0x03a2, 0x03a2),
+	(0x03cf, 0x03cf),
+	(0x03f7, 0x03ff),
+	(0x0487, 0x0487),
+	(0x04cf, 0x04cf),
+	(0x04f6, 0x04f7),
+	(0x04fa, 0x04ff),
+	(0x0510, 0x0530),
+	(0x0557, 0x0558),
+	(0x0560, 0x0560),
+	(0x0588, 0x0588),
+	(0x058b, 0x0590),
+	(0x05a2, 0x05a2),
+	(0x05ba, 0x05ba),
+	(0x05c5, 0x05cf),
+	(0x05eb, 0x05ef),
+	(0x05f5, 0x060b),
+	(0x060d, 0x061a),
+	(0x061c, 0x061e),
+	(0x0620, 0x0620),
+	(0x063b, 0x063f),
+	(0x0656, 0x065f),
+	(0x06ee, 0x06ef),
+	(0x06ff, 0x06ff),
+	(0x070e, 0x070e),
+	(0x072d, 0x072f),
+	(0x074b, 0x077f),
+	(0x07b2, 0x0900),
+	(0x0904, 0x0904),
+	(0x093a, 0x093b),
+	(0x094e, 0x094f),
+	(0x0955, 0x0957),
+	(0x0971, 0x0980),
+	(0x0984, 0x0984),
+	(0x098d, 0x098e),
+	(0x0991, 0x0992),
+	(0x09a9, 0x09a9),
+	(0x09b1, 0x09b1),
+	(0x09b3, 0x09b5),
+	(0x09ba, 0x09bb),
+	(0x09bd, 0x09bd),
+	(0x09c5, 0x09c6),
+	(0x09c9, 0x09ca),
+	(0x09ce, 0x09d6),
+	(0x09d8, 0x09db),
+	(0x09de, 0x09de),
+	(0x09e4, 0x09e5),
+	(0x09fb, 0x0a01),
+	(0x0a03, 0x0a04),
+	(0x0a0b, 0x0a0e),
+	(0x0a11, 0x0a12),
+	(0x0a29, 0x0a29),
+	(0x0a31, 0x0a31),
+	(0x0a34, 0x0a34),
+	(0x0a37, 0x0a37),
+	(0x0a3a, 0x0a3b),
+	(0x0a3d, 0x0a3d),
+	(0x0a43, 0x0a46),
+	(0x0a49, 0x0a4a),
+	(0x0a4e, 0x0a58),
+	(0x0a5d, 0x0a5d),
+	(0x0a5f, 0x0a65),
+	(0x0a75, 0x0a80),
+	(0x0a84, 0x0a84),
+	(0x0a8c, 0x0a8c),
+	(0x0a8e, 0x0a8e),
+	(0x0a92, 0x0a92),
+	(0x0aa9, 0x0aa9),
+	(0x0ab1, 0x0ab1),
+	(0x0ab4, 0x0ab4),
+	(0x0aba, 0x0abb),
+	(0x0ac6, 0x0ac6),
+	(0x0aca, 0x0aca),
+	(0x0ace, 0x0acf),
+	(0x0ad1, 0x0adf),
+	(0x0ae1, 0x0ae5),
+	(0x0af0, 0x0b00),
+	(0x0b04, 0x0b04),
+	(0x0b0d, 0x0b0e),
+	(0x0b11, 0x0b12),
+	(0x0b29, 0x0b29),
+	(0x0b31, 0x0b31),
+	(0x0b34, 0x0b35),
+	(0x0b3a, 0x0b3b),
+	(0x0b44, 0x0b46),
+	(0x0b49, 0x0b4a),
+	(0x0b4e, 0x0b55),
+	(0x0b58, 0x0b5b),
+	(0x0b5e, 0x0b5e),
+	(0x0b62, 0x0b65),
+	(0x0b71, 0x0b81),
+	(0x0b84, 0x0b84),
+	(0x0b8b, 0x0b8d),
+	(0x0b91, 0x0b91),
+	(0x0b96, 0x0b98),
+	(0x0b9b, 0x0b9b),
+	(0x0b9d, 0x0b9d),
+	(0x0ba0, 0x0ba2),
+	(0x0ba5, 0x0ba7),
+	(0x0bab, 0x0bad),
+	(0x0bb6, 0x0bb6),
+	(0x0bba, 0x0bbd),
+	(0x0bc3, 0x0bc5),
+	(0x0bc9, 0x0bc9),
+	(0x0bce, 0x0bd6),
+	(0x0bd8, 0x0be6),
+	(0x0bf3, 0x0c00),
+	(0x0c04, 0x0c04),
+	(0x0c0d, 0x0c0d),
+	(0x0c11, 0x0c11),
+	(0x0c29, 0x0c29),
+	(0x0c34, 0x0c34),
+	(0x0c3a, 0x0c3d),
+	(0x0c45, 0x0c45),
+	(0x0c49, 0x0c49),
+	(0x0c4e, 0x0c54),
+	(0x0c57, 0x0c5f),
+	(0x0c62, 0x0c65),
+	(0x0c70, 0x0c81),
+	(0x0c84, 0x0c84),
+	(0x0c8d, 0x0c8d),
+	(0x0c91, 0x0c91),
+	(0x0ca9, 0x0ca9),
+	(0x0cb4, 0x0cb4),
+	(0x0cba, 0x0cbd),
+	(0x0cc5, 0x0cc5),
+	(0x0cc9, 0x0cc9),
+	(0x0cce, 0x0cd4),
+	(0x0cd7, 0x0cdd),
+	(0x0cdf, 0x0cdf),
+	(0x0ce2, 0x0ce5),
+	(0x0cf0, 0x0d01),
+	(0x0d04, 0x0d04),
+	(0x0d0d, 0x0d0d),
+	(0x0d11, 0x0d11),
+	(0x0d29, 0x0d29),
+	(0x0d3a, 0x0d3d),
+	(0x0d44, 0x0d45),
+	(0x0d49, 0x0d49),
+	(0x0d4e, 0x0d56),
+	(0x0d58, 0x0d5f),
+	(0x0d62, 0x0d65),
+	(0x0d70, 0x0d81),
+	(0x0d84, 0x0d84),
+	(0x0d97, 0x0d99),
+	(0x0db2, 0x0db2),
+	(0x0dbc, 0x0dbc),
+	(0x0dbe, 0x0dbf),
+	(0x0dc7, 0x0dc9),
+	(0x0dcb, 0x0dce),
+	(0x0dd5, 0x0dd5),
+	(0x0dd7, 0x0dd7),
+	(0x0de0, 0x0df1),
+	(0x0df5, 0x0e00),
+	(0x0e3b, 0x0e3e),
+	(0x0e5c, 0x0e80),
+	(0x0e83, 0x0e83),
+	(0x0e85, 0x0e86),
+	(0x0e89, 0x0e89),
+	(0x0e8b, 0x0e8c),
+	(0x0e8e, 0x0e93),
+	(0x0e98, 0x0e98),
+	(0x0ea0, 0x0ea0),
+	(0x0ea4, 0x0ea4),
+	(0x0ea6, 0x0ea6),
+	(0x0ea8, 0x0ea9),
+	(0x0eac, 0x0eac),
+	(0x0eba, 0x0eba),
+	(0x0ebe, 0x0ebf),
+	(0x0ec5, 0x0ec5),
+	(0x0ec7, 0x0ec7),
+	(0x0ece, 0x0ecf),
+	(0x0eda, 0x0edb),
+	(0x0ede, 0x0eff),
+	(0x0f48, 0x0f48),
+	(0x0f6b, 0x0f70),
+	(0x0f8c, 0x0f8f),
+	(0x0f98, 0x0f98),
+	(0x0fbd, 0x0fbd),
+	(0x0fcd, 0x0fce),
+	(0x0fd0, 0x0fff),
+	(0x1022, 0x1022),
+	(0x1028, 0x1028),
+	(0x102b, 0x102b),
+	(0x1033, 0x1035),
+	(0x103a, 0x103f),
+	(0x105a, 0x109f),
+	(0x10c6, 0x10cf),
+	(0x10f9, 0x10fa),
+	(0x10fc, 0x10ff),
+	(0x115a, 0x115e),
+	(0x11a3, 0x11a7),
+	(0x11fa, 0x11ff),
+	(0x1207, 0x1207),
+	(0x1247, 0x1247),
+	(0x1249, 0x1249),
+	(0x124e, 0x124f),
+	(0x1257, 0x1257),
+	(0x1259, 0x1259),
+	(0x125e, 0x125f),
+	(0x1287, 0x1287),
+	(0x1289, 0x1289),
+	(0x128e, 0x128f),
+	(0x12af, 0x12af),
+	(0x12b1, 0x12b1),
+	(0x12b6, 0x12b7),
+	(0x12bf, 0x12bf),
+	(0x12c1, 0x12c1),
+	(0x12c6, 0x12c7),
+	(0x12cf, 0x12cf),
+	(0x12d7, 0x12d7),
+	(0x12ef, 0x12ef),
+	(0x130f, 0x130f),
+	(0x1311, 0x1311),
+	(0x1316, 0x1317),
+	(0x131f, 0x131f),
+	(0x1347, 0x1347),
+	(0x135b, 0x1360),
+	(0x137d, 0x139f),
+	(0x13f5, 0x1400),
+	(0x1677, 0x167f),
+	(0x169d, 0x169f),
+	(0x16f1, 0x16ff),
+	(0x170d, 0x170d),
+	(0x1715, 0x171f),
+	(0x1737, 0x173f),
+	(0x1754, 0x175f),
+	(0x176d, 0x176d),
+	(0x1771, 0x1771),
+	(0x1774, 0x177f),
+	(0x17dd, 0x17df),
+	(0x17ea, 0x17ff),
+	(0x180f, 0x180f),
+	(0x181a, 0x181f),
+	(0x1878, 0x187f),
+	(0x18aa, 0x1dff),
+	(0x1e9c, 0x1e9f),
+	(0x1efa, 0x1eff),
+	(0x1f16, 0x1f17),
+	(0x1f1e, 0x1f1f),
+	(0x1f46, 0x1f47),
+	(0x1f4e, 0x1f4f),
+	(0x1f58, 0x1f58),
+	(0x1f5a, 0x1f5a),
+	(0x1f5c, 0x1f5c),
+	(0x1f5e, 0x1f5e),
+	(0x1f7e, 0x1f7f),
+	(0x1fb5, 0x1fb5),
+	(0x1fc5, 0x1fc5),
+	(0x1fd4, 0x1fd5),
+	(0x1fdc, 0x1fdc),
+	(0x1ff0, 0x1ff1),
+	(0x1ff5, 0x1ff5),
+	(0x1fff, 0x1fff),
+	(0x2053, 0x2056),
+	(0x2058, 0x205e),
+	(0x2064, 0x2069),
+	(0x2072, 0x2073),
+	(0x208f, 0x209f),
+	(0x20b2, 0x20cf),
+	(0x20eb, 0x20ff),
+	(0x213b, 0x213c),
+	(0x214c, 0x2152),
+	(0x2184, 0x218f),
+	(0x23cf, 0x23ff),
+	(0x2427, 0x243f),
+	(0x244b, 0x245f),
+	(0x24ff, 0x24ff),
+	(0x2614, 0x2615),
+	(0x2618, 0x2618),
+	(0x267e, 0x267f),
+	(0x268a, 0x2700),
+	(0x2705, 0x2705),
+	(0x270a, 0x270b),
+	(0x2728, 0x2728),
+	(0x274c, 0x274c),
+	(0x274e, 0x274e),
+	(0x2753, 0x2755),
+	(0x2757, 0x2757),
+	(0x275f, 0x2760),
+	(0x2795, 0x2797),
+	(0x27b0, 0x27b0),
+	(0x27bf, 0x27cf),
+	(0x27ec, 0x27ef),
+	(0x2b00, 0x2e7f),
+	(0x2e9a, 0x2e9a),
+	(0x2ef4, 0x2eff),
+	(0x2fd6, 0x2fef),
+	(0x2ffc, 0x2fff),
+	(0x3040, 0x3040),
+	(0x3097, 0x3098),
+	(0x3100, 0x3104),
+	(0x312d, 0x3130),
+	(0x318f, 0x318f),
+	(0x31b8, 0x31ef),
+	(0x321d, 0x321f),
+	(0x3244, 0x3250),
+	(0x327c, 0x327e),
+	(0x32cc, 0x32cf),
+	(0x32ff, 0x32ff),
+	(0x3377, 0x337a),
+	(0x33de, 0x33df),
+	(0x33ff, 0x33ff),
+	(0x4db6, 0x4dff),
+	(0x9fa6, 0x9fff),
+	(0xa48d, 0xa48f),
+	(0xa4c7, 0xabff),
+	(0xd7a4, 0xd7ff),
+	(0xfa2e, 0xfa2f),
+	(0xfa6b, 0xfaff),
+	(0xfb07, 0xfb12),
+	(0xfb18, 0xfb1c),
+	(0xfb37, 0xfb37),
+	(0xfb3d, 0xfb3d),
+	(0xfb3f, 0xfb3f),
+	(0xfb42, 0xfb42),
+	(0xfb45, 0xfb45),
+	(0xfbb2, 0xfbd2),
+	(0xfd40, 0xfd4f),
+	(0xfd90, 0xfd91),
+	(0xfdc8, 0xfdcf),
+	(0xfdfd, 0xfdff),
+	(0xfe10, 0xfe1f),
+	(0xfe24, 0xfe2f),
+	(0xfe47, 0xfe48),
+	(0xfe53, 0xfe53),
+	(0xfe67, 0xfe67),
+	(0xfe6c, 0xfe6f),
+	(0xfe75, 0xfe75),
+	(0xfefd, 0xfefe),
+	(0xff00, 0xff00),
+	(0xffbf, 0xffc1),
+	(0xffc8, 0xffc9),
+	(0xffd0, 0xffd1),
+	(0xffd8, 0xffd9),
+	(0xffdd, 0xffdf),
+	(0xffe7, 0xffe7),
+	(0xffef, 0xfff8),
+	(0x10000, 0x102ff),
+	(0x1031f, 0x1031f),
+	(0x10324, 0x1032f),
+	(0x1034b, 0x103ff),
+	(0x10426, 0x10427),
+	(0x1044e, 0x1cfff),
+	(0x1d0f6, 0x1d0ff),
+	(0x1d127, 0x1d129),
+	(0x1d1de, 0x1d3ff),
+	(0x1d455, 0x1d455),
+	(0x1d49d, 0x1d49d),
+	(0x1d4a0, 0x1d4a1),
+	(0x1d4a3, 0x1d4a4),
+	(0x1d4a7, 0x1d4a8),
+	(0x1d4ad, 0x1d4ad),
+	(0x1d4ba, 0x1d4ba),
+	(0x1d4bc, 0x1d4bc),
+	(0x1d4c1, 0x1d4c1),
+	(0x1d4c4, 0x1d4c4),
+	(0x1d506, 0x1d506),
+	(0x1d50b, 0x1d50c),
+	(0x1d515, 0x1d515),
+	(0x1d51d, 0x1d51d),
+	(0x1d53a, 0x1d53a),
+	(0x1d53f, 0x1d53f),
+	(0x1d545, 0x1d545),
+	(0x1d547, 0x1d549),
+	(0x1d551, 0x1d551),
+	(0x1d6a4, 0x1d6a7),
+	(0x1d7ca, 0x1d7cd),
+	(0x1d800, 0x1fffd),
+	(0x2a6d7, 0x2f7ff),
+	(0x2fa1e, 0x2fffd),
+	(0x30000, 0x3fffd),
+	(0x40000, 0x4fffd),
+	(0x50000, 0x5fffd),
+	(0x60000, 0x6fffd),
+	(0x70000, 0x7fffd),
+	(0x80000, 0x8fffd),
+	(0x90000, 0x9fffd),
+	(0xa0000, 0xafffd),
+	(0xb0000, 0xbfffd),
+	(0xc0000, 0xcfffd),
+	(0xd0000, 0xdfffd),
+	(0xe0000, 0xe0000),
+	(0xe0002, 0xe001f),
+	(0xe0080, 0xefffd),
+]);
+
+// Encoded as ranges: ranges 64 bytes, bitset 8144 bytes, trie 2104 bytes
+const STRINGPREP_B_1: CodepointSet = CodepointSet::Ranges(&[
+	(0x00ad, 0x00ad),
+	(0x034f, 0x034f),
+	(0x1806, 0x1806),
+	(0x180b, 0x180d),
+	(0x200b, 0x200d),
+	(0x2060, 0x2060),
+	(0xfe00, 0xfe0f),
+	(0xfeff, 0xfeff),
+]);
+
+// Encoded as bitset: ranges 8 bytes, bitset 8 bytes, trie 18 bytes
+const STRINGPREP_C_1_1: CodepointSet = CodepointSet::Bitset {
+	start: 0x0020,
+	bits: &[
+		0x0000000000000001,
+	],
+};
+
+// Encoded as ranges: ranges 48 bytes, bitset 1520 bytes, trie 426 bytes
+const STRINGPREP_C_1_2: CodepointSet = CodepointSet::Ranges(&[
+	(0x00a0, 0x00a0),
+	(0x1680, 0x1680),
+	(0x2000, 0x200b),
+	(0x202f, 0x202f),
+	(0x205f, 0x205f),
+	(0x3000, 0x3000),
+]);
+
+// Encoded as bitset: ranges 16 bytes, bitset 16 bytes, trie 28 bytes
+const STRINGPREP_C_2_1: CodepointSet = CodepointSet::Bitset {
+	start: 0x0000,
+	bits: &[
+		0x00000000ffffffff, 0x8000000000000000,
+	],
+};
+
+// Encoded as ranges: ranges 88 bytes, bitset 14880 bytes, trie 3804 bytes
+const STRINGPREP_C_2_2: CodepointSet = CodepointSet::Ranges(&[
+	(0x0080, 0x009f),
+	(0x06dd, 0x06dd),
+	(0x070f, 0x070f),
+	(0x180e, 0x180e),
+	(0x200c, 0x200d),
+	(0x2028, 0x2029),
+	(0x2060, 0x2063),
+	(0x206a, 0x206f),
+	(0xfeff, 0xfeff),
+	(0xfff9, 0xfffc),
+	(0x1d173, 0x1d17a),
+]);
+
+// Encoded as ranges: ranges 24 bytes, bitset 132096 bytes, trie 34840 bytes
+const STRINGPREP_C_3: CodepointSet = CodepointSet::Ranges(&[
+	(0xe000, 0xf8ff),
+	(0xf0000, 0xffffd),
+	(0x100000, 0x10fffd),
+]);
+
+// Encoded as ranges: ranges 144 bytes, bitset 131144 bytes, trie 34840 bytes
+const STRINGPREP_C_4: CodepointSet = CodepointSet::Ranges(&[
+	(0xfdd0, 0xfdef),
+	(0xfffe, 0xffff),
+	(0x1fffe, 0x1ffff),
+	(0x2fffe, 0x2ffff),
+	(0x3fffe, 0x3ffff),
+	(0x4fffe, 0x4ffff),
+	(0x5fffe, 0x5ffff),
+	(0x6fffe, 0x6ffff),
+	(0x7fffe, 0x7ffff),
+	(0x8fffe, 0x8ffff),
+	(0x9fffe, 0x9ffff),
+	(0xafffe, 0xaffff),
+	(0xbfffe, 0xbffff),
+	(0xcfffe, 0xcffff),
+	(0xdfffe, 0xdffff),
+	(0xefffe, 0xeffff),
+	(0xffffe, 0xfffff),
+	(0x10fffe, 0x10ffff),
+]);
+
+// Encoded as ranges: ranges 8 bytes, bitset 256 bytes, trie 1808 bytes
+const STRINGPREP_C_5: CodepointSet = CodepointSet::Ranges(&[
+	(0xd800, 0xdfff),
+]);
+
+// Encoded as bitset: ranges 8 bytes, bitset 8 bytes, trie 2064 bytes
+const STRINGPREP_C_6: CodepointSet = CodepointSet::Bitset {
+	start: 0xfff9,
+	bits: &[
+		0x000000000000001f,
+	],
+};
+
+// Encoded as bitset: ranges 8 bytes, bitset 8 bytes, trie 400 bytes
+const STRINGPREP_C_7: CodepointSet = CodepointSet::Bitset {
+	start: 0x2ff0,
+	bits: &[
+		0x0000000000000fff,
+	],
+};
+
+// Encoded as ranges: ranges 32 bytes, bitset 936 bytes, trie 292 bytes
+const STRINGPREP_C_8: CodepointSet = CodepointSet::Ranges(&[
+	(0x0340, 0x0341),
+	(0x200e, 0x200f),
+	(0x202a, 0x202e),
+	(0x206a, 0x206f),
+]);
+
+// Encoded as bitset: ranges 16 bytes, bitset 16 bytes, trie 28700 bytes
+const STRINGPREP_C_9: CodepointSet = CodepointSet::Bitset {
+	start: 0xe0001,
+	bits: &[
+		0xffffffff80000001, 0x7fffffffffffffff,
+	],
+};
+
+// Encoded as ranges: ranges 272 bytes, bitset 7976 bytes, trie 2200 bytes
+const STRINGPREP_D_1: CodepointSet = CodepointSet::Ranges(&[
+	(0x05be, 0x05be),
+	(0x05c0, 0x05c0),
+	(0x05c3, 0x05c3),
+	(0x05d0, 0x05ea),
+	(0x05f0, 0x05f4),
+	(0x061b, 0x061b),
+	(0x061f, 0x061f),
+	(0x0621, 0x063a),
+	(0x0640, 0x064a),
+	(0x066d, 0x066f),
+	(0x0671, 0x06d5),
+	(0x06dd, 0x06dd),
+	(0x06e5, 0x06e6),
+	(0x06fa, 0x06fe),
+	(0x0700, 0x070d),
+	(0x0710, 0x0710),
+	(0x0712, 0x072c),
+	(0x0780, 0x07a5),
+	(0x07b1, 0x07b1),
+	(0x200f, 0x200f),
+	(0xfb1d, 0xfb1d),
+	(0xfb1f, 0xfb28),
+	(0xfb2a, 0xfb36),
+	(0xfb38, 0xfb3c),
+	(0xfb3e, 0xfb3e),
+	(0xfb40, 0xfb41),
+	(0xfb43, 0xfb44),
+	(0xfb46, 0xfbb1),
+	(0xfbd3, 0xfd3d),
+	(0xfd50, 0xfd8f),
+	(0xfd92, 0xfdc7),
+	(0xfdf0, 0xfdfc),
+	(0xfe70, 0xfe74),
+	(0xfe76, 0xfefc),
+]);
+
+// Encoded as ranges: ranges 2936 bytes, bitset 139256 bytes, trie 35832 bytes
+const STRINGPREP_D_2: CodepointSet = CodepointSet::Ranges(&[
+	(0x0041, 0x005a),
+	(0x0061, 0x007a),
+	(0x00aa, 0x00aa),
+	(0x00b5, 0x00b5),
+	(0x00ba, 0x00ba),
+	(0x00c0, 0x00d6),
+	(0x00d8, 0x00f6),
+	(0x00f8, 0x0220),
+	(0x0222, 0x0233),
+	(0x0250, 0x02ad),
+	(0x02b0, 0x02b8),
+	(0x02bb, 0x02c1),
+	(0x02d0, 0x02d1),
+	(0x02e0, 0x02e4),
+	(0x02ee, 0x02ee),
+	(0x037a, 0x037a),
+	(0x0386, 0x0386),
+	(0x0388, 0x038a),
+	(0x038c, 0x038c),
+	(0x038e, 0x03a1),
+	(0x03a3, 0x03ce),
+	(0x03d0, 0x03f5),
+	(0x0400, 0x0482),
+	(0x048a, 0x04ce),
+	(0x04d0, 0x04f5),
+	(0x04f8, 0x04f9),
+	(0x0500, 0x050f),
+	(0x0531, 0x0556),
+	(0x0559, 0x055f),
+	(0x0561, 0x0587),
+	(0x0589, 0x0589),
+	(0x0903, 0x0903),
+	(0x0905, 0x0939),
+	(0x093d, 0x0940),
+	(0x0949, 0x094c),
+	(0x0950, 0x0950),
+	(0x0958, 0x0961),
+	(0x0964, 0x0970),
+	(0x0982, 0x0983),
+	(0x0985, 0x098c),
+	(0x098f, 0x0990),
+	(0x0993, 0x09a8),
+	(0x09aa, 0x09b0),
+	(0x09b2, 0x09b2),
+	(0x09b6, 0x09b9),
+	(0x09be, 0x09c0),
+	(0x09c7, 0x09c8),
+	(0x09cb, 0x09cc),
+	(0x09d7, 0x09d7),
+	(0x09dc, 0x09dd),
+	(0x09df, 0x09e1),
+	(0x09e6, 0x09f1),
+	(0x09f4, 0x09fa),
+	(0x0a05, 0x0a0a),
+	(0x0a0f, 0x0a10),
+	(0x0a13, 0x0a28),
+	(0x0a2a, 0x0a30),
+	(0x0a32, 0x0a33),
+	(0x0a35, 0x0a36),
+	(0x0a38, 0x0a39),
+	(0x0a3e, 0x0a40),
+	(0x0a59, 0x0a5c),
+	(0x0a5e, 0x0a5e),
+	(0x0a66, 0x0a6f),
+	(0x0a72, 0x0a74),
+	(0x0a83, 0x0a83),
+	(0x0a85, 0x0a8b),
+	(0x0a8d, 0x0a8d),
+	(0x0a8f, 0x0a91),
+	(0x0a93, 0x0aa8),
+	(0x0aaa, 0x0ab0),
+	(0x0ab2, 0x0ab3),
+	(0x0ab5, 0x0ab9),
+	(0x0abd, 0x0ac0),
+	(0x0ac9, 0x0ac9),
+	(0x0acb, 0x0acc),
+	(0x0ad0, 0x0ad0),
+	(0x0ae0, 0x0ae0),
+	(0x0ae6, 0x0aef),
+	(0x0b02, 0x0b03),
+	(0x0b05, 0x0b0c),
+	(0x0b0f, 0x0b10),
+	(0x0b13, 0x0b28),
+	(0x0b2a, 0x0b30),
+	(0x0b32, 0x0b33),
+	(0x0b36, 0x0b39),
+	(0x0b3d, 0x0b3e),
+	(0x0b40, 0x0b40),
+	(0x0b47, 0x0b48),
+	(0x0b4b, 0x0b4c),
+	(0x0b57, 0x0b57),
+	(0x0b5c, 0x0b5d),
+	(0x0b5f, 0x0b61),
+	(0x0b66, 0x0b70),
+	(0x0b83, 0x0b83),
+	(0x0b85, 0x0b8a),
+	(0x0b8e, 0x0b90),
+	(0x0b92, 0x0b95),
+	(0x0b99, 0x0b9a),
+	(0x0b9c, 0x0b9c),
+	(0x0b9e, 0x0b9f),
+	(0x0ba3, 0x0ba4),
+	(0x0ba8, 0x0baa),
+	(0x0bae, 0x0bb5),
+	(0x0bb7, 0x0bb9),
+	(0x0bbe, 0x0bbf),
+	(0x0bc1, 0x0bc2),
+	(0x0bc6, 0x0bc8),
+	(0x0bca, 0x0bcc),
+	(0x0bd7, 0x0bd7),
+	(0x0be7, 0x0bf2),
+	(0x0c01, 0x0c03),
+	(0x0c05, 0x0c0c),
+	(0x0c0e, 0x0c10),
+	(0x0c12, 0x0c28),
+	(0x0c2a, 0x0c33),
+	(0x0c35, 0x0c39),
+	(0x0c41, 0x0c44),
+	(0x0c60, 0x0c61),
+	(0x0c66, 0x0c6f),
+	(0x0c82, 0x0c83),
+	(0x0c85, 0x0c8c),
+	(0x0c8e, 0x0c90),
+	(0x0c92, 0x0ca8),
+	(0x0caa, 0x0cb3),
+	(0x0cb5, 0x0cb9),
+	(0x0cbe, 0x0cc4),
+	(0x0cc6, 0x0cc8),
+	(0x0cca, 0x0ccb),
+	(0x0cd5, 0x0cd6),
+	(0x0cde, 0x0cde),
+	(0x0ce0, 0x0ce1),
+	(0x0ce6, 0x0cef),
+	(0x0d02, 0x0d03),
+	(0x0d05, 0x0d0c),
+	(0x0d0e, 0x0d10),
+	(0x0d12, 0x0d28),
+	(0x0d2a, 0x0d39),
+	(0x0d3e, 0x0d40),
+	(0x0d46, 0x0d48),
+	(0x0d4a, 0x0d4c),
+	(0x0d57, 0x0d57),
+	(0x0d60, 0x0d61),
+	(0x0d66, 0x0d6f),
+	(0x0d82, 0x0d83),
+	(0x0d85, 0x0d96),
+	(0x0d9a, 0x0db1),
+	(0x0db3, 0x0dbb),
+	(0x0dbd, 0x0dbd),
+	(0x0dc0, 0x0dc6),
+	(0x0dcf, 0x0dd1),
+	(0x0dd8, 0x0ddf),
+	(0x0df2, 0x0df4),
+	(0x0e01, 0x0e30),
+	(0x0e32, 0x0e33),
+	(0x0e40, 0x0e46),
+	(0x0e4f, 0x0e5b),
+	(0x0e81, 0x0e82),
+	(0x0e84, 0x0e84),
+	(0x0e87, 0x0e88),
+	(0x0e8a, 0x0e8a),
+	(0x0e8d, 0x0e8d),
+	(0x0e94, 0x0e97),
+	(0x0e99, 0x0e9f),
+	(0x0ea1, 0x0ea3),
+	(0x0ea5, 0x0ea5),
+	(0x0ea7, 0x0ea7),
+	(0x0eaa, 0x0eab),
+	(0x0ead, 0x0eb0),
+	(0x0eb2, 0x0eb3),
+	(0x0ebd, 0x0ebd),
+	(0x0ec0, 0x0ec4),
+	(0x0ec6, 0x0ec6),
+	(0x0ed0, 0x0ed9),
+	(0x0edc, 0x0edd),
+	(0x0f00, 0x0f17),
+	(0x0f1a, 0x0f34),
+	(0x0f36, 0x0f36),
+	(0x0f38, 0x0f38),
+	(0x0f3e, 0x0f47),
+	(0x0f49, 0x0f6a),
+	(0x0f7f, 0x0f7f),
+	(0x0f85, 0x0f85),
+	(0x0f88, 0x0f8b),
+	(0x0fbe, 0x0fc5),
+	(0x0fc7, 0x0fcc),
+	(0x0fcf, 0x0fcf),
+	(0x1000, 0x1021),
+	(0x1023, 0x1027),
+	(0x1029, 0x102a),
+	(0x102c, 0x102c),
+	(0x1031, 0x1031),
+	(0x1038, 0x1038),
+	(0x1040, 0x1057),
+	(0x10a0, 0x10c5),
+	(0x10d0, 0x10f8),
+	(0x10fb, 0x10fb),
+	(0x1100, 0x1159),
+	(0x115f, 0x11a2),
+	(0x11a8, 0x11f9),
+	(0x1200, 0x1206),
+	(0x1208, 0x1246),
+	(0x1248, 0x1248),
+	(0x124a, 0x124d),
+	(0x1250, 0x1256),
+	(0x1258, 0x1258),
+	(0x125a, 0x125d),
+	(0x1260, 0x1286),
+	(0x1288, 0x1288),
+	(0x128a, 0x128d),
+	(0x1290, 0x12ae),
+	(0x12b0, 0x12b0),
+	(0x12b2, 0x12b5),
+	(0x12b8, 0x12be),
+	(0x12c0, 0x12c0),
+	(0x12c2, 0x12c5),
+	(0x12c8, 0x12ce),
+	(0x12d0, 0x12d6),
+	(0x12d8, 0x12ee),
+	(0x12f0, 0x130e),
+	(0x1310, 0x1310),
+	(0x1312, 0x1315),
+	(0x1318, 0x131e),
+	(0x1320, 0x1346),
+	(0x1348, 0x135a),
+	(0x1361, 0x137c),
+	(0x13a0, 0x13f4),
+	(0x1401, 0x1676),
+	(0x1681, 0x169a),
+	(0x16a0, 0x16f0),
+	(0x1700, 0x170c),
+	(0x170e, 0x1711),
+	(0x1720, 0x1731),
+	(0x1734, 0x1736),
+	(0x1740, 0x1751),
+	(0x1760, 0x176c),
+	(0x176e, 0x1770),
+	(0x1780, 0x17b3),
+	(0x17b6, 0x17b6),
+	(0x17be, 0x17c5),
+	(0x17c7, 0x17c8),
+	(0x17d4, 0x17da),
+	(0x17dc, 0x17dc),
+	(0x17e0, 0x17e9),
+	(0x1810, 0x1819),
+	(0x1820, 0x1877),
+	(0x1880, 0x1884),
+	(0x1887, 0x18a8),
+	(0x1e00, 0x1e9b),
+	(0x1ea0, 0x1ef9),
+	(0x1f00, 0x1f15),
+	(0x1f18, 0x1f1d),
+	(0x1f20, 0x1f45),
+	(0x1f48, 0x1f4d),
+	(0x1f50, 0x1f57),
+	(0x1f59, 0x1f59),
+	(0x1f5b, 0x1f5b),
+	(0x1f5d, 0x1f5d),
+	(0x1f5f, 0x1f7d),
+	(0x1f80, 0x1fb4),
+	(0x1fb6, 0x1fbc),
+	(0x1fbe, 0x1fbe),
+	(0x1fc2, 0x1fc4),
+	(0x1fc6, 0x1fcc),
+	(0x1fd0, 0x1fd3),
+	(0x1fd6, 0x1fdb),
+	(0x1fe0, 0x1fec),
+	(0x1ff2, 0x1ff4),
+	(0x1ff6, 0x1ffc),
+	(0x200e, 0x200e),
+	(0x2071, 0x2071),
+	(0x207f, 0x207f),
+	(0x2102, 0x2102),
+	(0x2107, 0x2107),
+	(0x210a, 0x2113),
+	(0x2115, 0x2115),
+	(0x2119, 0x211d),
+	(0x2124, 0x2124),
+	(0x2126, 0x2126),
+	(0x2128, 0x2128),
+	(0x212a, 0x212d),
+	(0x212f, 0x2139),
+	(0x213d, 0x213f),
+	(0x2145, 0x2149),
+	(0x2160, 0x2183),
+	(0x2336, 0x237a),
+	(0x2395, 0x2395),
+	(0x249c, 0x24e9),
+	(0x2800, 0x28ff),
+	(0x3005, 0x3007),
+	(0x3021, 0x3029),
+	(0x302e, 0x302f),
+	(0x3031, 0x3035),
+	(0x3038, 0x303c),
+	(0x3041, 0x3096),
+	(0x309d, 0x309f),
+	(0x30a1, 0x30fa),
+	(0x30fc, 0x30ff),
+	(0x3105, 0x312c),
+	(0x3131, 0x318e),
+	(0x3190, 0x31b7),
+	(0x31f0, 0x321c),
+	(0x3220, 0x3243),
+	(0x3260, 0x327b),
+	(0x327f, 0x32b0),
+	(0x32c0, 0x32cb),
+	(0x32d0, 0x32fe),
+	(0x3300, 0x3376),
+	(0x337b, 0x33dd),
+	(0x33e0, 0x33fe),
+	(0x3400, 0x4db5),
+	(0x4e00, 0x9fa5),
+	(0xa000, 0xa48c),
+	(0xac00, 0xd7a3),
+	(0xd800, 0xfa2d),
+	(0xfa30, 0xfa6a),
+	(0xfb00, 0xfb06),
+	(0xfb13, 0xfb17),
+	(0xff21, 0xff3a),
+	(0xff41, 0xff5a),
+	(0xff66, 0xffbe),
+	(0xffc2, 0xffc7),
+	(0xffca, 0xffcf),
+	(0xffd2, 0xffd7),
+	(0xffda, 0xffdc),
+	(0x10300, 0x1031e),
+	(0x10320, 0x10323),
+	(0x10330, 0x1034a),
+	(0x10400, 0x10425),
+	(0x10428, 0x1044d),
+	(0x1d000, 0x1d0f5),
+	(0x1d100, 0x1d126),
+	(0x1d12a, 0x1d166),
+	(0x1d16a, 0x1d172),
+	(0x1d183, 0x1d184),
+	(0x1d18c, 0x1d1a9),
+	(0x1d1ae, 0x1d1dd),
+	(0x1d400, 0x1d454),
+	(0x1d456, 0x1d49c),
+	(0x1d49e, 0x1d49f),
+	(0x1d4a2, 0x1d4a2),
+	(0x1d4a5, 0x1d4a6),
+	(0x1d4a9, 0x1d4ac),
+	(0x1d4ae, 0x1d4b9),
+	(0x1d4bb, 0x1d4bb),
+	(0x1d4bd, 0x1d4c0),
+	(0x1d4c2, 0x1d4c3),
+	(0x1d4c5, 0x1d505),
+	(0x1d507, 0x1d50a),
+	(0x1d50d, 0x1d514),
+	(0x1d516, 0x1d51c),
+	(0x1d51e, 0x1d539),
+	(0x1d53b, 0x1d53e),
+	(0x1d540, 0x1d544),
+	(0x1d546, 0x1d546),
+	(0x1d54a, 0x1d550),
+	(0x1d552, 0x1d6a3),
+	(0x1d6a8, 0x1d6da),
+	(0x1d6dc, 0x1d714),
+	(0x1d716, 0x1d74e),
+	(0x1d750, 0x1d788),
+	(0x1d78a, 0x1d7c2),
+	(0x1d7c4, 0x1d7c9),
+	(0x20000, 0x2a6d6),
+	(0x2f800, 0x2fa1d),
+	(0xf0000, 0xffffd),
+	(0x100000, 0x10fffd),
+]);
+
+static STRINGPREP_B_2: [(u32, &[u32]); 1371] = [
+	(0x0041, &[0x0061]),
+	(0x0042, &[0x0062]),
+	(0x0043, &[0x0063]),
+	(0x0044, &[0x0064]),
+	(0x0045, &[0x0065]),
+	(0x0046, &[0x0066]),
+	(0x0047, &[0x0067]),
+	(0x0048, &[0x0068]),
+	(0x0049, &[0x0069]),
+	(0x004a, &[0x006a]),
+	(0x004b, &[0x006b]),
+	(0x004c, &[0x006c]),
+	(0x004d, &[0x006d]),
+	(0x004e, &[0x006e]),
+	(0x004f, &[0x006f]),
+	(0x0050, &[0x0070]),
+	(0x0051, &[0x0071]),
+	(0x0052, &[0x0072]),
+	(0x0053, &[0x0073]),
+	(0x0054, &[0x0074]),
+	(0x0055, &[0x0075]),
+	(0x0056, &[0x0076]),
+	(0x0057, &[0x0077]),
+	(0x0058, &[0x0078]),
+	(0x0059, &[0x0079]),
+	(0x005a, &[0x007a]),
+	(0x00b5, &[0x03bc]),
+	(0x00c0, &[0x00e0]),
+	(0x00c1, &[0x00e1]),
+	(0x00c2, &[0x00e2]),
+	(0x00c3, &[0x00e3]),
+	(0x00c4, &[0x00e4]),
+	(0x00c5, &[0x00e5]),
+	(0x00c6, &[0x00e6]),
+	(0x00c7, &[0x00e7]),
+	(0x00c8, &[0x00e8]),
+	(0x00c9, &[0x00e9]),
+	(0x00ca, &[0x00ea]),
+	(0x00cb, &[0x00eb]),
+	(0x00cc, &[0x00ec]),
+	(0x00cd, &[0x00ed]),
+	(0x00ce, &[0x00ee]),
+	(0x00cf, &[0x00ef]),
+	(0x00d0, &[0x00f0]),
+	(0x00d1, &[0x00f1]),
+	(0x00d2, &[0x00f2]),
+	(0x00d3, &[0x00f3]),
+	(0x00d4, &[0x00f4]),
+	(0x00d5, &[0x00f5]),
+	(0x00d6, &[0x00f6]),
+	(0x00d8, &[0x00f8]),
+	(0x00d9, &[0x00f9]),
+	(0x00da, &[0x00fa]),
+	(0x00db, &[0x00fb]),
+	(0x00dc, &[0x00fc]),
+	(0x00dd, &[0x00fd]),
+	(0x00de, &[0x00fe]),
+	(0x00df, &[0x0073, 0x0073]),
+	(0x0100, &[0x0101]),
+	(0x0102, &[0x0103]),
+	(0x0104, &[0x0105]),
+	(0x0106, &[0x0107]),
+	(0x0108, &[0x0109]),
+	(0x010a, &[0x010b]),
+	(0x010c, &[0x010d]),
+	(0x010e, &[0x010f]),
+	(0x0110, &[0x0111]),
+	(0x0112, &[0x0113]),
+	(0x0114, &[0x0115]),
+	(0x0116, &[0x0117]),
+	(0x0118, &[0x0119]),
+	(0x011a, &[0x011b]),
+	(0x011c, &[0x011d]),
+	(0x011e, &[0x011f]),
+	(0x0120, &[0x0121]),
+	(0x0122, &[0x0123]),
+	(0x0124, &[0x0125]),
+	(0x0126, &[0x0127]),
+	(0x0128, &[0x0129]),
+	(0x012a, &[0x012b]),
+	(0x012c, &[0x012d]),
+	(0x012e, &[0x012f]),
+	(0x0130, &[0x0069, 0x0307]),
+	(0x0132, &[0x0133]),
+	(0x0134, &[0x0135]),
+	(0x0136, &[0x0137]),
+	(0x0139, &[0x013a]),
+	(0x013b, &[0x013c]),
+	(0x013d, &[0x013e]),
+	(0x013f, &[0x0140]),
+	(0x0141, &[0x0142]),
+	(0x0143, &[0x0144]),
+	(0x0145, &[0x0146]),
+	(0x0147, &[0x0148]),
+	(0x0149, &[0x02bc, 0x006e]),
+	(0x014a, &[0x014b]),
+	(0x014c, &[0x014d]),
+	(0x014e, &[0x014f]),
+	(0x0150, &[0x0151]),
+	(0x0152, &[0x0153]),
+	(0x0154, &[0x0155]),
+	(0x0156, &[0x0157]),
+	(0x0158, &[0x0159]),
+	(0x015a, &[0x015b]),
+	(0x015c, &[0x015d]),
+	(0x015e, &[0x015f]),
+	(0x0160, &[0x0161]),
+	(0x0162, &[0x0163]),
+	(0x0164, &[0x0165]),
+	(0x0166, &[0x0167]),
+	(0x0168, &[0x0169]),
+	(0x016a, &[0x016b]),
+	(0x016c, &[0x016d]),
+	(0x016e, &[0x016f]),
+	(0x0170, &[0x0171]),
+	(0x0172, &[0x0173]),
+	(0x0174, &[0x0175]),
+	(0x0176, &[0x0177]),
+	(0x0178, &[0x00ff]),
+	(0x0179, &[0x017a]),
+	(0x017b, &[0x017c]),
+	(0x017d, &[0x017e]),
+	(0x017f, &[0x0073]),
+	(0x0181, &[0x0253]),
+	(0x0182, &[0x0183]),
+	(0x0184, &[0x0185]),
+	(0x0186, &[0x0254]),
+	(0x0187, &[0x0188]),
+	(0x0189, &[0x0256]),
+	(0x018a, &[0x0257]),
+	(0x018b, &[0x018c]),
+	(0x018e, &[0x01dd]),
+	(0x018f, &[0x0259]),
+	(0x0190, &[0x025b]),
+	(0x0191, &[0x0192]),
+	(0x0193, &[0x0260]),
+	(0x0194, &[0x0263]),
+	(0x0196, &[0x0269]),
+	(0x0197, &[0x0268]),
+	(0x0198, &[0x0199]),
+	(0x019c, &[0x026f]),
+	(0x019d, &[0x0272]),
+	(0x019f, &[0x0275]),
+	(0x01a0, &[0x01a1]),
+	(0x01a2, &[0x01a3]),
+	(0x01a4, &[0x01a5]),
+	(0x01a6, &[0x0280]),
+	(0x01a7, &[0x01a8]),
+	(0x01a9, &[0x0283]),
+	(0x01ac, &[0x01ad]),
+	(0x01ae, &[0x0288]),
+	(0x01af, &[0x01b0]),
+	(0x01b1, &[0x028a]),
+	(0x01b2, &[0x028b]),
+	(0x01b3, &[0x01b4]),
+	(0x01b5, &[0x01b6]),
+	(0x01b7, &[0x0292]),
+	(0x01b8, &[0x01b9]),
+	(0x01bc, &[0x01bd]),
+	(0x01c4, &[0x01c6]),
+	(0x01c5, &[0x01c6]),
+	(0x01c7, &[0x01c9]),
+	(0x01c8, &[0x01c9]),
+	(0x01ca, &[0x01cc]),
+	(0x01cb, &[0x01cc]),
+	(0x01cd, &[0x01ce]),
+	(0x01cf, &[0x01d0]),
+	(0x01d1, &[0x01d2]),
+	(0x01d3, &[0x01d4]),
+	(0x01d5, &[0x01d6]),
+	(0x01d7, &[0x01d8]),
+	(0x01d9, &[0x01da]),
+	(0x01db, &[0x01dc]),
+	(0x01de, &[0x01df]),
+	(0x01e0, &[0x01e1]),
+	(0x01e2, &[0x01e3]),
+	(0x01e4, &[0x01e5]),
+	(0x01e6, &[0x01e7]),
+	(0x01e8, &[0x01e9]),
+	(0x01ea, &[0x01eb]),
+	(0x01ec, &[0x01ed]),
+	(0x01ee, &[0x01ef]),
+	(0x01f0, &[0x006a, 0x030c]),
+	(0x01f1, &[0x01f3]),
+	(0x01f2, &[0x01f3]),
+	(0x01f4, &[0x01f5]),
+	(0x01f6, &[0x0195]),
+	(0x01f7, &[0x01bf]),
+	(0x01f8, &[0x01f9]),
+	(0x01fa, &[0x01fb]),
+	(0x01fc, &[0x01fd]),
+	(0x01fe, &[0x01ff]),
+	(0x0200, &[0x0201]),
+	(0x0202, &[0x0203]),
+	(0x0204, &[0x0205]),
+	(0x0206, &[0x0207]),
+	(0x0208, &[0x0209]),
+	(0x020a, &[0x020b]),
+	(0x020c, &[0x020d]),
+	(0x020e, &[0x020f]),
+	(0x0210, &[0x0211]),
+	(0x0212, &[0x0213]),
+	(0x0214, &[0x0215]),
+	(0x0216, &[0x0217]),
+	(0x0218, &[0x0219]),
+	(0x021a, &[0x021b]),
+	(0x021c, &[0x021d]),
+	(0x021e, &[0x021f]),
+	(0x0220, &[0x019e]),
+	(0x0222, &[0x0223]),
+	(0x0224, &[0x0225]),
+	(0x0226, &[0x0227]),
+	(0x0228, &[0x0229]),
+	(0x022a, &[0x022b]),
+	(0x022c, &[0x022d]),
+	(0x022e, &[0x022f]),
+	(0x0230, &[0x0231]),
+	(0x0232, &[0x0233]),
+	(0x0345, &[0x03b9]),
+	(0x037a, &[0x0020, 0x03b9]),
+	(0x0386, &[0x03ac]),
+	(0x0388, &[0x03ad]),
+	(0x0389, &[0x03ae]),
+	(0x038a, &[0x03af]),
+	(0x038c, &[0x03cc]),
+	(0x038e, &[0x03cd]),
+	(0x038f, &[0x03ce]),
+	(0x0390, &[0x03b9, 0x0308, 0x0301]),
+	(0x0391, &[0x03b1]),
+	(0x0392, &[0x03b2]),
+	(0x0393, &[0x03b3]),
+	(0x0394, &[0x03b4]),
+	(0x0395, &[0x03b5]),
+	(0x0396, &[0x03b6]),
+	(0x0397, &[0x03b7]),
+	(0x0398, &[0x03b8]),
+	(0x0399, &[0x03b9]),
+	(0x039a, &[0x03ba]),
+	(0x039b, &[0x03bb]),
+	(0x039c, &[0x03bc]),
+	(0x039d, &[0x03bd]),
+	(0x039e, &[0x03be]),
+	(0x039f, &[0x03bf]),
+	(0x03a0, &[0x03c0]),
+	(0x03a1, &[0x03c1]),
+	(0x03a3, &[0x03c3]),
+	(0x03a4, &[0x03c4]),
+	(0x03a5, &[0x03c5]),
+	(0x03a6, &[0x03c6]),
+	(0x03a7, &[0x03c7]),
+	(0x03a8, &[0x03c8]),
+	(0x03a9, &[0x03c9]),
+	(0x03aa, &[0x03ca]),
+	(0x03ab, &[0x03cb]),
+	(0x03b0, &[0x03c5, 0x0308, 0x0301]),
+	(0x03c2, &[0x03c3]),
+	(0x03d0, &[0x03b2]),
+	(0x03d1, &[0x03b8]),
+	(0x03d2, &[0x03c5]),
+	(0x03d3, &[0x03cd]),
+	(0x03d4, &[0x03cb]),
+	(0x03d5, &[0x03c6]),
+	(0x03d6, &[0x03c0]),
+	(0x03d8, &[0x03d9]),
+	(0x03da, &[0x03db]),
+	(0x03dc, &[0x03dd]),
+	(0x03de, &[0x03df]),
+	(0x03e0, &[0x03e1]),
+	(0x03e2, &[0x03e3]),
+	(0x03e4, &[0x03e5]),
+	(0x03e6, &[0x03e7]),
+	(0x03e8, &[0x03e9]),
+	(0x03ea, &[0x03eb]),
+	(0x03ec, &[0x03ed]),
+	(0x03ee, &[0x03ef]),
+	(0x03f0, &[0x03ba]),
+	(0x03f1, &[0x03c1]),
+	(0x03f2, &[0x03c3]),
+	(0x03f4, &[0x03b8]),
+	(0x03f5, &[0x03b5]),
+	(0x0400, &[0x0450]),
+	(0x0401, &[0x0451]),
+	(0x0402, &[0x0452]),
+	(0x0403, &[0x0453]),
+	(0x0404, &[0x0454]),
+	(0x0405, &[0x0455]),
+	(0x0406, &[0x0456]),
+	(0x0407, &[0x0457]),
+	(0x0408, &[0x0458]),
+	(0x0409, &[0x0459]),
+	(0x040a, &[0x045a]),
+	(0x040b, &[0x045b]),
+	(0x040c, &[0x045c]),
+	(0x040d, &[0x045d]),
+	(0x040e, &[0x045e]),
+	(0x040f, &[0x045f]),
+	(0x0410, &[0x0430]),
+	(0x0411, &[0x0431]),
+	(0x0412, &[0x0432]),
+	(0x0413, &[0x0433]),
+	(0x0414, &[0x0434]),
+	(0x0415, &[0x0435]),
+	(0x0416, &[0x0436]),
+	(0x0417, &[0x0437]),
+	(0x0418, &[0x0438]),
+	(0x0419, &[0x0439]),
+	(0x041a, &[0x043a]),
+	(0x041b, &[0x043b]),
+	(0x041c, &[0x043c]),
+	(0x041d, &[0x043d]),
+	(0x041e, &[0x043e]),
+	(0x041f, &[0x043f]),
+	(0x0420, &[0x0440]),
+	(0x0421, &[0x0441]),
+	(0x0422, &[0x0442]),
+	(0x0423, &[0x0443]),
+	(0x0424, &[0x0444]),
+	(0x0425, &[0x0445]),
+	(0x0426, &[0x0446]),
+	(0x0427, &[0x0447]),
+	(0x0428, &[0x0448]),
+	(0x0429, &[0x0449]),
+	(0x042a, &[0x044a]),
+	(0x042b, &[0x044b]),
+	(0x042c, &[0x044c]),
+	(0x042d, &[0x044d]),
+	(0x042e, &[0x044e]),
+	(0x042f, &[0x044f]),
+	(0x0460, &[0x0461]),
+	(0x0462, &[0x0463]),
+	(0x0464, &[0x0465]),
+	(0x0466, &[0x0467]),
+	(0x0468, &[0x0469]),
+	(0x046a, &[0x046b]),
+	(0x046c, &[0x046d]),
+	(0x046e, &[0x046f]),
+	(0x0470, &[0x0471]),
+	(0x0472, &[0x0473]),
+	(0x0474, &[0x0475]),
+	(0x0476, &[0x0477]),
+	(0x0478, &[0x0479]),
+	(0x047a, &[0x047b]),
+	(0x047c, &[0x047d]),
+	(0x047e, &[0x047f]),
+	(0x0480, &[0x0481]),
+	(0x048a, &[0x048b]),
+	(0x048c, &[0x048d]),
+	(0x048e, &[0x048f]),
+	(0x0490, &[0x0491]),
+	(0x0492, &[0x0493]),
+	(0x0494, &[0x0495]),
+	(0x0496, &[0x0497]),
+	(0x0498, &[0x0499]),
+	(0x049a, &[0x049b]),
+	(0x049c, &[0x049d]),
+	(0x049e, &[0x049f]),
+	(0x04a0, &[0x04a1]),
+	(0x04a2, &[0x04a3]),
+	(0x04a4, &[0x04a5]),
+	(0x04a6, &[0x04a7]),
+	(0x04a8, &[0x04a9]),
+	(0x04aa, &[0x04ab]),
+	(0x04ac, &[0x04ad]),
+	(0x04ae, &[0x04af]),
+	(0x04b0, &[0x04b1]),
+	(0x04b2, &[0x04b3]),
+	(0x04b4, &[0x04b5]),
+	(0x04b6, &[0x04b7]),
+	(0x04b8, &[0x04b9]),
+	(0x04ba, &[0x04bb]),
+	(0x04bc, &[0x04bd]),
+	(0x04be, &[0x04bf]),
+	(0x04c1, &[0x04c2]),
+	(0x04c3, &[0x04c4]),
+	(0x04c5, &[0x04c6]),
+	(0x04c7, &[0x04c8]),
+	(0x04c9, &[0x04ca]),
+	(0x04cb, &[0x04cc]),
+	(0x04cd, &[0x04ce]),
+	(0x04d0, &[0x04d1]),
+	(0x04d2, &[0x04d3]),
+	(0x04d4, &[0x04d5]),
+	(0x04d6, &[0x04d7]),
+	(0x04d8, &[0x04d9]),
+	(0x04da, &[0x04db]),
+	(0x04dc, &[0x04dd]),
+	(0x04de, &[0x04df]),
+	(0x04e0, &[0x04e1]),
+	(0x04e2, &[0x04e3]),
+	(0x04e4, &[0x04e5]),
+	(0x04e6, &[0x04e7]),
+	(0x04e8, &[0x04e9]),
+	(0x04ea, &[0x04eb]),
+	(0x04ec, &[0x04ed]),
+	(0x04ee, &[0x04ef]),
+	(0x04f0, &[0x04f1]),
+	(0x04f2, &[0x04f3]),
+	(0x04f4, &[0x04f5]),
+	(0x04f8, &[0x04f9]),
+	(0x0500, &[0x0501]),
+	(0x0502, &[0x0503]),
+	(0x0504, &[0x0505]),
+	(0x0506, &[0x0507]),
+	(0x0508, &[0x0509]),
+	(0x050a, &[0x050b]),
+	(0x050c, &[0x050d]),
+	(0x050e, &[0x050f]),
+	(0x0531, &[0x0561]),
+	(0x0532, &[0x0562]),
+	(0x0533, &[0x0563]),
+	(0x0534, &[0x0564]),
+	(0x0535, &[0x0565]),
+	(0x0536, &[0x0566]),
+	(0x0537, &[0x0567]),
+	(0x0538, &[0x0568]),
+	(0x0539, &[0x0569]),
+	(0x053a, &[0x056a]),
+	(0x053b, &[0x056b]),
+	(0x053c, &[0x056c]),
+	(0x053d, &[0x056d]),
+	(0x053e, &[0x056e]),
+	(0x053f, &[0x056f]),
+	(0x0540, &[0x0570]),
+	(0x0541, &[0x0571]),
+	(0x0542, &[0x0572]),
+	(0x0543, &[0x0573]),
+	(0x0544, &[0x0574]),
+	(0x0545, &[0x0575]),
+	(0x0546, &[0x0576]),
+	(0x0547, &[0x0577]),
+	(0x0548, &[0x0578]),
+	(0x0549, &[0x0579]),
+	(0x054a, &[0x057a]),
+	(0x054b, &[0x057b]),
+	(0x054c, &[0x057c]),
+	(0x054d, &[0x057d]),
+	(0x054e, &[0x057e]),
+	(0x054f, &[0x057f]),
+	(0x0550, &[0x0580]),
+	(0x0551, &[0x0581]),
+	(0x0552, &[0x0582]),
+	(0x0553, &[0x0583]),
+	(0x0554, &[0x0584]),
+	(0x0555, &[0x0585]),
+	(0x0556, &[0x0586]),
+	(0x0587, &[0x0565, 0x0582]),
+	(0x1e00, &[0x1e01]),
+	(0x1e02, &[0x1e03]),
+	(0x1e04, &[0x1e05]),
+	(0x1e06, &[0x1e07]),
+	(0x1e08, &[0x1e09]),
+	(0x1e0a, &[0x1e0b]),
+	(0x1e0c, &[0x1e0d]),
+	(0x1e0e, &[0x1e0f]),
+	(0x1e10, &[0x1e11]),
+	(0x1e12, &[0x1e13]),
+	(0x1e14, &[0x1e15]),
+	(0x1e16, &[0x1e17]),
+	(0x1e18, &[0x1e19]),
+	(0x1e1a, &[0x1e1b]),
+	(0x1e1c, &[0x1e1d]),
+	(0x1e1e, &[0x1e1f]),
+	(0x1e20, &[0x1e21]),
+	(0x1e22, &[0x1e23]),
+	(0x1e24, &[0x1e25]),
+	(0x1e26, &[0x1e27]),
+	(0x1e28, &[0x1e29]),
+	(0x1e2a, &[0x1e2b]),
+	(0x1e2c, &[0x1e2d]),
+	(0x1e2e, &[0x1e2f]),
+	(0x1e30, &[0x1e31]),
+	(0x1e32, &[0x1e33]),
+	(0x1e34, &[0x1e35]),
+	(0x1e36, &[0x1e37]),
+	(0x1e38, &[0x1e39]),
+	(0x1e3a, &[0x1e3b]),
+	(0x1e3c, &[0x1e3d]),
+	(0x1e3e, &[0x1e3f]),
+	(0x1e40, &[0x1e41]),
+	(0x1e42, &[0x1e43]),
+	(0x1e44, &[0x1e45]),
+	(0x1e46, &[0x1e47]),
+	(0x1e48, &[0x1e49]),
+	(0x1e4a, &[0x1e4b]),
+	(0x1e4c, &[0x1e4d]),
+	(0x1e4e, &[0x1e4f]),
+	(0x1e50, &[0x1e51]),
+	(0x1e52, &[0x1e53]),
+	(0x1e54, &[0x1e55]),
+	(0x1e56, &[0x1e57]),
+	(0x1e58, &[0x1e59]),
+	(0x1e5a, &[0x1e5b]),
+	(0x1e5c, &[0x1e5d]),
+	(0x1e5e, &[0x1e5f]),
+	(0x1e60, &[0x1e61]),
+	(0x1e62, &[0x1e63]),
+	(0x1e64, &[0x1e65]),
+	(0x1e66, &[0x1e67]),
+	(0x1e68, &[0x1e69]),
+	(0x1e6a, &[0x1e6b]),
+	(0x1e6c, &[0x1e6d]),
+	(0x1e6e, &[0x1e6f]),
+	(0x1e70, &[0x1e71]),
+	(0x1e72, &[0x1e73]),
+	(0x1e74, &[0x1e75]),
+	(0x1e76, &[0x1e77]),
+	(0x1e78, &[0x1e79]),
+	(0x1e7a, &[0x1e7b]),
+	(0x1e7c, &[0x1e7d]),
+	(0x1e7e, &[0x1e7f]),
+	(0x1e80, &[0x1e81]),
+	(0x1e82, &[0x1e83]),
+	(0x1e84, &[0x1e85]),
+	(0x1e86, &[0x1e87]),
+	(0x1e88, &[0x1e89]),
+	(0x1e8a, &[0x1e8b]),
+	(0x1e8c, &[0x1e8d]),
+	(0x1e8e, &[0x1e8f]),
+	(0x1e90, &[0x1e91]),
+	(0x1e92, &[0x1e93]),
+	(0x1e94, &[0x1e95]),
+	(0x1e96, &[0x0068, 0x0331]),
+	(0x1e97, &[0x0074, 0x0308]),
+	(0x1e98, &[0x0077, 0x030a]),
+	(0x1e99, &[0x0079, 0x030a]),
+	(0x1e9a, &[0x0061, 0x02be]),
+	(0x1e9b, &[0x1e61]),
+	(0x1ea0, &[0x1ea1]),
+	(0x1ea2, &[0x1ea3]),
+	(0x1ea4, &[0x1ea5]),
+	(0x1ea6, &[0x1ea7]),
+	(0x1ea8, &[0x1ea9]),
+	(0x1eaa, &[0x1eab]),
+	(0x1eac, &[0x1ead]),
+	(0x1eae, &[0x1eaf]),
+	(0x1eb0, &[0x1eb1]),
+	(0x1eb2, &[0x1eb3]),
+	(0x1eb4, &[0x1eb5]),
+	(0x1eb6, &[0x1eb7]),
+	(0x1eb8, &[0x1eb9]),
+	(0x1eba, &[0x1ebb]),
+	(0x1ebc, &[0x1ebd]),
+	(0x1ebe, &[0x1ebf]),
+	(0x1ec0, &[0x1ec1]),
+	(0x1ec2, &[0x1ec3]),
+	(0x1ec4, &[0x1ec5]),
+	(0x1ec6, &[0x1ec7]),
+	(0x1ec8, &[0x1ec9]),
+	(0x1eca, &[0x1ecb]),
+	(0x1ecc, &[0x1ecd]),
+	(0x1ece, &[0x1ecf]),
+	(0x1ed0, &[0x1ed1]),
+	(0x1ed2, &[0x1ed3]),
+	(0x1ed4, &[0x1ed5]),
+	(0x1ed6, &[0x1ed7]),
+	(0x1ed8, &[0x1ed9]),
+	(0x1eda, &[0x1edb]),
+	(0x1edc, &[0x1edd]),
+	(0x1ede, &[0x1edf]),
+	(0x1ee0, &[0x1ee1]),
+	(0x1ee2, &[0x1ee3]),
+	(0x1ee4, &[0x1ee5]),
+	(0x1ee6, &[0x1ee7]),
+	(0x1ee8, &[0x1ee9]),
+	(0x1eea, &[0x1eeb]),
+	(0x1eec, &[0x1eed]),
+	(0x1eee, &[0x1eef]),
+	(0x1ef0, &[0x1ef1]),
+	(0x1ef2, &[0x1ef3]),
+	(0x1ef4, &[0x1ef5]),
+	(0x1ef6, &[0x1ef7]),
+	(0x1ef8, &[0x1ef9]),
+	(0x1f08, &[0x1f00]),
+	(0x1f09, &[0x1f01]),
+	(0x1f0a, &[0x1f02]),
+	(0x1f0b, &[0x1f03]),
+	(0x1f0c, &[0x1f04]),
+	(0x1f0d, &[0x1f05]),
+	(0x1f0e, &[0x1f06]),
+	(0x1f0f, &[0x1f07]),
+	(0x1f18, &[0x1f10]),
+	(0x1f19, &[0x1f11]),
+	(0x1f1a, &[0x1f12]),
+	(0x1f1b, &[0x1f13]),
+	(0x1f1c, &[0x1f14]),
+	(0x1f1d, &[0x1f15]),
+	(0x1f28, &[0x1f20]),
+	(0x1f29, &[0x1f21]),
+	(0x1f2a, &[0x1f22]),
+	(0x1f2b, &[0x1f23]),
+	(0x1f2c, &[0x1f24]),
+	(0x1f2d, &[0x1f25]),
+	(0x1f2e, &[0x1f26]),
+	(0x1f2f, &[0x1f27]),
+	(0x1f38, &[0x1f30]),
+	(0x1f39, &[0x1f31]),
+	(0x1f3a, &[0x1f32]),
+	(0x1f3b, &[0x1f33]),
+	(0x1f3c, &[0x1f34]),
+	(0x1f3d, &[0x1f35]),
+	(0x1f3e, &[0x1f36]),
+	(0x1f3f, &[0x1f37]),
+	(0x1f48, &[0x1f40]),
+	(0x1f49, &[0x1f41]),
+	(0x1f4a, &[0x1f42]),
+	(0x1f4b, &[0x1f43]),
+	(0x1f4c, &[0x1f44]),
+	(0x1f4d, &[0x1f45]),
+	(0x1f50, &[0x03c5, 0x0313]),
+	(0x1f52, &[0x03c5, 0x0313, 0x0300]),
+	(0x1f54, &[0x03c5, 0x0313, 0x0301]),
+	(0x1f56, &[0x03c5, 0x0313, 0x0342]),
+	(0x1f59, &[0x1f51]),
+	(0x1f5b, &[0x1f53]),
+	(0x1f5d, &[0x1f55]),
+	(0x1f5f, &[0x1f57]),
+	(0x1f68, &[0x1f60]),
+	(0x1f69, &[0x1f61]),
+	(0x1f6a, &[0x1f62]),
+	(0x1f6b, &[0x1f63]),
+	(0x1f6c, &[0x1f64]),
+	(0x1f6d, &[0x1f65]),
+	(0x1f6e, &[0x1f66]),
+	(0x1f6f, &[0x1f67]),
+	(0x1f80, &[0x1f00, 0x03b9]),
+	(0x1f81, &[0x1f01, 0x03b9]),
+	(0x1f82, &[0x1f02, 0x03b9]),
+	(0x1f83, &[0x1f03, 0x03b9]),
+	(0x1f84, &[0x1f04, 0x03b9]),
+	(0x1f85, &[0x1f05, 0x03b9]),
+	(0x1f86, &[0x1f06, 0x03b9]),
+	(0x1f87, &[0x1f07, 0x03b9]),
+	(0x1f88, &[0x1f00, 0x03b9]),
+	(0x1f89, &[0x1f01, 0x03b9]),
+	(0x1f8a, &[0x1f02, 0x03b9]),
+	(0x1f8b, &[0x1f03, 0x03b9]),
+	(0x1f8c, &[0x1f04, 0x03b9]),
+	(0x1f8d, &[0x1f05, 0x03b9]),
+	(0x1f8e, &[0x1f06, 0x03b9]),
+	(0x1f8f, &[0x1f07, 0x03b9]),
+	(0x1f90, &[0x1f20, 0x03b9]),
+	(0x1f91, &[0x1f21, 0x03b9]),
+	(0x1f92, &[0x1f22, 0x03b9]),
+	(0x1f93, &[0x1f23, 0x03b9]),
+	(0x1f94, &[0x1f24, 0x03b9]),
+	(0x1f95, &[0x1f25, 0x03b9]),
+	(0x1f96, &[0x1f26, 0x03b9]),
+	(0x1f97, &[0x1f27, 0x03b9]),
+	(0x1f98, &[0x1f20, 0x03b9]),
+	(0x1f99, &[0x1f21, 0x03b9]),
+	(0x1f9a, &[0x1f22, 0x03b9]),
+	(0x1f9b, &[0x1f23, 0x03b9]),
+	(0x1f9c, &[0x1f24, 0x03b9]),
+	(0x1f9d, &[0x1f25, 0x03b9]),
+	(0x1f9e, &[0x1f26, 0x03b9]),
+	(0x1f9f, &[0x1f27, 0x03b9]),
+	(0x1fa0, &[0x1f60, 0x03b9]),
+	(0x1fa1, &[0x1f61, 0x03b9]),
+	(0x1fa2, &[0x1f62, 0x03b9]),
+	(0x1fa3, &[0x1f63, 0x03b9]),
+	(0x1fa4, &[0x1f64, 0x03b9]),
+	(0x1fa5, &[0x1f65, 0x03b9]),
+	(0x1fa6, &[0x1f66, 0x03b9]),
+	(0x1fa7, &[0x1f67, 0x03b9]),
+	(0x1fa8, &[0x1f60, 0x03b9]),
+	(0x1fa9, &[0x1f61, 0x03b9]),
+	(0x1faa, &[0x1f62, 0x03b9]),
+	(0x1fab, &[0x1f63, 0x03b9]),
+	(0x1fac, &[0x1f64, 0x03b9]),
+	(0x1fad, &[0x1f65, 0x03b9]),
+	(0x1fae, &[0x1f66, 0x03b9]),
+	(0x1faf, &[0x1f67, 0x03b9]),
+	(0x1fb2, &[0x1f70, 0x03b9]),
+	(0x1fb3, &[0x03b1, 0x03b9]),
+	(0x1fb4, &[0x03ac, 0x03b9]),
+	(0x1fb6, &[0x03b1, 0x0342]),
+	(0x1fb7, &[0x03b1, 0x0342, 0x03b9]),
+	(0x1fb8, &[0x1fb0]),
+	(0x1fb9, &[0x1fb1]),
+	(0x1fba, &[0x1f70]),
+	(0x1fbb, &[0x1f71]),
+	(0x1fbc, &[0x03b1, 0x03b9]),
+	(0x1fbe, &[0x03b9]),
+	(0x1fc2, &[0x1f74, 0x03b9]),
+	(0x1fc3, &[0x03b7, 0x03b9]),
+	(0x1fc4, &[0x03ae, 0x03b9]),
+	(0x1fc6, &[0x03b7, 0x0342]),
+	(0x1fc7, &[0x03b7, 0x0342, 0x03b9]),
+	(0x1fc8, &[0x1f72]),
+	(0x1fc9, &[0x1f73]),
+	(0x1fca, &[0x1f74]),
+	(0x1fcb, &[0x1f75]),
+	(0x1fcc, &[0x03b7, 0x03b9]),
+	(0x1fd2, &[0x03b9, 0x0308, 0x0300]),
+	(0x1fd3, &[0x03b9, 0x0308, 0x0301]),
+	(0x1fd6, &[0x03b9, 0x0342]),
+	(0x1fd7, &[0x03b9, 0x0308, 0x0342]),
+	(0x1fd8, &[0x1fd0]),
+	(0x1fd9, &[0x1fd1]),
+	(0x1fda, &[0x1f76]),
+	(0x1fdb, &[0x1f77]),
+	(0x1fe2, &[0x03c5, 0x0308, 0x0300]),
+	(0x1fe3, &[0x03c5, 0x0308, 0x0301]),
+	(0x1fe4, &[0x03c1, 0x0313]),
+	(0x1fe6, &[0x03c5, 0x0342]),
+	(0x1fe7, &[0x03c5, 0x0308, 0x0342]),
+	(0x1fe8, &[0x1fe0]),
+	(0x1fe9, &[0x1fe1]),
+	(0x1fea, &[0x1f7a]),
+	(0x1feb, &[0x1f7b]),
+	(0x1fec, &[0x1fe5]),
+	(0x1ff2, &[0x1f7c, 0x03b9]),
+	(0x1ff3, &[0x03c9, 0x03b9]),
+	(0x1ff4, &[0x03ce, 0x03b9]),
+	(0x1ff6, &[0x03c9, 0x0342]),
+	(0x1ff7, &[0x03c9, 0x0342, 0x03b9]),
+	(0x1ff8, &[0x1f78]),
+	(0x1ff9, &[0x1f79]),
+	(0x1ffa, &[0x1f7c]),
+	(0x1ffb, &[0x1f7d]),
+	(0x1ffc, &[0x03c9, 0x03b9]),
+	(0x20a8, &[0x0072, 0x0073]),
+	(0x2102, &[0x0063]),
+	(0x2103, &[0x00b0, 0x0063]),
+	(0x2107, &[0x025b]),
+	(0x2109, &[0x00b0, 0x0066]),
+	(0x210b, &[0x0068]),
+	(0x210c, &[0x0068]),
+	(0x210d, &[0x0068]),
+	(0x2110, &[0x0069]),
+	(0x2111, &[0x0069]),
+	(0x2112, &[0x006c]),
+	(0x2115, &[0x006e]),
+	(0x2116, &[0x006e, 0x006f]),
+	(0x2119, &[0x0070]),
+	(0x211a, &[0x0071]),
+	(0x211b, &[0x0072]),
+	(0x211c, &[0x0072]),
+	(0x211d, &[0x0072]),
+	(0x2120, &[0x0073, 0x006d]),
+	(0x2121, &[0x0074, 0x0065, 0x006c]),
+	(0x2122, &[0x0074, 0x006d]),
+	(0x2124, &[0x007a]),
+	(0x2126, &[0x03c9]),
+	(0x2128, &[0x007a]),
+	(0x212a, &[0x006b]),
+	(0x212b, &[0x00e5]),
+	(0x212c, &[0x0062]),
+	(0x212d, &[0x0063]),
+	(0x2130, &[0x0065]),
+	(0x2131, &[0x0066]),
+	(0x2133, &[0x006d]),
+	(0x213e, &[0x03b3]),
+	(0x213f, &[0x03c0]),
+	(0x2145, &[0x0064]),
+	(0x2160, &[0x2170]),
+	(0x2161, &[0x2171]),
+	(0x2162, &[0x2172]),
+	(0x2163, &[0x2173]),
+	(0x2164, &[0x2174]),
+	(0x2165, &[0x2175]),
+	(0x2166, &[0x2176]),
+	(0x2167, &[0x2177]),
+	(0x2168, &[0x2178]),
+	(0x2169, &[0x2179]),
+	(0x216a, &[0x217a]),
+	(0x216b, &[0x217b]),
+	(0x216c, &[0x217c]),
+	(0x216d, &[0x217d]),
+	(0x216e, &[0x217e]),
+	(0x216f, &[0x217f]),
+	(0x24b6, &[0x24d0]),
+	(0x24b7, &[0x24d1]),
+	(0x24b8, &[0x24d2]),
+	(0x24b9, &[0x24d3]),
+	(0x24ba, &[0x24d4]),
+	(0x24bb, &[0x24d5]),
+	(0x24bc, &[0x24d6]),
+	(0x24bd, &[0x24d7]),
+	(0x24be, &[0x24d8]),
+	(0x24bf, &[0x24d9]),
+	(0x24c0, &[0x24da]),
+	(0x24c1, &[0x24db]),
+	(0x24c2, &[0x24dc]),
+	(0x24c3, &[0x24dd]),
+	(0x24c4, &[0x24de]),
+	(0x24c5, &[0x24df]),
+	(0x24c6, &[0x24e0]),
+	(0x24c7, &[0x24e1]),
+	(0x24c8, &[0x24e2]),
+	(0x24c9, &[0x24e3]),
+	(0x24ca, &[0x24e4]),
+	(0x24cb, &[0x24e5]),
+	(0x24cc, &[0x24e6]),
+	(0x24cd, &[0x24e7]),
+	(0x24ce, &[0x24e8]),
+	(0x24cf, &[0x24e9]),
+	(0x3371, &[0x0068, 0x0070, 0x0061]),
+	(0x3373, &[0x0061, 0x0075]),
+	(0x3375, &[0x006f, 0x0076]),
+	(0x3380, &[0x0070, 0x0061]),
+	(0x3381, &[0x006e, 0x0061]),
+	(0x3382, &[0x03bc, 0x0061]),
+	(0x3383, &[0x006d, 0x0061]),
+	(0x3384, &[0x006b, 0x0061]),
+	(0x3385, &[0x006b, 0x0062]),
+	(0x3386, &[0x006d, 0x0062]),
+	(0x3387, &[0x0067, 0x0062]),
+	(0x338a, &[0x0070, 0x0066]),
+	(0x338b, &[0x006e, 0x0066]),
+	(0x338c, &[0x03bc, 0x0066]),
+	(0x3390, &[0x0068, 0x007a]),
+	(0x3391, &[0x006b, 0x0068, 0x007a]),
+	(0x3392, &[0x006d, 0x0068, 0x007a]),
+	(0x3393, &[0x0067, 0x0068, 0x007a]),
+	(0x3394, &[0x0074, 0x0068, 0x007a]),
+	(0x33a9, &[0x0070, 0x0061]),
+	(0x33aa, &[0x006b, 0x0070, 0x0061]),
+	(0x33ab, &[0x006d, 0x0070, 0x0061]),
+	(0x33ac, &[0x0067, 0x0070, 0x0061]),
+	(0x33b4, &[0x0070, 0x0076]),
+	(0x33b5, &[0x006e, 0x0076]),
+	(0x33b6, &[0x03bc, 0x0076]),
+	(0x33b7, &[0x006d, 0x0076]),
+	(0x33b8, &[0x006b, 0x0076]),
+	(0x33b9, &[0x006d, 0x0076]),
+	(0x33ba, &[0x0070, 0x0077]),
+	(0x33bb, &[0x006e, 0x0077]),
+	(0x33bc, &[0x03bc, 0x0077]),
+	(0x33bd, &[0x006d, 0x0077]),
+	(0x33be, &[0x006b, 0x0077]),
+	(0x33bf, &[0x006d, 0x0077]),
+	(0x33c0, &[0x006b, 0x03c9]),
+	(0x33c1, &[0x006d, 0x03c9]),
+	(0x33c3, &[0x0062, 0x0071]),
+	(0x33c6, &[0x0063, 0x2215, 0x006b, 0x0067]),
+	(0x33c7, &[0x0063, 0x006f, 0x002e]),
+	(0x33c8, &[0x0064, 0x0062]),
+	(0x33c9, &[0x0067, 0x0079]),
+	(0x33cb, &[0x0068, 0x0070]),
+	(0x33cd, &[0x006b, 0x006b]),
+	(0x33ce, &[0x006b, 0x006d]),
+	(0x33d7, &[0x0070, 0x0068]),
+	(0x33d9, &[0x0070, 0x0070, 0x006d]),
+	(0x33da, &[0x0070, 0x0072]),
+	(0x33dc, &[0x0073, 0x0076]),
+	(0x33dd, &[0x0077, 0x0062]),
+	(0xfb00, &[0x0066, 0x0066]),
+	(0xfb01, &[0x0066, 0x0069]),
+	(0xfb02, &[0x0066, 0x006c]),
+	(0xfb03, &[0x0066, 0x0066, 0x0069]),
+	(0xfb04, &[0x0066, 0x0066, 0x006c]),
+	(0xfb05, &[0x0073, 0x0074]),
+	(0xfb06, &[0x0073, 0x0074]),
+	(0xfb13, &[0x0574, 0x0576]),
+	(0xfb14, &[0x0574, 0x0565]),
+	(0xfb15, &[0x0574, 0x056b]),
+	(0xfb16, &[0x057e, 0x0576]),
+	(0xfb17, &[0x0574, 0x056d]),
+	(0xff21, &[0xff41]),
+	(0xff22, &[0xff42]),
+	(0xff23, &[0xff43]),
+	(0xff24, &[0xff44]),
+	(0xff25, &[0xff45]),
+	(0xff26, &[0xff46]),
+	(0xff27, &[0xff47]),
+	(0xff28, &[0xff48]),
+	(0xff29, &[0xff49]),
+	(0xff2a, &[0xff4a]),
+	(0xff2b, &[0xff4b]),
+	(0xff2c, &[0xff4c]),
+	(0xff2d, &[0xff4d]),
+	(0xff2e, &[0xff4e]),
+	(0xff2f, &[0xff4f]),
+	(0xff30, &[0xff50]),
+	(0xff31, &[0xff51]),
+	(0xff32, &[0xff52]),
+	(0xff33, &[0xff53]),
+	(0xff34, &[0xff54]),
+	(0xff35, &[0xff55]),
+	(0xff36, &[0xff56]),
+	(0xff37, &[0xff57]),
+	(0xff38, &[0xff58]),
+	(0xff39, &[0xff59]),
+	(0xff3a, &[0xff5a]),
+	(0x10400, &[0x10428]),
+	(0x10401, &[0x10429]),
+	(0x10402, &[0x1042a]),
+	(0x10403, &[0x1042b]),
+	(0x10404, &[0x1042c]),
+	(0x10405, &[0x1042d]),
+	(0x10406, &[0x1042e]),
+	(0x10407, &[0x1042f]),
+	(0x10408, &[0x10430]),
+	(0x10409, &[0x10431]),
+	(0x1040a, &[0x10432]),
+	(0x1040b, &[0x10433]),
+	(0x1040c, &[0x10434]),
+	(0x1040d, &[0x10435]),
+	(0x1040e, &[0x10436]),
+	(0x1040f, &[0x10437]),
+	(0x10410, &[0x10438]),
+	(0x10411, &[0x10439]),
+	(0x10412, &[0x1043a]),
+	(0x10413, &[0x1043b]),
+	(0x10414, &[0x1043c]),
+	(0x10415, &[0x1043d]),
+	(0x10416, &[0x1043e]),
+	(0x10417, &[0x1043f]),
+	(0x10418, &[0x10440]),
+	(0x10419, &[0x10441]),
+	(0x1041a, &[0x10442]),
+	(0x1041b, &[0x10443]),
+	(0x1041c, &[0x10444]),
+	(0x1041d, &[0x10445]),
+	(0x1041e, &[0x10446]),
+	(0x1041f, &[0x10447]),
+	(0x10420, &[0x10448]),
+	(0x10421, &[0x10449]),
+	(0x10422, &[0x1044a]),
+	(0x10423, &[0x1044b]),
+	(0x10424, &[0x1044c]),
+	(0x10425, &[0x1044d]),
+	(0x1d400, &[0x0061]),
+	(0x1d401, &[0x0062]),
+	(0x1d402, &[0x0063]),
+	(0x1d403, &[0x0064]),
+	(0x1d404, &[0x0065]),
+	(0x1d405, &[0x0066]),
+	(0x1d406, &[0x0067]),
+	(0x1d407, &[0x0068]),
+	(0x1d408, &[0x0069]),
+	(0x1d409, &[0x006a]),
+	(0x1d40a, &[0x006b]),
+	(0x1d40b, &[0x006c]),
+	(0x1d40c, &[0x006d]),
+	(0x1d40d, &[0x006e]),
+	(0x1d40e, &[0x006f]),
+	(0x1d40f, &[0x0070]),
+	(0x1d410, &[0x0071]),
+	(0x1d411, &[0x0072]),
+	(0x1d412, &[0x0073]),
+	(0x1d413, &[0x0074]),
+	(0x1d414, &[0x0075]),
+	(0x1d415, &[0x0076]),
+	(0x1d416, &[0x0077]),
+	(0x1d417, &[0x0078]),
+	(0x1d418, &[0x0079]),
+	(0x1d419, &[0x007a]),
+	(0x1d434, &[0x0061]),
+	(0x1d435, &[0x0062]),
+	(0x1d436, &[0x0063]),
+	(0x1d437, &[0x0064]),
+	(0x1d438, &[0x0065]),
+	(0x1d439, &[0x0066]),
+	(0x1d43a, &[0x0067]),
+	(0x1d43b, &[0x0068]),
+	(0x1d43c, &[0x0069]),
+	(0x1d43d, &[0x006a]),
+	(0x1d43e, &[0x006b]),
+	(0x1d43f, &[0x006c]),
+	(0x1d440, &[0x006d]),
+	(0x1d441, &[0x006e]),
+	(0x1d442, &[0x006f]),
+	(0x1d443, &[0x0070]),
+	(0x1d444, &[0x0071]),
+	(0x1d445, &[0x0072]),
+	(0x1d446, &[0x0073]),
+	(0x1d447, &[0x0074]),
+	(0x1d448, &[0x0075]),
+	(0x1d449, &[0x0076]),
+	(0x1d44a, &[0x0077]),
+	(0x1d44b, &[0x0078]),
+	(0x1d44c, &[0x0079]),
+	(0x1d44d, &[0x007a]),
+	(0x1d468, &[0x0061]),
+	(0x1d469, &[0x0062]),
+	(0x1d46a, &[0x0063]),
+	(0x1d46b, &[0x0064]),
+	(0x1d46c, &[0x0065]),
+	(0x1d46d, &[0x0066]),
+	(0x1d46e, &[0x0067]),
+	(0x1d46f, &[0x0068]),
+	(0x1d470, &[0x0069]),
+	(0x1d471, &[0x006a]),
+	(0x1d472, &[0x006b]),
+	(0x1d473, &[0x006c]),
+	(0x1d474, &[0x006d]),
+	(0x1d475, &[0x006e]),
+	(0x1d476, &[0x006f]),
+	(0x1d477, &[0x0070]),
+	(0x1d478, &[0x0071]),
+	(0x1d479, &[0x0072]),
+	(0x1d47a, &[0x0073]),
+	(0x1d47b, &[0x0074]),
+	(0x1d47c, &[0x0075]),
+	(0x1d47d, &[0x0076]),
+	(0x1d47e, &[0x0077]),
+	(0x1d47f, &[0x0078]),
+	(0x1d480, &[0x0079]),
+	(0x1d481, &[0x007a]),
+	(0x1d49c, &[0x0061]),
+	(0x1d49e, &[0x0063]),
+	(0x1d49f, &[0x0064]),
+	(0x1d4a2, &[0x0067]),
+	(0x1d4a5, &[0x006a]),
+	(0x1d4a6, &[0x006b]),
+	(0x1d4a9, &[0x006e]),
+	(0x1d4aa, &[0x006f]),
+	(0x1d4ab, &[0x0070]),
+	(0x1d4ac, &[0x0071]),
+	(0x1d4ae, &[0x0073]),
+	(0x1d4af, &[0x0074]),
+	(0x1d4b0, &[0x0075]),
+	(0x1d4b1, &[0x0076]),
+	(0x1d4b2, &[0x0077]),
+	(0x1d4b3, &[0x0078]),
+	(0x1d4b4, &[0x0079]),
+	(0x1d4b5, &[0x007a]),
+	(0x1d4d0, &[0x0061]),
+	(0x1d4d1, &[0x0062]),
+	(0x1d4d2, &[0x0063]),
+	(0x1d4d3, &[0x0064]),
+	(0x1d4d4, &[0x0065]),
+	(0x1d4d5, &[0x0066]),
+	(0x1d4d6, &[0x0067]),
+	(0x1d4d7, &[0x0068]),
+	(0x1d4d8, &[0x0069]),
+	(0x1d4d9, &[0x006a]),
+	(0x1d4da, &[0x006b]),
+	(0x1d4db, &[0x006c]),
+	(0x1d4dc, &[0x006d]),
+	(0x1d4dd, &[0x006e]),
+	(0x1d4de, &[0x006f]),
+	(0x1d4df, &[0x0070]),
+	(0x1d4e0, &[0x0071]),
+	(0x1d4e1, &[0x0072]),
+	(0x1d4e2, &[0x0073]),
+	(0x1d4e3, &[0x0074]),
+	(0x1d4e4, &[0x0075]),
+	(0x1d4e5, &[0x0076]),
+	(0x1d4e6, &[0x0077]),
+	(0x1d4e7, &[0x0078]),
+	(0x1d4e8, &[0x0079]),
+	(0x1d4e9, &[0x007a]),
+	(0x1d504, &[0x0061]),
+	(0x1d505, &[0x0062]),
+	(0x1d507, &[0x0064]),
+	(0x1d508, &[0x0065]),
+	(0x1d509, &[0x0066]),
+	(0x1d50a, &[0x0067]),
+	(0x1d50d, &[0x006a]),
+	(0x1d50e, &[0x006b]),
+	(0x1d50f, &[0x006c]),
+	(0x1d510, &[0x006d]),
+	(0x1d511, &[0x006e]),
+	(0x1d512, &[0x006f]),
+	(0x1d513, &[0x0070]),
+	(0x1d514, &[0x0071]),
+	(0x1d516, &[0x0073]),
+	(0x1d517, &[0x0074]),
+	(0x1d518, &[0x0075]),
+	(0x1d519, &[0x0076]),
+	(0x1d51a, &[0x0077]),
+	(0x1d51b, &[0x0078]),
+	(0x1d51c, &[0x0079]),
+	(0x1d538, &[0x0061]),
+	(0x1d539, &[0x0062]),
+	(0x1d53b, &[0x0064]),
+	(0x1d53c, &[0x0065]),
+	(0x1d53d, &[0x0066]),
+	(0x1d53e, &[0x0067]),
+	(0x1d540, &[0x0069]),
+	(0x1d541, &[0x006a]),
+	(0x1d542, &[0x006b]),
+	(0x1d543, &[0x006c]),
+	(0x1d544, &[0x006d]),
+	(0x1d546, &[0x006f]),
+	(0x1d54a, &[0x0073]),
+	(0x1d54b, &[0x0074]),
+	(0x1d54c, &[0x0075]),
+	(0x1d54d, &[0x0076]),
+	(0x1d54e, &[0x0077]),
+	(0x1d54f, &[0x0078]),
+	(0x1d550, &[0x0079]),
+	(0x1d56c, &[0x0061]),
+	(0x1d56d, &[0x0062]),
+	(0x1d56e, &[0x0063]),
+	(0x1d56f, &[0x0064]),
+	(0x1d570, &[0x0065]),
+	(0x1d571, &[0x0066]),
+	(0x1d572, &[0x0067]),
+	(0x1d573, &[0x0068]),
+	(0x1d574, &[0x0069]),
+	(0x1d575, &[0x006a]),
+	(0x1d576, &[0x006b]),
+	(0x1d577, &[0x006c]),
+	(0x1d578, &[0x006d]),
+	(0x1d579, &[0x006e]),
+	(0x1d57a, &[0x006f]),
+	(0x1d57b, &[0x0070]),
+	(0x1d57c, &[0x0071]),
+	(0x1d57d, &[0x0072]),
+	(0x1d57e, &[0x0073]),
+	(0x1d57f, &[0x0074]),
+	(0x1d580, &[0x0075]),
+	(0x1d581, &[0x0076]),
+	(0x1d582, &[0x0077]),
+	(0x1d583, &[0x0078]),
+	(0x1d584, &[0x0079]),
+	(0x1d585, &[0x007a]),
+	(0x1d5a0, &[0x0061]),
+	(0x1d5a1, &[0x0062]),
+	(0x1d5a2, &[0x0063]),
+	(0x1d5a3, &[0x0064]),
+	(0x1d5a4, &[0x0065]),
+	(0x1d5a5, &[0x0066]),
+	(0x1d5a6, &[0x0067]),
+	(0x1d5a7, &[0x0068]),
+	(0x1d5a8, &[0x0069]),
+	(0x1d5a9, &[0x006a]),
+	(0x1d5aa, &[0x006b]),
+	(0x1d5ab, &[0x006c]),
+	(0x1d5ac, &[0x006d]),
+	(0x1d5ad, &[0x006e]),
+	(0x1d5ae, &[0x006f]),
+	(0x1d5af, &[0x0070]),
+	(0x1d5b0, &[0x0071]),
+	(0x1d5b1, &[0x0072]),
+	(0x1d5b2, &[0x0073]),
+	(0x1d5b3, &[0x0074]),
+	(0x1d5b4, &[0x0075]),
+	(0x1d5b5, &[0x0076]),
+	(0x1d5b6, &[0x0077]),
+	(0x1d5b7, &[0x0078]),
+	(0x1d5b8, &[0x0079]),
+	(0x1d5b9, &[0x007a]),
+	(0x1d5d4, &[0x0061]),
+	(0x1d5d5, &[0x0062]),
+	(0x1d5d6, &[0x0063]),
+	(0x1d5d7, &[0x0064]),
+	(0x1d5d8, &[0x0065]),
+	(0x1d5d9, &[0x0066]),
+	(0x1d5da, &[0x0067]),
+	(0x1d5db, &[0x0068]),
+	(0x1d5dc, &[0x0069]),
+	(0x1d5dd, &[0x006a]),
+	(0x1d5de, &[0x006b]),
+	(0x1d5df, &[0x006c]),
+	(0x1d5e0, &[0x006d]),
+	(0x1d5e1, &[0x006e]),
+	(0x1d5e2, &[0x006f]),
+	(0x1d5e3, &[0x0070]),
+	(0x1d5e4, &[0x0071]),
+	(0x1d5e5, &[0x0072]),
+	(0x1d5e6, &[0x0073]),
+	(0x1d5e7, &[0x0074]),
+	(0x1d5e8, &[0x0075]),
+	(0x1d5e9, &[0x0076]),
+	(0x1d5ea, &[0x0077]),
+	(0x1d5eb, &[0x0078]),
+	(0x1d5ec, &[0x0079]),
+	(0x1d5ed, &[0x007a]),
+	(0x1d608, &[0x0061]),
+	(0x1d609, &[0x0062]),
+	(0x1d60a, &[0x0063]),
+	(0x1d60b, &[0x0064]),
+	(0x1d60c, &[0x0065]),
+	(0x1d60d, &[0x0066]),
+	(0x1d60e, &[0x0067]),
+	(0x1d60f, &[0x0068]),
+	(0x1d610, &[0x0069]),
+	(0x1d611, &[0x006a]),
+	(0x1d612, &[0x006b]),
+	(0x1d613, &[0x006c]),
+	(0x1d614, &[0x006d]),
+	(0x1d615, &[0x006e]),
+	(0x1d616, &[0x006f]),
+	(0x1d617, &[0x0070]),
+	(0x1d618, &[0x0071]),
+	(0x1d619, &[0x0072]),
+	(0x1d61a, &[0x0073]),
+	(0x1d61b, &[0x0074]),
+	(0x1d61c, &[0x0075]),
+	(0x1d61d, &[0x0076]),
+	(0x1d61e, &[0x0077]),
+	(0x1d61f, &[0x0078]),
+	(0x1d620, &[0x0079]),
+	(0x1d621, &[0x007a]),
+	(0x1d63c, &[0x0061]),
+	(0x1d63d, &[0x0062]),
+	(0x1d63e, &[0x0063]),
+	(0x1d63f, &[0x0064]),
+	(0x1d640, &[0x0065]),
+	(0x1d641, &[0x0066]),
+	(0x1d642, &[0x0067]),
+	(0x1d643, &[0x0068]),
+	(0x1d644, &[0x0069]),
+	(0x1d645, &[0x006a]),
+	(0x1d646, &[0x006b]),
+	(0x1d647, &[0x006c]),
+	(0x1d648, &[0x006d]),
+	(0x1d649, &[0x006e]),
+	(0x1d64a, &[0x006f]),
+	(0x1d64b, &[0x0070]),
+	(0x1d64c, &[0x0071]),
+	(0x1d64d, &[0x0072]),
+	(0x1d64e, &[0x0073]),
+	(0x1d64f, &[0x0074]),
+	(0x1d650, &[0x0075]),
+	(0x1d651, &[0x0076]),
+	(0x1d652, &[0x0077]),
+	(0x1d653, &[0x0078]),
+	(0x1d654, &[0x0079]),
+	(0x1d655, &[0x007a]),
+	(0x1d670, &[0x0061]),
+	(0x1d671, &[0x0062]),
+	(0x1d672, &[0x0063]),
+	(0x1d673, &[0x0064]),
+	(0x1d674, &[0x0065]),
+	(0x1d675, &[0x0066]),
+	(0x1d676, &[0x0067]),
+	(0x1d677, &[0x0068]),
+	(0x1d678, &[0x0069]),
+	(0x1d679, &[0x006a]),
+	(0x1d67a, &[0x006b]),
+	(0x1d67b, &[0x006c]),
+	(0x1d67c, &[0x006d]),
+	(0x1d67d, &[0x006e]),
+	(0x1d67e, &[0x006f]),
+	(0x1d67f, &[0x0070]),
+	(0x1d680, &[0x0071]),
+	(0x1d681, &[0x0072]),
+	(0x1d682, &[0x0073]),
+	(0x1d683, &[0x0074]),
+	(0x1d684, &[0x0075]),
+	(0x1d685, &[0x0076]),
+	(0x1d686, &[0x0077]),
+	(0x1d687, &[0x0078]),
+	(0x1d688, &[0x0079]),
+	(0x1d689, &[0x007a]),
+	(0x1d6a8, &[0x03b1]),
+	(0x1d6a9, &[0x03b2]),
+	(0x1d6aa, &[0x03b3]),
+	(0x1d6ab, &[0x03b4]),
+	(0x1d6ac, &[0x03b5]),
+	(0x1d6ad, &[0x03b6]),
+	(0x1d6ae, &[0x03b7]),
+	(0x1d6af, &[0x03b8]),
+	(0x1d6b0, &[0x03b9]),
+	(0x1d6b1, &[0x03ba]),
+	(0x1d6b2, &[0x03bb]),
+	(0x1d6b3, &[0x03bc]),
+	(0x1d6b4, &[0x03bd]),
+	(0x1d6b5, &[0x03be]),
+	(0x1d6b6, &[0x03bf]),
+	(0x1d6b7, &[0x03c0]),
+	(0x1d6b8, &[0x03c1]),
+	(0x1d6b9, &[0x03b8]),
+	(0x1d6ba, &[0x03c3]),
+	(0x1d6bb, &[0x03c4]),
+	(0x1d6bc, &[0x03c5]),
+	(0x1d6bd, &[0x03c6]),
+	(0x1d6be, &[0x03c7]),
+	(0x1d6bf, &[0x03c8]),
+	(0x1d6c0, &[0x03c9]),
+	(0x1d6d3, &[0x03c3]),
+	(0x1d6e2, &[0x03b1]),
+	(0x1d6e3, &[0x03b2]),
+	(0x1d6e4, &[0x03b3]),
+	(0x1d6e5, &[0x03b4]),
+	(0x1d6e6, &[0x03b5]),
+	(0x1d6e7, &[0x03b6]),
+	(0x1d6e8, &[0x03b7]),
+	(0x1d6e9, &[0x03b8]),
+	(0x1d6ea, &[0x03b9]),
+	(0x1d6eb, &[0x03ba]),
+	(0x1d6ec, &[0x03bb]),
+	(0x1d6ed, &[0x03bc]),
+	(0x1d6ee, &[0x03bd]),
+	(0x1d6ef, &[0x03be]),
+	(0x1d6f0, &[0x03bf]),
+	(0x1d6f1, &[0x03c0]),
+	(0x1d6f2, &[0x03c1]),
+	(0x1d6f3, &[0x03b8]),
+	(0x1d6f4, &[0x03c3]),
+	(0x1d6f5, &[0x03c4]),
+	(0x1d6f6, &[0x03c5]),
+	(0x1d6f7, &[0x03c6]),
+	(0x1d6f8, &[0x03c7]),
+	(0x1d6f9, &[0x03c8]),
+	(0x1d6fa, &[0x03c9]),
+	(0x1d70d, &[0x03c3]),
+	(0x1d71c, &[0x03b1]),
+	(0x1d71d, &[0x03b2]),
+	(0x1d71e, &[0x03b3]),
+	(0x1d71f, &[0x03b4]),
+	(0x1d720, &[0x03b5]),
+	(0x1d721, &[0x03b6]),
+	(0x1d722, &[0x03b7]),
+	(0x1d723, &[0x03b8]),
+	(0x1d724, &[0x03b9]),
+	(0x1d725, &[0x03ba]),
+	(0x1d726, &[0x03bb]),
+	(0x1d727, &[0x03bc]),
+	(0x1d728, &[0x03bd]),
+	(0x1d729, &[0x03be]),
+	(0x1d72a, &[0x03bf]),
+	(0x1d72b, &[0x03c0]),
+	(0x1d72c, &[0x03c1]),
+	(0x1d72d, &[0x03b8]),
+	(0x1d72e, &[0x03c3]),
+	(0x1d72f, &[0x03c4]),
+	(0x1d730, &[0x03c5]),
+	(0x1d731, &[0x03c6]),
+	(0x1d732, &[0x03c7]),
+	(0x1d733, &[0x03c8]),
+	(0x1d734, &[0x03c9]),
+	(0x1d747, &[0x03c3]),
+	(0x1d756, &[0x03b1]),
+	(0x1d757, &[0x03b2]),
+	(0x1d758, &[0x03b3]),
+	(0x1d759, &[0x03b4]),
+	(0x1d75a, &[0x03b5]),
+	(0x1d75b, &[0x03b6]),
+	(0x1d75c, &[0x03b7]),
+	(0x1d75d, &[0x03b8]),
+	(0x1d75e, &[0x03b9]),
+	(0x1d75f, &[0x03ba]),
+	(0x1d760, &[0x03bb]),
+	(0x1d761, &[0x03bc]),
+	(0x1d762, &[0x03bd]),
+	(0x1d763, &[0x03be]),
+	(0x1d764, &[0x03bf]),
+	(0x1d765, &[0x03c0]),
+	(0x1d766, &[0x03c1]),
+	(0x1d767, &[0x03b8]),
+	(0x1d768, &[0x03c3]),
+	(0x1d769, &[0x03c4]),
+	(0x1d76a, &[0x03c5]),
+	(0x1d76b, &[0x03c6]),
+	(0x1d76c, &[0x03c7]),
+	(0x1d76d, &[0x03c8]),
+	(0x1d76e, &[0x03c9]),
+	(0x1d781, &[0x03c3]),
+	(0x1d790, &[0x03b1]),
+	(0x1d791, &[0x03b2]),
+	(0x1d792, &[0x03b3]),
+	(0x1d793, &[0x03b4]),
+	(0x1d794, &[0x03b5]),
+	(0x1d795, &[0x03b6]),
+	(0x1d796, &[0x03b7]),
+	(0x1d797, &[0x03b8]),
+	(0x1d798, &[0x03b9]),
+	(0x1d799, &[0x03ba]),
+	(0x1d79a, &[0x03bb]),
+	(0x1d79b, &[0x03bc]),
+	(0x1d79c, &[0x03bd]),
+	(0x1d79d, &[0x03be]),
+	(0x1d79e, &[0x03bf]),
+	(0x1d79f, &[0x03c0]),
+	(0x1d7a0, &[0x03c1]),
+	(0x1d7a1, &[0x03b8]),
+	(0x1d7a2, &[0x03c3]),
+	(0x1d7a3, &[0x03c4]),
+	(0x1d7a4, &[0x03c5]),
+	(0x1d7a5, &[0x03c6]),
+	(0x1d7a6, &[0x03c7]),
+	(0x1d7a7, &[0x03c8]),
+	(0x1d7a8, &[0x03c9]),
+	(0x1d7bb, &[0x03c3]),
+];
+
@@ -0,0 +1,5 @@
+// File generated with precis-tools version 0.1.9
+
+/// The [Unicode version](http://www.unicode.org/versions) of data
+pub const UNICODE_VERSION: (u8, u8, u8) = (17, 0, 0);
+
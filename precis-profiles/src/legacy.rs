@@ -0,0 +1,391 @@
+//! Profiles as defined in the obsoleted [`rfc7613`](https://datatracker.ietf.org/doc/html/rfc7613)
+//! and [`rfc7700`](https://datatracker.ietf.org/doc/html/rfc7700).
+//!
+//! [`rfc8265`](https://datatracker.ietf.org/doc/html/rfc8265) and
+//! [`rfc8266`](https://datatracker.ietf.org/doc/html/rfc8266) changed the
+//! rules and the order in which they are applied, so strings stored under
+//! the old specifications might be enforced and compared differently now.
+//! The profiles in this module follow the old rules and are meant to be
+//! used only to check stored data. Differences with current profiles are:
+//! * The case mapping rule uses Unicode Default Case Folding instead of
+//!   the `toLowerCase` operation.
+//! * The width mapping rule of usernames is applied during enforcement,
+//!   after the string has been checked against the `IdentifierClass`.
+//! * [`Nickname`] applies the case mapping rule during enforcement and
+//!   rules are applied only once instead of until the string is stable.
+//!
+//! The [`differences`] function lists the inputs whose results differ between
+//! a legacy profile and the current one.
+//! # Example
+//! ```rust
+//! # use precis_core::profile::Profile;
+//! # use precis_profiles::legacy;
+//! # use precis_profiles::Nickname;
+//! # use std::borrow::Cow;
+//! // Legacy nicknames are case mapped when they are enforced
+//! assert_eq!(legacy::Nickname::new().enforce("Guybrush"), Ok(Cow::from("guybrush")));
+//! assert_eq!(Nickname::new().enforce("Guybrush"), Ok(Cow::from("Guybrush")));
+//!
+//! let diffs = legacy::differences(&legacy::Nickname::new(), &Nickname::new(),
+//!     ["guybrush", "Guybrush"]);
+//! assert_eq!(diffs.len(), 1);
+//! assert_eq!(diffs[0].input, "Guybrush");
+//! ```
+
+use crate::common;
+use crate::nicknames;
+use crate::usernames;
+use precis_core::profile::{Profile, Rules};
+use precis_core::Error;
+use precis_core::{FreeformClass, IdentifierClass, StringClass};
+use std::borrow::Cow;
+
+/// [`UsernameCaseMapped`](https://datatracker.ietf.org/doc/html/rfc7613#section-3.2)
+/// profile as defined in `rfc7613`.
+/// # Example
+/// ```rust
+/// # use precis_core::profile::Profile;
+/// # use precis_profiles::legacy::UsernameCaseMapped;
+/// # use std::borrow::Cow;
+/// let profile = UsernameCaseMapped::new();
+/// assert_eq!(profile.enforce("Stra\u{00df}e"), Ok(Cow::from("strasse")));
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct UsernameCaseMapped(IdentifierClass);
+
+impl UsernameCaseMapped {
+    /// Creates a legacy [`UsernameCaseMapped`] profile.
+    pub fn new() -> Self {
+        Self(IdentifierClass::default())
+    }
+}
+
+impl Profile for UsernameCaseMapped {
+    fn prepare<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let s = common::ensure_not_empty(s)?;
+        self.0.allows(&s)?;
+        Ok(s)
+    }
+
+    fn enforce<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let s = self.prepare(s)?;
+        let s = self.width_mapping_rule(s)?;
+        let s = self.case_mapping_rule(s)?;
+        let s = self.normalization_rule(s)?;
+        let s = common::ensure_not_empty(s)?;
+        self.directionality_rule(s)
+    }
+
+    fn compare<A, B>(&self, s1: A, s2: B) -> Result<bool, Error>
+    where
+        A: AsRef<str>,
+        B: AsRef<str>,
+    {
+        Ok(self.enforce(s1.as_ref())? == self.enforce(s2.as_ref())?)
+    }
+}
+
+impl Rules for UsernameCaseMapped {
+    fn width_mapping_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        usernames::width_mapping_rule(s)
+    }
+
+    fn case_mapping_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        common::case_folding_rule(s, None)
+    }
+
+    fn normalization_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        common::normalization_form_nfc(s, None)
+    }
+
+    fn directionality_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        usernames::directionality_rule(s)
+    }
+}
+
+/// [`UsernameCasePreserved`](https://datatracker.ietf.org/doc/html/rfc7613#section-3.3)
+/// profile as defined in `rfc7613`.
+/// # Example
+/// ```rust
+/// # use precis_core::profile::Profile;
+/// # use precis_profiles::legacy::UsernameCasePreserved;
+/// # use std::borrow::Cow;
+/// let profile = UsernameCasePreserved::new();
+/// assert_eq!(profile.enforce("Juliet"), Ok(Cow::from("Juliet")));
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct UsernameCasePreserved(IdentifierClass);
+
+impl UsernameCasePreserved {
+    /// Creates a legacy [`UsernameCasePreserved`] profile.
+    pub fn new() -> Self {
+        Self(IdentifierClass::default())
+    }
+}
+
+impl Profile for UsernameCasePreserved {
+    fn prepare<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let s = common::ensure_not_empty(s)?;
+        self.0.allows(&s)?;
+        Ok(s)
+    }
+
+    fn enforce<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let s = self.prepare(s)?;
+        let s = self.width_mapping_rule(s)?;
+        let s = self.normalization_rule(s)?;
+        let s = common::ensure_not_empty(s)?;
+        self.directionality_rule(s)
+    }
+
+    fn compare<A, B>(&self, s1: A, s2: B) -> Result<bool, Error>
+    where
+        A: AsRef<str>,
+        B: AsRef<str>,
+    {
+        Ok(self.enforce(s1.as_ref())? == self.enforce(s2.as_ref())?)
+    }
+}
+
+impl Rules for UsernameCasePreserved {
+    fn width_mapping_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        usernames::width_mapping_rule(s)
+    }
+
+    fn normalization_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        common::normalization_form_nfc(s, None)
+    }
+
+    fn directionality_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        usernames::directionality_rule(s)
+    }
+}
+
+/// [`OpaqueString`](https://datatracker.ietf.org/doc/html/rfc7613#section-4.2)
+/// profile as defined in `rfc7613`. Rules and their order did not change in
+/// `rfc8265`, this profile is provided so that all legacy profiles can be
+/// checked in the same way.
+/// # Example
+/// ```rust
+/// # use precis_core::profile::Profile;
+/// # use precis_profiles::legacy::OpaqueString;
+/// # use std::borrow::Cow;
+/// let profile = OpaqueString::new();
+/// assert_eq!(profile.enforce("Pass\u{00a0}Word"), Ok(Cow::from("Pass Word")));
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct OpaqueString(FreeformClass);
+
+impl OpaqueString {
+    /// Creates a legacy [`OpaqueString`] profile.
+    pub fn new() -> Self {
+        Self(FreeformClass::default())
+    }
+}
+
+impl Profile for OpaqueString {
+    fn prepare<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let s = common::ensure_not_empty(s)?;
+        self.0.allows(&s)?;
+        Ok(s)
+    }
+
+    fn enforce<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let s = self.prepare(s)?;
+        let s = self.additional_mapping_rule(s)?;
+        let s = self.normalization_rule(s)?;
+        common::ensure_not_empty(s)
+    }
+
+    fn compare<A, B>(&self, s1: A, s2: B) -> Result<bool, Error>
+    where
+        A: AsRef<str>,
+        B: AsRef<str>,
+    {
+        Ok(self.enforce(s1.as_ref())? == self.enforce(s2.as_ref())?)
+    }
+}
+
+impl Rules for OpaqueString {
+    fn additional_mapping_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        common::transform_from_first_match(
+            s,
+            common::is_non_ascii_space,
+            |c, res| {
+                if common::is_non_ascii_space(c) {
+                    res.push(common::SPACE);
+                } else {
+                    res.push(c);
+                }
+            },
+            None,
+        )
+    }
+
+    fn normalization_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        common::normalization_form_nfc(s, None)
+    }
+}
+
+/// [`Nickname`](https://datatracker.ietf.org/doc/html/rfc7700#section-2)
+/// profile as defined in `rfc7700`.
+/// # Example
+/// ```rust
+/// # use precis_core::profile::Profile;
+/// # use precis_profiles::legacy::Nickname;
+/// # use std::borrow::Cow;
+/// let profile = Nickname::new();
+/// assert_eq!(profile.enforce("  Guybrush   Threepwood "),
+///     Ok(Cow::from("guybrush threepwood")));
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Nickname(FreeformClass);
+
+impl Nickname {
+    /// Creates a legacy [`Nickname`] profile.
+    pub fn new() -> Self {
+        Self(FreeformClass::default())
+    }
+}
+
+impl Profile for Nickname {
+    fn prepare<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let s = common::ensure_not_empty(s)?;
+        self.0.allows(&s)?;
+        Ok(s)
+    }
+
+    fn enforce<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let s = self.prepare(s)?;
+        let s = self.additional_mapping_rule(s)?;
+        let s = self.case_mapping_rule(s)?;
+        let s = self.normalization_rule(s)?;
+        common::ensure_not_empty(s)
+    }
+
+    fn compare<A, B>(&self, s1: A, s2: B) -> Result<bool, Error>
+    where
+        A: AsRef<str>,
+        B: AsRef<str>,
+    {
+        Ok(self.enforce(s1.as_ref())? == self.enforce(s2.as_ref())?)
+    }
+}
+
+impl Rules for Nickname {
+    fn additional_mapping_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        nicknames::trim_spaces(s)
+    }
+
+    fn case_mapping_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        common::case_folding_rule(s, None)
+    }
+
+    fn normalization_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        common::normalization_form_nfkc(s, None)
+    }
+}
+
+/// Input whose result differs between a legacy profile and the current one.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Difference<'a> {
+    /// The input string
+    pub input: &'a str,
+    /// Result of the legacy profile
+    pub legacy: Result<Cow<'a, str>, Error>,
+    /// Result of the current profile
+    pub current: Result<Cow<'a, str>, Error>,
+}
+
+/// Enforces the inputs with a legacy profile and with the current one,
+/// and lists the inputs whose results differ. Inputs rejected by both
+/// profiles are not considered different, even if the errors are not the same.
+/// # Arguments:
+/// * `legacy`: The legacy profile
+/// * `current`: The current profile
+/// * `inputs`: Strings to check
+/// # Returns
+/// The [`Difference`]s found, in the same order as the inputs.
+pub fn differences<'a, L, C, I>(legacy: &L, current: &C, inputs: I) -> Vec<Difference<'a>>
+where
+    L: Profile,
+    C: Profile,
+    I: IntoIterator<Item = &'a str>,
+{
+    inputs
+        .into_iter()
+        .filter_map(|input| {
+            let l = legacy.enforce(input);
+            let c = current.enforce(input);
+            match (&l, &c) {
+                (Err(_), Err(_)) => None,
+                (Ok(a), Ok(b)) if a == b => None,
+                _ => Some(Difference {
+                    input,
+                    legacy: l,
+                    current: c,
+                }),
+            }
+        })
+        .collect()
+}
@@ -10,6 +10,28 @@
 //!   Preparation, Enforcement, and Comparison of Internationalized Strings
 //!   Representing Nicknames
 //!
+//! Applications can set length limits on enforced strings wrapping any profile
+//! in a [`Limited`] profile.
+//!
+//! The [`legacy`] module provides the profiles defined in the obsoleted `rfc7613`
+//! and `rfc7700`, to check strings stored under those specifications.
+//!
+//! The [`stringprep`] module implements the legacy `Nodeprep`, `Resourceprep` and
+//! `SASLprep` profiles to help applications migrating their stored data to `PRECIS`.
+//!
+//! The [`collisions`] module finds, in large sets of stored strings, the
+//! ones that become equal or are rejected when they are enforced with a profile.
+//!
+//! The [`confusables`] module detects enforced strings that look the same,
+//! such as usernames mixing Latin and Cyrillic letters, using the skeletons
+//! defined in `UTS #39`.
+//!
+//! The [`scripts`] module detects mixed-script strings and classifies them
+//! by the restriction levels defined in `UTS #39`.
+//!
+//! The [`sasl`] module provides helpers to prepare the identities and
+//! passwords used by the `SASL` `PLAIN` and `SCRAM` mechanisms.
+//!
 //! ```rust
 //! # use precis_core::profile::PrecisFastInvocation;
 //! # use precis_profiles::Nickname;
@@ -46,14 +68,45 @@
 
 #![deny(missing_docs)]
 
-include!(concat!(env!("OUT_DIR"), "/unicode_version.rs"));
+// Includes a generated table, committed in `src/generated` or regenerated
+// by the build script when the `regenerate` feature is enabled
+#[cfg(not(feature = "regenerate"))]
+macro_rules! include_table {
+    ($file:literal) => {
+        include!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/generated/",
+            $file
+        ));
+    };
+}
+
+#[cfg(feature = "regenerate")]
+macro_rules! include_table {
+    ($file:literal) => {
+        include!(concat!(env!("OUT_DIR"), "/", $file));
+    };
+}
+
+include_table!("unicode_version.rs");
 
 mod bidi;
 mod common;
+mod emoji;
+mod limits;
 mod nicknames;
 mod passwords;
 mod usernames;
 
+pub mod collisions;
+pub mod confusables;
+pub mod legacy;
+pub mod properties;
+pub mod sasl;
+pub mod scripts;
+pub mod stringprep;
+
+pub use crate::limits::{LengthLimits, Limited, SizeLimits};
 pub use crate::nicknames::Nickname;
 pub use crate::passwords::OpaqueString;
 pub use crate::usernames::UsernameCaseMapped;
@@ -0,0 +1,246 @@
+use crate::common;
+use precis_core::profile::Profile;
+use precis_core::{Error, LengthInfo, LengthLimit, LengthUnit};
+use std::borrow::Cow;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Length limits that applications can set on enforced strings.
+/// [`rfc8265`](https://datatracker.ietf.org/doc/html/rfc8265) and
+/// [`rfc8266`](https://datatracker.ietf.org/doc/html/rfc8266) leave
+/// length limits to applications. Because the width mapping and the
+/// normalization rules can change the length of a string, limits are
+/// checked on the enforced string.
+/// # Example
+/// ```rust
+/// # use precis_core::{Error, LengthInfo, LengthLimit, LengthUnit};
+/// # use precis_profiles::LengthLimits;
+/// let limits = LengthLimits::new(LengthUnit::Codepoints).min(2).max(4);
+/// assert_eq!(limits.check("abc"), Ok(()));
+/// assert_eq!(limits.check("abcde"),
+///     Err(Error::BadLength(LengthInfo::new(LengthUnit::Codepoints, 5, LengthLimit::Max(4)))));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LengthLimits {
+    unit: LengthUnit,
+    min: Option<usize>,
+    max: Option<usize>,
+}
+
+impl LengthLimits {
+    /// Creates length limits measured in the given unit. No limits are
+    /// set until [`min`](LengthLimits::min) or [`max`](LengthLimits::max)
+    /// are called.
+    pub fn new(unit: LengthUnit) -> Self {
+        Self {
+            unit,
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Sets the minimum length allowed
+    pub fn min(mut self, min: usize) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum length allowed
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Measures the length of a string in the unit of these limits
+    pub fn measure(&self, s: &str) -> usize {
+        match self.unit {
+            LengthUnit::Bytes => s.len(),
+            LengthUnit::Codepoints => s.chars().count(),
+            LengthUnit::Graphemes => s.graphemes(true).count(),
+        }
+    }
+
+    /// Checks whether a string satisfies these limits.
+    /// # Returns
+    /// [`Error::BadLength`] with the measured length and the limit
+    /// that was not satisfied.
+    pub fn check(&self, s: &str) -> Result<(), Error> {
+        let length = self.measure(s);
+        let limit = match (self.min, self.max) {
+            (Some(min), _) if length < min => LengthLimit::Min(min),
+            (_, Some(max)) if length > max => LengthLimit::Max(max),
+            _ => return Ok(()),
+        };
+        Err(Error::BadLength(LengthInfo::new(self.unit, length, limit)))
+    }
+}
+
+/// Profile that checks [`LengthLimits`] on the strings enforced by
+/// another profile. Preparation is delegated to the wrapped profile
+/// without checking limits, because the length of a string can still
+/// change when it is enforced.
+/// # Example
+/// ```rust
+/// # use precis_core::{Error, LengthInfo, LengthLimit, LengthUnit};
+/// # use precis_core::profile::Profile;
+/// # use precis_profiles::{LengthLimits, Limited, Nickname};
+/// # use std::borrow::Cow;
+/// let profile = Limited::new(Nickname::new(), LengthLimits::new(LengthUnit::Graphemes).max(8));
+///
+/// // Spaces are trimmed before measuring the length
+/// assert_eq!(profile.enforce("  Guybrush  "), Ok(Cow::from("Guybrush")));
+///
+/// // `x` followed by a combining acute accent is a single grapheme cluster
+/// assert_eq!(profile.enforce("x\u{0301}x\u{0301}"), Ok(Cow::from("x\u{0301}x\u{0301}")));
+///
+/// assert_eq!(profile.enforce("Threepwood"),
+///     Err(Error::BadLength(LengthInfo::new(LengthUnit::Graphemes, 10, LengthLimit::Max(8)))));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Limited<P> {
+    profile: P,
+    limits: LengthLimits,
+}
+
+impl<P: Profile> Limited<P> {
+    /// Creates a profile that checks `limits` on the strings enforced
+    /// by `profile`.
+    pub fn new(profile: P, limits: LengthLimits) -> Self {
+        Self { profile, limits }
+    }
+
+    /// Returns the wrapped profile
+    pub fn profile(&self) -> &P {
+        &self.profile
+    }
+
+    /// Returns the length limits
+    pub fn limits(&self) -> &LengthLimits {
+        &self.limits
+    }
+}
+
+impl<P: Profile> Profile for Limited<P> {
+    fn prepare<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.profile.prepare(s)
+    }
+
+    fn enforce<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let s = self.profile.enforce(s)?;
+        self.limits.check(&s)?;
+        Ok(s)
+    }
+
+    fn compare<A, B>(&self, s1: A, s2: B) -> Result<bool, Error>
+    where
+        A: AsRef<str>,
+        B: AsRef<str>,
+    {
+        // Both strings must satisfy the limits, but comparison is
+        // delegated because some profiles apply extra rules to compare
+        self.enforce(s1.as_ref())?;
+        self.enforce(s2.as_ref())?;
+        self.profile.compare(s1, s2)
+    }
+}
+
+/// Size limits, in bytes, that protect profiles against hostile inputs.
+/// Some code points expand a lot when they are normalized, e.g. `U+FDFA`
+/// becomes 18 code points with `NFKC`, and profiles such as [`Nickname`](crate::Nickname)
+/// can apply normalization several times. The input limit is checked before
+/// applying any rule, and the output limit is checked incrementally while
+/// mapping and normalization rules produce their output, so oversized
+/// strings are rejected before allocating memory for them. No limits are
+/// set by default.
+/// # Example
+/// ```rust
+/// # use precis_core::{Error, LengthInfo, LengthLimit, LengthUnit};
+/// # use precis_core::profile::Profile;
+/// # use precis_profiles::{Nickname, SizeLimits};
+/// let limits = SizeLimits::new().max_input(1024).max_output(32);
+/// let profile = Nickname::new().with_size_limits(limits);
+///
+/// assert!(profile.enforce("Guybrush").is_ok());
+/// assert!(matches!(profile.enforce("\u{fdfa}\u{fdfa}"),
+///     Err(Error::BadLength(LengthInfo { unit: LengthUnit::Bytes, limit: LengthLimit::Max(32), .. }))));
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct SizeLimits {
+    input: Option<usize>,
+    output: Option<usize>,
+}
+
+impl SizeLimits {
+    /// Creates size limits with no limits set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum size in bytes of the string before any rule is applied
+    pub fn max_input(mut self, max: usize) -> Self {
+        self.input = Some(max);
+        self
+    }
+
+    /// Sets the maximum size in bytes of the string produced by the mapping
+    /// and normalization rules
+    pub fn max_output(mut self, max: usize) -> Self {
+        self.output = Some(max);
+        self
+    }
+
+    pub(crate) fn check_input(&self, s: &str) -> Result<(), Error> {
+        common::check_size(s, self.input)
+    }
+
+    pub(crate) fn output(&self) -> Option<usize> {
+        self.output
+    }
+}
+
+#[cfg(test)]
+mod test_limits {
+    use crate::limits::*;
+
+    #[test]
+    fn measure() {
+        // "é" as `e` followed by a combining acute accent
+        let s = "e\u{0301}";
+        assert_eq!(LengthLimits::new(LengthUnit::Bytes).measure(s), 3);
+        assert_eq!(LengthLimits::new(LengthUnit::Codepoints).measure(s), 2);
+        assert_eq!(LengthLimits::new(LengthUnit::Graphemes).measure(s), 1);
+        assert_eq!(LengthLimits::new(LengthUnit::Graphemes).measure(""), 0);
+    }
+
+    #[test]
+    fn check() {
+        let limits = LengthLimits::new(LengthUnit::Bytes);
+        assert_eq!(limits.check(""), Ok(()));
+        assert_eq!(limits.check(&"a".repeat(1024)), Ok(()));
+
+        let limits = LengthLimits::new(LengthUnit::Bytes).min(2).max(3);
+        assert_eq!(
+            limits.check("a"),
+            Err(Error::BadLength(LengthInfo::new(
+                LengthUnit::Bytes,
+                1,
+                LengthLimit::Min(2)
+            )))
+        );
+        assert_eq!(limits.check("ab"), Ok(()));
+        assert_eq!(limits.check("abc"), Ok(()));
+        assert_eq!(
+            limits.check("\u{00e9}\u{00e9}"),
+            Err(Error::BadLength(LengthInfo::new(
+                LengthUnit::Bytes,
+                4,
+                LengthLimit::Max(3)
+            )))
+        );
+    }
+}
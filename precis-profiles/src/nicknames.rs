@@ -1,4 +1,6 @@
 use crate::common;
+use crate::emoji;
+use crate::limits::SizeLimits;
 use precis_core::profile::stabilize;
 use precis_core::profile::{PrecisFastInvocation, Profile, Rules};
 use precis_core::Error;
@@ -21,7 +23,7 @@ use std::sync::LazyLock;
 //
 //  c. Map interior sequences of more than one ASCII space character
 //     to a single ASCII space character.
-fn trim_spaces<'a, T>(s: T) -> Result<Cow<'a, str>, Error>
+pub(crate) fn trim_spaces<'a, T>(s: T) -> Result<Cow<'a, str>, Error>
 where
     T: Into<Cow<'a, str>>,
 {
@@ -106,12 +108,51 @@ where
 ///     "guybrush threepwood"), Ok(true));
 /// ```
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
-pub struct Nickname(FreeformClass);
+pub struct Nickname {
+    class: FreeformClass,
+    emoji_zwj_sequences: bool,
+    size: SizeLimits,
+}
 
 impl Nickname {
     /// Creates a [`Nickname`] profile.
     pub fn new() -> Self {
-        Self(FreeformClass::default())
+        Self::default()
+    }
+
+    /// Creates a [`Nickname`] profile that accepts emoji `ZWJ` sequences,
+    /// such as `👩‍💻` or `🏳️‍🌈`. The `FreeformClass` only allows
+    /// `ZERO WIDTH JOINER` (`U+200D`) after a virama, so these sequences
+    /// are rejected by the default profile. This profile also allows `ZWJ`
+    /// inside the emoji `ZWJ` sequences recommended for general interchange
+    /// (`RGI`), as listed in the `emoji-zwj-sequences.txt` file of
+    /// [`UTS #51`](https://www.unicode.org/reports/tr51/#def_rgi_emoji_zwj_sequence).
+    /// Any other use of `ZWJ`, including emoji joined in sequences that are
+    /// not `RGI`, is still rejected.
+    /// # Example
+    /// ```rust
+    /// # use precis_core::profile::Profile;
+    /// # use precis_profiles::Nickname;
+    /// # use std::borrow::Cow;
+    /// let profile = Nickname::with_emoji_zwj_sequences();
+    /// assert_eq!(profile.enforce("Guybrush \u{1f469}\u{200d}\u{1f4bb}"),
+    ///     Ok(Cow::from("Guybrush \u{1f469}\u{200d}\u{1f4bb}")));
+    /// assert!(profile.enforce("Guybrush\u{200d}").is_err());
+    /// assert!(profile.enforce("\u{1f355}\u{200d}\u{1f680}").is_err());
+    /// assert!(Nickname::new().enforce("\u{1f469}\u{200d}\u{1f4bb}").is_err());
+    /// ```
+    pub fn with_emoji_zwj_sequences() -> Self {
+        Self {
+            emoji_zwj_sequences: true,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the [`SizeLimits`] used to protect this profile against
+    /// inputs that expand a lot when they are normalized.
+    pub fn with_size_limits(mut self, size: SizeLimits) -> Self {
+        self.size = size;
+        self
     }
 
     fn apply_prepare_rules<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
@@ -119,7 +160,11 @@ impl Nickname {
         T: Into<Cow<'a, str>>,
     {
         let s = common::ensure_not_empty(s)?;
-        self.0.allows(&s)?;
+        if self.emoji_zwj_sequences {
+            emoji::allows_zwj_sequences(&self.class, &s)?;
+        } else {
+            self.class.allows(&s)?;
+        }
         Ok(s)
     }
 
@@ -149,6 +194,8 @@ impl Profile for Nickname {
     where
         S: Into<Cow<'a, str>>,
     {
+        let s = s.into();
+        self.size.check_input(&s)?;
         self.apply_prepare_rules(s)
     }
 
@@ -156,6 +203,8 @@ impl Profile for Nickname {
     where
         S: Into<Cow<'a, str>>,
     {
+        let s = s.into();
+        self.size.check_input(&s)?;
         stabilize(s, |s| self.apply_enforce_rules(s))
     }
 
@@ -164,6 +213,8 @@ impl Profile for Nickname {
         A: AsRef<str>,
         B: AsRef<str>,
     {
+        self.size.check_input(s1.as_ref())?;
+        self.size.check_input(s2.as_ref())?;
         Ok(stabilize(s1.as_ref(), |s| self.apply_compare_rules(s))?
             == stabilize(s2.as_ref(), |s| self.apply_compare_rules(s))?)
     }
@@ -181,14 +232,14 @@ impl Rules for Nickname {
     where
         T: Into<Cow<'a, str>>,
     {
-        common::case_mapping_rule(s)
+        common::case_mapping_rule(s, self.size.output())
     }
 
     fn normalization_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
     where
         T: Into<Cow<'a, str>>,
     {
-        common::normalization_form_nfkc(s)
+        common::normalization_form_nfkc(s, self.size.output())
     }
 }
 
@@ -1,4 +1,5 @@
 use crate::common;
+use crate::limits::SizeLimits;
 use precis_core::profile::{PrecisFastInvocation, Profile, Rules};
 use precis_core::Error;
 use precis_core::{FreeformClass, StringClass};
@@ -33,12 +34,22 @@ use std::sync::LazyLock;
 ///     "That’s the second biggest 🐵 I’ve ever seen!"), Ok(true));
 /// ```
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
-pub struct OpaqueString(FreeformClass);
+pub struct OpaqueString {
+    class: FreeformClass,
+    size: SizeLimits,
+}
 
 impl OpaqueString {
     /// Creates a [`OpaqueString`] profile.
     pub fn new() -> Self {
-        Self(FreeformClass::default())
+        Self::default()
+    }
+
+    /// Sets the [`SizeLimits`] used to protect this profile against
+    /// inputs that expand a lot when they are normalized.
+    pub fn with_size_limits(mut self, size: SizeLimits) -> Self {
+        self.size = size;
+        self
     }
 }
 
@@ -47,8 +58,10 @@ impl Profile for OpaqueString {
     where
         S: Into<Cow<'a, str>>,
     {
+        let s = s.into();
+        self.size.check_input(&s)?;
         let s = common::ensure_not_empty(s)?;
-        self.0.allows(&s)?;
+        self.class.allows(&s)?;
         Ok(s)
     }
 
@@ -76,20 +89,25 @@ impl Rules for OpaqueString {
     where
         T: Into<Cow<'a, str>>,
     {
-        common::transform_from_first_match(s, common::is_non_ascii_space, |c, res| {
-            if common::is_non_ascii_space(c) {
-                res.push(common::SPACE);
-            } else {
-                res.push(c);
-            }
-        })
+        common::transform_from_first_match(
+            s,
+            common::is_non_ascii_space,
+            |c, res| {
+                if common::is_non_ascii_space(c) {
+                    res.push(common::SPACE);
+                } else {
+                    res.push(c);
+                }
+            },
+            self.size.output(),
+        )
     }
 
     fn normalization_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
     where
         T: Into<Cow<'a, str>>,
     {
-        common::normalization_form_nfc(s)
+        common::normalization_form_nfc(s, self.size.output())
     }
 }
 
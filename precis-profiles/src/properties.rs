@@ -0,0 +1,25 @@
+//! Unicode properties used by the profiles in this crate. They are
+//! provided to help applications inspect how profiles deal with code
+//! points, the derived property values of the PRECIS string classes
+//! are provided by [`precis_core`].
+//! ```rust
+//! # use precis_profiles::properties::{self, BidiClass};
+//! assert_eq!(properties::bidi_class('a'), BidiClass::L);
+//! assert_eq!(properties::bidi_class('\u{05d0}'), BidiClass::R);
+//! assert_eq!(properties::width_mapping('\u{ff21}'), Some('A'));
+//! assert_eq!(properties::width_mapping('A'), None);
+//! ```
+
+pub use crate::bidi::BidiClass;
+
+/// Returns the value of the Unicode character property
+/// [`Bidi_Class`](http://www.unicode.org/reports/tr44/#Bidi_Class)
+pub fn bidi_class(c: char) -> BidiClass {
+    crate::bidi::bidi_class(c)
+}
+
+/// Returns the code point a fullwidth or halfwidth code point is mapped
+/// to by the width mapping rule of the username profiles, if any.
+pub fn width_mapping(c: char) -> Option<char> {
+    crate::usernames::width_mapping(c)
+}
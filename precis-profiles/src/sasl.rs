@@ -0,0 +1,373 @@
+//! Helpers to prepare the identities and passwords exchanged by
+//! `SASL` mechanisms that rely on PRECIS:
+//! * [`rfc4616`](https://datatracker.ietf.org/doc/html/rfc4616). The
+//!   `PLAIN` Simple Authentication and Security Layer (`SASL`) Mechanism.
+//! * [`rfc5802`](https://datatracker.ietf.org/doc/html/rfc5802) and
+//!   [`rfc7677`](https://datatracker.ietf.org/doc/html/rfc7677). Salted
+//!   Challenge Response Authentication Mechanism (`SCRAM`).
+//!
+//! Both mechanisms were originally specified on top of `SASLprep`, which
+//! has been obsoleted by [`rfc8265`](https://datatracker.ietf.org/doc/html/rfc8265).
+//! Authentication and authorization identities are enforced with the
+//! [`UsernameCaseMapped`] profile and passwords with the [`OpaqueString`]
+//! profile.
+//!
+//! ```rust
+//! # use precis_profiles::sasl::PlainMessage;
+//! let msg = PlainMessage::parse(b"\0Tim\0tanstaaftanstaaf").unwrap();
+//! assert_eq!(msg.authzid, None);
+//! assert_eq!(msg.authcid, "tim");
+//! assert_eq!(msg.passwd, "tanstaaftanstaaf");
+//! ```
+
+use crate::common;
+use crate::{OpaqueString, UsernameCaseMapped};
+use precis_core::profile::PrecisFastInvocation;
+use precis_core::Error;
+use std::borrow::Cow;
+
+// RFC 4616: UTF8NUL separates the fields of the `PLAIN` message
+const UTF8_NUL: u8 = 0x00;
+
+// RFC 5802: the characters ',' and '=' in user names are sent
+// as '=2C' and '=3D' respectively
+const SASLNAME_ESCAPED_COMMA: &str = "=2C";
+const SASLNAME_ESCAPED_EQUAL: &str = "=3D";
+
+fn utf8_field(bytes: &[u8], max_len: Option<usize>) -> Result<&str, Error> {
+    if bytes.is_empty() {
+        return Err(Error::Invalid);
+    }
+    let s = std::str::from_utf8(bytes).map_err(|_| Error::Invalid)?;
+    common::check_size(s, max_len)?;
+    Ok(s)
+}
+
+/// Enforces an authentication or authorization identity using the
+/// [`UsernameCaseMapped`] profile.
+/// # Arguments:
+/// * `s`: The identity
+/// # Returns
+/// The enforced identity or an error if it is not a valid username
+pub fn prepare_username<'a, S>(s: S) -> Result<Cow<'a, str>, Error>
+where
+    S: Into<Cow<'a, str>>,
+{
+    UsernameCaseMapped::enforce(s)
+}
+
+/// Enforces a password using the [`OpaqueString`] profile.
+/// # Arguments:
+/// * `s`: The password
+/// # Returns
+/// The enforced password or an error if it is not a valid password
+pub fn prepare_password<'a, S>(s: S) -> Result<Cow<'a, str>, Error>
+where
+    S: Into<Cow<'a, str>>,
+{
+    OpaqueString::enforce(s)
+}
+
+/// A `PLAIN` mechanism message as defined in
+/// [`rfc4616`](https://datatracker.ietf.org/doc/html/rfc4616#section-2):
+///
+/// > `message   = [authzid] UTF8NUL authcid UTF8NUL passwd`
+///
+/// All fields contain the enforced form of the values sent by the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlainMessage<'a> {
+    /// Authorization identity, if the client provided one.
+    pub authzid: Option<Cow<'a, str>>,
+    /// Authentication identity.
+    pub authcid: Cow<'a, str>,
+    /// Password.
+    pub passwd: Cow<'a, str>,
+}
+
+impl<'a> PlainMessage<'a> {
+    /// Parses a `PLAIN` message and enforces its fields. The authorization
+    /// and authentication identities are enforced with the
+    /// [`UsernameCaseMapped`] profile and the password with the
+    /// [`OpaqueString`] profile. The length of the fields is not limited,
+    /// use [`PlainMessage::parse_with_max_field_len`] to limit it.
+    /// # Arguments:
+    /// * `msg`: The `PLAIN` message sent by the client
+    /// # Returns
+    /// The enforced message, [`Error::Invalid`] if the message is malformed
+    /// or the error returned by the profile that rejected one of the fields.
+    pub fn parse(msg: &'a [u8]) -> Result<Self, Error> {
+        Self::parse_fields(msg, None)
+    }
+
+    /// Parses a `PLAIN` message like [`PlainMessage::parse`], rejecting
+    /// fields longer than `max_field_len` octets.
+    /// [`rfc4616`](https://datatracker.ietf.org/doc/html/rfc4616#section-2)
+    /// requires servers to accept fields of at least 255 octets, larger
+    /// limits are left to the server.
+    /// # Arguments:
+    /// * `msg`: The `PLAIN` message sent by the client
+    /// * `max_field_len`: Maximum length in octets of each field
+    /// # Returns
+    /// The enforced message or the same errors as [`PlainMessage::parse`].
+    /// Fields longer than `max_field_len` are rejected with
+    /// [`Error::BadLength`].
+    /// # Example
+    /// ```rust
+    /// # use precis_core::{Error, LengthInfo, LengthLimit, LengthUnit};
+    /// # use precis_profiles::sasl::PlainMessage;
+    /// let msg = PlainMessage::parse_with_max_field_len(b"\0tim\0tanstaaf", 8).unwrap();
+    /// assert_eq!(msg.passwd, "tanstaaf");
+    /// assert_eq!(PlainMessage::parse_with_max_field_len(b"\0tim\0tanstaaftanstaaf", 8),
+    ///     Err(Error::BadLength(LengthInfo::new(LengthUnit::Bytes, 16, LengthLimit::Max(8)))));
+    /// ```
+    pub fn parse_with_max_field_len(msg: &'a [u8], max_field_len: usize) -> Result<Self, Error> {
+        Self::parse_fields(msg, Some(max_field_len))
+    }
+
+    fn parse_fields(msg: &'a [u8], max_len: Option<usize>) -> Result<Self, Error> {
+        let mut fields = msg.split(|b| *b == UTF8_NUL);
+        let (authzid, authcid, passwd) =
+            match (fields.next(), fields.next(), fields.next(), fields.next()) {
+                (Some(authzid), Some(authcid), Some(passwd), None) => (authzid, authcid, passwd),
+                _ => return Err(Error::Invalid),
+            };
+
+        let authzid = if authzid.is_empty() {
+            None
+        } else {
+            Some(prepare_username(utf8_field(authzid, max_len)?)?)
+        };
+
+        Ok(Self {
+            authzid,
+            authcid: prepare_username(utf8_field(authcid, max_len)?)?,
+            passwd: prepare_password(utf8_field(passwd, max_len)?)?,
+        })
+    }
+
+    /// Encodes this message in the format sent by `PLAIN` clients.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let authzid = self.authzid.as_deref().unwrap_or_default();
+        let mut msg =
+            Vec::with_capacity(authzid.len() + self.authcid.len() + self.passwd.len() + 2);
+        msg.extend_from_slice(authzid.as_bytes());
+        msg.push(UTF8_NUL);
+        msg.extend_from_slice(self.authcid.as_bytes());
+        msg.push(UTF8_NUL);
+        msg.extend_from_slice(self.passwd.as_bytes());
+        msg
+    }
+}
+
+/// Encodes a user name as a `SCRAM` `saslname`. Characters ',' and '='
+/// are replaced by "=2C" and "=3D" respectively, as described in
+/// [`rfc5802`](https://datatracker.ietf.org/doc/html/rfc5802#section-5.1).
+/// # Arguments:
+/// * `s`: The user name
+/// # Returns
+/// The same string if no escaping was required or a new allocated
+/// string otherwise
+pub fn escape_saslname(s: &str) -> Cow<'_, str> {
+    match s.find([',', '=']) {
+        None => Cow::from(s),
+        Some(pos) => {
+            let mut res = String::from(&s[..pos]);
+            res.reserve(s.len() - res.len() + 4);
+            for c in s[pos..].chars() {
+                match c {
+                    ',' => res.push_str(SASLNAME_ESCAPED_COMMA),
+                    '=' => res.push_str(SASLNAME_ESCAPED_EQUAL),
+                    _ => res.push(c),
+                }
+            }
+            Cow::from(res)
+        }
+    }
+}
+
+/// Decodes a `SCRAM` `saslname` as described in
+/// [`rfc5802`](https://datatracker.ietf.org/doc/html/rfc5802#section-5.1).
+/// # Arguments:
+/// * `s`: The `saslname`
+/// # Returns
+/// The decoded user name, or [`Error::Invalid`] if the `saslname`
+/// contains a ',' or a '=' not followed by "2C" or "3D".
+pub fn unescape_saslname(s: &str) -> Result<Cow<'_, str>, Error> {
+    if s.contains(',') {
+        return Err(Error::Invalid);
+    }
+
+    match s.find('=') {
+        None => Ok(Cow::from(s)),
+        Some(pos) => {
+            let mut res = String::from(&s[..pos]);
+            let mut rest = &s[pos..];
+            while let Some(pos) = rest.find('=') {
+                res.push_str(&rest[..pos]);
+                rest = &rest[pos..];
+                if rest.starts_with(SASLNAME_ESCAPED_COMMA) {
+                    res.push(',');
+                } else if rest.starts_with(SASLNAME_ESCAPED_EQUAL) {
+                    res.push('=');
+                } else {
+                    return Err(Error::Invalid);
+                }
+                rest = &rest[SASLNAME_ESCAPED_COMMA.len()..];
+            }
+            res.push_str(rest);
+            Ok(Cow::from(res))
+        }
+    }
+}
+
+/// Decodes a `SCRAM` `saslname` and enforces the resulting user name
+/// with the [`UsernameCaseMapped`] profile.
+/// # Arguments:
+/// * `s`: The `saslname`
+/// # Returns
+/// The enforced user name
+pub fn prepare_saslname(s: &str) -> Result<Cow<'_, str>, Error> {
+    prepare_username(unescape_saslname(s)?)
+}
+
+/// Channel binding flag sent in the `GS2` header of a `SCRAM`
+/// `client-first-message`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChannelBinding<'a> {
+    /// "n": The client does not support channel binding.
+    NotSupported,
+    /// "y": The client supports channel binding but thinks the server does not.
+    NotUsed,
+    /// "p": The client requires channel binding of the given type.
+    Required(&'a str),
+}
+
+/// A `SCRAM` `client-first-message` as defined in
+/// [`rfc5802`](https://datatracker.ietf.org/doc/html/rfc5802#section-7):
+///
+/// > `client-first-message = gs2-header client-first-message-bare`
+///
+/// The authorization identity and the user name are decoded from their
+/// `saslname` representation and enforced with the [`UsernameCaseMapped`]
+/// profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScramClientFirst<'a> {
+    /// Channel binding flag.
+    pub channel_binding: ChannelBinding<'a>,
+    /// Authorization identity, if the client provided one.
+    pub authzid: Option<Cow<'a, str>>,
+    /// User name.
+    pub username: Cow<'a, str>,
+    /// Client nonce.
+    pub nonce: &'a str,
+    /// The `client-first-message-bare` part of the message, required
+    /// to compute the `AuthMessage`.
+    pub bare: &'a str,
+}
+
+impl<'a> ScramClientFirst<'a> {
+    /// Parses a `SCRAM` `client-first-message`.
+    /// # Arguments:
+    /// * `msg`: The message sent by the client
+    /// # Returns
+    /// The parsed message, [`Error::Invalid`] if the message is malformed
+    /// or the error returned by the profile if any of the identities is
+    /// not a valid username.
+    pub fn parse(msg: &'a str) -> Result<Self, Error> {
+        let mut parts = msg.splitn(3, ',');
+        let (cbind, authzid, bare) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(cbind), Some(authzid), Some(bare)) => (cbind, authzid, bare),
+            _ => return Err(Error::Invalid),
+        };
+
+        let channel_binding = match cbind {
+            "n" => ChannelBinding::NotSupported,
+            "y" => ChannelBinding::NotUsed,
+            _ => match cbind.strip_prefix("p=") {
+                Some(name) if !name.is_empty() => ChannelBinding::Required(name),
+                _ => return Err(Error::Invalid),
+            },
+        };
+
+        let authzid = match authzid {
+            "" => None,
+            _ => match authzid.strip_prefix("a=") {
+                Some(name) => Some(prepare_saslname(name)?),
+                None => return Err(Error::Invalid),
+            },
+        };
+
+        // client-first-message-bare = [reserved-mext ","] username "," nonce ["," extensions]
+        if bare.starts_with("m=") {
+            // Mandatory extensions are not supported
+            return Err(Error::Invalid);
+        }
+
+        let mut attrs = bare.splitn(3, ',');
+        let username = attrs
+            .next()
+            .and_then(|s| s.strip_prefix("n="))
+            .ok_or(Error::Invalid)?;
+        let nonce = attrs
+            .next()
+            .and_then(|s| s.strip_prefix("r="))
+            .filter(|s| !s.is_empty())
+            .ok_or(Error::Invalid)?;
+
+        Ok(Self {
+            channel_binding,
+            authzid,
+            username: prepare_saslname(username)?,
+            nonce,
+            bare,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_sasl {
+    use crate::sasl::*;
+    use precis_core::{LengthInfo, LengthLimit, LengthUnit};
+
+    #[test]
+    fn test_escape_saslname() {
+        assert_eq!(escape_saslname(""), Cow::from(""));
+        assert_eq!(escape_saslname("user"), Cow::from("user"));
+        assert!(matches!(escape_saslname("user"), Cow::Borrowed(_)));
+        assert_eq!(escape_saslname("a,b"), Cow::from("a=2Cb"));
+        assert_eq!(escape_saslname("a=b"), Cow::from("a=3Db"));
+        assert_eq!(escape_saslname(",=,"), Cow::from("=2C=3D=2C"));
+    }
+
+    #[test]
+    fn test_unescape_saslname() {
+        assert_eq!(unescape_saslname(""), Ok(Cow::from("")));
+        assert_eq!(unescape_saslname("user"), Ok(Cow::from("user")));
+        assert_eq!(unescape_saslname("a=2Cb"), Ok(Cow::from("a,b")));
+        assert_eq!(unescape_saslname("a=3Db"), Ok(Cow::from("a=b")));
+        assert_eq!(unescape_saslname("=2C=3D=2C"), Ok(Cow::from(",=,")));
+
+        assert_eq!(unescape_saslname("a,b"), Err(Error::Invalid));
+        assert_eq!(unescape_saslname("a=b"), Err(Error::Invalid));
+        assert_eq!(unescape_saslname("a=2c"), Err(Error::Invalid));
+        assert_eq!(unescape_saslname("a="), Err(Error::Invalid));
+        assert_eq!(unescape_saslname("a=3"), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn test_utf8_field() {
+        assert_eq!(utf8_field(b"user", None), Ok("user"));
+        assert_eq!(utf8_field(b"", None), Err(Error::Invalid));
+        assert_eq!(utf8_field(&[0xff, 0xfe], None), Err(Error::Invalid));
+        assert!(utf8_field(&[b'a'; 1024], None).is_ok());
+        assert!(utf8_field(&[b'a'; 255], Some(255)).is_ok());
+        assert_eq!(
+            utf8_field(&[b'a'; 256], Some(255)),
+            Err(Error::BadLength(LengthInfo::new(
+                LengthUnit::Bytes,
+                256,
+                LengthLimit::Max(255)
+            )))
+        );
+    }
+}
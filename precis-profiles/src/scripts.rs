@@ -0,0 +1,466 @@
+//! Script detection as described in
+//! [`UTS #39`](https://www.unicode.org/reports/tr39/#Mixed_Script_Detection).
+//!
+//! Profiles accept identifiers that mix scripts, such as a username made of
+//! Latin and Cyrillic letters, which can be used to spoof other identifiers.
+//! This module provides the values of the `Script` and `Script_Extensions`
+//! properties of code points, detects mixed-script strings, and classifies
+//! strings by [`RestrictionLevel`]. Applications can reject enforced strings
+//! above a restriction level wrapping any profile in a [`Restricted`] profile.
+//! ```rust
+//! # use precis_profiles::scripts::{self, RestrictionLevel, Script};
+//! assert_eq!(scripts::script('\u{0430}'), Script::Cyrillic);
+//! assert!(scripts::is_mixed_script("p\u{0430}ypal"));
+//! assert_eq!(scripts::restriction_level("paypal"), RestrictionLevel::AsciiOnly);
+//! assert_eq!(scripts::restriction_level("p\u{0430}ypal"),
+//!     RestrictionLevel::MinimallyRestrictive);
+//! ```
+
+include_table!("scripts.rs");
+
+use precis_core::profile::Profile;
+use precis_core::{CodepointInfo, Codepoints, Error, FreeformClass, UnexpectedError};
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+
+// Scripts recommended for use in identifiers, listed in
+// [`UAX #31`, Table 7](https://www.unicode.org/reports/tr31/#Table_Recommended_Scripts).
+// Latin, Cyrillic and Greek are left out because they can not be
+// combined with Latin in moderately restrictive strings.
+static RECOMMENDED_SCRIPTS: [Script; 26] = [
+    Script::Arabic,
+    Script::Armenian,
+    Script::Bengali,
+    Script::Bopomofo,
+    Script::Devanagari,
+    Script::Ethiopic,
+    Script::Georgian,
+    Script::Gujarati,
+    Script::Gurmukhi,
+    Script::Han,
+    Script::Hangul,
+    Script::Hebrew,
+    Script::Hiragana,
+    Script::Kannada,
+    Script::Katakana,
+    Script::Khmer,
+    Script::Lao,
+    Script::Malayalam,
+    Script::Myanmar,
+    Script::Oriya,
+    Script::Sinhala,
+    Script::Tamil,
+    Script::Telugu,
+    Script::Thaana,
+    Script::Thai,
+    Script::Tibetan,
+];
+
+// Sets of scripts covering highly restrictive strings
+static HIGHLY_RESTRICTIVE_SCRIPTS: [&[Script]; 3] = [
+    &[
+        Script::Latin,
+        Script::Han,
+        Script::Hiragana,
+        Script::Katakana,
+    ],
+    &[Script::Latin, Script::Han, Script::Bopomofo],
+    &[Script::Latin, Script::Han, Script::Hangul],
+];
+
+/// Returns the value of the `Script` property of a code point
+pub fn script(c: char) -> Script {
+    let cp = c as u32;
+    match SCRIPTS.binary_search_by(|(cps, _)| cps.partial_cmp(&cp).unwrap()) {
+        Ok(idx) => SCRIPTS[idx].1,
+        // `Scripts.txt`: "All code points not explicitly listed for Script
+        // have the value Unknown (Zzzz)."
+        Err(_) => Script::Unknown,
+    }
+}
+
+/// Returns the value of the `Script_Extensions` property of a code point,
+/// that is, the set of scripts the code point is commonly used with.
+pub fn script_extensions(c: char) -> &'static [Script] {
+    let cp = c as u32;
+    if let Ok(idx) = SCRIPT_EXTENSIONS.binary_search_by(|(cps, _)| cps.partial_cmp(&cp).unwrap()) {
+        return SCRIPT_EXTENSIONS[idx].1;
+    }
+
+    // Code points not listed have the same value as their `Script` property
+    match SCRIPTS.binary_search_by(|(cps, _)| cps.partial_cmp(&cp).unwrap()) {
+        Ok(idx) => std::slice::from_ref(&SCRIPTS[idx].1),
+        Err(_) => &[Script::Unknown],
+    }
+}
+
+// Scripts that can be written with the scripts of other writing systems
+// are augmented with them, so that Japanese or Korean strings are not
+// considered mixed-script.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+enum AugmentedScript {
+    Script(Script),
+    // Han with Bopomofo (`Hanb`)
+    HanWithBopomofo,
+    // Japanese (`Jpan`)
+    Japanese,
+    // Korean (`Kore`)
+    Korean,
+}
+
+// Returns the augmented script set of a code point, or `None` if the code
+// point is used with all scripts, i.e. `Common` or `Inherited`.
+fn augmented_scripts(c: char) -> Option<BTreeSet<AugmentedScript>> {
+    let scripts = script_extensions(c);
+    if scripts
+        .iter()
+        .any(|s| matches!(s, Script::Common | Script::Inherited))
+    {
+        return None;
+    }
+
+    let mut set = BTreeSet::new();
+    for s in scripts {
+        set.insert(AugmentedScript::Script(*s));
+        match s {
+            Script::Han => {
+                set.insert(AugmentedScript::HanWithBopomofo);
+                set.insert(AugmentedScript::Japanese);
+                set.insert(AugmentedScript::Korean);
+            }
+            Script::Hiragana | Script::Katakana => {
+                set.insert(AugmentedScript::Japanese);
+            }
+            Script::Hangul => {
+                set.insert(AugmentedScript::Korean);
+            }
+            Script::Bopomofo => {
+                set.insert(AugmentedScript::HanWithBopomofo);
+            }
+            _ => {}
+        }
+    }
+    Some(set)
+}
+
+// Returns the resolved script set of a string, or `None` if all code
+// points of the string are used with all scripts.
+fn resolved_scripts(s: &str) -> Option<BTreeSet<AugmentedScript>> {
+    s.chars()
+        .filter_map(augmented_scripts)
+        .reduce(|acc, set| acc.intersection(&set).copied().collect())
+}
+
+/// Checks whether a string mixes scripts, that is, whether its resolved
+/// script set is empty. Code points used with all scripts, such as digits
+/// or punctuation, do not make a string mixed-script, neither do Han,
+/// Hiragana and Katakana in Japanese strings.
+pub fn is_mixed_script(s: &str) -> bool {
+    resolved_scripts(s).is_some_and(|set| set.is_empty())
+}
+
+// A set of scripts covers a code point if they share at least one script
+fn is_covered_by(c: char, scripts: &[Script]) -> bool {
+    script_extensions(c)
+        .iter()
+        .any(|s| matches!(s, Script::Common | Script::Inherited) || scripts.contains(s))
+}
+
+/// Restriction levels defined in
+/// [`UTS #39`](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection),
+/// sorted from the most to the least restrictive. This implementation
+/// does not check the identifier profile of `UTS #39`, code points are
+/// expected to be checked by a PRECIS profile instead, so the
+/// `Unrestricted` level is never returned.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum RestrictionLevel {
+    /// All code points are in the `ASCII` range.
+    AsciiOnly,
+    /// All code points come from a single script, or from the scripts
+    /// of the Japanese, Korean or Chinese writing systems.
+    SingleScript,
+    /// The string is covered by Latin and the scripts of the Japanese,
+    /// Korean or Chinese writing systems.
+    HighlyRestrictive,
+    /// The string is covered by Latin and any other recommended script,
+    /// except Cyrillic and Greek.
+    ModeratelyRestrictive,
+    /// Any other string.
+    MinimallyRestrictive,
+}
+
+// Restriction level of a string computed as code points are appended
+// to it, so that the level of every prefix is known in a single pass.
+struct RestrictionState {
+    ascii: bool,
+    // `None` while all code points are used with all scripts
+    resolved: Option<BTreeSet<AugmentedScript>>,
+    // Whether each set of `HIGHLY_RESTRICTIVE_SCRIPTS` covers the string
+    highly_restrictive: [bool; HIGHLY_RESTRICTIVE_SCRIPTS.len()],
+    // Whether Latin and each of `RECOMMENDED_SCRIPTS` cover the string
+    moderately_restrictive: [bool; RECOMMENDED_SCRIPTS.len()],
+}
+
+impl RestrictionState {
+    fn new() -> Self {
+        Self {
+            ascii: true,
+            resolved: None,
+            highly_restrictive: [true; HIGHLY_RESTRICTIVE_SCRIPTS.len()],
+            moderately_restrictive: [true; RECOMMENDED_SCRIPTS.len()],
+        }
+    }
+
+    fn push(&mut self, c: char) {
+        self.ascii &= c.is_ascii();
+        if let Some(set) = augmented_scripts(c) {
+            self.resolved = Some(match self.resolved.take() {
+                Some(resolved) => resolved.intersection(&set).copied().collect(),
+                None => set,
+            });
+        }
+        for (covered, scripts) in self
+            .highly_restrictive
+            .iter_mut()
+            .zip(HIGHLY_RESTRICTIVE_SCRIPTS)
+        {
+            *covered &= is_covered_by(c, scripts);
+        }
+        for (covered, script) in self
+            .moderately_restrictive
+            .iter_mut()
+            .zip(RECOMMENDED_SCRIPTS)
+        {
+            *covered &= is_covered_by(c, &[Script::Latin, script]);
+        }
+    }
+
+    fn level(&self) -> RestrictionLevel {
+        if self.ascii {
+            RestrictionLevel::AsciiOnly
+        } else if !self.resolved.as_ref().is_some_and(BTreeSet::is_empty) {
+            RestrictionLevel::SingleScript
+        } else if self.highly_restrictive.contains(&true) {
+            RestrictionLevel::HighlyRestrictive
+        } else if self.moderately_restrictive.contains(&true) {
+            RestrictionLevel::ModeratelyRestrictive
+        } else {
+            RestrictionLevel::MinimallyRestrictive
+        }
+    }
+}
+
+/// Returns the most restrictive [`RestrictionLevel`] a string satisfies
+pub fn restriction_level(s: &str) -> RestrictionLevel {
+    let mut state = RestrictionState::new();
+    s.chars().for_each(|c| state.push(c));
+    state.level()
+}
+
+// Finds the code point that makes a string less restrictive than `level`.
+// Appending code points never makes a string more restrictive, so it is the
+// last code point of the shortest prefix above `level`. The property is the
+// value of the code point in the `FreeformClass`, which is the value it has
+// in the string class of the profile that accepted it.
+fn first_unrestricted(s: &str, level: RestrictionLevel) -> Option<CodepointInfo> {
+    let mut state = RestrictionState::new();
+    s.chars().enumerate().find_map(|(position, c)| {
+        state.push(c);
+        (state.level() > level).then(|| {
+            let property = FreeformClass::default().get_value_from_char(c);
+            CodepointInfo::new(c as u32, position, property)
+        })
+    })
+}
+
+/// Profile that rejects strings enforced by another profile when they do
+/// not satisfy a [`RestrictionLevel`]. Preparation is delegated to the
+/// wrapped profile, because code points can still change when a string
+/// is enforced. Rejected strings are reported with an
+/// [`Error::MixedScript`] holding the first code point of the enforced
+/// string that breaks the restriction level.
+/// # Example
+/// ```rust
+/// # use precis_core::{CodepointInfo, DerivedPropertyValue, Error};
+/// # use precis_core::profile::Profile;
+/// # use precis_profiles::scripts::{Restricted, RestrictionLevel};
+/// # use precis_profiles::UsernameCaseMapped;
+/// # use std::borrow::Cow;
+/// let profile = Restricted::new(UsernameCaseMapped::new(), RestrictionLevel::SingleScript);
+///
+/// assert_eq!(profile.enforce("PayPal"), Ok(Cow::from("paypal")));
+/// assert_eq!(profile.enforce("\u{0430}\u{0431}\u{0432}"), Ok(Cow::from("\u{0430}\u{0431}\u{0432}")));
+///
+/// // Latin and Cyrillic letters
+/// assert_eq!(profile.enforce("p\u{0430}ypal"),
+///    Err(Error::MixedScript(CodepointInfo::new(0x0430, 1, DerivedPropertyValue::PValid))));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Restricted<P> {
+    profile: P,
+    level: RestrictionLevel,
+}
+
+impl<P: Profile> Restricted<P> {
+    /// Creates a profile that rejects the strings enforced by `profile`
+    /// that are less restrictive than `level`.
+    pub fn new(profile: P, level: RestrictionLevel) -> Self {
+        Self { profile, level }
+    }
+
+    /// Returns the wrapped profile
+    pub fn profile(&self) -> &P {
+        &self.profile
+    }
+
+    /// Returns the restriction level
+    pub fn level(&self) -> RestrictionLevel {
+        self.level
+    }
+}
+
+impl<P: Profile> Profile for Restricted<P> {
+    fn prepare<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.profile.prepare(s)
+    }
+
+    fn enforce<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let s = self.profile.enforce(s)?;
+        if restriction_level(&s) <= self.level {
+            return Ok(s);
+        }
+        match first_unrestricted(&s, self.level) {
+            Some(info) => Err(Error::MixedScript(info)),
+            None => Err(Error::Unexpected(UnexpectedError::Undefined)),
+        }
+    }
+
+    fn compare<A, B>(&self, s1: A, s2: B) -> Result<bool, Error>
+    where
+        A: AsRef<str>,
+        B: AsRef<str>,
+    {
+        self.enforce(s1.as_ref())?;
+        self.enforce(s2.as_ref())?;
+        self.profile.compare(s1, s2)
+    }
+}
+
+#[cfg(test)]
+mod test_scripts {
+    use crate::scripts::*;
+    use precis_core::DerivedPropertyValue;
+
+    #[test]
+    fn scripts() {
+        assert_eq!(script('a'), Script::Latin);
+        assert_eq!(script('\u{03b1}'), Script::Greek);
+        assert_eq!(script('1'), Script::Common);
+        assert_eq!(script('\u{0301}'), Script::Inherited);
+        assert_eq!(script('\u{0378}'), Script::Unknown);
+        assert_eq!(script('\u{10300}'), Script::OldItalic);
+    }
+
+    #[test]
+    fn extensions() {
+        assert_eq!(script_extensions('a'), &[Script::Latin]);
+        assert_eq!(script_extensions('\u{0378}'), &[Script::Unknown]);
+        // KATAKANA-HIRAGANA PROLONGED SOUND MARK
+        assert_eq!(
+            script_extensions('\u{30fc}'),
+            &[Script::Hiragana, Script::Katakana]
+        );
+    }
+
+    #[test]
+    fn mixed_scripts() {
+        assert!(!is_mixed_script(""));
+        assert!(!is_mixed_script("123"));
+        assert!(!is_mixed_script("abc123"));
+        assert!(is_mixed_script("ab\u{0441}"));
+        // Han, Hiragana and Katakana
+        assert!(!is_mixed_script("\u{6f22}\u{3072}\u{30ab}"));
+        // Han and Hangul
+        assert!(!is_mixed_script("\u{6f22}\u{d55c}"));
+        // Hiragana and Hangul
+        assert!(is_mixed_script("\u{3072}\u{d55c}"));
+    }
+
+    #[test]
+    fn restriction_levels() {
+        assert_eq!(restriction_level(""), RestrictionLevel::AsciiOnly);
+        assert_eq!(restriction_level("abc"), RestrictionLevel::AsciiOnly);
+        assert_eq!(
+            restriction_level("caf\u{00e9}"),
+            RestrictionLevel::SingleScript
+        );
+        assert_eq!(
+            restriction_level("\u{03b1}\u{03b2}"),
+            RestrictionLevel::SingleScript
+        );
+        assert_eq!(
+            restriction_level("abc\u{6f22}\u{3072}"),
+            RestrictionLevel::HighlyRestrictive
+        );
+        assert_eq!(
+            restriction_level("abc\u{05d0}"),
+            RestrictionLevel::ModeratelyRestrictive
+        );
+        assert_eq!(
+            restriction_level("abc\u{0430}"),
+            RestrictionLevel::MinimallyRestrictive
+        );
+        assert_eq!(
+            restriction_level("abc\u{03b1}"),
+            RestrictionLevel::MinimallyRestrictive
+        );
+    }
+
+    #[test]
+    fn first_unrestricted_codepoint() {
+        let info = |cp, position| {
+            Some(CodepointInfo::new(
+                cp,
+                position,
+                DerivedPropertyValue::PValid,
+            ))
+        };
+        assert_eq!(first_unrestricted("abc", RestrictionLevel::AsciiOnly), None);
+        assert_eq!(
+            first_unrestricted("ab\u{0430}c", RestrictionLevel::AsciiOnly),
+            info(0x0430, 2)
+        );
+        assert_eq!(
+            first_unrestricted("ab\u{0430}c", RestrictionLevel::SingleScript),
+            info(0x0430, 2)
+        );
+        // Cyrillic is not mixed until a Latin letter follows it
+        assert_eq!(
+            first_unrestricted("\u{0430}\u{0431}1b", RestrictionLevel::SingleScript),
+            info(0x62, 3)
+        );
+        assert_eq!(
+            first_unrestricted(
+                "abc\u{05d0}\u{0430}",
+                RestrictionLevel::ModeratelyRestrictive
+            ),
+            info(0x0430, 4)
+        );
+        assert_eq!(
+            first_unrestricted("abc\u{0430}", RestrictionLevel::MinimallyRestrictive),
+            None
+        );
+        // Strings are scanned once
+        let long = format!("{}\u{0430}", "\u{00e9}".repeat(100_000));
+        assert_eq!(
+            first_unrestricted(&long, RestrictionLevel::SingleScript),
+            info(0x0430, 100_000)
+        );
+    }
+}
@@ -0,0 +1,397 @@
+//! Legacy [`stringprep`](https://datatracker.ietf.org/doc/html/rfc3454) profiles.
+//!
+//! `PRECIS` obsoletes `stringprep`, but applications migrating from it
+//! usually have stored data that was prepared with one of its profiles.
+//! This module implements the `stringprep` algorithm together with the
+//! following profiles, so that old and new preparations can be compared:
+//! * [`Nodeprep`], defined in [`rfc3920`](https://datatracker.ietf.org/doc/html/rfc3920#appendix-A),
+//!   replaced by [`UsernameCaseMapped`](crate::UsernameCaseMapped).
+//! * [`Resourceprep`], defined in [`rfc3920`](https://datatracker.ietf.org/doc/html/rfc3920#appendix-B),
+//!   replaced by [`OpaqueString`](crate::OpaqueString).
+//! * [`Saslprep`], defined in [`rfc4013`](https://datatracker.ietf.org/doc/html/rfc4013),
+//!   replaced by [`OpaqueString`](crate::OpaqueString).
+//!
+//! Tables are those defined in `rfc3454` for Unicode 3.2. Normalization is
+//! performed with the Unicode version supported by this crate, which is
+//! stable for all code points assigned in Unicode 3.2.
+//!
+//! The [`migration_status`] function reports whether a `stringprep` profile
+//! and a `PRECIS` profile agree on a given input.
+//! # Example
+//! ```rust
+//! # use precis_profiles::stringprep::{migration_status, MigrationStatus, Nodeprep};
+//! # use precis_profiles::UsernameCaseMapped;
+//! # use std::borrow::Cow;
+//! let nodeprep = Nodeprep::new();
+//! let precis = UsernameCaseMapped::new();
+//!
+//! assert_eq!(migration_status(&nodeprep, &precis, "Juliet"),
+//!     MigrationStatus::Agree(Cow::from("juliet")));
+//!
+//! // Spaces are prohibited in both profiles
+//! assert!(matches!(migration_status(&nodeprep, &precis, "Juliet Capulet"),
+//!     MigrationStatus::BothRejected { .. }));
+//! ```
+
+include_table!("stringprep_tables.rs");
+
+use crate::common;
+use precis_core::profile::Profile;
+use precis_core::CodepointSet;
+use precis_core::{CodepointInfo, DerivedPropertyValue, Error};
+use std::borrow::Cow;
+
+#[inline]
+fn in_table(table: &CodepointSet, c: char) -> bool {
+    table.contains(c as u32)
+}
+
+#[inline]
+fn case_fold(c: char) -> Option<&'static [u32]> {
+    let cp = c as u32;
+    STRINGPREP_B_2
+        .binary_search_by(|(key, _)| key.cmp(&cp))
+        .ok()
+        .map(|idx| STRINGPREP_B_2[idx].1)
+}
+
+// Tables prohibited in all the profiles implemented
+static COMMON_PROHIBITED: [&CodepointSet; 10] = [
+    &STRINGPREP_C_1_2,
+    &STRINGPREP_C_2_1,
+    &STRINGPREP_C_2_2,
+    &STRINGPREP_C_3,
+    &STRINGPREP_C_4,
+    &STRINGPREP_C_5,
+    &STRINGPREP_C_6,
+    &STRINGPREP_C_7,
+    &STRINGPREP_C_8,
+    &STRINGPREP_C_9,
+];
+
+// Additional code points prohibited by `Nodeprep`
+const NODEPREP_PROHIBITED: [char; 8] = ['"', '&', '\'', '/', ':', '<', '>', '@'];
+
+/// Specifies how unassigned code points (table `A.1`) are handled.
+/// Look at [`rfc3454` section 7](https://datatracker.ietf.org/doc/html/rfc3454#section-7)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum Unassigned {
+    /// Unassigned code points are allowed, used for queries.
+    Allowed,
+    /// Unassigned code points are prohibited, used for stored strings.
+    #[default]
+    Prohibited,
+}
+
+/// Preparation rules that define a `stringprep` profile
+struct StringprepRules {
+    // Map table `B.2`
+    case_fold: bool,
+    // Map table `C.1.2` to `SPACE`
+    map_non_ascii_space: bool,
+    // Table `C.1.1` is prohibited
+    prohibit_ascii_space: bool,
+    // Extra prohibited code points
+    prohibited: &'static [char],
+}
+
+impl StringprepRules {
+    fn needs_mapping(&self, c: char) -> bool {
+        in_table(&STRINGPREP_B_1, c)
+            || (self.case_fold && case_fold(c).is_some())
+            || (self.map_non_ascii_space && in_table(&STRINGPREP_C_1_2, c))
+    }
+
+    fn map<'a>(&self, s: Cow<'a, str>) -> Result<Cow<'a, str>, Error> {
+        common::transform_from_first_match(
+            s,
+            |c| self.needs_mapping(c),
+            |c, res| {
+                if in_table(&STRINGPREP_B_1, c) {
+                    // Map to nothing
+                } else if let Some(mapping) = case_fold(c).filter(|_| self.case_fold) {
+                    res.extend(mapping.iter().filter_map(|cp| char::from_u32(*cp)));
+                } else if self.map_non_ascii_space && in_table(&STRINGPREP_C_1_2, c) {
+                    res.push(common::SPACE);
+                } else {
+                    res.push(c);
+                }
+            },
+            None,
+        )
+    }
+
+    fn is_prohibited(&self, c: char) -> bool {
+        self.prohibited.contains(&c)
+            || (self.prohibit_ascii_space && in_table(&STRINGPREP_C_1_1, c))
+            || COMMON_PROHIBITED.iter().any(|table| in_table(table, c))
+    }
+
+    fn check_prohibited(&self, s: &str, unassigned: Unassigned) -> Result<(), Error> {
+        for (pos, c) in s.chars().enumerate() {
+            if self.is_prohibited(c) {
+                return Err(bad_codepoint(c, pos, DerivedPropertyValue::Disallowed));
+            }
+            if unassigned == Unassigned::Prohibited && in_table(&STRINGPREP_A_1, c) {
+                return Err(bad_codepoint(c, pos, DerivedPropertyValue::Unassigned));
+            }
+        }
+        Ok(())
+    }
+
+    fn prepare<'a>(&self, s: Cow<'a, str>, unassigned: Unassigned) -> Result<Cow<'a, str>, Error> {
+        let s = self.map(s)?;
+        let s = common::normalization_form_nfkc(s, None)?;
+        self.check_prohibited(&s, unassigned)?;
+        check_bidi(&s)?;
+        Ok(s)
+    }
+}
+
+fn bad_codepoint(c: char, position: usize, property: DerivedPropertyValue) -> Error {
+    Error::BadCodepoint(CodepointInfo::new(c as u32, position, property))
+}
+
+// `rfc3454` section 6. Bidirectional Characters. Prohibited characters
+// in table `C.8` are already checked before applying these rules.
+// 2) If a string contains any `RandALCat` character, the string MUST NOT
+//    contain any `LCat` character.
+// 3) If a string contains any `RandALCat` character, a `RandALCat`
+//    character MUST be the first character of the string, and a
+//    `RandALCat` character MUST be the last character of the string.
+fn check_bidi(s: &str) -> Result<(), Error> {
+    let is_rand_al_cat = |c| in_table(&STRINGPREP_D_1, c);
+
+    if s.find(is_rand_al_cat).is_none() {
+        return Ok(());
+    }
+
+    if s.find(|c| in_table(&STRINGPREP_D_2, c)).is_some() {
+        return Err(Error::Invalid);
+    }
+
+    let first = s.chars().next().is_some_and(is_rand_al_cat);
+    let last = s.chars().next_back().is_some_and(is_rand_al_cat);
+    if first && last {
+        Ok(())
+    } else {
+        Err(Error::Invalid)
+    }
+}
+
+/// Trait implemented by all `stringprep` profiles.
+pub trait Stringprep {
+    /// Prepares the string according to the rules of the `stringprep` profile.
+    /// # Arguments:
+    /// * `s`: String value
+    /// # Returns
+    /// The prepared string or an error if the string contains
+    /// prohibited code points or does not satisfy the bidirectional rules.
+    fn prepare<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>;
+}
+
+macro_rules! stringprep_profile {
+    ($(#[$meta:meta])* $name:ident, $rules:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+        pub struct $name(Unassigned);
+
+        impl $name {
+            #[doc = concat!("Creates a [`", stringify!($name), "`] profile for stored strings.")]
+            pub fn new() -> Self {
+                Self(Unassigned::Prohibited)
+            }
+
+            #[doc = concat!("Creates a [`", stringify!($name), "`] profile that handles")]
+            /// unassigned code points as specified.
+            pub fn with_unassigned(unassigned: Unassigned) -> Self {
+                Self(unassigned)
+            }
+        }
+
+        impl Stringprep for $name {
+            fn prepare<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+            where
+                S: Into<Cow<'a, str>>,
+            {
+                const RULES: StringprepRules = $rules;
+                RULES.prepare(s.into(), self.0)
+            }
+        }
+    };
+}
+
+stringprep_profile!(
+    /// `Nodeprep` profile of `stringprep` used to prepare the node identifier
+    /// of `XMPP` addresses.
+    /// Look at [`rfc3920` Appendix A](https://datatracker.ietf.org/doc/html/rfc3920#appendix-A)
+    /// # Example
+    /// ```rust
+    /// # use precis_profiles::stringprep::{Nodeprep, Stringprep};
+    /// # use std::borrow::Cow;
+    /// assert_eq!(Nodeprep::new().prepare("Juliet"), Ok(Cow::from("juliet")));
+    /// assert!(Nodeprep::new().prepare("juliet@capulet").is_err());
+    /// ```
+    Nodeprep,
+    StringprepRules {
+        case_fold: true,
+        map_non_ascii_space: false,
+        prohibit_ascii_space: true,
+        prohibited: &NODEPREP_PROHIBITED,
+    }
+);
+
+stringprep_profile!(
+    /// `Resourceprep` profile of `stringprep` used to prepare the resource
+    /// identifier of `XMPP` addresses.
+    /// Look at [`rfc3920` Appendix B](https://datatracker.ietf.org/doc/html/rfc3920#appendix-B)
+    /// # Example
+    /// ```rust
+    /// # use precis_profiles::stringprep::{Resourceprep, Stringprep};
+    /// # use std::borrow::Cow;
+    /// assert_eq!(Resourceprep::new().prepare("Balcony Window"), Ok(Cow::from("Balcony Window")));
+    /// ```
+    Resourceprep,
+    StringprepRules {
+        case_fold: false,
+        map_non_ascii_space: false,
+        prohibit_ascii_space: false,
+        prohibited: &[],
+    }
+);
+
+stringprep_profile!(
+    /// `SASLprep` profile of `stringprep` used to prepare user names and
+    /// passwords.
+    /// Look at [`rfc4013`](https://datatracker.ietf.org/doc/html/rfc4013)
+    /// # Example
+    /// ```rust
+    /// # use precis_profiles::stringprep::{Saslprep, Stringprep};
+    /// # use std::borrow::Cow;
+    /// assert_eq!(Saslprep::new().prepare("I\u{00AD}X"), Ok(Cow::from("IX")));
+    /// assert!(Saslprep::new().prepare("\u{0007}").is_err());
+    /// ```
+    Saslprep,
+    StringprepRules {
+        case_fold: false,
+        map_non_ascii_space: true,
+        prohibit_ascii_space: false,
+        prohibited: &[],
+    }
+);
+
+/// Result of preparing the same input with a `stringprep` profile
+/// and with the `PRECIS` profile that replaces it.
+#[derive(Debug, PartialEq, Eq)]
+pub enum MigrationStatus<'a> {
+    /// Both profiles accept the input and produce the same output.
+    Agree(Cow<'a, str>),
+    /// Both profiles accept the input but produce different outputs.
+    Differ {
+        /// Output of the `stringprep` profile
+        stringprep: Cow<'a, str>,
+        /// Output of the `PRECIS` profile
+        precis: Cow<'a, str>,
+    },
+    /// Only the `stringprep` profile accepts the input.
+    StringprepOnly {
+        /// Output of the `stringprep` profile
+        stringprep: Cow<'a, str>,
+        /// Error returned by the `PRECIS` profile
+        precis: Error,
+    },
+    /// Only the `PRECIS` profile accepts the input.
+    PrecisOnly {
+        /// Error returned by the `stringprep` profile
+        stringprep: Error,
+        /// Output of the `PRECIS` profile
+        precis: Cow<'a, str>,
+    },
+    /// Both profiles reject the input.
+    BothRejected {
+        /// Error returned by the `stringprep` profile
+        stringprep: Error,
+        /// Error returned by the `PRECIS` profile
+        precis: Error,
+    },
+}
+
+/// Prepares the input with a `stringprep` profile and enforces it with a
+/// `PRECIS` profile, reporting whether both of them agree.
+/// # Arguments:
+/// * `stringprep`: The legacy `stringprep` profile
+/// * `precis`: The `PRECIS` profile
+/// * `s`: String value
+/// # Returns
+/// The [`MigrationStatus`] of the input.
+pub fn migration_status<'a, S, P>(stringprep: &S, precis: &P, s: &'a str) -> MigrationStatus<'a>
+where
+    S: Stringprep,
+    P: Profile,
+{
+    match (stringprep.prepare(s), precis.enforce(s)) {
+        (Ok(sp), Ok(p)) if sp == p => MigrationStatus::Agree(p),
+        (Ok(sp), Ok(p)) => MigrationStatus::Differ {
+            stringprep: sp,
+            precis: p,
+        },
+        (Ok(sp), Err(e)) => MigrationStatus::StringprepOnly {
+            stringprep: sp,
+            precis: e,
+        },
+        (Err(e), Ok(p)) => MigrationStatus::PrecisOnly {
+            stringprep: e,
+            precis: p,
+        },
+        (Err(sp), Err(p)) => MigrationStatus::BothRejected {
+            stringprep: sp,
+            precis: p,
+        },
+    }
+}
+
+#[cfg(test)]
+mod test_stringprep {
+    use crate::stringprep::*;
+
+    #[test]
+    fn tables() {
+        // Tables must be sorted to perform binary searches
+        assert!(STRINGPREP_B_2.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(in_table(&STRINGPREP_A_1, '\u{0221}'));
+        assert!(!in_table(&STRINGPREP_A_1, 'a'));
+        assert!(in_table(&STRINGPREP_D_1, '\u{05d0}'));
+        assert!(in_table(&STRINGPREP_D_2, 'a'));
+        assert_eq!(case_fold('A'), Some(&[0x0061u32][..]));
+        assert_eq!(case_fold('\u{00df}'), Some(&[0x0073u32, 0x0073][..]));
+        assert_eq!(case_fold('a'), None);
+    }
+
+    #[test]
+    fn bidi() {
+        assert_eq!(check_bidi("abc"), Ok(()));
+        assert_eq!(check_bidi("\u{0627}\u{0628}"), Ok(()));
+        assert_eq!(check_bidi("\u{0627}1\u{0628}"), Ok(()));
+        assert_eq!(check_bidi("\u{0627}1"), Err(Error::Invalid));
+        assert_eq!(check_bidi("a\u{0627}"), Err(Error::Invalid));
+        assert_eq!(check_bidi("\u{0627}a\u{0628}"), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn unassigned() {
+        let res = Saslprep::new().prepare("\u{0221}");
+        assert_eq!(
+            res,
+            Err(Error::BadCodepoint(CodepointInfo::new(
+                0x0221,
+                0,
+                DerivedPropertyValue::Unassigned
+            )))
+        );
+
+        let profile = Saslprep::with_unassigned(Unassigned::Allowed);
+        assert_eq!(profile.prepare("\u{0221}"), Ok(Cow::from("\u{0221}")));
+    }
+}
@@ -1,10 +1,11 @@
-include!(concat!(env!("OUT_DIR"), "/width_mapping.rs"));
+include_table!("width_mapping.rs");
 
 use crate::bidi;
 use crate::common;
+use crate::limits::SizeLimits;
 use precis_core::profile::{PrecisFastInvocation, Profile, Rules};
 use precis_core::Codepoints;
-use precis_core::{Error, UnexpectedError};
+use precis_core::{CodepointInfo, DerivedPropertyValue, Error, UnexpectedError};
 use precis_core::{IdentifierClass, StringClass};
 use std::borrow::Cow;
 use std::sync::LazyLock;
@@ -16,11 +17,15 @@ fn get_decomposition_mapping(cp: u32) -> Option<u32> {
         .ok()
 }
 
+pub(crate) fn width_mapping(c: char) -> Option<char> {
+    get_decomposition_mapping(c as u32).and_then(char::from_u32)
+}
+
 fn has_width_mapping(c: char) -> bool {
     get_decomposition_mapping(c as u32).is_some()
 }
 
-fn width_mapping_rule<'a, T>(s: T) -> Result<Cow<'a, str>, Error>
+pub(crate) fn width_mapping_rule<'a, T>(s: T) -> Result<Cow<'a, str>, Error>
 where
     T: Into<Cow<'a, str>>,
 {
@@ -43,7 +48,7 @@ where
     }
 }
 
-fn directionality_rule<'a, T>(s: T) -> Result<Cow<'a, str>, Error>
+pub(crate) fn directionality_rule<'a, T>(s: T) -> Result<Cow<'a, str>, Error>
 where
     T: Into<Cow<'a, str>>,
 {
@@ -57,6 +62,65 @@ where
     }
 }
 
+// From `rfc8265` section 3.3 the syntax of a username is:
+//   username   = userpart *(%x20 userpart)
+//   userpart   = 1*(idchar)
+// Applications that allow spaces apply the rules of the profile to each
+// userpart separately. Empty userparts, produced by leading, trailing or
+// repeated spaces, are not allowed. The size of the username is checked
+// against `max_size` as userparts are joined, so the limit applies to the
+// whole username and not to each userpart.
+fn apply_to_userparts<'a, F>(
+    s: Cow<'a, str>,
+    max_size: Option<usize>,
+    f: F,
+) -> Result<Cow<'a, str>, Error>
+where
+    F: for<'b> Fn(&'b str) -> Result<Cow<'b, str>, Error>,
+{
+    if s.is_empty() {
+        return Err(Error::Invalid);
+    }
+
+    let mut res = String::with_capacity(s.len());
+    let mut offset = 0;
+    let mut parts = s.split(common::SPACE).peekable();
+    while let Some(part) = parts.next() {
+        let last = parts.peek().is_none();
+        if part.is_empty() {
+            // Report the space that produced the empty userpart
+            let position = if last { offset - 1 } else { offset };
+            return Err(Error::BadCodepoint(CodepointInfo::new(
+                common::SPACE as u32,
+                position,
+                DerivedPropertyValue::SpecClassDis,
+            )));
+        }
+
+        let len = part.chars().count();
+        let part = f(part).map_err(|e| match e {
+            Error::BadCodepoint(mut info) => {
+                info.position += offset;
+                Error::BadCodepoint(info)
+            }
+            e => e,
+        })?;
+
+        if !res.is_empty() {
+            res.push(common::SPACE);
+        }
+        res.push_str(&part);
+        common::check_size(&res, max_size)?;
+        offset += len + 1;
+    }
+
+    if res == s {
+        Ok(s)
+    } else {
+        Ok(res.into())
+    }
+}
+
 /// [`UsernameCaseMapped`](https://datatracker.ietf.org/doc/html/rfc8265#section-3.3).
 /// Profile designed to deal with `usernames` in security and application protocols.
 /// It replaces the `SASLprep` profile of `Stringprep`. Look at the
@@ -83,38 +147,90 @@ where
 ///
 /// // compare strings
 /// assert_eq!(profile.compare("Guybrush", "guybrush"), Ok(true));
+///
+/// // Usernames made of several userparts
+/// let profile = UsernameCaseMapped::with_userparts();
+/// assert_eq!(profile.enforce("Guybrush Threepwood"), Ok(Cow::from("guybrush threepwood")));
 /// ```
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
-pub struct UsernameCaseMapped(IdentifierClass);
+pub struct UsernameCaseMapped {
+    class: IdentifierClass,
+    userparts: bool,
+    size: SizeLimits,
+}
 
 impl UsernameCaseMapped {
     /// Creates a [`UsernameCaseMapped`] profile.
     pub fn new() -> Self {
-        Self(IdentifierClass::default())
+        Self::default()
     }
-}
 
-impl Profile for UsernameCaseMapped {
-    fn prepare<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    /// Creates a [`UsernameCaseMapped`] profile that accepts usernames made of
+    /// one or more userparts separated by `SPACE` (`U+0020`). Rules are
+    /// applied to each userpart separately. Leading, trailing or repeated
+    /// spaces are not allowed.
+    pub fn with_userparts() -> Self {
+        Self {
+            userparts: true,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the [`SizeLimits`] used to protect this profile against
+    /// inputs that expand a lot when they are mapped or normalized.
+    pub fn with_size_limits(mut self, size: SizeLimits) -> Self {
+        self.size = size;
+        self
+    }
+
+    fn prepare_userpart<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
     where
         S: Into<Cow<'a, str>>,
     {
         let s = self.width_mapping_rule(s)?;
         let s = common::ensure_not_empty(s)?;
-        self.0.allows(&s)?;
+        self.class.allows(&s)?;
         Ok(s)
     }
 
-    fn enforce<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    fn enforce_userpart<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
     where
         S: Into<Cow<'a, str>>,
     {
-        let s = self.prepare(s)?;
+        let s = self.prepare_userpart(s)?;
         let s = self.case_mapping_rule(s)?;
         let s = self.normalization_rule(s)?;
         let s = common::ensure_not_empty(s)?;
         self.directionality_rule(s)
     }
+}
+
+impl Profile for UsernameCaseMapped {
+    fn prepare<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let s = s.into();
+        self.size.check_input(&s)?;
+        if self.userparts {
+            apply_to_userparts(s, None, |part| self.prepare_userpart(part))
+        } else {
+            self.prepare_userpart(s)
+        }
+    }
+
+    fn enforce<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let s = s.into();
+        self.size.check_input(&s)?;
+        if self.userparts {
+            apply_to_userparts(s, self.size.output(), |part| self.enforce_userpart(part))
+        } else {
+            self.enforce_userpart(s)
+        }
+    }
 
     fn compare<A, B>(&self, s1: A, s2: B) -> Result<bool, Error>
     where
@@ -137,14 +253,14 @@ impl Rules for UsernameCaseMapped {
     where
         T: Into<Cow<'a, str>>,
     {
-        common::case_mapping_rule(s)
+        common::case_mapping_rule(s, self.size.output())
     }
 
     fn normalization_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
     where
         T: Into<Cow<'a, str>>,
     {
-        common::normalization_form_nfc(s)
+        common::normalization_form_nfc(s, self.size.output())
     }
 
     fn directionality_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
@@ -213,35 +329,83 @@ impl PrecisFastInvocation for UsernameCaseMapped {
 /// assert_eq!(profile.compare("Guybrush", "Guybrush"), Ok(true));
 /// ```
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
-pub struct UsernameCasePreserved(IdentifierClass);
+pub struct UsernameCasePreserved {
+    class: IdentifierClass,
+    userparts: bool,
+    size: SizeLimits,
+}
 
 impl UsernameCasePreserved {
     /// Creates a [`UsernameCasePreserved`] profile.
     pub fn new() -> Self {
-        Self(IdentifierClass::default())
+        Self::default()
     }
-}
 
-impl Profile for UsernameCasePreserved {
-    fn prepare<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    /// Creates a [`UsernameCasePreserved`] profile that accepts usernames made of
+    /// one or more userparts separated by `SPACE` (`U+0020`). Rules are
+    /// applied to each userpart separately. Leading, trailing or repeated
+    /// spaces are not allowed.
+    pub fn with_userparts() -> Self {
+        Self {
+            userparts: true,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the [`SizeLimits`] used to protect this profile against
+    /// inputs that expand a lot when they are mapped or normalized.
+    pub fn with_size_limits(mut self, size: SizeLimits) -> Self {
+        self.size = size;
+        self
+    }
+
+    fn prepare_userpart<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
     where
         S: Into<Cow<'a, str>>,
     {
         let s = self.width_mapping_rule(s)?;
         let s = common::ensure_not_empty(s)?;
-        self.0.allows(&s)?;
+        self.class.allows(&s)?;
         Ok(s)
     }
 
-    fn enforce<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    fn enforce_userpart<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
     where
         S: Into<Cow<'a, str>>,
     {
-        let s = self.prepare(s)?;
+        let s = self.prepare_userpart(s)?;
         let s = self.normalization_rule(s)?;
         let s = common::ensure_not_empty(s)?;
         self.directionality_rule(s)
     }
+}
+
+impl Profile for UsernameCasePreserved {
+    fn prepare<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let s = s.into();
+        self.size.check_input(&s)?;
+        if self.userparts {
+            apply_to_userparts(s, None, |part| self.prepare_userpart(part))
+        } else {
+            self.prepare_userpart(s)
+        }
+    }
+
+    fn enforce<'a, S>(&self, s: S) -> Result<Cow<'a, str>, Error>
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        let s = s.into();
+        self.size.check_input(&s)?;
+        if self.userparts {
+            apply_to_userparts(s, self.size.output(), |part| self.enforce_userpart(part))
+        } else {
+            self.enforce_userpart(s)
+        }
+    }
 
     fn compare<A, B>(&self, s1: A, s2: B) -> Result<bool, Error>
     where
@@ -264,7 +428,7 @@ impl Rules for UsernameCasePreserved {
     where
         T: Into<Cow<'a, str>>,
     {
-        common::normalization_form_nfc(s)
+        common::normalization_form_nfc(s, self.size.output())
     }
 
     fn directionality_rule<'a, T>(&self, s: T) -> Result<Cow<'a, str>, Error>
@@ -308,6 +472,7 @@ impl PrecisFastInvocation for UsernameCasePreserved {
 #[cfg(test)]
 mod profile_rules {
     use crate::usernames::*;
+    use precis_core::{LengthInfo, LengthLimit, LengthUnit};
 
     #[test]
     fn test_width_mapping_rule() {
@@ -332,6 +497,70 @@ mod profile_rules {
         assert_eq!(res, Ok(Cow::from("\u{0023}\u{0023}\u{0023}")));
     }
 
+    #[test]
+    fn test_apply_to_userparts() {
+        fn upper(s: &str) -> Result<Cow<'_, str>, Error> {
+            match s.find(|c: char| !c.is_ascii_alphabetic()) {
+                Some(pos) => Err(Error::BadCodepoint(CodepointInfo::new(
+                    s.as_bytes()[pos] as u32,
+                    pos,
+                    DerivedPropertyValue::Disallowed,
+                ))),
+                None => Ok(Cow::from(s.to_uppercase())),
+            }
+        }
+
+        let res = apply_to_userparts(Cow::from(""), None, upper);
+        assert_eq!(res, Err(Error::Invalid));
+
+        let res = apply_to_userparts(Cow::from("ab"), None, upper);
+        assert_eq!(res, Ok(Cow::from("AB")));
+
+        let res = apply_to_userparts(Cow::from("ab cd ef"), None, upper);
+        assert_eq!(res, Ok(Cow::from("AB CD EF")));
+
+        // String is not reallocated if userparts do not change
+        let res = apply_to_userparts(Cow::from("AB CD"), None, upper);
+        assert!(matches!(res, Ok(Cow::Borrowed("AB CD"))));
+
+        // Errors are reported relative to the whole string
+        let res = apply_to_userparts(Cow::from("ab c1"), None, upper);
+        assert_eq!(
+            res,
+            Err(Error::BadCodepoint(CodepointInfo::new(
+                0x31,
+                4,
+                DerivedPropertyValue::Disallowed
+            )))
+        );
+
+        // Size limit applies to the joined userparts
+        let res = apply_to_userparts(Cow::from("ab cd"), Some(5), upper);
+        assert_eq!(res, Ok(Cow::from("AB CD")));
+        let res = apply_to_userparts(Cow::from("ab cd ef"), Some(5), upper);
+        assert_eq!(
+            res,
+            Err(Error::BadLength(LengthInfo::new(
+                LengthUnit::Bytes,
+                8,
+                LengthLimit::Max(5)
+            )))
+        );
+
+        // Leading, trailing and repeated spaces
+        for (s, position) in [(" ab", 0), ("ab ", 2), ("ab  cd", 3), (" ", 0)] {
+            let res = apply_to_userparts(Cow::from(s), None, upper);
+            assert_eq!(
+                res,
+                Err(Error::BadCodepoint(CodepointInfo::new(
+                    0x20,
+                    position,
+                    DerivedPropertyValue::SpecClassDis
+                )))
+            );
+        }
+    }
+
     #[test]
     fn test_directionality_rule() {
         let res = directionality_rule("");
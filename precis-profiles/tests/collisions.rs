@@ -0,0 +1,158 @@
+#[cfg(test)]
+mod collisions {
+    use precis_core::{CodepointInfo, DerivedPropertyValue, Error};
+    use precis_profiles::collisions::{CollisionDetector, EquivalenceGroup, Member, Reject};
+    use precis_profiles::{Nickname, UsernameCaseMapped};
+
+    fn member(index: u64, input: &str) -> Member {
+        Member {
+            index,
+            input: String::from(input),
+        }
+    }
+
+    fn detect(memory_limit: usize) -> (Vec<EquivalenceGroup>, Vec<Reject>) {
+        let inputs = [
+            "Juliet",
+            "romeo",
+            "JULIET",
+            "",
+            "\u{ff32}omeo",
+            "tybalt",
+            "juliet",
+            "mercutio\u{00a0}",
+        ];
+        let detector =
+            CollisionDetector::new(UsernameCaseMapped::new()).with_memory_limit(memory_limit);
+        let report = detector.detect(inputs).unwrap();
+        assert_eq!(report.inputs(), 8);
+        assert_eq!(report.rejected(), 2);
+
+        let groups = report.groups().unwrap().collect::<Result<_, _>>().unwrap();
+        let rejects = report.rejects().unwrap().collect::<Result<_, _>>().unwrap();
+        (groups, rejects)
+    }
+
+    fn check(groups: Vec<EquivalenceGroup>, rejects: Vec<Reject>) {
+        assert_eq!(
+            groups,
+            vec![
+                EquivalenceGroup {
+                    key: String::from("juliet"),
+                    members: vec![
+                        member(0, "Juliet"),
+                        member(2, "JULIET"),
+                        member(6, "juliet")
+                    ],
+                    size: 3,
+                },
+                EquivalenceGroup {
+                    key: String::from("romeo"),
+                    members: vec![member(1, "romeo"), member(4, "\u{ff32}omeo")],
+                    size: 2,
+                },
+            ]
+        );
+
+        assert_eq!(rejects.len(), 2);
+        assert_eq!(rejects[0].index, 3);
+        assert_eq!(rejects[0].input, "");
+        assert_eq!(rejects[0].error, Error::Invalid);
+        assert_eq!(rejects[1].index, 7);
+        assert_eq!(
+            rejects[1].error,
+            Error::BadCodepoint(CodepointInfo::new(
+                0x00a0,
+                8,
+                DerivedPropertyValue::SpecClassDis
+            ))
+        );
+    }
+
+    #[test]
+    fn in_memory() {
+        let (groups, rejects) = detect(usize::MAX);
+        check(groups, rejects);
+    }
+
+    #[test]
+    fn spilled() {
+        // Every input is written to disk
+        let (groups, rejects) = detect(0);
+        check(groups, rejects);
+
+        // Some inputs are written to disk and some are kept in memory
+        let (groups, rejects) = detect(150);
+        check(groups, rejects);
+    }
+
+    #[test]
+    fn no_collisions() {
+        let detector = CollisionDetector::new(Nickname::new());
+        let report = detector.detect(Vec::<String>::new()).unwrap();
+        assert_eq!(report.inputs(), 0);
+        assert_eq!(report.groups().unwrap().count(), 0);
+        assert_eq!(report.rejects().unwrap().count(), 0);
+
+        let report = detector.detect(["Guybrush", "guybrush"]).unwrap();
+        assert_eq!(report.groups().unwrap().count(), 0);
+    }
+
+    #[test]
+    fn large_input() {
+        // Usernames that differ only in case collide by pairs
+        let inputs = (0..5000).flat_map(|i| [format!("user{}", i), format!("USER{}", i)]);
+        let detector = CollisionDetector::new(UsernameCaseMapped::new()).with_memory_limit(4096);
+        let report = detector.detect(inputs).unwrap();
+        assert_eq!(report.inputs(), 10000);
+
+        let mut count = 0;
+        let mut last = String::new();
+        for group in report.groups().unwrap() {
+            let group = group.unwrap();
+            assert!(group.key > last);
+            assert_eq!(group.members.len(), 2);
+            assert_eq!(group.size, 2);
+            assert_eq!(group.members[0].index + 1, group.members[1].index);
+            last = group.key;
+            count += 1;
+        }
+        assert_eq!(count, 5000);
+    }
+
+    #[test]
+    fn max_members() {
+        // A single large group keeps only the first members
+        let inputs = (0..1000).map(|i| if i % 2 == 0 { "juliet" } else { "JULIET" });
+        for memory_limit in [usize::MAX, 256] {
+            let detector = CollisionDetector::new(UsernameCaseMapped::new())
+                .with_memory_limit(memory_limit)
+                .with_max_members(3);
+            let report = detector.detect(inputs.clone()).unwrap();
+            let groups = report
+                .groups()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(
+                groups,
+                vec![EquivalenceGroup {
+                    key: String::from("juliet"),
+                    members: vec![
+                        member(0, "juliet"),
+                        member(1, "JULIET"),
+                        member(2, "juliet")
+                    ],
+                    size: 1000,
+                }]
+            );
+        }
+
+        // Two members are always kept
+        let detector = CollisionDetector::new(UsernameCaseMapped::new()).with_max_members(0);
+        let report = detector.detect(["romeo", "ROMEO", "Romeo"]).unwrap();
+        let group = report.groups().unwrap().next().unwrap().unwrap();
+        assert_eq!(group.members, vec![member(0, "romeo"), member(1, "ROMEO")]);
+        assert_eq!(group.size, 3);
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod confusables {
+    use precis_core::profile::Profile;
+    use precis_core::Error;
+    use precis_profiles::confusables::{skeleton, Confusables};
+    use precis_profiles::{Nickname, UsernameCaseMapped, UsernameCasePreserved};
+
+    #[test]
+    fn usernames() {
+        let profile = UsernameCaseMapped::new();
+
+        // Different after enforcement, but confusable
+        assert_eq!(profile.compare("paypal", "p\u{0430}ypal"), Ok(false));
+        assert_eq!(profile.confusable_with("paypal", "p\u{0430}ypal"), Ok(true));
+
+        // Case is mapped before computing skeletons
+        assert_eq!(profile.confusable_with("PAYPAL", "p\u{0430}ypal"), Ok(true));
+        assert_eq!(profile.confusable_with("paypal", "paypa1"), Ok(true));
+        assert_eq!(profile.confusable_with("paypal", "paypol"), Ok(false));
+
+        let profile = UsernameCasePreserved::new();
+        assert_eq!(profile.confusable_with("Juliet", "Juli\u{0435}t"), Ok(true));
+        assert_eq!(profile.confusable_with("Juliet", "Romeo"), Ok(false));
+    }
+
+    #[test]
+    fn nicknames() {
+        let profile = Nickname::new();
+        assert_eq!(
+            profile.confusable_with(
+                "  Guybrush   Threepwood ",
+                "Guybrush Thr\u{0435}\u{0435}pwood"
+            ),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn skeletons() {
+        let profile = UsernameCaseMapped::new();
+        assert_eq!(
+            Confusables::skeleton(&profile, "P\u{0430}yPal"),
+            Ok(skeleton("paypal"))
+        );
+        assert_eq!(Confusables::skeleton(&profile, ""), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn enforcement_errors() {
+        let profile = UsernameCaseMapped::new();
+        assert_eq!(profile.confusable_with("", "paypal"), Err(Error::Invalid));
+        assert!(profile.confusable_with("paypal", "pay pal").is_err());
+    }
+}
@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod usernames {
+    use precis_core::profile::Profile;
+    use precis_core::{CodepointInfo, DerivedPropertyValue, Error};
+    use precis_profiles::legacy;
+    use std::borrow::Cow;
+
+    #[test]
+    fn case_mapped() {
+        let profile = legacy::UsernameCaseMapped::new();
+        assert_eq!(profile.enforce("Juliet"), Ok(Cow::from("juliet")));
+        // Unicode Default Case Folding
+        assert_eq!(profile.enforce("Stra\u{00df}e"), Ok(Cow::from("strasse")));
+        assert_eq!(
+            profile.enforce("\u{039f}\u{03a3}"),
+            Ok(Cow::from("\u{03bf}\u{03c3}"))
+        );
+        assert_eq!(
+            profile.compare("\u{03bf}\u{03c2}", "\u{039f}\u{03a3}"),
+            Ok(true)
+        );
+        assert_eq!(profile.enforce(""), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn width_mapping() {
+        // Full width characters are rejected before the width mapping is applied
+        let res = legacy::UsernameCaseMapped::new().enforce("\u{ff2a}uliet");
+        assert_eq!(
+            res,
+            Err(Error::BadCodepoint(CodepointInfo::new(
+                0xff2a,
+                0,
+                DerivedPropertyValue::SpecClassDis
+            )))
+        );
+
+        let res = legacy::UsernameCasePreserved::new().enforce("\u{ff2a}uliet");
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn case_preserved() {
+        let profile = legacy::UsernameCasePreserved::new();
+        assert_eq!(profile.enforce("Juliet"), Ok(Cow::from("Juliet")));
+        assert_eq!(profile.compare("Juliet", "juliet"), Ok(false));
+        assert_eq!(
+            profile.enforce("\u{0627}\u{0031}\u{0628}"),
+            Ok(Cow::from("\u{0627}\u{0031}\u{0628}"))
+        );
+        assert_eq!(profile.enforce("\u{0627}a"), Err(Error::Invalid));
+    }
+}
+
+#[cfg(test)]
+mod passwords {
+    use precis_core::profile::Profile;
+    use precis_profiles::legacy;
+    use std::borrow::Cow;
+
+    #[test]
+    fn opaque_string() {
+        let profile = legacy::OpaqueString::new();
+        assert_eq!(
+            profile.enforce("Pass\u{2000}Word"),
+            Ok(Cow::from("Pass Word"))
+        );
+        assert_eq!(profile.enforce("\u{212b}"), Ok(Cow::from("\u{00c5}")));
+        assert_eq!(profile.compare("Secret", "secret"), Ok(false));
+    }
+}
+
+#[cfg(test)]
+mod nicknames {
+    use precis_core::profile::Profile;
+    use precis_profiles::legacy;
+    use std::borrow::Cow;
+
+    #[test]
+    fn nickname() {
+        let profile = legacy::Nickname::new();
+        assert_eq!(
+            profile.enforce("  Foo\u{00a0}\u{00a0}Bar  "),
+            Ok(Cow::from("foo bar"))
+        );
+        assert_eq!(profile.enforce("\u{2163}"), Ok(Cow::from("iv")));
+        assert_eq!(profile.compare("Foo Bar", "foo  bar"), Ok(true));
+        assert!(profile.enforce("   ").is_err());
+    }
+}
+
+#[cfg(test)]
+mod differences {
+    use precis_profiles::legacy::{self, Difference};
+    use precis_profiles::{Nickname, OpaqueString, UsernameCaseMapped};
+    use std::borrow::Cow;
+
+    #[test]
+    fn usernames() {
+        let inputs = ["juliet", "Stra\u{00df}e", "\u{ff2a}uliet", "", "a b"];
+        let diffs = legacy::differences(
+            &legacy::UsernameCaseMapped::new(),
+            &UsernameCaseMapped::new(),
+            inputs,
+        );
+
+        assert_eq!(diffs.len(), 2);
+        assert_eq!(
+            diffs[0],
+            Difference {
+                input: "Stra\u{00df}e",
+                legacy: Ok(Cow::from("strasse")),
+                current: Ok(Cow::from("stra\u{00df}e")),
+            }
+        );
+        assert_eq!(diffs[1].input, "\u{ff2a}uliet");
+        assert!(diffs[1].legacy.is_err());
+        assert_eq!(diffs[1].current, Ok(Cow::from("juliet")));
+    }
+
+    #[test]
+    fn passwords() {
+        let inputs = ["Secret", "Pass\u{2000}Word", "\u{212b}", ""];
+        let diffs = legacy::differences(&legacy::OpaqueString::new(), &OpaqueString::new(), inputs);
+        assert!(diffs.is_empty());
+    }
+
+    #[test]
+    fn nicknames() {
+        let inputs = ["foo", "Foo", "\u{03a3}", ""];
+        let diffs = legacy::differences(&legacy::Nickname::new(), &Nickname::new(), inputs);
+
+        assert_eq!(diffs.len(), 2);
+        assert_eq!(diffs[0].input, "Foo");
+        assert_eq!(diffs[1].input, "\u{03a3}");
+        assert_eq!(diffs[1].legacy, Ok(Cow::from("\u{03c3}")));
+        assert_eq!(diffs[1].current, Ok(Cow::from("\u{03a3}")));
+    }
+}
@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod limits {
+    use precis_core::profile::Profile;
+    use precis_core::{Error, LengthInfo, LengthLimit, LengthUnit};
+    use precis_profiles::{LengthLimits, Limited, Nickname, OpaqueString, UsernameCaseMapped};
+    use std::borrow::Cow;
+
+    fn length_error(unit: LengthUnit, length: usize, limit: LengthLimit) -> Error {
+        Error::BadLength(LengthInfo::new(unit, length, limit))
+    }
+
+    #[test]
+    fn width_mapping_shrinks() {
+        // Full width characters take three bytes in UTF-8, but they are
+        // mapped to their narrow ASCII equivalents
+        let limits = LengthLimits::new(LengthUnit::Bytes).max(6);
+        let profile = Limited::new(UsernameCaseMapped::new(), limits);
+
+        assert_eq!(
+            profile.enforce("\u{ff2a}\u{ff35}\u{ff2c}\u{ff29}\u{ff25}\u{ff34}"),
+            Ok(Cow::from("juliet"))
+        );
+        assert_eq!(
+            profile.enforce("romeo\u{00e9}"),
+            Err(length_error(LengthUnit::Bytes, 7, LengthLimit::Max(6)))
+        );
+    }
+
+    #[test]
+    fn normalization_grows() {
+        // U+FDFA expands to 18 code points with NFKC
+        let limits = LengthLimits::new(LengthUnit::Codepoints).max(16);
+        let profile = Limited::new(Nickname::new(), limits);
+
+        assert_eq!(
+            profile.enforce("\u{fdfa}"),
+            Err(length_error(
+                LengthUnit::Codepoints,
+                18,
+                LengthLimit::Max(16)
+            ))
+        );
+        assert_eq!(profile.prepare("\u{fdfa}"), Ok(Cow::from("\u{fdfa}")));
+    }
+
+    #[test]
+    fn min_length() {
+        let limits = LengthLimits::new(LengthUnit::Graphemes).min(3);
+        let profile = Limited::new(Nickname::new(), limits);
+
+        // Trimmed spaces do not count
+        assert_eq!(
+            profile.enforce("  ab  "),
+            Err(length_error(LengthUnit::Graphemes, 2, LengthLimit::Min(3)))
+        );
+        // Combining marks are part of the same grapheme cluster
+        assert_eq!(
+            profile.enforce("a\u{0301}b\u{0301}"),
+            Err(length_error(LengthUnit::Graphemes, 2, LengthLimit::Min(3)))
+        );
+        assert_eq!(profile.enforce("abc"), Ok(Cow::from("abc")));
+    }
+
+    #[test]
+    fn profile_errors_first() {
+        let limits = LengthLimits::new(LengthUnit::Bytes).min(4);
+        let profile = Limited::new(OpaqueString::new(), limits);
+        assert_eq!(profile.enforce(""), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn compare() {
+        let limits = LengthLimits::new(LengthUnit::Codepoints).max(5);
+        let profile = Limited::new(Nickname::new(), limits);
+
+        // Nickname comparison applies case mapping
+        assert_eq!(profile.compare("Foo", "foo"), Ok(true));
+        assert_eq!(profile.compare("Foo", "bar"), Ok(false));
+        assert_eq!(
+            profile.compare("Foo", "Foobar"),
+            Err(length_error(LengthUnit::Codepoints, 6, LengthLimit::Max(5)))
+        );
+    }
+}
+
+#[cfg(test)]
+mod size_limits {
+    use precis_core::profile::Profile;
+    use precis_core::{Error, LengthInfo, LengthLimit, LengthUnit};
+    use precis_profiles::{
+        Nickname, OpaqueString, SizeLimits, UsernameCaseMapped, UsernameCasePreserved,
+    };
+    use std::borrow::Cow;
+
+    fn is_size_error<T>(res: Result<T, Error>, max: usize) -> bool {
+        matches!(res, Err(Error::BadLength(LengthInfo {
+                unit: LengthUnit::Bytes,
+                length,
+                limit: LengthLimit::Max(limit),
+            })) if limit == max && length > max)
+    }
+
+    #[test]
+    fn input_limit() {
+        let limits = SizeLimits::new().max_input(8);
+
+        let profile = Nickname::new().with_size_limits(limits);
+        assert_eq!(profile.enforce("Guybrush"), Ok(Cow::from("Guybrush")));
+        assert_eq!(
+            profile.enforce("Guybrush "),
+            Err(Error::BadLength(LengthInfo::new(
+                LengthUnit::Bytes,
+                9,
+                LengthLimit::Max(8)
+            )))
+        );
+        assert!(is_size_error(profile.prepare("Guybrush "), 8));
+        assert!(matches!(
+            profile.compare("Guybrush", "Threepwood"),
+            Err(Error::BadLength(_))
+        ));
+
+        let profile = OpaqueString::new().with_size_limits(limits);
+        assert!(is_size_error(profile.enforce("123456789"), 8));
+
+        let profile = UsernameCaseMapped::new().with_size_limits(limits);
+        assert!(is_size_error(profile.enforce("123456789"), 8));
+
+        // Input limit applies to the whole username, not to each userpart
+        let profile = UsernameCasePreserved::with_userparts().with_size_limits(limits);
+        assert!(is_size_error(profile.enforce("John Smith"), 8));
+    }
+
+    #[test]
+    fn nfkc_expansion() {
+        // U+FDFA takes three bytes but expands to 33 bytes with NFKC
+        let input = "\u{fdfa}".repeat(1024);
+        let limits = SizeLimits::new().max_input(4096).max_output(256);
+        let profile = Nickname::new().with_size_limits(limits);
+
+        assert!(is_size_error(profile.enforce(input.as_str()), 256));
+        assert!(is_size_error(profile.compare(input.as_str(), "a"), 256));
+
+        // Preparation does not normalize strings
+        assert!(profile.prepare(input.as_str()).is_ok());
+
+        // Without limits the string grows
+        let res = Nickname::new().enforce(input.as_str()).unwrap();
+        assert!(res.len() > 32 * 1024);
+    }
+
+    #[test]
+    fn case_mapping_expansion() {
+        // U+0130 takes two bytes and is lower cased to three bytes
+        let input = "\u{0130}".repeat(4);
+        let limits = SizeLimits::new().max_output(10);
+
+        let profile = UsernameCaseMapped::new().with_size_limits(limits);
+        assert!(is_size_error(profile.enforce(input.as_str()), 10));
+        assert_eq!(
+            UsernameCaseMapped::new().enforce(input.as_str()),
+            Ok(Cow::from("i\u{0307}".repeat(4)))
+        );
+
+        // Output limit applies to the whole username, not to each userpart
+        let input = ["\u{0130}\u{0130}"; 3].join(" ");
+        let profile = UsernameCaseMapped::with_userparts().with_size_limits(limits);
+        assert!(is_size_error(profile.enforce(input.as_str()), 10));
+        assert!(profile.enforce("\u{0130}\u{0130} a").is_ok());
+    }
+
+    #[test]
+    fn no_limits() {
+        let profile = Nickname::new().with_size_limits(SizeLimits::new());
+        assert_eq!(profile, Nickname::new());
+        assert_eq!(profile.enforce("\u{fdfa}").map(|s| s.len()), Ok(33));
+    }
+}
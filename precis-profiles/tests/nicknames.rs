@@ -311,3 +311,123 @@ fn test_single_character_with_context_rules() {
         "Middle dot alone should fail - requires l·l context"
     );
 }
+
+#[cfg(test)]
+mod emoji_zwj_sequences {
+    use precis_core::profile::Profile;
+    use precis_core::{CodepointInfo, DerivedPropertyValue, Error};
+    use precis_profiles::Nickname;
+    use std::borrow::Cow;
+
+    fn zwj_error(position: usize) -> Error {
+        Error::BadCodepoint(CodepointInfo::new(
+            0x200d,
+            position,
+            DerivedPropertyValue::ContextJ,
+        ))
+    }
+
+    #[test]
+    fn accepted() {
+        let profile = Nickname::with_emoji_zwj_sequences();
+
+        // Woman technologist
+        let res = profile.enforce("\u{1f469}\u{200d}\u{1f4bb}");
+        assert_eq!(res, Ok(Cow::from("\u{1f469}\u{200d}\u{1f4bb}")));
+
+        // Family: man, woman, girl
+        let res = profile.enforce("  Foo \u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}  ");
+        assert_eq!(
+            res,
+            Ok(Cow::from("Foo \u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}"))
+        );
+
+        // Heart on fire, the heart has an emoji presentation selector
+        let res = profile.prepare("\u{2764}\u{fe0f}\u{200d}\u{1f525}");
+        assert_eq!(res, Ok(Cow::from("\u{2764}\u{fe0f}\u{200d}\u{1f525}")));
+
+        let res = profile.compare(
+            "FOO \u{1f469}\u{200d}\u{1f4bb}",
+            "foo \u{1f469}\u{200d}\u{1f4bb}",
+        );
+        assert_eq!(res, Ok(true));
+    }
+
+    #[test]
+    fn other_groups() {
+        let profile = Nickname::with_emoji_zwj_sequences();
+
+        for sequence in [
+            // Polar bear
+            "\u{1f43b}\u{200d}\u{2744}\u{fe0f}",
+            // Black cat
+            "\u{1f408}\u{200d}\u{2b1b}",
+            // Phoenix
+            "\u{1f426}\u{200d}\u{1f525}",
+            // Face exhaling
+            "\u{1f62e}\u{200d}\u{1f4a8}",
+            // Broken chain
+            "\u{26d3}\u{fe0f}\u{200d}\u{1f4a5}",
+            // Woman gesturing NO
+            "\u{1f645}\u{200d}\u{2640}\u{fe0f}",
+            // Man running facing right
+            "\u{1f3c3}\u{200d}\u{2642}\u{fe0f}\u{200d}\u{27a1}\u{fe0f}",
+        ] {
+            let res = profile.enforce(sequence);
+            assert_eq!(res, Ok(Cow::from(sequence)), "{:x?}", sequence);
+        }
+    }
+
+    #[test]
+    fn rejected() {
+        let profile = Nickname::with_emoji_zwj_sequences();
+
+        // Leading or trailing joiners have no context to be checked
+        assert!(profile.enforce("\u{200d}\u{1f469}").is_err());
+        assert!(profile.enforce("\u{1f469}\u{200d}").is_err());
+        assert_eq!(profile.enforce("Foo\u{200d}Bar"), Err(zwj_error(3)));
+        assert_eq!(
+            profile.enforce("\u{1f469}\u{200d}\u{200d}\u{1f4bb}"),
+            Err(zwj_error(1))
+        );
+        assert_eq!(
+            profile.enforce("\u{1f469}\u{200d}\u{1f4bb}\u{200d}a"),
+            Err(zwj_error(3))
+        );
+
+        // Presentation selectors are only allowed inside sequences
+        assert!(profile.enforce("\u{2764}\u{fe0f}").is_err());
+    }
+
+    #[test]
+    fn not_rgi() {
+        let profile = Nickname::with_emoji_zwj_sequences();
+
+        // Pizza and rocket joined by ZWJ
+        assert_eq!(
+            profile.enforce("\u{1f355}\u{200d}\u{1f680}"),
+            Err(zwj_error(1))
+        );
+        // Woman technologist joined to a rocket
+        assert_eq!(
+            profile.enforce("Foo \u{1f469}\u{200d}\u{1f4bb}\u{200d}\u{1f680}"),
+            Err(zwj_error(7))
+        );
+        // Pizza joined to a woman technologist
+        assert_eq!(
+            profile.enforce("\u{1f355}\u{200d}\u{1f469}\u{200d}\u{1f4bb}"),
+            Err(zwj_error(1))
+        );
+        // Polar bear without the emoji presentation selector
+        assert_eq!(
+            profile.enforce("\u{1f43b}\u{200d}\u{2744}"),
+            Err(zwj_error(1))
+        );
+    }
+
+    #[test]
+    fn default_profile() {
+        let res = Nickname::new().enforce("\u{1f469}\u{200d}\u{1f4bb}");
+        assert_eq!(res, Err(zwj_error(1)));
+    }
+}
@@ -0,0 +1,231 @@
+#[cfg(test)]
+mod plain {
+    use precis_core::{CodepointInfo, DerivedPropertyValue, Error};
+    use precis_core::{LengthInfo, LengthLimit, LengthUnit};
+    use precis_profiles::sasl::PlainMessage;
+    use std::borrow::Cow;
+
+    #[test]
+    fn rfc4616_examples() {
+        // Section 4. Examples
+        let msg = PlainMessage::parse(b"\0tim\0tanstaaftanstaaf").unwrap();
+        assert_eq!(msg.authzid, None);
+        assert_eq!(msg.authcid, Cow::from("tim"));
+        assert_eq!(msg.passwd, Cow::from("tanstaaftanstaaf"));
+
+        let msg = PlainMessage::parse(b"Ursel\0Kurt\0xipj3plmq").unwrap();
+        assert_eq!(msg.authzid, Some(Cow::from("ursel")));
+        assert_eq!(msg.authcid, Cow::from("kurt"));
+        assert_eq!(msg.passwd, Cow::from("xipj3plmq"));
+    }
+
+    #[test]
+    fn enforce_fields() {
+        // Identities are case mapped and width mapped
+        let msg = PlainMessage::parse("\0Ｊｕｌｉｅｔ\0Pass\u{00a0}Word".as_bytes()).unwrap();
+        assert_eq!(msg.authcid, Cow::from("juliet"));
+        // Non-ASCII spaces in passwords are mapped to SPACE
+        assert_eq!(msg.passwd, Cow::from("Pass Word"));
+
+        // Spaces are not allowed in identities
+        assert_eq!(
+            PlainMessage::parse(b"\0tim smith\0secret"),
+            Err(Error::BadCodepoint(CodepointInfo::new(
+                0x0020,
+                3,
+                DerivedPropertyValue::SpecClassDis
+            )))
+        );
+
+        // Control characters are not allowed in passwords
+        assert_eq!(
+            PlainMessage::parse(b"\0tim\0sec\x07ret"),
+            Err(Error::BadCodepoint(CodepointInfo::new(
+                0x0007,
+                3,
+                DerivedPropertyValue::Disallowed
+            )))
+        );
+    }
+
+    #[test]
+    fn malformed_messages() {
+        assert_eq!(PlainMessage::parse(b""), Err(Error::Invalid));
+        assert_eq!(PlainMessage::parse(b"tim"), Err(Error::Invalid));
+        assert_eq!(PlainMessage::parse(b"\0tim"), Err(Error::Invalid));
+        assert_eq!(PlainMessage::parse(b"\0\0secret"), Err(Error::Invalid));
+        assert_eq!(PlainMessage::parse(b"\0tim\0"), Err(Error::Invalid));
+        assert_eq!(PlainMessage::parse(b"a\0tim\0pw\0"), Err(Error::Invalid));
+        assert_eq!(PlainMessage::parse(b"\0tim\0\xff\xfe"), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn field_length() {
+        // RFC 4616: 255 octets is the minimum length servers must accept,
+        // not a maximum
+        let mut msg = vec![0u8];
+        msg.extend_from_slice(&[b'a'; 300]);
+        msg.extend_from_slice(b"\0secret");
+        let plain = PlainMessage::parse(&msg).unwrap();
+        assert_eq!(plain.authcid.len(), 300);
+
+        assert_eq!(
+            PlainMessage::parse_with_max_field_len(&msg, 255),
+            Err(Error::BadLength(LengthInfo::new(
+                LengthUnit::Bytes,
+                300,
+                LengthLimit::Max(255)
+            )))
+        );
+        assert_eq!(PlainMessage::parse_with_max_field_len(&msg, 300), Ok(plain));
+
+        // Every field is checked
+        assert!(PlainMessage::parse_with_max_field_len(b"ursel\0kurt\0xipj3plmq", 9).is_ok());
+        assert!(PlainMessage::parse_with_max_field_len(b"ursel\0kurt\0xipj3plmq", 8).is_err());
+        assert!(PlainMessage::parse_with_max_field_len(b"ursel\0kurt\0xipj", 4).is_err());
+        assert!(PlainMessage::parse_with_max_field_len(b"\0tim\0secret", 5).is_err());
+    }
+
+    #[test]
+    fn to_bytes() {
+        let msg = PlainMessage::parse(b"Ursel\0Kurt\0xipj3plmq").unwrap();
+        assert_eq!(msg.to_bytes(), b"ursel\0kurt\0xipj3plmq");
+
+        let msg = PlainMessage::parse(b"\0tim\0tanstaaftanstaaf").unwrap();
+        assert_eq!(msg.to_bytes(), b"\0tim\0tanstaaftanstaaf");
+    }
+
+    #[test]
+    fn round_trip() {
+        let long = format!("\0{}\0{}", "a".repeat(512), "b".repeat(512));
+        let messages: [&[u8]; 6] = [
+            b"\0tim\0tanstaaftanstaaf",
+            b"Ursel\0Kurt\0xipj3plmq",
+            "\0Ｊｕｌｉｅｔ\0Pass\u{00a0}Word".as_bytes(),
+            "\0\u{03b1}\u{03b2}\0\u{2163} \u{00e9}".as_bytes(),
+            b"admin\0tim\0pass word",
+            long.as_bytes(),
+        ];
+        for bytes in messages {
+            let msg = PlainMessage::parse(bytes).unwrap();
+            let encoded = msg.to_bytes();
+            // Enforced fields are stable
+            let parsed = PlainMessage::parse(&encoded).unwrap();
+            assert_eq!(parsed, msg);
+            assert_eq!(parsed.to_bytes(), encoded);
+        }
+    }
+}
+
+#[cfg(test)]
+mod scram {
+    use precis_core::Error;
+    use precis_profiles::sasl::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn rfc7677_client_first_message() {
+        // Section 3. SCRAM-SHA-256 test vector
+        let msg = ScramClientFirst::parse("n,,n=user,r=rOprNGfwEbeRWgbNEkqO").unwrap();
+        assert_eq!(msg.channel_binding, ChannelBinding::NotSupported);
+        assert_eq!(msg.authzid, None);
+        assert_eq!(msg.username, Cow::from("user"));
+        assert_eq!(msg.nonce, "rOprNGfwEbeRWgbNEkqO");
+        assert_eq!(msg.bare, "n=user,r=rOprNGfwEbeRWgbNEkqO");
+
+        // The password of the test vector is already in enforced form
+        assert_eq!(prepare_password("pencil"), Ok(Cow::from("pencil")));
+    }
+
+    #[test]
+    fn rfc5802_client_first_message() {
+        // Section 5. SCRAM-SHA-1 example
+        let msg = ScramClientFirst::parse("n,,n=user,r=fyko+d2lbbFgONRv9qkxdawL").unwrap();
+        assert_eq!(msg.channel_binding, ChannelBinding::NotSupported);
+        assert_eq!(msg.authzid, None);
+        assert_eq!(msg.username, Cow::from("user"));
+        assert_eq!(msg.nonce, "fyko+d2lbbFgONRv9qkxdawL");
+        assert_eq!(msg.bare, "n=user,r=fyko+d2lbbFgONRv9qkxdawL");
+
+        // The password of the example is already in enforced form
+        assert_eq!(prepare_password("pencil"), Ok(Cow::from("pencil")));
+    }
+
+    #[test]
+    fn saslname_escapes() {
+        assert_eq!(escape_saslname("a,b"), Cow::from("a=2Cb"));
+        assert_eq!(escape_saslname("a=b"), Cow::from("a=3Db"));
+        assert_eq!(unescape_saslname("a=2Cb"), Ok(Cow::from("a,b")));
+        assert_eq!(unescape_saslname("a=3Db"), Ok(Cow::from("a=b")));
+        assert_eq!(unescape_saslname("=2C=3D"), Ok(Cow::from(",=")));
+
+        // '=' must be followed by "2C" or "3D"
+        for bad in ["a=b", "a=2c", "a=3d", "a=2", "a=", "=", "a==3D", "a=2Xb"] {
+            assert_eq!(unescape_saslname(bad), Err(Error::Invalid), "{}", bad);
+        }
+
+        let msg = ScramClientFirst::parse("n,a=ops=2Cadmin,n=a=3Db,r=abc").unwrap();
+        assert_eq!(msg.authzid, Some(Cow::from("ops,admin")));
+        assert_eq!(msg.username, Cow::from("a=b"));
+        assert_eq!(
+            ScramClientFirst::parse("n,,n=a=2Xb,r=abc"),
+            Err(Error::Invalid)
+        );
+    }
+
+    #[test]
+    fn client_first_message() {
+        let msg = ScramClientFirst::parse("p=tls-exporter,a=Admin=2Cops,n=Juliet=3D,r=abc,ext=1")
+            .unwrap();
+        assert_eq!(
+            msg.channel_binding,
+            ChannelBinding::Required("tls-exporter")
+        );
+        assert_eq!(msg.authzid, Some(Cow::from("admin,ops")));
+        assert_eq!(msg.username, Cow::from("juliet="));
+        assert_eq!(msg.nonce, "abc");
+        assert_eq!(msg.bare, "n=Juliet=3D,r=abc,ext=1");
+
+        let msg = ScramClientFirst::parse("y,,n=user,r=abc").unwrap();
+        assert_eq!(msg.channel_binding, ChannelBinding::NotUsed);
+    }
+
+    #[test]
+    fn malformed_client_first_message() {
+        let invalid = [
+            "",
+            "n,,",
+            "x,,n=user,r=abc",
+            "p=,,n=user,r=abc",
+            "n,user,n=user,r=abc",
+            "n,,m=ext,n=user,r=abc",
+            "n,,user,r=abc",
+            "n,,n=user",
+            "n,,n=user,r=",
+            "n,,n=us=er,r=abc",
+            "n,,n=,r=abc",
+        ];
+        for msg in invalid {
+            assert_eq!(ScramClientFirst::parse(msg), Err(Error::Invalid), "{}", msg);
+        }
+    }
+
+    #[test]
+    fn saslname_round_trip() {
+        let names = ["user", "a,b", "a=b", ",=", "ユーザー"];
+        for name in names {
+            assert_eq!(
+                unescape_saslname(&escape_saslname(name)),
+                Ok(Cow::from(name))
+            );
+        }
+    }
+
+    #[test]
+    fn prepare_saslname_enforces_username() {
+        assert_eq!(prepare_saslname("User=2C1"), Ok(Cow::from("user,1")));
+        assert_eq!(prepare_saslname("user"), Ok(Cow::from("user")));
+        assert!(prepare_saslname("foo bar").is_err());
+        assert_eq!(prepare_saslname("a=2"), Err(Error::Invalid));
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod restricted {
+    use precis_core::profile::Profile;
+    use precis_core::{CodepointInfo, DerivedPropertyValue, Error};
+    use precis_profiles::scripts::{Restricted, RestrictionLevel};
+    use precis_profiles::{Nickname, UsernameCaseMapped};
+    use std::borrow::Cow;
+
+    fn mixed_script(cp: u32, position: usize) -> Error {
+        Error::MixedScript(CodepointInfo::new(
+            cp,
+            position,
+            DerivedPropertyValue::PValid,
+        ))
+    }
+
+    #[test]
+    fn single_script() {
+        let profile = Restricted::new(UsernameCaseMapped::new(), RestrictionLevel::SingleScript);
+        assert_eq!(profile.level(), RestrictionLevel::SingleScript);
+
+        assert_eq!(profile.enforce("Juliet"), Ok(Cow::from("juliet")));
+        assert_eq!(
+            profile.enforce("\u{03b1}\u{03b2}\u{03b3}"),
+            Ok(Cow::from("\u{03b1}\u{03b2}\u{03b3}"))
+        );
+        // Japanese mixes Han, Hiragana and Katakana
+        assert_eq!(
+            profile.enforce("\u{6f22}\u{3072}\u{30ab}"),
+            Ok(Cow::from("\u{6f22}\u{3072}\u{30ab}"))
+        );
+
+        // Latin and Cyrillic, the first Cyrillic letter is reported
+        assert_eq!(
+            profile.enforce("p\u{0430}ypal"),
+            Err(mixed_script(0x0430, 1))
+        );
+        // Latin and Hiragana
+        assert_eq!(
+            profile.enforce("juliet\u{3072}"),
+            Err(mixed_script(0x3072, 6))
+        );
+
+        // Errors of the wrapped profile are returned first
+        assert_eq!(profile.enforce(""), Err(Error::Invalid));
+        assert!(profile.enforce("pay pal").is_err());
+
+        // Positions refer to the enforced string
+        let profile = Restricted::new(Nickname::new(), RestrictionLevel::SingleScript);
+        assert_eq!(
+            profile.enforce("  Pay   p\u{0430}l"),
+            Err(mixed_script(0x0430, 5))
+        );
+    }
+
+    #[test]
+    fn highly_restrictive() {
+        let profile = Restricted::new(
+            UsernameCaseMapped::new(),
+            RestrictionLevel::HighlyRestrictive,
+        );
+        assert_eq!(
+            profile.enforce("juliet\u{3072}"),
+            Ok(Cow::from("juliet\u{3072}"))
+        );
+        // Latin and Devanagari
+        assert_eq!(
+            profile.enforce("juliet\u{0915}"),
+            Err(mixed_script(0x0915, 6))
+        );
+        // Hebrew is rejected by the directionality rule of the profile
+        assert_eq!(profile.enforce("juliet\u{05d0}"), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn moderately_restrictive() {
+        let profile = Restricted::new(Nickname::new(), RestrictionLevel::ModeratelyRestrictive);
+        assert_eq!(
+            profile.enforce("Juliet \u{05d0}"),
+            Ok(Cow::from("Juliet \u{05d0}"))
+        );
+        assert_eq!(
+            profile.enforce("Juliet \u{0430}"),
+            Err(mixed_script(0x0430, 7))
+        );
+        assert_eq!(
+            profile.enforce("Juliet \u{03b1}"),
+            Err(mixed_script(0x03b1, 7))
+        );
+    }
+
+    #[test]
+    fn compare() {
+        let profile = Restricted::new(UsernameCaseMapped::new(), RestrictionLevel::SingleScript);
+        assert_eq!(profile.compare("Juliet", "juliet"), Ok(true));
+        assert_eq!(
+            profile.compare("paypal", "p\u{0430}ypal"),
+            Err(mixed_script(0x0430, 1))
+        );
+    }
+}
@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod saslprep {
+    use precis_core::{CodepointInfo, DerivedPropertyValue, Error};
+    use precis_profiles::stringprep::{Saslprep, Stringprep};
+    use std::borrow::Cow;
+
+    #[test]
+    fn rfc4013_examples() {
+        // Section 3. Examples
+        let profile = Saslprep::new();
+        // SOFT HYPHEN mapped to nothing
+        assert_eq!(profile.prepare("I\u{00ad}X"), Ok(Cow::from("IX")));
+        // no transformation
+        assert_eq!(profile.prepare("user"), Ok(Cow::from("user")));
+        // case preserved, will not match #2
+        assert_eq!(profile.prepare("USER"), Ok(Cow::from("USER")));
+        // output is NFKC, input in ISO 8859-1
+        assert_eq!(profile.prepare("\u{00aa}"), Ok(Cow::from("a")));
+        // output is NFKC, will match #1
+        assert_eq!(profile.prepare("\u{2168}"), Ok(Cow::from("IX")));
+        // Error - prohibited character
+        assert_eq!(
+            profile.prepare("\u{0007}"),
+            Err(Error::BadCodepoint(CodepointInfo::new(
+                0x0007,
+                0,
+                DerivedPropertyValue::Disallowed
+            )))
+        );
+        // Error - bidirectional check
+        assert_eq!(profile.prepare("\u{0627}\u{0031}"), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn map_non_ascii_spaces() {
+        let profile = Saslprep::new();
+        assert_eq!(profile.prepare("a\u{00a0}b"), Ok(Cow::from("a b")));
+        assert_eq!(profile.prepare("a\u{3000}b"), Ok(Cow::from("a b")));
+    }
+}
+
+#[cfg(test)]
+mod nodeprep {
+    use precis_core::{CodepointInfo, DerivedPropertyValue, Error};
+    use precis_profiles::stringprep::{Nodeprep, Stringprep};
+    use std::borrow::Cow;
+
+    #[test]
+    fn prepare() {
+        let profile = Nodeprep::new();
+        assert_eq!(profile.prepare("Juliet"), Ok(Cow::from("juliet")));
+        assert_eq!(profile.prepare("Stra\u{00df}e"), Ok(Cow::from("strasse")));
+        assert_eq!(profile.prepare("\u{2168}"), Ok(Cow::from("ix")));
+        assert_eq!(profile.prepare("\u{00c9}"), Ok(Cow::from("\u{00e9}")));
+    }
+
+    #[test]
+    fn prohibited() {
+        let profile = Nodeprep::new();
+        for (pos, c) in ['"', '&', '\'', '/', ':', '<', '>', '@', ' ']
+            .into_iter()
+            .enumerate()
+        {
+            let s = format!("{}{}", "a".repeat(pos), c);
+            assert_eq!(
+                profile.prepare(s),
+                Err(Error::BadCodepoint(CodepointInfo::new(
+                    c as u32,
+                    pos,
+                    DerivedPropertyValue::Disallowed
+                )))
+            );
+        }
+
+        assert_eq!(
+            profile.prepare("a\u{1680}b"),
+            Err(Error::BadCodepoint(CodepointInfo::new(
+                0x1680,
+                1,
+                DerivedPropertyValue::Disallowed
+            )))
+        );
+    }
+}
+
+#[cfg(test)]
+mod resourceprep {
+    use precis_core::{CodepointInfo, DerivedPropertyValue, Error};
+    use precis_profiles::stringprep::{Resourceprep, Stringprep, Unassigned};
+    use std::borrow::Cow;
+
+    #[test]
+    fn prepare() {
+        let profile = Resourceprep::new();
+        assert_eq!(profile.prepare("Home Office"), Ok(Cow::from("Home Office")));
+        assert_eq!(profile.prepare("a@b/c"), Ok(Cow::from("a@b/c")));
+        assert_eq!(profile.prepare("x\u{200b}y"), Ok(Cow::from("xy")));
+        assert!(profile.prepare("a\u{1680}b").is_err());
+    }
+
+    #[test]
+    fn unassigned() {
+        // U+0221 was unassigned in Unicode 3.2
+        assert_eq!(
+            Resourceprep::new().prepare("a\u{0221}"),
+            Err(Error::BadCodepoint(CodepointInfo::new(
+                0x0221,
+                1,
+                DerivedPropertyValue::Unassigned
+            )))
+        );
+        assert_eq!(
+            Resourceprep::with_unassigned(Unassigned::Allowed).prepare("a\u{0221}"),
+            Ok(Cow::from("a\u{0221}"))
+        );
+    }
+}
+
+#[cfg(test)]
+mod migration {
+    use precis_core::Error;
+    use precis_profiles::stringprep::{
+        migration_status, MigrationStatus, Nodeprep, Resourceprep, Saslprep,
+    };
+    use precis_profiles::{OpaqueString, UsernameCaseMapped};
+    use std::borrow::Cow;
+
+    #[test]
+    fn nodeprep_to_username() {
+        let nodeprep = Nodeprep::new();
+        let precis = UsernameCaseMapped::new();
+
+        assert_eq!(
+            migration_status(&nodeprep, &precis, "Romeo"),
+            MigrationStatus::Agree(Cow::from("romeo"))
+        );
+
+        // Nodeprep applies NFKC, PRECIS keeps compatibility characters
+        // out of identifiers
+        assert!(matches!(
+            migration_status(&nodeprep, &precis, "\u{2168}"),
+            MigrationStatus::StringprepOnly { .. }
+        ));
+
+        // Nodeprep case folds Eszett, UsernameCaseMapped does not
+        assert_eq!(
+            migration_status(&nodeprep, &precis, "Stra\u{00df}e"),
+            MigrationStatus::Differ {
+                stringprep: Cow::from("strasse"),
+                precis: Cow::from("stra\u{00df}e"),
+            }
+        );
+
+        // '@' is prohibited by Nodeprep but valid in PRECIS identifiers
+        assert!(matches!(
+            migration_status(&nodeprep, &precis, "juliet@capulet"),
+            MigrationStatus::PrecisOnly { .. }
+        ));
+
+        assert!(matches!(
+            migration_status(&nodeprep, &precis, "\u{0007}"),
+            MigrationStatus::BothRejected { .. }
+        ));
+    }
+
+    #[test]
+    fn saslprep_to_opaque_string() {
+        let saslprep = Saslprep::new();
+        let precis = OpaqueString::new();
+
+        assert_eq!(
+            migration_status(&saslprep, &precis, "correct horse"),
+            MigrationStatus::Agree(Cow::from("correct horse"))
+        );
+
+        // SASLprep applies NFKC while OpaqueString applies NFC
+        assert_eq!(
+            migration_status(&saslprep, &precis, "\u{00aa}"),
+            MigrationStatus::Differ {
+                stringprep: Cow::from("a"),
+                precis: Cow::from("\u{00aa}"),
+            }
+        );
+
+        // Empty strings are not allowed in PRECIS
+        assert_eq!(
+            migration_status(&saslprep, &precis, ""),
+            MigrationStatus::StringprepOnly {
+                stringprep: Cow::from(""),
+                precis: Error::Invalid,
+            }
+        );
+    }
+
+    #[test]
+    fn resourceprep_to_opaque_string() {
+        let resourceprep = Resourceprep::new();
+        let precis = OpaqueString::new();
+
+        assert_eq!(
+            migration_status(&resourceprep, &precis, "Balcony"),
+            MigrationStatus::Agree(Cow::from("Balcony"))
+        );
+    }
+}
@@ -432,3 +432,93 @@ mod case_preserved {
         assert_eq!(res, Ok(Cow::from("π")));
     }
 }
+
+#[cfg(test)]
+mod userparts {
+    use precis_core::profile::Profile;
+    use precis_core::{CodepointInfo, DerivedPropertyValue, Error};
+    use precis_profiles::{UsernameCaseMapped, UsernameCasePreserved};
+    use std::borrow::Cow;
+
+    fn space_error(position: usize) -> Error {
+        Error::BadCodepoint(CodepointInfo::new(
+            0x0020,
+            position,
+            DerivedPropertyValue::SpecClassDis,
+        ))
+    }
+
+    #[test]
+    fn case_mapped() {
+        let profile = UsernameCaseMapped::with_userparts();
+
+        assert_eq!(profile.prepare("John Smith"), Ok(Cow::from("John Smith")));
+        assert_eq!(profile.enforce("John Smith"), Ok(Cow::from("john smith")));
+        assert_eq!(profile.enforce("Juliet"), Ok(Cow::from("juliet")));
+        // Width mapping is applied to each userpart
+        assert_eq!(
+            profile.enforce("\u{ff2a}ohn \u{ff33}mith"),
+            Ok(Cow::from("john smith"))
+        );
+        assert_eq!(profile.compare("John Smith", "JOHN SMITH"), Ok(true));
+        assert_eq!(profile.compare("John Smith", "JohnSmith"), Ok(false));
+
+        // The default profile does not accept spaces
+        assert_eq!(
+            UsernameCaseMapped::new().enforce("John Smith"),
+            Err(space_error(4))
+        );
+    }
+
+    #[test]
+    fn case_preserved() {
+        let profile = UsernameCasePreserved::with_userparts();
+
+        assert_eq!(profile.enforce("John Smith"), Ok(Cow::from("John Smith")));
+        assert_eq!(profile.compare("John Smith", "john smith"), Ok(false));
+        assert_eq!(profile.enforce("A\u{030a} B"), Ok(Cow::from("\u{00c5} B")));
+    }
+
+    #[test]
+    fn spaces() {
+        let profile = UsernameCaseMapped::with_userparts();
+
+        assert_eq!(profile.enforce(""), Err(Error::Invalid));
+        assert_eq!(profile.enforce(" "), Err(space_error(0)));
+        assert_eq!(profile.enforce(" John"), Err(space_error(0)));
+        assert_eq!(profile.enforce("John "), Err(space_error(4)));
+        assert_eq!(profile.enforce("John  Smith"), Err(space_error(5)));
+
+        // Only SPACE separates userparts
+        assert_eq!(
+            profile.enforce("John\u{00a0}Smith"),
+            Err(Error::BadCodepoint(CodepointInfo::new(
+                0x00a0,
+                4,
+                DerivedPropertyValue::SpecClassDis
+            )))
+        );
+    }
+
+    #[test]
+    fn userpart_errors() {
+        let profile = UsernameCasePreserved::with_userparts();
+
+        // Positions are relative to the whole username
+        assert_eq!(
+            profile.enforce("John Sm\u{2163}th"),
+            Err(Error::BadCodepoint(CodepointInfo::new(
+                0x2163,
+                7,
+                DerivedPropertyValue::SpecClassDis
+            )))
+        );
+
+        // The directionality rule is applied to each userpart
+        assert_eq!(
+            profile.enforce("\u{05d0}\u{05d1} abc"),
+            Ok(Cow::from("\u{05d0}\u{05d1} abc"))
+        );
+        assert_eq!(profile.enforce("abc \u{05d0}a"), Err(Error::Invalid));
+    }
+}
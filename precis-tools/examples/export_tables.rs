@@ -0,0 +1,35 @@
+//! Exports the tables used by the PRECIS crates as `JSON` or as a `C` header,
+//! so that implementations in other languages use the same data.
+//!
+//! ```text
+//! cargo run -p precis-tools --example export_tables -- json <precis_csv> <ucd_dir> > precis_tables.json
+//! cargo run -p precis-tools --example export_tables -- c <precis_csv> <ucd_dir> > precis_tables.h
+//! ```
+
+use precis_tools::TableExporter;
+use std::path::Path;
+use std::{env, io, process};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.len() != 3 || !(args[0] == "json" || args[0] == "c") {
+        eprintln!("usage: export_tables <json|c> <precis_csv> <ucd_dir>");
+        process::exit(2);
+    }
+
+    let mut exporter = TableExporter::new();
+    let res = exporter
+        .add_derived_properties_csv(Path::new(&args[1]))
+        .and_then(|_| exporter.add_ucd_tables(Path::new(&args[2])))
+        .and_then(|_| {
+            let mut out = io::stdout().lock();
+            match args[0].as_str() {
+                "json" => exporter.write_json(&mut out),
+                _ => exporter.write_c_header(&mut out),
+            }
+        });
+    if let Err(e) = res {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,25 @@
+//! Writes the derived property values of all code points in the format
+//! of the `IANA` `precis-tables.csv` registry file.
+//!
+//! ```text
+//! cargo run -p precis-tools --example precis_tables -- <ucd_dir> > precis-tables.csv
+//! ```
+
+use precis_tools::PrecisTables;
+use std::path::Path;
+use std::{env, io, process};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.len() != 1 {
+        eprintln!("usage: precis_tables <ucd_dir>");
+        process::exit(2);
+    }
+
+    let res = PrecisTables::from_ucd_dir(Path::new(&args[0]))
+        .and_then(|tables| tables.write_csv(&mut io::stdout().lock()));
+    if let Err(e) = res {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,122 @@
+//! Prints the size of the tables of code points of every general category,
+//! script and joining type in each encoding, the encoding chosen by the
+//! generators, and the time taken to look up every code point.
+//!
+//! ```text
+//! cargo run --release -p precis-tools --example table_report -- <ucd_dir>
+//! ```
+
+use precis_tools::{CodepointTable, EncodingReport, Error, TableEncoding, Ucd};
+use std::collections::BTreeMap;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use std::{env, process};
+use ucd_parse::{Codepoint, Codepoints};
+
+const MAX_CODEPOINT: u32 = 0x10ffff;
+
+// Size of each element of the `[Codepoints; N]` arrays used before
+const CODEPOINTS_SIZE: usize = 16;
+
+fn collect_tables(ucd: &Ucd) -> Result<BTreeMap<String, Vec<Codepoints>>, Error> {
+    let mut tables: BTreeMap<String, Vec<Codepoints>> = BTreeMap::new();
+    for cp in 0..=MAX_CODEPOINT {
+        let cps = match Codepoint::from_u32(cp) {
+            Ok(cps) => Codepoints::Single(cps),
+            Err(_) => continue,
+        };
+        let names = [
+            format!("gc={}", ucd.general_category(cp)?),
+            format!("sc={}", ucd.script(cp)?),
+            format!("jt={}", ucd.joining_type(cp)?),
+        ];
+        for name in names {
+            tables.entry(name).or_default().push(cps);
+        }
+    }
+    Ok(tables)
+}
+
+fn lookup_time(table: &CodepointTable) -> Duration {
+    let now = Instant::now();
+    let mut found = 0;
+    for cp in 0..=MAX_CODEPOINT {
+        if black_box(table).contains(black_box(cp)) {
+            found += 1;
+        }
+    }
+    black_box(found);
+    now.elapsed()
+}
+
+fn print_report(tables: &BTreeMap<String, Vec<Codepoints>>) {
+    println!(
+        "{:<12} {:>8} {:>7} {:>10} {:>8} {:>8} {:>8} {:>7} {:>10} {:>10} {:>10}",
+        "table",
+        "cps",
+        "ranges",
+        "codepoints",
+        "ranges",
+        "bitset",
+        "trie",
+        "chosen",
+        "ranges ns",
+        "bitset ns",
+        "trie ns"
+    );
+
+    let mut before = 0;
+    let mut after = 0;
+    let mut times: BTreeMap<String, Duration> = BTreeMap::new();
+    for (name, cps) in tables.iter() {
+        let report = EncodingReport::new(cps);
+        let nanos: Vec<f64> = TableEncoding::ALL
+            .iter()
+            .map(|&encoding| {
+                let elapsed = lookup_time(&CodepointTable::with_encoding(cps, encoding));
+                *times.entry(encoding.to_string()).or_default() += elapsed;
+                elapsed.as_nanos() as f64 / f64::from(MAX_CODEPOINT + 1)
+            })
+            .collect();
+        before += report.ranges * CODEPOINTS_SIZE;
+        after += report.chosen_size();
+        println!(
+            "{:<12} {:>8} {:>7} {:>10} {:>8} {:>8} {:>8} {:>7} {:>10.2} {:>10.2} {:>10.2}",
+            name,
+            report.codepoints,
+            report.ranges,
+            report.ranges * CODEPOINTS_SIZE,
+            report.size(TableEncoding::Ranges),
+            report.size(TableEncoding::Bitset),
+            report.size(TableEncoding::Trie),
+            report.chosen,
+            nanos[2],
+            nanos[0],
+            nanos[1]
+        );
+    }
+
+    println!();
+    println!("tables: {}", tables.len());
+    println!("size as [Codepoints; N]: {} bytes", before);
+    println!("size in the chosen encodings: {} bytes", after);
+    for (encoding, elapsed) in times.iter() {
+        println!("total lookup time ({}): {:?}", encoding, elapsed);
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.len() != 1 {
+        eprintln!("usage: table_report <ucd_dir>");
+        process::exit(2);
+    }
+
+    match collect_tables(&Ucd::new(&args[0])) {
+        Ok(tables) => print_report(&tables),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
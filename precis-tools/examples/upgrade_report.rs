@@ -0,0 +1,32 @@
+//! Prints the impact of moving the PRECIS tables to a new version of Unicode.
+//!
+//! ```text
+//! cargo run -p precis-tools --example upgrade_report -- <old_ucd_dir> <new_ucd_dir> [--csv]
+//! ```
+
+use precis_tools::UpgradeReport;
+use std::path::Path;
+use std::{env, io, process};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let csv = args.iter().any(|arg| arg == "--csv");
+    let dirs: Vec<&String> = args.iter().filter(|arg| *arg != "--csv").collect();
+    if dirs.len() != 2 {
+        eprintln!("usage: upgrade_report <old_ucd_dir> <new_ucd_dir> [--csv]");
+        process::exit(2);
+    }
+
+    let res =
+        UpgradeReport::from_ucd_dirs(Path::new(dirs[0]), Path::new(dirs[1])).and_then(|report| {
+            let mut out = io::stdout().lock();
+            match csv {
+                true => report.write_csv(&mut out),
+                false => report.write_text(&mut out),
+            }
+        });
+    if let Err(e) = res {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
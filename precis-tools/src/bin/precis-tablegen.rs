@@ -0,0 +1,114 @@
+//! Generates the PRECIS tables from a `UCD` directory, or a `UCD.zip`
+//! archive, as Rust code, `JSON` or `CSV`, so that they can be generated
+//! ahead of time, committed and reviewed.
+//!
+//! ```text
+//! $ precis-tablegen --ucd ucd-17.0.0 --unicode-version 17.0.0 \
+//!     --tables bidi-class,space-separator --format rust -o tables.rs
+//! $ precis-tablegen --ucd ucd-6.3.0 --unicode-version 6.3.0 \
+//!     --registry precis-tables-6.3.0.csv --tables derived-property --format json
+//! ```
+
+use clap::{Parser, ValueEnum};
+use precis_tools::{Error, OutputFormat, Table, TableGen, Ucd};
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(name = "precis-tablegen", version, about)]
+struct Cli {
+    /// `UCD` directory or `UCD.zip` archive
+    #[arg(long)]
+    ucd: PathBuf,
+    /// Unicode version of the `UCD` files, such as `17.0.0`
+    #[arg(long)]
+    unicode_version: String,
+    /// `IANA` `precis-tables.csv` file to read the derived property values
+    /// from, instead of computing them from the `UCD` files
+    #[arg(long)]
+    registry: Option<PathBuf>,
+    /// Tables to generate, all of them if none is provided
+    #[arg(short, long, value_enum, value_delimiter = ',')]
+    tables: Vec<TableName>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = FormatName::Rust)]
+    format: FormatName,
+    /// File to write, stdout if none is provided. The file is left
+    /// untouched if its content does not change
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+enum TableName {
+    DerivedProperty,
+    BidiClass,
+    WidthMapping,
+    SpaceSeparator,
+    Context,
+    LowercaseMapping,
+}
+
+impl From<TableName> for Table {
+    fn from(name: TableName) -> Self {
+        match name {
+            TableName::DerivedProperty => Table::DerivedProperty,
+            TableName::BidiClass => Table::BidiClass,
+            TableName::WidthMapping => Table::WidthMapping,
+            TableName::SpaceSeparator => Table::SpaceSeparator,
+            TableName::Context => Table::Context,
+            TableName::LowercaseMapping => Table::LowercaseMapping,
+        }
+    }
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+enum FormatName {
+    Rust,
+    Json,
+    Csv,
+}
+
+impl From<FormatName> for OutputFormat {
+    fn from(name: FormatName) -> Self {
+        match name {
+            FormatName::Rust => OutputFormat::Rust,
+            FormatName::Json => OutputFormat::Json,
+            FormatName::Csv => OutputFormat::Csv,
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Error> {
+    let mut gen = TableGen::new(&Ucd::new(&cli.ucd), &cli.unicode_version);
+    if let Some(csv) = &cli.registry {
+        gen = gen.with_registry(csv);
+    }
+    match cli.tables.is_empty() {
+        true => Table::ALL.into_iter().for_each(|table| gen.add(table)),
+        false => cli.tables.into_iter().for_each(|name| gen.add(name.into())),
+    }
+
+    let mut out = Vec::new();
+    gen.write(cli.format.into(), &mut out)?;
+    let path = match cli.output {
+        Some(path) => path,
+        None => return Ok(io::stdout().write_all(&out)?),
+    };
+    if fs::read(&path).is_ok_and(|current| current == out) {
+        return Ok(());
+    }
+    fs::write(&path, out).map_err(|e| Error::from(format!("{}: {}", path.display(), e).as_str()))
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
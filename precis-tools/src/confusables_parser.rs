@@ -0,0 +1,125 @@
+use crate::Error;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use ucd_parse::Codepoint;
+
+/// A single row in the
+/// [`confusables.txt`](https://www.unicode.org/reports/tr39/#Confusable_Detection)
+/// file defined in `UTS #39`. Each row maps a code point to the prototype
+/// used to compute the skeleton of a string.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Confusable {
+    /// The source code point
+    pub codepoint: Codepoint,
+    /// The sequence of code points the source code point is confusable with
+    pub prototype: Vec<Codepoint>,
+}
+
+impl Confusable {
+    /// Parses a `confusables.txt` file.
+    /// # Arguments:
+    /// * `path` - The file path
+    /// # Returns
+    /// The rows found in the file, in the same order they are listed in it.
+    pub fn parse<P: AsRef<Path>>(path: P) -> Result<Vec<Confusable>, Error> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|e| Error {
+            mesg: format!("IO Error: {}", e),
+            line: None,
+            path: Some(path.to_path_buf()),
+        })?;
+
+        Self::parse_str(&content).map_err(|mut e| {
+            e.path = Some(path.to_path_buf());
+            e
+        })
+    }
+
+    fn parse_str(content: &str) -> Result<Vec<Confusable>, Error> {
+        let mut entries = Vec::new();
+        for (n, line) in content.lines().enumerate() {
+            // The file starts with a byte order mark
+            let line = line.trim_start_matches('\u{feff}').trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let entry = line.parse().map_err(|mut e: Error| {
+                e.line = Some(n as u64 + 1);
+                e
+            })?;
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+}
+
+impl FromStr for Confusable {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Confusable, Error> {
+        let line = line.split('#').next().unwrap_or_default();
+        let mut fields = line.split(';').map(str::trim);
+        let (Some(source), Some(prototype)) = (fields.next(), fields.next()) else {
+            return err!("invalid confusables line: '{}'", line);
+        };
+
+        let codepoint: Codepoint = source.parse()?;
+        let mut cps = Vec::new();
+        for cp in prototype.split_whitespace() {
+            cps.push(cp.parse()?);
+        }
+        if cps.is_empty() {
+            return err!("missing prototype for codepoint {:#06x}", codepoint.value());
+        }
+
+        Ok(Confusable {
+            codepoint,
+            prototype: cps,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::confusables_parser::*;
+
+    #[test]
+    fn parse_entry() {
+        let entry = Confusable::from_str(
+            "0430 ;\t0061 ;\tMA\t# ( а → a ) CYRILLIC SMALL LETTER A → LATIN SMALL LETTER A\t#",
+        )
+        .unwrap();
+        assert_eq!(entry.codepoint, Codepoint::from_u32(0x0430).unwrap());
+        assert_eq!(entry.prototype, vec![Codepoint::from_u32(0x0061).unwrap()]);
+
+        let entry = Confusable::from_str("0022 ;\t0027 0027 ;\tMA").unwrap();
+        assert_eq!(
+            entry.prototype,
+            vec![
+                Codepoint::from_u32(0x0027).unwrap(),
+                Codepoint::from_u32(0x0027).unwrap()
+            ]
+        );
+
+        assert!(Confusable::from_str("").is_err());
+        assert!(Confusable::from_str("0430").is_err());
+        assert!(Confusable::from_str("0430 ; ; MA").is_err());
+        assert!(Confusable::from_str("XYZ ; 0061 ; MA").is_err());
+    }
+
+    #[test]
+    fn parse_file() {
+        let content = "\u{feff}# confusables.txt\n\
+            \n\
+            05AD ;\t0596 ;\tMA\t# comment\n\
+            0430 ;\t0061 ;\tMA\t# comment\n";
+        let entries = Confusable::parse_str(content).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].codepoint, Codepoint::from_u32(0x0430).unwrap());
+
+        let err = Confusable::parse_str("0430 ;\t0061 ;\tMA\n0431 ;\n").unwrap_err();
+        assert_eq!(err.line(), Some(2));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::Error;
 use regex::Regex;
+use std::fmt;
 use std::fs::File;
 use std::io::{self, BufRead};
 use std::marker::PhantomData;
@@ -99,7 +100,7 @@ impl<R: io::Read, D: FromStr<Err = Error>> Iterator for CsvLineParser<R, D> {
 /// Represents the derived property value assigned
 /// to an Unicode code point. This value is parsed
 /// from the `CSV` maintained in the `IANA` registry.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum DerivedProperty {
     /// Those code points that are allowed to be used in any PRECIS string class.
     PValid,
@@ -149,6 +150,21 @@ impl FromStr for DerivedProperty {
     }
 }
 
+impl fmt::Display for DerivedProperty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            DerivedProperty::PValid => "PVALID",
+            DerivedProperty::FreePVal => "FREE_PVAL",
+            DerivedProperty::ContextJ => "CONTEXTJ",
+            DerivedProperty::ContextO => "CONTEXTO",
+            DerivedProperty::Disallowed => "DISALLOWED",
+            DerivedProperty::IdDis => "ID_DIS",
+            DerivedProperty::Unassigned => "UNASSIGNED",
+        };
+        write!(f, "{}", name)
+    }
+}
+
 fn parse_codepoint_range(s: &str) -> Result<ucd_parse::CodepointRange, Error> {
     static PARTS: LazyLock<Regex> =
         LazyLock::new(|| Regex::new(r"^(?P<start>[A-Z0-9]+)-(?P<end>[A-Z0-9]+)$").unwrap());
@@ -227,6 +243,15 @@ pub enum DerivedProperties {
     Tuple((DerivedProperty, DerivedProperty)),
 }
 
+impl fmt::Display for DerivedProperties {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DerivedProperties::Single(p) => write!(f, "{}", p),
+            DerivedProperties::Tuple((p1, p2)) => write!(f, "{} or {}", p1, p2),
+        }
+    }
+}
+
 impl FromStr for DerivedProperties {
     type Err = Error;
 
@@ -259,6 +284,24 @@ impl FromStr for PrecisDerivedProperty {
     }
 }
 
+impl fmt::Display for PrecisDerivedProperty {
+    /// Formats the row as a line of the `precis-tables.csv` file.
+    /// Descriptions containing commas are quoted.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.codepoints {
+            ucd_parse::Codepoints::Single(cp) => write!(f, "{:04X}", cp.value())?,
+            ucd_parse::Codepoints::Range(r) => {
+                write!(f, "{:04X}-{:04X}", r.start.value(), r.end.value())?
+            }
+        }
+        write!(f, ",{},", self.properties)?;
+        match self.description.contains(',') {
+            true => write!(f, "\"{}\"", self.description),
+            false => write!(f, "{}", self.description),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::csv_parser::*;
@@ -324,6 +367,18 @@ mod tests {
         );
 
         assert!(DerivedProperty::from_str("ASDFR").is_err());
+
+        for name in [
+            "PVALID",
+            "FREE_PVAL",
+            "CONTEXTJ",
+            "CONTEXTO",
+            "DISALLOWED",
+            "ID_DIS",
+            "UNASSIGNED",
+        ] {
+            assert_eq!(DerivedProperty::from_str(name).unwrap().to_string(), name);
+        }
     }
 
     #[test]
@@ -403,4 +458,26 @@ mod tests {
         assert!(PrecisDerivedProperty::from_str(",,SPACE").is_err());
         assert!(PrecisDerivedProperty::from_str("").is_err());
     }
+
+    #[test]
+    fn precis_derived_property_display() {
+        for line in [
+            "0020,ID_DIS or FREE_PVAL,SPACE",
+            "0000-001F,DISALLOWED,NULL..INFORMATION SEPARATOR ONE",
+            "10FFFE-10FFFF,DISALLOWED,<NOT A CHARACTER>..<NOT A CHARACTER>",
+        ] {
+            let row = PrecisDerivedProperty::from_str(line).unwrap();
+            assert_eq!(row.to_string(), line);
+        }
+
+        let row = PrecisDerivedProperty {
+            codepoints: codepoints!(0x4e00, 0x9fcc),
+            properties: DerivedProperties::Single(DerivedProperty::PValid),
+            description: String::from("<CJK IDEOGRAPH, FIRST>..<CJK IDEOGRAPH, LAST>"),
+        };
+        assert_eq!(
+            row.to_string(),
+            "4E00-9FCC,PVALID,\"<CJK IDEOGRAPH, FIRST>..<CJK IDEOGRAPH, LAST>\""
+        );
+    }
 }
@@ -1,39 +1,322 @@
 //! Module for downloading files from the internet.
+//!
+//! Files are fetched offline first: files already in the destination or in
+//! the cache directory are not downloaded again. The cache directory and a
+//! mirror standing in for `unicode.org` and `iana.org` can be set with the
+//! [`CACHE_DIR_ENV`] and [`MIRROR_URL_ENV`] environment variables. Files are
+//! checked against a [`Manifest`] of `SHA-256` checksums, and written
+//! atomically, so an interrupted build never leaves a truncated file behind.
 
 use crate::Error;
 use reqwest::header::USER_AGENT;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
 use std::env;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// Environment variable with the directory where downloaded files are cached.
+pub const CACHE_DIR_ENV: &str = "PRECIS_CACHE_DIR";
+
+/// Environment variable with the base `URL` of a mirror used instead of
+/// `https://www.unicode.org/Public` and `https://www.iana.org/assignments`.
+/// The mirror must serve the files with the same layout as those sites.
+pub const MIRROR_URL_ENV: &str = "PRECIS_MIRROR_URL";
+
+const UNICODE_URL: &str = "https://www.unicode.org/Public";
+const IANA_URL: &str = "https://www.iana.org/assignments";
 
 fn get_csv_file_name(ucd_version: &str) -> String {
     format!("precis-tables-{}.csv", ucd_version)
 }
 
-fn get_precis_csv_tables_uri(ucd_version: &str) -> String {
-    format!(
-        "https://www.iana.org/assignments/precis-tables-{}/{}",
-        ucd_version,
-        get_csv_file_name(ucd_version)
-    )
+fn io_error(e: std::io::Error, path: &Path) -> Error {
+    Error {
+        mesg: format!("IO Error: {}", e),
+        line: None,
+        path: Some(path.to_path_buf()),
+    }
 }
 
-fn get_unicode_ucd_uri(ucd_version: &str) -> String {
-    format!("https://www.unicode.org/Public/{}/ucd", ucd_version)
+/// Returns the `SHA-256` checksum of `data` as a lowercase hexadecimal string.
+pub fn sha256(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
 }
 
-fn download(url: &str, dest: &Path) -> Result<(), Error> {
-    let pkg_name = env!("CARGO_PKG_NAME");
+// Writes the file to a temporary file in the same directory first, so that
+// `dest` is either missing or complete
+fn write_atomic(dest: &Path, data: &[u8]) -> Result<(), Error> {
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent).map_err(|e| io_error(e, parent))?;
+    }
+    let mut tmp = dest.as_os_str().to_owned();
+    tmp.push(format!(".tmp-{}", std::process::id()));
+    let tmp = PathBuf::from(tmp);
+    fs::write(&tmp, data).map_err(|e| io_error(e, &tmp))?;
+    fs::rename(&tmp, dest).map_err(|e| {
+        let _ = fs::remove_file(&tmp);
+        io_error(e, dest)
+    })
+}
 
-    let client = reqwest::blocking::Client::new();
-    let text = client
-        .get(url)
-        .header(USER_AGENT, pkg_name)
-        .send()
-        .unwrap()
-        .text()
-        .unwrap();
-    Ok(fs::write(dest, text)?)
+/// `SHA-256` checksums of the files used to build the tables for a Unicode
+/// version. Each line holds the checksum and the path of the file, such as
+/// `ucd/UnicodeData.txt`, `security/confusables.txt` or
+/// `csv/precis-tables-6.3.0.csv`, in the format used by `sha256sum`. Empty
+/// lines and lines starting with `#` are ignored.
+#[derive(Clone, Debug, Default)]
+pub struct Manifest {
+    checksums: HashMap<String, String>,
+}
+
+impl Manifest {
+    /// Parses the content of a manifest.
+    pub fn parse(content: &str) -> Result<Self, Error> {
+        let mut checksums = HashMap::new();
+        for (n, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line.split_whitespace();
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(checksum), Some(file), None)
+                    if checksum.len() == 64 && checksum.chars().all(|c| c.is_ascii_hexdigit()) =>
+                {
+                    checksums.insert(
+                        String::from(file.trim_start_matches('*')),
+                        checksum.to_ascii_lowercase(),
+                    );
+                }
+                _ => {
+                    return Err(Error {
+                        mesg: format!("invalid manifest line: '{}'", line),
+                        line: Some(n as u64 + 1),
+                        path: None,
+                    })
+                }
+            }
+        }
+        Ok(Self { checksums })
+    }
+
+    /// Reads the manifest stored in `path`.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|e| io_error(e, path))?;
+        Self::parse(&content).map_err(|mut e| {
+            e.path = Some(path.to_path_buf());
+            e
+        })
+    }
+
+    /// Returns the checksum of `file`, if it is listed.
+    pub fn get(&self, file: &str) -> Option<&str> {
+        self.checksums.get(file).map(String::as_str)
+    }
+}
+
+/// Fetches the files used to build the tables of a Unicode version.
+/// # Example
+/// ```no_run
+/// # use precis_tools::download::{Fetcher, Manifest};
+/// # use std::path::Path;
+/// let manifest = Manifest::from_path("resources/sha256-6.3.0.txt").unwrap();
+/// let fetcher = Fetcher::from_env("6.3.0").with_manifest(manifest);
+/// fetcher.get_ucd_file(Path::new("out/ucd"), "UnicodeData.txt").unwrap();
+/// fetcher.get_csv_file(Path::new("out/csv")).unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Fetcher {
+    version: String,
+    cache_dir: Option<PathBuf>,
+    mirror: Option<String>,
+    manifest: Option<Manifest>,
+}
+
+impl Fetcher {
+    /// Creates a new fetcher for the files of `ucd_version`, without cache
+    /// directory, mirror or manifest.
+    pub fn new(ucd_version: &str) -> Self {
+        Self {
+            version: String::from(ucd_version),
+            cache_dir: None,
+            mirror: None,
+            manifest: None,
+        }
+    }
+
+    /// Creates a new fetcher for the files of `ucd_version` that takes the
+    /// cache directory and the mirror from the [`CACHE_DIR_ENV`] and
+    /// [`MIRROR_URL_ENV`] environment variables, when they are set.
+    pub fn from_env(ucd_version: &str) -> Self {
+        let mut fetcher = Self::new(ucd_version);
+        if let Some(dir) = env::var_os(CACHE_DIR_ENV).filter(|dir| !dir.is_empty()) {
+            fetcher = fetcher.with_cache_dir(dir);
+        }
+        if let Some(url) = env::var(MIRROR_URL_ENV).ok().filter(|url| !url.is_empty()) {
+            fetcher = fetcher.with_mirror(&url);
+        }
+        fetcher
+    }
+
+    /// Sets the directory where downloaded files are cached. Files are
+    /// stored in a subdirectory named after the Unicode version.
+    pub fn with_cache_dir<P: AsRef<Path>>(mut self, dir: P) -> Self {
+        self.cache_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Sets the base `URL` of the mirror files are downloaded from.
+    pub fn with_mirror(mut self, url: &str) -> Self {
+        self.mirror = Some(String::from(url.trim_end_matches('/')));
+        self
+    }
+
+    /// Sets the manifest files are checked against. Files not listed in
+    /// the manifest are rejected.
+    pub fn with_manifest(mut self, manifest: Manifest) -> Self {
+        self.manifest = Some(manifest);
+        self
+    }
+
+    fn base_url<'a>(&'a self, default: &'a str) -> &'a str {
+        self.mirror.as_deref().unwrap_or(default)
+    }
+
+    // Returns an error if `data` does not match the checksum of `file`
+    fn verify(&self, file: &str, data: &[u8]) -> Result<(), Error> {
+        let Some(manifest) = &self.manifest else {
+            return Ok(());
+        };
+        match manifest.get(file) {
+            Some(expected) if expected == sha256(data) => Ok(()),
+            Some(expected) => err!(
+                "checksum mismatch for {}: expected {}, got {}",
+                file,
+                expected,
+                sha256(data)
+            ),
+            None => err!("{} is not listed in the manifest", file),
+        }
+    }
+
+    fn download(&self, url: &str) -> Result<Vec<u8>, Error> {
+        let pkg_name = env!("CARGO_PKG_NAME");
+        let http_error = |e: reqwest::Error| Error::parse(format!("HTTP Error: {}", e));
+
+        let client = reqwest::blocking::Client::new();
+        let response = client
+            .get(url)
+            .header(USER_AGENT, pkg_name)
+            .send()
+            .and_then(|response| response.error_for_status())
+            .map_err(http_error)?;
+        Ok(response.bytes().map_err(http_error)?.to_vec())
+    }
+
+    // Fetches the file named `file` in the manifest from `url` into `dest`
+    fn fetch(&self, file: &str, url: &str, dest: &Path) -> Result<(), Error> {
+        // Files already fetched are only trusted if they can be checked
+        if self.manifest.is_some() {
+            if let Ok(data) = fs::read(dest) {
+                if self.verify(file, &data).is_ok() {
+                    return Ok(());
+                }
+            }
+        }
+
+        let cached = self
+            .cache_dir
+            .as_ref()
+            .map(|dir| dir.join(&self.version).join(file));
+        if let Some(data) = cached.as_ref().and_then(|path| fs::read(path).ok()) {
+            if self.verify(file, &data).is_ok() {
+                return write_atomic(dest, &data);
+            }
+        }
+
+        let data = self.download(url)?;
+        self.verify(file, &data).map_err(|mut e| {
+            e.mesg = format!("{} downloaded from {}", e.mesg, url);
+            e
+        })?;
+        if let Some(path) = cached {
+            write_atomic(&path, &data)?;
+        }
+        write_atomic(dest, &data)
+    }
+
+    /// Gets a ucd file, such as `UnicodeData.txt` or
+    /// `extracted/DerivedJoiningType.txt`
+    /// # Arguments
+    /// * `dest`: Destination directory
+    /// * `file`: File name
+    /// # Returns
+    /// `Ok(())` if the file was fetched successfully, `Err(Error)` otherwise
+    pub fn get_ucd_file(&self, dest: &Path, file: &str) -> Result<(), Error> {
+        let url = format!(
+            "{}/{}/ucd/{}",
+            self.base_url(UNICODE_URL),
+            self.version,
+            file
+        );
+        self.fetch(&format!("ucd/{}", file), &url, &dest.join(file))
+    }
+
+    /// Gets a file of the Unicode security mechanisms (`UTS #39`)
+    /// # Arguments
+    /// * `dest`: Destination directory
+    /// * `file`: File name
+    /// # Returns
+    /// `Ok(())` if the file was fetched successfully, `Err(Error)` otherwise
+    pub fn get_security_file(&self, dest: &Path, file: &str) -> Result<(), Error> {
+        let url = format!(
+            "{}/security/{}/{}",
+            self.base_url(UNICODE_URL),
+            self.version,
+            file
+        );
+        self.fetch(&format!("security/{}", file), &url, &dest.join(file))
+    }
+
+    /// Gets an emoji data file that is not part of the `UCD`, such as
+    /// `emoji-test.txt`. These files are published for each
+    /// emoji version, which only has a major and a minor number.
+    /// # Arguments
+    /// * `dest`: Destination directory
+    /// * `file`: File name
+    /// # Returns
+    /// `Ok(())` if the file was fetched successfully, `Err(Error)` otherwise
+    pub fn get_emoji_file(&self, dest: &Path, file: &str) -> Result<(), Error> {
+        let emoji_version: Vec<&str> = self.version.split('.').take(2).collect();
+        let url = format!(
+            "{}/emoji/{}/{}",
+            self.base_url(UNICODE_URL),
+            emoji_version.join("."),
+            file
+        );
+        self.fetch(&format!("emoji/{}", file), &url, &dest.join(file))
+    }
+
+    /// Gets the `IANA` csv file with the derived property values
+    /// # Arguments
+    /// * `dest`: Destination directory
+    /// # Returns
+    /// `Ok(())` if the file was fetched successfully, `Err(Error)` otherwise
+    pub fn get_csv_file(&self, dest: &Path) -> Result<(), Error> {
+        let name = get_csv_file_name(&self.version);
+        let url = format!(
+            "{}/precis-tables-{}/{}",
+            self.base_url(IANA_URL),
+            self.version,
+            name
+        );
+        self.fetch(&format!("csv/{}", name), &url, &dest.join(&name))
+    }
 }
 
 /// Gets a ucd file from the Internet
@@ -44,9 +327,18 @@ fn download(url: &str, dest: &Path) -> Result<(), Error> {
 /// # Returns
 /// `Ok(())` if the file was downloaded successfully, `Err(Error)` otherwise
 pub fn get_ucd_file(ucd_version: &str, dest: &Path, file: &str) -> Result<(), Error> {
-    let url = format!("{}/{}", get_unicode_ucd_uri(ucd_version), file);
-    let dest_path = dest.join(file);
-    download(&url, &dest_path)
+    Fetcher::from_env(ucd_version).get_ucd_file(dest, file)
+}
+
+/// Gets a file of the Unicode security mechanisms (`UTS #39`) from the Internet
+/// # Arguments
+/// * `ucd_version`: Unicode version
+/// * `dest`: Destination directory
+/// * `file`: File name
+/// # Returns
+/// `Ok(())` if the file was downloaded successfully, `Err(Error)` otherwise
+pub fn get_security_file(ucd_version: &str, dest: &Path, file: &str) -> Result<(), Error> {
+    Fetcher::from_env(ucd_version).get_security_file(dest, file)
 }
 
 /// Gets a csv file from the Internet
@@ -56,6 +348,191 @@ pub fn get_ucd_file(ucd_version: &str, dest: &Path, file: &str) -> Result<(), Er
 /// # Returns
 /// `Ok(())` if the file was downloaded successfully, `Err(Error)` otherwise
 pub fn get_csv_file(ucd_version: &str, dest: &Path) -> Result<(), Error> {
-    let dest_path = dest.join(get_csv_file_name(ucd_version));
-    download(&get_precis_csv_tables_uri(ucd_version), &dest_path)
+    Fetcher::from_env(ucd_version).get_csv_file(dest)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::download::*;
+    use crate::test_utils::TempDir;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    const UNICODE_DATA: &str = "0041;LATIN CAPITAL LETTER A;Lu;0;L;;;;;N;;;;0061;\n";
+
+    // Local HTTP server standing in for unicode.org. It answers with the
+    // content of the files in `files` and `404` otherwise, and records the
+    // paths requested.
+    struct Server {
+        url: String,
+        requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Server {
+        fn start(files: &[(&str, &str)]) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let files: HashMap<String, String> = files
+                .iter()
+                .map(|(path, content)| (path.to_string(), content.to_string()))
+                .collect();
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let log = Arc::clone(&requests);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut request = String::new();
+                    reader.read_line(&mut request).unwrap();
+                    loop {
+                        let mut header = String::new();
+                        reader.read_line(&mut header).unwrap();
+                        if header.trim().is_empty() {
+                            break;
+                        }
+                    }
+                    let path = request.split_whitespace().nth(1).unwrap().to_string();
+                    let (status, body) = match files.get(&path) {
+                        Some(content) => ("200 OK", content.as_str()),
+                        None => ("404 Not Found", "<html>Not Found</html>"),
+                    };
+                    log.lock().unwrap().push(path);
+                    write!(
+                        stream,
+                        "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                }
+            });
+            Self { url, requests }
+        }
+
+        fn requests(&self) -> Vec<String> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    fn manifest() -> Manifest {
+        Manifest::parse(&format!(
+            "# Unicode 17.0.0\n{}  ucd/UnicodeData.txt\n",
+            sha256(UNICODE_DATA.as_bytes())
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn parse_manifest() {
+        let manifest = manifest();
+        assert_eq!(
+            manifest.get("ucd/UnicodeData.txt"),
+            Some(sha256(UNICODE_DATA.as_bytes()).as_str())
+        );
+        assert_eq!(manifest.get("ucd/Scripts.txt"), None);
+
+        let err = Manifest::parse("# comment\n\nabcd  ucd/UnicodeData.txt\n").unwrap_err();
+        assert_eq!(err.line(), Some(3));
+    }
+
+    #[test]
+    fn download_and_cache() {
+        let server = Server::start(&[("/17.0.0/ucd/UnicodeData.txt", UNICODE_DATA)]);
+        let dir = TempDir::new("download-cache");
+        let cache = dir.join("cache");
+        let fetcher = Fetcher::new("17.0.0")
+            .with_mirror(&server.url)
+            .with_cache_dir(&cache)
+            .with_manifest(manifest());
+
+        fetcher
+            .get_ucd_file(&dir.join("a"), "UnicodeData.txt")
+            .unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.join("a/UnicodeData.txt")).unwrap(),
+            UNICODE_DATA
+        );
+        assert_eq!(
+            fs::read_to_string(cache.join("17.0.0/ucd/UnicodeData.txt")).unwrap(),
+            UNICODE_DATA
+        );
+
+        // Served from the cache and from the destination without network
+        fetcher
+            .get_ucd_file(&dir.join("b"), "UnicodeData.txt")
+            .unwrap();
+        fetcher
+            .get_ucd_file(&dir.join("b"), "UnicodeData.txt")
+            .unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.join("b/UnicodeData.txt")).unwrap(),
+            UNICODE_DATA
+        );
+        assert_eq!(server.requests(), ["/17.0.0/ucd/UnicodeData.txt"]);
+
+        // A corrupted cache is downloaded again
+        fs::write(cache.join("17.0.0/ucd/UnicodeData.txt"), "garbage").unwrap();
+        fetcher
+            .get_ucd_file(&dir.join("c"), "UnicodeData.txt")
+            .unwrap();
+        assert_eq!(server.requests().len(), 2);
+        assert_eq!(
+            fs::read_to_string(cache.join("17.0.0/ucd/UnicodeData.txt")).unwrap(),
+            UNICODE_DATA
+        );
+    }
+
+    #[test]
+    fn download_errors() {
+        let server = Server::start(&[
+            ("/17.0.0/ucd/UnicodeData.txt", "tampered"),
+            ("/17.0.0/ucd/Scripts.txt", "0041 ; Latin\n"),
+        ]);
+        let dir = TempDir::new("download-errors");
+        let fetcher = Fetcher::new("17.0.0").with_mirror(&server.url);
+
+        // Error pages are not saved
+        assert!(fetcher.get_ucd_file(&dir, "PropList.txt").is_err());
+        assert!(!dir.join("PropList.txt").exists());
+
+        // Checksums are verified
+        let fetcher = fetcher.with_manifest(manifest());
+        let err = fetcher.get_ucd_file(&dir, "UnicodeData.txt").unwrap_err();
+        assert!(err.mesg().contains("checksum mismatch"));
+        assert!(!dir.join("UnicodeData.txt").exists());
+
+        // Files not listed in the manifest are rejected
+        let err = fetcher.get_ucd_file(&dir, "Scripts.txt").unwrap_err();
+        assert!(err.mesg().contains("not listed"));
+        assert!(!dir.join("Scripts.txt").exists());
+
+        // No server listening
+        let fetcher = Fetcher::new("17.0.0").with_mirror("http://127.0.0.1:1");
+        assert!(fetcher.get_ucd_file(&dir, "UnicodeData.txt").is_err());
+    }
+
+    #[test]
+    fn mirror_layout() {
+        let csv = "Codepoint,Property,Description\r\n";
+        let server = Server::start(&[
+            ("/security/17.0.0/confusables.txt", "# confusables\n"),
+            ("/emoji/17.0/emoji-test.txt", "# emoji test\n"),
+            ("/precis-tables-17.0.0/precis-tables-17.0.0.csv", csv),
+        ]);
+        let dir = TempDir::new("download-layout");
+        let fetcher = Fetcher::new("17.0.0").with_mirror(&format!("{}/", server.url));
+
+        fetcher.get_security_file(&dir, "confusables.txt").unwrap();
+        fetcher.get_emoji_file(&dir, "emoji-test.txt").unwrap();
+        fetcher.get_csv_file(&dir).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.join("precis-tables-17.0.0.csv")).unwrap(),
+            csv
+        );
+        assert!(dir.join("confusables.txt").is_file());
+        assert!(dir.join("emoji-test.txt").is_file());
+    }
 }
@@ -0,0 +1,144 @@
+use crate::Error;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use ucd_parse::Codepoint;
+
+/// A single row in the emoji test data file `emoji-test.txt` defined in
+/// [`UTS #51`](https://www.unicode.org/reports/tr51/#Data_Files_Table).
+/// Each row lists a sequence of code points, its qualification status
+/// and, in the trailing comment, the emoji, the version it was introduced
+/// in and its short name.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmojiTestEntry {
+    /// The code points of the sequence
+    pub codepoints: Vec<Codepoint>,
+    /// The qualification status, such as `fully-qualified`
+    pub status: String,
+    /// The short name of the emoji
+    pub name: String,
+}
+
+impl EmojiTestEntry {
+    /// Parses an emoji test data file.
+    /// # Arguments:
+    /// * `path` - The file path
+    /// # Returns
+    /// The rows found in the file, in the same order they are listed in it.
+    pub fn parse<P: AsRef<Path>>(path: P) -> Result<Vec<EmojiTestEntry>, Error> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|e| Error {
+            mesg: format!("IO Error: {}", e),
+            line: None,
+            path: Some(path.to_path_buf()),
+        })?;
+
+        Self::parse_str(&content).map_err(|mut e| {
+            e.path = Some(path.to_path_buf());
+            e
+        })
+    }
+
+    fn parse_str(content: &str) -> Result<Vec<EmojiTestEntry>, Error> {
+        let mut entries = Vec::new();
+        for (n, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let entry = line.parse().map_err(|mut e: Error| {
+                e.line = Some(n as u64 + 1);
+                e
+            })?;
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+}
+
+impl FromStr for EmojiTestEntry {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<EmojiTestEntry, Error> {
+        let (data, comment) = line.split_once('#').unwrap_or((line, ""));
+        let Some((sequence, status)) = data.split_once(';') else {
+            return err!("invalid emoji test line: '{}'", line);
+        };
+
+        let mut codepoints = Vec::new();
+        for cp in sequence.split_whitespace() {
+            codepoints.push(cp.parse()?);
+        }
+        let status = status.trim();
+        if codepoints.is_empty() || status.is_empty() {
+            return err!("invalid emoji test line: '{}'", line);
+        }
+
+        // The comment is: <emoji> E<version> <name>
+        let name = comment
+            .trim()
+            .splitn(3, ' ')
+            .nth(2)
+            .unwrap_or_default()
+            .trim();
+
+        Ok(EmojiTestEntry {
+            codepoints,
+            status: String::from(status),
+            name: String::from(name),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::emoji_test_parser::*;
+
+    #[test]
+    fn parse_entry() {
+        let entry = EmojiTestEntry::from_str(
+            "1F469 200D 1F4BB   ; fully-qualified     # 👩‍💻 E4.0 woman technologist",
+        )
+        .unwrap();
+        assert_eq!(
+            entry.codepoints,
+            vec![
+                Codepoint::from_u32(0x1f469).unwrap(),
+                Codepoint::from_u32(0x200d).unwrap(),
+                Codepoint::from_u32(0x1f4bb).unwrap()
+            ]
+        );
+        assert_eq!(entry.status, "fully-qualified");
+        assert_eq!(entry.name, "woman technologist");
+
+        let entry = EmojiTestEntry::from_str("263A ; unqualified").unwrap();
+        assert_eq!(entry.status, "unqualified");
+        assert_eq!(entry.name, "");
+
+        assert!(EmojiTestEntry::from_str("").is_err());
+        assert!(EmojiTestEntry::from_str("1F469 200D 1F4BB").is_err());
+        assert!(EmojiTestEntry::from_str("1F469 200D 1F4BB ; ").is_err());
+        assert!(EmojiTestEntry::from_str(" ; fully-qualified # empty").is_err());
+        assert!(EmojiTestEntry::from_str("1F469 XYZ ; fully-qualified").is_err());
+    }
+
+    #[test]
+    fn parse_file() {
+        let content = "# emoji-test.txt\n\
+            \n\
+            # group: People & Body\n\
+            1F469 200D 1F4BB ; fully-qualified # 👩‍💻 E4.0 woman technologist\n\
+            1F3F3 200D 1F308 ; minimally-qualified # 🏳‍🌈 E4.0 rainbow flag\n";
+        let entries = EmojiTestEntry::parse_str(content).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].name, "rainbow flag");
+        assert_eq!(entries[1].status, "minimally-qualified");
+
+        let err = EmojiTestEntry::parse_str(
+            "1F469 200D 1F4BB ; fully-qualified # 👩‍💻 E4.0 woman technologist\n1F469\n",
+        )
+        .unwrap_err();
+        assert_eq!(err.line(), Some(2));
+    }
+}
@@ -0,0 +1,719 @@
+//! Exports the tables used by the PRECIS crates to other languages.
+//!
+//! The tables generated for Rust by the [`CodeGen`](crate::CodeGen) elements
+//! can also be written as `JSON`, `CSV` or as a `C` header, so that
+//! implementations in other languages consume exactly the same data: the
+//! derived property values of the string classes, the `Bidi_Class` values,
+//! the wide and narrow width mappings, the code points in the `Zs` general
+//! category, the tables used by the context rules and the simple lowercase
+//! mappings.
+
+use crate::csv_parser::{CsvLineParser, DerivedProperty, PrecisDerivedProperty};
+use crate::generators::bidi_class::{BidiClassGen, BIDI_CLASS_VALUES};
+use crate::generators::constants::{CONTEXT_JOINING_TYPES, CONTEXT_SCRIPTS};
+use crate::generators::derived_property_table::{class_properties, codepoint_bounds};
+use crate::tables::PrecisTables;
+use crate::{
+    DerivedJoiningType, Error, LowercaseMappingTableGen, Ucd, UcdCodeGen, UcdLineParser,
+    UcdTableGen, ViramaTableGen, WidthMappingTableGen,
+};
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use ucd_parse::Script;
+
+const CRLF: &str = "\r\n";
+
+// Values of the derived property in the order of the `C` enumeration
+const DERIVED_PROPERTY_VALUES: [DerivedProperty; 7] = [
+    DerivedProperty::PValid,
+    DerivedProperty::FreePVal,
+    DerivedProperty::ContextJ,
+    DerivedProperty::ContextO,
+    DerivedProperty::Disallowed,
+    DerivedProperty::IdDis,
+    DerivedProperty::Unassigned,
+];
+
+// Row of the derived property table
+struct DerivedPropertyRow {
+    first: u32,
+    last: u32,
+    identifier: DerivedProperty,
+    freeform: DerivedProperty,
+}
+
+// Row of the `Bidi_Class` table
+struct BidiClassRow {
+    first: u32,
+    last: u32,
+    value: String,
+}
+
+// Row of the width mapping table
+struct WidthMappingRow {
+    first: u32,
+    last: u32,
+    mapping: u32,
+}
+
+// Name of a table of code points, and its ranges of code points
+type RangeTable = (String, Vec<(u32, u32)>);
+
+/// Writes the tables used by the PRECIS crates as `JSON`, `CSV` or as a
+/// `C` header. Tables are loaded separately, because `precis-core` and
+/// `precis-profiles` do not use the same version of Unicode. Only the
+/// tables loaded are written.
+/// # Example
+/// ```no_run
+/// # use precis_tools::TableExporter;
+/// # use std::fs::File;
+/// # use std::path::Path;
+/// let mut exporter = TableExporter::new();
+/// exporter
+///     .add_derived_properties_csv(Path::new("precis-tables-6.3.0.csv"))
+///     .unwrap();
+/// exporter.add_ucd_tables(Path::new("ucd-17.0.0")).unwrap();
+///
+/// let mut file = File::create("precis_tables.json").unwrap();
+/// exporter.write_json(&mut file).unwrap();
+/// let mut file = File::create("precis_tables.h").unwrap();
+/// exporter.write_c_header(&mut file).unwrap();
+/// ```
+#[derive(Default)]
+pub struct TableExporter {
+    unicode_version: Option<String>,
+    derived_properties: Option<Vec<DerivedPropertyRow>>,
+    bidi_class: Option<Vec<BidiClassRow>>,
+    width_mapping: Option<Vec<WidthMappingRow>>,
+    space_separator: Option<Vec<(u32, u32)>>,
+    context: Option<Vec<RangeTable>>,
+    lowercase_mapping: Option<Vec<(u32, u32)>>,
+}
+
+impl TableExporter {
+    /// Creates a new exporter without tables.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the Unicode version of the tables, which is written along
+    /// with them.
+    pub fn set_unicode_version(&mut self, version: &str) {
+        self.unicode_version = Some(String::from(version));
+    }
+
+    /// Loads the derived property values from an `IANA` `precis-tables.csv`
+    /// file, such as the one `precis-core` is built from.
+    pub fn add_derived_properties_csv(&mut self, csv: &Path) -> Result<(), Error> {
+        let parser: CsvLineParser<File, PrecisDerivedProperty> = CsvLineParser::from_path(csv)?;
+        let rows = parser
+            .map(|row| derived_property_row(&row?))
+            .collect::<Result<_, Error>>()?;
+        self.derived_properties = Some(rows);
+        Ok(())
+    }
+
+    /// Computes the derived property values from the files in `ucd_dir`.
+    /// The same files as [`PrecisTables`] are required.
+    pub fn add_derived_properties_ucd(&mut self, ucd_dir: &Path) -> Result<(), Error> {
+        self.add_derived_properties(&PrecisTables::from_ucd_dir(ucd_dir)?)
+    }
+
+    /// Loads the derived property values of `tables`.
+    pub fn add_derived_properties(&mut self, tables: &PrecisTables) -> Result<(), Error> {
+        let rows = tables
+            .rows()
+            .iter()
+            .map(derived_property_row)
+            .collect::<Result<_, Error>>()?;
+        self.derived_properties = Some(rows);
+        Ok(())
+    }
+
+    /// Loads the `Bidi_Class` table from the `extracted/DerivedBidiClass.txt`
+    /// file, and the width mapping and `Zs` tables from the `UnicodeData.txt`
+    /// file in `ucd_dir`, such as `precis-profiles` does.
+    pub fn add_ucd_tables(&mut self, ucd_dir: &Path) -> Result<(), Error> {
+        let ucd = Ucd::new(ucd_dir);
+        self.add_bidi_class(&ucd)?;
+        self.add_width_mapping(&ucd)?;
+        self.add_space_separator(&ucd)
+    }
+
+    /// Loads the `Bidi_Class` table from the `extracted/DerivedBidiClass.txt`
+    /// file.
+    pub fn add_bidi_class(&mut self, ucd: &Ucd) -> Result<(), Error> {
+        let mut bidi_gen = BidiClassGen::new("bidi_class");
+        bidi_gen.parse_ucd(ucd)?;
+        let rows = bidi_gen
+            .into_table()
+            .into_iter()
+            .map(|(cps, value)| {
+                let (first, last) = codepoint_bounds(&cps);
+                BidiClassRow { first, last, value }
+            })
+            .collect();
+        self.bidi_class = Some(rows);
+        Ok(())
+    }
+
+    /// Loads the wide and narrow width mappings from the `UnicodeData.txt`
+    /// file.
+    pub fn add_width_mapping(&mut self, ucd: &Ucd) -> Result<(), Error> {
+        let mut width_gen = WidthMappingTableGen::new("width_mapping");
+        for udata in ucd.unicode_data()?.iter() {
+            width_gen.process_entry(udata)?;
+        }
+        let rows = width_gen
+            .into_table()
+            .into_iter()
+            .map(|(cps, cp)| {
+                let (first, last) = codepoint_bounds(&cps);
+                WidthMappingRow {
+                    first,
+                    last,
+                    mapping: cp.value(),
+                }
+            })
+            .collect();
+        self.width_mapping = Some(rows);
+        Ok(())
+    }
+
+    /// Loads the code points in the `Zs` general category from the
+    /// `UnicodeData.txt` file.
+    pub fn add_space_separator(&mut self, ucd: &Ucd) -> Result<(), Error> {
+        let mut zs_gen = UcdTableGen::new("Zs", "space_separator");
+        for udata in ucd.unicode_data()?.iter() {
+            zs_gen.process_entry(udata)?;
+        }
+        self.space_separator = Some(zs_gen.table().iter().map(codepoint_bounds).collect());
+        Ok(())
+    }
+
+    /// Loads the tables used by the context rules, such as `precis-core`
+    /// does: the code points with the `Virama` combining class from the
+    /// `UnicodeData.txt` file, the scripts from the `Scripts.txt` file and
+    /// the joining types from the `extracted/DerivedJoiningType.txt` file.
+    pub fn add_context_tables(&mut self, ucd: &Ucd) -> Result<(), Error> {
+        let mut virama_gen = ViramaTableGen::new("virama");
+        for udata in ucd.unicode_data()?.iter() {
+            virama_gen.process_entry(udata)?;
+        }
+        let mut tables = vec![(String::from("virama"), virama_gen.table())];
+
+        let mut script_gens: Vec<UcdTableGen> = CONTEXT_SCRIPTS
+            .iter()
+            .map(|(value, name)| UcdTableGen::new(value, name))
+            .collect();
+        for line in ucd.parse::<Script>()?.iter() {
+            for gen in script_gens.iter_mut() {
+                gen.process_entry(line)?;
+            }
+        }
+        let mut jt_gens: Vec<UcdTableGen> = CONTEXT_JOINING_TYPES
+            .iter()
+            .map(|(value, name)| UcdTableGen::new(value, name))
+            .collect();
+        for line in ucd.parse::<DerivedJoiningType>()?.iter() {
+            for gen in jt_gens.iter_mut() {
+                gen.process_entry(line)?;
+            }
+        }
+
+        let names = CONTEXT_SCRIPTS.iter().chain(CONTEXT_JOINING_TYPES.iter());
+        let gens = script_gens.iter().chain(jt_gens.iter());
+        for ((_, name), gen) in names.zip(gens) {
+            tables.push((name.to_lowercase(), gen.table()));
+        }
+
+        let tables = tables
+            .into_iter()
+            .map(|(name, cps)| (name, cps.iter().map(codepoint_bounds).collect()))
+            .collect();
+        self.context = Some(tables);
+        Ok(())
+    }
+
+    /// Loads the simple lowercase mappings from the `UnicodeData.txt` file.
+    pub fn add_lowercase_mapping(&mut self, ucd: &Ucd) -> Result<(), Error> {
+        let mut lower_gen = LowercaseMappingTableGen::new("lowercase_mapping");
+        for udata in ucd.unicode_data()?.iter() {
+            lower_gen.process_entry(udata)?;
+        }
+        self.lowercase_mapping = Some(lower_gen.into_table());
+        Ok(())
+    }
+
+    /// Writes the tables loaded as a `JSON` object with one array per
+    /// table. Code points are written as numbers and each entry covers
+    /// the range of code points from `first` to `last`, both included.
+    pub fn write_json<W: Write>(&self, w: &mut W) -> Result<(), Error> {
+        let mut tables: Vec<(&str, Vec<String>)> = Vec::new();
+        if let Some(rows) = &self.derived_properties {
+            let entries = rows
+                .iter()
+                .map(|r| {
+                    format!(
+                        "{{\"first\": {}, \"last\": {}, \"identifier\": \"{}\", \"freeform\": \"{}\"}}",
+                        r.first, r.last, r.identifier, r.freeform
+                    )
+                })
+                .collect();
+            tables.push(("derived_properties", entries));
+        }
+        if let Some(rows) = &self.bidi_class {
+            let entries = rows
+                .iter()
+                .map(|r| {
+                    format!(
+                        "{{\"first\": {}, \"last\": {}, \"value\": \"{}\"}}",
+                        r.first, r.last, r.value
+                    )
+                })
+                .collect();
+            tables.push(("bidi_class", entries));
+        }
+        if let Some(rows) = &self.width_mapping {
+            let entries = rows
+                .iter()
+                .map(|r| {
+                    format!(
+                        "{{\"first\": {}, \"last\": {}, \"mapping\": {}}}",
+                        r.first, r.last, r.mapping
+                    )
+                })
+                .collect();
+            tables.push(("width_mapping", entries));
+        }
+        if let Some(ranges) = &self.space_separator {
+            tables.push(("space_separator", json_ranges(ranges)));
+        }
+        if let Some(context) = &self.context {
+            for (name, ranges) in context.iter() {
+                tables.push((name, json_ranges(ranges)));
+            }
+        }
+        if let Some(rows) = &self.lowercase_mapping {
+            let entries = rows
+                .iter()
+                .map(|(cp, mapping)| format!("{{\"codepoint\": {}, \"mapping\": {}}}", cp, mapping))
+                .collect();
+            tables.push(("lowercase_mapping", entries));
+        }
+
+        writeln!(w, "{{")?;
+        if let Some(version) = &self.unicode_version {
+            let sep = if tables.is_empty() { "" } else { "," };
+            writeln!(w, "  \"unicode_version\": \"{}\"{}", version, sep)?;
+        }
+        for (i, (name, entries)) in tables.iter().enumerate() {
+            write_json_array(w, name, entries, i + 1 == tables.len())?;
+        }
+        writeln!(w, "}}")?;
+        Ok(())
+    }
+
+    /// Writes the tables loaded as `CSV` rows with the name of the table,
+    /// the code points and their value. Code points and values are written
+    /// as in the `IANA` registry, and the value is empty for the tables
+    /// that only list code points.
+    pub fn write_csv<W: Write>(&self, w: &mut W) -> Result<(), Error> {
+        write!(w, "Table,Codepoint,Value{}", CRLF)?;
+        if let Some(rows) = &self.derived_properties {
+            for r in rows.iter() {
+                let value = match r.identifier == r.freeform {
+                    true => r.identifier.to_string(),
+                    false => format!("{} or {}", r.identifier, r.freeform),
+                };
+                write_csv_row(w, "derived_properties", r.first, r.last, &value)?;
+            }
+        }
+        if let Some(rows) = &self.bidi_class {
+            for r in rows.iter() {
+                write_csv_row(w, "bidi_class", r.first, r.last, &r.value)?;
+            }
+        }
+        if let Some(rows) = &self.width_mapping {
+            for r in rows.iter() {
+                let value = format!("{:04X}", r.mapping);
+                write_csv_row(w, "width_mapping", r.first, r.last, &value)?;
+            }
+        }
+        if let Some(ranges) = &self.space_separator {
+            for (first, last) in ranges.iter() {
+                write_csv_row(w, "space_separator", *first, *last, "")?;
+            }
+        }
+        if let Some(context) = &self.context {
+            for (name, ranges) in context.iter() {
+                for (first, last) in ranges.iter() {
+                    write_csv_row(w, name, *first, *last, "")?;
+                }
+            }
+        }
+        if let Some(rows) = &self.lowercase_mapping {
+            for (cp, mapping) in rows.iter() {
+                let value = format!("{:04X}", mapping);
+                write_csv_row(w, "lowercase_mapping", *cp, *cp, &value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the tables loaded as `C` arrays sorted by code point, along
+    /// with the enumerations and structures required to use them.
+    pub fn write_c_header<W: Write>(&self, w: &mut W) -> Result<(), Error> {
+        writeln!(
+            w,
+            "/* File generated with {} version {} */",
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION")
+        )?;
+        writeln!(w)?;
+        writeln!(w, "#ifndef PRECIS_TABLES_H")?;
+        writeln!(w, "#define PRECIS_TABLES_H")?;
+        writeln!(w)?;
+        writeln!(w, "#include <stdint.h>")?;
+        writeln!(w)?;
+
+        if let Some(version) = &self.unicode_version {
+            writeln!(w, "#define PRECIS_UNICODE_VERSION \"{}\"", version)?;
+            writeln!(w)?;
+        }
+
+        if let Some(rows) = &self.derived_properties {
+            let values: Vec<String> = DERIVED_PROPERTY_VALUES
+                .iter()
+                .map(|p| format!("PRECIS_{}", p))
+                .collect();
+            write_c_enum(w, "precis_derived_property", &values)?;
+            write_c_struct(
+                w,
+                "precis_derived_property_range",
+                &[
+                    "uint32_t first",
+                    "uint32_t last",
+                    "enum precis_derived_property identifier",
+                    "enum precis_derived_property freeform",
+                ],
+            )?;
+            let entries: Vec<String> = rows
+                .iter()
+                .map(|r| {
+                    format!(
+                        "{{0x{:04X}, 0x{:04X}, PRECIS_{}, PRECIS_{}}}",
+                        r.first, r.last, r.identifier, r.freeform
+                    )
+                })
+                .collect();
+            write_c_array(
+                w,
+                "precis_derived_property_range",
+                "precis_derived_properties",
+                &entries,
+            )?;
+        }
+
+        if let Some(rows) = &self.bidi_class {
+            let values: Vec<String> = BIDI_CLASS_VALUES
+                .iter()
+                .map(|(value, _)| format!("PRECIS_BIDI_{}", value))
+                .collect();
+            write_c_enum(w, "precis_bidi_class", &values)?;
+            write_c_struct(
+                w,
+                "precis_bidi_class_range",
+                &[
+                    "uint32_t first",
+                    "uint32_t last",
+                    "enum precis_bidi_class value",
+                ],
+            )?;
+            let entries: Vec<String> = rows
+                .iter()
+                .map(|r| {
+                    format!(
+                        "{{0x{:04X}, 0x{:04X}, PRECIS_BIDI_{}}}",
+                        r.first, r.last, r.value
+                    )
+                })
+                .collect();
+            write_c_array(w, "precis_bidi_class_range", "precis_bidi_class", &entries)?;
+        }
+
+        if let Some(rows) = &self.width_mapping {
+            write_c_struct(
+                w,
+                "precis_width_mapping_range",
+                &["uint32_t first", "uint32_t last", "uint32_t mapping"],
+            )?;
+            let entries: Vec<String> = rows
+                .iter()
+                .map(|r| {
+                    format!(
+                        "{{0x{:04X}, 0x{:04X}, 0x{:04X}}}",
+                        r.first, r.last, r.mapping
+                    )
+                })
+                .collect();
+            write_c_array(
+                w,
+                "precis_width_mapping_range",
+                "precis_width_mapping",
+                &entries,
+            )?;
+        }
+
+        let mut sets: Vec<(String, &[(u32, u32)])> = Vec::new();
+        if let Some(ranges) = &self.space_separator {
+            sets.push((String::from("precis_space_separator"), ranges));
+        }
+        if let Some(context) = &self.context {
+            for (name, ranges) in context.iter() {
+                sets.push((format!("precis_{}", name), ranges));
+            }
+        }
+        if !sets.is_empty() {
+            write_c_struct(
+                w,
+                "precis_codepoint_range",
+                &["uint32_t first", "uint32_t last"],
+            )?;
+        }
+        for (name, ranges) in sets.iter() {
+            let entries: Vec<String> = ranges
+                .iter()
+                .map(|(first, last)| format!("{{0x{:04X}, 0x{:04X}}}", first, last))
+                .collect();
+            write_c_array(w, "precis_codepoint_range", name, &entries)?;
+        }
+
+        if let Some(rows) = &self.lowercase_mapping {
+            write_c_struct(
+                w,
+                "precis_case_mapping",
+                &["uint32_t codepoint", "uint32_t mapping"],
+            )?;
+            let entries: Vec<String> = rows
+                .iter()
+                .map(|(cp, mapping)| format!("{{0x{:04X}, 0x{:04X}}}", cp, mapping))
+                .collect();
+            write_c_array(
+                w,
+                "precis_case_mapping",
+                "precis_lowercase_mapping",
+                &entries,
+            )?;
+        }
+
+        writeln!(w, "#endif /* PRECIS_TABLES_H */")?;
+        Ok(())
+    }
+}
+
+fn derived_property_row(row: &PrecisDerivedProperty) -> Result<DerivedPropertyRow, Error> {
+    let (first, last) = codepoint_bounds(&row.codepoints);
+    let (identifier, freeform) = class_properties(&row.properties)?;
+    Ok(DerivedPropertyRow {
+        first,
+        last,
+        identifier,
+        freeform,
+    })
+}
+
+fn json_ranges(ranges: &[(u32, u32)]) -> Vec<String> {
+    ranges
+        .iter()
+        .map(|(first, last)| format!("{{\"first\": {}, \"last\": {}}}", first, last))
+        .collect()
+}
+
+fn write_csv_row<W: Write>(
+    w: &mut W,
+    table: &str,
+    first: u32,
+    last: u32,
+    value: &str,
+) -> Result<(), Error> {
+    match first == last {
+        true => write!(w, "{},{:04X},{}{}", table, first, value, CRLF)?,
+        false => write!(w, "{},{:04X}-{:04X},{}{}", table, first, last, value, CRLF)?,
+    }
+    Ok(())
+}
+
+fn write_json_array<W: Write>(
+    w: &mut W,
+    name: &str,
+    entries: &[String],
+    last: bool,
+) -> Result<(), Error> {
+    writeln!(w, "  \"{}\": [", name)?;
+    for (i, entry) in entries.iter().enumerate() {
+        let sep = if i + 1 == entries.len() { "" } else { "," };
+        writeln!(w, "    {}{}", entry, sep)?;
+    }
+    writeln!(w, "  ]{}", if last { "" } else { "," })?;
+    Ok(())
+}
+
+fn write_c_enum<W: Write>(w: &mut W, name: &str, values: &[String]) -> Result<(), Error> {
+    writeln!(w, "enum {} {{", name)?;
+    for value in values.iter() {
+        writeln!(w, "\t{},", value)?;
+    }
+    writeln!(w, "}};")?;
+    writeln!(w)?;
+    Ok(())
+}
+
+fn write_c_struct<W: Write>(w: &mut W, name: &str, fields: &[&str]) -> Result<(), Error> {
+    writeln!(w, "struct {} {{", name)?;
+    for field in fields.iter() {
+        writeln!(w, "\t{};", field)?;
+    }
+    writeln!(w, "}};")?;
+    writeln!(w)?;
+    Ok(())
+}
+
+fn write_c_array<W: Write>(
+    w: &mut W,
+    ty: &str,
+    name: &str,
+    entries: &[String],
+) -> Result<(), Error> {
+    writeln!(w, "#define {}_LEN {}", name.to_uppercase(), entries.len())?;
+    writeln!(
+        w,
+        "static const struct {} {}[{}_LEN] = {{",
+        ty,
+        name,
+        name.to_uppercase()
+    )?;
+    for entry in entries.iter() {
+        writeln!(w, "\t{},", entry)?;
+    }
+    writeln!(w, "}};")?;
+    writeln!(w)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::export::*;
+    use crate::test_utils::TempDir;
+
+    const UNICODE_DATA: &str = "\
+0020;SPACE;Zs;0;WS;;;;;N;;;;;
+0021;EXCLAMATION MARK;Po;0;ON;;;;;N;;;;;
+0022;QUOTATION MARK;Po;0;ON;;;;;N;;;;;
+0041;LATIN CAPITAL LETTER A;Lu;0;L;;;;;N;;;;00E0;
+3000;IDEOGRAPHIC SPACE;Zs;0;WS;<wide> 0020;;;;N;;;;;
+FF01;FULLWIDTH EXCLAMATION MARK;Po;0;ON;<wide> 0021;;;;N;;;;;
+";
+
+    const DERIVED_BIDI_CLASS: &str = "\
+# @missing: 0000..10FFFF; Left_To_Right
+# @missing: 0590..05FF; Right_To_Left
+0020          ; WS
+0021..0022    ; ON
+3000          ; WS
+FF01          ; ON
+";
+
+    const CSV: &str = "Codepoint,Property,Description\r\n\
+                       0000-001F,DISALLOWED,NULL..UNIT SEPARATOR\r\n\
+                       0020,ID_DIS or FREE_PVAL,SPACE\r\n\
+                       0021-10FFFF,PVALID,EXCLAMATION MARK..<NOT A CHARACTER>\r\n";
+
+    fn exporter(name: &str) -> TableExporter {
+        let dir = TempDir::new(&format!("export-{}", name));
+        dir.write("UnicodeData.txt", UNICODE_DATA);
+        dir.write("extracted/DerivedBidiClass.txt", DERIVED_BIDI_CLASS);
+        let csv = dir.write("precis-tables.csv", CSV);
+
+        let mut exporter = TableExporter::new();
+        exporter.add_derived_properties_csv(&csv).unwrap();
+        exporter.add_ucd_tables(&dir).unwrap();
+        exporter
+    }
+
+    #[test]
+    fn json() {
+        let mut out = Vec::new();
+        exporter("json").write_json(&mut out).unwrap();
+        let json = String::from_utf8(out).unwrap();
+
+        let expected = r#"{
+  "derived_properties": [
+    {"first": 0, "last": 31, "identifier": "DISALLOWED", "freeform": "DISALLOWED"},
+    {"first": 32, "last": 32, "identifier": "ID_DIS", "freeform": "FREE_PVAL"},
+    {"first": 33, "last": 1114111, "identifier": "PVALID", "freeform": "PVALID"}
+  ],
+  "bidi_class": [
+    {"first": 0, "last": 31, "value": "L"},
+    {"first": 32, "last": 32, "value": "WS"},
+    {"first": 33, "last": 34, "value": "ON"},
+    {"first": 35, "last": 1423, "value": "L"},
+    {"first": 1424, "last": 1535, "value": "R"},
+    {"first": 1536, "last": 12287, "value": "L"},
+    {"first": 12288, "last": 12288, "value": "WS"},
+    {"first": 12289, "last": 65280, "value": "L"},
+    {"first": 65281, "last": 65281, "value": "ON"},
+    {"first": 65282, "last": 1114111, "value": "L"}
+  ],
+  "width_mapping": [
+    {"first": 12288, "last": 12288, "mapping": 32},
+    {"first": 65281, "last": 65281, "mapping": 33}
+  ],
+  "space_separator": [
+    {"first": 32, "last": 32},
+    {"first": 12288, "last": 12288}
+  ]
+}
+"#;
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn c_header() {
+        let mut out = Vec::new();
+        exporter("c").write_c_header(&mut out).unwrap();
+        let header = String::from_utf8(out).unwrap();
+
+        assert!(header.contains("#ifndef PRECIS_TABLES_H\n#define PRECIS_TABLES_H\n"));
+        assert!(header.contains("enum precis_derived_property {\n\tPRECIS_PVALID,\n"));
+        assert!(header.contains(
+            "#define PRECIS_DERIVED_PROPERTIES_LEN 3\n\
+             static const struct precis_derived_property_range precis_derived_properties[PRECIS_DERIVED_PROPERTIES_LEN] = {\n\
+             \t{0x0000, 0x001F, PRECIS_DISALLOWED, PRECIS_DISALLOWED},\n\
+             \t{0x0020, 0x0020, PRECIS_ID_DIS, PRECIS_FREE_PVAL},\n\
+             \t{0x0021, 0x10FFFF, PRECIS_PVALID, PRECIS_PVALID},\n\
+             };\n"
+        ));
+        assert!(header.contains("\t{0x0021, 0x0022, PRECIS_BIDI_ON},\n"));
+        assert!(header.contains("\t{0xFF01, 0xFF01, 0x0021},\n"));
+        assert!(header.contains(
+            "static const struct precis_codepoint_range precis_space_separator[PRECIS_SPACE_SEPARATOR_LEN] = {\n\
+             \t{0x0020, 0x0020},\n\
+             \t{0x3000, 0x3000},\n\
+             };\n"
+        ));
+        assert!(header.ends_with("#endif /* PRECIS_TABLES_H */\n"));
+    }
+
+    #[test]
+    fn only_loaded_tables() {
+        let mut out = Vec::new();
+        TableExporter::new().write_json(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "{\n}\n");
+
+        let mut out = Vec::new();
+        TableExporter::new().write_c_header(&mut out).unwrap();
+        assert!(!String::from_utf8(out).unwrap().contains("static const"));
+    }
+}
@@ -1,12 +1,12 @@
 use crate::common;
+use crate::generators::codepoint_set::{CodepointTable, EncodingReport, TableEncoding};
 use crate::Error;
 use std::collections::HashSet;
-use std::fs::File;
 use std::io::Write;
 use ucd_parse::Codepoints::{Range, Single};
 use ucd_parse::{Codepoint, Codepoints};
 
-pub fn generate_file_header(file: &mut File) -> Result<(), Error> {
+pub fn generate_file_header(file: &mut dyn Write) -> Result<(), Error> {
     let pkg_name = env!("CARGO_PKG_NAME");
     let version = env!("CARGO_PKG_VERSION");
 
@@ -29,7 +29,7 @@ pub fn generate_codepoint_str(c: &Codepoints) -> String {
     }
 }
 
-fn vector_start(file: &mut File, t: &str, name: &str, len: usize) -> Result<(), Error> {
+fn vector_start(file: &mut dyn Write, t: &str, name: &str, len: usize) -> Result<(), Error> {
     // Let's follow rust constant naming convention in upper case
     let const_name = name.to_uppercase();
 
@@ -40,20 +40,13 @@ fn vector_start(file: &mut File, t: &str, name: &str, len: usize) -> Result<(),
     )?)
 }
 
-fn vector_codepoints(file: &mut File, vec: &[Codepoints]) -> Result<(), Error> {
-    for cps in vec.iter() {
-        writeln!(file, "\t{},", generate_codepoint_str(cps))?;
-    }
-    Ok(())
-}
-
-fn vector_end(file: &mut File) -> Result<(), Error> {
+fn vector_end(file: &mut dyn Write) -> Result<(), Error> {
     writeln!(file, "];")?;
     Ok(writeln!(file)?)
 }
 
 pub fn generate_width_mapping_vector(
-    file: &mut File,
+    file: &mut dyn Write,
     name: &str,
     vec: &[(Codepoints, Codepoint)],
 ) -> Result<(), Error> {
@@ -70,8 +63,49 @@ pub fn generate_width_mapping_vector(
     vector_end(file)
 }
 
+pub fn generate_codepoint_mapping_vector(
+    file: &mut dyn Write,
+    name: &str,
+    vec: &[(u32, u32)],
+) -> Result<(), Error> {
+    vector_start(file, "(u32, u32)", name, vec.len())?;
+    for (cp, mapping) in vec.iter() {
+        writeln!(file, "\t({:#06x}, {:#06x}),", cp, mapping)?;
+    }
+
+    vector_end(file)
+}
+
+pub fn generate_mapping_vector(
+    file: &mut dyn Write,
+    name: &str,
+    vec: &[(u32, &[u32])],
+) -> Result<(), Error> {
+    vector_start(file, "(u32, &[u32])", name, vec.len())?;
+    for (cp, mapping) in vec.iter() {
+        let mapping: Vec<String> = mapping.iter().map(|cp| format!("{:#06x}", cp)).collect();
+        writeln!(file, "\t({:#06x}, &[{}]),", cp, mapping.join(", "))?;
+    }
+
+    vector_end(file)
+}
+
+pub fn generate_sequence_vector(
+    file: &mut dyn Write,
+    name: &str,
+    vec: &[&[u32]],
+) -> Result<(), Error> {
+    vector_start(file, "&[u32]", name, vec.len())?;
+    for sequence in vec.iter() {
+        let sequence: Vec<String> = sequence.iter().map(|cp| format!("{:#06x}", cp)).collect();
+        writeln!(file, "\t&[{}],", sequence.join(", "))?;
+    }
+
+    vector_end(file)
+}
+
 pub fn generate_code_from_hashset(
-    file: &mut File,
+    file: &mut dyn Write,
     name: &str,
     codepoints: &HashSet<u32>,
 ) -> Result<(), Error> {
@@ -80,7 +114,7 @@ pub fn generate_code_from_hashset(
 }
 
 pub fn generate_code_from_range(
-    file: &mut File,
+    file: &mut dyn Write,
     name: &str,
     range: &std::ops::Range<u32>,
 ) -> Result<(), Error> {
@@ -97,11 +131,18 @@ pub fn generate_code_from_range(
 }
 
 pub fn generate_code_from_vec(
-    file: &mut File,
+    file: &mut dyn Write,
     name: &str,
     vec: &[Codepoints],
 ) -> Result<(), Error> {
-    vector_start(file, "Codepoints", name, vec.len())?;
-    vector_codepoints(file, vec)?;
-    vector_end(file)
+    let report = EncodingReport::new(vec);
+    writeln!(
+        file,
+        "// Encoded as {}: ranges {} bytes, bitset {} bytes, trie {} bytes",
+        report.chosen,
+        report.size(TableEncoding::Ranges),
+        report.size(TableEncoding::Bitset),
+        report.size(TableEncoding::Trie)
+    )?;
+    CodepointTable::with_encoding(vec, report.chosen).write(file, name)
 }
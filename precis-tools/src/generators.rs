@@ -1,15 +1,22 @@
 use crate::error::Error;
 use crate::file_writer;
-use std::fs::File;
-use std::path::Path;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
 pub mod ascii7;
 pub mod backward_compatible;
 pub mod bidi_class;
+pub mod codepoint_set;
 pub mod codepoints;
+pub mod confusables;
 pub mod constants;
 pub mod derived_property;
+pub mod derived_property_table;
+pub mod emoji_sequences;
 pub mod exceptions;
+pub mod scripts;
+pub mod stringprep;
 pub mod ucd_generator;
 pub mod unicode_version;
 
@@ -17,7 +24,7 @@ pub mod unicode_version;
 /// [`CodeGen`] elements. The resulting file will contain the
 /// code generated by every element added to the code generator.
 pub struct RustCodeGen {
-    file: File,
+    path: PathBuf,
     generators: Vec<Box<dyn CodeGen>>,
 }
 
@@ -26,14 +33,14 @@ impl RustCodeGen {
     /// # Arguments:
     /// * `filename` - The file name
     /// # Returns:
-    /// This method returns a new [`RustCodeGen`] instance if no errors
-    /// occurs when the file is created
+    /// This method returns a new [`RustCodeGen`] instance. The file is
+    /// not written until [`RustCodeGen::generate_code`] is called.
     pub fn new<P>(filename: P) -> Result<Self, Error>
     where
         P: AsRef<Path>,
     {
         Ok(Self {
-            file: File::create(filename)?,
+            path: filename.as_ref().to_path_buf(),
             generators: Vec::new(),
         })
     }
@@ -46,23 +53,85 @@ impl RustCodeGen {
         self.generators.push(gen);
     }
 
-    /// Write the code into the file created on construction. In case of
-    /// error, the file generated could stay in an incomplete or
-    /// inconsistent state.
-    pub fn generate_code(&mut self) -> Result<(), Error> {
-        file_writer::generate_file_header(&mut self.file)?;
+    /// Writes the code generated by every element into `out`.
+    pub fn write_code(&mut self, out: &mut dyn Write) -> Result<(), Error> {
+        file_writer::generate_file_header(out)?;
         let it = self.generators.iter_mut();
         for gen in it {
-            gen.generate_code(&mut self.file)?;
+            gen.generate_code(out)?;
         }
         Ok(())
     }
+
+    /// Generates the code in memory.
+    pub fn generate_to_vec(&mut self) -> Result<Vec<u8>, Error> {
+        let mut code = Vec::new();
+        self.write_code(&mut code)?;
+        Ok(code)
+    }
+
+    /// Generates the code in memory and writes it into the file given on
+    /// construction. The file is left untouched if its content is already
+    /// the same, so that crates including it are not needlessly recompiled.
+    /// Returns whether the file was written.
+    pub fn generate_code(&mut self) -> Result<bool, Error> {
+        let code = self.generate_to_vec()?;
+        if fs::read(&self.path).is_ok_and(|current| current == code) {
+            return Ok(false);
+        }
+        fs::write(&self.path, code).map_err(|e| Error {
+            mesg: format!("IO Error: {}", e),
+            line: None,
+            path: Some(self.path.clone()),
+        })?;
+        Ok(true)
+    }
 }
 
 /// Trait implemented by all elements which are able to generate code.
 pub trait CodeGen {
     /// Writes the Rust code itself.
     /// # Arguments:
-    /// * `file` - The output file
-    fn generate_code(&mut self, file: &mut File) -> Result<(), Error>;
+    /// * `file` - The output writer
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generators::*;
+    use crate::test_utils::TempDir;
+    use crate::Ascii7Gen;
+
+    fn code_gen(path: &Path) -> RustCodeGen {
+        let mut gen = RustCodeGen::new(path).unwrap();
+        gen.add(Box::new(Ascii7Gen::new()));
+        gen
+    }
+
+    #[test]
+    fn generate_in_memory() {
+        let code = code_gen(Path::new("unused.rs")).generate_to_vec().unwrap();
+        let code = String::from_utf8(code).unwrap();
+        assert!(code.starts_with("// File generated with precis-tools"));
+        assert!(code.contains("const ASCII7: "));
+        assert!(!Path::new("unused.rs").exists());
+    }
+
+    #[test]
+    fn unchanged_files_not_written() {
+        let dir = TempDir::new("codegen");
+        let path = dir.join("tables.rs");
+
+        assert!(code_gen(&path).generate_code().unwrap());
+        let modified = fs::metadata(&path).unwrap().modified().unwrap();
+        assert!(!code_gen(&path).generate_code().unwrap());
+        assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), modified);
+
+        fs::write(&path, "// stale").unwrap();
+        assert!(code_gen(&path).generate_code().unwrap());
+        assert_eq!(
+            fs::read(&path).unwrap(),
+            code_gen(&path).generate_to_vec().unwrap()
+        );
+    }
 }
@@ -2,7 +2,7 @@ use crate::error::Error;
 use crate::file_writer;
 use crate::generators::constants::{ASCII7_END, ASCII7_START};
 use crate::generators::CodeGen;
-use std::fs::File;
+use std::io::Write;
 
 const ASCII7: std::ops::Range<u32> = std::ops::Range {
     start: ASCII7_START,
@@ -27,7 +27,7 @@ impl Default for Ascii7Gen {
 }
 
 impl CodeGen for Ascii7Gen {
-    fn generate_code(&mut self, file: &mut File) -> Result<(), Error> {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
         file_writer::generate_code_from_range(file, "ascii7", &ASCII7)
     }
 }
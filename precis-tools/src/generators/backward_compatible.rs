@@ -1,6 +1,5 @@
 use crate::error::Error;
 use crate::generators::CodeGen;
-use std::fs::File;
 use std::io::Write;
 
 /// Generates the [`BackwardCompatible`](https://datatracker.ietf.org/doc/html/rfc8264#section-9.7)
@@ -21,10 +20,10 @@ impl Default for BackwardCompatibleGen {
 }
 
 impl CodeGen for BackwardCompatibleGen {
-    fn generate_code(&mut self, file: &mut File) -> Result<(), Error> {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
         writeln!(
             file,
-            "static BACKWARD_COMPATIBLE: [(Codepoints, DerivedPropertyValue); 0] = [",
+            "const BACKWARD_COMPATIBLE: &[(Codepoints, DerivedPropertyValue)] = &[",
         )?;
         writeln!(file, "];")?;
         Ok(writeln!(file)?)
@@ -1,16 +1,51 @@
 use crate::file_writer;
+use crate::ucd::Ucd;
 use crate::ucd_parsers;
 use crate::Error;
-use crate::{CodeGen, UcdLineParser};
-use std::fs::File;
+use crate::{CodeGen, UcdCodeGen, UcdLineParser};
 use std::io::Write;
+use std::path::Path;
 use ucd_parse::{CodepointRange, Codepoints};
 
+// Values of the `Bidi_Class` property and their long names
+pub(crate) const BIDI_CLASS_VALUES: [(&str, &str); 23] = [
+    ("AL", "Arabic_Letter"),
+    ("AN", "Arabic_Number"),
+    ("B", "Paragraph_Separator"),
+    ("BN", "Boundary_Neutral"),
+    ("CS", "Common_Separator"),
+    ("EN", "European_Number"),
+    ("ES", "European_Separator"),
+    ("ET", "European_Terminator"),
+    ("FSI", "First_Strong_Isolate"),
+    ("L", "Left_To_Right"),
+    ("LRE", "Left_To_Right_Embedding"),
+    ("LRI", "Left_To_Right_Isolate"),
+    ("LRO", "Left_To_Right_Override"),
+    ("NSM", "Nonspacing_Mark"),
+    ("ON", "Other_Neutral"),
+    ("PDF", "Pop_Directional_Format"),
+    ("PDI", "Pop_Directional_Isolate"),
+    ("R", "Right_To_Left"),
+    ("RLE", "Right_To_Left_Embedding"),
+    ("RLI", "Right_To_Left_Isolate"),
+    ("RLO", "Right_To_Left_Override"),
+    ("S", "Segment_Separator"),
+    ("WS", "White_Space"),
+];
+
 /// Generates a table of tuples (`Codepoints`, `BidiClass`) representing
 /// the values of the Unicode character property
 /// [`Bidi_Class`](http://www.unicode.org/reports/tr44/#Bidi_Class).
 /// Possible values are listed in
 /// [`UAX44`, Table 13](http://www.unicode.org/reports/tr44/#Bidi_Class_Values).
+///
+/// When it is added to a [`GeneralCategoryGen`](crate::GeneralCategoryGen),
+/// the table only contains the code points listed in `UnicodeData.txt`.
+/// When it is added directly to a [`UcdFileGen`](crate::UcdFileGen), the
+/// table is read from `extracted/DerivedBidiClass.txt` and covers every
+/// code point, including the unassigned ones, which take the default
+/// values declared in the `@missing` lines of the file.
 pub struct BidiClassGen {
     table_name: String,
     vec: Vec<(Codepoints, String)>,
@@ -27,7 +62,7 @@ impl BidiClassGen {
 }
 
 impl CodeGen for BidiClassGen {
-    fn generate_code(&mut self, file: &mut File) -> Result<(), Error> {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
         generate_bidi_class_enum(file)?;
         self.generate_bidi_class_table(file)
     }
@@ -40,6 +75,19 @@ impl UcdLineParser<ucd_parsers::UnicodeData> for BidiClassGen {
     }
 }
 
+impl UcdCodeGen for BidiClassGen {
+    fn parse_unicode_file(&mut self, ucd_path: &Path) -> Result<(), Error> {
+        self.parse_ucd(&Ucd::new(ucd_path))
+    }
+
+    fn parse_ucd(&mut self, ucd: &Ucd) -> Result<(), Error> {
+        for (range, bidi) in ucd.bidi_classes()?.iter() {
+            add_range(range, bidi, &mut self.vec);
+        }
+        Ok(())
+    }
+}
+
 fn add_range(range: &CodepointRange, bidi: &str, vec: &mut Vec<(Codepoints, String)>) {
     if range.start.value() == range.end.value() {
         vec.push((Codepoints::Single(range.start), String::from(bidi)));
@@ -48,7 +96,7 @@ fn add_range(range: &CodepointRange, bidi: &str, vec: &mut Vec<(Codepoints, Stri
     }
 }
 
-fn generate_bidi_class_enum(file: &mut File) -> Result<(), Error> {
+fn generate_bidi_class_enum(file: &mut dyn Write) -> Result<(), Error> {
     writeln!(
         file,
         "/// Represents values of the Unicode character property"
@@ -71,35 +119,22 @@ fn generate_bidi_class_enum(file: &mut File) -> Result<(), Error> {
 
     writeln!(file, "#[derive(Copy, Clone, PartialEq, Eq, Debug)]")?;
     writeln!(file, "pub enum BidiClass {{")?;
-    writeln!(file, "\tAL,")?;
-    writeln!(file, "\tAN,")?;
-    writeln!(file, "\tB,")?;
-    writeln!(file, "\tBN,")?;
-    writeln!(file, "\tCS,")?;
-    writeln!(file, "\tEN,")?;
-    writeln!(file, "\tES,")?;
-    writeln!(file, "\tET,")?;
-    writeln!(file, "\tFSI,")?;
-    writeln!(file, "\tL,")?;
-    writeln!(file, "\tLRE,")?;
-    writeln!(file, "\tLRI,")?;
-    writeln!(file, "\tLRO,")?;
-    writeln!(file, "\tNSM,")?;
-    writeln!(file, "\tON,")?;
-    writeln!(file, "\tPDF,")?;
-    writeln!(file, "\tPDI,")?;
-    writeln!(file, "\tR,")?;
-    writeln!(file, "\tRLE,")?;
-    writeln!(file, "\tRLI,")?;
-    writeln!(file, "\tRLO,")?;
-    writeln!(file, "\tS,")?;
-    writeln!(file, "\tWS,")?;
+    for (value, name) in BIDI_CLASS_VALUES.iter() {
+        writeln!(file, "\t/// `{}`", name)?;
+        writeln!(file, "\t{},", value)?;
+    }
     writeln!(file, "}}")?;
 
     Ok(writeln!(file)?)
 }
 impl BidiClassGen {
-    fn generate_bidi_class_table(&mut self, file: &mut File) -> Result<(), Error> {
+    /// Returns the table of `Bidi_Class` values merged into ranges
+    pub(crate) fn into_table(mut self) -> Vec<(Codepoints, String)> {
+        self.compress_into_ranges();
+        self.vec
+    }
+
+    fn generate_bidi_class_table(&mut self, file: &mut dyn Write) -> Result<(), Error> {
         self.compress_into_ranges();
         self.write_table_to_file(file)
     }
@@ -171,10 +206,15 @@ impl BidiClassGen {
             }
         }
 
+        // Add the last range, if any
+        if let (Some(r), Some(bidi)) = (range.as_ref(), val.as_ref()) {
+            add_range(r, bidi, &mut out);
+        }
+
         self.vec = out;
     }
 
-    fn write_table_to_file(&mut self, file: &mut File) -> Result<(), Error> {
+    fn write_table_to_file(&mut self, file: &mut dyn Write) -> Result<(), Error> {
         writeln!(
             file,
             "static {}: [(Codepoints, BidiClass); {}] = [",
@@ -195,3 +235,80 @@ impl BidiClassGen {
         Ok(writeln!(file)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::generators::bidi_class::*;
+    use crate::test_utils::TempDir;
+    use crate::DerivedBidiClass;
+
+    const DERIVED_BIDI_CLASS: &str = "\
+# DerivedBidiClass.txt
+#
+# @missing: 0000..10FFFF; Left_To_Right
+# @missing: 0590..05FF; Right_To_Left
+# @missing: 05F0..05F7; Arabic_Letter
+# @missing: 20A0..20CF; European_Terminator
+
+05D0..05EA    ; R # Lo  [27] HEBREW LETTER ALEF..HEBREW LETTER TAV
+20AC          ; ET # Sc       EURO SIGN
+";
+
+    fn ucd_dir(name: &str, content: &str) -> TempDir {
+        let dir = TempDir::new(&format!("bidi-{}", name));
+        dir.write("extracted/DerivedBidiClass.txt", content);
+        dir
+    }
+
+    #[test]
+    fn parse_missing() {
+        let dir = ucd_dir("missing", DERIVED_BIDI_CLASS);
+        let missing = DerivedBidiClass::parse_missing(&dir).unwrap();
+
+        let missing: Vec<String> = missing
+            .iter()
+            .map(|(r, v)| format!("{}: {}", r, v))
+            .collect();
+        assert_eq!(
+            missing,
+            [
+                "0000..10FFFF: Left_To_Right",
+                "0590..05FF: Right_To_Left",
+                "05F0..05F7: Arabic_Letter",
+                "20A0..20CF: European_Terminator",
+            ]
+        );
+    }
+
+    #[test]
+    fn derived_bidi_class_table() {
+        let dir = ucd_dir("table", DERIVED_BIDI_CLASS);
+        let mut gen = BidiClassGen::new("bidi_class_table");
+        gen.parse_unicode_file(&dir).unwrap();
+
+        let table: Vec<String> = gen
+            .into_table()
+            .iter()
+            .map(|(cps, value)| format!("{}: {}", cps, value))
+            .collect();
+        assert_eq!(
+            table,
+            [
+                "0000..058F: L",
+                "0590..05EF: R",
+                "05F0..05F7: AL",
+                "05F8..05FF: R",
+                "0600..209F: L",
+                "20A0..20CF: ET",
+                "20D0..10FFFF: L",
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_bidi_class() {
+        let dir = ucd_dir("unknown", "# @missing: 0000..10FFFF; Unknown\n");
+        let mut gen = BidiClassGen::new("bidi_class_table");
+        assert!(gen.parse_unicode_file(&dir).is_err());
+    }
+}
@@ -0,0 +1,453 @@
+use crate::error::Error;
+use crate::generators::CodeGen;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::Write;
+use ucd_parse::Codepoints;
+
+// Number of code points in each block of the bitsets and tries
+const BLOCK_BITS: u32 = 64;
+
+/// Generates the `CodepointSet` `enum` used by the tables of code points
+/// created by the generators. Each table is stored with the encoding that
+/// [`CodepointTable::new`] chooses for its content.
+/// # Example:
+/// ```rust
+/// pub enum CodepointSet {
+///     Ranges(&'static [(u32, u32)]),
+///     Bitset { start: u32, bits: &'static [u64] },
+///     Trie { index: &'static [u16], leaves: &'static [u64] },
+/// }
+/// ```
+pub struct CodepointSetGen {}
+
+impl CodepointSetGen {
+    /// Creates a new generator for the `CodepointSet` `enum`
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for CodepointSetGen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodeGen for CodepointSetGen {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
+        let template = include_str!("codepoint_set.template");
+        Ok(writeln!(file, "{}", template)?)
+    }
+}
+
+/// Encodings available for the tables of code points.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TableEncoding {
+    /// Sorted array of `(u32, u32)` ranges searched by binary search.
+    Ranges,
+    /// One bit per code point between the first and the last code point.
+    Bitset,
+    /// Two-level trie of blocks of 64 code points, sharing identical blocks.
+    Trie,
+}
+
+impl TableEncoding {
+    /// All the encodings, in order of preference when they take the same size.
+    pub const ALL: [TableEncoding; 3] = [
+        TableEncoding::Bitset,
+        TableEncoding::Trie,
+        TableEncoding::Ranges,
+    ];
+}
+
+impl fmt::Display for TableEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TableEncoding::Ranges => write!(f, "ranges"),
+            TableEncoding::Bitset => write!(f, "bitset"),
+            TableEncoding::Trie => write!(f, "trie"),
+        }
+    }
+}
+
+/// Table of code points in one of the available [`TableEncoding`]s.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CodepointTable {
+    /// Sorted and disjoint inclusive ranges of code points.
+    Ranges(Vec<(u32, u32)>),
+    /// Bits of the code points from `start`, 64 code points per word.
+    Bitset {
+        /// First code point of the table.
+        start: u32,
+        /// Bits of the code points.
+        bits: Vec<u64>,
+    },
+    /// Index of the leaf of each block of 64 code points, and the bits of
+    /// the distinct blocks. The first leaf is always the empty block.
+    Trie {
+        /// Index of the leaf of each block.
+        index: Vec<u16>,
+        /// Bits of the distinct blocks.
+        leaves: Vec<u64>,
+    },
+}
+
+// Merges the sorted code points into inclusive ranges
+fn into_ranges(codepoints: &[Codepoints]) -> Vec<(u32, u32)> {
+    let mut bounds: Vec<(u32, u32)> = codepoints
+        .iter()
+        .map(|cps| match cps {
+            Codepoints::Single(cp) => (cp.value(), cp.value()),
+            Codepoints::Range(r) => (r.start.value(), r.end.value()),
+        })
+        .collect();
+    bounds.sort();
+
+    let mut ranges: Vec<(u32, u32)> = Vec::new();
+    for (start, end) in bounds {
+        match ranges.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => ranges.push((start, end)),
+        }
+    }
+    ranges
+}
+
+fn set_bits(bits: &mut [u64], start: u32, ranges: &[(u32, u32)]) {
+    for &(first, last) in ranges {
+        for cp in first..=last {
+            let offset = cp - start;
+            bits[(offset / BLOCK_BITS) as usize] |= 1 << (offset % BLOCK_BITS);
+        }
+    }
+}
+
+impl CodepointTable {
+    /// Encodes the code points with the encoding chosen by
+    /// [`CodepointTable::choose_encoding`].
+    pub fn new(codepoints: &[Codepoints]) -> Self {
+        let ranges = into_ranges(codepoints);
+        let encoding = Self::choose_encoding(&ranges);
+        Self::encode_ranges(ranges, encoding)
+    }
+
+    /// Encodes the code points with the given encoding.
+    pub fn with_encoding(codepoints: &[Codepoints], encoding: TableEncoding) -> Self {
+        Self::encode_ranges(into_ranges(codepoints), encoding)
+    }
+
+    fn encode_ranges(ranges: Vec<(u32, u32)>, encoding: TableEncoding) -> Self {
+        match encoding {
+            TableEncoding::Ranges => CodepointTable::Ranges(ranges),
+            TableEncoding::Bitset => {
+                let (start, end) = match (ranges.first(), ranges.last()) {
+                    (Some(first), Some(last)) => (first.0, last.1),
+                    _ => {
+                        return CodepointTable::Bitset {
+                            start: 0,
+                            bits: Vec::new(),
+                        }
+                    }
+                };
+                let mut bits = vec![0; ((end - start) / BLOCK_BITS + 1) as usize];
+                set_bits(&mut bits, start, &ranges);
+                CodepointTable::Bitset { start, bits }
+            }
+            TableEncoding::Trie => {
+                let blocks = ranges.last().map_or(0, |last| last.1 / BLOCK_BITS + 1);
+                let mut bits = vec![0; blocks as usize];
+                set_bits(&mut bits, 0, &ranges);
+
+                let mut leaves = vec![0];
+                let mut ids = HashMap::from([(0, 0)]);
+                let index = bits
+                    .iter()
+                    .map(|block| {
+                        *ids.entry(*block).or_insert_with(|| {
+                            leaves.push(*block);
+                            (leaves.len() - 1) as u16
+                        })
+                    })
+                    .collect();
+                CodepointTable::Trie { index, leaves }
+            }
+        }
+    }
+
+    /// Chooses the encoding of the ranges of code points. The smallest
+    /// encoding is chosen, except that a bitset or a trie, which are looked
+    /// up in constant time, are preferred over binary searching the ranges
+    /// when they take at most a quarter more bytes.
+    pub fn choose_encoding(ranges: &[(u32, u32)]) -> TableEncoding {
+        let sizes: Vec<(TableEncoding, usize)> = TableEncoding::ALL
+            .iter()
+            .map(|&encoding| {
+                let table = Self::encode_ranges(ranges.to_vec(), encoding);
+                (encoding, table.size())
+            })
+            .collect();
+        let smallest = sizes.iter().map(|(_, size)| *size).min().unwrap_or(0);
+        sizes
+            .iter()
+            .find(|(encoding, size)| {
+                *encoding != TableEncoding::Ranges && *size <= smallest + smallest / 4
+            })
+            .map_or(TableEncoding::Ranges, |(encoding, _)| *encoding)
+    }
+
+    /// Returns the encoding of the table.
+    pub fn encoding(&self) -> TableEncoding {
+        match self {
+            CodepointTable::Ranges(_) => TableEncoding::Ranges,
+            CodepointTable::Bitset { .. } => TableEncoding::Bitset,
+            CodepointTable::Trie { .. } => TableEncoding::Trie,
+        }
+    }
+
+    /// Returns the number of bytes taken by the data of the table.
+    pub fn size(&self) -> usize {
+        match self {
+            CodepointTable::Ranges(ranges) => ranges.len() * std::mem::size_of::<(u32, u32)>(),
+            CodepointTable::Bitset { bits, .. } => bits.len() * std::mem::size_of::<u64>(),
+            CodepointTable::Trie { index, leaves } => {
+                index.len() * std::mem::size_of::<u16>() + leaves.len() * std::mem::size_of::<u64>()
+            }
+        }
+    }
+
+    /// Returns whether the table contains the code point. The lookup is the
+    /// same as the one of the generated `CodepointSet`.
+    pub fn contains(&self, cp: u32) -> bool {
+        match self {
+            CodepointTable::Ranges(ranges) => ranges
+                .binary_search_by(|&(start, end)| {
+                    if end < cp {
+                        std::cmp::Ordering::Less
+                    } else if start > cp {
+                        std::cmp::Ordering::Greater
+                    } else {
+                        std::cmp::Ordering::Equal
+                    }
+                })
+                .is_ok(),
+            CodepointTable::Bitset { start, bits } => match cp.checked_sub(*start) {
+                Some(offset) => bits
+                    .get((offset / BLOCK_BITS) as usize)
+                    .is_some_and(|word| word & (1 << (offset % BLOCK_BITS)) != 0),
+                None => false,
+            },
+            CodepointTable::Trie { index, leaves } => index
+                .get((cp / BLOCK_BITS) as usize)
+                .is_some_and(|&leaf| leaves[leaf as usize] & (1 << (cp % BLOCK_BITS)) != 0),
+        }
+    }
+
+    /// Writes the table as a `const` `CodepointSet` named `name`, so that it
+    /// can be read in const context.
+    pub(crate) fn write(&self, file: &mut dyn Write, name: &str) -> Result<(), Error> {
+        let const_name = name.to_uppercase();
+        match self {
+            CodepointTable::Ranges(ranges) => {
+                writeln!(
+                    file,
+                    "const {}: CodepointSet = CodepointSet::Ranges(&[",
+                    const_name
+                )?;
+                for (start, end) in ranges.iter() {
+                    writeln!(file, "\t({:#06x}, {:#06x}),", start, end)?;
+                }
+                writeln!(file, "]);")?;
+            }
+            CodepointTable::Bitset { start, bits } => {
+                writeln!(
+                    file,
+                    "const {}: CodepointSet = CodepointSet::Bitset {{",
+                    const_name
+                )?;
+                writeln!(file, "\tstart: {:#06x},", start)?;
+                write_words(file, "bits", bits)?;
+                writeln!(file, "}};")?;
+            }
+            CodepointTable::Trie { index, leaves } => {
+                writeln!(
+                    file,
+                    "const {}: CodepointSet = CodepointSet::Trie {{",
+                    const_name
+                )?;
+                writeln!(file, "\tindex: &[")?;
+                for chunk in index.chunks(16) {
+                    let line: Vec<String> = chunk.iter().map(|i| i.to_string()).collect();
+                    writeln!(file, "\t\t{},", line.join(", "))?;
+                }
+                writeln!(file, "\t],")?;
+                write_words(file, "leaves", leaves)?;
+                writeln!(file, "}};")?;
+            }
+        }
+        Ok(writeln!(file)?)
+    }
+}
+
+fn write_words(file: &mut dyn Write, field: &str, words: &[u64]) -> Result<(), Error> {
+    writeln!(file, "\t{}: &[", field)?;
+    for chunk in words.chunks(4) {
+        let line: Vec<String> = chunk.iter().map(|w| format!("{:#018x}", w)).collect();
+        writeln!(file, "\t\t{},", line.join(", "))?;
+    }
+    Ok(writeln!(file, "\t],")?)
+}
+
+/// Sizes of a table of code points in every [`TableEncoding`], and the
+/// encoding chosen by the generator.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EncodingReport {
+    /// Number of code points in the table.
+    pub codepoints: usize,
+    /// Number of ranges of consecutive code points in the table.
+    pub ranges: usize,
+    /// Size in bytes of the table in each encoding.
+    pub sizes: Vec<(TableEncoding, usize)>,
+    /// Encoding chosen by the generator.
+    pub chosen: TableEncoding,
+}
+
+impl EncodingReport {
+    /// Computes the report for a table of code points.
+    pub fn new(codepoints: &[Codepoints]) -> Self {
+        let ranges = into_ranges(codepoints);
+        Self {
+            codepoints: ranges.iter().map(|(s, e)| (e - s + 1) as usize).sum(),
+            ranges: ranges.len(),
+            sizes: TableEncoding::ALL
+                .iter()
+                .map(|&encoding| {
+                    let table = CodepointTable::encode_ranges(ranges.clone(), encoding);
+                    (encoding, table.size())
+                })
+                .collect(),
+            chosen: CodepointTable::choose_encoding(&ranges),
+        }
+    }
+
+    /// Returns the size in bytes of the table in the chosen encoding.
+    pub fn chosen_size(&self) -> usize {
+        self.size(self.chosen)
+    }
+
+    /// Returns the size in bytes of the table in `encoding`.
+    pub fn size(&self, encoding: TableEncoding) -> usize {
+        self.sizes
+            .iter()
+            .find(|(e, _)| *e == encoding)
+            .map_or(0, |(_, size)| *size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generators::codepoint_set::*;
+    use ucd_parse::{Codepoint, CodepointRange};
+
+    fn codepoints(ranges: &[(u32, u32)]) -> Vec<Codepoints> {
+        ranges
+            .iter()
+            .map(|&(start, end)| {
+                Codepoints::Range(CodepointRange {
+                    start: Codepoint::from_u32(start).unwrap(),
+                    end: Codepoint::from_u32(end).unwrap(),
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn lookups() {
+        let cps = codepoints(&[(0x02, 0x04), (0x07, 0x07), (0x0a, 0x14), (0x1100, 0x115f)]);
+        for encoding in TableEncoding::ALL {
+            let table = CodepointTable::with_encoding(&cps, encoding);
+            assert_eq!(table.encoding(), encoding);
+            for cp in 0..0x2000 {
+                let expected = (0x02..=0x04).contains(&cp)
+                    || cp == 0x07
+                    || (0x0a..=0x14).contains(&cp)
+                    || (0x1100..=0x115f).contains(&cp);
+                assert_eq!(table.contains(cp), expected, "{} {:#06x}", encoding, cp);
+            }
+            assert!(!table.contains(0x10ffff));
+        }
+    }
+
+    #[test]
+    fn empty_table() {
+        for encoding in TableEncoding::ALL {
+            let table = CodepointTable::with_encoding(&[], encoding);
+            assert!(!table.contains(0));
+        }
+    }
+
+    #[test]
+    fn chosen_encodings() {
+        // A few wide ranges
+        let ranges = [(0x0000, 0x001f), (0x007f, 0x009f)];
+        assert_eq!(
+            CodepointTable::choose_encoding(&ranges),
+            TableEncoding::Ranges
+        );
+
+        // Dense and scattered code points
+        let ranges: Vec<(u32, u32)> = (0x0300..0x0400).step_by(3).map(|cp| (cp, cp)).collect();
+        assert_eq!(
+            CodepointTable::choose_encoding(&ranges),
+            TableEncoding::Bitset
+        );
+
+        // The same blocks repeated over the whole code point space
+        let ranges: Vec<(u32, u32)> = (0..0x110000)
+            .step_by(0x100)
+            .flat_map(|block| (0..64).step_by(2).map(move |cp| (block + cp, block + cp)))
+            .collect();
+        assert_eq!(
+            CodepointTable::choose_encoding(&ranges),
+            TableEncoding::Trie
+        );
+    }
+
+    #[test]
+    fn report() {
+        let report = EncodingReport::new(&codepoints(&[(0x41, 0x5a), (0x61, 0x7a)]));
+        assert_eq!(report.codepoints, 52);
+        assert_eq!(report.ranges, 2);
+        assert_eq!(report.size(TableEncoding::Ranges), 16);
+        assert_eq!(report.size(TableEncoding::Bitset), 8);
+        assert_eq!(report.size(TableEncoding::Trie), 2 * 2 + 2 * 8);
+        assert_eq!(report.chosen, TableEncoding::Bitset);
+        assert_eq!(report.chosen_size(), 8);
+    }
+
+    #[test]
+    fn generated_code() {
+        let cps = codepoints(&[(0x41, 0x5a)]);
+        let mut code = Vec::new();
+        CodepointTable::with_encoding(&cps, TableEncoding::Ranges)
+            .write(&mut code, "upper")
+            .unwrap();
+        CodepointTable::with_encoding(&cps, TableEncoding::Bitset)
+            .write(&mut code, "upper")
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(code).unwrap(),
+            "const UPPER: CodepointSet = CodepointSet::Ranges(&[\n\
+             \t(0x0041, 0x005a),\n\
+             ]);\n\
+             \n\
+             const UPPER: CodepointSet = CodepointSet::Bitset {\n\
+             \tstart: 0x0041,\n\
+             \tbits: &[\n\
+             \t\t0x0000000003ffffff,\n\
+             \t],\n\
+             };\n\
+             \n"
+        );
+    }
+}
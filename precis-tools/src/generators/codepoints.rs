@@ -1,6 +1,5 @@
 use crate::error::Error;
 use crate::generators::CodeGen;
-use std::fs::File;
 use std::io::Write;
 
 /// Generate the `Codepoints` `struct` used by all tables created by all
@@ -28,7 +27,7 @@ impl Default for CodepointsGen {
 }
 
 impl CodeGen for CodepointsGen {
-    fn generate_code(&mut self, file: &mut File) -> Result<(), Error> {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
         let template = include_str!("codepoints.template");
         Ok(writeln!(file, "{}", template)?)
     }
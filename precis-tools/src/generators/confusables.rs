@@ -0,0 +1,105 @@
+use crate::confusables_parser::Confusable;
+use crate::error::Error;
+use crate::file_writer;
+use crate::generators::ucd_generator::{UcdCodeGen, UcdLineParser};
+use crate::generators::CodeGen;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+
+/// Name of the file containing the confusable mappings
+const CONFUSABLES_FILE: &str = "confusables.txt";
+
+/// Generator that aggregates elements that are able to generate tables
+/// from the `confusables.txt` file defined in
+/// [`UTS #39`](https://www.unicode.org/reports/tr39/#Confusable_Detection).
+/// The file is read from the directory provided when the code is generated.
+pub struct ConfusablesGen {
+    generators: Vec<Box<dyn UcdLineParser<Confusable>>>,
+}
+
+impl ConfusablesGen {
+    /// Creates a new `ConfusablesGen` element.
+    pub fn new() -> Self {
+        Self {
+            generators: Vec::new(),
+        }
+    }
+
+    /// Add a new confusables parser
+    pub fn add(&mut self, gen: Box<dyn UcdLineParser<Confusable>>) {
+        self.generators.push(gen);
+    }
+}
+
+impl Default for ConfusablesGen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UcdCodeGen for ConfusablesGen {
+    fn parse_unicode_file(&mut self, path: &Path) -> Result<(), Error> {
+        let entries = Confusable::parse(path.join(CONFUSABLES_FILE))?;
+        for entry in entries.iter() {
+            let it = self.generators.iter_mut();
+            for gen in it {
+                gen.process_entry(entry)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CodeGen for ConfusablesGen {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
+        let it = self.generators.iter_mut();
+        for gen in it {
+            gen.generate_code(file)?;
+        }
+        Ok(())
+    }
+}
+
+/// Generator that creates a table with the prototype of each confusable
+/// code point. Each element of the table is a tuple containing the code
+/// point and the sequence of code points of its prototype.
+pub struct ConfusableMappingGen {
+    table_name: String,
+    mappings: HashMap<u32, Vec<u32>>,
+}
+
+impl ConfusableMappingGen {
+    /// Creates a new [`ConfusableMappingGen`]
+    /// # Arguments:
+    /// * `table_name` - The name of the generated table
+    pub fn new(table_name: &str) -> Self {
+        Self {
+            table_name: String::from(table_name),
+            mappings: HashMap::new(),
+        }
+    }
+}
+
+impl UcdLineParser<Confusable> for ConfusableMappingGen {
+    fn process_entry(&mut self, entry: &Confusable) -> Result<(), Error> {
+        let cp = entry.codepoint.value();
+        let prototype = entry.prototype.iter().map(|cp| cp.value()).collect();
+        match self.mappings.insert(cp, prototype) {
+            Some(_) => err!("Codepoint already processed {:#06x}", cp),
+            None => Ok(()),
+        }
+    }
+}
+
+impl CodeGen for ConfusableMappingGen {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
+        let mut vec: Vec<(u32, &[u32])> = self
+            .mappings
+            .iter()
+            .map(|(cp, prototype)| (*cp, prototype.as_slice()))
+            .collect();
+        vec.sort();
+        file_writer::generate_mapping_vector(file, &self.table_name, &vec)
+    }
+}
@@ -7,3 +7,20 @@ pub(crate) const ASCII7_END: u32 = 0x007E; // ~ (TILDE)
 
 // Canonical combining class for Virama characters
 pub(crate) const CANONICAL_COMBINING_CLASS_VIRAMA: u8 = 9;
+
+// Scripts used by the context rules, and the names of their tables
+pub(crate) const CONTEXT_SCRIPTS: [(&str, &str); 5] = [
+    ("Greek", "Greek"),
+    ("Hebrew", "Hebrew"),
+    ("Hiragana", "Hiragana"),
+    ("Katakana", "Katakana"),
+    ("Han", "Han"),
+];
+
+// Joining types used by the context rules, and the names of their tables
+pub(crate) const CONTEXT_JOINING_TYPES: [(&str, &str); 4] = [
+    ("D", "Dual_Joining"),
+    ("L", "Left_Joining"),
+    ("R", "Right_Joining"),
+    ("T", "Transparent"),
+];
@@ -1,6 +1,5 @@
 use crate::error::Error;
 use crate::generators::CodeGen;
-use std::fs::File;
 use std::io::Write;
 
 /// Generates the derived property `enum` with the
@@ -35,7 +34,7 @@ impl Default for DerivedPropertyValueGen {
 }
 
 impl CodeGen for DerivedPropertyValueGen {
-    fn generate_code(&mut self, file: &mut File) -> Result<(), Error> {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
         writeln!(file, "/// Derived property value")?;
         writeln!(file, "/// # Notes")?;
         writeln!(
@@ -0,0 +1,227 @@
+use crate::csv_parser::{CsvLineParser, DerivedProperties, DerivedProperty, PrecisDerivedProperty};
+use crate::error::Error;
+use crate::file_writer;
+use crate::generators::CodeGen;
+use crate::tables::PrecisTables;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use ucd_parse::Codepoints;
+
+// Last Unicode code point
+const LAST_CODEPOINT: u32 = 0x10FFFF;
+
+/// Splits the derived properties of a registry row into the values for the
+/// `IdentifierClass` and the `FreeformClass`.
+pub(crate) fn class_properties(
+    props: &DerivedProperties,
+) -> Result<(DerivedProperty, DerivedProperty), Error> {
+    match props {
+        DerivedProperties::Single(p @ DerivedProperty::PValid)
+        | DerivedProperties::Single(p @ DerivedProperty::ContextJ)
+        | DerivedProperties::Single(p @ DerivedProperty::ContextO)
+        | DerivedProperties::Single(p @ DerivedProperty::Disallowed)
+        | DerivedProperties::Single(p @ DerivedProperty::Unassigned) => Ok((*p, *p)),
+        DerivedProperties::Tuple((DerivedProperty::IdDis, DerivedProperty::FreePVal))
+        | DerivedProperties::Tuple((DerivedProperty::FreePVal, DerivedProperty::IdDis)) => {
+            Ok((DerivedProperty::IdDis, DerivedProperty::FreePVal))
+        }
+        _ => err!("unexpected derived property value: {}", props),
+    }
+}
+
+// Name of the `DerivedPropertyValue` variant
+fn value_name(prop: DerivedProperty) -> &'static str {
+    match prop {
+        DerivedProperty::PValid => "PValid",
+        DerivedProperty::FreePVal => "SpecClassPval",
+        DerivedProperty::ContextJ => "ContextJ",
+        DerivedProperty::ContextO => "ContextO",
+        DerivedProperty::Disallowed => "Disallowed",
+        DerivedProperty::IdDis => "SpecClassDis",
+        DerivedProperty::Unassigned => "Unassigned",
+    }
+}
+
+/// Returns the first and last code points of `cps`.
+pub(crate) fn codepoint_bounds(cps: &Codepoints) -> (u32, u32) {
+    match cps {
+        Codepoints::Single(cp) => (cp.value(), cp.value()),
+        Codepoints::Range(r) => (r.start.value(), r.end.value()),
+    }
+}
+
+/// Generates the table of derived property values straight from the
+/// `IANA` [`precis-tables.csv`](https://www.iana.org/assignments/precis-tables)
+/// registry file, so that the values used at runtime are literally the
+/// published ones. Each entry holds the value for the `IdentifierClass`
+/// and the value for the `FreeformClass`, in that order.
+/// The registry must be sorted by code point and cover the whole Unicode
+/// code point space, otherwise the generation fails.
+/// # Example:
+/// ```rust
+/// # enum Codepoints { Single(u32), Range(std::ops::RangeInclusive<u32>) }
+/// # enum DerivedPropertyValue { Disallowed, SpecClassDis, SpecClassPval }
+/// const DERIVED_PROPERTIES: &[(Codepoints, (DerivedPropertyValue, DerivedPropertyValue))] = &[
+///     (Codepoints::Range(std::ops::RangeInclusive::new(0x0000, 0x001f)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),
+///     (Codepoints::Single(0x0020), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),
+/// ];
+/// ```
+pub struct DerivedPropertyTableGen {
+    source: Source,
+    name: String,
+}
+
+// Rows of the table, read from the registry file or already computed
+enum Source {
+    Registry(PathBuf),
+    Rows(Vec<PrecisDerivedProperty>),
+}
+
+impl DerivedPropertyTableGen {
+    /// Creates a new table generator for the derived property values
+    /// # Arguments:
+    /// * `csv` - Path to the `IANA` `precis-tables.csv` file
+    /// * `name` - Name of the table
+    pub fn new<P: AsRef<Path>>(csv: P, name: &str) -> Self {
+        Self {
+            source: Source::Registry(csv.as_ref().to_path_buf()),
+            name: String::from(name),
+        }
+    }
+
+    /// Creates a new table generator for the derived property values
+    /// computed from a `UCD` directory, for versions of Unicode not
+    /// published in the registry.
+    /// # Arguments:
+    /// * `tables` - Derived property values of all the code points
+    /// * `name` - Name of the table
+    pub fn from_tables(tables: &PrecisTables, name: &str) -> Self {
+        Self {
+            source: Source::Rows(tables.rows().to_vec()),
+            name: String::from(name),
+        }
+    }
+
+    fn rows(&self) -> Result<(String, Vec<PrecisDerivedProperty>), Error> {
+        match &self.source {
+            Source::Registry(csv) => {
+                let parser: CsvLineParser<File, PrecisDerivedProperty> =
+                    CsvLineParser::from_path(csv)?;
+                let rows = parser.collect::<Result<_, Error>>()?;
+                Ok((csv.display().to_string(), rows))
+            }
+            Source::Rows(rows) => Ok((self.name.clone(), rows.clone())),
+        }
+    }
+}
+
+impl CodeGen for DerivedPropertyTableGen {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
+        let (origin, table) = self.rows()?;
+
+        let mut rows = Vec::new();
+        let mut next = 0;
+        for row in table {
+            let (start, end) = codepoint_bounds(&row.codepoints);
+            if start != next {
+                return err!(
+                    "{}: expected code point {:04X}, found {:04X}",
+                    origin,
+                    next,
+                    start
+                );
+            }
+            let (id, free) = class_properties(&row.properties)?;
+            rows.push((row.codepoints, value_name(id), value_name(free)));
+            next = end + 1;
+        }
+        if next != LAST_CODEPOINT + 1 {
+            return err!("{}: code points from {:04X} are missing", origin, next);
+        }
+
+        writeln!(
+            file,
+            "const {}: &[(Codepoints, (DerivedPropertyValue, DerivedPropertyValue))] = &[",
+            self.name.to_uppercase()
+        )?;
+        for (cps, id, free) in rows.iter() {
+            writeln!(
+                file,
+                "\t({}, (DerivedPropertyValue::{}, DerivedPropertyValue::{})),",
+                file_writer::generate_codepoint_str(cps),
+                id,
+                free
+            )?;
+        }
+        writeln!(file, "];")?;
+        Ok(writeln!(file)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generators::derived_property_table::*;
+    use crate::test_utils::TempDir;
+
+    fn generate(name: &str, csv: &str) -> Result<String, Error> {
+        let dir = TempDir::new(&format!("derived-property-{}", name));
+        let csv_path = dir.write("precis-tables.csv", csv);
+
+        let mut code = Vec::new();
+        DerivedPropertyTableGen::new(csv_path, "derived_properties")
+            .generate_code(&mut code)
+            .map(|_| String::from_utf8(code).unwrap())
+    }
+
+    #[test]
+    fn derived_property_table() {
+        let csv = "Codepoint,Property,Description\r\n\
+                   0000-001F,DISALLOWED,NULL..UNIT SEPARATOR\r\n\
+                   0020,ID_DIS or FREE_PVAL,SPACE\r\n\
+                   0021-10FFFF,UNASSIGNED,<RESERVED>..<RESERVED>\r\n";
+        let code = generate("table", csv).unwrap();
+        let lines: Vec<&str> = code.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "const DERIVED_PROPERTIES: &[(Codepoints, (DerivedPropertyValue, DerivedPropertyValue))] = &[",
+                "\t(Codepoints::Range(std::ops::RangeInclusive::new(0x0000, 0x001f)), (DerivedPropertyValue::Disallowed, DerivedPropertyValue::Disallowed)),",
+                "\t(Codepoints::Single(0x0020), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),",
+                "\t(Codepoints::Range(std::ops::RangeInclusive::new(0x0021, 0x10ffff)), (DerivedPropertyValue::Unassigned, DerivedPropertyValue::Unassigned)),",
+                "];",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn incomplete_table() {
+        // Gap between 0000 and 0002
+        let csv = "Codepoint,Property,Description\r\n\
+                   0000,DISALLOWED,NULL\r\n\
+                   0002-10FFFF,UNASSIGNED,<RESERVED>..<RESERVED>\r\n";
+        assert!(generate("gap", csv).is_err());
+
+        // Last code points missing
+        let csv = "Codepoint,Property,Description\r\n\
+                   0000-FFFF,DISALLOWED,NULL..<NOT A CHARACTER>\r\n";
+        assert!(generate("missing", csv).is_err());
+
+        // Rows not sorted by code point
+        let csv = "Codepoint,Property,Description\r\n\
+                   0001-10FFFF,UNASSIGNED,<RESERVED>..<RESERVED>\r\n\
+                   0000,DISALLOWED,NULL\r\n";
+        assert!(generate("unsorted", csv).is_err());
+
+        // Malformed row
+        let csv = "Codepoint,Property,Description\r\n\
+                   0000-10FFFF,PVALID or,NULL..<NOT A CHARACTER>\r\n";
+        assert!(generate("malformed", csv).is_err());
+
+        // Values not defined for string classes
+        let csv = "Codepoint,Property,Description\r\n\
+                   0000-10FFFF,FREE_PVAL,NULL..<NOT A CHARACTER>\r\n";
+        assert!(generate("value", csv).is_err());
+    }
+}
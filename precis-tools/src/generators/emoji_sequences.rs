@@ -0,0 +1,106 @@
+use crate::emoji_test_parser::EmojiTestEntry;
+use crate::error::Error;
+use crate::file_writer;
+use crate::generators::ucd_generator::UcdCodeGen;
+use crate::generators::CodeGen;
+use std::collections::BTreeSet;
+use std::io::Write;
+use std::path::Path;
+
+/// Name of the file listing every emoji recommended for general
+/// interchange (`RGI`) with its qualification status
+const EMOJI_TEST_FILE: &str = "emoji-test.txt";
+
+/// Status of the `RGI` emoji in the `emoji-test.txt` file
+const FULLY_QUALIFIED: &str = "fully-qualified";
+
+/// ZERO WIDTH JOINER
+const ZWJ: u32 = 0x200d;
+
+/// Generator that creates a table with the `RGI` emoji `ZWJ` sequences
+/// defined in [`UTS #51`](https://www.unicode.org/reports/tr51/#def_rgi_emoji_zwj_sequence).
+/// The sequences are the fully-qualified entries of the `emoji-test.txt`
+/// file that contain a `ZWJ`, which are the same ones listed as
+/// `RGI_Emoji_ZWJ_Sequence` in `emoji-zwj-sequences.txt`.
+/// The file is read from the directory provided when the code is generated.
+/// Each element of the table is the sequence of code points of an emoji,
+/// and the table is sorted so it can be binary searched.
+pub struct EmojiZwjSequencesGen {
+    table_name: String,
+    sequences: BTreeSet<Vec<u32>>,
+}
+
+impl EmojiZwjSequencesGen {
+    /// Creates a new [`EmojiZwjSequencesGen`]
+    /// # Arguments:
+    /// * `table_name` - The name of the generated table
+    pub fn new(table_name: &str) -> Self {
+        Self {
+            table_name: String::from(table_name),
+            sequences: BTreeSet::new(),
+        }
+    }
+}
+
+impl UcdCodeGen for EmojiZwjSequencesGen {
+    fn parse_unicode_file(&mut self, path: &Path) -> Result<(), Error> {
+        let entries = EmojiTestEntry::parse(path.join(EMOJI_TEST_FILE))?;
+        for entry in entries.iter().filter(|e| e.status == FULLY_QUALIFIED) {
+            let sequence: Vec<u32> = entry.codepoints.iter().map(|cp| cp.value()).collect();
+            if !sequence.contains(&ZWJ) {
+                continue;
+            }
+            if !self.sequences.insert(sequence) {
+                return err!("Sequence already processed: {}", entry.name);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CodeGen for EmojiZwjSequencesGen {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
+        let vec: Vec<&[u32]> = self.sequences.iter().map(Vec::as_slice).collect();
+        file_writer::generate_sequence_vector(file, &self.table_name, &vec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generators::emoji_sequences::*;
+    use crate::test_utils::TempDir;
+
+    #[test]
+    fn rgi_emoji_zwj_sequences() {
+        let dir = TempDir::new("emoji-zwj-sequences");
+        dir.write(
+            EMOJI_TEST_FILE,
+            "# emoji-test.txt\n\
+             1F469 200D 1F4BB ; fully-qualified # 👩‍💻 E4.0 woman technologist\n\
+             1F3F3 FE0F 200D 1F308 ; fully-qualified # 🏳️‍🌈 E4.0 rainbow flag\n\
+             1F3F3 200D 1F308 ; minimally-qualified # 🏳‍🌈 E4.0 rainbow flag\n\
+             1F355 ; fully-qualified # 🍕 E0.6 pizza\n",
+        );
+
+        let mut gen = EmojiZwjSequencesGen::new("emoji_zwj_sequences");
+        gen.parse_unicode_file(&dir).unwrap();
+        let mut code = Vec::new();
+        gen.generate_code(&mut code).unwrap();
+        assert_eq!(
+            String::from_utf8(code).unwrap(),
+            "static EMOJI_ZWJ_SEQUENCES: [&[u32]; 2] = [\n\
+             \t&[0x1f3f3, 0xfe0f, 0x200d, 0x1f308],\n\
+             \t&[0x1f469, 0x200d, 0x1f4bb],\n\
+             ];\n\n"
+        );
+
+        // Sequences are listed once
+        dir.write(
+            EMOJI_TEST_FILE,
+            "1F469 200D 1F4BB ; fully-qualified # 👩‍💻 E4.0 woman technologist\n\
+             1F469 200D 1F4BB ; fully-qualified # 👩‍💻 E4.0 woman technologist\n",
+        );
+        let mut gen = EmojiZwjSequencesGen::new("emoji_zwj_sequences");
+        assert!(gen.parse_unicode_file(&dir).is_err());
+    }
+}
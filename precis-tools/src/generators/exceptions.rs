@@ -1,8 +1,54 @@
+use crate::csv_parser::DerivedProperty;
 use crate::error::Error;
 use crate::generators::CodeGen;
-use std::fs::File;
 use std::io::Write;
 
+/// Code points in the [Exceptions](https://datatracker.ietf.org/doc/html/rfc8264#section-9.6)
+/// group and their derived property values.
+pub(crate) const EXCEPTIONS: [(u32, DerivedProperty); 41] = [
+    (0x00B7, DerivedProperty::ContextO),
+    (0x00DF, DerivedProperty::PValid),
+    (0x0375, DerivedProperty::ContextO),
+    (0x03C2, DerivedProperty::PValid),
+    (0x05F3, DerivedProperty::ContextO),
+    (0x05F4, DerivedProperty::ContextO),
+    (0x0640, DerivedProperty::Disallowed),
+    (0x0660, DerivedProperty::ContextO),
+    (0x0661, DerivedProperty::ContextO),
+    (0x0662, DerivedProperty::ContextO),
+    (0x0663, DerivedProperty::ContextO),
+    (0x0664, DerivedProperty::ContextO),
+    (0x0665, DerivedProperty::ContextO),
+    (0x0666, DerivedProperty::ContextO),
+    (0x0667, DerivedProperty::ContextO),
+    (0x0668, DerivedProperty::ContextO),
+    (0x0669, DerivedProperty::ContextO),
+    (0x06F0, DerivedProperty::ContextO),
+    (0x06F1, DerivedProperty::ContextO),
+    (0x06F2, DerivedProperty::ContextO),
+    (0x06F3, DerivedProperty::ContextO),
+    (0x06F4, DerivedProperty::ContextO),
+    (0x06F5, DerivedProperty::ContextO),
+    (0x06F6, DerivedProperty::ContextO),
+    (0x06F7, DerivedProperty::ContextO),
+    (0x06F8, DerivedProperty::ContextO),
+    (0x06F9, DerivedProperty::ContextO),
+    (0x06FD, DerivedProperty::PValid),
+    (0x06FE, DerivedProperty::PValid),
+    (0x07FA, DerivedProperty::Disallowed),
+    (0x0F0B, DerivedProperty::PValid),
+    (0x3007, DerivedProperty::PValid),
+    (0x302E, DerivedProperty::Disallowed),
+    (0x302F, DerivedProperty::Disallowed),
+    (0x3031, DerivedProperty::Disallowed),
+    (0x3032, DerivedProperty::Disallowed),
+    (0x3033, DerivedProperty::Disallowed),
+    (0x3034, DerivedProperty::Disallowed),
+    (0x3035, DerivedProperty::Disallowed),
+    (0x303B, DerivedProperty::Disallowed),
+    (0x30FB, DerivedProperty::ContextO),
+];
+
 /// Generates the [Exceptions](https://datatracker.ietf.org/doc/html/rfc8264#section-9.6)
 /// table required by the PRECIS framework.
 pub struct ExceptionsGen {}
@@ -21,177 +67,18 @@ impl Default for ExceptionsGen {
 }
 
 impl CodeGen for ExceptionsGen {
-    fn generate_code(&mut self, file: &mut File) -> Result<(), Error> {
-        writeln!(
-            file,
-            "static EXCEPTIONS: [(Codepoints, DerivedPropertyValue); 41] = [",
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x00B7), DerivedPropertyValue::ContextO),"
-        )?;
-
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x00DF), DerivedPropertyValue::PValid),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x0375), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x03C2), DerivedPropertyValue::PValid),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x05F3), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x05F4), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x0640), DerivedPropertyValue::Disallowed),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x0660), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x0661), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x0662), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x0663), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x0664), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x0665), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x0666), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x0667), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x0668), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x0669), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x06F0), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x06F1), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x06F2), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x06F3), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x06F4), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x06F5), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x06F6), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x06F7), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x06F8), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x06F9), DerivedPropertyValue::ContextO),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x06FD), DerivedPropertyValue::PValid),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x06FE), DerivedPropertyValue::PValid),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x07FA), DerivedPropertyValue::Disallowed),"
-        )?;
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
         writeln!(
             file,
-            "\t(Codepoints::Single(0x0F0B), DerivedPropertyValue::PValid),"
+            "const EXCEPTIONS: &[(Codepoints, DerivedPropertyValue)] = &["
         )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x3007), DerivedPropertyValue::PValid),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x302E), DerivedPropertyValue::Disallowed),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x302F), DerivedPropertyValue::Disallowed),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x3031), DerivedPropertyValue::Disallowed),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x3032), DerivedPropertyValue::Disallowed),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x3033), DerivedPropertyValue::Disallowed),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x3034), DerivedPropertyValue::Disallowed),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x3035), DerivedPropertyValue::Disallowed),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x303B), DerivedPropertyValue::Disallowed),"
-        )?;
-        writeln!(
-            file,
-            "\t(Codepoints::Single(0x30FB), DerivedPropertyValue::ContextO),"
-        )?;
-
+        for (cp, value) in EXCEPTIONS.iter() {
+            writeln!(
+                file,
+                "\t(Codepoints::Single(0x{:04X}), DerivedPropertyValue::{:?}),",
+                cp, value
+            )?;
+        }
         writeln!(file, "];")?;
         Ok(writeln!(file)?)
     }
@@ -0,0 +1,174 @@
+use crate::file_writer;
+use crate::{CodeGen, Error, Ucd, UcdCodeGen};
+use std::collections::{BTreeSet, HashMap};
+use std::io::Write;
+use std::path::Path;
+use ucd_parse::{Codepoints, PropertyValueAlias, Script, ScriptExtension};
+
+// Value of the `Script` property for code points not listed in `Scripts.txt`
+const UNKNOWN_SCRIPT: &str = "Unknown";
+
+fn codepoints_start(cps: &Codepoints) -> u32 {
+    match cps {
+        Codepoints::Single(cp) => cp.value(),
+        Codepoints::Range(r) => r.start.value(),
+    }
+}
+
+// Script names in the `UCD` files use underscores, e.g. `Old_Italic`,
+// let's follow the rust naming rules for `enum` variants
+fn variant_name(script: &str) -> String {
+    script.replace('_', "")
+}
+
+/// Generates the tables of the Unicode character properties
+/// [`Script`](http://www.unicode.org/reports/tr24/) and `Script_Extensions`
+/// from the files `Scripts.txt`, `ScriptExtensions.txt` and
+/// `PropertyValueAliases.txt`. Generated code contains a `Script` `enum`,
+/// a table of tuples (`Codepoints`, `Script`) and a table of tuples
+/// (`Codepoints`, `&[Script]`) with the code points whose `Script_Extensions`
+/// value is not the same as their `Script` value. Both tables are sorted
+/// by code point.
+pub struct ScriptsGen {
+    scripts_table: String,
+    extensions_table: String,
+    names: BTreeSet<String>,
+    short_names: HashMap<String, String>,
+    scripts: Vec<(Codepoints, String)>,
+    extensions: Vec<(Codepoints, Vec<String>)>,
+}
+
+impl ScriptsGen {
+    /// Creates a new [`ScriptsGen`]
+    /// # Arguments:
+    /// * `scripts_table` - The name of the `Script` table
+    /// * `extensions_table` - The name of the `Script_Extensions` table
+    pub fn new(scripts_table: &str, extensions_table: &str) -> Self {
+        Self {
+            scripts_table: String::from(scripts_table),
+            extensions_table: String::from(extensions_table),
+            names: BTreeSet::new(),
+            short_names: HashMap::new(),
+            scripts: Vec::new(),
+            extensions: Vec::new(),
+        }
+    }
+
+    fn generate_script_enum(&self, file: &mut dyn Write) -> Result<(), Error> {
+        writeln!(
+            file,
+            "/// Represents values of the Unicode character property"
+        )?;
+        writeln!(
+            file,
+            "/// [Script](http://www.unicode.org/reports/tr24/). Code points not"
+        )?;
+        writeln!(file, "/// assigned to any script have the value `Unknown`.")?;
+        writeln!(
+            file,
+            "#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]"
+        )?;
+        writeln!(file, "pub enum Script {{")?;
+        for name in self.names.iter() {
+            match self.short_names.get(name) {
+                Some(short) => writeln!(file, "\t/// `{}` (`{}`)", name, short)?,
+                None => writeln!(file, "\t/// `{}`", name)?,
+            }
+            writeln!(file, "\t{},", variant_name(name))?;
+        }
+        writeln!(file, "}}")?;
+        Ok(writeln!(file)?)
+    }
+
+    fn generate_scripts_table(&self, file: &mut dyn Write) -> Result<(), Error> {
+        writeln!(
+            file,
+            "static {}: [(Codepoints, Script); {}] = [",
+            self.scripts_table.to_uppercase(),
+            self.scripts.len()
+        )?;
+        for (cps, script) in self.scripts.iter() {
+            writeln!(
+                file,
+                "\t({}, Script::{}),",
+                file_writer::generate_codepoint_str(cps),
+                variant_name(script)
+            )?;
+        }
+        writeln!(file, "];")?;
+        Ok(writeln!(file)?)
+    }
+
+    fn generate_extensions_table(&self, file: &mut dyn Write) -> Result<(), Error> {
+        writeln!(
+            file,
+            "static {}: [(Codepoints, &[Script]); {}] = [",
+            self.extensions_table.to_uppercase(),
+            self.extensions.len()
+        )?;
+        for (cps, scripts) in self.extensions.iter() {
+            let scripts: Vec<String> = scripts
+                .iter()
+                .map(|s| format!("Script::{}", variant_name(s)))
+                .collect();
+            writeln!(
+                file,
+                "\t({}, &[{}]),",
+                file_writer::generate_codepoint_str(cps),
+                scripts.join(", ")
+            )?;
+        }
+        writeln!(file, "];")?;
+        Ok(writeln!(file)?)
+    }
+}
+
+impl UcdCodeGen for ScriptsGen {
+    fn parse_unicode_file(&mut self, ucd_path: &Path) -> Result<(), Error> {
+        self.parse_ucd(&Ucd::new(ucd_path))
+    }
+
+    fn parse_ucd(&mut self, ucd: &Ucd) -> Result<(), Error> {
+        // `Script_Extensions` values use the short names of the scripts
+        let aliases = ucd.parse::<PropertyValueAlias>()?;
+        let mut long_names = HashMap::new();
+        for alias in aliases.iter().filter(|alias| alias.property == "sc") {
+            long_names.insert(alias.abbreviation.clone(), alias.long.clone());
+            self.short_names
+                .insert(alias.long.clone(), alias.abbreviation.clone());
+        }
+
+        let scripts = ucd.parse::<Script>()?;
+        self.names.insert(String::from(UNKNOWN_SCRIPT));
+        for line in scripts.iter() {
+            self.names.insert(line.script.clone());
+            self.scripts.push((line.codepoints, line.script.clone()));
+        }
+        self.scripts.sort_by_key(|(cps, _)| codepoints_start(cps));
+
+        let extensions = ucd.parse::<ScriptExtension>()?;
+        for line in extensions.iter() {
+            let mut scripts = Vec::new();
+            for name in line.scripts.iter() {
+                match long_names.get(name) {
+                    Some(long) if self.names.contains(long) => scripts.push(long.clone()),
+                    _ => return err!("Unknown script {} in Script_Extensions", name),
+                }
+            }
+            scripts.sort();
+            self.extensions.push((line.codepoints, scripts));
+        }
+        self.extensions
+            .sort_by_key(|(cps, _)| codepoints_start(cps));
+
+        Ok(())
+    }
+}
+
+impl CodeGen for ScriptsGen {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
+        self.generate_script_enum(file)?;
+        self.generate_scripts_table(file)?;
+        self.generate_extensions_table(file)
+    }
+}
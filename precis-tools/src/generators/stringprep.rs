@@ -0,0 +1,129 @@
+use crate::error::Error;
+use crate::file_writer;
+use crate::generators::ucd_generator::{UcdCodeGen, UcdLineParser};
+use crate::generators::CodeGen;
+use crate::stringprep_parser::StringprepEntry;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use ucd_parse::Codepoints;
+
+/// Name of the file containing the `stringprep` tables
+const STRINGPREP_TABLES_FILE: &str = "rfc3454.txt";
+
+/// Generator that aggregates elements that are able to generate tables
+/// from the appendices of [`rfc3454`](https://datatracker.ietf.org/doc/html/rfc3454).
+/// Tables are read from the file `rfc3454.txt` located in the directory
+/// provided when the code is generated.
+pub struct StringprepGen {
+    generators: Vec<Box<dyn UcdLineParser<StringprepEntry>>>,
+}
+
+impl StringprepGen {
+    /// Creates a new `StringprepGen` element.
+    pub fn new() -> Self {
+        Self {
+            generators: Vec::new(),
+        }
+    }
+
+    /// Add a new `stringprep` table parser
+    pub fn add(&mut self, gen: Box<dyn UcdLineParser<StringprepEntry>>) {
+        self.generators.push(gen);
+    }
+}
+
+impl Default for StringprepGen {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UcdCodeGen for StringprepGen {
+    fn parse_unicode_file(&mut self, ucd_path: &Path) -> Result<(), Error> {
+        let entries = StringprepEntry::parse(ucd_path.join(STRINGPREP_TABLES_FILE))?;
+        for entry in entries.iter() {
+            let it = self.generators.iter_mut();
+            for gen in it {
+                gen.process_entry(entry)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CodeGen for StringprepGen {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
+        let it = self.generators.iter_mut();
+        for gen in it {
+            gen.generate_code(file)?;
+        }
+        Ok(())
+    }
+}
+
+/// Generator that creates a table of mappings defined in
+/// one of the `stringprep` tables of appendix B. Each element
+/// of the table is a tuple containing the code point and the
+/// sequence of code points it maps to.
+pub struct StringprepMappingGen {
+    table: String,
+    table_name: String,
+    mappings: HashMap<u32, Vec<u32>>,
+}
+
+impl StringprepMappingGen {
+    /// Creates a new [`StringprepMappingGen`]
+    /// # Arguments:
+    /// * `table` - The `stringprep` table identifier, e.g. `B.2`
+    /// * `table_name` - The name of the generated table
+    pub fn new(table: &str, table_name: &str) -> Self {
+        Self {
+            table: String::from(table),
+            table_name: String::from(table_name),
+            mappings: HashMap::new(),
+        }
+    }
+
+    fn insert(&mut self, cp: u32, mapping: &[u32]) -> Result<(), Error> {
+        match self.mappings.insert(cp, mapping.to_vec()) {
+            Some(_) => err!("Codepoint already processed {:#06x}", cp),
+            None => Ok(()),
+        }
+    }
+}
+
+impl UcdLineParser<StringprepEntry> for StringprepMappingGen {
+    fn process_entry(&mut self, entry: &StringprepEntry) -> Result<(), Error> {
+        if self.table != entry.table {
+            return Ok(());
+        }
+
+        let Some(ref mapping) = entry.mapping else {
+            return err!("Table {} does not contain mappings", entry.table);
+        };
+        let mapping: Vec<u32> = mapping.iter().map(|cp| cp.value()).collect();
+
+        match entry.codepoints {
+            Codepoints::Single(cp) => self.insert(cp.value(), &mapping),
+            Codepoints::Range(r) => {
+                for cp in r.start.value()..=r.end.value() {
+                    self.insert(cp, &mapping)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl CodeGen for StringprepMappingGen {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
+        let mut vec: Vec<(u32, &[u32])> = self
+            .mappings
+            .iter()
+            .map(|(cp, mapping)| (*cp, mapping.as_slice()))
+            .collect();
+        vec.sort();
+        file_writer::generate_mapping_vector(file, &self.table_name, &vec)
+    }
+}
@@ -3,43 +3,43 @@ use crate::error::Error;
 use crate::file_writer;
 use crate::generators::constants::CANONICAL_COMBINING_CLASS_VIRAMA;
 use crate::generators::CodeGen;
+use crate::stringprep_parser::StringprepEntry;
+use crate::ucd::Ucd;
 use crate::ucd_parsers;
 use std::collections::HashSet;
-use std::fs::File;
-use std::path::{Path, PathBuf};
+use std::io::Write;
+use std::path::Path;
 use ucd_parse::Codepoints;
 use ucd_parse::CoreProperty;
+use ucd_parse::EmojiProperty;
 use ucd_parse::Property;
 use ucd_parse::Script;
 use ucd_parse::UnicodeDataDecompositionTag;
 use ucd_parsers::DerivedJoiningType;
 use ucd_parsers::HangulSyllableType;
-
-fn parse_unicode_file<U: ucd_parse::UcdFile, F>(path: &Path, mut f: F) -> Result<(), Error>
-where
-    F: FnMut(&U) -> Result<(), Error>,
-{
-    let lines: Vec<U> = ucd_parse::parse(path)?;
-    for line in lines.iter() {
-        f(line)?;
-    }
-    Ok(())
-}
+use unicode_normalization::UnicodeNormalization;
 
 /// Generator that aggregates other [`UcdCodeGen`] elements.
 pub struct UcdFileGen {
-    ucd_path: PathBuf,
+    ucd: Ucd,
     generators: Vec<Box<dyn UcdCodeGen>>,
 }
 
 impl UcdFileGen {
     /// Creates a new `UcdFileGen` element.
     /// # Arguments:
-    /// `path` - path where `UCD` files are stored
+    /// `path` - path where `UCD` files are stored, either a directory or a
+    /// `UCD.zip` archive
     pub fn new<P: AsRef<Path>>(path: P) -> Self {
-        let path = path.as_ref();
+        Self::from_ucd(&Ucd::new(path))
+    }
+
+    /// Creates a new `UcdFileGen` element that reads the `UCD` files from
+    /// `ucd`. Files already parsed by other users of `ucd` are not parsed
+    /// again.
+    pub fn from_ucd(ucd: &Ucd) -> Self {
         Self {
-            ucd_path: path.to_path_buf(),
+            ucd: ucd.clone(),
             generators: Vec::new(),
         }
     }
@@ -51,10 +51,10 @@ impl UcdFileGen {
 }
 
 impl CodeGen for UcdFileGen {
-    fn generate_code(&mut self, file: &mut File) -> Result<(), Error> {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
         let it = self.generators.iter_mut();
         for gen in it {
-            gen.parse_unicode_file(&self.ucd_path)?;
+            gen.parse_ucd(&self.ucd)?;
             gen.generate_code(file)?;
         }
         Ok(())
@@ -67,6 +67,13 @@ pub trait UcdCodeGen: CodeGen {
     /// # Arguments:
     /// `ucd_path` - Path where `UCD` file is stored.
     fn parse_unicode_file(&mut self, ucd_path: &Path) -> Result<(), Error>;
+
+    /// Parses the `UCD` files from an in-memory [`Ucd`] database. The default
+    /// implementation reads the files from the directory of `ucd`, elements
+    /// that use the files cached by [`Ucd`] override it.
+    fn parse_ucd(&mut self, ucd: &Ucd) -> Result<(), Error> {
+        self.parse_unicode_file(ucd.path())
+    }
 }
 
 /// Generic trait used by parsers to generate code.
@@ -96,8 +103,15 @@ impl UcdTableGen {
     }
 }
 
+impl UcdTableGen {
+    /// Returns the table of code points merged into ranges
+    pub(crate) fn table(&self) -> Vec<Codepoints> {
+        common::get_codepoints_vector(&self.cps)
+    }
+}
+
 impl CodeGen for UcdTableGen {
-    fn generate_code(&mut self, file: &mut File) -> Result<(), Error> {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
         file_writer::generate_code_from_hashset(file, &self.table_name, &self.cps)
     }
 }
@@ -138,6 +152,18 @@ impl UcdLineParser<Property> for UcdTableGen {
     }
 }
 
+impl UcdLineParser<StringprepEntry> for UcdTableGen {
+    fn process_entry(&mut self, entry: &StringprepEntry) -> Result<(), Error> {
+        if self.name == entry.table {
+            match entry.codepoints {
+                Codepoints::Single(cp) => common::insert_codepoint(cp.value(), &mut self.cps)?,
+                Codepoints::Range(r) => common::insert_codepoint_range(&r, &mut self.cps)?,
+            }
+        }
+        Ok(())
+    }
+}
+
 impl UcdLineParser<CoreProperty> for UcdTableGen {
     fn process_entry(&mut self, line: &CoreProperty) -> Result<(), Error> {
         if self.name == line.property {
@@ -150,6 +176,18 @@ impl UcdLineParser<CoreProperty> for UcdTableGen {
     }
 }
 
+impl UcdLineParser<EmojiProperty> for UcdTableGen {
+    fn process_entry(&mut self, line: &EmojiProperty) -> Result<(), Error> {
+        if self.name == line.property {
+            match line.codepoints {
+                Codepoints::Single(cp) => common::insert_codepoint(cp.value(), &mut self.cps)?,
+                Codepoints::Range(r) => common::insert_codepoint_range(&r, &mut self.cps)?,
+            }
+        }
+        Ok(())
+    }
+}
+
 impl UcdLineParser<Script> for UcdTableGen {
     fn process_entry(&mut self, line: &Script) -> Result<(), Error> {
         if self.name == line.script {
@@ -199,20 +237,25 @@ impl<T: ucd_parse::UcdFile> Default for UnicodeGen<T> {
     }
 }
 
-impl<T: ucd_parse::UcdFile> UcdCodeGen for UnicodeGen<T> {
+impl<T: ucd_parse::UcdFile + 'static> UcdCodeGen for UnicodeGen<T> {
     fn parse_unicode_file(&mut self, ucd_path: &Path) -> Result<(), Error> {
-        parse_unicode_file(ucd_path, |line: &T| {
+        self.parse_ucd(&Ucd::new(ucd_path))
+    }
+
+    fn parse_ucd(&mut self, ucd: &Ucd) -> Result<(), Error> {
+        let lines = ucd.parse::<T>()?;
+        for line in lines.iter() {
             let it = self.generators.iter_mut();
             for gen in it {
                 gen.process_entry(line)?;
             }
-            Ok(())
-        })
+        }
+        Ok(())
     }
 }
 
 impl<T: ucd_parse::UcdFile> CodeGen for UnicodeGen<T> {
-    fn generate_code(&mut self, file: &mut File) -> Result<(), Error> {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
         let it = self.generators.iter_mut();
         for gen in it {
             gen.generate_code(file)?;
@@ -249,7 +292,11 @@ impl Default for GeneralCategoryGen {
 
 impl UcdCodeGen for GeneralCategoryGen {
     fn parse_unicode_file(&mut self, ucd_path: &Path) -> Result<(), Error> {
-        let cps: Vec<ucd_parsers::UnicodeData> = ucd_parsers::UnicodeData::parse(ucd_path)?;
+        self.parse_ucd(&Ucd::new(ucd_path))
+    }
+
+    fn parse_ucd(&mut self, ucd: &Ucd) -> Result<(), Error> {
+        let cps = ucd.unicode_data()?;
         for udata in cps.iter() {
             let it = self.generators.iter_mut();
             for gen in it {
@@ -261,7 +308,7 @@ impl UcdCodeGen for GeneralCategoryGen {
 }
 
 impl CodeGen for GeneralCategoryGen {
-    fn generate_code(&mut self, file: &mut File) -> Result<(), Error> {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
         let it = self.generators.iter_mut();
         for gen in it {
             gen.generate_code(file)?;
@@ -287,8 +334,15 @@ impl ViramaTableGen {
     }
 }
 
+impl ViramaTableGen {
+    /// Returns the table of code points merged into ranges
+    pub(crate) fn table(&self) -> Vec<Codepoints> {
+        common::get_codepoints_vector(&self.cps)
+    }
+}
+
 impl CodeGen for ViramaTableGen {
-    fn generate_code(&mut self, file: &mut File) -> Result<(), Error> {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
         file_writer::generate_code_from_hashset(file, &self.table_name, &self.cps)
     }
 }
@@ -311,6 +365,123 @@ impl UcdLineParser<ucd_parsers::UnicodeData> for ViramaTableGen {
     }
 }
 
+/// Generator that creates a table of the Unicode code points that are
+/// changed by Unicode Normalization Form KC, the `HasCompat` category of
+/// [`rfc8264`](https://datatracker.ietf.org/doc/html/rfc8264#section-9.17).
+/// Code points assigned in `UnicodeData.txt` are normalized with the data
+/// of the [`unicode-normalization`](https://docs.rs/unicode-normalization)
+/// crate. The normalization stability policy guarantees that the result
+/// does not change for code points assigned in older versions, but code
+/// points assigned in newer versions would be left out, so an error is
+/// returned if the `UCD` files are newer than the normalization data.
+pub struct HasCompatTableGen {
+    table_name: String,
+    cps: HashSet<u32>,
+}
+
+impl HasCompatTableGen {
+    /// Creates a new table generator for code points with compatibility mappings
+    pub fn new(table_name: &str) -> Self {
+        Self {
+            table_name: String::from(table_name),
+            cps: HashSet::new(),
+        }
+    }
+
+    fn insert_if_compat(&mut self, cp: u32) -> Result<(), Error> {
+        if let Some(c) = char::from_u32(cp) {
+            let mut normalized = c.nfkc();
+            if !matches!((normalized.next(), normalized.next()), (Some(nc), None) if nc == c) {
+                common::insert_codepoint(cp, &mut self.cps)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CodeGen for HasCompatTableGen {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
+        file_writer::generate_code_from_hashset(file, &self.table_name, &self.cps)
+    }
+}
+
+impl UcdCodeGen for HasCompatTableGen {
+    fn parse_unicode_file(&mut self, ucd_path: &Path) -> Result<(), Error> {
+        self.parse_ucd(&Ucd::new(ucd_path))
+    }
+
+    fn parse_ucd(&mut self, ucd: &Ucd) -> Result<(), Error> {
+        let version = ucd.version()?;
+        let (major, minor, patch) = unicode_normalization::UNICODE_VERSION;
+        let supported = (major as u64, minor as u64, patch as u64);
+        if version > supported {
+            return err!(
+                "Normalization data of Unicode {}.{}.{} is older than the UCD files of Unicode {}.{}.{}",
+                supported.0,
+                supported.1,
+                supported.2,
+                version.0,
+                version.1,
+                version.2
+            );
+        }
+
+        for udata in ucd.unicode_data()?.iter() {
+            match udata.codepoints {
+                Codepoints::Range(ref r) => {
+                    for cp in r.start.value()..=r.end.value() {
+                        self.insert_if_compat(cp)?;
+                    }
+                }
+                Codepoints::Single(ref cp) => self.insert_if_compat(cp.value())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Generator that creates a table of tuples (`u32`, `u32`) with the code
+/// points of the `UnicodeData.txt` file and their simple lowercase mapping.
+/// Code points without a simple lowercase mapping are not in the table.
+pub struct LowercaseMappingTableGen {
+    name: String,
+    vec: Vec<(u32, u32)>,
+}
+
+impl LowercaseMappingTableGen {
+    /// Creates a new lowercase mapping table generator
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: String::from(name),
+            vec: Vec::new(),
+        }
+    }
+}
+
+impl LowercaseMappingTableGen {
+    /// Returns the table of code points and their lowercase mappings
+    pub(crate) fn into_table(self) -> Vec<(u32, u32)> {
+        self.vec
+    }
+}
+
+impl UcdLineParser<ucd_parsers::UnicodeData> for LowercaseMappingTableGen {
+    fn process_entry(&mut self, udata: &ucd_parsers::UnicodeData) -> Result<(), Error> {
+        if let (Codepoints::Single(cp), Some(mapping)) =
+            (udata.codepoints, udata.simple_lowercase_mapping)
+        {
+            self.vec.push((cp.value(), mapping.value()));
+        }
+        Ok(())
+    }
+}
+
+impl CodeGen for LowercaseMappingTableGen {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
+        file_writer::generate_codepoint_mapping_vector(file, &self.name, &self.vec)
+    }
+}
+
 /// Generator that creates a table of Unicode code points
 /// and their decomposition mappings.
 pub struct WidthMappingTableGen {
@@ -328,6 +499,13 @@ impl WidthMappingTableGen {
     }
 }
 
+impl WidthMappingTableGen {
+    /// Returns the table of code points and their width mappings
+    pub(crate) fn into_table(self) -> Vec<(Codepoints, ucd_parse::Codepoint)> {
+        self.vec
+    }
+}
+
 impl UcdLineParser<ucd_parsers::UnicodeData> for WidthMappingTableGen {
     fn process_entry(&mut self, udata: &ucd_parsers::UnicodeData) -> Result<(), Error> {
         if udata.decomposition.len == 0 {
@@ -347,7 +525,7 @@ impl UcdLineParser<ucd_parsers::UnicodeData> for WidthMappingTableGen {
 }
 
 impl CodeGen for WidthMappingTableGen {
-    fn generate_code(&mut self, file: &mut File) -> Result<(), Error> {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
         file_writer::generate_width_mapping_vector(file, &self.name, &self.vec)
     }
 }
@@ -381,8 +559,9 @@ impl UcdLineParser<ucd_parsers::UnicodeData> for UnassignedTableGen {
                     self.range.end = ucd_parse::Codepoint::from_u32(r.start.value() - 1)?;
                     common::add_codepoints(&self.range, &mut self.vec);
                 }
-                self.range.start = ucd_parse::Codepoint::from_u32(r.end.value() + 1)?;
-                self.range.end = r.start;
+                let next_cp = ucd_parse::Codepoint::from_u32(r.end.value() + 1)?;
+                self.range.start = next_cp;
+                self.range.end = next_cp;
             }
             Codepoints::Single(ref cp) => {
                 let next_cp = ucd_parse::Codepoint::from_u32(cp.value() + 1)?;
@@ -400,7 +579,7 @@ impl UcdLineParser<ucd_parsers::UnicodeData> for UnassignedTableGen {
 }
 
 impl CodeGen for UnassignedTableGen {
-    fn generate_code(&mut self, file: &mut File) -> Result<(), Error> {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
         file_writer::generate_code_from_vec(file, &self.name, &self.vec)
     }
 }
@@ -1,7 +1,6 @@
 use crate::error::Error;
 use crate::generators::CodeGen;
 use regex::Regex;
-use std::fs::File;
 use std::io::Write;
 use std::sync::LazyLock;
 
@@ -20,7 +19,7 @@ impl UnicodeVersionGen {
     }
 }
 
-fn get_version(version: &str) -> Result<(u64, u64, u64), Error> {
+pub(crate) fn get_version(version: &str) -> Result<(u64, u64, u64), Error> {
     static VERSION_RX: LazyLock<Regex> =
         LazyLock::new(|| Regex::new(r"([0-9]+).([0-9]+).([0-9]+)").unwrap());
 
@@ -48,7 +47,7 @@ fn get_version(version: &str) -> Result<(u64, u64, u64), Error> {
 }
 
 impl CodeGen for UnicodeVersionGen {
-    fn generate_code(&mut self, file: &mut File) -> Result<(), Error> {
+    fn generate_code(&mut self, file: &mut dyn Write) -> Result<(), Error> {
         let (major, minor, patch) = get_version(&self.version)?;
         writeln!(
             file,
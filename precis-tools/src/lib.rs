@@ -5,20 +5,34 @@
 
 #![deny(missing_docs)]
 
+pub use crate::confusables_parser::Confusable;
+pub use crate::emoji_test_parser::EmojiTestEntry;
 pub use crate::generators::ascii7::Ascii7Gen;
 pub use crate::generators::backward_compatible::BackwardCompatibleGen;
 pub use crate::generators::bidi_class::BidiClassGen;
+pub use crate::generators::codepoint_set::{
+    CodepointSetGen, CodepointTable, EncodingReport, TableEncoding,
+};
 pub use crate::generators::codepoints::CodepointsGen;
+pub use crate::generators::confusables::{ConfusableMappingGen, ConfusablesGen};
 pub use crate::generators::derived_property::DerivedPropertyValueGen;
+pub use crate::generators::derived_property_table::DerivedPropertyTableGen;
+pub use crate::generators::emoji_sequences::EmojiZwjSequencesGen;
 pub use crate::generators::exceptions::ExceptionsGen;
+pub use crate::generators::scripts::ScriptsGen;
+pub use crate::generators::stringprep::{StringprepGen, StringprepMappingGen};
 pub use crate::generators::ucd_generator::{
-    GeneralCategoryGen, UcdCodeGen, UcdFileGen, UcdLineParser, UcdTableGen, UnassignedTableGen,
-    UnicodeGen, ViramaTableGen, WidthMappingTableGen,
+    GeneralCategoryGen, HasCompatTableGen, LowercaseMappingTableGen, UcdCodeGen, UcdFileGen,
+    UcdLineParser, UcdTableGen, UnassignedTableGen, UnicodeGen, ViramaTableGen,
+    WidthMappingTableGen,
 };
 pub use crate::generators::unicode_version::UnicodeVersionGen;
 pub use crate::generators::{CodeGen, RustCodeGen};
+pub use crate::stringprep_parser::StringprepEntry;
+pub use crate::ucd_parsers::DerivedBidiClass;
 pub use crate::ucd_parsers::DerivedJoiningType;
 pub use crate::ucd_parsers::HangulSyllableType;
+pub use crate::ucd_parsers::NormalizationProperty;
 pub use crate::ucd_parsers::UnicodeData;
 
 pub use crate::csv_parser::{
@@ -27,8 +41,11 @@ pub use crate::csv_parser::{
 
 pub use crate::error::Error;
 
-#[cfg(feature = "networking")]
-pub mod download;
+pub use crate::export::TableExporter;
+pub use crate::tablegen::{OutputFormat, Table, TableGen};
+pub use crate::tables::PrecisTables;
+pub use crate::ucd::Ucd;
+pub use crate::upgrade::{CodepointProperties, PrecisProperties, PropertyChange, UpgradeReport};
 
 macro_rules! err {
     ($($tt:tt)*) => {
@@ -36,9 +53,22 @@ macro_rules! err {
     }
 }
 
+#[cfg(feature = "networking")]
+pub mod download;
+
 mod common;
+mod confusables_parser;
 mod csv_parser;
+mod emoji_test_parser;
 mod error;
+mod export;
 mod file_writer;
 mod generators;
+mod stringprep_parser;
+mod tablegen;
+mod tables;
+#[cfg(test)]
+mod test_utils;
+mod ucd;
 mod ucd_parsers;
+mod upgrade;
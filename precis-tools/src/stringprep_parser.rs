@@ -0,0 +1,227 @@
+use crate::Error;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use ucd_parse::{Codepoint, CodepointRange, Codepoints};
+
+const TABLE_START_PREFIX: &str = "----- Start Table ";
+const TABLE_END_PREFIX: &str = "----- End Table ";
+const TABLE_MARKER_SUFFIX: &str = " -----";
+
+// Tables in appendix B contain mappings, the rest of them
+// only list code points followed by an optional comment
+const MAPPING_TABLES_PREFIX: &str = "B.";
+
+/// A single row in one of the tables defined in the appendices of
+/// [`rfc3454`](https://datatracker.ietf.org/doc/html/rfc3454).
+/// Tables are delimited by `----- Start Table <id> -----` and
+/// `----- End Table <id> -----` markers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StringprepEntry {
+    /// Identifier of the table this row belongs to, e.g. `A.1` or `C.2.2`
+    pub table: String,
+    /// The code points corresponding to this row.
+    pub codepoints: Codepoints,
+    /// The mapping of the code points, only present in tables of
+    /// appendix B. An empty vector means the code points are mapped
+    /// to nothing.
+    pub mapping: Option<Vec<Codepoint>>,
+}
+
+fn parse_codepoints(s: &str) -> Result<Codepoints, Error> {
+    match s.split_once('-') {
+        Some((start, end)) => {
+            let start: Codepoint = start.trim().parse()?;
+            let end: Codepoint = end.trim().parse()?;
+            if start.value() > end.value() {
+                return err!("invalid codepoint range: '{}'", s);
+            }
+            Ok(Codepoints::Range(CodepointRange { start, end }))
+        }
+        None => Ok(Codepoints::Single(s.trim().parse()?)),
+    }
+}
+
+fn parse_mapping(s: &str) -> Result<Vec<Codepoint>, Error> {
+    let mut mapping = Vec::new();
+    for cp in s.split_whitespace() {
+        mapping.push(cp.parse()?);
+    }
+    Ok(mapping)
+}
+
+fn parse_table_marker<'a>(line: &'a str, prefix: &str) -> Option<&'a str> {
+    line.strip_prefix(prefix)?.strip_suffix(TABLE_MARKER_SUFFIX)
+}
+
+impl StringprepEntry {
+    /// Parses a file containing `rfc3454` tables.
+    /// # Arguments:
+    /// * `path` - The file path
+    /// # Returns
+    /// The rows of all tables found in the file, in the same order they
+    /// are listed in it.
+    pub fn parse<P: AsRef<Path>>(path: P) -> Result<Vec<StringprepEntry>, Error> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|e| Error {
+            mesg: format!("IO Error: {}", e),
+            line: None,
+            path: Some(path.to_path_buf()),
+        })?;
+
+        Self::parse_str(&content).map_err(|mut e| {
+            e.path = Some(path.to_path_buf());
+            e
+        })
+    }
+
+    fn parse_str(content: &str) -> Result<Vec<StringprepEntry>, Error> {
+        let mut entries = Vec::new();
+        let mut table: Option<&str> = None;
+
+        for (n, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let res = match table {
+                None => match parse_table_marker(line, TABLE_START_PREFIX) {
+                    Some(id) => {
+                        table = Some(id);
+                        Ok(())
+                    }
+                    None => err!("expected start of table, found: '{}'", line),
+                },
+                Some(id) => {
+                    if let Some(end) = parse_table_marker(line, TABLE_END_PREFIX) {
+                        if end == id {
+                            table = None;
+                            Ok(())
+                        } else {
+                            err!("table {} ended while parsing table {}", end, id)
+                        }
+                    } else {
+                        let mut entry: StringprepEntry = line.parse()?;
+                        if id.starts_with(MAPPING_TABLES_PREFIX) {
+                            let mapping = line.split(';').nth(1).unwrap_or_default();
+                            entry.mapping = Some(parse_mapping(mapping)?);
+                        }
+                        entry.table = String::from(id);
+                        entries.push(entry);
+                        Ok(())
+                    }
+                }
+            };
+
+            res.map_err(|mut e: Error| {
+                e.line = Some(n as u64 + 1);
+                e
+            })?;
+        }
+
+        match table {
+            Some(id) => err!("table {} is not terminated", id),
+            None => Ok(entries),
+        }
+    }
+}
+
+impl FromStr for StringprepEntry {
+    type Err = Error;
+
+    /// Parses the code points of a row. The table identifier and mappings
+    /// depend on the context of the row in the file, so they are left empty.
+    fn from_str(line: &str) -> Result<StringprepEntry, Error> {
+        let cps = line.split(';').next().unwrap_or_default();
+        Ok(StringprepEntry {
+            table: String::new(),
+            codepoints: parse_codepoints(cps)?,
+            mapping: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::stringprep_parser::*;
+
+    #[test]
+    fn parse_codepoints_entry() {
+        let entry = StringprepEntry::from_str("0221").unwrap();
+        assert_eq!(
+            entry.codepoints,
+            Codepoints::Single(Codepoint::from_u32(0x0221).unwrap())
+        );
+
+        let entry = StringprepEntry::from_str("0234-024F; comment").unwrap();
+        assert_eq!(
+            entry.codepoints,
+            Codepoints::Range(CodepointRange {
+                start: Codepoint::from_u32(0x0234).unwrap(),
+                end: Codepoint::from_u32(0x024f).unwrap(),
+            })
+        );
+
+        assert!(StringprepEntry::from_str("").is_err());
+        assert!(StringprepEntry::from_str("024F-0234").is_err());
+        assert!(StringprepEntry::from_str("XYZ").is_err());
+    }
+
+    #[test]
+    fn parse_tables() {
+        let content = "# comment\n\
+            ----- Start Table A.1 -----\n\
+            0221\n\
+            0234-024F\n\
+            ----- End Table A.1 -----\n\
+            \n\
+            ----- Start Table B.1 -----\n\
+            00AD; ; Map to nothing\n\
+            ----- End Table B.1 -----\n\
+            ----- Start Table B.2 -----\n\
+            0130; 0069 0307; Case map\n\
+            ----- End Table B.2 -----\n\
+            ----- Start Table C.1.1 -----\n\
+            0020; SPACE\n\
+            ----- End Table C.1.1 -----\n";
+
+        let entries = StringprepEntry::parse_str(content).unwrap();
+        assert_eq!(entries.len(), 5);
+        assert_eq!(entries[0].table, "A.1");
+        assert_eq!(entries[0].mapping, None);
+        assert_eq!(entries[1].table, "A.1");
+        assert_eq!(entries[2].table, "B.1");
+        assert_eq!(entries[2].mapping, Some(Vec::new()));
+        assert_eq!(entries[3].table, "B.2");
+        assert_eq!(
+            entries[3].mapping,
+            Some(vec![
+                Codepoint::from_u32(0x0069).unwrap(),
+                Codepoint::from_u32(0x0307).unwrap()
+            ])
+        );
+        assert_eq!(entries[4].table, "C.1.1");
+        assert_eq!(entries[4].mapping, None);
+    }
+
+    #[test]
+    fn parse_malformed_tables() {
+        // Row outside a table
+        let err = StringprepEntry::parse_str("0221\n").unwrap_err();
+        assert_eq!(err.line(), Some(1));
+
+        // Table not terminated
+        assert!(StringprepEntry::parse_str("----- Start Table A.1 -----\n0221\n").is_err());
+
+        // Wrong end marker
+        let content = "----- Start Table A.1 -----\n0221\n----- End Table A.2 -----\n";
+        let err = StringprepEntry::parse_str(content).unwrap_err();
+        assert_eq!(err.line(), Some(3));
+
+        // Invalid mapping
+        let content =
+            "----- Start Table B.2 -----\n0041; XYZ; Case map\n----- End Table B.2 -----\n";
+        assert!(StringprepEntry::parse_str(content).is_err());
+    }
+}
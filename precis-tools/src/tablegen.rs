@@ -0,0 +1,376 @@
+//! Generates a selection of the PRECIS tables for a `UCD` directory, as
+//! Rust code, `JSON` or `CSV`. This is what the `precis-tablegen` binary
+//! runs, so that tables can be generated ahead of time and reviewed
+//! instead of being generated by build scripts.
+
+use crate::file_writer;
+use crate::generators::constants::{CONTEXT_JOINING_TYPES, CONTEXT_SCRIPTS};
+use crate::generators::unicode_version::get_version;
+use crate::tables::PrecisTables;
+use crate::{
+    BidiClassGen, CodeGen, DerivedJoiningType, DerivedPropertyTableGen, Error, GeneralCategoryGen,
+    LowercaseMappingTableGen, TableExporter, Ucd, UcdFileGen, UcdTableGen, UnicodeGen,
+    ViramaTableGen, WidthMappingTableGen,
+};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use ucd_parse::Script;
+
+/// Tables that can be generated by [`TableGen`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Table {
+    /// Derived property values of the `IdentifierClass` and the
+    /// `FreeformClass`.
+    DerivedProperty,
+    /// Values of the `Bidi_Class` property.
+    BidiClass,
+    /// Wide and narrow width mappings.
+    WidthMapping,
+    /// Code points in the `Zs` general category.
+    SpaceSeparator,
+    /// Code points with the `Virama` combining class, and the scripts and
+    /// joining types used by the context rules.
+    Context,
+    /// Simple lowercase mappings.
+    LowercaseMapping,
+}
+
+impl Table {
+    /// All the tables, in the order they are written.
+    pub const ALL: [Table; 6] = [
+        Table::DerivedProperty,
+        Table::BidiClass,
+        Table::WidthMapping,
+        Table::SpaceSeparator,
+        Table::Context,
+        Table::LowercaseMapping,
+    ];
+}
+
+/// Output formats of [`TableGen`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OutputFormat {
+    /// Rust `static` tables, as generated for the PRECIS crates.
+    Rust,
+    /// `JSON` object with one array per table, see [`TableExporter::write_json`].
+    Json,
+    /// `CSV` rows, see [`TableExporter::write_csv`].
+    Csv,
+}
+
+/// Generates a selection of tables from a `UCD` directory.
+/// # Example
+/// ```no_run
+/// # use precis_tools::{OutputFormat, Table, TableGen, Ucd};
+/// let mut gen = TableGen::new(&Ucd::new("ucd-17.0.0"), "17.0.0");
+/// gen.add(Table::BidiClass);
+/// gen.add(Table::SpaceSeparator);
+/// gen.write(OutputFormat::Json, &mut std::io::stdout()).unwrap();
+/// ```
+pub struct TableGen {
+    ucd: Ucd,
+    version: String,
+    registry: Option<PathBuf>,
+    tables: Vec<Table>,
+}
+
+impl TableGen {
+    /// Creates a new generator without tables.
+    /// # Arguments:
+    /// * `ucd` - The `UCD` files
+    /// * `version` - Unicode version of the files, such as `17.0.0`
+    pub fn new(ucd: &Ucd, version: &str) -> Self {
+        Self {
+            ucd: ucd.clone(),
+            version: String::from(version),
+            registry: None,
+            tables: Vec::new(),
+        }
+    }
+
+    /// Reads the derived property values from an `IANA` `precis-tables.csv`
+    /// file instead of computing them from the `UCD` files.
+    pub fn with_registry<P: AsRef<Path>>(mut self, csv: P) -> Self {
+        self.registry = Some(csv.as_ref().to_path_buf());
+        self
+    }
+
+    /// Adds a table to generate. Tables are always written in the order
+    /// of [`Table::ALL`].
+    pub fn add(&mut self, table: Table) {
+        if !self.tables.contains(&table) {
+            self.tables.push(table);
+        }
+    }
+
+    fn selected(&self) -> impl Iterator<Item = Table> + '_ {
+        Table::ALL
+            .into_iter()
+            .filter(|table| self.tables.contains(table))
+    }
+
+    /// Checks that the version given on construction is the version of
+    /// the `UCD` files. The check is skipped when the version of the files
+    /// can not be found, because `PropList.txt` is not available.
+    pub fn check_version(&self) -> Result<(), Error> {
+        let expected = get_version(&self.version)?;
+        match self.ucd.version() {
+            Ok(found) if found != expected => err!(
+                "{}: Unicode version {}.{}.{} found, {} expected",
+                self.ucd.path().display(),
+                found.0,
+                found.1,
+                found.2,
+                self.version
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the version of the `UCD` files and writes the tables added
+    /// in the given format.
+    pub fn write(&self, format: OutputFormat, mut out: &mut dyn Write) -> Result<(), Error> {
+        self.check_version()?;
+        match format {
+            OutputFormat::Rust => self.write_rust(out),
+            OutputFormat::Json => self.exporter()?.write_json(&mut out),
+            OutputFormat::Csv => self.exporter()?.write_csv(&mut out),
+        }
+    }
+
+    // Returns the generator of the Rust code of the table
+    fn code_gen(&self, table: Table) -> Result<Box<dyn CodeGen>, Error> {
+        let mut ucd_gen = UcdFileGen::from_ucd(&self.ucd);
+        let mut gc_gen = GeneralCategoryGen::new();
+        match table {
+            Table::DerivedProperty => {
+                let name = "derived_properties";
+                return Ok(match &self.registry {
+                    Some(csv) => Box::new(DerivedPropertyTableGen::new(csv, name)),
+                    None => Box::new(DerivedPropertyTableGen::from_tables(
+                        &PrecisTables::from_ucd(&self.ucd)?,
+                        name,
+                    )),
+                });
+            }
+            Table::BidiClass => ucd_gen.add(Box::new(BidiClassGen::new("bidi_class"))),
+            Table::WidthMapping => {
+                gc_gen.add(Box::new(WidthMappingTableGen::new("width_mapping")));
+                ucd_gen.add(Box::new(gc_gen));
+            }
+            Table::SpaceSeparator => {
+                gc_gen.add(Box::new(UcdTableGen::new("Zs", "space_separator")));
+                ucd_gen.add(Box::new(gc_gen));
+            }
+            Table::Context => {
+                let mut script_gen: UnicodeGen<Script> = UnicodeGen::new();
+                let mut djt_gen: UnicodeGen<DerivedJoiningType> = UnicodeGen::new();
+                gc_gen.add(Box::new(ViramaTableGen::new("virama")));
+                for (value, name) in CONTEXT_SCRIPTS.iter() {
+                    script_gen.add(Box::new(UcdTableGen::new(value, name)));
+                }
+                for (value, name) in CONTEXT_JOINING_TYPES.iter() {
+                    djt_gen.add(Box::new(UcdTableGen::new(value, name)));
+                }
+                ucd_gen.add(Box::new(gc_gen));
+                ucd_gen.add(Box::new(script_gen));
+                ucd_gen.add(Box::new(djt_gen));
+            }
+            Table::LowercaseMapping => {
+                gc_gen.add(Box::new(LowercaseMappingTableGen::new("lowercase_mapping")));
+                ucd_gen.add(Box::new(gc_gen));
+            }
+        }
+        Ok(Box::new(ucd_gen))
+    }
+
+    fn write_rust(&self, out: &mut dyn Write) -> Result<(), Error> {
+        file_writer::generate_file_header(out)?;
+        writeln!(out, "// Unicode version {}", self.version)?;
+        writeln!(out)?;
+        for table in self.selected() {
+            self.code_gen(table)?.generate_code(out)?;
+        }
+        Ok(())
+    }
+
+    fn exporter(&self) -> Result<TableExporter, Error> {
+        let mut exporter = TableExporter::new();
+        exporter.set_unicode_version(&self.version);
+        for table in self.selected() {
+            match table {
+                Table::DerivedProperty => match &self.registry {
+                    Some(csv) => exporter.add_derived_properties_csv(csv)?,
+                    None => exporter.add_derived_properties(&PrecisTables::from_ucd(&self.ucd)?)?,
+                },
+                Table::BidiClass => exporter.add_bidi_class(&self.ucd)?,
+                Table::WidthMapping => exporter.add_width_mapping(&self.ucd)?,
+                Table::SpaceSeparator => exporter.add_space_separator(&self.ucd)?,
+                Table::Context => exporter.add_context_tables(&self.ucd)?,
+                Table::LowercaseMapping => exporter.add_lowercase_mapping(&self.ucd)?,
+            }
+        }
+        Ok(exporter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tablegen::*;
+    use crate::test_utils::TempDir;
+
+    const UNICODE_DATA: &str = "\
+0020;SPACE;Zs;0;WS;;;;;N;;;;;
+0041;LATIN CAPITAL LETTER A;Lu;0;L;;;;;N;;;;0061;
+0061;LATIN SMALL LETTER A;Ll;0;L;;;;;N;;;0041;;0041
+0391;GREEK CAPITAL LETTER ALPHA;Lu;0;L;;;;;N;;;;03B1;
+094D;DEVANAGARI SIGN VIRAMA;Mn;9;NSM;;;;;N;;;;;
+3000;IDEOGRAPHIC SPACE;Zs;0;WS;<wide> 0020;;;;N;;;;;
+";
+
+    const SCRIPTS: &str = "\
+0041          ; Latin
+0391          ; Greek
+";
+
+    const DERIVED_JOINING_TYPE: &str = "\
+0628          ; D
+094D          ; T
+";
+
+    const DERIVED_BIDI_CLASS: &str = "\
+# @missing: 0000..10FFFF; Left_To_Right
+0020          ; WS
+3000          ; WS
+";
+
+    const PROP_LIST: &str = "# PropList-17.0.0.txt\n";
+
+    fn with_ucd<F: FnOnce(&Ucd)>(name: &str, f: F) {
+        let dir = TempDir::new(&format!("tablegen-{}", name));
+        dir.write("UnicodeData.txt", UNICODE_DATA);
+        dir.write("Scripts.txt", SCRIPTS);
+        dir.write("PropList.txt", PROP_LIST);
+        dir.write("extracted/DerivedJoiningType.txt", DERIVED_JOINING_TYPE);
+        dir.write("extracted/DerivedBidiClass.txt", DERIVED_BIDI_CLASS);
+
+        f(&Ucd::new(&dir));
+    }
+
+    fn generate(gen: &TableGen, format: OutputFormat) -> String {
+        let mut out = Vec::new();
+        gen.write(format, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn rust_tables() {
+        with_ucd("rust", |ucd| {
+            let mut gen = TableGen::new(ucd, "17.0.0");
+            gen.add(Table::LowercaseMapping);
+            gen.add(Table::SpaceSeparator);
+            gen.add(Table::Context);
+            let code = generate(&gen, OutputFormat::Rust);
+
+            assert!(code.contains("// Unicode version 17.0.0\n"));
+            let zs = code.find("const SPACE_SEPARATOR: CodepointSet").unwrap();
+            let virama = code.find("const VIRAMA: CodepointSet").unwrap();
+            let lower = code
+                .find(
+                    "static LOWERCASE_MAPPING: [(u32, u32); 2] = [\n\
+                     \t(0x0041, 0x0061),\n\
+                     \t(0x0391, 0x03b1),\n\
+                     ];\n",
+                )
+                .unwrap();
+            assert!(zs < virama && virama < lower);
+            assert!(code.contains("const GREEK: CodepointSet"));
+            assert!(code.contains("const TRANSPARENT: CodepointSet"));
+            assert!(!code.contains("BIDI_CLASS"));
+        });
+    }
+
+    #[test]
+    fn json_tables() {
+        with_ucd("json", |ucd| {
+            let mut gen = TableGen::new(ucd, "17.0.0");
+            gen.add(Table::Context);
+            gen.add(Table::WidthMapping);
+            let json = generate(&gen, OutputFormat::Json);
+
+            assert!(json.starts_with("{\n  \"unicode_version\": \"17.0.0\",\n"));
+            assert!(json.contains(
+                "  \"width_mapping\": [\n    {\"first\": 12288, \"last\": 12288, \"mapping\": 32}\n  ],\n"
+            ));
+            assert!(json.contains("  \"virama\": [\n    {\"first\": 2381, \"last\": 2381}\n  ],\n"));
+            assert!(json.contains("  \"greek\": [\n    {\"first\": 913, \"last\": 913}\n  ],\n"));
+            assert!(json
+                .contains("  \"dual_joining\": [\n    {\"first\": 1576, \"last\": 1576}\n  ],\n"));
+            assert!(json.contains("  \"han\": [\n  ],\n"));
+            assert!(json.ends_with(
+                "  \"transparent\": [\n    {\"first\": 2381, \"last\": 2381}\n  ]\n}\n"
+            ));
+        });
+    }
+
+    #[test]
+    fn csv_tables() {
+        with_ucd("csv", |ucd| {
+            let mut gen = TableGen::new(ucd, "17.0.0");
+            gen.add(Table::SpaceSeparator);
+            gen.add(Table::BidiClass);
+            gen.add(Table::LowercaseMapping);
+            let csv = generate(&gen, OutputFormat::Csv);
+
+            assert_eq!(
+                csv,
+                "Table,Codepoint,Value\r\n\
+                 bidi_class,0000-001F,L\r\n\
+                 bidi_class,0020,WS\r\n\
+                 bidi_class,0021-2FFF,L\r\n\
+                 bidi_class,3000,WS\r\n\
+                 bidi_class,3001-10FFFF,L\r\n\
+                 space_separator,0020,\r\n\
+                 space_separator,3000,\r\n\
+                 lowercase_mapping,0041,0061\r\n\
+                 lowercase_mapping,0391,03B1\r\n"
+            );
+        });
+    }
+
+    #[test]
+    fn derived_properties_from_registry() {
+        with_ucd("registry", |ucd| {
+            let dir = TempDir::new("tablegen-registry-csv");
+            let csv = dir.write(
+                "precis-tables.csv",
+                "Codepoint,Property,Description\r\n\
+                 0000-001F,DISALLOWED,NULL..UNIT SEPARATOR\r\n\
+                 0020,ID_DIS or FREE_PVAL,SPACE\r\n\
+                 0021-10FFFF,PVALID,EXCLAMATION MARK..<NOT A CHARACTER>\r\n",
+            );
+
+            let mut gen = TableGen::new(ucd, "17.0.0").with_registry(&csv);
+            gen.add(Table::DerivedProperty);
+            let code = generate(&gen, OutputFormat::Rust);
+            assert!(code.contains(
+                "\t(Codepoints::Single(0x0020), (DerivedPropertyValue::SpecClassDis, DerivedPropertyValue::SpecClassPval)),\n"
+            ));
+            let csv_out = generate(&gen, OutputFormat::Csv);
+            assert!(csv_out.contains("derived_properties,0020,ID_DIS or FREE_PVAL\r\n"));
+        });
+    }
+
+    #[test]
+    fn version_mismatch() {
+        with_ucd("version", |ucd| {
+            let mut gen = TableGen::new(ucd, "16.0.0");
+            gen.add(Table::SpaceSeparator);
+            let mut out = Vec::new();
+            assert!(gen.write(OutputFormat::Rust, &mut out).is_err());
+            assert!(out.is_empty());
+            assert!(TableGen::new(ucd, "17.0.0").check_version().is_ok());
+            assert!(TableGen::new(ucd, "latest").check_version().is_err());
+        });
+    }
+}
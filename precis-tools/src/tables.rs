@@ -0,0 +1,237 @@
+use crate::csv_parser::{DerivedProperties, DerivedProperty, PrecisDerivedProperty};
+use crate::upgrade::PrecisProperties;
+use crate::{Error, Ucd};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+use ucd_parse::{Codepoint, CodepointRange, Codepoints};
+
+// Number of Unicode code points
+const CODEPOINTS: u32 = 0x110000;
+
+// Line terminator used in the `IANA` registry files
+const CRLF: &str = "\r\n";
+
+// Names of the code points in `UnicodeData.txt`. Code points in ranges,
+// such as `CJK` ideographs, are named after the first or the last code
+// point of the range.
+struct Names {
+    names: BTreeMap<u32, String>,
+}
+
+impl Names {
+    fn from_ucd(ucd: &Ucd) -> Result<Self, Error> {
+        let rows = ucd.parse::<ucd_parse::UnicodeData>()?;
+        let names = rows
+            .iter()
+            .map(|row| {
+                // Controls are named after their Unicode 1.0 name
+                let name = match row.name.as_str() {
+                    "<control>" if !row.unicode1_name.is_empty() => &row.unicode1_name,
+                    _ => &row.name,
+                };
+                (row.codepoint.value(), name.to_uppercase())
+            })
+            .collect();
+        Ok(Self { names })
+    }
+
+    fn get(&self, cp: u32, first: bool) -> Option<&str> {
+        if let Some(name) = self.names.get(&cp) {
+            return Some(name);
+        }
+        let (_, start) = self.names.range(..cp).next_back()?;
+        let (_, end) = self.names.range(cp..).next()?;
+        match (start.ends_with(", FIRST>"), end.ends_with(", LAST>")) {
+            (true, true) if first => Some(start),
+            (true, true) => Some(end),
+            _ => None,
+        }
+    }
+}
+
+fn derived_properties(props: &PrecisProperties, cp: u32) -> DerivedProperties {
+    let p = props.get(cp).unwrap();
+    match p.identifier_class == p.freeform_class {
+        true => DerivedProperties::Single(p.identifier_class),
+        false => DerivedProperties::Tuple((p.identifier_class, p.freeform_class)),
+    }
+}
+
+/// Derived property values of all the Unicode code points, as they are
+/// published in the `IANA` [`precis-tables.csv`](https://www.iana.org/assignments/precis-tables)
+/// registry. Consecutive code points with the same derived property
+/// values are merged in a single row described by the names of the first
+/// and last code points.
+/// # Example
+/// ```no_run
+/// # use precis_tools::PrecisTables;
+/// # use std::fs::File;
+/// # use std::path::Path;
+/// let tables = PrecisTables::from_ucd_dir(Path::new("ucd-17.0.0")).unwrap();
+/// let mut file = File::create("precis-tables-17.0.0.csv").unwrap();
+/// tables.write_csv(&mut file).unwrap();
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrecisTables {
+    rows: Vec<PrecisDerivedProperty>,
+}
+
+impl PrecisTables {
+    /// Computes the derived property values of all code points from the
+    /// files in `ucd_dir`. The same files as [`PrecisProperties`] are required.
+    pub fn from_ucd_dir(ucd_dir: &Path) -> Result<Self, Error> {
+        Self::from_ucd(&Ucd::new(ucd_dir))
+    }
+
+    /// Computes the derived property values of all code points from the
+    /// files in `ucd`.
+    pub fn from_ucd(ucd: &Ucd) -> Result<Self, Error> {
+        let props = PrecisProperties::from_ucd(ucd)?;
+        let names = Names::from_ucd(ucd)?;
+
+        let name = |cp: u32, value: &DerivedProperties, first: bool| -> String {
+            match names.get(cp, first) {
+                Some(name) => String::from(name),
+                None if *value == DerivedProperties::Single(DerivedProperty::Unassigned) => {
+                    String::from("<RESERVED>")
+                }
+                // Code points not in `UnicodeData.txt` which are not
+                // unassigned are non characters
+                None => String::from("<NOT A CHARACTER>"),
+            }
+        };
+
+        let mut rows = Vec::new();
+        let mut start = 0;
+        while start < CODEPOINTS {
+            let value = derived_properties(&props, start);
+            let mut end = start;
+            while end + 1 < CODEPOINTS && derived_properties(&props, end + 1) == value {
+                end += 1;
+            }
+
+            let first = name(start, &value, true);
+            let (codepoints, description) = if start == end {
+                (Codepoints::Single(Codepoint::from_u32(start)?), first)
+            } else {
+                let range = CodepointRange {
+                    start: Codepoint::from_u32(start)?,
+                    end: Codepoint::from_u32(end)?,
+                };
+                let last = name(end, &value, false);
+                (Codepoints::Range(range), format!("{}..{}", first, last))
+            };
+            rows.push(PrecisDerivedProperty {
+                codepoints,
+                properties: value,
+                description,
+            });
+            start = end + 1;
+        }
+
+        Ok(Self { rows })
+    }
+
+    /// Returns the rows of the table sorted by code point.
+    pub fn rows(&self) -> &[PrecisDerivedProperty] {
+        &self.rows
+    }
+
+    /// Writes the table in the `CSV` format used by the `IANA` registry,
+    /// which can be read back with [`CsvLineParser`](crate::CsvLineParser).
+    pub fn write_csv<W: Write>(&self, w: &mut W) -> Result<(), Error> {
+        write!(w, "Codepoint,Property,Description{}", CRLF)?;
+        for row in self.rows.iter() {
+            write!(w, "{}{}", row, CRLF)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tables::*;
+    use crate::test_utils::TempDir;
+    use crate::{CsvLineParser, DerivedProperty};
+
+    const UNICODE_DATA: &str = "\
+0000;<control>;Cc;0;BN;;;;;N;NULL;;;;
+0001;<control>;Cc;0;BN;;;;;N;;;;;
+0020;SPACE;Zs;0;WS;;;;;N;;;;;
+0021;EXCLAMATION MARK;Po;0;ON;;;;;N;;;;;
+007E;TILDE;Sm;0;ON;;;;;N;;;;;
+00B7;MIDDLE DOT;Po;0;ON;;;;;N;;;;;
+00B8;CEDILLA;Sk;0;ON;<compat> 0020 0327;;;;N;SPACING CEDILLA;;;;
+00D7;MULTIPLICATION SIGN;Sm;0;ON;;;;;N;;;;;
+00DF;LATIN SMALL LETTER SHARP S;Ll;0;L;;;;;N;;;;;
+4E00;<CJK Ideograph, First>;Lo;0;L;;;;;N;;;;;
+9FCC;<CJK Ideograph, Last>;Lo;0;L;;;;;N;;;;;
+";
+
+    #[test]
+    fn precis_tables() {
+        let dir = TempDir::new("tables");
+        dir.write("UnicodeData.txt", UNICODE_DATA);
+        dir.write(
+            "PropList.txt",
+            "# PropList-1.0.0.txt\nFDD0..FDEF ; Noncharacter_Code_Point\n\
+            FFFE..FFFF ; Noncharacter_Code_Point\n10FFFE..10FFFF ; Noncharacter_Code_Point\n",
+        );
+        dir.write("DerivedCoreProperties.txt", "");
+        dir.write("HangulSyllableType.txt", "");
+        dir.write(
+            "DerivedNormalizationProps.txt",
+            "00B8 ; NFKC_QC; N # Sk CEDILLA\n",
+        );
+        dir.write(
+            "extracted/DerivedBidiClass.txt",
+            "# @missing: 0000..10FFFF; Left_To_Right\n",
+        );
+
+        let tables = PrecisTables::from_ucd_dir(&dir).unwrap();
+        let mut csv = Vec::new();
+        tables.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+
+        let expected = [
+            "Codepoint,Property,Description",
+            "0000-0001,DISALLOWED,NULL..<CONTROL>",
+            "0002-001F,UNASSIGNED,<RESERVED>..<RESERVED>",
+            "0020,ID_DIS or FREE_PVAL,SPACE",
+            "0021,PVALID,EXCLAMATION MARK",
+            "0022-007D,UNASSIGNED,<RESERVED>..<RESERVED>",
+            "007E,PVALID,TILDE",
+            "007F-00B6,UNASSIGNED,<RESERVED>..<RESERVED>",
+            "00B7,CONTEXTO,MIDDLE DOT",
+            "00B8,ID_DIS or FREE_PVAL,CEDILLA",
+            "00B9-00D6,UNASSIGNED,<RESERVED>..<RESERVED>",
+            "00D7,ID_DIS or FREE_PVAL,MULTIPLICATION SIGN",
+            "00D8-00DE,UNASSIGNED,<RESERVED>..<RESERVED>",
+            "00DF,PVALID,LATIN SMALL LETTER SHARP S",
+        ];
+        let lines: Vec<&str> = csv.split("\r\n").collect();
+        assert!(lines.starts_with(&expected));
+        assert!(
+            lines.contains(&"4E00-9FCC,PVALID,\"<CJK IDEOGRAPH, FIRST>..<CJK IDEOGRAPH, LAST>\"")
+        );
+        assert!(lines.contains(&"FDD0-FDEF,DISALLOWED,<NOT A CHARACTER>..<NOT A CHARACTER>"));
+        assert_eq!(
+            lines[lines.len() - 2],
+            "10FFFE-10FFFF,DISALLOWED,<NOT A CHARACTER>..<NOT A CHARACTER>"
+        );
+        assert_eq!(lines[lines.len() - 1], "");
+
+        // The table can be parsed back
+        let path = dir.write("precis-tables.csv", &csv);
+        let rows: Vec<PrecisDerivedProperty> = CsvLineParser::from_path(&path)
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(rows.len(), tables.rows().len());
+        assert_eq!(
+            rows[3].properties,
+            DerivedProperties::Single(DerivedProperty::PValid)
+        );
+    }
+}
@@ -0,0 +1,565 @@
+//! In-memory view of a Unicode Character Database (`UCD`) directory or
+//! `UCD.zip` archive.
+//!
+//! Files are parsed the first time they are needed and kept in memory, so
+//! that generators sharing the same [`Ucd`] do not parse them again.
+
+use crate::generators::bidi_class::BIDI_CLASS_VALUES;
+use crate::ucd_parsers::{DerivedBidiClass, DerivedJoiningType, UnicodeData};
+use crate::Error;
+use std::any::{Any, TypeId};
+use std::cell::{OnceCell, RefCell};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::LazyLock;
+use ucd_parse::{
+    Codepoint, CodepointRange, Codepoints, CoreProperty, Property, Script, UcdFile,
+    UnicodeDataDecomposition,
+};
+
+// Number of Unicode code points
+const CODEPOINTS: usize = 0x110000;
+
+// Values of the code points not listed in the `UCD` files
+const DEFAULT_GENERAL_CATEGORY: &str = "Cn";
+const DEFAULT_SCRIPT: &str = "Unknown";
+const DEFAULT_JOINING_TYPE: &str = "U";
+const DEFAULT_BIDI_CLASS: &str = "L";
+
+// Table of code point ranges sorted by code point
+type RangeTable<T> = Vec<(u32, u32, T)>;
+
+fn bounds(cps: &Codepoints) -> (u32, u32) {
+    match cps {
+        Codepoints::Single(cp) => (cp.value(), cp.value()),
+        Codepoints::Range(r) => (r.start.value(), r.end.value()),
+    }
+}
+
+fn sorted<T>(mut table: RangeTable<T>) -> RangeTable<T> {
+    table.sort_by_key(|(start, _, _)| *start);
+    table
+}
+
+fn lookup<T>(table: &[(u32, u32, T)], cp: u32) -> Option<&T> {
+    table
+        .binary_search_by(|(start, end, _)| {
+            if *end < cp {
+                std::cmp::Ordering::Less
+            } else if *start > cp {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|idx| &table[idx].2)
+}
+
+// Returns the short name of the `Bidi_Class` value given its short or long name
+fn bidi_class_name(value: &str) -> Result<&'static str, Error> {
+    match BIDI_CLASS_VALUES
+        .iter()
+        .find(|(short, long)| *short == value || *long == value)
+    {
+        Some((short, _)) => Ok(short),
+        None => err!("Unknown Bidi_Class value: {}", value),
+    }
+}
+
+// Parses the rows of the content of a `UCD` file read from `path`
+fn parse_rows<U: ucd_parse::UcdFile>(content: &str, path: &Path) -> Result<Vec<U>, Error> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.starts_with('#') && !line.trim().is_empty())
+        .map(|(n, line)| {
+            line.parse().map_err(|e: ucd_parse::Error| Error {
+                mesg: format!("Parse error: {}", e),
+                line: Some(n as u64 + 1),
+                path: Some(path.to_path_buf()),
+            })
+        })
+        .collect()
+}
+
+// Returns the value stored in the cell, initializing it first if needed
+fn get_or_load<T, F>(cell: &OnceCell<T>, load: F) -> Result<&T, Error>
+where
+    F: FnOnce() -> Result<T, Error>,
+{
+    if let Some(value) = cell.get() {
+        return Ok(value);
+    }
+    let value = load()?;
+    Ok(cell.get_or_init(|| value))
+}
+
+struct UcdInner {
+    path: PathBuf,
+    files: RefCell<HashMap<TypeId, Rc<dyn Any>>>,
+    unicode_data: OnceCell<Rc<Vec<UnicodeData>>>,
+    scripts: OnceCell<RangeTable<String>>,
+    joining_types: OnceCell<RangeTable<String>>,
+    bidi_classes: OnceCell<Vec<(CodepointRange, &'static str)>>,
+    properties: OnceCell<HashMap<String, RangeTable<()>>>,
+}
+
+/// Unicode Character Database loaded in memory. Files are read either from
+/// a directory or from the entries of a
+/// [`UCD.zip`](https://www.unicode.org/Public/zipped/) archive, such as
+/// `extracted/DerivedJoiningType.txt`. Each file is parsed at most once,
+/// the first time a query or a generator needs it. Clones share the files
+/// already parsed, so the same [`Ucd`] can be given to several
+/// [`UcdFileGen`](crate::UcdFileGen) elements.
+/// # Example
+/// ```no_run
+/// # use precis_tools::Ucd;
+/// let ucd = Ucd::new("ucd-17.0.0");
+/// assert_eq!(ucd.general_category(0x0041).unwrap(), "Lu");
+/// assert_eq!(ucd.script(0x05D0).unwrap(), "Hebrew");
+/// assert_eq!(ucd.bidi_class(0x05D0).unwrap(), "R");
+/// assert_eq!(ucd.simple_lowercase_mapping(0x0041).unwrap(), Some(0x0061));
+/// assert!(ucd.has_property(0x200D, "Join_Control").unwrap());
+/// ```
+#[derive(Clone)]
+pub struct Ucd {
+    inner: Rc<UcdInner>,
+}
+
+impl Ucd {
+    /// Creates a new database for the `UCD` files in `path`, which is either
+    /// a directory or a `UCD.zip` archive. Files are not read until they are
+    /// needed.
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            inner: Rc::new(UcdInner {
+                path: path.as_ref().to_path_buf(),
+                files: RefCell::new(HashMap::new()),
+                unicode_data: OnceCell::new(),
+                scripts: OnceCell::new(),
+                joining_types: OnceCell::new(),
+                bidi_classes: OnceCell::new(),
+                properties: OnceCell::new(),
+            }),
+        }
+    }
+
+    /// Returns the directory or the archive where the `UCD` files are stored.
+    pub fn path(&self) -> &Path {
+        &self.inner.path
+    }
+
+    /// Returns whether the `UCD` files are read from a `UCD.zip` archive.
+    pub fn is_archive(&self) -> bool {
+        self.path().is_file()
+    }
+
+    /// Returns the content of the `UCD` file at the path `relative` to the
+    /// directory or the root of the archive.
+    pub fn read_file<P: AsRef<Path>>(&self, relative: P) -> Result<String, Error> {
+        let relative = relative.as_ref();
+        let path = self.path().join(relative);
+        let error = |mesg: String| Error {
+            mesg,
+            line: None,
+            path: Some(path.clone()),
+        };
+
+        if !self.is_archive() {
+            return std::fs::read_to_string(&path).map_err(|e| error(format!("IO Error: {}", e)));
+        }
+
+        let file = File::open(self.path()).map_err(|e| error(format!("IO Error: {}", e)))?;
+        let mut archive =
+            zip::ZipArchive::new(file).map_err(|e| error(format!("Zip Error: {}", e)))?;
+        // Names of the entries always use forward slashes
+        let name = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let mut entry = archive
+            .by_name(&name)
+            .map_err(|e| error(format!("Zip Error: {}", e)))?;
+        let mut content = String::new();
+        entry
+            .read_to_string(&mut content)
+            .map_err(|e| error(format!("IO Error: {}", e)))?;
+        Ok(content)
+    }
+
+    /// Returns the Unicode version of the files, as it is written in the
+    /// header of `PropList.txt`.
+    pub fn version(&self) -> Result<(u64, u64, u64), Error> {
+        static VERSION_RX: LazyLock<regex::Regex> =
+            LazyLock::new(|| regex::Regex::new(r"-([0-9]+)\.([0-9]+)\.([0-9]+)\.txt").unwrap());
+
+        let content = self.read_file(Property::relative_file_path())?;
+        let first = content.lines().next().unwrap_or_default();
+        let caps = match VERSION_RX.captures(first) {
+            Some(caps) => caps,
+            None => return err!("Failed to find version in line {:?}", first),
+        };
+        let number = |n: usize| {
+            caps[n]
+                .parse::<u64>()
+                .map_err(|e| Error::parse(e.to_string()))
+        };
+        Ok((number(1)?, number(2)?, number(3)?))
+    }
+
+    /// Returns all the rows of the `UCD` file `U`. The file is parsed the
+    /// first time it is requested.
+    pub fn parse<U: ucd_parse::UcdFile + 'static>(&self) -> Result<Rc<Vec<U>>, Error> {
+        let id = TypeId::of::<U>();
+        if let Some(rows) = self.inner.files.borrow().get(&id) {
+            return Ok(Rc::clone(rows).downcast().unwrap());
+        }
+        let rows: Rc<Vec<U>> = match self.is_archive() {
+            false => Rc::new(ucd_parse::parse(self.path())?),
+            true => {
+                let content = self.read_file(U::relative_file_path())?;
+                Rc::new(parse_rows(&content, &U::file_path(self.path()))?)
+            }
+        };
+        self.inner
+            .files
+            .borrow_mut()
+            .insert(id, Rc::clone(&rows) as Rc<dyn Any>);
+        Ok(rows)
+    }
+
+    /// Returns the rows of the `UnicodeData.txt` file, with the code point
+    /// ranges merged into a single row.
+    pub fn unicode_data(&self) -> Result<Rc<Vec<UnicodeData>>, Error> {
+        self.unicode_data_rows().map(Rc::clone)
+    }
+
+    fn unicode_data_rows(&self) -> Result<&Rc<Vec<UnicodeData>>, Error> {
+        get_or_load(&self.inner.unicode_data, || {
+            let raws = self.parse::<ucd_parse::UnicodeData>()?;
+            Ok(Rc::new(UnicodeData::from_rows(&raws, self.path())?))
+        })
+    }
+
+    fn unicode_data_entry(&self, cp: u32) -> Result<Option<&UnicodeData>, Error> {
+        let rows = self.unicode_data_rows()?;
+        Ok(rows
+            .binary_search_by(|udata| {
+                let (start, end) = bounds(&udata.codepoints);
+                if end < cp {
+                    std::cmp::Ordering::Less
+                } else if start > cp {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .ok()
+            .map(|idx| &rows[idx]))
+    }
+
+    /// Returns the short name of the `General_Category` of the code point,
+    /// `Cn` if it is not listed in `UnicodeData.txt`.
+    pub fn general_category(&self, cp: u32) -> Result<&str, Error> {
+        Ok(self
+            .unicode_data_entry(cp)?
+            .map_or(DEFAULT_GENERAL_CATEGORY, |udata| &udata.general_category))
+    }
+
+    /// Returns the decomposition mapping of the code point, or `None` if
+    /// the code point does not decompose.
+    pub fn decomposition(&self, cp: u32) -> Result<Option<&UnicodeDataDecomposition>, Error> {
+        Ok(self.unicode_data_entry(cp)?.and_then(|udata| {
+            // Code points without decomposition are mapped to themselves,
+            // or to the first code point of the range they belong to
+            let d = &udata.decomposition;
+            let (start, end) = bounds(&udata.codepoints);
+            let mapping = d.mapping[0].value();
+            match d.tag.is_none() && d.len == 1 && (start..=end).contains(&mapping) {
+                true => None,
+                false => Some(d),
+            }
+        }))
+    }
+
+    /// Returns the simple uppercase mapping of the code point, if any.
+    pub fn simple_uppercase_mapping(&self, cp: u32) -> Result<Option<u32>, Error> {
+        Ok(self
+            .unicode_data_entry(cp)?
+            .and_then(|udata| udata.simple_uppercase_mapping)
+            .map(|cp| cp.value()))
+    }
+
+    /// Returns the simple lowercase mapping of the code point, if any.
+    pub fn simple_lowercase_mapping(&self, cp: u32) -> Result<Option<u32>, Error> {
+        Ok(self
+            .unicode_data_entry(cp)?
+            .and_then(|udata| udata.simple_lowercase_mapping)
+            .map(|cp| cp.value()))
+    }
+
+    /// Returns the simple titlecase mapping of the code point, if any.
+    pub fn simple_titlecase_mapping(&self, cp: u32) -> Result<Option<u32>, Error> {
+        Ok(self
+            .unicode_data_entry(cp)?
+            .and_then(|udata| udata.simple_titlecase_mapping)
+            .map(|cp| cp.value()))
+    }
+
+    /// Returns the long name of the `Script` of the code point from the
+    /// `Scripts.txt` file, `Unknown` if it is not listed.
+    pub fn script(&self, cp: u32) -> Result<&str, Error> {
+        let table = get_or_load(&self.inner.scripts, || {
+            let rows = self.parse::<Script>()?;
+            Ok(sorted(
+                rows.iter()
+                    .map(|row| {
+                        let (start, end) = bounds(&row.codepoints);
+                        (start, end, row.script.clone())
+                    })
+                    .collect(),
+            ))
+        })?;
+        Ok(lookup(table, cp).map_or(DEFAULT_SCRIPT, String::as_str))
+    }
+
+    /// Returns the short name of the `Joining_Type` of the code point from
+    /// the `extracted/DerivedJoiningType.txt` file, `U` if it is not listed.
+    pub fn joining_type(&self, cp: u32) -> Result<&str, Error> {
+        let table = get_or_load(&self.inner.joining_types, || {
+            let rows = self.parse::<DerivedJoiningType>()?;
+            Ok(sorted(
+                rows.iter()
+                    .map(|row| {
+                        let (start, end) = bounds(&row.prop.codepoints);
+                        (start, end, row.prop.property.clone())
+                    })
+                    .collect(),
+            ))
+        })?;
+        Ok(lookup(table, cp).map_or(DEFAULT_JOINING_TYPE, String::as_str))
+    }
+
+    /// Returns the ranges of code points with the same `Bidi_Class` value,
+    /// covering all the code points. Values are read from the
+    /// `extracted/DerivedBidiClass.txt` file, code points not listed take the
+    /// default values declared in its `@missing` lines.
+    pub(crate) fn bidi_classes(&self) -> Result<&[(CodepointRange, &'static str)], Error> {
+        let table = get_or_load(&self.inner.bidi_classes, || {
+            let mut values = vec![DEFAULT_BIDI_CLASS; CODEPOINTS];
+
+            // Default values are overridden by the later `@missing` lines
+            let content = self.read_file(DerivedBidiClass::relative_file_path())?;
+            let path = DerivedBidiClass::file_path(self.path());
+            for (range, value) in DerivedBidiClass::parse_missing_lines(&content, path)?.iter() {
+                let value = bidi_class_name(value)?;
+                for cp in range.start.value()..=range.end.value() {
+                    values[cp as usize] = value;
+                }
+            }
+
+            for row in self.parse::<DerivedBidiClass>()?.iter() {
+                let value = bidi_class_name(&row.prop.property)?;
+                let (start, end) = bounds(&row.prop.codepoints);
+                for cp in start..=end {
+                    values[cp as usize] = value;
+                }
+            }
+
+            let mut table = Vec::new();
+            let mut start = 0;
+            while start < CODEPOINTS {
+                let mut end = start;
+                while end + 1 < CODEPOINTS && values[end + 1] == values[start] {
+                    end += 1;
+                }
+                let range = CodepointRange {
+                    start: Codepoint::from_u32(start as u32)?,
+                    end: Codepoint::from_u32(end as u32)?,
+                };
+                table.push((range, values[start]));
+                start = end + 1;
+            }
+            Ok(table)
+        })?;
+        Ok(table)
+    }
+
+    /// Returns the short name of the `Bidi_Class` of the code point from the
+    /// `extracted/DerivedBidiClass.txt` file, including the default values
+    /// of the unassigned code points.
+    pub fn bidi_class(&self, cp: u32) -> Result<&'static str, Error> {
+        let table = self.bidi_classes()?;
+        let idx = table.partition_point(|(range, _)| range.end.value() < cp);
+        Ok(table
+            .get(idx)
+            .map_or(DEFAULT_BIDI_CLASS, |(_, value)| value))
+    }
+
+    /// Returns whether the binary property `name`, listed in the
+    /// `PropList.txt` or `DerivedCoreProperties.txt` files, is true for
+    /// the code point. An error is returned for unknown properties.
+    pub fn has_property(&self, cp: u32, name: &str) -> Result<bool, Error> {
+        let properties = get_or_load(&self.inner.properties, || {
+            let mut properties: HashMap<String, RangeTable<()>> = HashMap::new();
+            for row in self.parse::<Property>()?.iter() {
+                let (start, end) = bounds(&row.codepoints);
+                properties
+                    .entry(row.property.clone())
+                    .or_default()
+                    .push((start, end, ()));
+            }
+            for row in self.parse::<CoreProperty>()?.iter() {
+                let (start, end) = bounds(&row.codepoints);
+                properties
+                    .entry(row.property.clone())
+                    .or_default()
+                    .push((start, end, ()));
+            }
+            Ok(properties
+                .into_iter()
+                .map(|(name, table)| (name, sorted(table)))
+                .collect())
+        })?;
+        match properties.get(name) {
+            Some(table) => Ok(lookup(table, cp).is_some()),
+            None => err!("Unknown property: {}", name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::TempDir;
+    use crate::ucd::*;
+    use std::fs;
+    use std::io::Write;
+
+    const UNICODE_DATA: &str = "\
+0041;LATIN CAPITAL LETTER A;Lu;0;L;;;;;N;;;;0061;
+0061;LATIN SMALL LETTER A;Ll;0;L;;;;;N;;;0041;;0041
+05D0;HEBREW LETTER ALEF;Lo;0;R;;;;;N;;;;;
+0628;ARABIC LETTER BEH;Lo;0;AL;;;;;N;;;;;
+200D;ZERO WIDTH JOINER;Cf;0;BN;;;;;N;;;;;
+4E00;<CJK Ideograph, First>;Lo;0;L;;;;;N;;;;;
+9FFF;<CJK Ideograph, Last>;Lo;0;L;;;;;N;;;;;
+FF21;FULLWIDTH LATIN CAPITAL LETTER A;Lu;0;L;<wide> 0041;;;;N;;;;FF41;
+";
+
+    fn ucd_dir(name: &str) -> TempDir {
+        let dir = TempDir::new(&format!("ucd-{}", name));
+        dir.write("UnicodeData.txt", UNICODE_DATA);
+        dir.write(
+            "Scripts.txt",
+            "0041 ; Latin\n0061 ; Latin\n05D0 ; Hebrew\n0628 ; Arabic\n",
+        );
+        dir.write("extracted/DerivedJoiningType.txt", "0628 ; D\n200D ; C\n");
+        dir.write(
+            "extracted/DerivedBidiClass.txt",
+            "# @missing: 0000..10FFFF; Left_To_Right\n\
+             # @missing: 0590..05FF; Right_To_Left\n\
+             05D0 ; R\n0628 ; AL\n200D ; BN\n",
+        );
+        dir.write(
+            "PropList.txt",
+            "# PropList-17.0.0.txt\n200C..200D ; Join_Control\n",
+        );
+        dir.write(
+            "DerivedCoreProperties.txt",
+            "200D ; Default_Ignorable_Code_Point\n",
+        );
+        dir
+    }
+
+    fn check_queries(ucd: &Ucd) {
+        assert_eq!(ucd.version().unwrap(), (17, 0, 0));
+
+        assert_eq!(ucd.general_category(0x0041).unwrap(), "Lu");
+        assert_eq!(ucd.general_category(0x6C34).unwrap(), "Lo");
+        assert_eq!(ucd.general_category(0x0042).unwrap(), "Cn");
+
+        assert_eq!(ucd.script(0x05D0).unwrap(), "Hebrew");
+        assert_eq!(ucd.script(0x0042).unwrap(), "Unknown");
+
+        assert_eq!(ucd.joining_type(0x0628).unwrap(), "D");
+        assert_eq!(ucd.joining_type(0x0041).unwrap(), "U");
+
+        assert_eq!(ucd.bidi_class(0x0628).unwrap(), "AL");
+        assert_eq!(ucd.bidi_class(0x05FF).unwrap(), "R");
+        assert_eq!(ucd.bidi_class(0x0041).unwrap(), "L");
+
+        let decomposition = ucd.decomposition(0xFF21).unwrap().unwrap();
+        assert_eq!(
+            decomposition.tag,
+            Some(ucd_parse::UnicodeDataDecompositionTag::Wide)
+        );
+        assert_eq!(decomposition.mapping[0].value(), 0x0041);
+        assert!(ucd.decomposition(0x0041).unwrap().is_none());
+        assert!(ucd.decomposition(0x6C34).unwrap().is_none());
+
+        assert_eq!(ucd.simple_lowercase_mapping(0x0041).unwrap(), Some(0x0061));
+        assert_eq!(ucd.simple_uppercase_mapping(0x0061).unwrap(), Some(0x0041));
+        assert_eq!(ucd.simple_titlecase_mapping(0x0061).unwrap(), Some(0x0041));
+        assert_eq!(ucd.simple_lowercase_mapping(0x0061).unwrap(), None);
+
+        assert!(ucd.has_property(0x200C, "Join_Control").unwrap());
+        assert!(ucd
+            .has_property(0x200D, "Default_Ignorable_Code_Point")
+            .unwrap());
+        assert!(!ucd.has_property(0x0041, "Join_Control").unwrap());
+        assert!(ucd.has_property(0x0041, "Unknown_Property").is_err());
+    }
+
+    #[test]
+    fn queries() {
+        let dir = ucd_dir("queries");
+        check_queries(&Ucd::new(&dir));
+    }
+
+    #[test]
+    fn archive() {
+        let dir = ucd_dir("archive");
+        let archive_dir = TempDir::new("ucd-archive-zip");
+        let path = archive_dir.join("ucd.zip");
+        let mut zip = zip::ZipWriter::new(File::create(&path).unwrap());
+        for name in [
+            "UnicodeData.txt",
+            "Scripts.txt",
+            "PropList.txt",
+            "DerivedCoreProperties.txt",
+            "extracted/DerivedJoiningType.txt",
+            "extracted/DerivedBidiClass.txt",
+        ] {
+            zip.start_file(name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            zip.write_all(&fs::read(dir.join(name)).unwrap()).unwrap();
+        }
+        zip.finish().unwrap();
+        drop(dir);
+
+        let ucd = Ucd::new(&path);
+        assert!(ucd.is_archive());
+        check_queries(&ucd);
+
+        let err = ucd.read_file("Missing.txt").unwrap_err();
+        assert_eq!(err.path(), Some(path.join("Missing.txt").as_path()));
+    }
+
+    #[test]
+    fn files_parsed_once() {
+        let dir = ucd_dir("cache");
+        let ucd = Ucd::new(&dir);
+        let rows = ucd.parse::<Script>().unwrap();
+        let clone = ucd.clone();
+
+        // Files already parsed are not read again
+        drop(dir);
+        assert!(Rc::ptr_eq(&rows, &clone.parse::<Script>().unwrap()));
+        assert!(clone.parse::<Property>().is_err());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::Error;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use ucd_parse::UcdFile;
 
@@ -33,6 +33,51 @@ impl FromStr for HangulSyllableType {
     }
 }
 
+/// A single row in the
+/// [`DerivedNormalizationProps`](http://www.unicode.org/reports/tr44/#DerivedNormalizationProps.txt)
+/// file. Unlike [`ucd_parse::DerivedNormalizationProperty`], the value of
+/// the enumerated properties, such as `NFKC_QC`, is kept.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct NormalizationProperty {
+    /// The code point or code point range for this entry.
+    pub codepoints: ucd_parse::Codepoints,
+    /// The property name assigned to the code points in this entry.
+    pub property: String,
+    /// The value of the property, if it is not a binary property.
+    pub value: Option<String>,
+}
+
+impl ucd_parse::UcdFile for NormalizationProperty {
+    fn relative_file_path() -> &'static Path {
+        Path::new("DerivedNormalizationProps.txt")
+    }
+}
+
+impl ucd_parse::UcdFileByCodepoint for NormalizationProperty {
+    fn codepoints(&self) -> ucd_parse::CodepointIter {
+        self.codepoints.into_iter()
+    }
+}
+
+impl FromStr for NormalizationProperty {
+    type Err = ucd_parse::Error;
+
+    fn from_str(line: &str) -> Result<NormalizationProperty, ucd_parse::Error> {
+        let prop = ucd_parse::DerivedNormalizationProperty::from_str(line)?;
+        let data = line.split('#').next().unwrap_or_default();
+        let value = data
+            .split(';')
+            .nth(2)
+            .map(|v| String::from(v.trim()))
+            .filter(|v| !v.is_empty());
+        Ok(NormalizationProperty {
+            codepoints: prop.codepoints,
+            property: prop.property,
+            value,
+        })
+    }
+}
+
 /// A single row in the `DerivedJoiningType` file.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct DerivedJoiningType {
@@ -61,6 +106,86 @@ impl FromStr for DerivedJoiningType {
     }
 }
 
+/// A single row in the
+/// [`DerivedBidiClass`](http://www.unicode.org/reports/tr44/#Bidi_Class)
+/// file. Code points not listed in the file take the default values
+/// declared in its `@missing` lines, see [`DerivedBidiClass::parse_missing`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DerivedBidiClass {
+    /// A single row in the `DerivedBidiClass.txt` file. The property
+    /// holds the short name of the `Bidi_Class` value.
+    pub prop: ucd_parse::Property,
+}
+
+impl ucd_parse::UcdFile for DerivedBidiClass {
+    fn relative_file_path() -> &'static Path {
+        Path::new("extracted/DerivedBidiClass.txt")
+    }
+}
+
+impl ucd_parse::UcdFileByCodepoint for DerivedBidiClass {
+    fn codepoints(&self) -> ucd_parse::CodepointIter {
+        self.prop.codepoints.into_iter()
+    }
+}
+
+impl FromStr for DerivedBidiClass {
+    type Err = ucd_parse::Error;
+
+    fn from_str(line: &str) -> Result<DerivedBidiClass, ucd_parse::Error> {
+        let prop = ucd_parse::Property::from_str(line)?;
+        Ok(DerivedBidiClass { prop })
+    }
+}
+
+impl DerivedBidiClass {
+    /// Parses the `@missing` lines of the `DerivedBidiClass.txt` file in
+    /// `ucd_dir`. Each line assigns a default value, usually the long name
+    /// of the `Bidi_Class` value, to the code points in a range which are
+    /// not explicitly listed in the file. Lines are returned in the same
+    /// order as in the file, later lines override the earlier ones as
+    /// described in [`UAX44`](http://www.unicode.org/reports/tr44/#Missing_Conventions).
+    pub fn parse_missing(
+        ucd_dir: &Path,
+    ) -> Result<Vec<(ucd_parse::CodepointRange, String)>, Error> {
+        let path = Self::file_path(ucd_dir);
+        let content = std::fs::read_to_string(&path).map_err(|e| Error {
+            mesg: format!("IO Error: {}", e),
+            line: None,
+            path: Some(path.clone()),
+        })?;
+        Self::parse_missing_lines(&content, path)
+    }
+
+    // Parses the `@missing` lines of the content of the file read from `path`
+    pub(crate) fn parse_missing_lines(
+        content: &str,
+        path: PathBuf,
+    ) -> Result<Vec<(ucd_parse::CodepointRange, String)>, Error> {
+        let mut missing = Vec::new();
+        for (n, line) in content.lines().enumerate() {
+            let Some(data) = line.strip_prefix("# @missing:") else {
+                continue;
+            };
+            let mut parts = data.split(';').map(str::trim);
+            let (Some(range), Some(value)) = (parts.next(), parts.next()) else {
+                return Err(Error {
+                    mesg: format!("invalid @missing line: '{}'", line),
+                    line: Some(n as u64 + 1),
+                    path: Some(path),
+                });
+            };
+            let range = range.parse().map_err(|e: ucd_parse::Error| Error {
+                mesg: e.to_string(),
+                line: Some(n as u64 + 1),
+                path: Some(path.clone()),
+            })?;
+            missing.push((range, String::from(value)));
+        }
+        Ok(missing)
+    }
+}
+
 /// Extension of the `UnicodeData` `struct` provided by the
 /// [`ucd_parse`](https://docs.rs/ucd-parse) crate. Unlike the
 /// original one, this `struct` does not represent a single line in the
@@ -122,9 +247,18 @@ pub struct UnicodeData {
 impl UnicodeData {
     /// Parse a particular `UCD` file into a sequence of rows.
     pub fn parse(ucd_dir: &Path) -> Result<Vec<UnicodeData>, Error> {
+        let raws: Vec<ucd_parse::UnicodeData> = ucd_parse::parse(ucd_dir)?;
+        Self::from_rows(&raws, ucd_dir)
+    }
+
+    /// Merges the rows of the `UnicodeData.txt` file in `ucd_dir` that
+    /// were already parsed.
+    pub(crate) fn from_rows(
+        raws: &[ucd_parse::UnicodeData],
+        ucd_dir: &Path,
+    ) -> Result<Vec<UnicodeData>, Error> {
         let mut xs = vec![];
 
-        let raws: Vec<ucd_parse::UnicodeData> = ucd_parse::parse(ucd_dir)?;
         let mut range: Option<ucd_parse::CodepointRange> = None;
         for udata in raws.iter() {
             match range.as_mut() {
@@ -0,0 +1,665 @@
+//! Reports the impact of moving the PRECIS tables to a new version of Unicode.
+//!
+//! [`rfc8264`](https://datatracker.ietf.org/doc/html/rfc8264#section-11)
+//! requires reviewing the changes in the derived property values of the code
+//! points whenever a new version of Unicode is adopted. This module computes
+//! the derived property values, the `Bidi_Class`, the width mapping and the
+//! membership to the `Zs` general category of every code point for two `UCD`
+//! directories and lists the code points whose values changed. Changes that
+//! remove code points from a string class, such as `PVALID` to `DISALLOWED`,
+//! are considered breaking changes.
+//!
+//! Next files are required in each `UCD` directory: `UnicodeData.txt`,
+//! `PropList.txt`, `DerivedCoreProperties.txt`, `HangulSyllableType.txt`,
+//! `DerivedNormalizationProps.txt` and `extracted/DerivedBidiClass.txt`.
+
+use crate::csv_parser::DerivedProperty;
+use crate::generators::bidi_class::BIDI_CLASS_VALUES;
+use crate::generators::constants::{ASCII7_END, ASCII7_START};
+use crate::generators::exceptions::EXCEPTIONS;
+use crate::ucd_parsers::{HangulSyllableType, NormalizationProperty};
+use crate::{Error, Ucd};
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::path::Path;
+use ucd_parse::{CodepointRange, CoreProperty, Property, UnicodeDataDecompositionTag};
+
+// Number of Unicode code points
+const CODEPOINTS: u32 = 0x110000;
+
+// Groups of general categories used by the PRECIS derived property
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Category {
+    // 9.1 LetterDigits (A)
+    LetterDigits,
+    // 9.18 OtherLetterDigits (R)
+    OtherLetterDigits,
+    // 9.12 Controls (L)
+    Controls,
+    // 9.14 Spaces (N)
+    Spaces,
+    // 9.15 Symbols (O)
+    Symbols,
+    // 9.16 Punctuation (P)
+    Punctuation,
+    // Any other assigned code point
+    Other,
+    // General_Category Cn
+    NotAssigned,
+}
+
+impl Category {
+    fn from_general_category(gc: &str) -> Self {
+        match gc {
+            "Ll" | "Lu" | "Lo" | "Nd" | "Lm" | "Mn" | "Mc" => Category::LetterDigits,
+            "Lt" | "Nl" | "No" | "Me" => Category::OtherLetterDigits,
+            "Cc" => Category::Controls,
+            "Zs" => Category::Spaces,
+            "Sm" | "Sc" | "Sk" | "So" => Category::Symbols,
+            "Pc" | "Pd" | "Ps" | "Pe" | "Pi" | "Pf" | "Po" => Category::Punctuation,
+            _ => Category::Other,
+        }
+    }
+}
+
+/// Properties of a code point relevant to the PRECIS framework.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CodepointProperties {
+    /// Derived property value in the `IdentifierClass`
+    pub identifier_class: DerivedProperty,
+    /// Derived property value in the `FreeformClass`
+    pub freeform_class: DerivedProperty,
+    /// Abbreviated name of the `Bidi_Class` value
+    pub bidi_class: &'static str,
+    /// Code point this one is mapped to by the width mapping rule
+    pub width_mapping: Option<u32>,
+    /// Whether the code point belongs to the `Zs` general category
+    pub space_separator: bool,
+}
+
+/// Properties relevant to the PRECIS framework of all the Unicode code
+/// points, computed from the files in a `UCD` directory.
+pub struct PrecisProperties {
+    version: Option<String>,
+    values: Vec<(DerivedProperty, DerivedProperty)>,
+    bidi_classes: Vec<u8>,
+    width_mappings: HashMap<u32, u32>,
+    spaces: HashSet<u32>,
+}
+
+impl PrecisProperties {
+    /// Computes the properties of all code points from the files in `ucd_dir`.
+    pub fn from_ucd_dir(ucd_dir: &Path) -> Result<Self, Error> {
+        Self::from_ucd(&Ucd::new(ucd_dir))
+    }
+
+    /// Computes the properties of all code points from the files in `ucd`.
+    pub fn from_ucd(ucd: &Ucd) -> Result<Self, Error> {
+        let size = CODEPOINTS as usize;
+        let mut categories = vec![Category::NotAssigned; size];
+        let mut width_mappings = HashMap::new();
+        let mut spaces = HashSet::new();
+
+        for udata in ucd.unicode_data()?.iter() {
+            let category = Category::from_general_category(&udata.general_category);
+            let width = match udata.decomposition.tag {
+                Some(UnicodeDataDecompositionTag::Wide)
+                | Some(UnicodeDataDecompositionTag::Narrow) => {
+                    Some(udata.decomposition.mapping[0].value())
+                }
+                _ => None,
+            };
+            for cp in udata.codepoints.into_iter().map(|cp| cp.value()) {
+                categories[cp as usize] = category;
+                if let Some(mapping) = width {
+                    width_mappings.insert(cp, mapping);
+                }
+                if category == Category::Spaces {
+                    spaces.insert(cp);
+                }
+            }
+        }
+
+        // Unassigned code points take the default values of their block
+        let mut bidi_classes = vec![0; size];
+        for (range, value) in ucd.bidi_classes()?.iter() {
+            let bidi = bidi_class_index(value)?;
+            for cp in range.start.value()..=range.end.value() {
+                bidi_classes[cp as usize] = bidi;
+            }
+        }
+
+        let mut join_control = HashSet::new();
+        let mut non_characters = HashSet::new();
+        let props = ucd.parse::<Property>()?;
+        for prop in props.iter() {
+            let set = match prop.property.as_str() {
+                "Join_Control" => &mut join_control,
+                "Noncharacter_Code_Point" => &mut non_characters,
+                _ => continue,
+            };
+            set.extend(prop.codepoints.into_iter().map(|cp| cp.value()));
+        }
+
+        let mut ignorable = HashSet::new();
+        let props = ucd.parse::<CoreProperty>()?;
+        for prop in props.iter() {
+            if prop.property == "Default_Ignorable_Code_Point" {
+                ignorable.extend(prop.codepoints.into_iter().map(|cp| cp.value()));
+            }
+        }
+
+        let mut old_hangul_jamo = HashSet::new();
+        let props = ucd.parse::<HangulSyllableType>()?;
+        for hst in props.iter() {
+            if matches!(hst.prop.property.as_str(), "L" | "V" | "T") {
+                old_hangul_jamo.extend(hst.prop.codepoints.into_iter().map(|cp| cp.value()));
+            }
+        }
+
+        // Code points that can not appear in `NFKC` are changed by the
+        // normalization, that is what `HasCompat` looks for.
+        let mut has_compat = HashSet::new();
+        let props = ucd.parse::<NormalizationProperty>()?;
+        for prop in props.iter() {
+            if prop.property == "NFKC_QC" && prop.value.as_deref() == Some("N") {
+                has_compat.extend(prop.codepoints.into_iter().map(|cp| cp.value()));
+            }
+        }
+
+        let exceptions: HashMap<u32, DerivedProperty> = EXCEPTIONS.iter().copied().collect();
+        let values = (0..CODEPOINTS)
+            .map(|cp| {
+                // 9.6 Exceptions (F)
+                if let Some(value) = exceptions.get(&cp) {
+                    return (*value, *value);
+                }
+                // 9.7 BackwardCompatible (G) is empty
+                let category = categories[cp as usize];
+                let value = if category == Category::NotAssigned && !non_characters.contains(&cp) {
+                    // 9.10 Unassigned (J)
+                    DerivedProperty::Unassigned
+                } else if (ASCII7_START..=ASCII7_END).contains(&cp) {
+                    // 9.11 ASCII7 (K)
+                    DerivedProperty::PValid
+                } else if join_control.contains(&cp) {
+                    // 9.8 JoinControl (H)
+                    DerivedProperty::ContextJ
+                } else if old_hangul_jamo.contains(&cp)
+                    || ignorable.contains(&cp)
+                    || non_characters.contains(&cp)
+                    || category == Category::Controls
+                {
+                    // 9.9 OldHangulJamo (I), 9.13 PrecisIgnorableProperties (M)
+                    // and 9.12 Controls (L)
+                    DerivedProperty::Disallowed
+                } else if has_compat.contains(&cp) {
+                    // 9.17 HasCompat (Q)
+                    return (DerivedProperty::IdDis, DerivedProperty::FreePVal);
+                } else {
+                    match category {
+                        Category::LetterDigits => DerivedProperty::PValid,
+                        Category::OtherLetterDigits
+                        | Category::Spaces
+                        | Category::Symbols
+                        | Category::Punctuation => {
+                            return (DerivedProperty::IdDis, DerivedProperty::FreePVal)
+                        }
+                        _ => DerivedProperty::Disallowed,
+                    }
+                };
+                (value, value)
+            })
+            .collect();
+
+        let version = ucd
+            .version()
+            .ok()
+            .map(|(major, minor, patch)| format!("{}.{}.{}", major, minor, patch));
+
+        Ok(Self {
+            version,
+            values,
+            bidi_classes,
+            width_mappings,
+            spaces,
+        })
+    }
+
+    /// Returns the Unicode version of the `UCD` files, if it is known.
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    /// Returns the properties of the code point `cp`, or `None` if
+    /// it is not a valid code point.
+    pub fn get(&self, cp: u32) -> Option<CodepointProperties> {
+        let (identifier_class, freeform_class) = *self.values.get(cp as usize)?;
+        Some(CodepointProperties {
+            identifier_class,
+            freeform_class,
+            bidi_class: BIDI_CLASS_VALUES[self.bidi_classes[cp as usize] as usize].0,
+            width_mapping: self.width_mappings.get(&cp).copied(),
+            space_separator: self.spaces.contains(&cp),
+        })
+    }
+}
+
+fn bidi_class_index(bidi: &str) -> Result<u8, Error> {
+    match BIDI_CLASS_VALUES
+        .iter()
+        .position(|(value, _)| *value == bidi)
+    {
+        Some(index) => Ok(index as u8),
+        None => err!("Unknown Bidi_Class value: {}", bidi),
+    }
+}
+
+// Allowed code points rank higher than contextual ones, which rank
+// higher than code points not allowed in the string class.
+fn rank(value: DerivedProperty) -> u8 {
+    match value {
+        DerivedProperty::PValid | DerivedProperty::FreePVal => 2,
+        DerivedProperty::ContextJ | DerivedProperty::ContextO => 1,
+        _ => 0,
+    }
+}
+
+/// Consecutive code points whose properties changed in the same way.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PropertyChange {
+    /// The code points
+    pub codepoints: CodepointRange,
+    /// Properties in the old Unicode version
+    pub old: CodepointProperties,
+    /// Properties in the new Unicode version
+    pub new: CodepointProperties,
+}
+
+impl PropertyChange {
+    /// Returns true if the code points are no longer allowed, or are
+    /// allowed only in some contexts, in any of the string classes.
+    pub fn is_breaking(&self) -> bool {
+        rank(self.new.identifier_class) < rank(self.old.identifier_class)
+            || rank(self.new.freeform_class) < rank(self.old.freeform_class)
+    }
+
+    // Number of code points
+    fn len(&self) -> u32 {
+        self.codepoints.end.value() - self.codepoints.start.value() + 1
+    }
+
+    fn codepoints_str(&self, separator: &str) -> String {
+        let (start, end) = (self.codepoints.start.value(), self.codepoints.end.value());
+        match start == end {
+            true => format!("{:04X}", start),
+            false => format!("{:04X}{}{:04X}", start, separator, end),
+        }
+    }
+
+    fn differences(&self) -> Vec<String> {
+        let mut diffs = Vec::new();
+        let (old, new) = (&self.old, &self.new);
+        if old.identifier_class != new.identifier_class {
+            diffs.push(format!(
+                "IdentifierClass: {} -> {}",
+                old.identifier_class, new.identifier_class
+            ));
+        }
+        if old.freeform_class != new.freeform_class {
+            diffs.push(format!(
+                "FreeformClass: {} -> {}",
+                old.freeform_class, new.freeform_class
+            ));
+        }
+        if old.bidi_class != new.bidi_class {
+            diffs.push(format!(
+                "Bidi_Class: {} -> {}",
+                old.bidi_class, new.bidi_class
+            ));
+        }
+        if old.width_mapping != new.width_mapping {
+            diffs.push(format!(
+                "width mapping: {} -> {}",
+                mapping_str(old.width_mapping, "none"),
+                mapping_str(new.width_mapping, "none")
+            ));
+        }
+        if old.space_separator != new.space_separator {
+            diffs.push(format!(
+                "Zs: {} -> {}",
+                old.space_separator, new.space_separator
+            ));
+        }
+        diffs
+    }
+}
+
+fn mapping_str(mapping: Option<u32>, none: &str) -> String {
+    mapping.map_or(String::from(none), |cp| format!("{:04X}", cp))
+}
+
+/// Code points whose PRECIS properties differ between two versions of Unicode.
+/// # Example
+/// ```no_run
+/// # use precis_tools::UpgradeReport;
+/// # use std::path::Path;
+/// let report = UpgradeReport::from_ucd_dirs(Path::new("ucd-6.3.0"), Path::new("ucd-17.0.0"))
+///     .unwrap();
+/// for change in report.breaking_changes() {
+///     println!("{:?}", change.codepoints);
+/// }
+/// report.write_csv(&mut std::io::stdout()).unwrap();
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeReport {
+    old_version: Option<String>,
+    new_version: Option<String>,
+    changes: Vec<PropertyChange>,
+}
+
+impl UpgradeReport {
+    /// Compares the properties of all code points in two versions of Unicode.
+    pub fn new(old: &PrecisProperties, new: &PrecisProperties) -> Self {
+        let mut changes: Vec<PropertyChange> = Vec::new();
+        for cp in 0..CODEPOINTS {
+            let (Some(o), Some(n)) = (old.get(cp), new.get(cp)) else {
+                continue;
+            };
+            if o == n {
+                continue;
+            }
+            let codepoint = ucd_parse::Codepoint::from_u32(cp).unwrap();
+            match changes.last_mut() {
+                Some(last)
+                    if last.codepoints.end.value() + 1 == cp && last.old == o && last.new == n =>
+                {
+                    last.codepoints.end = codepoint;
+                }
+                _ => changes.push(PropertyChange {
+                    codepoints: CodepointRange {
+                        start: codepoint,
+                        end: codepoint,
+                    },
+                    old: o,
+                    new: n,
+                }),
+            }
+        }
+
+        Self {
+            old_version: old.version.clone(),
+            new_version: new.version.clone(),
+            changes,
+        }
+    }
+
+    /// Computes the properties of all code points from the files in
+    /// two `UCD` directories and compares them.
+    pub fn from_ucd_dirs(old_ucd_dir: &Path, new_ucd_dir: &Path) -> Result<Self, Error> {
+        let old = PrecisProperties::from_ucd_dir(old_ucd_dir)?;
+        let new = PrecisProperties::from_ucd_dir(new_ucd_dir)?;
+        Ok(Self::new(&old, &new))
+    }
+
+    /// Returns the changes sorted by code point.
+    pub fn changes(&self) -> &[PropertyChange] {
+        &self.changes
+    }
+
+    /// Returns the breaking changes sorted by code point.
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &PropertyChange> {
+        self.changes.iter().filter(|c| c.is_breaking())
+    }
+
+    /// Writes the report as text, breaking changes are listed first.
+    pub fn write_text<W: Write>(&self, w: &mut W) -> Result<(), Error> {
+        let changed: u32 = self.changes.iter().map(|c| c.len()).sum();
+        let breaking: u32 = self.breaking_changes().map(|c| c.len()).sum();
+        writeln!(
+            w,
+            "PRECIS impact of upgrading Unicode {} to {}",
+            self.old_version.as_deref().unwrap_or("unknown"),
+            self.new_version.as_deref().unwrap_or("unknown")
+        )?;
+        writeln!(
+            w,
+            "Code points changed: {} ({} breaking)",
+            changed, breaking
+        )?;
+
+        for (title, breaking) in [("Breaking changes", true), ("Other changes", false)] {
+            let mut changes = self
+                .changes
+                .iter()
+                .filter(|c| c.is_breaking() == breaking)
+                .peekable();
+            if changes.peek().is_none() {
+                continue;
+            }
+            writeln!(w)?;
+            writeln!(w, "{}:", title)?;
+            for change in changes {
+                writeln!(
+                    w,
+                    "{:<12} {}",
+                    change.codepoints_str(".."),
+                    change.differences().join(", ")
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the report as `CSV`, one row per change. Code points are
+    /// written in the same format as the `IANA` `precis-tables.csv` file.
+    pub fn write_csv<W: Write>(&self, w: &mut W) -> Result<(), Error> {
+        writeln!(
+            w,
+            "Codepoint,Breaking,\
+            Old IdentifierClass,New IdentifierClass,\
+            Old FreeformClass,New FreeformClass,\
+            Old Bidi_Class,New Bidi_Class,\
+            Old WidthMapping,New WidthMapping,\
+            Old Zs,New Zs"
+        )?;
+        for change in self.changes.iter() {
+            let (old, new) = (&change.old, &change.new);
+            writeln!(
+                w,
+                "{},{},{},{},{},{},{},{},{},{},{},{}",
+                change.codepoints_str("-"),
+                change.is_breaking(),
+                old.identifier_class,
+                new.identifier_class,
+                old.freeform_class,
+                new.freeform_class,
+                old.bidi_class,
+                new.bidi_class,
+                mapping_str(old.width_mapping, ""),
+                mapping_str(new.width_mapping, ""),
+                old.space_separator,
+                new.space_separator
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::TempDir;
+    use crate::upgrade::*;
+
+    const UNICODE_DATA: &str = "\
+0020;SPACE;Zs;0;WS;;;;;N;;;;;
+0041;LATIN CAPITAL LETTER A;Lu;0;L;;;;;N;;;;0061;
+00B7;MIDDLE DOT;Po;0;ON;;;;;N;;;;;
+05D0;HEBREW LETTER ALEF;Lo;0;R;;;;;N;;;;;
+1100;HANGUL CHOSEONG KIYEOK;Lo;0;L;;;;;N;;;;;
+180E;MONGOLIAN VOWEL SEPARATOR;Zs;0;WS;;;;;N;;;;;
+200D;ZERO WIDTH JOINER;Cf;0;BN;;;;;N;;;;;
+2160;ROMAN NUMERAL ONE;Nl;0;L;<compat> 0049;;;1;N;;;;2170;
+2E2F;VERTICAL TILDE;Lm;0;ON;;;;;N;;;;;
+4E00;<CJK Ideograph, First>;Lo;0;L;;;;;N;;;;;
+4E02;<CJK Ideograph, Last>;Lo;0;L;;;;;N;;;;;
+FF21;FULLWIDTH LATIN CAPITAL LETTER A;Lu;0;L;;;;;N;;;;FF41;
+";
+
+    // Changes: U+05D0 is `AL`, U+180E is not a space, U+2E2F is a
+    // punctuation, U+4E03 and U+4E04 are assigned and U+FF21 has
+    // a width mapping.
+    const UNICODE_DATA_NEW: &str = "\
+0020;SPACE;Zs;0;WS;;;;;N;;;;;
+0041;LATIN CAPITAL LETTER A;Lu;0;L;;;;;N;;;;0061;
+00B7;MIDDLE DOT;Po;0;ON;;;;;N;;;;;
+05D0;HEBREW LETTER ALEF;Lo;0;AL;;;;;N;;;;;
+1100;HANGUL CHOSEONG KIYEOK;Lo;0;L;;;;;N;;;;;
+180E;MONGOLIAN VOWEL SEPARATOR;Cf;0;BN;;;;;N;;;;;
+200D;ZERO WIDTH JOINER;Cf;0;BN;;;;;N;;;;;
+2160;ROMAN NUMERAL ONE;Nl;0;L;<compat> 0049;;;1;N;;;;2170;
+2E2F;VERTICAL TILDE;Po;0;ON;;;;;N;;;;;
+4E00;<CJK Ideograph, First>;Lo;0;L;;;;;N;;;;;
+4E04;<CJK Ideograph, Last>;Lo;0;L;;;;;N;;;;;
+FF21;FULLWIDTH LATIN CAPITAL LETTER A;Lu;0;L;<wide> 0041;;;;N;;;;FF41;
+";
+
+    fn ucd_dir(
+        name: &str,
+        version: &str,
+        unicode_data: &str,
+        ignorable: &str,
+        nfkc_qc: &str,
+    ) -> TempDir {
+        let dir = TempDir::new(&format!("upgrade-{}", name));
+        dir.write("UnicodeData.txt", unicode_data);
+        dir.write(
+            "PropList.txt",
+            format!(
+                "# PropList-{}.txt\n\
+                200D          ; Join_Control # Cf       ZERO WIDTH JOINER\n\
+                FDD0..FDEF    ; Noncharacter_Code_Point # Cn  [32] <noncharacter-FDD0>..<noncharacter-FDEF>\n",
+                version
+            ),
+        );
+        dir.write("DerivedCoreProperties.txt", ignorable);
+        dir.write(
+            "HangulSyllableType.txt",
+            "1100          ; L # Lo       HANGUL CHOSEONG KIYEOK\n",
+        );
+        dir.write("DerivedNormalizationProps.txt", nfkc_qc);
+        // Bidi classes are those listed in `UnicodeData.txt`
+        let mut bidi = String::from(
+            "# @missing: 0000..10FFFF; Left_To_Right\n\
+             # @missing: 0590..05FF; Right_To_Left\n",
+        );
+        for line in unicode_data.lines() {
+            let fields: Vec<&str> = line.split(';').collect();
+            bidi.push_str(&format!("{} ; {}\n", fields[0], fields[4]));
+        }
+        dir.write("extracted/DerivedBidiClass.txt", bidi);
+        dir
+    }
+
+    #[test]
+    fn normalization_property() {
+        let prop: NormalizationProperty =
+            "2160 ; NFKC_QC; N # Nl ROMAN NUMERAL ONE".parse().unwrap();
+        assert_eq!(prop.property, "NFKC_QC");
+        assert_eq!(prop.value.as_deref(), Some("N"));
+
+        let prop: NormalizationProperty = "0340..0341 ; Full_Composition_Exclusion # Mn"
+            .parse()
+            .unwrap();
+        assert_eq!(prop.property, "Full_Composition_Exclusion");
+        assert_eq!(prop.value, None);
+    }
+
+    #[test]
+    fn upgrade_report() {
+        let old = ucd_dir(
+            "old",
+            "1.0.0",
+            UNICODE_DATA,
+            "",
+            "2160 ; NFKC_QC; N # Nl ROMAN NUMERAL ONE\n",
+        );
+        let new = ucd_dir(
+            "new",
+            "2.0.0",
+            UNICODE_DATA_NEW,
+            "180E ; Default_Ignorable_Code_Point # Cf MONGOLIAN VOWEL SEPARATOR\n",
+            "2160 ; NFKC_QC; N # Nl ROMAN NUMERAL ONE\n\
+            FF21 ; NFKC_QC; N # Lu FULLWIDTH LATIN CAPITAL LETTER A\n",
+        );
+
+        let props = PrecisProperties::from_ucd_dir(&old).unwrap();
+        assert_eq!(props.version(), Some("1.0.0"));
+        let values = |cp| {
+            let p = props.get(cp).unwrap();
+            (p.identifier_class, p.freeform_class)
+        };
+        use DerivedProperty::*;
+        assert_eq!(values(0x0020), (IdDis, FreePVal));
+        assert_eq!(values(0x0041), (PValid, PValid));
+        assert_eq!(values(0x00B7), (ContextO, ContextO));
+        assert_eq!(values(0x0378), (Unassigned, Unassigned));
+        assert_eq!(values(0x1100), (Disallowed, Disallowed));
+        assert_eq!(values(0x200D), (ContextJ, ContextJ));
+        assert_eq!(values(0x2160), (IdDis, FreePVal));
+        assert_eq!(values(0x4E01), (PValid, PValid));
+        assert_eq!(values(0xFDD0), (Disallowed, Disallowed));
+        assert_eq!(props.get(0x0378).unwrap().bidi_class, "L");
+        assert_eq!(props.get(0x05D0).unwrap().bidi_class, "R");
+        // Default value of the unassigned code points of the block
+        assert_eq!(props.get(0x05FF).unwrap().bidi_class, "R");
+        assert!(props.get(0x180E).unwrap().space_separator);
+        assert_eq!(props.get(0x110000), None);
+
+        let report = UpgradeReport::from_ucd_dirs(&old, &new).unwrap();
+        let changes = report.changes();
+        assert_eq!(changes.len(), 5);
+        let cps: Vec<String> = changes.iter().map(|c| c.codepoints_str("..")).collect();
+        assert_eq!(cps, ["05D0", "180E", "2E2F", "4E03..4E04", "FF21"]);
+        assert_eq!(changes[3].len(), 2);
+
+        let breaking: Vec<u32> = report
+            .breaking_changes()
+            .map(|c| c.codepoints.start.value())
+            .collect();
+        assert_eq!(breaking, [0x180E, 0x2E2F, 0xFF21]);
+
+        let mut text = Vec::new();
+        report.write_text(&mut text).unwrap();
+        assert_eq!(
+            String::from_utf8(text).unwrap(),
+            "\
+PRECIS impact of upgrading Unicode 1.0.0 to 2.0.0
+Code points changed: 6 (3 breaking)
+
+Breaking changes:
+180E         IdentifierClass: ID_DIS -> DISALLOWED, FreeformClass: FREE_PVAL -> DISALLOWED, Bidi_Class: WS -> BN, Zs: true -> false
+2E2F         IdentifierClass: PVALID -> ID_DIS, FreeformClass: PVALID -> FREE_PVAL
+FF21         IdentifierClass: PVALID -> ID_DIS, FreeformClass: PVALID -> FREE_PVAL, width mapping: none -> 0041
+
+Other changes:
+05D0         Bidi_Class: R -> AL
+4E03..4E04   IdentifierClass: UNASSIGNED -> PVALID, FreeformClass: UNASSIGNED -> PVALID
+"
+        );
+
+        let mut csv = Vec::new();
+        report.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(
+            lines[2],
+            "180E,true,ID_DIS,DISALLOWED,FREE_PVAL,DISALLOWED,WS,BN,,,true,false"
+        );
+        assert_eq!(
+            lines[4],
+            "4E03-4E04,false,UNASSIGNED,PVALID,UNASSIGNED,PVALID,L,L,,,false,false"
+        );
+    }
+}